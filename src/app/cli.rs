@@ -0,0 +1,364 @@
+use crate::domain::models::sprint::{Sprint, SprintState};
+use crate::domain::models::ticket::Ticket;
+use crate::domain::services::release_notes_service::ReleaseNotesService;
+use crate::domain::services::sprint_service::{ChangelogFormat, SprintService};
+use crate::domain::services::template_service;
+use crate::domain::services::timesheet_service::TimesheetService;
+use crate::domain::services::TicketService;
+use crate::infrastructure::api::ApiClient;
+use crate::infrastructure::config::{ProjectDefaults, TicketTemplate};
+use crate::utils::time::DisplayTimezone;
+use crate::utils::{LazyJiraError, Result};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::io::{BufRead, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Most tickets `lazyjira list` prints
+pub const LIST_LIMIT: usize = 100;
+
+/// JQL for `lazyjira list`: read from `jql_file` when given, from `stdin`
+/// when the query is `-`, and otherwise the query itself
+pub fn read_jql(query: Option<&str>, jql_file: Option<&Path>, stdin: impl Read) -> Result<String> {
+    let jql = match (jql_file, query) {
+        (Some(path), _) => std::fs::read_to_string(path).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to read {}: {}", path.display(), e))
+        })?,
+        (None, Some("-")) => {
+            let mut jql = String::new();
+            let mut stdin = stdin;
+            stdin.read_to_string(&mut jql)?;
+            jql
+        }
+        (None, Some(query)) => query.to_string(),
+        (None, None) => String::new(),
+    };
+    // Files are often written one clause per line. Only blank lines are
+    // dropped: the rest goes to Jira as written, which reads line breaks as
+    // spaces, so quoted values keep their spacing
+    let jql = jql
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if jql.is_empty() {
+        return Err(LazyJiraError::Validation(
+            "no JQL given; pass it as an argument, `-` for stdin or --jql-file <path>".to_string(),
+        ));
+    }
+    Ok(jql)
+}
+
+/// Tickets printed by `lazyjira list`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Listing {
+    /// One tab-separated line per ticket: key, status, summary
+    pub lines: Vec<String>,
+    /// Number of tickets matching the query, which can be more than listed;
+    /// None when the list is cut short and Jira could not count them
+    pub total: Option<usize>,
+}
+
+impl Listing {
+    /// Notice for stderr when only the first tickets of the search are listed
+    pub fn truncated_notice(&self) -> Option<String> {
+        match self.total {
+            Some(total) if total <= self.lines.len() => None,
+            Some(total) => Some(format!(
+                "showing {} of {} tickets; narrow the query to see the rest",
+                self.lines.len(),
+                total
+            )),
+            None => Some(format!(
+                "showing the first {} tickets; narrow the query to see the rest",
+                self.lines.len()
+            )),
+        }
+    }
+}
+
+/// Up to `LIST_LIMIT` tickets matching `jql`
+pub async fn list(client: &dyn ApiClient, jql: &str) -> Result<Listing> {
+    let result = client.search_issues(jql, 0, LIST_LIMIT).await?;
+    let lines: Vec<String> = result.issues.iter().map(ticket_line).collect();
+    // Jira's search does not say how many issues match, so a full page is
+    // counted separately
+    let total = if lines.len() < LIST_LIMIT {
+        Some(lines.len())
+    } else {
+        match client.count_issues(jql).await {
+            Ok(count) => Some(count.max(lines.len())),
+            Err(e) => {
+                log::warn!("list: Failed to count the tickets of {}: {}", jql, e);
+                None
+            }
+        }
+    };
+    Ok(Listing { lines, total })
+}
+
+fn ticket_line(ticket: &Ticket) -> String {
+    format!("{}\t{}\t{}", ticket.key, ticket.status.name, ticket.summary)
+}
+
+/// Most tickets listed in release notes
+pub const RELEASE_NOTES_LIMIT: usize = 500;
+
+/// Title and JQL of `lazyjira release-notes`: the issues of a fix version,
+/// optionally narrowed to a project, or those matching a query
+pub fn release_notes_query(
+    version: Option<&str>,
+    project: Option<&str>,
+    jql: Option<&str>,
+) -> Result<(String, String)> {
+    match (version, jql) {
+        (Some(version), _) => {
+            let project = project
+                .map(|project| format!("project = \"{}\" AND ", project))
+                .unwrap_or_default();
+            Ok((
+                format!("Release notes — {}", version),
+                format!("{}fixVersion = \"{}\" ORDER BY key ASC", project, version),
+            ))
+        }
+        (None, Some(jql)) => Ok(("Release notes".to_string(), jql.to_string())),
+        (None, None) => Err(LazyJiraError::Validation(
+            "pass --version <name> [--project <key>] or --jql <query>".to_string(),
+        )),
+    }
+}
+
+/// Markdown release notes of the tickets matching `jql`
+pub async fn release_notes(client: &dyn ApiClient, title: &str, jql: &str) -> Result<String> {
+    let result = client.search_issues(jql, 0, RELEASE_NOTES_LIMIT).await?;
+    Ok(ReleaseNotesService::markdown(title, &result.issues))
+}
+
+/// Sprint of `lazyjira sprint-report`: the one with the given id or name,
+/// otherwise the active sprint, otherwise the last closed one. Sprints are
+/// looked up on `board_id`, or on the first scrum board without one
+pub async fn find_sprint(client: &dyn ApiClient, board_id: Option<&str>, sprint: Option<&str>) -> Result<Sprint> {
+    let board_id = match board_id {
+        Some(board_id) => board_id.to_string(),
+        None => client
+            .get_boards()
+            .await?
+            .into_iter()
+            .find(|board| board.is_scrum())
+            .map(|board| board.id)
+            .ok_or_else(|| LazyJiraError::Validation("no scrum board found; pass --board <id>".to_string()))?,
+    };
+    let sprints = client
+        .get_sprints(&board_id, &[SprintState::Active, SprintState::Closed])
+        .await?;
+    let found = match sprint {
+        Some(wanted) => sprints
+            .into_iter()
+            .find(|sprint| sprint.id == wanted || sprint.name.eq_ignore_ascii_case(wanted)),
+        None => {
+            let active = sprints.iter().find(|sprint| sprint.is_active()).cloned();
+            active.or_else(|| sprints.into_iter().rev().find(|sprint| sprint.state == SprintState::Closed))
+        }
+    };
+    found.ok_or_else(|| {
+        LazyJiraError::Validation(format!(
+            "no sprint {} on board {}",
+            sprint.unwrap_or("active or closed"),
+            board_id
+        ))
+    })
+}
+
+/// Changelog of the issues resolved in `sprint`
+pub async fn sprint_changelog(client: &dyn ApiClient, sprint: &Sprint, format: ChangelogFormat) -> Result<String> {
+    let issues = client.get_sprint_issues(&sprint.id).await?;
+    Ok(SprintService::changelog(sprint, &issues, format))
+}
+
+/// CSV timesheet of the current user for the week containing `week`, a
+/// YYYY-MM-DD date, or this week without one
+pub async fn timesheet(client: &dyn ApiClient, week: Option<&str>, timezone: &DisplayTimezone) -> Result<String> {
+    let date = match week {
+        Some(week) => NaiveDate::parse_from_str(week, "%Y-%m-%d")
+            .map_err(|_| LazyJiraError::Validation(format!("'{}' is not a YYYY-MM-DD date", week)))?,
+        None => timezone.today(),
+    };
+    let user = client.get_myself().await?;
+    let timesheet = TimesheetService::load(client, &user, date, timezone).await?;
+    Ok(timesheet.csv())
+}
+
+/// Create a ticket from a template for `lazyjira create`. `{date}`,
+/// `{branch}` and `{user}` are filled in; every other placeholder is asked
+/// for on `prompt` and read from `input` until it gets an answer
+pub async fn create_from_template(
+    client: Arc<dyn ApiClient>,
+    template: &TicketTemplate,
+    defaults: Option<&ProjectDefaults>,
+    mut input: impl BufRead,
+    mut prompt: impl Write,
+) -> Result<Ticket> {
+    let mut values = builtin_values(client.as_ref(), template).await;
+    for name in template_service::unresolved(template, &values) {
+        let value = loop {
+            write!(prompt, "{}: ", name)?;
+            prompt.flush()?;
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                return Err(LazyJiraError::Validation(format!("no value given for {{{}}}", name)));
+            }
+            if !line.trim().is_empty() {
+                break line.trim().to_string();
+            }
+        };
+        values.insert(name, value);
+    }
+
+    let mut data = template_service::apply(template, &values);
+    if let Some(defaults) = defaults {
+        data = data.with_project_defaults(defaults);
+    }
+    TicketService::new(client).create_ticket(data).await
+}
+
+/// Values of the built-in variables the template uses
+async fn builtin_values(client: &dyn ApiClient, template: &TicketTemplate) -> HashMap<String, String> {
+    let mut values = HashMap::new();
+    for name in template_service::placeholders(template) {
+        let value = match name.as_str() {
+            "date" => Some(chrono::Local::now().format("%Y-%m-%d").to_string()),
+            "branch" => current_branch(),
+            "user" => client.get_myself().await.ok().map(|user| user.display_name),
+            _ => None,
+        };
+        // Left unresolved, and so asked for, when it cannot be found out
+        if let Some(value) = value {
+            values.insert(name, value);
+        }
+    }
+    values
+}
+
+/// Git branch checked out in the working directory
+fn current_branch() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .ok()?;
+    let branch = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !branch.is_empty()).then_some(branch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::api::demo_client::DemoApiClient;
+
+    #[tokio::test]
+    async fn test_read_jql_from_argument_stdin_or_file() {
+        let stdin = "project = FOO\n\n  AND status = Done\n".as_bytes();
+        assert_eq!(
+            read_jql(Some("-"), None, stdin).unwrap(),
+            "project = FOO\n  AND status = Done"
+        );
+        assert_eq!(read_jql(Some("project = BAR"), None, &b""[..]).unwrap(), "project = BAR");
+        assert!(read_jql(None, None, &b""[..]).is_err());
+        assert!(read_jql(Some("-"), None, &b"\n"[..]).is_err());
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("query.jql");
+        std::fs::write(&path, "project = PROJ\nORDER BY key\n").unwrap();
+        assert_eq!(
+            read_jql(Some("ignored"), Some(&path), &b""[..]).unwrap(),
+            "project = PROJ\nORDER BY key"
+        );
+        std::fs::write(&path, "summary ~ \"  spaced  \"\n").unwrap();
+        assert_eq!(read_jql(None, Some(&path), &b""[..]).unwrap(), "summary ~ \"  spaced  \"");
+
+        let listing = list(&DemoApiClient::new().unwrap(), "project = PROJ").await.unwrap();
+        assert!(listing.lines.iter().any(|line| line.starts_with("PROJ-123\t")));
+        assert_eq!(listing.truncated_notice(), None);
+    }
+
+    #[test]
+    fn test_listing_notices_truncation() {
+        let mut listing = Listing {
+            lines: vec!["PROJ-1\tTo Do\tFirst".to_string(); LIST_LIMIT],
+            total: None,
+        };
+        assert_eq!(
+            listing.truncated_notice().as_deref(),
+            Some("showing the first 100 tickets; narrow the query to see the rest")
+        );
+        listing.total = Some(LIST_LIMIT);
+        assert_eq!(listing.truncated_notice(), None);
+    }
+
+    #[tokio::test]
+    async fn test_release_notes_for_version_or_query() {
+        let (title, jql) = release_notes_query(Some("2.0"), Some("PROJ"), None).unwrap();
+        assert_eq!(title, "Release notes — 2.0");
+        assert_eq!(jql, "project = \"PROJ\" AND fixVersion = \"2.0\" ORDER BY key ASC");
+        let (_, jql) = release_notes_query(None, None, Some("sprint = 5")).unwrap();
+        assert_eq!(jql, "sprint = 5");
+        assert!(release_notes_query(None, Some("PROJ"), None).is_err());
+
+        let notes = release_notes(&DemoApiClient::new().unwrap(), &title, "project = PROJ").await.unwrap();
+        assert!(notes.starts_with("# Release notes — 2.0\n"));
+        assert!(notes.contains("PROJ-123"));
+    }
+
+    #[tokio::test]
+    async fn test_sprint_changelog_of_active_sprint() {
+        let client = DemoApiClient::new().unwrap();
+        let sprint = find_sprint(&client, None, None).await.unwrap();
+        assert!(sprint.is_active());
+        assert_eq!(find_sprint(&client, None, Some(&sprint.name)).await.unwrap().id, sprint.id);
+        assert!(find_sprint(&client, None, Some("No such sprint")).await.is_err());
+
+        let csv = sprint_changelog(&client, &sprint, ChangelogFormat::Csv).await.unwrap();
+        assert!(csv.starts_with("key,summary,assignee,points\n"));
+    }
+
+    #[tokio::test]
+    async fn test_timesheet_of_this_week() {
+        let client = DemoApiClient::new().unwrap();
+        let csv = timesheet(&client, None, &DisplayTimezone::Local).await.unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].starts_with("issue,summary,"));
+        assert!(lines.len() > 2, "the demo user logged work today");
+        assert!(lines[lines.len() - 1].starts_with("total,,"));
+        assert!(timesheet(&client, Some("last week"), &DisplayTimezone::Local).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_from_template_prompts_for_placeholders() {
+        let client: Arc<dyn ApiClient> = Arc::new(DemoApiClient::new().unwrap());
+        let template = TicketTemplate {
+            project: "PROJ".to_string(),
+            issue_type: Some("Bug".to_string()),
+            summary: "{component}: {title} ({date})".to_string(),
+            description: None,
+            labels: Vec::new(),
+        };
+        let mut prompts = Vec::new();
+        let ticket = create_from_template(
+            client.clone(),
+            &template,
+            None,
+            "API\n\nTimeout\n".as_bytes(),
+            &mut prompts,
+        )
+        .await
+        .unwrap();
+
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        assert_eq!(ticket.summary, format!("API: Timeout ({})", today));
+        // The empty answer asks again
+        assert_eq!(String::from_utf8(prompts).unwrap(), "component: title: title: ");
+
+        let result = create_from_template(client, &template, None, "API\n".as_bytes(), Vec::new()).await;
+        assert!(matches!(result, Err(LazyJiraError::Validation(_))));
+    }
+}
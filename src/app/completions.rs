@@ -0,0 +1,195 @@
+use crate::infrastructure::config::Config;
+use crate::infrastructure::storage::recent_tickets::RecentTickets;
+
+/// Shell for which `lazyjira completions` prints a script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "bash" => Some(Shell::Bash),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            _ => None,
+        }
+    }
+
+    /// Completion script; filter names and ticket keys are looked up when
+    /// completing, through `lazyjira complete filters|tickets`
+    pub fn script(self) -> &'static str {
+        match self {
+            Shell::Bash => BASH,
+            Shell::Zsh => ZSH,
+            Shell::Fish => FISH,
+        }
+    }
+}
+
+/// Values completed for `lazyjira complete <what>`, one per line: saved
+/// filter names, recently opened ticket keys or template names
+pub fn candidates(what: &str, config: &Config, recent: &RecentTickets) -> Option<Vec<String>> {
+    match what {
+        "filters" => Some(
+            config
+                .filters
+                .iter()
+                .take(9)
+                .map(|filter| filter.name.clone())
+                .collect(),
+        ),
+        "tickets" => Some(recent.keys().to_vec()),
+        "templates" => Some(config.templates.keys().cloned().collect()),
+        _ => None,
+    }
+}
+
+const BASH: &str = r#"_lazyjira() {
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    local prev="${COMP_WORDS[COMP_CWORD-1]}"
+    local IFS=$'\n'
+    case "$prev" in
+        --color)
+            COMPREPLY=($(compgen -W $'auto\nalways\nnever' -- "$cur"))
+            return ;;
+        --filter)
+            COMPREPLY=($(compgen -W "$(lazyjira complete filters 2>/dev/null)" -- "$cur"))
+            COMPREPLY=("${COMPREPLY[@]// /\\ }")
+            return ;;
+        --open)
+            COMPREPLY=($(compgen -W "$(lazyjira complete tickets 2>/dev/null)" -- "$cur"))
+            return ;;
+        completions)
+            COMPREPLY=($(compgen -W $'bash\nzsh\nfish' -- "$cur"))
+            return ;;
+        create)
+            COMPREPLY=($(compgen -W "$(lazyjira complete templates 2>/dev/null)" -- "$cur"))
+            return ;;
+        --jql-file|--output)
+            COMPREPLY=($(compgen -f -- "$cur"))
+            return ;;
+    esac
+    local words=$'--demo\n--color\n--filter\n--open'
+    if [[ $COMP_CWORD -eq 1 ]]; then
+        words+=$'\ndoctor\nkeys\nlist\ncreate\nrelease-notes\nsprint-report\ntimesheet\ncompletions'
+    elif [[ ${COMP_WORDS[1]} == keys ]]; then
+        words+=$'\n--markdown'
+    elif [[ ${COMP_WORDS[1]} == list ]]; then
+        words+=$'\n--jql-file'
+    elif [[ ${COMP_WORDS[1]} == release-notes ]]; then
+        words+=$'\n--version\n--project\n--jql\n--output\n--clipboard'
+    elif [[ ${COMP_WORDS[1]} == sprint-report ]]; then
+        words+=$'\n--board\n--csv\n--output\n--clipboard'
+    elif [[ ${COMP_WORDS[1]} == timesheet ]]; then
+        words+=$'\n--week\n--output\n--clipboard'
+    fi
+    COMPREPLY=($(compgen -W "$words" -- "$cur"))
+}
+complete -F _lazyjira lazyjira
+"#;
+
+const ZSH: &str = r#"#compdef lazyjira
+
+_lazyjira() {
+    local state
+    _arguments \
+        '--demo[run against sample data]' \
+        '--color[when to use colors]:when:(auto always never)' \
+        '--filter[start with a quick filter]:filter:->filters' \
+        '--open[start with a ticket open]:ticket:->tickets' \
+        '--markdown[print the keymap as Markdown]' \
+        '--jql-file[read the JQL of list from a file]:file:_files' \
+        '--version[fix version of the release notes]:version:' \
+        '--project[project of the release notes]:project:' \
+        '--jql[query of the release notes]:jql:' \
+        '--output[write the release notes to a file]:file:_files' \
+        '--clipboard[copy the report]' \
+        '--board[board of the sprint report]:board:' \
+        '--csv[export the sprint report as CSV]' \
+        '--week[a date in the week of the timesheet]:date:' \
+        '1:command:((doctor\:"check the setup" keys\:"print the keymap" list\:"print tickets matching JQL" create\:"create a ticket from a template" release-notes\:"print release notes in Markdown" sprint-report\:"export the issues resolved in a sprint" timesheet\:"export my logged hours of a week" completions\:"print a completion script"))' \
+        '2:argument:->argument'
+    case $state in
+        argument)
+            case $words[2] in
+                completions) compadd bash zsh fish ;;
+                create) compadd -- "${(@f)$(lazyjira complete templates 2>/dev/null)}" ;;
+            esac ;;
+        filters) compadd -- "${(@f)$(lazyjira complete filters 2>/dev/null)}" ;;
+        tickets) compadd -- "${(@f)$(lazyjira complete tickets 2>/dev/null)}" ;;
+    esac
+}
+
+_lazyjira "$@"
+"#;
+
+const FISH: &str = r#"complete -c lazyjira -f
+complete -c lazyjira -n __fish_use_subcommand -a doctor -d 'Check the setup'
+complete -c lazyjira -n __fish_use_subcommand -a keys -d 'Print the keymap'
+complete -c lazyjira -n __fish_use_subcommand -a list -d 'Print tickets matching JQL'
+complete -c lazyjira -n __fish_use_subcommand -a create -d 'Create a ticket from a template'
+complete -c lazyjira -n '__fish_seen_subcommand_from create' -a '(lazyjira complete templates 2>/dev/null)'
+complete -c lazyjira -n __fish_use_subcommand -a release-notes -d 'Print release notes in Markdown'
+complete -c lazyjira -n '__fish_seen_subcommand_from release-notes' -l version -x -d 'Fix version of the notes'
+complete -c lazyjira -n '__fish_seen_subcommand_from release-notes' -l project -x -d 'Project of the notes'
+complete -c lazyjira -n '__fish_seen_subcommand_from release-notes' -l jql -x -d 'Query of the notes'
+complete -c lazyjira -n '__fish_seen_subcommand_from release-notes' -l output -r -F -d 'Write the notes to a file'
+complete -c lazyjira -n '__fish_seen_subcommand_from release-notes' -l clipboard -d 'Copy the notes'
+complete -c lazyjira -n __fish_use_subcommand -a sprint-report -d 'Export the issues resolved in a sprint'
+complete -c lazyjira -n '__fish_seen_subcommand_from sprint-report' -l board -x -d 'Board of the sprint'
+complete -c lazyjira -n '__fish_seen_subcommand_from sprint-report' -l csv -d 'Export as CSV'
+complete -c lazyjira -n '__fish_seen_subcommand_from sprint-report' -l output -r -F -d 'Write the report to a file'
+complete -c lazyjira -n '__fish_seen_subcommand_from sprint-report' -l clipboard -d 'Copy the report'
+complete -c lazyjira -n __fish_use_subcommand -a timesheet -d 'Export my logged hours of a week'
+complete -c lazyjira -n '__fish_seen_subcommand_from timesheet' -l week -x -d 'A date in the week'
+complete -c lazyjira -n '__fish_seen_subcommand_from timesheet' -l output -r -F -d 'Write the timesheet to a file'
+complete -c lazyjira -n '__fish_seen_subcommand_from timesheet' -l clipboard -d 'Copy the timesheet'
+complete -c lazyjira -n __fish_use_subcommand -a completions -d 'Print a completion script'
+complete -c lazyjira -n '__fish_seen_subcommand_from completions' -a 'bash zsh fish'
+complete -c lazyjira -n '__fish_seen_subcommand_from keys' -l markdown -d 'Print the keymap as Markdown'
+complete -c lazyjira -n '__fish_seen_subcommand_from list' -l jql-file -r -F -d 'Read the JQL from a file'
+complete -c lazyjira -l demo -d 'Run against sample data'
+complete -c lazyjira -l color -x -a 'auto always never' -d 'When to use colors'
+complete -c lazyjira -l filter -x -a '(lazyjira complete filters 2>/dev/null)' -d 'Start with a quick filter'
+complete -c lazyjira -l open -x -a '(lazyjira complete tickets 2>/dev/null)' -d 'Start with a ticket open'
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::config::FilterConfig;
+
+    #[test]
+    fn test_scripts_and_candidates() {
+        for shell in ["bash", "zsh", "fish"] {
+            let script = Shell::parse(shell).unwrap().script();
+            for word in ["doctor", "keys", "list", "jql-file", "create", "release-notes", "sprint-report", "timesheet", "completions", "complete filters", "complete tickets", "complete templates"] {
+                assert!(script.contains(word), "{} script lacks {}", shell, word);
+            }
+        }
+        assert_eq!(Shell::parse("powershell"), None);
+
+        let mut config = Config::default();
+        config.filters.push(FilterConfig {
+            name: "Blocked tickets".to_string(),
+            jql: "status = Blocked".to_string(),
+        });
+        let mut recent = RecentTickets::default();
+        recent.record("PROJ-1");
+        recent.record("PROJ-2");
+
+        assert_eq!(
+            candidates("filters", &config, &recent),
+            Some(vec!["Blocked tickets".to_string()])
+        );
+        assert_eq!(
+            candidates("tickets", &config, &recent),
+            Some(vec!["PROJ-2".to_string(), "PROJ-1".to_string()])
+        );
+        assert_eq!(candidates("users", &config, &recent), None);
+    }
+}
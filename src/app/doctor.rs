@@ -0,0 +1,282 @@
+use crate::infrastructure::api::jira_client::{JiraApiClient, ProbeResponse};
+use crate::infrastructure::api::ConnectionValidator;
+use crate::infrastructure::config::credentials::CredentialSource;
+use crate::infrastructure::config::Config;
+use crate::utils::color::{paint, AnsiColor};
+use reqwest::StatusCode;
+
+/// Share of the rate-limit window below which headroom is reported as low
+const LOW_HEADROOM: f64 = 0.1;
+
+/// Checks that need a working Jira client, in the order they run
+const CONNECTION_CHECKS: [&str; 4] = ["network", "credentials", "api version", "rate limit"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+    /// Not run because an earlier check failed
+    Skipped,
+}
+
+/// Result of one `lazyjira doctor` check
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// What to do about a warning or failure
+    pub hint: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, hint: &str) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            hint: Some(hint.to_string()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: &str) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            hint: Some(hint.to_string()),
+        }
+    }
+
+    fn skipped(name: &'static str) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Skipped,
+            detail: "skipped".to_string(),
+            hint: None,
+        }
+    }
+
+    /// "✓ credentials  signed in as Jane Doe", plus an indented hint line;
+    /// `color` paints the symbol
+    pub fn report(&self, color: bool) -> String {
+        let symbol = match self.status {
+            CheckStatus::Pass => paint("✓", AnsiColor::Green, color),
+            CheckStatus::Warn => paint("!", AnsiColor::Yellow, color),
+            CheckStatus::Fail => paint("✗", AnsiColor::Red, color),
+            CheckStatus::Skipped => "-".to_string(),
+        };
+        let mut report = format!("{} {:<12} {}", symbol, self.name, self.detail);
+        if let Some(hint) = &self.hint {
+            report.push_str(&format!("\n  {:<12} → {}", "", hint));
+        }
+        report
+    }
+}
+
+/// Run every check; checks that depend on a failed one are skipped
+pub async fn run_checks() -> Vec<Check> {
+    let mut checks = vec![check_config()];
+
+    // A broken config file is reported above; fall back to jira-cli here
+    let config = Config::load().unwrap_or_default();
+    let source = CredentialSource::from_config(&config.jira);
+    let jira_cli_config = match config.load_credentials() {
+        Ok(Some(config)) => match ConnectionValidator::validate_config(&config) {
+            Ok(()) => {
+                checks.push(Check::pass(
+                    "jira-cli",
+                    format!("instance {}, token from {}", config.instance, source.label()),
+                ));
+                Some(config)
+            }
+            Err(e) => {
+                checks.push(Check::fail(
+                    "jira-cli",
+                    e.to_string(),
+                    "Fix the instance, username and token in your jira-cli config",
+                ));
+                None
+            }
+        },
+        Ok(None) => {
+            checks.push(Check::fail(
+                "jira-cli",
+                "no config found",
+                "Configure jira-cli, or create ~/.config/jira-cli/config.yaml",
+            ));
+            None
+        }
+        Err(e) => {
+            checks.push(Check::fail(
+                "jira-cli",
+                e.to_string(),
+                "Fix your jira-cli config or the jira.credentials settings",
+            ));
+            None
+        }
+    };
+
+    let client = jira_cli_config.and_then(|config| {
+        match JiraApiClient::from_jira_cli_config(&config) {
+            Ok(client) => Some(client),
+            Err(e) => {
+                checks.push(Check::fail(
+                    "jira-cli",
+                    e.to_string(),
+                    "Use auth type api-token or basic with a token",
+                ));
+                None
+            }
+        }
+    });
+    match client {
+        Some(client) => checks.extend(check_connection(&client).await),
+        None => checks.extend(CONNECTION_CHECKS.into_iter().map(Check::skipped)),
+    }
+    checks
+}
+
+/// The lazyjira config file is optional, but must parse and hold valid settings
+fn check_config() -> Check {
+    let path = match Config::config_path() {
+        Ok(path) => path,
+        Err(e) => return Check::warn("config", e.to_string(), "Defaults will be used"),
+    };
+    if !path.exists() {
+        return Check::pass("config", format!("{} not found, using defaults", path.display()));
+    }
+    match Config::load() {
+        Ok(config) => {
+            let problems = config.validate();
+            if problems.is_empty() {
+                Check::pass("config", path.display().to_string())
+            } else {
+                Check::warn(
+                    "config",
+                    problems.join("; "),
+                    "Invalid settings fall back to their defaults",
+                )
+            }
+        }
+        Err(e) => Check::fail("config", e.to_string(), &format!("Fix or remove {}", path.display())),
+    }
+}
+
+/// Network, credential, API version and rate-limit checks against Jira
+pub async fn check_connection(client: &JiraApiClient) -> Vec<Check> {
+    let server_info = match client.probe("serverInfo").await {
+        Ok(response) => response,
+        Err(e) => {
+            let mut checks = vec![Check::fail(
+                "network",
+                e.to_string(),
+                "Check your internet connection, VPN or proxy, and the instance URL",
+            )];
+            checks.extend(CONNECTION_CHECKS[1..].iter().copied().map(Check::skipped));
+            return checks;
+        }
+    };
+    let mut checks = vec![Check::pass(
+        "network",
+        format!("reached Jira in {}ms", server_info.latency.as_millis()),
+    )];
+
+    let myself = match client.probe("myself").await {
+        Ok(response) => response,
+        Err(e) => {
+            checks.push(Check::fail("credentials", e.to_string(), "Try again; the connection dropped"));
+            checks.extend(CONNECTION_CHECKS[2..].iter().copied().map(Check::skipped));
+            return checks;
+        }
+    };
+    checks.push(check_credentials(&myself));
+    checks.push(check_api_version(&server_info));
+    checks.push(check_rate_limit(&myself));
+    checks
+}
+
+fn check_credentials(myself: &ProbeResponse) -> Check {
+    match myself.status {
+        StatusCode::OK => Check::pass(
+            "credentials",
+            format!(
+                "signed in as {}",
+                myself.body["displayName"].as_str().unwrap_or("unknown user")
+            ),
+        ),
+        StatusCode::UNAUTHORIZED => Check::fail(
+            "credentials",
+            "401 Unauthorized",
+            "Create a new API token at https://id.atlassian.com/manage-profile/security/api-tokens",
+        ),
+        StatusCode::FORBIDDEN => Check::fail(
+            "credentials",
+            "403 Forbidden",
+            "Check that the account is active and allowed to use the REST API",
+        ),
+        status => Check::warn(
+            "credentials",
+            format!("unexpected response {}", status),
+            "Run again with RUST_LOG=debug for details",
+        ),
+    }
+}
+
+fn check_api_version(server_info: &ProbeResponse) -> Check {
+    match server_info.body["version"].as_str() {
+        Some(version) if server_info.status.is_success() => Check::pass(
+            "api version",
+            format!(
+                "Jira {} {}, REST API v3",
+                server_info.body["deploymentType"].as_str().unwrap_or("Server"),
+                version
+            ),
+        ),
+        _ if server_info.status == StatusCode::NOT_FOUND => Check::fail(
+            "api version",
+            "REST API v3 not found",
+            "lazyjira needs Jira Cloud or a Jira version with REST API v3",
+        ),
+        _ => Check::warn(
+            "api version",
+            format!("serverInfo answered {}", server_info.status),
+            "The version could not be determined; some features may not work",
+        ),
+    }
+}
+
+fn check_rate_limit(response: &ProbeResponse) -> Check {
+    if response.status == StatusCode::TOO_MANY_REQUESTS {
+        let wait = response
+            .retry_after
+            .map(|seconds| format!(", retry after {}s", seconds))
+            .unwrap_or_default();
+        return Check::fail(
+            "rate limit",
+            format!("rate limited{}", wait),
+            "Close other clients using this account, or raise ui.refresh_interval",
+        );
+    }
+    match response.rate_limit {
+        Some((remaining, limit)) if (remaining as f64) < limit as f64 * LOW_HEADROOM => Check::warn(
+            "rate limit",
+            format!("{} of {} requests left", remaining, limit),
+            "Close other clients using this account, or raise ui.refresh_interval",
+        ),
+        Some((remaining, limit)) => {
+            Check::pass("rate limit", format!("{} of {} requests left", remaining, limit))
+        }
+        None => Check::pass("rate limit", "not limited"),
+    }
+}
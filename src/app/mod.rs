@@ -1,3 +1,6 @@
 pub mod state;
+pub mod cli;
 pub mod commands;
+pub mod completions;
+pub mod doctor;
 pub mod workflows;
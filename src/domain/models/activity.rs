@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+
+/// What happened on an issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActivityKind {
+    Comment { body: String },
+    Transition { from: String, to: String },
+    /// Assigned to someone, or unassigned when None
+    Assignment { to: Option<String> },
+}
+
+/// A comment, transition or assignment on an issue, for the activity stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Activity {
+    pub key: String,
+    pub summary: String,
+    /// Display name of whoever did it; None for automation
+    pub author: Option<String>,
+    pub at: DateTime<Utc>,
+    pub kind: ActivityKind,
+}
+
+impl Activity {
+    /// One line describing what happened, e.g. "moved In Progress → Done"
+    pub fn describe(&self) -> String {
+        match &self.kind {
+            ActivityKind::Comment { body } => {
+                let first_line = body.lines().find(|line| !line.trim().is_empty()).unwrap_or("");
+                format!("commented: {}", first_line.trim())
+            }
+            ActivityKind::Transition { from, to } => format!("moved {} → {}", from, to),
+            ActivityKind::Assignment { to: Some(to) } => format!("assigned to {}", to),
+            ActivityKind::Assignment { to: None } => "unassigned".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_activity() {
+        let activity = |kind| Activity {
+            key: "PROJ-1".to_string(),
+            summary: "Fix login".to_string(),
+            author: Some("Jane Doe".to_string()),
+            at: Utc::now(),
+            kind,
+        };
+        let comment = activity(ActivityKind::Comment { body: "\nLooks good\nShip it".to_string() });
+        assert_eq!(comment.describe(), "commented: Looks good");
+        let transition = activity(ActivityKind::Transition {
+            from: "In Progress".to_string(),
+            to: "Done".to_string(),
+        });
+        assert_eq!(transition.describe(), "moved In Progress → Done");
+        assert_eq!(activity(ActivityKind::Assignment { to: None }).describe(), "unassigned");
+    }
+}
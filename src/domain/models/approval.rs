@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use super::user::User;
+
+/// An approval a Jira Service Management request waits on, e.g. a manager
+/// signing off a purchase
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Approval {
+    pub id: String,
+    pub name: String,
+    pub decision: ApprovalDecision,
+    /// Whether the current user is an approver who can still answer
+    pub can_answer: bool,
+    pub approvers: Vec<Approver>,
+}
+
+/// An approver and their answer
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Approver {
+    pub user: User,
+    pub decision: ApprovalDecision,
+}
+
+/// Outcome of an approval, or of one approver's part in it
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ApprovalDecision {
+    Pending,
+    Approved,
+    Declined,
+}
+
+impl ApprovalDecision {
+    /// Decision from the service desk API's "pending", "approved" or "declined"
+    pub fn from_api(decision: &str) -> Self {
+        match decision {
+            "approved" => ApprovalDecision::Approved,
+            "declined" => ApprovalDecision::Declined,
+            _ => ApprovalDecision::Pending,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ApprovalDecision::Pending => "pending",
+            ApprovalDecision::Approved => "approved",
+            ApprovalDecision::Declined => "declined",
+        }
+    }
+}
+
+impl Approval {
+    pub fn is_pending(&self) -> bool {
+        self.decision == ApprovalDecision::Pending
+    }
+
+    /// Whether the current user can approve or decline it now
+    pub fn is_answerable(&self) -> bool {
+        self.is_pending() && self.can_answer
+    }
+
+    /// Approvers with their answers, e.g. "Jane Doe (approved), Alex Kim"
+    pub fn approvers_summary(&self) -> String {
+        self.approvers
+            .iter()
+            .map(|approver| match approver.decision {
+                ApprovalDecision::Pending => approver.user.display_name.clone(),
+                decision => format!("{} ({})", approver.user.display_name, decision.label()),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approval_summary() {
+        let approver = |name: &str, decision| Approver {
+            user: User::new(name.to_lowercase(), name.to_string()),
+            decision,
+        };
+        let approval = Approval {
+            id: "1".to_string(),
+            name: "Manager sign-off".to_string(),
+            decision: ApprovalDecision::from_api("pending"),
+            can_answer: true,
+            approvers: vec![
+                approver("Jane", ApprovalDecision::Approved),
+                approver("Alex", ApprovalDecision::Pending),
+            ],
+        };
+        assert!(approval.is_answerable());
+        assert_eq!(approval.approvers_summary(), "Jane (approved), Alex");
+        assert_eq!(ApprovalDecision::from_api("declined").label(), "declined");
+    }
+}
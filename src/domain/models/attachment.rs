@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// A file attached to a Jira issue
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Attachment {
+    pub id: String,
+    pub filename: String,
+    pub mime_type: String,
+    /// Size in bytes
+    pub size: u64,
+    /// URL of the attachment content, requiring authentication
+    pub content_url: String,
+}
+
+impl Attachment {
+    pub fn is_image(&self) -> bool {
+        self.mime_type.starts_with("image/")
+    }
+
+    /// Human-readable size, e.g. "12.3 KB"
+    pub fn size_label(&self) -> String {
+        const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+        if self.size < 1024 {
+            return format!("{} B", self.size);
+        }
+        let mut size = self.size as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit + 1 < UNITS.len() {
+            size /= 1024.0;
+            unit += 1;
+        }
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_kind_and_size() {
+        let attachment = Attachment {
+            id: "10001".to_string(),
+            filename: "screenshot.png".to_string(),
+            mime_type: "image/png".to_string(),
+            size: 12_595,
+            content_url: "https://example.atlassian.net/rest/api/3/attachment/content/10001".to_string(),
+        };
+
+        assert!(attachment.is_image());
+        assert_eq!(attachment.size_label(), "12.3 KB");
+        assert_eq!(Attachment { size: 512, ..attachment }.size_label(), "512 B");
+    }
+}
@@ -1,15 +1,85 @@
 use serde::{Deserialize, Serialize};
 
 /// Represents a Jira board
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Board {
     pub id: String,
     pub name: String,
     pub board_type: BoardType,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum BoardType {
     Scrum,
     Kanban,
 }
+
+impl Board {
+    /// Check if the board supports sprints
+    pub fn is_scrum(&self) -> bool {
+        self.board_type == BoardType::Scrum
+    }
+}
+
+/// A column of a board's configuration and the statuses mapped to it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BoardColumnConfig {
+    pub name: String,
+    pub status_ids: Vec<String>,
+    /// WIP limits, set when the board constrains columns by issue count
+    #[serde(default)]
+    pub min: Option<u32>,
+    #[serde(default)]
+    pub max: Option<u32>,
+}
+
+/// How a column's issue count compares to its WIP limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WipStatus {
+    Within,
+    /// Fewer issues than the column minimum
+    BelowMin,
+    /// More issues than the column maximum
+    AboveMax,
+}
+
+impl BoardColumnConfig {
+    pub fn has_limits(&self) -> bool {
+        self.min.is_some() || self.max.is_some()
+    }
+
+    /// Compare `count` issues in the column against its limits
+    pub fn wip_status(&self, count: usize) -> WipStatus {
+        match (self.min, self.max) {
+            (_, Some(max)) if count > max as usize => WipStatus::AboveMax,
+            (Some(min), _) if count < min as usize => WipStatus::BelowMin,
+            _ => WipStatus::Within,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wip_status() {
+        let column = BoardColumnConfig {
+            name: "In Progress".to_string(),
+            status_ids: vec!["3".to_string()],
+            min: Some(1),
+            max: Some(3),
+        };
+        assert_eq!(column.wip_status(0), WipStatus::BelowMin);
+        assert_eq!(column.wip_status(3), WipStatus::Within);
+        assert_eq!(column.wip_status(4), WipStatus::AboveMax);
+
+        let unlimited = BoardColumnConfig {
+            min: None,
+            max: None,
+            ..column
+        };
+        assert!(!unlimited.has_limits());
+        assert_eq!(unlimited.wip_status(99), WipStatus::Within);
+    }
+}
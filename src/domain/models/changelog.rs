@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::user::User;
+
+/// A status transition recorded in an issue's changelog
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StatusChange {
+    pub at: DateTime<Utc>,
+    pub from: String,
+    pub to: String,
+}
+
+/// A change to one field of an issue, from its changelog
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldChange {
+    pub at: DateTime<Utc>,
+    /// Who made the change; None for automation without a user
+    pub author: Option<User>,
+    /// Field id, e.g. "status" or "assignee"
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
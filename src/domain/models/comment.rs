@@ -10,6 +10,98 @@ pub struct Comment {
     pub body: String,
     pub created: DateTime<Utc>,
     pub updated: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub visibility: CommentVisibility,
+    /// Mentions as they appear in the body, e.g. "@Jane Doe"
+    #[serde(default)]
+    pub mentions: Vec<String>,
+}
+
+/// Who can see a comment
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CommentVisibility {
+    #[default]
+    Public,
+    /// Jira Service Management comment hidden from customers
+    Internal,
+    /// Restricted to a project role
+    Role(String),
+    /// Restricted to a group
+    Group(String),
+}
+
+impl CommentVisibility {
+    /// Short label, e.g. for a badge or the composer selector
+    pub fn label(&self) -> String {
+        match self {
+            CommentVisibility::Public => "public".to_string(),
+            CommentVisibility::Internal => "internal".to_string(),
+            CommentVisibility::Role(role) => format!("role: {}", role),
+            CommentVisibility::Group(group) => format!("group: {}", group),
+        }
+    }
+
+    /// Label on a service desk request, where public comments are shared
+    /// with the customer
+    pub fn service_desk_label(&self) -> String {
+        match self {
+            CommentVisibility::Public => "customer-visible".to_string(),
+            _ => self.label(),
+        }
+    }
+
+    pub fn is_public(&self) -> bool {
+        *self == CommentVisibility::Public
+    }
+}
+
+/// Order an issue's comments are listed in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CommentOrder {
+    #[default]
+    OldestFirst,
+    NewestFirst,
+}
+
+impl CommentOrder {
+    /// From the `comment_order` setting; anything but "newest" is oldest first
+    pub fn from_config(value: &str) -> Self {
+        if value.eq_ignore_ascii_case("newest") {
+            CommentOrder::NewestFirst
+        } else {
+            CommentOrder::OldestFirst
+        }
+    }
+
+    /// Value of the `comment_order` setting
+    pub fn config_value(self) -> &'static str {
+        match self {
+            CommentOrder::OldestFirst => "oldest",
+            CommentOrder::NewestFirst => "newest",
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            CommentOrder::OldestFirst => CommentOrder::NewestFirst,
+            CommentOrder::NewestFirst => CommentOrder::OldestFirst,
+        }
+    }
+
+    /// Value of the `orderBy` parameter of Jira's comment listing
+    pub fn order_by(self) -> &'static str {
+        match self {
+            CommentOrder::OldestFirst => "created",
+            CommentOrder::NewestFirst => "-created",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CommentOrder::OldestFirst => "oldest first",
+            CommentOrder::NewestFirst => "newest first",
+        }
+    }
 }
 
 impl Comment {
@@ -21,6 +113,8 @@ impl Comment {
             body,
             created,
             updated: None,
+            visibility: CommentVisibility::Public,
+            mentions: Vec::new(),
         }
     }
 }
@@ -42,5 +136,26 @@ mod tests {
         assert_eq!(comment.id, "10000");
         assert_eq!(comment.body, "This is a comment");
         assert_eq!(comment.author.display_name, "John Doe");
+        assert!(comment.visibility.is_public());
+    }
+
+    #[test]
+    fn test_comment_visibility_label() {
+        assert_eq!(CommentVisibility::Internal.label(), "internal");
+        assert_eq!(
+            CommentVisibility::Role("Developers".to_string()).label(),
+            "role: Developers"
+        );
+        assert_eq!(CommentVisibility::Public.service_desk_label(), "customer-visible");
+        assert_eq!(CommentVisibility::Internal.service_desk_label(), "internal");
+    }
+
+    #[test]
+    fn test_comment_order() {
+        let order = CommentOrder::from_config("Newest");
+        assert_eq!(order, CommentOrder::NewestFirst);
+        assert_eq!(order.order_by(), "-created");
+        assert_eq!(order.toggled().config_value(), "oldest");
+        assert_eq!(CommentOrder::from_config("sideways"), CommentOrder::OldestFirst);
     }
 }
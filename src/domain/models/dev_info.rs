@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+/// Branches, commits and pull requests linked to an issue by a connected
+/// GitHub, GitLab or Bitbucket
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DevInfo {
+    pub branches: Vec<Branch>,
+    pub commits: Vec<Commit>,
+    pub pull_requests: Vec<PullRequest>,
+}
+
+impl DevInfo {
+    pub fn is_empty(&self) -> bool {
+        self.branches.is_empty() && self.commits.is_empty() && self.pull_requests.is_empty()
+    }
+
+    /// Counts for a heading, e.g. "1 PR, 2 branches, 5 commits"
+    pub fn summary(&self) -> String {
+        let count = |n: usize, one: &str, many: &str| format!("{} {}", n, if n == 1 { one } else { many });
+        [
+            count(self.pull_requests.len(), "PR", "PRs"),
+            count(self.branches.len(), "branch", "branches"),
+            count(self.commits.len(), "commit", "commits"),
+        ]
+        .join(", ")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Branch {
+    pub name: String,
+    pub repository: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Commit {
+    /// Abbreviated hash
+    pub id: String,
+    /// First line of the commit message
+    pub message: String,
+    pub author: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PullRequest {
+    /// Id as the host shows it, e.g. "#42"
+    pub id: String,
+    pub title: String,
+    pub status: PullRequestStatus,
+    pub repository: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PullRequestStatus {
+    Open,
+    Merged,
+    Declined,
+    Draft,
+}
+
+impl PullRequestStatus {
+    /// Status from the dev-status API, e.g. "OPEN"; unknown ones count as open
+    pub fn from_api(status: &str) -> Self {
+        match status.to_uppercase().as_str() {
+            "MERGED" => PullRequestStatus::Merged,
+            "DECLINED" | "CLOSED" => PullRequestStatus::Declined,
+            "DRAFT" => PullRequestStatus::Draft,
+            _ => PullRequestStatus::Open,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PullRequestStatus::Open => "open",
+            PullRequestStatus::Merged => "merged",
+            PullRequestStatus::Declined => "declined",
+            PullRequestStatus::Draft => "draft",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dev_info_summary() {
+        let mut info = DevInfo::default();
+        assert!(info.is_empty());
+        info.branches.push(Branch {
+            name: "feature/PROJ-1".to_string(),
+            repository: "api".to_string(),
+            url: "https://github.com/acme/api/tree/feature/PROJ-1".to_string(),
+        });
+        assert!(!info.is_empty());
+        assert_eq!(info.summary(), "0 PRs, 1 branch, 0 commits");
+
+        assert_eq!(PullRequestStatus::from_api("MERGED"), PullRequestStatus::Merged);
+        assert_eq!(PullRequestStatus::from_api("closed"), PullRequestStatus::Declined);
+        assert_eq!(PullRequestStatus::from_api("UNKNOWN").label(), "open");
+    }
+}
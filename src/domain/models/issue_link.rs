@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use super::ticket::{IssueRef, Status};
+
+/// Name of the link type Jira uses for blockers
+const BLOCKS_LINK_TYPE: &str = "Blocks";
+
+/// A link from an issue to another, e.g. "blocks PROJ-7"
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IssueLink {
+    pub id: String,
+    /// Name of the link type, e.g. "Blocks" or "Relates"
+    pub link_type: String,
+    /// How the link reads from this issue, e.g. "is blocked by"
+    pub description: String,
+    /// Whether this issue is the link's source ("blocks") rather than its
+    /// target ("is blocked by")
+    pub outward: bool,
+    pub issue: IssueRef,
+    pub status: Status,
+}
+
+impl IssueLink {
+    fn is_blocker_link(&self) -> bool {
+        self.link_type.eq_ignore_ascii_case(BLOCKS_LINK_TYPE)
+    }
+
+    /// Whether this issue blocks the linked one
+    pub fn blocks(&self) -> bool {
+        self.is_blocker_link() && self.outward
+    }
+
+    /// Whether the linked issue blocks this one
+    pub fn is_blocked_by(&self) -> bool {
+        self.is_blocker_link() && !self.outward
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// An entity property stored on an issue, e.g. by an automation rule or an
+/// app
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IssueProperty {
+    pub key: String,
+    pub value: Value,
+}
+
+impl IssueProperty {
+    /// The value as indented JSON, one line per entry
+    pub fn pretty_lines(&self) -> Vec<String> {
+        serde_json::to_string_pretty(&self.value)
+            .unwrap_or_else(|_| self.value.to_string())
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+}
@@ -3,6 +3,21 @@ pub mod user;
 pub mod comment;
 pub mod board;
 pub mod sprint;
+pub mod changelog;
+pub mod project;
+pub mod attachment;
+pub mod remote_link;
+pub mod issue_link;
+pub mod dev_info;
+pub mod service_request;
+pub mod sla;
+pub mod approval;
+pub mod smart_view;
+pub mod worklog;
+pub mod pomodoro;
+pub mod activity;
+pub mod permission;
+pub mod issue_property;
 
 // Re-exports for convenience (will be used when UI is implemented)
 #[allow(unused_imports)]
@@ -11,3 +26,7 @@ pub use ticket::Ticket;
 pub use user::User;
 #[allow(unused_imports)]
 pub use comment::Comment;
+#[allow(unused_imports)]
+pub use board::Board;
+#[allow(unused_imports)]
+pub use sprint::Sprint;
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+/// Project permission an action of the app needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+    AssignIssues,
+    TransitionIssues,
+    AddComments,
+    EditIssues,
+    ModifyReporter,
+    MoveIssues,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 6] = [
+        Permission::AssignIssues,
+        Permission::TransitionIssues,
+        Permission::AddComments,
+        Permission::EditIssues,
+        Permission::ModifyReporter,
+        Permission::MoveIssues,
+    ];
+
+    /// Key of the permission in Jira's API
+    pub fn key(self) -> &'static str {
+        match self {
+            Permission::AssignIssues => "ASSIGN_ISSUES",
+            Permission::TransitionIssues => "TRANSITION_ISSUES",
+            Permission::AddComments => "ADD_COMMENTS",
+            Permission::EditIssues => "EDIT_ISSUES",
+            Permission::ModifyReporter => "MODIFY_REPORTER",
+            Permission::MoveIssues => "MOVE_ISSUES",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|permission| permission.key() == key)
+    }
+
+    /// What it allows, e.g. "assign issues"
+    pub fn label(self) -> &'static str {
+        match self {
+            Permission::AssignIssues => "assign issues",
+            Permission::TransitionIssues => "transition issues",
+            Permission::AddComments => "add comments",
+            Permission::EditIssues => "edit issues",
+            Permission::ModifyReporter => "change reporters",
+            Permission::MoveIssues => "move issues",
+        }
+    }
+}
+
+/// Permissions the signed-in user has in a project
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectPermissions {
+    granted: HashSet<Permission>,
+}
+
+impl ProjectPermissions {
+    pub fn new(granted: impl IntoIterator<Item = Permission>) -> Self {
+        Self {
+            granted: granted.into_iter().collect(),
+        }
+    }
+
+    /// Every permission, for clients without permission checks
+    pub fn all() -> Self {
+        Self::new(Permission::ALL)
+    }
+
+    pub fn allows(&self, permission: Permission) -> bool {
+        self.granted.contains(&permission)
+    }
+
+    /// Permissions the user lacks
+    pub fn denied(&self) -> impl Iterator<Item = Permission> + '_ {
+        Permission::ALL.into_iter().filter(|permission| !self.allows(*permission))
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+
+/// Part of a pomodoro cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PomodoroPhase {
+    Work,
+    Break,
+}
+
+impl PomodoroPhase {
+    pub fn label(self) -> &'static str {
+        match self {
+            PomodoroPhase::Work => "work",
+            PomodoroPhase::Break => "break",
+        }
+    }
+}
+
+/// Alternating work and break intervals on an issue; the work time adds up
+/// to a worklog offered when the pomodoro stops
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pomodoro {
+    pub key: String,
+    pub work_seconds: u64,
+    pub break_seconds: u64,
+    pub started: DateTime<Utc>,
+    pub phase: PomodoroPhase,
+    pub phase_started: DateTime<Utc>,
+    /// Work intervals finished so far
+    pub completed: u32,
+}
+
+impl Pomodoro {
+    /// Start with a work interval
+    pub fn start(key: String, work_minutes: u64, break_minutes: u64, now: DateTime<Utc>) -> Self {
+        Self {
+            key,
+            work_seconds: work_minutes.max(1) * 60,
+            break_seconds: break_minutes.max(1) * 60,
+            started: now,
+            phase: PomodoroPhase::Work,
+            phase_started: now,
+            completed: 0,
+        }
+    }
+
+    fn phase_seconds(&self) -> u64 {
+        match self.phase {
+            PomodoroPhase::Work => self.work_seconds,
+            PomodoroPhase::Break => self.break_seconds,
+        }
+    }
+
+    fn phase_elapsed(&self, now: DateTime<Utc>) -> u64 {
+        (now - self.phase_started).num_seconds().max(0) as u64
+    }
+
+    /// Seconds left in the current interval
+    pub fn remaining(&self, now: DateTime<Utc>) -> u64 {
+        self.phase_seconds().saturating_sub(self.phase_elapsed(now))
+    }
+
+    /// Move on to the next interval once the current one is over, returning
+    /// the phase that began
+    pub fn advance(&mut self, now: DateTime<Utc>) -> Option<PomodoroPhase> {
+        if self.remaining(now) > 0 {
+            return None;
+        }
+        self.phase_started += chrono::Duration::seconds(self.phase_seconds() as i64);
+        self.phase = match self.phase {
+            PomodoroPhase::Work => {
+                self.completed += 1;
+                PomodoroPhase::Break
+            }
+            PomodoroPhase::Break => PomodoroPhase::Work,
+        };
+        Some(self.phase)
+    }
+
+    /// Work time of the finished intervals and of the one running, without
+    /// the breaks
+    pub fn worked_seconds(&self, now: DateTime<Utc>) -> u64 {
+        let running = match self.phase {
+            PomodoroPhase::Work => self.phase_elapsed(now).min(self.work_seconds),
+            PomodoroPhase::Break => 0,
+        };
+        self.completed as u64 * self.work_seconds + running
+    }
+
+    /// Phase and time left as M:SS, for the status bar
+    pub fn clock(&self, now: DateTime<Utc>) -> String {
+        let remaining = self.remaining(now);
+        format!("{} {}:{:02}", self.phase.label(), remaining / 60, remaining % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_pomodoro_cycle() {
+        let start = Utc::now();
+        let at = |minutes: i64| start + Duration::minutes(minutes);
+        let mut pomodoro = Pomodoro::start("PROJ-1".to_string(), 25, 5, start);
+        assert_eq!(pomodoro.clock(at(10)), "work 15:00");
+        assert_eq!(pomodoro.advance(at(24)), None);
+
+        assert_eq!(pomodoro.advance(at(26)), Some(PomodoroPhase::Break));
+        assert_eq!(pomodoro.clock(at(26)), "break 4:00");
+        assert_eq!(pomodoro.worked_seconds(at(28)), 25 * 60);
+
+        assert_eq!(pomodoro.advance(at(30)), Some(PomodoroPhase::Work));
+        assert_eq!(pomodoro.completed, 1);
+        assert_eq!(pomodoro.worked_seconds(at(40)), 35 * 60);
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Represents a Jira project with the issue types it supports
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Project {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    pub issue_types: Vec<IssueType>,
+}
+
+/// An issue type available in a project
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IssueType {
+    pub id: String,
+    pub name: String,
+    pub subtask: bool,
+}
+
+/// A version (release) of a project
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Version {
+    pub id: String,
+    pub name: String,
+    pub start_date: Option<NaiveDate>,
+    pub release_date: Option<NaiveDate>,
+    pub released: bool,
+}
+
+impl Project {
+    /// Issue types a standard (non-subtask) issue can be moved to
+    pub fn standard_issue_types(&self) -> Vec<&IssueType> {
+        self.issue_types.iter().filter(|t| !t.subtask).collect()
+    }
+
+    /// Best target for an issue of the given type: the same name, else the first standard type
+    pub fn matching_issue_type(&self, name: &str) -> Option<&IssueType> {
+        let types = self.standard_issue_types();
+        types
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+            .or_else(|| types.first())
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_type(id: &str, name: &str, subtask: bool) -> IssueType {
+        IssueType {
+            id: id.to_string(),
+            name: name.to_string(),
+            subtask,
+        }
+    }
+
+    #[test]
+    fn test_matching_issue_type() {
+        let project = Project {
+            id: "10001".to_string(),
+            key: "NEW".to_string(),
+            name: "New project".to_string(),
+            issue_types: vec![
+                issue_type("1", "Sub-task", true),
+                issue_type("2", "Task", false),
+                issue_type("3", "Bug", false),
+            ],
+        };
+
+        assert_eq!(project.matching_issue_type("bug").map(|t| t.id.as_str()), Some("3"));
+        assert_eq!(project.matching_issue_type("Story").map(|t| t.id.as_str()), Some("2"));
+        assert_eq!(project.standard_issue_types().len(), 2);
+    }
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+/// Application type Jira gives links to Confluence pages
+const CONFLUENCE_APPLICATION: &str = "com.atlassian.confluence";
+
+/// A web link attached to a Jira issue, e.g. a design doc or a dashboard
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RemoteLink {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    /// Name of the application the link points into, when Jira knows it
+    pub application: Option<String>,
+    /// Type of that application, e.g. "com.atlassian.confluence"
+    pub application_type: Option<String>,
+}
+
+impl RemoteLink {
+    /// Whether the link points to a Confluence page, by its application or
+    /// failing that its URL
+    pub fn is_confluence(&self) -> bool {
+        self.application_type.as_deref() == Some(CONFLUENCE_APPLICATION)
+            || self.url.contains("/wiki/")
+    }
+
+    /// Name of the application to show next to the title
+    pub fn application_label(&self) -> Option<&str> {
+        match self.application.as_deref() {
+            Some(name) => Some(name),
+            None if self.is_confluence() => Some("Confluence"),
+            None => None,
+        }
+    }
+}
+
+/// Id of the Confluence page a URL points to, from its
+/// `/pages/{id}` path or `pageId` parameter; short `/x/` links have none
+pub fn confluence_page_id(url: &str) -> Option<&str> {
+    let digits = |text: &'_ str| -> Option<usize> {
+        let len = text.chars().take_while(char::is_ascii_digit).count();
+        (len > 0).then_some(len)
+    };
+    if let Some(start) = url.find("pageId=") {
+        let id = &url[start + "pageId=".len()..];
+        return digits(id).map(|len| &id[..len]);
+    }
+    let (_, after) = url.split_once("/pages/")?;
+    let id = after.strip_prefix("edit-v2/").unwrap_or(after);
+    let len = digits(id)?;
+    // The id is a whole path segment, e.g. not "/pages/viewpage.action"
+    id[len..]
+        .chars()
+        .next()
+        .is_none_or(|c| matches!(c, '/' | '?' | '#'))
+        .then_some(&id[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confluence_links() {
+        let link = RemoteLink {
+            id: "1".to_string(),
+            title: "Wiki Page".to_string(),
+            url: "https://example.atlassian.net/wiki/pages/viewpage.action?pageId=98765".to_string(),
+            application: None,
+            application_type: Some(CONFLUENCE_APPLICATION.to_string()),
+        };
+        assert!(link.is_confluence());
+        assert_eq!(link.application_label(), Some("Confluence"));
+        assert_eq!(confluence_page_id(&link.url), Some("98765"));
+
+        assert_eq!(
+            confluence_page_id("https://example.atlassian.net/wiki/spaces/ENG/pages/12345/Design+Doc"),
+            Some("12345")
+        );
+        assert_eq!(
+            confluence_page_id("https://example.atlassian.net/wiki/spaces/ENG/pages/edit-v2/12345"),
+            Some("12345")
+        );
+        assert_eq!(confluence_page_id("https://example.atlassian.net/wiki/x/AbC"), None);
+        assert_eq!(confluence_page_id("https://example.com/pages/12345abc"), None);
+
+        let dashboard = RemoteLink {
+            url: "https://grafana.example.com/d/api".to_string(),
+            application_type: None,
+            ..link
+        };
+        assert!(!dashboard.is_confluence());
+        assert_eq!(dashboard.application_label(), None);
+    }
+}
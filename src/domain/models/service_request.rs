@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use super::user::User;
+
+/// The Jira Service Management side of an issue raised as a customer
+/// request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ServiceRequest {
+    /// Request type the customer picked, e.g. "Get IT help"
+    pub request_type: String,
+    /// The request's page on the customer portal
+    pub portal_url: Option<String>,
+    /// Customers the request is shared with besides the reporter
+    #[serde(default)]
+    pub participants: Vec<User>,
+    /// Names of the customer organizations the request is shared with
+    #[serde(default)]
+    pub organizations: Vec<String>,
+}
+
+impl ServiceRequest {
+    /// Who the request is shared with, e.g. "Jane Doe, Acme Corp"
+    pub fn shared_with(&self) -> String {
+        self.participants
+            .iter()
+            .map(|user| user.display_name.as_str())
+            .chain(self.organizations.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
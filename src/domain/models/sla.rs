@@ -0,0 +1,90 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// A service level agreement tracked on a Jira Service Management request,
+/// e.g. "Time to first response"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sla {
+    pub name: String,
+    /// Time the agreement allows
+    pub goal: Option<Duration>,
+    /// Time left when the SLA was fetched, negative once breached
+    pub remaining: Option<Duration>,
+    /// When the running cycle breaches; None when paused or completed
+    pub breach_time: Option<DateTime<Utc>>,
+    pub paused: bool,
+    /// Whether the cycle is over, e.g. the first response was given
+    pub completed: bool,
+    pub breached: bool,
+}
+
+/// How close an SLA is to breaching, for picking its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlaUrgency {
+    Met,
+    Paused,
+    OnTrack,
+    /// Less than a quarter of the goal left
+    AtRisk,
+    Breached,
+}
+
+impl Sla {
+    /// Time left at `now`, counting down while the cycle runs
+    pub fn remaining_at(&self, now: DateTime<Utc>) -> Option<Duration> {
+        match self.breach_time {
+            Some(breach_time) if !self.paused && !self.completed => Some(breach_time - now),
+            _ => self.remaining,
+        }
+    }
+
+    pub fn urgency(&self, now: DateTime<Utc>) -> SlaUrgency {
+        let remaining = self.remaining_at(now);
+        if self.breached || remaining.is_some_and(|left| left < Duration::zero()) {
+            SlaUrgency::Breached
+        } else if self.completed {
+            SlaUrgency::Met
+        } else if self.paused {
+            SlaUrgency::Paused
+        } else if let (Some(left), Some(goal)) = (remaining, self.goal) {
+            if left * 4 < goal {
+                SlaUrgency::AtRisk
+            } else {
+                SlaUrgency::OnTrack
+            }
+        } else {
+            SlaUrgency::OnTrack
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sla_counts_down_to_breach() {
+        let now = Utc::now();
+        let mut sla = Sla {
+            name: "Time to first response".to_string(),
+            goal: Some(Duration::hours(4)),
+            remaining: Some(Duration::hours(3)),
+            breach_time: Some(now + Duration::hours(2)),
+            paused: false,
+            completed: false,
+            breached: false,
+        };
+        assert_eq!(sla.remaining_at(now), Some(Duration::hours(2)));
+        assert_eq!(sla.urgency(now), SlaUrgency::OnTrack);
+        assert_eq!(sla.urgency(now + Duration::minutes(90)), SlaUrgency::AtRisk);
+        assert_eq!(sla.urgency(now + Duration::hours(3)), SlaUrgency::Breached);
+
+        sla.paused = true;
+        assert_eq!(sla.remaining_at(now + Duration::hours(3)), Some(Duration::hours(3)));
+        assert_eq!(sla.urgency(now), SlaUrgency::Paused);
+
+        sla.completed = true;
+        assert_eq!(sla.urgency(now), SlaUrgency::Met);
+        sla.breached = true;
+        assert_eq!(sla.urgency(now), SlaUrgency::Breached);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::domain::models::user::User;
+
+/// Predefined views reachable from the view switcher, each a JQL template
+/// filled in with the signed-in user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmartView {
+    MyOpenWork,
+    ReportedByMe,
+    RecentlyResolved,
+    MentionsMe,
+}
+
+impl SmartView {
+    pub const ALL: [SmartView; 4] = [
+        SmartView::MyOpenWork,
+        SmartView::ReportedByMe,
+        SmartView::RecentlyResolved,
+        SmartView::MentionsMe,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SmartView::MyOpenWork => "My open work",
+            SmartView::ReportedByMe => "Reported by me",
+            SmartView::RecentlyResolved => "Recently resolved",
+            SmartView::MentionsMe => "Mentions me",
+        }
+    }
+
+    /// The view's JQL for `user`
+    pub fn jql(&self, user: &User) -> String {
+        let account_id = user.account_id.replace('"', "");
+        match self {
+            SmartView::MyOpenWork => format!(
+                "assignee = \"{}\" AND resolution IS EMPTY ORDER BY priority DESC, updated DESC",
+                account_id
+            ),
+            SmartView::ReportedByMe => {
+                format!("reporter = \"{}\" ORDER BY created DESC", account_id)
+            }
+            SmartView::RecentlyResolved => format!(
+                "assignee = \"{}\" AND resolved >= -14d ORDER BY resolved DESC",
+                account_id
+            ),
+            // Mentions are stored as [~accountid:...] in comment bodies
+            SmartView::MentionsMe => format!(
+                "comment ~ \"accountid:{}\" ORDER BY updated DESC",
+                account_id
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jql_uses_account_id() {
+        let user = User::new("5b10ac8d".to_string(), "Jane Doe".to_string());
+        assert_eq!(
+            SmartView::ReportedByMe.jql(&user),
+            "reporter = \"5b10ac8d\" ORDER BY created DESC"
+        );
+        assert!(SmartView::MentionsMe.jql(&user).contains("accountid:5b10ac8d"));
+    }
+}
@@ -2,18 +2,66 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Represents a Jira sprint
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Sprint {
     pub id: String,
     pub name: String,
     pub state: SprintState,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
+    /// When a closed sprint was completed
+    pub complete_date: Option<DateTime<Utc>>,
+    pub goal: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum SprintState {
+    #[serde(rename = "future")]
     Future,
+    #[serde(rename = "active")]
     Active,
+    #[serde(rename = "closed")]
     Closed,
 }
+
+impl SprintState {
+    /// Value used by the Agile API
+    pub fn as_api_str(&self) -> &'static str {
+        match self {
+            SprintState::Future => "future",
+            SprintState::Active => "active",
+            SprintState::Closed => "closed",
+        }
+    }
+}
+
+impl Sprint {
+    pub fn is_active(&self) -> bool {
+        self.state == SprintState::Active
+    }
+
+    pub fn is_future(&self) -> bool {
+        self.state == SprintState::Future
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sprint_state_checks() {
+        let sprint = Sprint {
+            id: "1".to_string(),
+            name: "Sprint 1".to_string(),
+            state: SprintState::Active,
+            start_date: None,
+            end_date: None,
+            complete_date: None,
+            goal: None,
+        };
+        assert!(sprint.is_active());
+        assert!(!sprint.is_future());
+        assert_eq!(sprint.state.as_api_str(), "active");
+    }
+}
@@ -1,23 +1,58 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use super::attachment::Attachment;
+use super::issue_link::IssueLink;
+use super::service_request::ServiceRequest;
 use super::user::User;
 
 /// Represents a Jira ticket/issue
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Ticket {
     pub id: String,
     pub key: String,
     pub summary: String,
     pub status: Status,
     pub assignee: Option<User>,
+    #[serde(default)]
+    pub reporter: Option<User>,
     pub priority: Priority,
     pub issue_type: String,
     pub project_key: String,
     pub description: Option<String>,
+    pub parent: Option<IssueRef>,
+    pub story_points: Option<f64>,
+    /// When the issue was resolved, if it has been
+    pub resolved: Option<DateTime<Utc>>,
+    /// When work on the issue is planned to start, e.g. for an epic
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+    pub due_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Request details when the issue was raised through a service desk
+    #[serde(default)]
+    pub service_request: Option<ServiceRequest>,
+    /// Links to other issues, e.g. blockers
+    #[serde(default)]
+    pub links: Vec<IssueLink>,
+    /// Values of the custom fields set on the issue, as text by field id
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
     pub created: DateTime<Utc>,
     pub updated: DateTime<Utc>,
 }
 
+/// Lightweight reference to another issue (e.g. a parent or epic)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IssueRef {
+    pub key: String,
+    pub summary: String,
+    pub issue_type: String,
+}
+
 /// Ticket status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Status {
@@ -63,32 +98,66 @@ impl Ticket {
             summary,
             status,
             assignee: None,
+            reporter: None,
             priority: Priority::Medium,
             issue_type: "Task".to_string(),
             project_key,
             description: None,
+            parent: None,
+            story_points: None,
+            resolved: None,
+            start_date: None,
+            due_date: None,
+            attachments: Vec::new(),
+            labels: Vec::new(),
+            service_request: None,
+            links: Vec::new(),
+            custom_fields: BTreeMap::new(),
             created: Utc::now(),
             updated: Utc::now(),
         }
     }
 
+    /// Value of a custom field, e.g. `customfield_10201`, when it is set
+    pub fn custom_field(&self, id: &str) -> Option<&str> {
+        self.custom_fields.get(id).map(String::as_str)
+    }
+
     /// Check if ticket is in "Done" status category
-    #[allow(dead_code)] // Will be used for filtering
     pub fn is_done(&self) -> bool {
         matches!(self.status.category, StatusCategory::Done)
     }
 
     /// Check if ticket is in "In Progress" status category
-    #[allow(dead_code)] // Will be used for filtering
     pub fn is_in_progress(&self) -> bool {
         matches!(self.status.category, StatusCategory::InProgress)
     }
 
     /// Check if ticket is in "To Do" status category
-    #[allow(dead_code)] // Will be used for filtering
     pub fn is_todo(&self) -> bool {
         matches!(self.status.category, StatusCategory::ToDo)
     }
+
+    /// Get the epic this ticket belongs to, if its parent is an epic
+    pub fn epic(&self) -> Option<&IssueRef> {
+        self.parent
+            .as_ref()
+            .filter(|parent| parent.issue_type.eq_ignore_ascii_case("epic"))
+    }
+
+    /// Check if status, assignee or summary differ from an earlier snapshot of the same ticket
+    pub fn has_tracked_changes(&self, previous: &Ticket) -> bool {
+        self.status.id != previous.status.id
+            || self.status.name != previous.status.name
+            || self.summary != previous.summary
+            || self.assignee.as_ref().map(|u| &u.account_id)
+                != previous.assignee.as_ref().map(|u| &u.account_id)
+    }
+
+    /// Check if the ticket is past its due date and not done
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        !self.is_done() && self.due_date.map(|due| due < today).unwrap_or(false)
+    }
 }
 
 #[cfg(test)]
@@ -156,4 +225,59 @@ mod tests {
         assert!(!done_ticket.is_in_progress());
         assert!(done_ticket.is_done());
     }
+
+    #[test]
+    fn test_is_overdue() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let mut ticket = Ticket::new(
+            "PROJ-1".to_string(),
+            "Late".to_string(),
+            Status {
+                id: "1".to_string(),
+                name: "To Do".to_string(),
+                category: StatusCategory::ToDo,
+            },
+        );
+        assert!(!ticket.is_overdue(today));
+
+        ticket.due_date = NaiveDate::from_ymd_opt(2024, 3, 9);
+        assert!(ticket.is_overdue(today));
+
+        ticket.status.category = StatusCategory::Done;
+        assert!(!ticket.is_overdue(today));
+    }
+
+    #[test]
+    fn test_has_tracked_changes() {
+        let original = Ticket::new(
+            "PROJ-1".to_string(),
+            "Original".to_string(),
+            Status {
+                id: "1".to_string(),
+                name: "To Do".to_string(),
+                category: StatusCategory::ToDo,
+            },
+        );
+
+        let mut unchanged = original.clone();
+        unchanged.updated = Utc::now();
+        unchanged.description = Some("Edited description".to_string());
+        assert!(!unchanged.has_tracked_changes(&original));
+
+        let mut renamed = original.clone();
+        renamed.summary = "Renamed".to_string();
+        assert!(renamed.has_tracked_changes(&original));
+
+        let mut reassigned = original.clone();
+        reassigned.assignee = Some(User::new("123".to_string(), "Jane".to_string()));
+        assert!(reassigned.has_tracked_changes(&original));
+
+        let mut moved = original.clone();
+        moved.status = Status {
+            id: "3".to_string(),
+            name: "In Progress".to_string(),
+            category: StatusCategory::InProgress,
+        };
+        assert!(moved.has_tracked_changes(&original));
+    }
 }
@@ -17,6 +17,17 @@ impl User {
             email_address: None,
         }
     }
+
+    /// Up to two initials from the display name, e.g. "JD" for "John Doe"
+    pub fn initials(&self) -> String {
+        let mut words = self.display_name.split_whitespace().filter_map(|w| w.chars().next());
+        let initials: String = match (words.next(), words.next_back()) {
+            (Some(first), Some(last)) => [first, last].iter().collect(),
+            (Some(first), None) => first.to_string(),
+            _ => "?".to_string(),
+        };
+        initials.to_uppercase()
+    }
 }
 
 #[cfg(test)]
@@ -34,4 +45,13 @@ mod tests {
         assert_eq!(user.display_name, "John Doe");
         assert_eq!(user.email_address, None);
     }
+
+    #[test]
+    fn test_user_initials() {
+        let user = |name: &str| User::new("1".to_string(), name.to_string());
+        assert_eq!(user("John Doe").initials(), "JD");
+        assert_eq!(user("ada  de la lovelace").initials(), "AL");
+        assert_eq!(user("Cher").initials(), "C");
+        assert_eq!(user(" ").initials(), "?");
+    }
 }
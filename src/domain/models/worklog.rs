@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use super::user::User;
+
+/// Time logged against an issue
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Worklog {
+    pub id: String,
+    pub author: User,
+    /// When the logged work started
+    pub started: DateTime<Utc>,
+    pub time_spent_seconds: u64,
+}
+
+/// A timer running on an issue, whose time is offered as a worklog when it
+/// stops
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkTimer {
+    pub key: String,
+    pub started: DateTime<Utc>,
+}
+
+impl WorkTimer {
+    pub fn start(key: String, now: DateTime<Utc>) -> Self {
+        Self { key, started: now }
+    }
+
+    pub fn elapsed_seconds(&self, now: DateTime<Utc>) -> u64 {
+        (now - self.started).num_seconds().max(0) as u64
+    }
+
+    /// Elapsed time as H:MM:SS, for the status bar
+    pub fn clock(&self, now: DateTime<Utc>) -> String {
+        let seconds = self.elapsed_seconds(now);
+        format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_timer_clock() {
+        let now = Utc::now();
+        let timer = WorkTimer::start("PROJ-1".to_string(), now - Duration::seconds(3725));
+        assert_eq!(timer.elapsed_seconds(now), 3725);
+        assert_eq!(timer.clock(now), "1:02:05");
+        assert_eq!(timer.clock(now - Duration::hours(2)), "0:00:00");
+    }
+}
@@ -0,0 +1,115 @@
+use crate::domain::models::activity::{Activity, ActivityKind};
+use crate::domain::models::changelog::FieldChange;
+use crate::domain::models::comment::{Comment, CommentOrder};
+use crate::domain::models::ticket::Ticket;
+use crate::domain::services::filter_service::FilterService;
+use crate::infrastructure::api::ApiClient;
+use crate::utils::Result;
+use chrono::{DateTime, Duration, Utc};
+
+/// Most recently updated issues whose activity is loaded
+const ACTIVITY_ISSUES: usize = 25;
+
+/// How far back the activity stream goes
+const ACTIVITY_DAYS: i64 = 7;
+
+/// Most entries kept in the activity stream
+const ACTIVITY_LIMIT: usize = 200;
+
+/// Service for the stream of comments, transitions and assignments across
+/// the issues of a query
+pub struct ActivityService;
+
+impl ActivityService {
+    /// Comments, transitions and assignments on `ticket` after `since`
+    pub fn collect(
+        ticket: &Ticket,
+        comments: &[Comment],
+        changes: &[FieldChange],
+        since: DateTime<Utc>,
+    ) -> Vec<Activity> {
+        let activity = |author: Option<String>, at, kind| Activity {
+            key: ticket.key.clone(),
+            summary: ticket.summary.trim().to_string(),
+            author,
+            at,
+            kind,
+        };
+        let comments = comments.iter().map(|comment| {
+            activity(
+                Some(comment.author.display_name.clone()),
+                comment.created,
+                ActivityKind::Comment { body: comment.body.clone() },
+            )
+        });
+        let changes = changes.iter().filter_map(|change| {
+            let kind = match change.field.as_str() {
+                "status" => ActivityKind::Transition {
+                    from: change.from.clone().unwrap_or_default(),
+                    to: change.to.clone().unwrap_or_default(),
+                },
+                "assignee" => ActivityKind::Assignment { to: change.to.clone() },
+                _ => return None,
+            };
+            let author = change.author.as_ref().map(|author| author.display_name.clone());
+            Some(activity(author, change.at, kind))
+        });
+        comments.chain(changes).filter(|activity| activity.at > since).collect()
+    }
+
+    /// Activity of the last week on the issues matching `jql`, newest first
+    pub async fn load(client: &dyn ApiClient, jql: &str, now: DateTime<Utc>) -> Result<Vec<Activity>> {
+        let since = now - Duration::days(ACTIVITY_DAYS);
+        let jql = FilterService::restrict_query(jql, &format!("updated >= -{}d", ACTIVITY_DAYS));
+        let result = client.search_issues(&jql, 0, ACTIVITY_ISSUES).await?;
+        let mut activities = Vec::new();
+        for ticket in &result.issues {
+            let comments = client.get_comments(&ticket.key, CommentOrder::OldestFirst).await?;
+            let changes = client.get_recent_changes(&ticket.key).await?;
+            activities.extend(Self::collect(ticket, &comments, &changes, since));
+        }
+        activities.sort_by_key(|activity| std::cmp::Reverse(activity.at));
+        activities.truncate(ACTIVITY_LIMIT);
+        Ok(activities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{Status, StatusCategory};
+    use crate::domain::models::user::User;
+
+    #[test]
+    fn test_collect_activity_since() {
+        let now = Utc::now();
+        let status = Status {
+            id: "3".to_string(),
+            name: "In Progress".to_string(),
+            category: StatusCategory::InProgress,
+        };
+        let ticket = Ticket::new("PROJ-1".to_string(), "Fix login".to_string(), status);
+        let jane = User::new("jane".to_string(), "Jane Doe".to_string());
+        let comments = vec![
+            Comment::new("1".to_string(), jane.clone(), "Old news".to_string(), now - Duration::days(9)),
+            Comment::new("2".to_string(), jane.clone(), "On it".to_string(), now - Duration::hours(2)),
+        ];
+        let change = |field: &str, from: &str, to: &str| FieldChange {
+            at: now - Duration::hours(1),
+            author: Some(jane.clone()),
+            field: field.to_string(),
+            from: Some(from.to_string()),
+            to: Some(to.to_string()),
+        };
+        let changes = vec![
+            change("status", "In Progress", "Done"),
+            change("assignee", "Jane Doe", "Alex Kim"),
+            change("labels", "", "ui"),
+        ];
+
+        let activities = ActivityService::collect(&ticket, &comments, &changes, now - Duration::days(7));
+        let described: Vec<String> = activities.iter().map(Activity::describe).collect();
+        assert_eq!(described, vec!["commented: On it", "moved In Progress → Done", "assigned to Alex Kim"]);
+        assert!(activities.iter().all(|a| a.author.as_deref() == Some("Jane Doe")));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::domain::models::ticket::Ticket;
+use crate::infrastructure::api::ApiClient;
+use crate::utils::{LazyJiraError, Result};
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+/// Most issues loaded from each instance
+const CROSS_SEARCH_ISSUES: usize = 50;
+
+/// An issue found on one of the instances
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossSearchHit {
+    /// Name of the instance's tab
+    pub instance: String,
+    pub ticket: Ticket,
+}
+
+/// Issues found by one search across instances
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CrossSearchResult {
+    pub hits: Vec<CrossSearchHit>,
+    /// Instances the search failed on, with the error
+    pub failures: Vec<(String, String)>,
+}
+
+/// Service running one query against every configured instance
+pub struct CrossSearchService;
+
+impl CrossSearchService {
+    /// JQL for what was typed: JQL is kept as it is, anything else becomes
+    /// a text search; None when there is nothing to search
+    pub fn jql(query: &str) -> Option<String> {
+        let query = query.trim();
+        if query.is_empty() {
+            return None;
+        }
+        let lower = query.to_lowercase();
+        let is_jql = ["=", "~", "<", ">", " in ", " is ", "order by"]
+            .iter()
+            .any(|operator| lower.contains(operator));
+        if is_jql {
+            return Some(query.to_string());
+        }
+        Some(format!(
+            "text ~ \"{}\" ORDER BY updated DESC",
+            query.replace('\\', "\\\\").replace('"', "\\\"")
+        ))
+    }
+
+    /// One list of the issues each instance returned, most recently
+    /// updated first
+    pub fn merge(results: Vec<(String, Result<Vec<Ticket>>)>) -> CrossSearchResult {
+        let mut merged = CrossSearchResult::default();
+        for (instance, result) in results {
+            match result {
+                Ok(tickets) => merged.hits.extend(tickets.into_iter().map(|ticket| CrossSearchHit {
+                    instance: instance.clone(),
+                    ticket,
+                })),
+                Err(e) => merged.failures.push((instance, e.to_string())),
+            }
+        }
+        merged.hits.sort_by_key(|hit| Reverse(hit.ticket.updated));
+        merged
+    }
+
+    /// Run `jql` on all instances at once
+    pub async fn search(instances: &[(String, Arc<dyn ApiClient>)], jql: &str) -> CrossSearchResult {
+        let searches: Vec<_> = instances
+            .iter()
+            .map(|(name, client)| {
+                let client = client.clone();
+                let jql = jql.to_string();
+                let search = tokio::spawn(async move {
+                    client
+                        .search_issues(&jql, 0, CROSS_SEARCH_ISSUES)
+                        .await
+                        .map(|result| result.issues)
+                });
+                (name.clone(), search)
+            })
+            .collect();
+
+        let mut results = Vec::new();
+        for (name, search) in searches {
+            let result = search
+                .await
+                .unwrap_or_else(|e| Err(LazyJiraError::Internal(format!("Search task failed: {}", e))));
+            results.push((name, result));
+        }
+        Self::merge(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{Status, StatusCategory};
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_jql_from_text_or_jql() {
+        assert_eq!(CrossSearchService::jql("  "), None);
+        assert_eq!(
+            CrossSearchService::jql("login \"bug\"").unwrap(),
+            "text ~ \"login \\\"bug\\\"\" ORDER BY updated DESC"
+        );
+        assert_eq!(
+            CrossSearchService::jql(" assignee = currentUser() ").unwrap(),
+            "assignee = currentUser()"
+        );
+        assert_eq!(
+            CrossSearchService::jql("status IN (Blocked)").unwrap(),
+            "status IN (Blocked)"
+        );
+    }
+
+    #[test]
+    fn test_merge_newest_first() {
+        let status = Status {
+            id: "1".to_string(),
+            name: "To Do".to_string(),
+            category: StatusCategory::ToDo,
+        };
+        let now = Utc::now();
+        let ticket = |key: &str, hours_ago: i64| {
+            let mut ticket = Ticket::new(key.to_string(), key.to_string(), status.clone());
+            ticket.updated = now - Duration::hours(hours_ago);
+            ticket
+        };
+
+        let merged = CrossSearchService::merge(vec![
+            ("Home".to_string(), Ok(vec![ticket("HOME-1", 1), ticket("HOME-2", 5)])),
+            ("Acme".to_string(), Ok(vec![ticket("ACME-9", 3)])),
+            ("Globex".to_string(), Err(LazyJiraError::Internal("offline".to_string()))),
+        ]);
+        let keys: Vec<(&str, &str)> = merged
+            .hits
+            .iter()
+            .map(|hit| (hit.instance.as_str(), hit.ticket.key.as_str()))
+            .collect();
+        assert_eq!(keys, vec![("Home", "HOME-1"), ("Acme", "ACME-9"), ("Home", "HOME-2")]);
+        assert_eq!(merged.failures.len(), 1);
+        assert_eq!(merged.failures[0].0, "Globex");
+    }
+}
@@ -0,0 +1,115 @@
+use crate::domain::models::ticket::{Priority, StatusCategory, Ticket};
+use crate::domain::services::filter_service::TicketFilter;
+use chrono::NaiveDate;
+
+/// A single dashboard count that can be drilled into
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DashboardTile {
+    pub label: String,
+    pub count: usize,
+    pub filter: TicketFilter,
+}
+
+/// Service summarizing tickets for the dashboard
+pub struct DashboardService;
+
+impl DashboardService {
+    /// Build dashboard tiles: counts per status category, per priority
+    /// (highest first, only those in use), overdue tickets and, once the
+    /// signed-in user's account id is known, their work in progress
+    pub fn tiles(tickets: &[Ticket], today: NaiveDate, account_id: Option<&str>) -> Vec<DashboardTile> {
+        let mut filters: Vec<TicketFilter> = [
+            StatusCategory::ToDo,
+            StatusCategory::InProgress,
+            StatusCategory::Done,
+        ]
+        .into_iter()
+        .map(TicketFilter::Category)
+        .collect();
+
+        filters.extend(
+            [
+                Priority::Critical,
+                Priority::Highest,
+                Priority::High,
+                Priority::Medium,
+                Priority::Low,
+                Priority::Lowest,
+            ]
+            .into_iter()
+            .filter(|priority| tickets.iter().any(|t| t.priority == *priority))
+            .map(TicketFilter::Priority),
+        );
+
+        filters.push(TicketFilter::Overdue);
+        if let Some(account_id) = account_id {
+            filters.push(TicketFilter::MyWorkInProgress(account_id.to_string()));
+        }
+
+        filters
+            .into_iter()
+            .map(|filter| DashboardTile {
+                label: filter.label(),
+                count: tickets.iter().filter(|t| filter.matches(t, today)).count(),
+                filter,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::Status;
+    use crate::domain::models::user::User;
+
+    fn ticket(key: &str, category: StatusCategory, priority: Priority) -> Ticket {
+        let mut ticket = Ticket::new(
+            key.to_string(),
+            "Summary".to_string(),
+            Status {
+                id: "1".to_string(),
+                name: "Status".to_string(),
+                category,
+            },
+        );
+        ticket.priority = priority;
+        ticket
+    }
+
+    #[test]
+    fn test_tiles_count_categories_priorities_and_overdue() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let mut late = ticket("PROJ-1", StatusCategory::ToDo, Priority::High);
+        late.due_date = NaiveDate::from_ymd_opt(2024, 3, 1);
+        let mut mine = ticket("PROJ-2", StatusCategory::InProgress, Priority::High);
+        mine.assignee = Some(User::new("me".to_string(), "Me".to_string()));
+        let tickets = vec![
+            late,
+            mine,
+            ticket("PROJ-3", StatusCategory::Done, Priority::Low),
+            ticket("PROJ-4", StatusCategory::InProgress, Priority::Low),
+        ];
+
+        let tiles = DashboardService::tiles(&tickets, today, Some("me"));
+        let count = |filter: TicketFilter| {
+            tiles
+                .iter()
+                .find(|tile| tile.filter == filter)
+                .map(|tile| tile.count)
+        };
+
+        assert_eq!(count(TicketFilter::Category(StatusCategory::ToDo)), Some(1));
+        assert_eq!(count(TicketFilter::Priority(Priority::High)), Some(2));
+        assert_eq!(count(TicketFilter::Priority(Priority::Medium)), None);
+        assert_eq!(count(TicketFilter::Overdue), Some(1));
+        assert_eq!(count(TicketFilter::Category(StatusCategory::InProgress)), Some(2));
+        assert_eq!(count(TicketFilter::MyWorkInProgress("me".to_string())), Some(1));
+
+        // Without a signed-in user there is no work of theirs to count
+        let tiles = DashboardService::tiles(&tickets, today, None);
+        assert!(!tiles
+            .iter()
+            .any(|tile| matches!(tile.filter, TicketFilter::MyWorkInProgress(_))));
+    }
+}
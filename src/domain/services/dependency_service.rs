@@ -0,0 +1,247 @@
+use crate::domain::models::issue_link::IssueLink;
+use crate::domain::models::ticket::{Status, Ticket};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Side of the focused issue a chain of blockers runs on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyDirection {
+    /// Issues the focused one waits on, and what those wait on
+    BlockedBy,
+    /// Issues waiting on the focused one, and what waits on those
+    Blocks,
+}
+
+/// An issue in the dependency graph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyNode {
+    pub key: String,
+    pub summary: String,
+    pub status: Status,
+    pub blocked_by: Vec<String>,
+    pub blocks: Vec<String>,
+    /// Whether the issue itself was fetched, so its own links are known;
+    /// otherwise it is only known from a link to it
+    pub loaded: bool,
+}
+
+impl DependencyNode {
+    fn next(&self, direction: DependencyDirection) -> &[String] {
+        match direction {
+            DependencyDirection::BlockedBy => &self.blocked_by,
+            DependencyDirection::Blocks => &self.blocks,
+        }
+    }
+}
+
+/// A row of the dependency tree drawn from the focused issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyRow {
+    pub key: String,
+    pub direction: DependencyDirection,
+    pub depth: usize,
+    /// Tree lines drawn before the key, e.g. "│  └─ "
+    pub prefix: String,
+    /// Whether the issue already appears higher up, so it is not expanded again
+    pub repeated: bool,
+    /// Whether the issue has links that were not followed
+    pub truncated: bool,
+}
+
+/// "Blocks" and "is blocked by" links reachable from an issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyGraph {
+    pub root: String,
+    nodes: HashMap<String, DependencyNode>,
+}
+
+impl DependencyGraph {
+    pub fn new(root: &Ticket) -> Self {
+        let mut graph = Self {
+            root: root.key.clone(),
+            nodes: HashMap::new(),
+        };
+        graph.insert(root);
+        graph
+    }
+
+    pub fn node(&self, key: &str) -> Option<&DependencyNode> {
+        self.nodes.get(key)
+    }
+
+    /// Whether the root has no blocker links either way
+    pub fn is_empty(&self) -> bool {
+        self.nodes.len() <= 1
+    }
+
+    /// Add a fetched issue, and the issues its blocker links point to
+    pub fn insert(&mut self, ticket: &Ticket) {
+        let keys = |matches: fn(&IssueLink) -> bool| -> Vec<String> {
+            ticket
+                .links
+                .iter()
+                .filter(|link| matches(link))
+                .map(|link| link.issue.key.clone())
+                .collect()
+        };
+        self.nodes.insert(
+            ticket.key.clone(),
+            DependencyNode {
+                key: ticket.key.clone(),
+                summary: ticket.summary.clone(),
+                status: ticket.status.clone(),
+                blocked_by: keys(IssueLink::is_blocked_by),
+                blocks: keys(IssueLink::blocks),
+                loaded: true,
+            },
+        );
+        for link in &ticket.links {
+            if !link.blocks() && !link.is_blocked_by() {
+                continue;
+            }
+            self.nodes
+                .entry(link.issue.key.clone())
+                .or_insert_with(|| DependencyNode {
+                    key: link.issue.key.clone(),
+                    summary: link.issue.summary.clone(),
+                    status: link.status.clone(),
+                    blocked_by: Vec::new(),
+                    blocks: Vec::new(),
+                    loaded: false,
+                });
+        }
+    }
+
+    /// Stop following an issue that could not be fetched
+    pub fn mark_loaded(&mut self, key: &str) {
+        if let Some(node) = self.nodes.get_mut(key) {
+            node.loaded = true;
+        }
+    }
+
+    /// Issues within `max_depth` links of the root, either way, whose own
+    /// links are needed but not yet known
+    pub fn unloaded(&self, max_depth: usize) -> Vec<String> {
+        let mut missing = Vec::new();
+        for direction in [DependencyDirection::BlockedBy, DependencyDirection::Blocks] {
+            let mut seen = HashSet::from([self.root.clone()]);
+            let mut queue = VecDeque::from([(self.root.clone(), 0)]);
+            while let Some((key, depth)) = queue.pop_front() {
+                let Some(node) = self.nodes.get(&key) else {
+                    continue;
+                };
+                if !node.loaded {
+                    if !missing.contains(&key) {
+                        missing.push(key);
+                    }
+                    continue;
+                }
+                if depth + 1 >= max_depth {
+                    continue;
+                }
+                for next in node.next(direction) {
+                    if seen.insert(next.clone()) {
+                        queue.push_back((next.clone(), depth + 1));
+                    }
+                }
+            }
+        }
+        missing
+    }
+
+    /// Tree of the chains running from the root in `direction`; an issue
+    /// reached twice is listed again but only expanded the first time
+    pub fn rows(&self, direction: DependencyDirection) -> Vec<DependencyRow> {
+        let mut rows = Vec::new();
+        let mut seen = HashSet::from([self.root.clone()]);
+        if let Some(root) = self.nodes.get(&self.root) {
+            self.push_children(root, direction, "", 1, &mut seen, &mut rows);
+        }
+        rows
+    }
+
+    fn push_children(
+        &self,
+        node: &DependencyNode,
+        direction: DependencyDirection,
+        indent: &str,
+        depth: usize,
+        seen: &mut HashSet<String>,
+        rows: &mut Vec<DependencyRow>,
+    ) {
+        let children = node.next(direction);
+        for (i, key) in children.iter().enumerate() {
+            let last = i + 1 == children.len();
+            let branch = if last { "└─ " } else { "├─ " };
+            let child = self.nodes.get(key);
+            let repeated = !seen.insert(key.clone());
+            rows.push(DependencyRow {
+                key: key.clone(),
+                direction,
+                depth,
+                prefix: format!("{}{}", indent, branch),
+                repeated,
+                truncated: !repeated && child.is_some_and(|child| !child.loaded),
+            });
+            if let Some(child) = child.filter(|child| child.loaded && !repeated) {
+                let indent = format!("{}{}", indent, if last { "   " } else { "│  " });
+                self.push_children(child, direction, &indent, depth + 1, seen, rows);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{IssueRef, StatusCategory};
+
+    fn status() -> Status {
+        Status {
+            id: "1".to_string(),
+            name: "To Do".to_string(),
+            category: StatusCategory::ToDo,
+        }
+    }
+
+    fn ticket(key: &str, blocked_by: &[&str], blocks: &[&str]) -> Ticket {
+        let mut ticket = Ticket::new(key.to_string(), format!("Summary of {}", key), status());
+        let link = |other: &&str, outward: bool| IssueLink {
+            id: format!("{}-{}", key, other),
+            link_type: "Blocks".to_string(),
+            description: if outward { "blocks" } else { "is blocked by" }.to_string(),
+            outward,
+            issue: IssueRef {
+                key: other.to_string(),
+                summary: format!("Summary of {}", other),
+                issue_type: "Task".to_string(),
+            },
+            status: status(),
+        };
+        ticket.links = blocked_by
+            .iter()
+            .map(|other| link(other, false))
+            .chain(blocks.iter().map(|other| link(other, true)))
+            .collect();
+        ticket
+    }
+
+    #[test]
+    fn test_dependency_tree() {
+        // A waits on B and C, which both wait on D; A blocks E
+        let mut graph = DependencyGraph::new(&ticket("A", &["B", "C"], &["E"]));
+        assert_eq!(graph.unloaded(3), vec!["B", "C", "E"]);
+        graph.insert(&ticket("B", &["D"], &["A"]));
+        graph.insert(&ticket("C", &["D"], &["A"]));
+        graph.insert(&ticket("E", &[], &[]));
+        graph.insert(&ticket("D", &["F"], &["B", "C"]));
+        // F lies beyond the depth limit
+        assert!(graph.unloaded(3).is_empty());
+
+        let rows = graph.rows(DependencyDirection::BlockedBy);
+        let drawn: Vec<String> = rows.iter().map(|row| format!("{}{}", row.prefix, row.key)).collect();
+        assert_eq!(drawn, vec!["├─ B", "│  └─ D", "│     └─ F", "└─ C", "   └─ D"]);
+        assert!(rows[2].truncated);
+        assert!(rows[4].repeated);
+        assert_eq!(graph.rows(DependencyDirection::Blocks).len(), 1);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::domain::models::ticket::Ticket;
+use similar::{ChangeTag, TextDiff};
+
+/// A single field whose value differs between two snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+/// Kind of a line in a description diff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+/// A line in a description diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub text: String,
+}
+
+/// Differences between a cached ticket and its latest version
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TicketDiff {
+    pub field_changes: Vec<FieldChange>,
+    pub description_lines: Vec<DiffLine>,
+}
+
+impl TicketDiff {
+    /// Check if the description changed
+    pub fn description_changed(&self) -> bool {
+        self.description_lines
+            .iter()
+            .any(|line| line.kind != DiffLineKind::Unchanged)
+    }
+
+    /// Check if nothing changed between the two snapshots
+    pub fn is_empty(&self) -> bool {
+        self.field_changes.is_empty() && !self.description_changed()
+    }
+}
+
+/// Service for comparing ticket snapshots
+pub struct DiffService;
+
+impl DiffService {
+    /// Compare a cached ticket against the latest fetched version
+    pub fn diff_tickets(old: &Ticket, new: &Ticket) -> TicketDiff {
+        let fields: [(&str, String, String); 5] = [
+            ("Summary", old.summary.clone(), new.summary.clone()),
+            ("Status", old.status.name.clone(), new.status.name.clone()),
+            ("Assignee", assignee_name(old), assignee_name(new)),
+            ("Priority", format!("{:?}", old.priority), format!("{:?}", new.priority)),
+            ("Type", old.issue_type.clone(), new.issue_type.clone()),
+        ];
+
+        let field_changes = fields
+            .into_iter()
+            .filter(|(_, old_value, new_value)| old_value != new_value)
+            .map(|(field, old_value, new_value)| FieldChange {
+                field: field.to_string(),
+                old_value,
+                new_value,
+            })
+            .collect();
+
+        let description_lines = Self::diff_text(
+            old.description.as_deref().unwrap_or(""),
+            new.description.as_deref().unwrap_or(""),
+        );
+
+        TicketDiff {
+            field_changes,
+            description_lines,
+        }
+    }
+
+    /// Produce a line-based diff of two texts
+    pub fn diff_text(old: &str, new: &str) -> Vec<DiffLine> {
+        TextDiff::from_lines(old, new)
+            .iter_all_changes()
+            .map(|change| DiffLine {
+                kind: match change.tag() {
+                    ChangeTag::Equal => DiffLineKind::Unchanged,
+                    ChangeTag::Insert => DiffLineKind::Added,
+                    ChangeTag::Delete => DiffLineKind::Removed,
+                },
+                text: change.value().trim_end_matches('\n').to_string(),
+            })
+            .collect()
+    }
+}
+
+fn assignee_name(ticket: &Ticket) -> String {
+    ticket
+        .assignee
+        .as_ref()
+        .map(|u| u.display_name.clone())
+        .unwrap_or_else(|| "Unassigned".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{Status, StatusCategory};
+    use crate::domain::models::user::User;
+
+    fn create_test_ticket() -> Ticket {
+        let mut ticket = Ticket::new(
+            "PROJ-1".to_string(),
+            "Original summary".to_string(),
+            Status {
+                id: "1".to_string(),
+                name: "To Do".to_string(),
+                category: StatusCategory::ToDo,
+            },
+        );
+        ticket.description = Some("First line\nSecond line".to_string());
+        ticket
+    }
+
+    #[test]
+    fn test_diff_identical_tickets() {
+        let ticket = create_test_ticket();
+        let diff = DiffService::diff_tickets(&ticket, &ticket.clone());
+        assert!(diff.is_empty());
+        assert!(!diff.description_changed());
+    }
+
+    #[test]
+    fn test_diff_field_changes() {
+        let old = create_test_ticket();
+        let mut new = old.clone();
+        new.summary = "New summary".to_string();
+        new.assignee = Some(User::new("1".to_string(), "Jane Doe".to_string()));
+
+        let diff = DiffService::diff_tickets(&old, &new);
+        assert_eq!(diff.field_changes.len(), 2);
+        assert_eq!(diff.field_changes[0].field, "Summary");
+        assert_eq!(diff.field_changes[0].old_value, "Original summary");
+        assert_eq!(diff.field_changes[0].new_value, "New summary");
+        assert_eq!(diff.field_changes[1].field, "Assignee");
+        assert_eq!(diff.field_changes[1].old_value, "Unassigned");
+        assert_eq!(diff.field_changes[1].new_value, "Jane Doe");
+    }
+
+    #[test]
+    fn test_diff_description() {
+        let old = create_test_ticket();
+        let mut new = old.clone();
+        new.description = Some("First line\nChanged line".to_string());
+
+        let diff = DiffService::diff_tickets(&old, &new);
+        assert!(diff.field_changes.is_empty());
+        assert!(diff.description_changed());
+        assert!(diff.description_lines.contains(&DiffLine {
+            kind: DiffLineKind::Removed,
+            text: "Second line".to_string(),
+        }));
+        assert!(diff.description_lines.contains(&DiffLine {
+            kind: DiffLineKind::Added,
+            text: "Changed line".to_string(),
+        }));
+        assert_eq!(diff.description_lines[0].kind, DiffLineKind::Unchanged);
+    }
+}
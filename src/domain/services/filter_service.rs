@@ -1,10 +1,276 @@
-use crate::domain::models::ticket::{StatusCategory, Ticket};
+use crate::domain::models::ticket::{Priority, StatusCategory, Ticket};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// A predicate over tickets, used to drill into a filtered list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TicketFilter {
+    Category(StatusCategory),
+    Priority(Priority),
+    Overdue,
+    /// In progress and assigned to the account with this id
+    MyWorkInProgress(String),
+}
+
+impl TicketFilter {
+    /// Check whether a ticket matches the filter
+    pub fn matches(&self, ticket: &Ticket, today: NaiveDate) -> bool {
+        match self {
+            TicketFilter::Category(category) => ticket.status.category == *category,
+            TicketFilter::Priority(priority) => ticket.priority == *priority,
+            TicketFilter::Overdue => ticket.is_overdue(today),
+            TicketFilter::MyWorkInProgress(account_id) => {
+                ticket.is_in_progress()
+                    && ticket
+                        .assignee
+                        .as_ref()
+                        .is_some_and(|user| user.account_id == *account_id)
+            }
+        }
+    }
+
+    /// Short label describing the filter
+    pub fn label(&self) -> String {
+        match self {
+            TicketFilter::Category(StatusCategory::ToDo) => "To Do".to_string(),
+            TicketFilter::Category(StatusCategory::InProgress) => "In Progress".to_string(),
+            TicketFilter::Category(StatusCategory::Done) => "Done".to_string(),
+            TicketFilter::Priority(priority) => format!("{:?} priority", priority),
+            TicketFilter::Overdue => "Overdue".to_string(),
+            TicketFilter::MyWorkInProgress(_) => "My work in progress".to_string(),
+        }
+    }
+}
+
+/// Whose tickets the list shows, cycled without editing the JQL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssigneeScope {
+    #[default]
+    Everyone,
+    Me,
+    Unassigned,
+}
+
+impl AssigneeScope {
+    /// Everyone → assigned to me → unassigned → everyone
+    pub fn next(self) -> Self {
+        match self {
+            AssigneeScope::Everyone => AssigneeScope::Me,
+            AssigneeScope::Me => AssigneeScope::Unassigned,
+            AssigneeScope::Unassigned => AssigneeScope::Everyone,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AssigneeScope::Everyone => "everyone",
+            AssigneeScope::Me => "assigned to me",
+            AssigneeScope::Unassigned => "unassigned",
+        }
+    }
+}
+
+/// Ticket field the filter sidebar groups by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FacetKind {
+    Project,
+    Status,
+    Assignee,
+    Reporter,
+    Label,
+}
+
+impl FacetKind {
+    pub const ALL: [FacetKind; 5] = [
+        FacetKind::Project,
+        FacetKind::Status,
+        FacetKind::Assignee,
+        FacetKind::Reporter,
+        FacetKind::Label,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FacetKind::Project => "Projects",
+            FacetKind::Status => "Statuses",
+            FacetKind::Assignee => "Assignees",
+            FacetKind::Reporter => "Reporters",
+            FacetKind::Label => "Labels",
+        }
+    }
+
+    /// The ticket's values for this field; only labels can have several
+    fn values(&self, ticket: &Ticket) -> Vec<String> {
+        match self {
+            FacetKind::Project => vec![ticket.project_key.clone()],
+            FacetKind::Status => vec![ticket.status.name.clone()],
+            FacetKind::Assignee => vec![ticket
+                .assignee
+                .as_ref()
+                .map(|user| user.display_name.clone())
+                .unwrap_or_else(|| "Unassigned".to_string())],
+            FacetKind::Reporter => ticket
+                .reporter
+                .iter()
+                .map(|user| user.display_name.clone())
+                .collect(),
+            FacetKind::Label => ticket.labels.clone(),
+        }
+    }
+}
+
+/// One value of a ticket field, e.g. status "In Progress"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Facet {
+    pub kind: FacetKind,
+    pub value: String,
+}
+
+/// How a list of a query's results keeps the query's order once some of
+/// its issues are replaced by newer copies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeOrder {
+    /// Changed issues stay where they are: the query sorts by nothing, or
+    /// only by fields an edit cannot change
+    InPlace,
+    /// Sort the list by the updated time, newest first when descending
+    Updated { descending: bool },
+    /// The order depends on fields an edit can change; only Jira can sort it
+    Reload,
+}
 
 /// Service for filtering tickets
-#[allow(dead_code)] // Will be used when filtering is implemented
 pub struct FilterService;
 
 impl FilterService {
+    /// AND a clause onto a JQL query, keeping the query's ORDER BY last
+    pub fn restrict_query(jql: &str, clause: &str) -> String {
+        let (filter, order_by) = match order_by_start(jql) {
+            Some(idx) => (jql[..idx].trim(), Some(jql[idx..].trim())),
+            None => (jql.trim(), None),
+        };
+        let mut restricted = if filter.is_empty() {
+            clause.to_string()
+        } else {
+            format!("{} AND ({})", clause, filter)
+        };
+        if let Some(order_by) = order_by {
+            restricted.push(' ');
+            restricted.push_str(order_by);
+        }
+        restricted
+    }
+
+    /// How merging changed issues into the results of `jql` keeps them in
+    /// order
+    pub fn merge_order(jql: &str) -> MergeOrder {
+        const FIXED: [&str; 4] = ["key", "issuekey", "id", "created"];
+        let Some(idx) = order_by_start(jql) else {
+            return MergeOrder::InPlace;
+        };
+        let terms: Vec<Vec<String>> = jql[idx + "ORDER BY".len()..]
+            .split(',')
+            .map(|term| term.split_whitespace().map(str::to_lowercase).collect())
+            .collect();
+        let fixed = |term: &[String]| FIXED.contains(&term.first().map(String::as_str).unwrap_or(""));
+        let (first, rest) = terms.split_first().expect("split always yields a term");
+        if !rest.iter().all(|term| fixed(term)) {
+            return MergeOrder::Reload;
+        }
+        match first.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+            _ if fixed(first) => MergeOrder::InPlace,
+            // Without a direction the order is up to Jira
+            ["updated", "desc"] => MergeOrder::Updated { descending: true },
+            ["updated", "asc"] => MergeOrder::Updated { descending: false },
+            _ => MergeOrder::Reload,
+        }
+    }
+
+    /// Keep tickets matching a filter
+    pub fn apply(tickets: &[Ticket], filter: &TicketFilter, today: NaiveDate) -> Vec<Ticket> {
+        tickets
+            .iter()
+            .filter(|ticket| filter.matches(ticket, today))
+            .cloned()
+            .collect()
+    }
+
+    /// Facet values of the tickets with how many tickets have each, grouped
+    /// by kind and most common first
+    pub fn facet_counts(tickets: &[Ticket]) -> Vec<(Facet, usize)> {
+        let mut counts: Vec<(Facet, usize)> = Vec::new();
+        for ticket in tickets {
+            for kind in FacetKind::ALL {
+                for value in kind.values(ticket) {
+                    match counts
+                        .iter_mut()
+                        .find(|(facet, _)| facet.kind == kind && facet.value == value)
+                    {
+                        Some((_, count)) => *count += 1,
+                        None => counts.push((Facet { kind, value }, 1)),
+                    }
+                }
+            }
+        }
+        counts.sort_by(|(a, a_count), (b, b_count)| {
+            a.kind
+                .cmp(&b.kind)
+                .then(b_count.cmp(a_count))
+                .then_with(|| a.value.cmp(&b.value))
+        });
+        counts
+    }
+
+    /// Keep tickets whose key or summary contains `text`, ignoring case
+    pub fn apply_text(tickets: &[Ticket], text: &str) -> Vec<Ticket> {
+        let text = text.trim().to_lowercase();
+        tickets
+            .iter()
+            .filter(|ticket| {
+                ticket.key.to_lowercase().contains(&text)
+                    || ticket.summary.to_lowercase().contains(&text)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Keep tickets matching the selected facets: any selected value of a
+    /// kind, for every kind with a selection
+    pub fn apply_facets(tickets: &[Ticket], selected: &[Facet]) -> Vec<Ticket> {
+        tickets
+            .iter()
+            .filter(|ticket| {
+                FacetKind::ALL.iter().all(|kind| {
+                    let mut wanted = selected.iter().filter(|facet| facet.kind == *kind).peekable();
+                    wanted.peek().is_none() || {
+                        let values = kind.values(ticket);
+                        wanted.any(|facet| values.contains(&facet.value))
+                    }
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Keep the tickets in the assignee scope; `my_account_id` is the
+    /// signed-in user's
+    pub fn apply_assignee_scope(
+        tickets: &[Ticket],
+        scope: AssigneeScope,
+        my_account_id: &str,
+    ) -> Vec<Ticket> {
+        match scope {
+            AssigneeScope::Everyone => tickets.to_vec(),
+            AssigneeScope::Me => Self::filter_by_assignee(tickets, my_account_id),
+            AssigneeScope::Unassigned => tickets
+                .iter()
+                .filter(|ticket| ticket.assignee.is_none())
+                .cloned()
+                .collect(),
+        }
+    }
+
     /// Filter tickets by status category
     #[allow(dead_code)] // Will be used when filtering is implemented
     pub fn filter_by_status_category(
@@ -19,7 +285,6 @@ impl FilterService {
     }
 
     /// Filter tickets assigned to a user
-    #[allow(dead_code)] // Will be used when filtering is implemented
     pub fn filter_by_assignee(tickets: &[Ticket], account_id: &str) -> Vec<Ticket> {
         tickets
             .iter()
@@ -52,6 +317,30 @@ impl FilterService {
     }
 }
 
+/// Byte offset of the ORDER BY keyword in `jql`, ignoring case and any
+/// "order by" inside a quoted string
+fn order_by_start(jql: &str) -> Option<usize> {
+    let bytes = jql.as_bytes();
+    let mut quote = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        match (quote, bytes[i]) {
+            (Some(_), b'\\') => i += 1,
+            (Some(q), b) if b == q => quote = None,
+            (Some(_), _) => {}
+            (None, b @ (b'"' | b'\'')) => quote = Some(b),
+            (None, _) if is_order_by(bytes.get(i..i + 8)) => return Some(i),
+            (None, _) => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn is_order_by(word: Option<&[u8]>) -> bool {
+    word.is_some_and(|word| word.eq_ignore_ascii_case(b"ORDER BY"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,10 +359,21 @@ mod tests {
                     category: StatusCategory::ToDo,
                 },
                 assignee: Some(User::new("user1".to_string(), "User 1".to_string())),
+                reporter: None,
                 priority: crate::domain::models::ticket::Priority::Medium,
                 issue_type: "Task".to_string(),
                 project_key: "PROJ".to_string(),
                 description: None,
+                parent: None,
+                story_points: None,
+                resolved: None,
+                start_date: None,
+                due_date: None,
+                attachments: Vec::new(),
+                labels: Vec::new(),
+                service_request: None,
+                links: Vec::new(),
+                custom_fields: Default::default(),
                 created: chrono::Utc::now(),
                 updated: chrono::Utc::now(),
             },
@@ -87,10 +387,21 @@ mod tests {
                     category: StatusCategory::InProgress,
                 },
                 assignee: Some(User::new("user2".to_string(), "User 2".to_string())),
+                reporter: None,
                 priority: crate::domain::models::ticket::Priority::High,
                 issue_type: "Bug".to_string(),
                 project_key: "PROJ".to_string(),
                 description: None,
+                parent: None,
+                story_points: None,
+                resolved: None,
+                start_date: None,
+                due_date: None,
+                attachments: Vec::new(),
+                labels: Vec::new(),
+                service_request: None,
+                links: Vec::new(),
+                custom_fields: Default::default(),
                 created: chrono::Utc::now(),
                 updated: chrono::Utc::now(),
             },
@@ -104,10 +415,21 @@ mod tests {
                     category: StatusCategory::Done,
                 },
                 assignee: Some(User::new("user1".to_string(), "User 1".to_string())),
+                reporter: None,
                 priority: crate::domain::models::ticket::Priority::Low,
                 issue_type: "Task".to_string(),
                 project_key: "PROJ".to_string(),
                 description: None,
+                parent: None,
+                story_points: None,
+                resolved: None,
+                start_date: None,
+                due_date: None,
+                attachments: Vec::new(),
+                labels: Vec::new(),
+                service_request: None,
+                links: Vec::new(),
+                custom_fields: Default::default(),
                 created: chrono::Utc::now(),
                 updated: chrono::Utc::now(),
             },
@@ -128,6 +450,138 @@ mod tests {
         assert_eq!(done_tickets[0].key, "PROJ-3");
     }
 
+    #[test]
+    fn test_apply_ticket_filter() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let mut tickets = create_test_tickets();
+        tickets[0].due_date = NaiveDate::from_ymd_opt(2024, 3, 1);
+        tickets[2].due_date = NaiveDate::from_ymd_opt(2024, 3, 1);
+
+        let overdue = FilterService::apply(&tickets, &TicketFilter::Overdue, today);
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].key, "PROJ-1");
+
+        let mine = TicketFilter::MyWorkInProgress("user2".to_string());
+        let in_progress = FilterService::apply(&tickets, &mine, today);
+        assert_eq!(in_progress.len(), 1);
+        assert_eq!(in_progress[0].key, "PROJ-2");
+
+        // Someone else's work in progress is left out
+        let theirs = TicketFilter::MyWorkInProgress("user1".to_string());
+        assert!(FilterService::apply(&tickets, &theirs, today).is_empty());
+    }
+
+    #[test]
+    fn test_facet_counts_and_apply_facets() {
+        let mut tickets = create_test_tickets();
+        tickets[0].labels = vec!["backend".to_string()];
+        tickets[1].labels = vec!["backend".to_string(), "ui".to_string()];
+
+        let counts = FilterService::facet_counts(&tickets);
+        let assignees: Vec<(&str, usize)> = counts
+            .iter()
+            .filter(|(facet, _)| facet.kind == FacetKind::Assignee)
+            .map(|(facet, count)| (facet.value.as_str(), *count))
+            .collect();
+        assert_eq!(assignees, vec![("User 1", 2), ("User 2", 1)]);
+        assert_eq!(counts[0], (Facet { kind: FacetKind::Project, value: "PROJ".to_string() }, 3));
+
+        let facet = |kind, value: &str| Facet { kind, value: value.to_string() };
+        tickets[2].reporter = Some(User::new("user2".to_string(), "User 2".to_string()));
+        let reported = FilterService::apply_facets(&tickets, &[facet(FacetKind::Reporter, "User 2")]);
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].key, "PROJ-3");
+
+        let backend = FilterService::apply_facets(&tickets, &[facet(FacetKind::Label, "backend")]);
+        assert_eq!(backend.len(), 2);
+
+        // Values of one kind widen the filter, other kinds narrow it
+        let filtered = FilterService::apply_facets(
+            &tickets,
+            &[
+                facet(FacetKind::Status, "To Do"),
+                facet(FacetKind::Status, "Done"),
+                facet(FacetKind::Assignee, "User 1"),
+            ],
+        );
+        assert_eq!(filtered.len(), 2);
+        let filtered = FilterService::apply_facets(
+            &tickets,
+            &[facet(FacetKind::Status, "Done"), facet(FacetKind::Label, "backend")],
+        );
+        assert!(filtered.is_empty());
+    }
+
+    #[test]
+    fn test_apply_text() {
+        let tickets = create_test_tickets();
+        let found = FilterService::apply_text(&tickets, "proj-2");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].key, "PROJ-2");
+        assert_eq!(FilterService::apply_text(&tickets, " ").len(), tickets.len());
+    }
+
+    #[test]
+    fn test_restrict_query_keeps_order_by() {
+        assert_eq!(
+            FilterService::restrict_query("project = X ORDER BY updated DESC", "updated >= -5m"),
+            "updated >= -5m AND (project = X) ORDER BY updated DESC"
+        );
+        assert_eq!(FilterService::restrict_query("", "key in (A-1)"), "key in (A-1)");
+        // Case-mapping "ı" changes its byte length, and quoted text is not a keyword
+        assert_eq!(
+            FilterService::restrict_query(
+                "summary ~ \"İstanbul 'order by' ılık\" order by updated DESC",
+                "updated >= -5m"
+            ),
+            "updated >= -5m AND (summary ~ \"İstanbul 'order by' ılık\") order by updated DESC"
+        );
+        assert_eq!(
+            FilterService::restrict_query("text ~ 'sort \\' order by' ", "key in (A-1)"),
+            "key in (A-1) AND (text ~ 'sort \\' order by')"
+        );
+    }
+
+    #[test]
+    fn test_merge_order() {
+        assert_eq!(FilterService::merge_order("project = X"), MergeOrder::InPlace);
+        assert_eq!(FilterService::merge_order("project = X ORDER BY key, created DESC"), MergeOrder::InPlace);
+        assert_eq!(
+            FilterService::merge_order("assignee = currentUser() ORDER BY updated DESC"),
+            MergeOrder::Updated { descending: true }
+        );
+        assert_eq!(
+            FilterService::merge_order("project = X order by Updated asc, key"),
+            MergeOrder::Updated { descending: false }
+        );
+        assert_eq!(FilterService::merge_order("project = X ORDER BY updated"), MergeOrder::Reload);
+        assert_eq!(FilterService::merge_order("project = X ORDER BY priority DESC"), MergeOrder::Reload);
+        assert_eq!(
+            FilterService::merge_order("project = X ORDER BY updated DESC, priority"),
+            MergeOrder::Reload
+        );
+    }
+
+    #[test]
+    fn test_apply_assignee_scope() {
+        let mut tickets = create_test_tickets();
+        tickets[1].assignee = None;
+
+        let scope = AssigneeScope::default().next();
+        assert_eq!(scope, AssigneeScope::Me);
+        let mine = FilterService::apply_assignee_scope(&tickets, scope, "user1");
+        assert_eq!(mine.len(), 2);
+
+        let scope = scope.next();
+        let unassigned = FilterService::apply_assignee_scope(&tickets, scope, "user1");
+        assert_eq!(unassigned.len(), 1);
+        assert_eq!(unassigned[0].key, "PROJ-2");
+
+        let scope = scope.next();
+        assert_eq!(scope, AssigneeScope::Everyone);
+        assert_eq!(FilterService::apply_assignee_scope(&tickets, scope, "user1").len(), 3);
+    }
+
     #[test]
     fn test_filter_by_assignee() {
         let tickets = create_test_tickets();
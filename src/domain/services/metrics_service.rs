@@ -0,0 +1,123 @@
+use crate::domain::models::changelog::StatusChange;
+use crate::domain::models::ticket::Ticket;
+use chrono::{DateTime, Duration, Utc};
+
+/// Total time a ticket has spent in one status
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusDuration {
+    pub status: String,
+    pub duration: Duration,
+    /// Whether the ticket is currently in this status
+    pub current: bool,
+}
+
+/// Service for ticket flow metrics
+pub struct MetricsService;
+
+impl MetricsService {
+    /// Time spent in each status, in the order statuses were first entered
+    ///
+    /// The ticket starts in the status the first transition moved it out of
+    /// (or its current status when it never moved) at creation time; the last
+    /// status runs until `now`.
+    pub fn time_in_status(
+        ticket: &Ticket,
+        changes: &[StatusChange],
+        now: DateTime<Utc>,
+    ) -> Vec<StatusDuration> {
+        let mut durations: Vec<StatusDuration> = Vec::new();
+        let mut add = |status: &str, duration: Duration| {
+            match durations.iter_mut().find(|d| d.status == status) {
+                Some(entry) => entry.duration += duration,
+                None => durations.push(StatusDuration {
+                    status: status.to_string(),
+                    duration,
+                    current: false,
+                }),
+            }
+        };
+
+        let mut status = changes
+            .first()
+            .map(|c| c.from.clone())
+            .unwrap_or_else(|| ticket.status.name.clone());
+        let mut since = ticket.created;
+
+        for change in changes {
+            add(&status, (change.at - since).max(Duration::zero()));
+            status = change.to.clone();
+            since = change.at;
+        }
+        add(&status, (now - since).max(Duration::zero()));
+
+        if let Some(entry) = durations.iter_mut().find(|d| d.status == status) {
+            entry.current = true;
+        }
+        durations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{Status, StatusCategory};
+    use chrono::TimeZone;
+
+    fn change(at: DateTime<Utc>, from: &str, to: &str) -> StatusChange {
+        StatusChange {
+            at,
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_time_in_status_accumulates_revisits() {
+        let created = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        let mut ticket = Ticket::new(
+            "PROJ-1".to_string(),
+            "Flow".to_string(),
+            Status {
+                id: "3".to_string(),
+                name: "In Progress".to_string(),
+                category: StatusCategory::InProgress,
+            },
+        );
+        ticket.created = created;
+
+        let changes = vec![
+            change(created + Duration::hours(2), "To Do", "In Progress"),
+            change(created + Duration::hours(5), "In Progress", "In Review"),
+            change(created + Duration::hours(6), "In Review", "In Progress"),
+        ];
+        let now = created + Duration::hours(10);
+
+        let durations = MetricsService::time_in_status(&ticket, &changes, now);
+        assert_eq!(durations.len(), 3);
+        assert_eq!(durations[0].status, "To Do");
+        assert_eq!(durations[0].duration, Duration::hours(2));
+        assert_eq!(durations[1].status, "In Progress");
+        assert_eq!(durations[1].duration, Duration::hours(7));
+        assert!(durations[1].current);
+        assert_eq!(durations[2].duration, Duration::hours(1));
+    }
+
+    #[test]
+    fn test_time_in_status_without_changes() {
+        let ticket = Ticket::new(
+            "PROJ-2".to_string(),
+            "Untouched".to_string(),
+            Status {
+                id: "1".to_string(),
+                name: "To Do".to_string(),
+                category: StatusCategory::ToDo,
+            },
+        );
+        let now = ticket.created + Duration::days(3);
+
+        let durations = MetricsService::time_in_status(&ticket, &[], now);
+        assert_eq!(durations.len(), 1);
+        assert_eq!(durations[0].duration, Duration::days(3));
+        assert!(durations[0].current);
+    }
+}
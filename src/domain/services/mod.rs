@@ -1,5 +1,16 @@
 pub mod ticket_service;
 pub mod filter_service;
+pub mod diff_service;
+pub mod sprint_service;
+pub mod dashboard_service;
+pub mod metrics_service;
+pub mod reminder_service;
+pub mod template_service;
+pub mod dependency_service;
+pub mod release_notes_service;
+pub mod timesheet_service;
+pub mod activity_service;
+pub mod cross_search_service;
 
 // Re-export for convenience (will be used when app is implemented)
 #[allow(unused_imports)]
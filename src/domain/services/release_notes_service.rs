@@ -0,0 +1,100 @@
+use crate::domain::models::ticket::Ticket;
+
+/// Section of the release notes an issue is listed under
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReleaseSection {
+    Features,
+    Bugs,
+    Tasks,
+}
+
+impl ReleaseSection {
+    /// Section for an issue type; anything that is neither a feature nor a
+    /// bug counts as a task
+    pub fn of(issue_type: &str) -> Self {
+        match issue_type.to_lowercase().as_str() {
+            "story" | "feature" | "new feature" | "improvement" | "epic" => ReleaseSection::Features,
+            "bug" | "defect" | "incident" => ReleaseSection::Bugs,
+            _ => ReleaseSection::Tasks,
+        }
+    }
+
+    pub fn heading(self) -> &'static str {
+        match self {
+            ReleaseSection::Features => "Features",
+            ReleaseSection::Bugs => "Bugs",
+            ReleaseSection::Tasks => "Tasks",
+        }
+    }
+}
+
+/// Service for writing release notes
+pub struct ReleaseNotesService;
+
+impl ReleaseNotesService {
+    /// Markdown release notes titled `title`, listing the tickets by key
+    /// under Features, Bugs and Tasks; empty sections are left out
+    pub fn markdown(title: &str, tickets: &[Ticket]) -> String {
+        let mut notes = format!("# {}\n", title);
+        if tickets.is_empty() {
+            notes.push_str("\nNo issues.\n");
+            return notes;
+        }
+
+        let mut sorted: Vec<&Ticket> = tickets.iter().collect();
+        sorted.sort_by_key(|ticket| (ReleaseSection::of(&ticket.issue_type), key_order(&ticket.key)));
+        let mut section = None;
+        for ticket in sorted {
+            let ticket_section = ReleaseSection::of(&ticket.issue_type);
+            if section != Some(ticket_section) {
+                notes.push_str(&format!("\n## {}\n\n", ticket_section.heading()));
+                section = Some(ticket_section);
+            }
+            notes.push_str(&format!("- {} {}\n", ticket.key, ticket.summary.trim()));
+        }
+        notes
+    }
+}
+
+/// Sort key putting PROJ-9 before PROJ-10
+fn key_order(key: &str) -> (String, u64) {
+    match key.rsplit_once('-') {
+        Some((project, number)) => (project.to_string(), number.parse().unwrap_or(0)),
+        None => (key.to_string(), 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{Status, StatusCategory};
+
+    fn ticket(key: &str, issue_type: &str, summary: &str) -> Ticket {
+        let status = Status {
+            id: "3".to_string(),
+            name: "Done".to_string(),
+            category: StatusCategory::Done,
+        };
+        let mut ticket = Ticket::new(key.to_string(), summary.to_string(), status);
+        ticket.issue_type = issue_type.to_string();
+        ticket
+    }
+
+    #[test]
+    fn test_release_notes_grouped_by_type() {
+        let tickets = vec![
+            ticket("PROJ-10", "Task", "Upgrade the database driver"),
+            ticket("PROJ-12", "Bug", "Login fails on Safari"),
+            ticket("PROJ-9", "Story", "Pay by card"),
+            ticket("PROJ-11", "Story", "Pay by invoice"),
+        ];
+        assert_eq!(
+            ReleaseNotesService::markdown("Release 2.0", &tickets),
+            "# Release 2.0\n\
+             \n## Features\n\n- PROJ-9 Pay by card\n- PROJ-11 Pay by invoice\n\
+             \n## Bugs\n\n- PROJ-12 Login fails on Safari\n\
+             \n## Tasks\n\n- PROJ-10 Upgrade the database driver\n"
+        );
+        assert_eq!(ReleaseNotesService::markdown("Release 2.1", &[]), "# Release 2.1\n\nNo issues.\n");
+    }
+}
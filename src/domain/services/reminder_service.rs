@@ -0,0 +1,98 @@
+use crate::domain::models::ticket::Ticket;
+use chrono::{Duration, NaiveDate};
+
+/// An open ticket that is overdue or due soon
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueReminder {
+    pub key: String,
+    pub summary: String,
+    pub due_date: NaiveDate,
+}
+
+impl DueReminder {
+    /// Human-readable due state relative to `today`
+    pub fn due_label(&self, today: NaiveDate) -> String {
+        let days = (self.due_date - today).num_days();
+        match days {
+            d if d < 0 => format!("overdue by {}d", -d),
+            0 => "due today".to_string(),
+            1 => "due tomorrow".to_string(),
+            d => format!("due in {}d", d),
+        }
+    }
+
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.due_date < today
+    }
+}
+
+/// Service for due-date reminders
+pub struct ReminderService;
+
+impl ReminderService {
+    /// JQL for my open tickets due within `window_days` (overdue ones included)
+    pub fn due_soon_jql(window_days: u32) -> String {
+        format!(
+            "assignee = currentUser() AND statusCategory != Done AND duedate <= endOfDay(\"+{}d\") ORDER BY duedate ASC",
+            window_days
+        )
+    }
+
+    /// Open tickets that are overdue or due within `window_days` of today, soonest first
+    pub fn due_reminders(tickets: &[Ticket], today: NaiveDate, window_days: u32) -> Vec<DueReminder> {
+        let horizon = today + Duration::days(i64::from(window_days));
+        let mut reminders: Vec<DueReminder> = tickets
+            .iter()
+            .filter(|ticket| !ticket.is_done())
+            .filter_map(|ticket| {
+                let due_date = ticket.due_date.filter(|due| *due <= horizon)?;
+                Some(DueReminder {
+                    key: ticket.key.clone(),
+                    summary: ticket.summary.clone(),
+                    due_date,
+                })
+            })
+            .collect();
+        reminders.sort_by_key(|reminder| reminder.due_date);
+        reminders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{Status, StatusCategory};
+
+    fn ticket(key: &str, category: StatusCategory, due: Option<NaiveDate>) -> Ticket {
+        let mut ticket = Ticket::new(
+            key.to_string(),
+            "Summary".to_string(),
+            Status {
+                id: "1".to_string(),
+                name: "Status".to_string(),
+                category,
+            },
+        );
+        ticket.due_date = due;
+        ticket
+    }
+
+    #[test]
+    fn test_due_reminders_within_window() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let tickets = vec![
+            ticket("P-1", StatusCategory::ToDo, NaiveDate::from_ymd_opt(2024, 3, 12)),
+            ticket("P-2", StatusCategory::InProgress, NaiveDate::from_ymd_opt(2024, 3, 8)),
+            ticket("P-3", StatusCategory::ToDo, NaiveDate::from_ymd_opt(2024, 3, 13)),
+            ticket("P-4", StatusCategory::Done, NaiveDate::from_ymd_opt(2024, 3, 1)),
+            ticket("P-5", StatusCategory::ToDo, None),
+        ];
+
+        let reminders = ReminderService::due_reminders(&tickets, today, 2);
+        let keys: Vec<&str> = reminders.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["P-2", "P-1"]);
+        assert!(reminders[0].is_overdue(today));
+        assert_eq!(reminders[0].due_label(today), "overdue by 2d");
+        assert_eq!(reminders[1].due_label(today), "due in 2d");
+    }
+}
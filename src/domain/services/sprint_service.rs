@@ -0,0 +1,441 @@
+use crate::domain::models::sprint::Sprint;
+use crate::domain::models::ticket::{StatusCategory, Ticket};
+use crate::domain::services::filter_service::FilterService;
+use crate::utils::text::csv_field;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+/// Default sprint length used when a sprint has no planned dates
+const DEFAULT_SPRINT_DAYS: i64 = 14;
+
+/// Upper bound on the number of days tracked for sprint progress
+const MAX_PROGRESS_DAYS: i64 = 60;
+
+/// What happens to unfinished work when a sprint is completed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionPlan {
+    pub sprint: Sprint,
+    pub incomplete_keys: Vec<String>,
+    /// Sprint receiving the incomplete issues; `None` moves them to the backlog
+    pub target: Option<Sprint>,
+}
+
+impl CompletionPlan {
+    /// Human readable summary used in the confirmation dialog
+    pub fn summary(&self) -> String {
+        let destination = self
+            .target
+            .as_ref()
+            .map(|s| s.name.clone())
+            .unwrap_or_else(|| "the backlog".to_string());
+
+        if self.incomplete_keys.is_empty() {
+            format!("Complete {}? All issues are done.", self.sprint.name)
+        } else {
+            format!(
+                "Complete {}? {} incomplete issue(s) will move to {}: {}",
+                self.sprint.name,
+                self.incomplete_keys.len(),
+                destination,
+                self.incomplete_keys.join(", ")
+            )
+        }
+    }
+}
+
+/// Completed work at the end of a sprint day
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyProgress {
+    pub date: NaiveDate,
+    pub done_issues: usize,
+    pub done_points: f64,
+}
+
+/// Burndown data for a sprint
+#[derive(Debug, Clone, PartialEq)]
+pub struct SprintProgress {
+    pub total_issues: usize,
+    pub total_points: f64,
+    pub days: Vec<DailyProgress>,
+}
+
+impl SprintProgress {
+    /// Issues done by the last tracked day
+    pub fn done_issues(&self) -> usize {
+        self.days.last().map(|d| d.done_issues).unwrap_or(0)
+    }
+
+    /// Story points done by the last tracked day
+    pub fn done_points(&self) -> f64 {
+        self.days.last().map(|d| d.done_points).unwrap_or(0.0)
+    }
+
+    /// Whether any issue in the sprint is estimated
+    pub fn uses_points(&self) -> bool {
+        self.total_points > 0.0
+    }
+
+    /// Remaining work per day, in points when the sprint is estimated and in issues otherwise
+    pub fn remaining_series(&self) -> Vec<u64> {
+        self.days
+            .iter()
+            .map(|day| {
+                if self.uses_points() {
+                    (self.total_points - day.done_points).max(0.0).round() as u64
+                } else {
+                    (self.total_issues - day.done_issues) as u64
+                }
+            })
+            .collect()
+    }
+}
+
+/// Work completed in a closed sprint
+#[derive(Debug, Clone, PartialEq)]
+pub struct SprintVelocity {
+    pub sprint_name: String,
+    pub completed_issues: usize,
+    pub completed_points: f64,
+}
+
+/// Format of a sprint changelog export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangelogFormat {
+    Markdown,
+    Csv,
+}
+
+/// Service for sprint planning decisions
+pub struct SprintService;
+
+impl SprintService {
+    /// Build the plan for completing a sprint given its issues and the board's sprints
+    pub fn plan_completion(sprint: &Sprint, issues: &[Ticket], sprints: &[Sprint]) -> CompletionPlan {
+        let incomplete_keys = issues
+            .iter()
+            .filter(|ticket| ticket.status.category != StatusCategory::Done)
+            .map(|ticket| ticket.key.clone())
+            .collect();
+
+        let target = sprints
+            .iter()
+            .find(|s| s.is_future() && s.id != sprint.id)
+            .cloned();
+
+        CompletionPlan {
+            sprint: sprint.clone(),
+            incomplete_keys,
+            target,
+        }
+    }
+
+    /// Compute daily progress from the sprint start until today (or the sprint end)
+    ///
+    /// Issues count as done on their resolution date; done issues without one
+    /// fall back to their last update. Returns `None` for unstarted sprints.
+    pub fn progress(sprint: &Sprint, issues: &[Ticket], now: DateTime<Utc>) -> Option<SprintProgress> {
+        let start = sprint.start_date?.date_naive();
+        let last = sprint.end_date.map(|end| end.min(now)).unwrap_or(now).date_naive();
+        let last = last.min(start + Duration::days(MAX_PROGRESS_DAYS - 1));
+
+        let completions: Vec<(NaiveDate, f64)> = issues
+            .iter()
+            .filter(|ticket| ticket.status.category == StatusCategory::Done)
+            .map(|ticket| {
+                let done_at = ticket.resolved.unwrap_or(ticket.updated);
+                (done_at.date_naive(), ticket.story_points.unwrap_or(0.0))
+            })
+            .collect();
+
+        let days = start
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .map(|date| {
+                let done: Vec<f64> = completions
+                    .iter()
+                    .filter(|(done_on, _)| *done_on <= date)
+                    .map(|(_, points)| *points)
+                    .collect();
+                DailyProgress {
+                    date,
+                    done_issues: done.len(),
+                    done_points: done.iter().sum(),
+                }
+            })
+            .collect();
+
+        Some(SprintProgress {
+            total_issues: issues.len(),
+            total_points: issues.iter().filter_map(|t| t.story_points).sum(),
+            days,
+        })
+    }
+
+    /// Work completed in a sprint: done issues resolved before it was closed
+    ///
+    /// Issues carried over and finished in a later sprint are not counted.
+    pub fn velocity(sprint: &Sprint, issues: &[Ticket]) -> SprintVelocity {
+        let completed = Self::resolved_issues(sprint, issues);
+        SprintVelocity {
+            sprint_name: sprint.name.clone(),
+            completed_issues: completed.len(),
+            completed_points: completed.iter().filter_map(|t| t.story_points).sum(),
+        }
+    }
+
+    /// Issues done within the sprint: by the time it was completed or due to
+    /// end, or by now while it has no end
+    pub fn resolved_issues<'a>(sprint: &Sprint, issues: &'a [Ticket]) -> Vec<&'a Ticket> {
+        let cutoff = sprint.complete_date.or(sprint.end_date);
+        issues
+            .iter()
+            .filter(|ticket| ticket.status.category == StatusCategory::Done)
+            .filter(|ticket| {
+                let done_at = ticket.resolved.unwrap_or(ticket.updated);
+                cutoff.map(|cutoff| done_at <= cutoff).unwrap_or(true)
+            })
+            .collect()
+    }
+
+    /// Everything resolved in the sprint with its assignee and points, for
+    /// sprint review documents
+    pub fn changelog(sprint: &Sprint, issues: &[Ticket], format: ChangelogFormat) -> String {
+        let resolved = Self::resolved_issues(sprint, issues);
+        let rows: Vec<[String; 4]> = resolved
+            .iter()
+            .map(|ticket| {
+                [
+                    ticket.key.clone(),
+                    ticket.summary.trim().to_string(),
+                    ticket
+                        .assignee
+                        .as_ref()
+                        .map(|user| user.display_name.clone())
+                        .unwrap_or_else(|| "Unassigned".to_string()),
+                    ticket.story_points.map(|points| points.to_string()).unwrap_or_default(),
+                ]
+            })
+            .collect();
+
+        match format {
+            ChangelogFormat::Csv => {
+                let mut csv = String::from("key,summary,assignee,points\n");
+                for row in &rows {
+                    let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+                    csv.push_str(&fields.join(","));
+                    csv.push('\n');
+                }
+                csv
+            }
+            ChangelogFormat::Markdown => {
+                let mut markdown = format!("# {} — resolved issues\n\n", sprint.name);
+                if let Some(goal) = &sprint.goal {
+                    markdown.push_str(&format!("Goal: {}\n\n", goal));
+                }
+                markdown.push_str("| Key | Summary | Assignee | Points |\n|---|---|---|---|\n");
+                for row in &rows {
+                    let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+                    markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+                }
+                // Summed from 0.0, as an empty f64 sum is -0
+                let points = resolved
+                    .iter()
+                    .filter_map(|ticket| ticket.story_points)
+                    .fold(0.0, |sum, points| sum + points);
+                markdown.push_str(&format!("\n{} issues, {} points\n", resolved.len(), points));
+                markdown
+            }
+        }
+    }
+
+    /// Start and end dates for starting a sprint, preferring its planned dates
+    pub fn start_window(sprint: &Sprint, now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+        match (sprint.start_date, sprint.end_date) {
+            (Some(start), Some(end)) if end > now => (start.min(now), end),
+            _ => (now, now + Duration::days(DEFAULT_SPRINT_DAYS)),
+        }
+    }
+
+    /// Restrict `jql` to issues in the given sprints, keeping its ORDER BY.
+    /// Returns `jql` unchanged when no sprints are given
+    pub fn restrict_to_sprints(jql: &str, sprints: &[Sprint]) -> String {
+        if sprints.is_empty() {
+            return jql.to_string();
+        }
+        let ids: Vec<&str> = sprints.iter().map(|sprint| sprint.id.as_str()).collect();
+        FilterService::restrict_query(jql, &format!("sprint in ({})", ids.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::sprint::SprintState;
+    use crate::domain::models::ticket::Status;
+    use crate::domain::models::user::User;
+    use chrono::TimeZone;
+
+    fn sprint(id: &str, state: SprintState) -> Sprint {
+        Sprint {
+            id: id.to_string(),
+            name: format!("Sprint {}", id),
+            state,
+            start_date: None,
+            end_date: None,
+            complete_date: None,
+            goal: None,
+        }
+    }
+
+    fn ticket(key: &str, category: StatusCategory) -> Ticket {
+        Ticket::new(
+            key.to_string(),
+            "Summary".to_string(),
+            Status {
+                id: "1".to_string(),
+                name: "Status".to_string(),
+                category,
+            },
+        )
+    }
+
+    #[test]
+    fn test_plan_completion_moves_incomplete_to_next_sprint() {
+        let active = sprint("1", SprintState::Active);
+        let sprints = vec![active.clone(), sprint("2", SprintState::Future)];
+        let issues = vec![
+            ticket("PROJ-1", StatusCategory::Done),
+            ticket("PROJ-2", StatusCategory::InProgress),
+            ticket("PROJ-3", StatusCategory::ToDo),
+        ];
+
+        let plan = SprintService::plan_completion(&active, &issues, &sprints);
+        assert_eq!(plan.incomplete_keys, vec!["PROJ-2", "PROJ-3"]);
+        assert_eq!(plan.target.as_ref().map(|s| s.id.as_str()), Some("2"));
+        assert!(plan.summary().contains("2 incomplete issue(s) will move to Sprint 2"));
+    }
+
+    #[test]
+    fn test_plan_completion_falls_back_to_backlog() {
+        let active = sprint("1", SprintState::Active);
+        let issues = vec![ticket("PROJ-2", StatusCategory::ToDo)];
+
+        let plan = SprintService::plan_completion(&active, &issues, std::slice::from_ref(&active));
+        assert!(plan.target.is_none());
+        assert!(plan.summary().contains("the backlog"));
+    }
+
+    #[test]
+    fn test_progress_tracks_resolutions_per_day() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        let mut active = sprint("1", SprintState::Active);
+        active.start_date = Some(start);
+        active.end_date = Some(start + Duration::days(13));
+
+        let mut first = ticket("PROJ-1", StatusCategory::Done);
+        first.story_points = Some(3.0);
+        first.resolved = Some(start + Duration::days(1));
+        let mut second = ticket("PROJ-2", StatusCategory::InProgress);
+        second.story_points = Some(5.0);
+
+        let now = start + Duration::days(2);
+        let progress = SprintService::progress(&active, &[first, second], now).unwrap();
+
+        assert_eq!(progress.days.len(), 3);
+        assert_eq!(progress.total_issues, 2);
+        assert_eq!(progress.done_issues(), 1);
+        assert_eq!(progress.done_points(), 3.0);
+        assert_eq!(progress.remaining_series(), vec![8, 5, 5]);
+
+        assert!(SprintService::progress(&sprint("2", SprintState::Future), &[], now).is_none());
+    }
+
+    #[test]
+    fn test_velocity_ignores_work_finished_after_close() {
+        let closed_at = Utc.with_ymd_and_hms(2024, 3, 14, 17, 0, 0).unwrap();
+        let mut closed = sprint("1", SprintState::Closed);
+        closed.complete_date = Some(closed_at);
+
+        let mut on_time = ticket("PROJ-1", StatusCategory::Done);
+        on_time.story_points = Some(5.0);
+        on_time.resolved = Some(closed_at - Duration::days(2));
+        let mut carried_over = ticket("PROJ-2", StatusCategory::Done);
+        carried_over.story_points = Some(3.0);
+        carried_over.resolved = Some(closed_at + Duration::days(3));
+        let open = ticket("PROJ-3", StatusCategory::ToDo);
+
+        let velocity = SprintService::velocity(&closed, &[on_time, carried_over, open]);
+        assert_eq!(velocity.sprint_name, "Sprint 1");
+        assert_eq!(velocity.completed_issues, 1);
+        assert_eq!(velocity.completed_points, 5.0);
+    }
+
+    #[test]
+    fn test_changelog_lists_resolved_issues() {
+        let mut active = sprint("7", SprintState::Active);
+        active.goal = Some("Ship checkout".to_string());
+        let mut paid = ticket("PROJ-1", StatusCategory::Done);
+        paid.summary = "Pay by card | invoice".to_string();
+        paid.story_points = Some(3.0);
+        paid.assignee = Some(User::new("jane".to_string(), "Jane Doe".to_string()));
+        let mut quoted = ticket("PROJ-2", StatusCategory::Done);
+        quoted.summary = "Say \"hello\", world".to_string();
+        let issues = [paid, quoted, ticket("PROJ-3", StatusCategory::InProgress)];
+
+        assert_eq!(
+            SprintService::changelog(&active, &issues, ChangelogFormat::Markdown),
+            "# Sprint 7 — resolved issues\n\nGoal: Ship checkout\n\n\
+             | Key | Summary | Assignee | Points |\n|---|---|---|---|\n\
+             | PROJ-1 | Pay by card \\| invoice | Jane Doe | 3 |\n\
+             | PROJ-2 | Say \"hello\", world | Unassigned |  |\n\
+             \n2 issues, 3 points\n"
+        );
+        assert_eq!(
+            SprintService::changelog(&active, &issues, ChangelogFormat::Csv),
+            "key,summary,assignee,points\n\
+             PROJ-1,Pay by card | invoice,Jane Doe,3\n\
+             PROJ-2,\"Say \"\"hello\"\", world\",Unassigned,\n"
+        );
+    }
+
+    #[test]
+    fn test_start_window() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        let unplanned = sprint("1", SprintState::Future);
+        assert_eq!(
+            SprintService::start_window(&unplanned, now),
+            (now, now + Duration::days(14))
+        );
+
+        let mut planned = sprint("2", SprintState::Future);
+        planned.start_date = Some(now + Duration::days(1));
+        planned.end_date = Some(now + Duration::days(10));
+        assert_eq!(
+            SprintService::start_window(&planned, now),
+            (now, now + Duration::days(10))
+        );
+    }
+
+    #[test]
+    fn test_restrict_to_sprints() {
+        let sprints = [sprint("12", SprintState::Active), sprint("13", SprintState::Future)];
+        assert_eq!(
+            SprintService::restrict_to_sprints(
+                "assignee = currentUser() order by updated DESC",
+                &sprints
+            ),
+            "sprint in (12, 13) AND (assignee = currentUser()) order by updated DESC"
+        );
+        assert_eq!(
+            SprintService::restrict_to_sprints("ORDER BY rank", &sprints[..1]),
+            "sprint in (12) ORDER BY rank"
+        );
+        assert_eq!(SprintService::restrict_to_sprints("project = X", &[]), "project = X");
+        assert_eq!(
+            SprintService::restrict_to_sprints(
+                "summary ~ \"ılık order by\" ORDER BY updated DESC",
+                &sprints[..1]
+            ),
+            "sprint in (12) AND (summary ~ \"ılık order by\") ORDER BY updated DESC"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use crate::infrastructure::api::client::CreateIssueData;
+use crate::infrastructure::config::TicketTemplate;
+use std::collections::HashMap;
+
+/// Placeholders of a template, each once, in the order they first appear
+pub fn placeholders(template: &TicketTemplate) -> Vec<String> {
+    let mut names = Vec::new();
+    for text in texts(template) {
+        for name in names_in(text) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+/// Placeholders of a template without a value in `values`
+pub fn unresolved(template: &TicketTemplate, values: &HashMap<String, String>) -> Vec<String> {
+    placeholders(template)
+        .into_iter()
+        .filter(|name| !values.contains_key(name))
+        .collect()
+}
+
+/// Issue to create from a template, with its placeholders replaced by
+/// `values`; placeholders without a value are left as written
+pub fn apply(template: &TicketTemplate, values: &HashMap<String, String>) -> CreateIssueData {
+    CreateIssueData {
+        project_key: template.project.clone(),
+        issue_type: template.issue_type.clone().unwrap_or_default(),
+        summary: substitute(&template.summary, values),
+        description: template.description.as_deref().map(|text| substitute(text, values)),
+        assignee: None,
+        priority: None,
+        labels: template.labels.iter().map(|label| substitute(label, values)).collect(),
+        components: Vec::new(),
+    }
+}
+
+/// Replace each `{name}` in `text` that has a value
+pub fn substitute(text: &str, values: &HashMap<String, String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}').map(|end| &after[..end]) {
+            Some(name) if is_name(name) && values.contains_key(name) => {
+                out.push_str(&values[name]);
+                rest = &after[name.len() + 1..];
+            }
+            _ => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn texts(template: &TicketTemplate) -> impl Iterator<Item = &str> {
+    std::iter::once(template.summary.as_str())
+        .chain(template.description.as_deref())
+        .chain(template.labels.iter().map(String::as_str))
+}
+
+fn names_in(text: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        if let Some(name) = rest.find('}').map(|end| &rest[..end]) {
+            if is_name(name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// Braces around anything else, e.g. JSON in a description, are kept as text
+fn is_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholders_are_substituted() {
+        let template = TicketTemplate {
+            project: "PROJ".to_string(),
+            issue_type: Some("Bug".to_string()),
+            summary: "{component}: {title}".to_string(),
+            description: Some("Seen on {branch} at {date}: {\"code\": 1} {title}".to_string()),
+            labels: vec!["{component}".to_string()],
+        };
+        assert_eq!(placeholders(&template), vec!["component", "title", "branch", "date"]);
+
+        let mut values = HashMap::from([
+            ("branch".to_string(), "main".to_string()),
+            ("date".to_string(), "2024-05-01".to_string()),
+        ]);
+        assert_eq!(unresolved(&template, &values), vec!["component", "title"]);
+
+        values.insert("component".to_string(), "API".to_string());
+        values.insert("title".to_string(), "Timeout".to_string());
+        assert!(unresolved(&template, &values).is_empty());
+
+        let data = apply(&template, &values);
+        assert_eq!(data.summary, "API: Timeout");
+        assert_eq!(
+            data.description.as_deref(),
+            Some("Seen on main at 2024-05-01: {\"code\": 1} Timeout")
+        );
+        assert_eq!(data.labels, vec!["API"]);
+        assert_eq!(substitute("{missing} {", &values), "{missing} {");
+    }
+}
@@ -0,0 +1,184 @@
+use crate::domain::models::ticket::Ticket;
+use crate::domain::models::user::User;
+use crate::domain::models::worklog::Worklog;
+use crate::infrastructure::api::ApiClient;
+use crate::utils::text::csv_field;
+use crate::utils::time::DisplayTimezone;
+use crate::utils::Result;
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Most issues with logged work loaded for one week
+const TIMESHEET_ISSUES: usize = 200;
+
+/// Time logged on one issue over a week
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimesheetRow {
+    pub key: String,
+    pub summary: String,
+    /// Seconds logged on each day, Monday first
+    pub seconds: [u64; 7],
+}
+
+impl TimesheetRow {
+    pub fn total(&self) -> u64 {
+        self.seconds.iter().sum()
+    }
+}
+
+/// A user's logged time over a Monday-to-Sunday week, by issue and day
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timesheet {
+    pub week_start: NaiveDate,
+    pub rows: Vec<TimesheetRow>,
+}
+
+impl Timesheet {
+    /// Empty timesheet for the week containing `date`
+    pub fn for_week(date: NaiveDate) -> Self {
+        Self {
+            week_start: date - Duration::days(date.weekday().num_days_from_monday() as i64),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn days(&self) -> [NaiveDate; 7] {
+        std::array::from_fn(|i| self.week_start + Duration::days(i as i64))
+    }
+
+    /// Add the work `author_id` logged on `ticket` during the week, dating
+    /// each worklog by its start in `timezone`; issues with none are left out
+    pub fn add(&mut self, ticket: &Ticket, worklogs: &[Worklog], author_id: &str, timezone: &DisplayTimezone) {
+        let mut seconds = [0; 7];
+        for worklog in worklogs.iter().filter(|worklog| worklog.author.account_id == author_id) {
+            let day = (timezone.date(&worklog.started) - self.week_start).num_days();
+            if let Ok(day) = usize::try_from(day) {
+                if let Some(total) = seconds.get_mut(day) {
+                    *total += worklog.time_spent_seconds;
+                }
+            }
+        }
+        if seconds.iter().any(|&s| s > 0) {
+            self.rows.push(TimesheetRow {
+                key: ticket.key.clone(),
+                summary: ticket.summary.trim().to_string(),
+                seconds,
+            });
+        }
+    }
+
+    /// Seconds logged on each day across all issues
+    pub fn day_totals(&self) -> [u64; 7] {
+        std::array::from_fn(|day| self.rows.iter().map(|row| row.seconds[day]).sum())
+    }
+
+    pub fn total(&self) -> u64 {
+        self.rows.iter().map(TimesheetRow::total).sum()
+    }
+
+    /// The timesheet as CSV: a row per issue with the hours of each day and
+    /// their total, then a row of totals
+    pub fn csv(&self) -> String {
+        let days: Vec<String> = self.days().iter().map(|day| day.to_string()).collect();
+        let mut csv = format!("issue,summary,{},total\n", days.join(","));
+        for row in &self.rows {
+            let hours: Vec<String> = row.seconds.iter().map(|&s| format_hours(s)).collect();
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                row.key,
+                csv_field(&row.summary),
+                hours.join(","),
+                format_hours(row.total())
+            ));
+        }
+        let totals: Vec<String> = self.day_totals().iter().map(|&s| format_hours(s)).collect();
+        csv.push_str(&format!("total,,{},{}\n", totals.join(","), format_hours(self.total())));
+        csv
+    }
+}
+
+/// Seconds as hours with at most two decimals, e.g. "1.5"
+pub fn format_hours(seconds: u64) -> String {
+    let hours = format!("{:.2}", seconds as f64 / 3600.0);
+    hours.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Service for building timesheets from worklogs
+pub struct TimesheetService;
+
+impl TimesheetService {
+    /// Timesheet of `user` for the week containing `date`, from the issues
+    /// they logged work on
+    pub async fn load(
+        client: &dyn ApiClient,
+        user: &User,
+        date: NaiveDate,
+        timezone: &DisplayTimezone,
+    ) -> Result<Timesheet> {
+        let mut timesheet = Timesheet::for_week(date);
+        let days = timesheet.days();
+        // Jira dates worklogs in the user's profile timezone, so take a day
+        // either side and leave the exact cut to `add`
+        let jql = format!(
+            "worklogAuthor = currentUser() AND worklogDate >= \"{}\" AND worklogDate <= \"{}\" ORDER BY key ASC",
+            days[0] - Duration::days(1),
+            days[6] + Duration::days(1)
+        );
+        let result = client.search_issues(&jql, 0, TIMESHEET_ISSUES).await?;
+        for ticket in &result.issues {
+            let worklogs = client.get_worklogs(&ticket.key).await?;
+            timesheet.add(ticket, &worklogs, &user.account_id, timezone);
+        }
+        Ok(timesheet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{Status, StatusCategory};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_timesheet_by_issue_and_day() {
+        let status = Status {
+            id: "3".to_string(),
+            name: "In Progress".to_string(),
+            category: StatusCategory::InProgress,
+        };
+        let me = User::new("me".to_string(), "Jane Doe".to_string());
+        let other = User::new("other".to_string(), "Alex Kim".to_string());
+        let worklog = |author: &User, day: u32, hours: u64| Worklog {
+            id: format!("{}-{}", author.account_id, day),
+            author: author.clone(),
+            started: Utc.with_ymd_and_hms(2024, 1, day, 9, 0, 0).unwrap(),
+            time_spent_seconds: hours * 1800,
+        };
+
+        // Wednesday 2024-01-17 is in the week starting Monday 2024-01-15
+        let mut timesheet = Timesheet::for_week(NaiveDate::from_ymd_opt(2024, 1, 17).unwrap());
+        assert_eq!(timesheet.week_start, NaiveDate::from_ymd_opt(2024, 1, 15).unwrap());
+        let login = Ticket::new("PROJ-1".to_string(), "Fix login, again".to_string(), status.clone());
+        let docs = Ticket::new("PROJ-2".to_string(), "Write docs".to_string(), status.clone());
+        let idle = Ticket::new("PROJ-3".to_string(), "Someone else's".to_string(), status);
+        let timezone = DisplayTimezone::from_config(Some("UTC"));
+        timesheet.add(
+            &login,
+            &[worklog(&me, 15, 3), worklog(&me, 15, 1), worklog(&me, 14, 8), worklog(&other, 16, 8)],
+            "me",
+            &timezone,
+        );
+        timesheet.add(&docs, &[worklog(&me, 21, 1)], "me", &timezone);
+        timesheet.add(&idle, &[worklog(&other, 16, 2)], "me", &timezone);
+
+        assert_eq!(timesheet.rows.len(), 2);
+        assert_eq!(timesheet.rows[0].seconds, [7200, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(timesheet.total(), 9000);
+        assert_eq!(
+            timesheet.csv(),
+            "issue,summary,2024-01-15,2024-01-16,2024-01-17,2024-01-18,2024-01-19,2024-01-20,2024-01-21,total\n\
+             PROJ-1,\"Fix login, again\",2,0,0,0,0,0,0,2\n\
+             PROJ-2,Write docs,0,0,0,0,0,0,0.5,0.5\n\
+             total,,2,0,0,0,0,0,0.5,2.5\n"
+        );
+    }
+}
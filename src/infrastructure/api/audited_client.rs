@@ -0,0 +1,301 @@
+use crate::domain::models::board::{Board, BoardColumnConfig};
+use crate::domain::models::changelog::{FieldChange, StatusChange};
+use crate::domain::models::permission::ProjectPermissions;
+use crate::domain::models::comment::{Comment, CommentOrder, CommentVisibility};
+use crate::domain::models::project::{Project, Version};
+use crate::domain::models::remote_link::RemoteLink;
+use crate::domain::models::issue_property::IssueProperty;
+use crate::domain::models::dev_info::DevInfo;
+use crate::domain::models::sla::Sla;
+use crate::domain::models::approval::Approval;
+use crate::domain::models::worklog::Worklog;
+use crate::domain::models::sprint::{Sprint, SprintState};
+use crate::domain::models::ticket::Ticket;
+use crate::domain::models::user::User;
+use crate::infrastructure::api::client::{
+    ApiClient, CreateIssueData, IssueDetails, LabelChange, RankPosition, SearchResult, Transition,
+    TransitionField, UpdateIssueData,
+};
+use crate::infrastructure::api::request_log::RequestRecord;
+use crate::infrastructure::storage::audit_log::{AuditEntry, AuditLog};
+use crate::utils::time::format_duration;
+use crate::utils::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::sync::Arc;
+
+/// API client wrapper that records every write operation in an audit log;
+/// reads are passed through untouched
+pub struct AuditedApiClient {
+    inner: Arc<dyn ApiClient>,
+    log: Arc<AuditLog>,
+}
+
+impl AuditedApiClient {
+    pub fn new(inner: Arc<dyn ApiClient>, log: Arc<AuditLog>) -> Self {
+        Self { inner, log }
+    }
+
+    /// Log the outcome of a write and hand the result back
+    fn record<T>(&self, target: &str, action: String, result: Result<T>) -> Result<T> {
+        let error = result.as_ref().err().map(|e| e.to_string());
+        self.log.record(AuditEntry::new(target, action, error));
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiClient for AuditedApiClient {
+    async fn get_issue(&self, key: &str) -> Result<Ticket> {
+        self.inner.get_issue(key).await
+    }
+
+    async fn get_issue_details(&self, key: &str) -> Result<IssueDetails> {
+        self.inner.get_issue_details(key).await
+    }
+
+    async fn search_issues(&self, jql: &str, start_at: usize, max_results: usize) -> Result<SearchResult> {
+        self.inner.search_issues(jql, start_at, max_results).await
+    }
+
+    async fn create_issue(&self, data: CreateIssueData) -> Result<Ticket> {
+        let project_key = data.project_key.clone();
+        let result = self.inner.create_issue(data).await;
+        let target = match &result {
+            Ok(ticket) => ticket.key.clone(),
+            Err(_) => project_key,
+        };
+        self.record(&target, "create issue".to_string(), result)
+    }
+
+    async fn update_issue(&self, key: &str, data: UpdateIssueData) -> Result<()> {
+        let mut fields: Vec<&String> = data.fields.keys().collect();
+        fields.sort();
+        let action = format!(
+            "update {}",
+            fields.iter().map(|f| f.as_str()).collect::<Vec<_>>().join(", ")
+        );
+        let result = self.inner.update_issue(key, data).await;
+        self.record(key, action, result)
+    }
+
+    async fn transition_issue(
+        &self,
+        key: &str,
+        transition_id: &str,
+        comment: Option<String>,
+        fields: Option<serde_json::Value>,
+    ) -> Result<()> {
+        let action = format!("transition {}", transition_id);
+        let result = self
+            .inner
+            .transition_issue(key, transition_id, comment, fields)
+            .await;
+        self.record(key, action, result)
+    }
+
+    async fn get_transitions(&self, key: &str) -> Result<Vec<Transition>> {
+        self.inner.get_transitions(key).await
+    }
+
+    async fn get_edit_fields(&self, key: &str) -> Result<Vec<TransitionField>> {
+        self.inner.get_edit_fields(key).await
+    }
+
+    async fn get_create_fields(&self, project_key: &str, issue_type_id: &str) -> Result<Vec<TransitionField>> {
+        self.inner.get_create_fields(project_key, issue_type_id).await
+    }
+
+    async fn get_field_names(&self) -> Result<std::collections::HashMap<String, String>> {
+        self.inner.get_field_names().await
+    }
+
+    async fn add_comment(&self, key: &str, comment: String, visibility: &CommentVisibility) -> Result<()> {
+        let action = format!("add comment ({})", visibility.label());
+        let result = self.inner.add_comment(key, comment, visibility).await;
+        self.record(key, action, result)
+    }
+
+    async fn get_comments(&self, key: &str, order: CommentOrder) -> Result<Vec<Comment>> {
+        self.inner.get_comments(key, order).await
+    }
+
+    async fn download_attachment(&self, content_url: &str) -> Result<Vec<u8>> {
+        self.inner.download_attachment(content_url).await
+    }
+
+    async fn get_project_roles(&self, project_key: &str) -> Result<Vec<String>> {
+        self.inner.get_project_roles(project_key).await
+    }
+
+    async fn get_my_groups(&self) -> Result<Vec<String>> {
+        self.inner.get_my_groups().await
+    }
+
+    async fn get_my_permissions(&self, project_key: &str) -> Result<ProjectPermissions> {
+        self.inner.get_my_permissions(project_key).await
+    }
+
+    async fn count_issues(&self, jql: &str) -> Result<usize> {
+        self.inner.count_issues(jql).await
+    }
+
+    async fn get_myself(&self) -> Result<User> {
+        self.inner.get_myself().await
+    }
+
+    async fn get_status_changes(&self, key: &str) -> Result<Vec<StatusChange>> {
+        self.inner.get_status_changes(key).await
+    }
+
+    async fn get_recent_changes(&self, key: &str) -> Result<Vec<FieldChange>> {
+        self.inner.get_recent_changes(key).await
+    }
+
+    async fn assign_issue(&self, key: &str, account_id: &str) -> Result<()> {
+        let result = self.inner.assign_issue(key, account_id).await;
+        self.record(key, format!("assign to {}", account_id), result)
+    }
+
+    async fn search_assignable_users(&self, key: &str, query: &str) -> Result<Vec<User>> {
+        self.inner.search_assignable_users(key, query).await
+    }
+
+    async fn get_projects(&self) -> Result<Vec<Project>> {
+        self.inner.get_projects().await
+    }
+
+    async fn get_versions(&self, project_key: &str) -> Result<Vec<Version>> {
+        self.inner.get_versions(project_key).await
+    }
+
+    async fn release_version(&self, version_id: &str, release_date: NaiveDate) -> Result<()> {
+        let result = self.inner.release_version(version_id, release_date).await;
+        self.record(&format!("version {}", version_id), "release version".to_string(), result)
+    }
+
+    async fn move_issue(&self, key: &str, project_key: &str, issue_type_id: &str) -> Result<Ticket> {
+        let result = self.inner.move_issue(key, project_key, issue_type_id).await;
+        let action = match &result {
+            Ok(moved) => format!("move to {}", moved.key),
+            Err(_) => format!("move to {}", project_key),
+        };
+        self.record(key, action, result)
+    }
+
+    async fn get_boards(&self) -> Result<Vec<Board>> {
+        self.inner.get_boards().await
+    }
+
+    async fn get_board_columns(&self, board_id: &str) -> Result<Vec<BoardColumnConfig>> {
+        self.inner.get_board_columns(board_id).await
+    }
+
+    async fn get_sprints(&self, board_id: &str, states: &[SprintState]) -> Result<Vec<Sprint>> {
+        self.inner.get_sprints(board_id, states).await
+    }
+
+    async fn get_sprint_issues(&self, sprint_id: &str) -> Result<Vec<Ticket>> {
+        self.inner.get_sprint_issues(sprint_id).await
+    }
+
+    async fn start_sprint(
+        &self,
+        sprint_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<()> {
+        let result = self.inner.start_sprint(sprint_id, start_date, end_date).await;
+        self.record(&format!("sprint {}", sprint_id), "start sprint".to_string(), result)
+    }
+
+    async fn complete_sprint(&self, sprint_id: &str) -> Result<()> {
+        let result = self.inner.complete_sprint(sprint_id).await;
+        self.record(&format!("sprint {}", sprint_id), "complete sprint".to_string(), result)
+    }
+
+    async fn move_issues_to_sprint(&self, sprint_id: &str, keys: &[String]) -> Result<()> {
+        let result = self.inner.move_issues_to_sprint(sprint_id, keys).await;
+        self.record(&keys.join(", "), format!("move to sprint {}", sprint_id), result)
+    }
+
+    async fn move_issues_to_backlog(&self, keys: &[String]) -> Result<()> {
+        let result = self.inner.move_issues_to_backlog(keys).await;
+        self.record(&keys.join(", "), "move to backlog".to_string(), result)
+    }
+
+    async fn get_backlog(&self, board_id: &str) -> Result<Vec<Ticket>> {
+        self.inner.get_backlog(board_id).await
+    }
+
+    async fn rank_issue(&self, key: &str, position: RankPosition) -> Result<()> {
+        let action = match &position {
+            RankPosition::Before(other) => format!("rank before {}", other),
+            RankPosition::After(other) => format!("rank after {}", other),
+        };
+        let result = self.inner.rank_issue(key, position).await;
+        self.record(key, action, result)
+    }
+
+    async fn change_label(&self, key: &str, change: &LabelChange) -> Result<()> {
+        let action = match change {
+            LabelChange::Add(label) => format!("add label '{}'", label),
+            LabelChange::Remove(label) => format!("remove label '{}'", label),
+        };
+        let result = self.inner.change_label(key, change).await;
+        self.record(key, action, result)
+    }
+
+    async fn get_remote_links(&self, key: &str) -> Result<Vec<RemoteLink>> {
+        self.inner.get_remote_links(key).await
+    }
+
+    async fn get_issue_properties(&self, key: &str) -> Result<Vec<IssueProperty>> {
+        self.inner.get_issue_properties(key).await
+    }
+
+    async fn get_dev_info(&self, issue_id: &str) -> Result<DevInfo> {
+        self.inner.get_dev_info(issue_id).await
+    }
+
+    async fn get_slas(&self, key: &str) -> Result<Vec<Sla>> {
+        self.inner.get_slas(key).await
+    }
+
+    async fn get_approvals(&self, key: &str) -> Result<Vec<Approval>> {
+        self.inner.get_approvals(key).await
+    }
+
+    async fn answer_approval(&self, key: &str, approval_id: &str, approve: bool) -> Result<()> {
+        let result = self.inner.answer_approval(key, approval_id, approve).await;
+        let decision = if approve { "approve" } else { "decline" };
+        self.record(key, format!("{} approval {}", decision, approval_id), result)
+    }
+
+    async fn get_worklogs(&self, key: &str) -> Result<Vec<Worklog>> {
+        self.inner.get_worklogs(key).await
+    }
+
+    async fn add_worklog(
+        &self,
+        key: &str,
+        started: DateTime<Utc>,
+        time_spent_seconds: u64,
+        comment: Option<&str>,
+    ) -> Result<()> {
+        let result = self.inner.add_worklog(key, started, time_spent_seconds, comment).await;
+        self.record(key, format!("log {}", format_duration(time_spent_seconds)), result)
+    }
+
+    async fn get_confluence_page_title(&self, page_url: &str) -> Result<Option<String>> {
+        self.inner.get_confluence_page_title(page_url).await
+    }
+
+    async fn add_remote_link(&self, key: &str, title: &str, url: &str) -> Result<()> {
+        let result = self.inner.add_remote_link(key, title, url).await;
+        self.record(key, format!("add web link '{}'", title), result)
+    }
+
+    fn recent_requests(&self) -> Vec<RequestRecord> {
+        self.inner.recent_requests()
+    }
+}
@@ -1,5 +1,20 @@
+use crate::domain::models::board::{Board, BoardColumnConfig};
+use crate::domain::models::changelog::{FieldChange, StatusChange};
+use crate::domain::models::project::{Project, Version};
+use crate::domain::models::remote_link::RemoteLink;
+use crate::domain::models::issue_property::IssueProperty;
+use crate::domain::models::dev_info::DevInfo;
+use crate::domain::models::sla::Sla;
+use crate::domain::models::approval::Approval;
+use crate::domain::models::worklog::Worklog;
+use crate::domain::models::permission::ProjectPermissions;
+use crate::domain::models::sprint::{Sprint, SprintState};
 use crate::domain::models::ticket::Ticket;
+use crate::domain::models::user::User;
+use crate::infrastructure::api::request_log::RequestRecord;
+use crate::infrastructure::config::ProjectDefaults;
 use crate::utils::Result;
+use chrono::{DateTime, NaiveDate, Utc};
 
 /// Trait for API client implementations
 #[async_trait::async_trait]
@@ -14,6 +29,9 @@ pub trait ApiClient: Send + Sync {
         start_at: usize,
         max_results: usize,
     ) -> Result<SearchResult>;
+
+    /// Number of issues matching a JQL query, without fetching them
+    async fn count_issues(&self, jql: &str) -> Result<usize>;
     
     /// Create a new issue
     #[allow(dead_code)] // Will be used when ticket creation UI is implemented
@@ -23,25 +41,212 @@ pub trait ApiClient: Send + Sync {
     #[allow(dead_code)] // Will be used when update feature is implemented
     async fn update_issue(&self, key: &str, data: UpdateIssueData) -> Result<()>;
     
-    /// Transition an issue to a new status
-    #[allow(dead_code)] // Will be used when transitions are implemented
+    /// Transition an issue to a new status, setting any screen fields
+    /// (e.g. `{"resolution": {"id": "10000"}}`) and adding an optional comment
     async fn transition_issue(
         &self,
         key: &str,
         transition_id: &str,
         comment: Option<String>,
+        fields: Option<serde_json::Value>,
     ) -> Result<()>;
     
-    /// Get available transitions for an issue
-    #[allow(dead_code)] // Will be used when transitions are implemented
+    /// Get available transitions for an issue, with the fields their screens ask for
     async fn get_transitions(&self, key: &str) -> Result<Vec<Transition>>;
+
+    /// Fields of an issue the current user may edit, from its edit metadata
+    async fn get_edit_fields(&self, key: &str) -> Result<Vec<TransitionField>>;
+
+    /// Fields of the create screen for an issue type of a project
+    async fn get_create_fields(&self, project_key: &str, issue_type_id: &str) -> Result<Vec<TransitionField>>;
+
+    /// Names of all fields, custom ones included, by field id
+    async fn get_field_names(&self) -> Result<std::collections::HashMap<String, String>>;
     
-    /// Add a comment to an issue
-    #[allow(dead_code)] // Will be used when commenting is implemented
-    async fn add_comment(&self, key: &str, comment: String) -> Result<()>;
+    /// Add a comment to an issue, optionally restricted to a role or group or
+    /// marked internal
+    async fn add_comment(
+        &self,
+        key: &str,
+        comment: String,
+        visibility: &crate::domain::models::comment::CommentVisibility,
+    ) -> Result<()>;
     
-    /// Get comments for an issue
-    async fn get_comments(&self, key: &str) -> Result<Vec<crate::domain::models::comment::Comment>>;
+    /// Get comments for an issue, in the given order
+    async fn get_comments(
+        &self,
+        key: &str,
+        order: crate::domain::models::comment::CommentOrder,
+    ) -> Result<Vec<crate::domain::models::comment::Comment>>;
+
+    /// Download an attachment's content from its `content_url`
+    async fn download_attachment(&self, content_url: &str) -> Result<Vec<u8>>;
+
+    /// Get the names of a project's roles
+    async fn get_project_roles(&self, project_key: &str) -> Result<Vec<String>>;
+
+    /// Get the names of the current user's groups
+    async fn get_my_groups(&self) -> Result<Vec<String>>;
+
+    /// Which of the app's actions the current user may take in a project
+    async fn get_my_permissions(&self, project_key: &str) -> Result<ProjectPermissions>;
+
+    /// The signed-in user
+    async fn get_myself(&self) -> Result<User>;
+
+    /// Get the status transitions of an issue from its changelog, oldest first
+    async fn get_status_changes(&self, key: &str) -> Result<Vec<StatusChange>>;
+
+    /// Latest changes to any of an issue's fields, with who made them
+    async fn get_recent_changes(&self, key: &str) -> Result<Vec<FieldChange>>;
+
+    /// Assign an issue to a user
+    async fn assign_issue(&self, key: &str, account_id: &str) -> Result<()>;
+
+    /// Search users that can be assigned to an issue
+    async fn search_assignable_users(&self, key: &str, query: &str) -> Result<Vec<User>>;
+
+    /// Get projects visible to the current user with their issue types
+    async fn get_projects(&self) -> Result<Vec<Project>>;
+
+    /// Get the unarchived versions of a project
+    async fn get_versions(&self, project_key: &str) -> Result<Vec<Version>>;
+
+    /// Mark a version released on the given date
+    async fn release_version(&self, version_id: &str, release_date: NaiveDate) -> Result<()>;
+
+    /// Move an issue to another project and issue type, returning it under its new key
+    async fn move_issue(&self, key: &str, project_key: &str, issue_type_id: &str) -> Result<Ticket>;
+
+    /// Get boards visible to the current user
+    async fn get_boards(&self) -> Result<Vec<Board>>;
+
+    /// Get the column-to-status mapping of a board, left to right
+    async fn get_board_columns(&self, board_id: &str) -> Result<Vec<BoardColumnConfig>>;
+
+    /// Get the sprints of a board in the given states, oldest first
+    async fn get_sprints(&self, board_id: &str, states: &[SprintState]) -> Result<Vec<Sprint>>;
+
+    /// Get the issues in a sprint
+    async fn get_sprint_issues(&self, sprint_id: &str) -> Result<Vec<Ticket>>;
+
+    /// Start a future sprint
+    async fn start_sprint(
+        &self,
+        sprint_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<()>;
+
+    /// Complete an active sprint
+    async fn complete_sprint(&self, sprint_id: &str) -> Result<()>;
+
+    /// Move issues into a sprint
+    async fn move_issues_to_sprint(&self, sprint_id: &str, keys: &[String]) -> Result<()>;
+
+    /// Move issues back to the backlog
+    async fn move_issues_to_backlog(&self, keys: &[String]) -> Result<()>;
+
+    /// Get the backlog of a board in rank order
+    async fn get_backlog(&self, board_id: &str) -> Result<Vec<Ticket>>;
+
+    /// Rank an issue relative to another issue
+    async fn rank_issue(&self, key: &str, position: RankPosition) -> Result<()>;
+
+    /// Add or remove a single label on an issue, leaving its other labels untouched
+    async fn change_label(&self, key: &str, change: &LabelChange) -> Result<()>;
+
+    /// Get the web links attached to an issue
+    async fn get_remote_links(&self, key: &str) -> Result<Vec<RemoteLink>>;
+
+    /// Get the entity properties stored on an issue, e.g. by automation
+    /// rules and apps
+    async fn get_issue_properties(&self, key: &str) -> Result<Vec<IssueProperty>>;
+
+    /// Attach a web link to an issue
+    async fn add_remote_link(&self, key: &str, title: &str, url: &str) -> Result<()>;
+
+    /// Branches, commits and pull requests linked to an issue by its id
+    async fn get_dev_info(&self, issue_id: &str) -> Result<DevInfo>;
+
+    /// Service level agreements of a Jira Service Management request
+    async fn get_slas(&self, key: &str) -> Result<Vec<Sla>>;
+
+    /// Approvals of a Jira Service Management request
+    async fn get_approvals(&self, key: &str) -> Result<Vec<Approval>>;
+
+    /// Approve or decline one of a request's approvals as the current user
+    async fn answer_approval(&self, key: &str, approval_id: &str, approve: bool) -> Result<()>;
+
+    /// Time logged against an issue, by anyone
+    async fn get_worklogs(&self, key: &str) -> Result<Vec<Worklog>>;
+
+    /// Log time spent on an issue as the current user
+    async fn add_worklog(
+        &self,
+        key: &str,
+        started: DateTime<Utc>,
+        time_spent_seconds: u64,
+        comment: Option<&str>,
+    ) -> Result<()>;
+
+    /// Get an issue with its status changes and the transitions it offers,
+    /// as the detail view shows them
+    async fn get_issue_details(&self, key: &str) -> Result<IssueDetails> {
+        let (ticket, changes, transitions) = tokio::join!(
+            self.get_issue(key),
+            self.get_status_changes(key),
+            self.get_transitions(key)
+        );
+        Ok(IssueDetails {
+            ticket: ticket?,
+            changes: changes?,
+            transitions: transitions?,
+        })
+    }
+
+    /// Title of the Confluence page at `page_url`; None when the page is not
+    /// on this client's site, so its credentials do not cover it
+    async fn get_confluence_page_title(&self, _page_url: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Recent HTTP requests, oldest first; empty for clients that do not track them
+    fn recent_requests(&self) -> Vec<RequestRecord> {
+        Vec::new()
+    }
+}
+
+/// Where to rank an issue relative to another one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RankPosition {
+    Before(String),
+    After(String),
+}
+
+/// A label edit applied to an issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LabelChange {
+    Add(String),
+    Remove(String),
+}
+
+impl LabelChange {
+    /// The label being added or removed
+    pub fn label(&self) -> &str {
+        match self {
+            LabelChange::Add(label) | LabelChange::Remove(label) => label,
+        }
+    }
+}
+
+/// An issue with its status history and available transitions
+#[derive(Debug, Clone)]
+pub struct IssueDetails {
+    pub ticket: Ticket,
+    /// Status changes, oldest first
+    pub changes: Vec<StatusChange>,
+    pub transitions: Vec<Transition>,
 }
 
 /// Search result with pagination
@@ -80,20 +285,91 @@ pub struct CreateIssueData {
     pub assignee: Option<String>,
     #[allow(dead_code)] // Will be used when create issue is fully implemented
     pub priority: Option<String>,
+    pub labels: Vec<String>,
+    /// Component names
+    pub components: Vec<String>,
+}
+
+impl CreateIssueData {
+    /// Fill in the project's configured issue type, labels and component
+    /// where nothing was chosen
+    pub fn with_project_defaults(mut self, defaults: &ProjectDefaults) -> Self {
+        if self.issue_type.trim().is_empty() {
+            if let Some(issue_type) = &defaults.issue_type {
+                self.issue_type = issue_type.clone();
+            }
+        }
+        for label in &defaults.labels {
+            if !self.labels.contains(label) {
+                self.labels.push(label.clone());
+            }
+        }
+        if self.components.is_empty() {
+            self.components.extend(defaults.component.clone());
+        }
+        self
+    }
 }
 
 /// Data for updating an issue
-#[derive(Debug, Clone)]
-#[allow(dead_code)] // Will be used when update feature is implemented
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UpdateIssueData {
     pub fields: std::collections::HashMap<String, serde_json::Value>,
 }
 
+impl UpdateIssueData {
+    /// Set the reporter; Jira only allows this with the Modify Reporter permission
+    pub fn reporter(account_id: &str) -> Self {
+        Self {
+            fields: std::collections::HashMap::from([(
+                "reporter".to_string(),
+                serde_json::json!({ "accountId": account_id }),
+            )]),
+        }
+    }
+}
+
 /// Available transition for an issue
-#[derive(Debug, Clone)]
-#[allow(dead_code)] // Will be used when transitions are implemented
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Transition {
     pub id: String,
     pub name: String,
     pub to_status: String,
+    /// Fields on the transition screen; empty when it has no screen
+    pub fields: Vec<TransitionField>,
+}
+
+/// A field on a transition or edit screen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionField {
+    /// Field id used in the transition request, e.g. "resolution" or "customfield_10020"
+    pub key: String,
+    pub name: String,
+    pub required: bool,
+    pub kind: FieldKind,
+}
+
+/// How a screen field is edited and sent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldKind {
+    /// One of the allowed values; `multiple` fields are sent as a one-element array
+    Select {
+        options: Vec<FieldOption>,
+        multiple: bool,
+    },
+    Text,
+    Number,
+    /// A date sent as YYYY-MM-DD
+    Date,
+    /// A user picked from the ones assignable to the issue
+    User,
+    /// Added as a comment rather than set as a field
+    Comment,
+}
+
+/// An allowed value of a select field, such as a resolution or fix version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldOption {
+    pub id: String,
+    pub name: String,
 }
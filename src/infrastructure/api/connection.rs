@@ -90,12 +90,10 @@ impl ConnectionValidator {
             ));
         }
 
-        if config.auth.auth_type == "api-token" {
-            if config.auth.token.is_none() {
-                return Err(LazyJiraError::Config(
-                    "API token is required for api-token authentication".to_string()
-                ));
-            }
+        if config.auth.auth_type == "api-token" && config.auth.token.is_none() {
+            return Err(LazyJiraError::Config(
+                "API token is required for api-token authentication".to_string()
+            ));
         }
 
         Ok(())
@@ -170,6 +168,7 @@ mod tests {
             _key: &str,
             _transition_id: &str,
             _comment: Option<String>,
+            _fields: Option<serde_json::Value>,
         ) -> Result<()> {
             Err(LazyJiraError::Internal("Not implemented".to_string()))
         }
@@ -178,13 +177,238 @@ mod tests {
             Err(LazyJiraError::Internal("Not implemented".to_string()))
         }
 
-        async fn add_comment(&self, _key: &str, _comment: String) -> Result<()> {
+        async fn get_edit_fields(
+            &self,
+            _key: &str,
+        ) -> Result<Vec<crate::infrastructure::api::client::TransitionField>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_create_fields(
+            &self,
+            _project_key: &str,
+            _issue_type_id: &str,
+        ) -> Result<Vec<crate::infrastructure::api::client::TransitionField>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_field_names(&self) -> Result<std::collections::HashMap<String, String>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn add_comment(&self, _key: &str, _comment: String, _visibility: &crate::domain::models::comment::CommentVisibility) -> Result<()> {
             Err(LazyJiraError::Internal("Not implemented".to_string()))
         }
 
-        async fn get_comments(&self, _key: &str) -> Result<Vec<crate::domain::models::comment::Comment>> {
+        async fn get_comments(
+            &self,
+            _key: &str,
+            _order: crate::domain::models::comment::CommentOrder,
+        ) -> Result<Vec<crate::domain::models::comment::Comment>> {
             Ok(vec![])
         }
+
+        async fn get_boards(&self) -> Result<Vec<crate::domain::models::board::Board>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_sprints(
+            &self,
+            _board_id: &str,
+            _states: &[crate::domain::models::sprint::SprintState],
+        ) -> Result<Vec<crate::domain::models::sprint::Sprint>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_sprint_issues(&self, _sprint_id: &str) -> Result<Vec<Ticket>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn start_sprint(
+            &self,
+            _sprint_id: &str,
+            _start_date: chrono::DateTime<chrono::Utc>,
+            _end_date: chrono::DateTime<chrono::Utc>,
+        ) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn complete_sprint(&self, _sprint_id: &str) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn move_issues_to_sprint(&self, _sprint_id: &str, _keys: &[String]) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn move_issues_to_backlog(&self, _keys: &[String]) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_backlog(&self, _board_id: &str) -> Result<Vec<Ticket>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn rank_issue(
+            &self,
+            _key: &str,
+            _position: crate::infrastructure::api::client::RankPosition,
+        ) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_status_changes(
+            &self,
+            _key: &str,
+        ) -> Result<Vec<crate::domain::models::changelog::StatusChange>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_recent_changes(
+            &self,
+            _key: &str,
+        ) -> Result<Vec<crate::domain::models::changelog::FieldChange>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_board_columns(
+            &self,
+            _board_id: &str,
+        ) -> Result<Vec<crate::domain::models::board::BoardColumnConfig>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn assign_issue(&self, _key: &str, _account_id: &str) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn search_assignable_users(
+            &self,
+            _key: &str,
+            _query: &str,
+        ) -> Result<Vec<crate::domain::models::user::User>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_projects(&self) -> Result<Vec<crate::domain::models::project::Project>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_versions(
+            &self,
+            _project_key: &str,
+        ) -> Result<Vec<crate::domain::models::project::Version>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn release_version(
+            &self,
+            _version_id: &str,
+            _release_date: chrono::NaiveDate,
+        ) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn move_issue(
+            &self,
+            _key: &str,
+            _project_key: &str,
+            _issue_type_id: &str,
+        ) -> Result<Ticket> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn change_label(
+            &self,
+            _key: &str,
+            _change: &crate::infrastructure::api::client::LabelChange,
+        ) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_remote_links(
+            &self,
+            _key: &str,
+        ) -> Result<Vec<crate::domain::models::remote_link::RemoteLink>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_issue_properties(
+            &self,
+            _key: &str,
+        ) -> Result<Vec<crate::domain::models::issue_property::IssueProperty>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_dev_info(
+            &self,
+            _issue_id: &str,
+        ) -> Result<crate::domain::models::dev_info::DevInfo> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_slas(&self, _key: &str) -> Result<Vec<crate::domain::models::sla::Sla>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_approvals(
+            &self,
+            _key: &str,
+        ) -> Result<Vec<crate::domain::models::approval::Approval>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn answer_approval(&self, _key: &str, _approval_id: &str, _approve: bool) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_worklogs(
+            &self,
+            _key: &str,
+        ) -> Result<Vec<crate::domain::models::worklog::Worklog>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn add_worklog(
+            &self,
+            _key: &str,
+            _started: chrono::DateTime<chrono::Utc>,
+            _time_spent_seconds: u64,
+            _comment: Option<&str>,
+        ) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn add_remote_link(&self, _key: &str, _title: &str, _url: &str) -> Result<()> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_project_roles(&self, _project_key: &str) -> Result<Vec<String>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_my_groups(&self) -> Result<Vec<String>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_my_permissions(
+            &self,
+            _project_key: &str,
+        ) -> Result<crate::domain::models::permission::ProjectPermissions> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn count_issues(&self, _jql: &str) -> Result<usize> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn get_myself(&self) -> Result<crate::domain::models::user::User> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
+
+        async fn download_attachment(&self, _content_url: &str) -> Result<Vec<u8>> {
+            Err(LazyJiraError::Internal("Not implemented".to_string()))
+        }
     }
 
     #[tokio::test]
@@ -0,0 +1,791 @@
+use super::client::{
+    ApiClient, CreateIssueData, FieldKind, FieldOption, LabelChange, RankPosition, SearchResult,
+    Transition, TransitionField, UpdateIssueData,
+};
+use super::parser::{parse_issue, parse_search_results};
+use crate::domain::models::board::{Board, BoardColumnConfig, BoardType};
+use crate::domain::models::changelog::{FieldChange, StatusChange};
+use crate::domain::models::permission::ProjectPermissions;
+use crate::domain::models::comment::{Comment, CommentOrder, CommentVisibility};
+use crate::domain::models::project::{IssueType, Project, Version};
+use crate::domain::models::remote_link::RemoteLink;
+use crate::domain::models::issue_property::IssueProperty;
+use crate::domain::models::dev_info::DevInfo;
+use crate::domain::models::sla::Sla;
+use crate::domain::models::approval::Approval;
+use crate::domain::models::worklog::Worklog;
+use crate::domain::models::sprint::{Sprint, SprintState};
+use crate::domain::models::ticket::{Priority, Status, StatusCategory, Ticket};
+use crate::domain::models::user::User;
+use crate::utils::{JiraApiError, LazyJiraError, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+/// Sample responses kept with the API client tests, seeding the demo
+const SEARCH_FIXTURE: &str = include_str!("../../../tests/fixtures/search.json");
+const ISSUE_FIXTURE: &str = include_str!("../../../tests/fixtures/issue.json");
+
+/// Workflow of the demo project: transition id, status id, name and category
+const WORKFLOW: [(&str, &str, &str, StatusCategory); 3] = [
+    ("11", "1", "To Do", StatusCategory::ToDo),
+    ("21", "3", "In Progress", StatusCategory::InProgress),
+    ("31", "10001", "Done", StatusCategory::Done),
+];
+
+const DEMO_BOARD_ID: &str = "1";
+const DEMO_SPRINT_ID: &str = "1";
+
+/// In-memory Jira used by `--demo`, seeded from the test fixtures; writes
+/// change the in-memory data so the UI behaves as it would against Jira
+pub struct DemoApiClient {
+    state: Mutex<DemoState>,
+}
+
+struct DemoState {
+    tickets: Vec<Ticket>,
+    comments: HashMap<String, Vec<Comment>>,
+    remote_links: HashMap<String, Vec<RemoteLink>>,
+    worklogs: HashMap<String, Vec<Worklog>>,
+    /// Changelog of each ticket, newest first
+    changes: HashMap<String, Vec<FieldChange>>,
+    users: Vec<User>,
+    sprints: Vec<Sprint>,
+    /// Keys of the tickets in the demo sprint; the rest are in the backlog
+    sprint_keys: HashSet<String>,
+}
+
+impl DemoApiClient {
+    pub fn new() -> Result<Self> {
+        let search: serde_json::Value = serde_json::from_str(SEARCH_FIXTURE)
+            .map_err(|e| LazyJiraError::Parse(format!("Invalid demo fixture: {}", e)))?;
+        let (_, _, _, mut tickets) = parse_search_results(&search)?;
+
+        // The single-issue fixture carries the fuller version of its ticket
+        let issue: serde_json::Value = serde_json::from_str(ISSUE_FIXTURE)
+            .map_err(|e| LazyJiraError::Parse(format!("Invalid demo fixture: {}", e)))?;
+        let detailed = parse_issue(&issue)?;
+        match tickets.iter_mut().find(|t| t.key == detailed.key) {
+            Some(ticket) => *ticket = detailed,
+            None => tickets.push(detailed),
+        }
+
+        let mut users: Vec<User> = tickets.iter().filter_map(|t| t.assignee.clone()).collect();
+        users.push(User::new("demo-jane".to_string(), "Jane Smith".to_string()));
+        users.push(User::new("demo-alex".to_string(), "Alex Kim".to_string()));
+        users.dedup_by(|a, b| a.account_id == b.account_id);
+
+        let now = Utc::now();
+        let sprint_keys = tickets
+            .iter()
+            .filter(|t| !t.is_todo())
+            .map(|t| t.key.clone())
+            .collect();
+        let comments = tickets
+            .first()
+            .map(|ticket| {
+                let comment = Comment {
+                    id: "1".to_string(),
+                    author: users[0].clone(),
+                    body: "Reproduced on the login page, looking into it.".to_string(),
+                    created: ticket.updated,
+                    updated: None,
+                    visibility: CommentVisibility::Public,
+                    mentions: Vec::new(),
+                };
+                HashMap::from([(ticket.key.clone(), vec![comment])])
+            })
+            .unwrap_or_default();
+        // The demo user logged a couple of hours a day on the work in progress
+        let worklogs = tickets
+            .iter()
+            .filter(|t| t.is_in_progress())
+            .map(|ticket| {
+                let worklogs = (0..3)
+                    .map(|days_ago| Worklog {
+                        id: format!("{}-{}", ticket.id, days_ago),
+                        author: users[0].clone(),
+                        started: now - Duration::days(days_ago),
+                        time_spent_seconds: 7200,
+                    })
+                    .collect();
+                (ticket.key.clone(), worklogs)
+            })
+            .collect();
+        // and started it a few hours ago
+        let changes = tickets
+            .iter()
+            .filter(|t| t.is_in_progress())
+            .map(|ticket| {
+                let change = FieldChange {
+                    at: now - Duration::hours(3),
+                    author: Some(users[0].clone()),
+                    field: "status".to_string(),
+                    from: Some("To Do".to_string()),
+                    to: Some(ticket.status.name.clone()),
+                };
+                (ticket.key.clone(), vec![change])
+            })
+            .collect();
+
+        Ok(Self {
+            state: Mutex::new(DemoState {
+                tickets,
+                comments,
+                remote_links: HashMap::new(),
+                worklogs,
+                changes,
+                users,
+                sprints: vec![Sprint {
+                    id: DEMO_SPRINT_ID.to_string(),
+                    name: "Demo Sprint 1".to_string(),
+                    state: SprintState::Active,
+                    start_date: Some(now - Duration::days(4)),
+                    end_date: Some(now + Duration::days(10)),
+                    complete_date: None,
+                    goal: Some("Try out LazyJira".to_string()),
+                }],
+                sprint_keys,
+            }),
+        })
+    }
+
+    fn state(&self) -> Result<std::sync::MutexGuard<'_, DemoState>> {
+        self.state
+            .lock()
+            .map_err(|_| LazyJiraError::Internal("Demo data is unavailable".to_string()))
+    }
+}
+
+impl DemoState {
+    fn ticket_mut(&mut self, key: &str) -> Result<&mut Ticket> {
+        self.tickets
+            .iter_mut()
+            .find(|t| t.key == key)
+            .ok_or_else(|| not_found(key))
+    }
+
+    /// Add a change by the demo user to the top of a ticket's changelog
+    fn record_change(&mut self, key: &str, field: &str, from: Option<String>, to: Option<String>) {
+        let change = FieldChange {
+            at: Utc::now(),
+            author: self.users.first().cloned(),
+            field: field.to_string(),
+            from,
+            to,
+        };
+        self.changes.entry(key.to_string()).or_default().insert(0, change);
+    }
+}
+
+fn not_found(key: &str) -> LazyJiraError {
+    LazyJiraError::Jira(JiraApiError {
+        status: 404,
+        messages: vec![format!("Issue {} does not exist", key)],
+        field_errors: Default::default(),
+    })
+}
+
+fn unsupported(action: &str) -> LazyJiraError {
+    LazyJiraError::Validation(format!("{} is not available in demo mode", action))
+}
+
+fn transitions_from(status_id: &str) -> Vec<Transition> {
+    WORKFLOW
+        .iter()
+        .filter(|(_, id, _, _)| *id != status_id)
+        .map(|(transition_id, _, name, category)| Transition {
+            id: transition_id.to_string(),
+            name: name.to_string(),
+            to_status: name.to_string(),
+            fields: if *category == StatusCategory::Done {
+                vec![TransitionField {
+                    key: "resolution".to_string(),
+                    name: "Resolution".to_string(),
+                    required: true,
+                    kind: FieldKind::Select {
+                        options: vec![
+                            FieldOption {
+                                id: "1".to_string(),
+                                name: "Done".to_string(),
+                            },
+                            FieldOption {
+                                id: "2".to_string(),
+                                name: "Won't Do".to_string(),
+                            },
+                        ],
+                        multiple: false,
+                    },
+                }]
+            } else {
+                Vec::new()
+            },
+        })
+        .collect()
+}
+
+/// Priorities offered by the demo edit form, with Jira's default ids
+const PRIORITIES: [(&str, Priority); 5] = [
+    ("5", Priority::Highest),
+    ("4", Priority::High),
+    ("3", Priority::Medium),
+    ("2", Priority::Low),
+    ("1", Priority::Lowest),
+];
+
+/// Custom field of the demo issues, with the teams it offers
+const TEAM_FIELD: &str = "customfield_10201";
+const TEAMS: [(&str, &str); 2] = [("10", "Platform"), ("11", "Mobile")];
+
+/// Fields the demo edit form offers
+fn edit_fields() -> Vec<TransitionField> {
+    let field = |key: &str, name: &str, required: bool, kind: FieldKind| TransitionField {
+        key: key.to_string(),
+        name: name.to_string(),
+        required,
+        kind,
+    };
+    vec![
+        field("summary", "Summary", true, FieldKind::Text),
+        field("assignee", "Assignee", false, FieldKind::User),
+        field("duedate", "Due date", false, FieldKind::Date),
+        field(
+            "priority",
+            "Priority",
+            false,
+            FieldKind::Select {
+                options: PRIORITIES
+                    .iter()
+                    .map(|(id, priority)| FieldOption {
+                        id: id.to_string(),
+                        name: format!("{:?}", priority),
+                    })
+                    .collect(),
+                multiple: false,
+            },
+        ),
+        field(
+            TEAM_FIELD,
+            "Team",
+            false,
+            FieldKind::Select {
+                options: TEAMS
+                    .iter()
+                    .map(|(id, name)| FieldOption {
+                        id: id.to_string(),
+                        name: name.to_string(),
+                    })
+                    .collect(),
+                multiple: false,
+            },
+        ),
+    ]
+}
+
+#[async_trait::async_trait]
+impl ApiClient for DemoApiClient {
+    async fn get_issue(&self, key: &str) -> Result<Ticket> {
+        let state = self.state()?;
+        state
+            .tickets
+            .iter()
+            .find(|t| t.key == key)
+            .cloned()
+            .ok_or_else(|| not_found(key))
+    }
+
+    async fn search_issues(&self, _jql: &str, start_at: usize, max_results: usize) -> Result<SearchResult> {
+        let state = self.state()?;
+        Ok(SearchResult {
+            start_at,
+            max_results,
+            total: state.tickets.len(),
+            issues: state
+                .tickets
+                .iter()
+                .skip(start_at)
+                .take(max_results)
+                .cloned()
+                .collect(),
+        })
+    }
+
+    async fn create_issue(&self, data: CreateIssueData) -> Result<Ticket> {
+        let mut state = self.state()?;
+        let number = state
+            .tickets
+            .iter()
+            .filter_map(|t| t.key.rsplit('-').next()?.parse::<u64>().ok())
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let (_, id, name, category) = &WORKFLOW[0];
+        let mut ticket = Ticket::new(
+            format!("{}-{}", data.project_key, number),
+            data.summary,
+            Status {
+                id: id.to_string(),
+                name: name.to_string(),
+                category: category.clone(),
+            },
+        );
+        ticket.description = data.description;
+        state.tickets.push(ticket.clone());
+        Ok(ticket)
+    }
+
+    async fn update_issue(&self, key: &str, data: UpdateIssueData) -> Result<()> {
+        let mut state = self.state()?;
+        let reporter = data
+            .fields
+            .get("reporter")
+            .and_then(|reporter| reporter["accountId"].as_str())
+            .and_then(|id| state.users.iter().find(|user| user.account_id == id).cloned());
+        let assignee = data
+            .fields
+            .get("assignee")
+            .and_then(|assignee| assignee["accountId"].as_str())
+            .and_then(|id| state.users.iter().find(|user| user.account_id == id).cloned());
+        let ticket = state.ticket_mut(key)?;
+        if reporter.is_some() {
+            ticket.reporter = reporter;
+        }
+        if assignee.is_some() {
+            ticket.assignee = assignee;
+        }
+        if let Some(summary) = data.fields.get("summary").and_then(|v| v.as_str()) {
+            ticket.summary = summary.to_string();
+        }
+        if let Some(due) = data.fields.get("duedate").and_then(|v| v.as_str()) {
+            ticket.due_date = NaiveDate::parse_from_str(due, "%Y-%m-%d").ok();
+        }
+        if let Some(id) = data.fields.get("priority").and_then(|v| v["id"].as_str()) {
+            if let Some((_, priority)) = PRIORITIES.iter().find(|(option, _)| *option == id) {
+                ticket.priority = priority.clone();
+            }
+        }
+        if let Some(id) = data.fields.get(TEAM_FIELD).and_then(|v| v["id"].as_str()) {
+            if let Some((_, team)) = TEAMS.iter().find(|(option, _)| *option == id) {
+                ticket.custom_fields.insert(TEAM_FIELD.to_string(), team.to_string());
+            }
+        }
+        ticket.updated = Utc::now();
+        Ok(())
+    }
+
+    async fn transition_issue(
+        &self,
+        key: &str,
+        transition_id: &str,
+        comment: Option<String>,
+        _fields: Option<serde_json::Value>,
+    ) -> Result<()> {
+        let (_, status_id, name, category) = WORKFLOW
+            .iter()
+            .find(|(id, _, _, _)| *id == transition_id)
+            .ok_or_else(|| LazyJiraError::Api(format!("Unknown transition {}", transition_id)))?;
+
+        {
+            let mut state = self.state()?;
+            let ticket = state.ticket_mut(key)?;
+            let from = std::mem::replace(
+                &mut ticket.status,
+                Status {
+                    id: status_id.to_string(),
+                    name: name.to_string(),
+                    category: category.clone(),
+                },
+            );
+            ticket.resolved = (*category == StatusCategory::Done).then(Utc::now);
+            ticket.updated = Utc::now();
+            state.record_change(key, "status", Some(from.name), Some(name.to_string()));
+        }
+
+        if let Some(comment) = comment {
+            self.add_comment(key, comment, &CommentVisibility::Public).await?;
+        }
+        Ok(())
+    }
+
+    async fn get_transitions(&self, key: &str) -> Result<Vec<Transition>> {
+        let state = self.state()?;
+        let ticket = state.tickets.iter().find(|t| t.key == key).ok_or_else(|| not_found(key))?;
+        Ok(transitions_from(&ticket.status.id))
+    }
+
+    async fn get_edit_fields(&self, key: &str) -> Result<Vec<TransitionField>> {
+        self.state()?.tickets.iter().find(|t| t.key == key).ok_or_else(|| not_found(key))?;
+        Ok(edit_fields())
+    }
+
+    async fn get_create_fields(&self, _project_key: &str, _issue_type_id: &str) -> Result<Vec<TransitionField>> {
+        Ok(edit_fields())
+    }
+
+    async fn get_field_names(&self) -> Result<HashMap<String, String>> {
+        Ok(edit_fields()
+            .into_iter()
+            .map(|field| (field.key, field.name))
+            .collect())
+    }
+
+    async fn add_comment(&self, key: &str, comment: String, visibility: &CommentVisibility) -> Result<()> {
+        let mut state = self.state()?;
+        state.ticket_mut(key)?;
+        let author = state.users[0].clone();
+        let comments = state.comments.entry(key.to_string()).or_default();
+        comments.push(Comment {
+            id: (comments.len() + 1).to_string(),
+            author,
+            body: comment,
+            created: Utc::now(),
+            updated: None,
+            visibility: visibility.clone(),
+            mentions: Vec::new(),
+        });
+        Ok(())
+    }
+
+    async fn get_comments(&self, key: &str, order: CommentOrder) -> Result<Vec<Comment>> {
+        let mut comments = self.state()?.comments.get(key).cloned().unwrap_or_default();
+        comments.sort_by_key(|comment| comment.created);
+        if order == CommentOrder::NewestFirst {
+            comments.reverse();
+        }
+        Ok(comments)
+    }
+
+    async fn download_attachment(&self, _content_url: &str) -> Result<Vec<u8>> {
+        Err(unsupported("Downloading attachments"))
+    }
+
+    async fn get_project_roles(&self, _project_key: &str) -> Result<Vec<String>> {
+        Ok(vec!["Administrators".to_string(), "Developers".to_string()])
+    }
+
+    async fn get_my_groups(&self) -> Result<Vec<String>> {
+        Ok(vec!["jira-software-users".to_string()])
+    }
+
+    async fn get_my_permissions(&self, _project_key: &str) -> Result<ProjectPermissions> {
+        Ok(ProjectPermissions::all())
+    }
+
+    async fn count_issues(&self, _jql: &str) -> Result<usize> {
+        Ok(self.state()?.tickets.len())
+    }
+
+    async fn get_myself(&self) -> Result<User> {
+        self.state()?
+            .users
+            .first()
+            .cloned()
+            .ok_or_else(|| LazyJiraError::Internal("Demo data has no users".to_string()))
+    }
+
+    async fn get_status_changes(&self, _key: &str) -> Result<Vec<StatusChange>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_recent_changes(&self, key: &str) -> Result<Vec<FieldChange>> {
+        Ok(self.state()?.changes.get(key).cloned().unwrap_or_default())
+    }
+
+    async fn assign_issue(&self, key: &str, account_id: &str) -> Result<()> {
+        let mut state = self.state()?;
+        let user = state.users.iter().find(|u| u.account_id == account_id).cloned();
+        let ticket = state.ticket_mut(key)?;
+        let to = user.as_ref().map(|u| u.display_name.clone());
+        let from = std::mem::replace(&mut ticket.assignee, user).map(|u| u.display_name);
+        ticket.updated = Utc::now();
+        state.record_change(key, "assignee", from, to);
+        Ok(())
+    }
+
+    async fn search_assignable_users(&self, _key: &str, query: &str) -> Result<Vec<User>> {
+        let query = query.to_lowercase();
+        Ok(self
+            .state()?
+            .users
+            .iter()
+            .filter(|u| u.display_name.to_lowercase().contains(&query))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_projects(&self) -> Result<Vec<Project>> {
+        let state = self.state()?;
+        let mut keys: Vec<&str> = state.tickets.iter().map(|t| t.project_key.as_str()).collect();
+        keys.sort();
+        keys.dedup();
+        Ok(keys
+            .into_iter()
+            .enumerate()
+            .map(|(idx, key)| Project {
+                id: (10000 + idx).to_string(),
+                key: key.to_string(),
+                name: format!("{} (demo)", key),
+                issue_types: ["Bug", "Story", "Task"]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| IssueType {
+                        id: (10001 + i).to_string(),
+                        name: name.to_string(),
+                        subtask: false,
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+
+    async fn get_versions(&self, _project_key: &str) -> Result<Vec<Version>> {
+        Ok(Vec::new())
+    }
+
+    async fn release_version(&self, _version_id: &str, _release_date: NaiveDate) -> Result<()> {
+        Err(unsupported("Releasing versions"))
+    }
+
+    async fn move_issue(&self, _key: &str, _project_key: &str, _issue_type_id: &str) -> Result<Ticket> {
+        Err(unsupported("Moving issues between projects"))
+    }
+
+    async fn get_boards(&self) -> Result<Vec<Board>> {
+        Ok(vec![Board {
+            id: DEMO_BOARD_ID.to_string(),
+            name: "Demo board".to_string(),
+            board_type: BoardType::Scrum,
+        }])
+    }
+
+    async fn get_board_columns(&self, _board_id: &str) -> Result<Vec<BoardColumnConfig>> {
+        Ok(WORKFLOW
+            .iter()
+            .map(|(_, status_id, name, category)| BoardColumnConfig {
+                name: name.to_string(),
+                status_ids: vec![status_id.to_string()],
+                min: None,
+                // A tight limit so the demo board shows the indicator
+                max: (*category == StatusCategory::InProgress).then_some(1),
+            })
+            .collect())
+    }
+
+    async fn get_sprints(&self, _board_id: &str, states: &[SprintState]) -> Result<Vec<Sprint>> {
+        Ok(self
+            .state()?
+            .sprints
+            .iter()
+            .filter(|s| states.is_empty() || states.contains(&s.state))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_sprint_issues(&self, _sprint_id: &str) -> Result<Vec<Ticket>> {
+        let state = self.state()?;
+        Ok(state
+            .tickets
+            .iter()
+            .filter(|t| state.sprint_keys.contains(&t.key))
+            .cloned()
+            .collect())
+    }
+
+    async fn start_sprint(
+        &self,
+        sprint_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<()> {
+        let mut state = self.state()?;
+        let sprint = state
+            .sprints
+            .iter_mut()
+            .find(|s| s.id == sprint_id)
+            .ok_or_else(|| LazyJiraError::Api(format!("Sprint {} does not exist", sprint_id)))?;
+        sprint.state = SprintState::Active;
+        sprint.start_date = Some(start_date);
+        sprint.end_date = Some(end_date);
+        Ok(())
+    }
+
+    async fn complete_sprint(&self, sprint_id: &str) -> Result<()> {
+        let mut state = self.state()?;
+        let sprint = state
+            .sprints
+            .iter_mut()
+            .find(|s| s.id == sprint_id)
+            .ok_or_else(|| LazyJiraError::Api(format!("Sprint {} does not exist", sprint_id)))?;
+        sprint.state = SprintState::Closed;
+        sprint.complete_date = Some(Utc::now());
+        Ok(())
+    }
+
+    async fn move_issues_to_sprint(&self, _sprint_id: &str, keys: &[String]) -> Result<()> {
+        self.state()?.sprint_keys.extend(keys.iter().cloned());
+        Ok(())
+    }
+
+    async fn move_issues_to_backlog(&self, keys: &[String]) -> Result<()> {
+        let mut state = self.state()?;
+        for key in keys {
+            state.sprint_keys.remove(key);
+        }
+        Ok(())
+    }
+
+    async fn get_backlog(&self, _board_id: &str) -> Result<Vec<Ticket>> {
+        let state = self.state()?;
+        Ok(state
+            .tickets
+            .iter()
+            .filter(|t| !t.is_done() && !state.sprint_keys.contains(&t.key))
+            .cloned()
+            .collect())
+    }
+
+    async fn rank_issue(&self, key: &str, position: RankPosition) -> Result<()> {
+        let mut state = self.state()?;
+        let from = state
+            .tickets
+            .iter()
+            .position(|t| t.key == key)
+            .ok_or_else(|| not_found(key))?;
+        let ticket = state.tickets.remove(from);
+        let (other, after) = match &position {
+            RankPosition::Before(other) => (other, false),
+            RankPosition::After(other) => (other, true),
+        };
+        let to = state
+            .tickets
+            .iter()
+            .position(|t| &t.key == other)
+            .map(|idx| idx + usize::from(after))
+            .unwrap_or(from)
+            .min(state.tickets.len());
+        state.tickets.insert(to, ticket);
+        Ok(())
+    }
+
+    async fn change_label(&self, key: &str, change: &LabelChange) -> Result<()> {
+        let mut state = self.state()?;
+        let ticket = state.ticket_mut(key)?;
+        ticket.labels.retain(|label| label != change.label());
+        if let LabelChange::Add(label) = change {
+            ticket.labels.push(label.clone());
+        }
+        ticket.updated = Utc::now();
+        Ok(())
+    }
+
+    async fn get_remote_links(&self, key: &str) -> Result<Vec<RemoteLink>> {
+        Ok(self.state()?.remote_links.get(key).cloned().unwrap_or_default())
+    }
+
+    async fn get_issue_properties(&self, key: &str) -> Result<Vec<IssueProperty>> {
+        let state = self.state()?;
+        let ticket = state.tickets.iter().find(|t| t.key == key).ok_or_else(|| not_found(key))?;
+        // As an automation rule would leave behind on every issue
+        Ok(vec![IssueProperty {
+            key: "demo.automation".to_string(),
+            value: serde_json::json!({
+                "rule": "Assign new issues to the demo user",
+                "lastRun": ticket.created.to_rfc3339(),
+                "runs": 1
+            }),
+        }])
+    }
+
+    async fn get_dev_info(&self, _issue_id: &str) -> Result<DevInfo> {
+        // The demo has no connected development tools
+        Ok(DevInfo::default())
+    }
+
+    async fn get_slas(&self, _key: &str) -> Result<Vec<Sla>> {
+        // The demo project is not a service desk
+        Ok(Vec::new())
+    }
+
+    async fn get_approvals(&self, _key: &str) -> Result<Vec<Approval>> {
+        Ok(Vec::new())
+    }
+
+    async fn answer_approval(&self, _key: &str, _approval_id: &str, _approve: bool) -> Result<()> {
+        Err(unsupported("Answering approvals"))
+    }
+
+    async fn get_worklogs(&self, key: &str) -> Result<Vec<Worklog>> {
+        Ok(self.state()?.worklogs.get(key).cloned().unwrap_or_default())
+    }
+
+    async fn add_worklog(
+        &self,
+        key: &str,
+        started: DateTime<Utc>,
+        time_spent_seconds: u64,
+        _comment: Option<&str>,
+    ) -> Result<()> {
+        let mut state = self.state()?;
+        state.ticket_mut(key)?;
+        let author = state
+            .users
+            .first()
+            .cloned()
+            .ok_or_else(|| LazyJiraError::Internal("Demo data has no users".to_string()))?;
+        let worklogs = state.worklogs.entry(key.to_string()).or_default();
+        worklogs.push(Worklog {
+            id: format!("{}-{}", key, worklogs.len() + 1),
+            author,
+            started,
+            time_spent_seconds,
+        });
+        Ok(())
+    }
+
+    async fn add_remote_link(&self, key: &str, title: &str, url: &str) -> Result<()> {
+        let mut state = self.state()?;
+        state.ticket_mut(key)?;
+        let links = state.remote_links.entry(key.to_string()).or_default();
+        links.push(RemoteLink {
+            id: (links.len() + 1).to_string(),
+            title: title.to_string(),
+            url: url.to_string(),
+            application: None,
+            application_type: None,
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_demo_client_is_seeded_from_fixtures() {
+        let client = DemoApiClient::new().unwrap();
+        let result = client.search_issues("", 0, 50).await.unwrap();
+        let keys: Vec<&str> = result.issues.iter().map(|t| t.key.as_str()).collect();
+        assert_eq!(keys, vec!["PROJ-123", "PROJ-124"]);
+
+        let detailed = client.get_issue("PROJ-123").await.unwrap();
+        assert_eq!(detailed.description.as_deref(), Some("This is a description of the bug."));
+        assert_eq!(client.get_backlog(DEMO_BOARD_ID).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_demo_client_applies_transitions() {
+        let client = DemoApiClient::new().unwrap();
+        let transitions = client.get_transitions("PROJ-124").await.unwrap();
+        let done = transitions.iter().find(|t| t.to_status == "Done").unwrap();
+        assert!(!done.fields.is_empty());
+
+        client
+            .transition_issue("PROJ-124", &done.id, Some("Shipped".to_string()), None)
+            .await
+            .unwrap();
+        let ticket = client.get_issue("PROJ-124").await.unwrap();
+        assert!(ticket.is_done());
+        assert_eq!(client.get_comments("PROJ-124", CommentOrder::OldestFirst).await.unwrap()[0].body, "Shipped");
+    }
+}
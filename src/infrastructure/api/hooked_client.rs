@@ -0,0 +1,333 @@
+use crate::domain::models::board::{Board, BoardColumnConfig};
+use crate::domain::models::changelog::{FieldChange, StatusChange};
+use crate::domain::models::permission::ProjectPermissions;
+use crate::domain::models::comment::{Comment, CommentOrder, CommentVisibility};
+use crate::domain::models::project::{Project, Version};
+use crate::domain::models::remote_link::RemoteLink;
+use crate::domain::models::issue_property::IssueProperty;
+use crate::domain::models::dev_info::DevInfo;
+use crate::domain::models::sla::Sla;
+use crate::domain::models::approval::Approval;
+use crate::domain::models::worklog::Worklog;
+use crate::domain::models::sprint::{Sprint, SprintState};
+use crate::domain::models::ticket::Ticket;
+use crate::domain::models::user::User;
+use crate::infrastructure::api::client::{
+    ApiClient, CreateIssueData, IssueDetails, LabelChange, RankPosition, SearchResult, Transition,
+    TransitionField, UpdateIssueData,
+};
+use crate::infrastructure::api::request_log::RequestRecord;
+use crate::infrastructure::config::HooksConfig;
+use crate::infrastructure::scripts::{self, ScriptContext};
+use crate::utils::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::sync::{Arc, RwLock};
+
+/// API client wrapper that runs the configured lifecycle hooks after
+/// transitions, assignments and comments succeed. The hooks are shared so a
+/// config reload takes effect immediately
+pub struct HookedApiClient {
+    inner: Arc<dyn ApiClient>,
+    hooks: Arc<RwLock<HooksConfig>>,
+    instance_url: String,
+}
+
+impl HookedApiClient {
+    pub fn new(
+        inner: Arc<dyn ApiClient>,
+        hooks: Arc<RwLock<HooksConfig>>,
+        instance_url: String,
+    ) -> Self {
+        Self {
+            inner,
+            hooks,
+            instance_url,
+        }
+    }
+
+    /// The currently configured command for one hook
+    fn hook(&self, select: impl Fn(&HooksConfig) -> &Option<String>) -> Option<String> {
+        self.hooks.read().ok().and_then(|hooks| select(&hooks).clone())
+    }
+
+    /// Run a hook in the background so a slow script does not hold up the UI
+    fn spawn_hook(&self, command: Option<String>, key: &str, vars: Vec<(&'static str, String)>) {
+        let Some(command) = command else {
+            return;
+        };
+        let inner = self.inner.clone();
+        let instance_url = self.instance_url.clone();
+        let key = key.to_string();
+        tokio::spawn(async move {
+            match run_hook(inner.as_ref(), &command, &key, &instance_url, vars).await {
+                Ok(_) => log::debug!("spawn_hook: '{}' ran for {}", command, key),
+                Err(e) => log::warn!("Hook '{}' failed for {}: {}", command, key, e),
+            }
+        });
+    }
+}
+
+/// Run a hook with the ticket as it is after the action
+async fn run_hook(
+    client: &dyn ApiClient,
+    command: &str,
+    key: &str,
+    instance_url: &str,
+    vars: Vec<(&'static str, String)>,
+) -> Result<String> {
+    let ticket = client.get_issue(key).await?;
+    let context = vars.into_iter().fold(
+        ScriptContext::for_ticket(&ticket, instance_url)?,
+        |context, (name, value)| context.with_var(name, value),
+    );
+    scripts::run(command, &context).await
+}
+
+#[async_trait::async_trait]
+impl ApiClient for HookedApiClient {
+    async fn get_issue(&self, key: &str) -> Result<Ticket> {
+        self.inner.get_issue(key).await
+    }
+
+    async fn get_issue_details(&self, key: &str) -> Result<IssueDetails> {
+        self.inner.get_issue_details(key).await
+    }
+
+    async fn search_issues(&self, jql: &str, start_at: usize, max_results: usize) -> Result<SearchResult> {
+        self.inner.search_issues(jql, start_at, max_results).await
+    }
+
+    async fn create_issue(&self, data: CreateIssueData) -> Result<Ticket> {
+        self.inner.create_issue(data).await
+    }
+
+    async fn update_issue(&self, key: &str, data: UpdateIssueData) -> Result<()> {
+        self.inner.update_issue(key, data).await
+    }
+
+    async fn transition_issue(
+        &self,
+        key: &str,
+        transition_id: &str,
+        comment: Option<String>,
+        fields: Option<serde_json::Value>,
+    ) -> Result<()> {
+        let mut vars = vec![("LAZYJIRA_TRANSITION_ID", transition_id.to_string())];
+        if let Some(comment) = &comment {
+            vars.push(("LAZYJIRA_COMMENT", comment.clone()));
+        }
+        self.inner
+            .transition_issue(key, transition_id, comment, fields)
+            .await?;
+        self.spawn_hook(self.hook(|hooks| &hooks.on_transition), key, vars);
+        Ok(())
+    }
+
+    async fn get_transitions(&self, key: &str) -> Result<Vec<Transition>> {
+        self.inner.get_transitions(key).await
+    }
+
+    async fn get_edit_fields(&self, key: &str) -> Result<Vec<TransitionField>> {
+        self.inner.get_edit_fields(key).await
+    }
+
+    async fn get_create_fields(&self, project_key: &str, issue_type_id: &str) -> Result<Vec<TransitionField>> {
+        self.inner.get_create_fields(project_key, issue_type_id).await
+    }
+
+    async fn get_field_names(&self) -> Result<std::collections::HashMap<String, String>> {
+        self.inner.get_field_names().await
+    }
+
+    async fn add_comment(&self, key: &str, comment: String, visibility: &CommentVisibility) -> Result<()> {
+        let vars = vec![("LAZYJIRA_COMMENT", comment.clone())];
+        self.inner.add_comment(key, comment, visibility).await?;
+        self.spawn_hook(self.hook(|hooks| &hooks.on_comment), key, vars);
+        Ok(())
+    }
+
+    async fn get_comments(&self, key: &str, order: CommentOrder) -> Result<Vec<Comment>> {
+        self.inner.get_comments(key, order).await
+    }
+
+    async fn download_attachment(&self, content_url: &str) -> Result<Vec<u8>> {
+        self.inner.download_attachment(content_url).await
+    }
+
+    async fn get_project_roles(&self, project_key: &str) -> Result<Vec<String>> {
+        self.inner.get_project_roles(project_key).await
+    }
+
+    async fn get_my_groups(&self) -> Result<Vec<String>> {
+        self.inner.get_my_groups().await
+    }
+
+    async fn get_my_permissions(&self, project_key: &str) -> Result<ProjectPermissions> {
+        self.inner.get_my_permissions(project_key).await
+    }
+
+    async fn count_issues(&self, jql: &str) -> Result<usize> {
+        self.inner.count_issues(jql).await
+    }
+
+    async fn get_myself(&self) -> Result<User> {
+        self.inner.get_myself().await
+    }
+
+    async fn get_status_changes(&self, key: &str) -> Result<Vec<StatusChange>> {
+        self.inner.get_status_changes(key).await
+    }
+
+    async fn get_recent_changes(&self, key: &str) -> Result<Vec<FieldChange>> {
+        self.inner.get_recent_changes(key).await
+    }
+
+    async fn assign_issue(&self, key: &str, account_id: &str) -> Result<()> {
+        self.inner.assign_issue(key, account_id).await?;
+        let vars = vec![("LAZYJIRA_ASSIGNEE_ID", account_id.to_string())];
+        self.spawn_hook(self.hook(|hooks| &hooks.on_assign), key, vars);
+        Ok(())
+    }
+
+    async fn search_assignable_users(&self, key: &str, query: &str) -> Result<Vec<User>> {
+        self.inner.search_assignable_users(key, query).await
+    }
+
+    async fn get_projects(&self) -> Result<Vec<Project>> {
+        self.inner.get_projects().await
+    }
+
+    async fn get_versions(&self, project_key: &str) -> Result<Vec<Version>> {
+        self.inner.get_versions(project_key).await
+    }
+
+    async fn release_version(&self, version_id: &str, release_date: NaiveDate) -> Result<()> {
+        self.inner.release_version(version_id, release_date).await
+    }
+
+    async fn move_issue(&self, key: &str, project_key: &str, issue_type_id: &str) -> Result<Ticket> {
+        self.inner.move_issue(key, project_key, issue_type_id).await
+    }
+
+    async fn get_boards(&self) -> Result<Vec<Board>> {
+        self.inner.get_boards().await
+    }
+
+    async fn get_board_columns(&self, board_id: &str) -> Result<Vec<BoardColumnConfig>> {
+        self.inner.get_board_columns(board_id).await
+    }
+
+    async fn get_sprints(&self, board_id: &str, states: &[SprintState]) -> Result<Vec<Sprint>> {
+        self.inner.get_sprints(board_id, states).await
+    }
+
+    async fn get_sprint_issues(&self, sprint_id: &str) -> Result<Vec<Ticket>> {
+        self.inner.get_sprint_issues(sprint_id).await
+    }
+
+    async fn start_sprint(
+        &self,
+        sprint_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<()> {
+        self.inner.start_sprint(sprint_id, start_date, end_date).await
+    }
+
+    async fn complete_sprint(&self, sprint_id: &str) -> Result<()> {
+        self.inner.complete_sprint(sprint_id).await
+    }
+
+    async fn move_issues_to_sprint(&self, sprint_id: &str, keys: &[String]) -> Result<()> {
+        self.inner.move_issues_to_sprint(sprint_id, keys).await
+    }
+
+    async fn move_issues_to_backlog(&self, keys: &[String]) -> Result<()> {
+        self.inner.move_issues_to_backlog(keys).await
+    }
+
+    async fn get_backlog(&self, board_id: &str) -> Result<Vec<Ticket>> {
+        self.inner.get_backlog(board_id).await
+    }
+
+    async fn rank_issue(&self, key: &str, position: RankPosition) -> Result<()> {
+        self.inner.rank_issue(key, position).await
+    }
+
+    async fn change_label(&self, key: &str, change: &LabelChange) -> Result<()> {
+        self.inner.change_label(key, change).await
+    }
+
+    async fn get_remote_links(&self, key: &str) -> Result<Vec<RemoteLink>> {
+        self.inner.get_remote_links(key).await
+    }
+
+    async fn get_issue_properties(&self, key: &str) -> Result<Vec<IssueProperty>> {
+        self.inner.get_issue_properties(key).await
+    }
+
+    async fn get_dev_info(&self, issue_id: &str) -> Result<DevInfo> {
+        self.inner.get_dev_info(issue_id).await
+    }
+
+    async fn get_slas(&self, key: &str) -> Result<Vec<Sla>> {
+        self.inner.get_slas(key).await
+    }
+
+    async fn get_approvals(&self, key: &str) -> Result<Vec<Approval>> {
+        self.inner.get_approvals(key).await
+    }
+
+    async fn answer_approval(&self, key: &str, approval_id: &str, approve: bool) -> Result<()> {
+        self.inner.answer_approval(key, approval_id, approve).await
+    }
+
+    async fn get_worklogs(&self, key: &str) -> Result<Vec<Worklog>> {
+        self.inner.get_worklogs(key).await
+    }
+
+    async fn add_worklog(
+        &self,
+        key: &str,
+        started: DateTime<Utc>,
+        time_spent_seconds: u64,
+        comment: Option<&str>,
+    ) -> Result<()> {
+        self.inner.add_worklog(key, started, time_spent_seconds, comment).await
+    }
+
+    async fn get_confluence_page_title(&self, page_url: &str) -> Result<Option<String>> {
+        self.inner.get_confluence_page_title(page_url).await
+    }
+
+    async fn add_remote_link(&self, key: &str, title: &str, url: &str) -> Result<()> {
+        self.inner.add_remote_link(key, title, url).await
+    }
+
+    fn recent_requests(&self) -> Vec<RequestRecord> {
+        self.inner.recent_requests()
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::infrastructure::api::demo_client::DemoApiClient;
+
+    #[tokio::test]
+    async fn test_hook_gets_updated_ticket_and_action_vars() {
+        let client = DemoApiClient::new().unwrap();
+        client.transition_issue("PROJ-124", "21", None, None).await.unwrap();
+
+        let output = run_hook(
+            &client,
+            "echo \"$LAZYJIRA_KEY $LAZYJIRA_STATUS $LAZYJIRA_TRANSITION_ID\"",
+            "PROJ-124",
+            "demo.atlassian.net",
+            vec![("LAZYJIRA_TRANSITION_ID", "21".to_string())],
+        )
+        .await
+        .unwrap();
+        assert_eq!(output, "PROJ-124 In Progress 21");
+    }
+}
@@ -1,114 +1,437 @@
-use super::client::{ApiClient, CreateIssueData, SearchResult, Transition, UpdateIssueData};
-use super::parser::{parse_comments, parse_issue};
-use super::rate_limiter::RateLimiter;
+use super::client::{
+    ApiClient, CreateIssueData, FieldKind, FieldOption, IssueDetails, LabelChange, RankPosition, SearchResult,
+    Transition, TransitionField, UpdateIssueData,
+};
+use super::parser::{
+    parse_agile_issues, parse_board_columns, parse_boards, parse_comments, parse_issue, parse_project_roles,
+    parse_dev_status_detail, parse_dev_status_instances, parse_projects, parse_versions, parse_approvals, parse_remote_links, parse_issue_property, parse_issue_property_keys, parse_slas, parse_sprints, parse_status_changes, parse_field_changes, parse_permissions, parse_user, parse_user_groups, parse_users, parse_worklogs,
+};
+use super::rate_limiter::{RateLimitProfile, RateLimiter};
+use super::request_log::{RequestLog, RequestRecord, RequestTracker};
 use super::retry::{retry_with_backoff, RetryConfig};
+use crate::domain::models::board::{Board, BoardColumnConfig};
+use crate::domain::models::changelog::{FieldChange, StatusChange};
+use crate::domain::models::permission::{Permission, ProjectPermissions};
+use crate::domain::models::project::{Project, Version};
+use crate::domain::models::remote_link::{confluence_page_id, RemoteLink};
+use crate::domain::models::issue_property::IssueProperty;
+use crate::domain::models::dev_info::DevInfo;
+use crate::domain::models::sla::Sla;
+use crate::domain::models::approval::Approval;
+use crate::domain::models::worklog::Worklog;
+use crate::domain::models::sprint::{Sprint, SprintState};
 use crate::domain::models::ticket::Ticket;
-use crate::domain::models::comment::Comment;
+use crate::domain::models::user::User;
+use crate::domain::models::comment::{Comment, CommentOrder, CommentVisibility};
 use crate::infrastructure::config::JiraCliConfig;
-use crate::utils::{LazyJiraError, Result};
+use crate::infrastructure::storage::metadata_cache::MetadataStore;
+use crate::utils::{JiraApiError, LazyJiraError, Result};
 use base64::Engine;
-use reqwest::Client;
+use chrono::{DateTime, NaiveDate, Utc};
+use reqwest::{Client, Method, RequestBuilder};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Request timeout unless the builder sets another
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long cached metadata is used: the field list and create screens
+/// rarely change, while an issue's edit screen follows its status
+const FIELDS_TTL_HOURS: i64 = 24;
+const CREATE_META_TTL_HOURS: i64 = 24;
+const EDIT_META_TTL_HOURS: i64 = 1;
+
+/// Maximum number of issues the Agile API moves in a single request
+const MAX_ISSUES_PER_MOVE: usize = 50;
+
+/// How often and how many times to poll a bulk move task before giving up
+const MOVE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+const MOVE_POLL_ATTEMPTS: usize = 60;
+
+/// Outcome of a single unretried request, for diagnostics
+#[derive(Debug, Clone)]
+pub struct ProbeResponse {
+    pub status: reqwest::StatusCode,
+    pub latency: std::time::Duration,
+    /// Requests left and allowed in the current window, when Jira reports them
+    pub rate_limit: Option<(u64, u64)>,
+    /// Seconds to wait before retrying, sent with 429 responses
+    pub retry_after: Option<u64>,
+    /// JSON body, or Null when the body is not JSON
+    pub body: serde_json::Value,
+}
+
+/// How requests authenticate
+#[derive(Clone)]
+pub enum JiraAuth {
+    /// Email and API token (Jira Cloud), or username and password
+    Basic { username: String, password: String },
+    /// Personal access token (Jira Data Center)
+    Bearer(String),
+}
+
+impl JiraAuth {
+    pub fn basic(username: impl Into<String>, password: impl Into<String>) -> Self {
+        JiraAuth::Basic {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Credentials of a jira-cli config. For Atlassian Cloud, API token auth
+    /// is Basic auth with username:token, so "api-token" and "basic" both map
+    /// to Basic
+    pub fn from_jira_cli_config(config: &JiraCliConfig) -> Result<Self> {
+        match (config.auth.auth_type.as_str(), &config.auth.token) {
+            ("api-token" | "basic", Some(token)) => Ok(Self::basic(&config.auth.username, token)),
+            ("api-token", None) => Err(LazyJiraError::Authentication(
+                "API token not found in config".to_string(),
+            )),
+            ("basic", None) => Err(LazyJiraError::Authentication(
+                "Password not found for basic auth".to_string(),
+            )),
+            (auth_type, _) => Err(LazyJiraError::Authentication(format!(
+                "Unsupported auth type: {}",
+                auth_type
+            ))),
+        }
+    }
+
+    /// Value of the Authorization header
+    fn header(&self) -> String {
+        match self {
+            JiraAuth::Basic { username, password } => {
+                let credentials = format!("{}:{}", username, password);
+                let encoded = base64::engine::general_purpose::STANDARD.encode(credentials);
+                format!("Basic {}", encoded)
+            }
+            JiraAuth::Bearer(token) => format!("Bearer {}", token),
+        }
+    }
+}
+
+// Keeps secrets out of logs
+impl std::fmt::Debug for JiraAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JiraAuth::Basic { username, .. } => write!(f, "Basic({})", username),
+            JiraAuth::Bearer(_) => write!(f, "Bearer"),
+        }
+    }
+}
+
+/// Adjusts every request before it is sent, e.g. to add headers a proxy
+/// needs. Closures taking and returning a `RequestBuilder` work as middleware
+pub trait Middleware: Send + Sync {
+    fn handle(&self, request: RequestBuilder) -> RequestBuilder;
+}
+
+impl<F> Middleware for F
+where
+    F: Fn(RequestBuilder) -> RequestBuilder + Send + Sync,
+{
+    fn handle(&self, request: RequestBuilder) -> RequestBuilder {
+        self(request)
+    }
+}
+
+/// Builds authenticated requests; cloned into retried request futures
+#[derive(Clone)]
+struct Requester {
+    client: Client,
+    auth_header: Option<String>,
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl Requester {
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        let mut request = self.client.request(method, url);
+        if let Some(auth_header) = &self.auth_header {
+            request = request.header("Authorization", auth_header);
+        }
+        self.middleware
+            .iter()
+            .fold(request, |request, middleware| middleware.handle(request))
+    }
+}
+
+/// Builder for [`JiraApiClient`]. Either an instance or a base URL is
+/// required; everything else has Jira Cloud defaults
+///
+/// ```no_run
+/// use lazyjira::infrastructure::api::jira_client::{JiraApiClient, JiraAuth};
+/// use std::time::Duration;
+///
+/// # fn example() -> lazyjira::Result<()> {
+/// let client = JiraApiClient::builder()
+///     .instance("acme.atlassian.net")
+///     .auth(JiraAuth::basic("jane@acme.com", "api-token"))
+///     .timeout(Duration::from_secs(10))
+///     .middleware(|request: reqwest::RequestBuilder| request.header("X-Team", "platform"))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct JiraApiClientBuilder {
+    instance: Option<String>,
+    base_url: Option<String>,
+    auth: Option<JiraAuth>,
+    timeout: Duration,
+    connect_timeout: Option<Duration>,
+    rate_limit: RateLimitProfile,
+    retry_config: RetryConfig,
+    middleware: Vec<Arc<dyn Middleware>>,
+    metadata_cache: Option<PathBuf>,
+}
+
+impl JiraApiClientBuilder {
+    fn new() -> Self {
+        Self {
+            instance: None,
+            base_url: None,
+            auth: None,
+            timeout: DEFAULT_TIMEOUT,
+            connect_timeout: None,
+            rate_limit: RateLimitProfile::JiraCloud,
+            retry_config: RetryConfig::default(),
+            middleware: Vec::new(),
+            metadata_cache: None,
+        }
+    }
+
+    /// Jira host, e.g. "acme.atlassian.net"; the APIs are reached over https
+    pub fn instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    /// Platform REST API root, e.g. "http://localhost:8080/rest/api/3";
+    /// takes precedence over the instance. The Agile API is expected next
+    /// to it
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Without auth, requests are anonymous
+    pub fn auth(mut self, auth: JiraAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Limit on a whole request, 30 seconds by default
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Jira Cloud's limit by default
+    pub fn rate_limit(mut self, profile: RateLimitProfile) -> Self {
+        self.rate_limit = profile;
+        self
+    }
+
+    pub fn retry(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// Add middleware; it runs after the ones added before it
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Keep field and screen metadata in this file between runs; without
+    /// it, metadata is fetched every time
+    pub fn metadata_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.metadata_cache = Some(path.into());
+        self
+    }
+
+    pub fn build(self) -> Result<JiraApiClient> {
+        if let RateLimitProfile::Custom { requests, per } = self.rate_limit {
+            if requests == 0 || per.is_zero() {
+                return Err(LazyJiraError::Config(format!(
+                    "Rate limit of {} requests per {:?} never lets a request through",
+                    requests, per
+                )));
+            }
+        }
+
+        let base_url = match (self.base_url, &self.instance) {
+            (Some(base_url), _) => base_url.trim_end_matches('/').to_string(),
+            (None, Some(instance)) => format!("https://{}/rest/api/3", instance),
+            (None, None) => {
+                return Err(LazyJiraError::Config(
+                    "Set the Jira instance or base URL".to_string(),
+                ))
+            }
+        };
+
+        // Agile (boards, sprints) endpoints live next to the platform REST API
+        let site_url = match (base_url.strip_suffix("/rest/api/3"), &self.instance) {
+            (Some(root), _) => root.to_string(),
+            (None, Some(instance)) => format!("https://{}", instance),
+            (None, None) => base_url.clone(),
+        };
+        let agile_url = format!("{}/rest/agile/1.0", site_url);
+
+        let mut client = Client::builder().timeout(self.timeout);
+        if let Some(timeout) = self.connect_timeout {
+            client = client.connect_timeout(timeout);
+        }
+        let client = client.build().map_err(LazyJiraError::Network)?;
+
+        Ok(JiraApiClient {
+            requester: Requester {
+                client,
+                auth_header: self.auth.as_ref().map(JiraAuth::header),
+                middleware: self.middleware,
+            },
+            base_url,
+            agile_url,
+            site_url,
+            rate_limiter: Arc::new(self.rate_limit.limiter()),
+            retry_config: self.retry_config,
+            request_log: RequestLog::new(),
+            metadata: self.metadata_cache.map(MetadataStore::open),
+        })
+    }
+}
 
 /// Jira REST API client implementation
-#[allow(dead_code)] // Will be used when API integration is complete
 pub struct JiraApiClient {
-    client: Client,
+    requester: Requester,
     base_url: String,
-    auth_header: String,
+    agile_url: String,
+    /// Root of the Atlassian site, where Confluence lives under /wiki
+    site_url: String,
     rate_limiter: Arc<RateLimiter>,
     retry_config: RetryConfig,
+    /// Recent HTTP requests, for the debug view
+    request_log: RequestLog,
+    /// Metadata responses kept between runs, when enabled
+    metadata: Option<MetadataStore>,
 }
 
 impl JiraApiClient {
-    /// Create a new Jira API client from jira-cli config
-    #[allow(dead_code)] // Will be used when API integration is complete
+    pub fn builder() -> JiraApiClientBuilder {
+        JiraApiClientBuilder::new()
+    }
+
+    /// Client for the instance and credentials of a jira-cli config
     pub fn from_jira_cli_config(config: &JiraCliConfig) -> Result<Self> {
-        Self::new(config, None)
+        Self::builder()
+            .instance(&config.instance)
+            .auth(JiraAuth::from_jira_cli_config(config)?)
+            .build()
     }
 
-    /// Create a new Jira API client with optional custom base URL (for testing)
-    pub fn new(config: &JiraCliConfig, base_url_override: Option<String>) -> Result<Self> {
-        let base_url = if let Some(override_url) = base_url_override {
-            override_url
-        } else {
-            format!("https://{}/rest/api/3", config.instance)
-        };
-        
-        // Build authentication header
-        // For Atlassian Cloud, API token auth uses Basic auth with username:token
-        // So we support both "api-token" and "basic" (when token is present)
-        let auth_header = if config.auth.auth_type == "api-token" || 
-            (config.auth.auth_type == "basic" && config.auth.token.is_some()) {
-            if let Some(token) = &config.auth.token {
-                let credentials = format!("{}:{}", config.auth.username, token);
-                let encoded = base64::engine::general_purpose::STANDARD.encode(credentials);
-                format!("Basic {}", encoded)
-            } else {
-                return Err(LazyJiraError::Authentication(
-                    "API token not found in config".to_string(),
-                ));
-            }
-        } else if config.auth.auth_type == "basic" {
-            // Basic auth with username/password (not API token)
-            if let Some(password) = &config.auth.token {
-                let credentials = format!("{}:{}", config.auth.username, password);
-                let encoded = base64::engine::general_purpose::STANDARD.encode(credentials);
-                format!("Basic {}", encoded)
-            } else {
-                return Err(LazyJiraError::Authentication(
-                    "Password not found for basic auth".to_string(),
-                ));
-            }
-        } else {
-            return Err(LazyJiraError::Authentication(format!(
-                "Unsupported auth type: {}",
-                config.auth.auth_type
-            )));
+    /// Keep field and screen metadata in this file between runs
+    pub fn with_metadata_cache(mut self, path: PathBuf) -> Self {
+        self.metadata = Some(MetadataStore::open(path));
+        self
+    }
+
+    /// GET metadata from the platform REST API, from the cache while it is
+    /// younger than `ttl_hours`
+    async fn get_metadata(&self, endpoint: &str, ttl_hours: i64) -> Result<serde_json::Value> {
+        let Some(metadata) = &self.metadata else {
+            return self.get(endpoint).await;
         };
+        let url = format!("{}/{}", self.base_url, endpoint);
+        if let Some(value) = metadata.get(&url, chrono::Duration::hours(ttl_hours)) {
+            log::debug!("get_metadata: Using cached {}", url);
+            return Ok(value);
+        }
+        let value = self.get(endpoint).await?;
+        metadata.insert(&url, value.clone());
+        Ok(value)
+    }
 
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .map_err(|e| LazyJiraError::Network(e))?;
+    /// Drop the cached edit screen of an issue whose status or project changed
+    fn forget_edit_meta(&self, key: &str) {
+        if let Some(metadata) = &self.metadata {
+            metadata.remove(&format!("{}/issue/{}/editmeta", self.base_url, key));
+        }
+    }
 
-        Ok(Self {
-            client,
-            base_url,
-            auth_header,
-            rate_limiter: Arc::new(RateLimiter::jira_cloud()),
-            retry_config: RetryConfig::default(),
-        })
+    /// Make an authenticated GET request against the platform REST API
+    async fn get(&self, endpoint: &str) -> Result<serde_json::Value> {
+        self.get_url(format!("{}/{}", self.base_url, endpoint)).await
+    }
+
+    /// Make an authenticated GET request against the Agile REST API
+    async fn agile_get(&self, endpoint: &str) -> Result<serde_json::Value> {
+        self.get_url(format!("{}/{}", self.agile_url, endpoint)).await
+    }
+
+    /// Fetch every page of an Agile API issue listing such as
+    /// `sprint/{id}/issue`, following startAt until the total is reached
+    async fn agile_get_issues(&self, path: &str) -> Result<Vec<Ticket>> {
+        let mut tickets = Vec::new();
+        let mut start_at = 0;
+
+        loop {
+            let endpoint = format!("{}?startAt={}&maxResults=100", path, start_at);
+            let json = self.agile_get(&endpoint).await?;
+            let page_len = json
+                .get("issues")
+                .and_then(|v| v.as_array())
+                .map(|issues| issues.len())
+                .unwrap_or(0);
+            let total = json.get("total").and_then(|v| v.as_u64()).unwrap_or(0) as usize;
+
+            tickets.extend(parse_agile_issues(&json)?);
+            start_at += page_len;
+            if page_len == 0 || start_at >= total {
+                break;
+            }
+        }
+
+        Ok(tickets)
     }
 
     /// Make an authenticated GET request with rate limiting and retry
-    async fn get(&self, endpoint: &str) -> Result<serde_json::Value> {
+    async fn get_url(&self, url: String) -> Result<serde_json::Value> {
         // Wait for rate limiter token
         self.rate_limiter.wait_for_token().await?;
 
         // Retry with exponential backoff
-        let url = format!("{}/{}", self.base_url, endpoint);
-        let auth_header = self.auth_header.clone();
-        let client = self.client.clone();
+        let requester = self.requester.clone();
         
-        retry_with_backoff(&self.retry_config, move || {
+        let tracker = Arc::new(RequestTracker::new());
+        let tracked = tracker.clone();
+        let request_url = url.clone();
+
+        let result = retry_with_backoff(&self.retry_config, move || {
             let url = url.clone();
-            let auth_header = auth_header.clone();
-            let client = client.clone();
+            let requester = requester.clone();
+            let tracker = tracked.clone();
             async move {
-                let response = client
-                    .get(&url)
-                    .header("Authorization", &auth_header)
+                tracker.attempt();
+                let response = requester
+                    .request(Method::GET, &url)
                     .header("Accept", "application/json")
                     .send()
                     .await
-                    .map_err(|e| LazyJiraError::Network(e))?;
+                    .map_err(LazyJiraError::Network)?;
+                tracker.response(response.status());
 
                 // Handle 429 (Too Many Requests) specifically
                 if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
                     // Wait a bit longer for rate limit
                     tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-                    return Err(LazyJiraError::Api("429 Too Many Requests".to_string()));
+                    let error_text = response.text().await.unwrap_or_default();
+                    return Err(LazyJiraError::Jira(JiraApiError::from_response(429, &error_text)));
                 }
 
                 // Handle response
@@ -117,7 +440,7 @@ impl JiraApiClient {
                     response
                         .json()
                         .await
-                        .map_err(|e| LazyJiraError::Network(e))
+                        .map_err(LazyJiraError::Network)
                 } else {
                     let error_text = response.text().await.unwrap_or_default();
                     Err(match status {
@@ -127,77 +450,194 @@ impl JiraApiClient {
                         reqwest::StatusCode::FORBIDDEN => {
                             LazyJiraError::Authentication("Forbidden".to_string())
                         }
-                        _ => LazyJiraError::Api(format!("API error ({}): {}", status, error_text)),
+                        _ => LazyJiraError::Jira(JiraApiError::from_response(status.as_u16(), &error_text)),
                     })
                 }
             }
         })
-        .await
+        .await;
+        self.request_log.record(tracker.finish("GET", &request_url));
+        result
     }
 
-    /// Make an authenticated POST request with rate limiting and retry
+    /// Make an authenticated GET request for raw bytes, such as attachment content
+    async fn get_bytes(&self, url: String) -> Result<Vec<u8>> {
+        self.rate_limiter.wait_for_token().await?;
+
+        let requester = self.requester.clone();
+
+        let tracker = Arc::new(RequestTracker::new());
+        let tracked = tracker.clone();
+        let request_url = url.clone();
+
+        let result = retry_with_backoff(&self.retry_config, move || {
+            let url = url.clone();
+            let requester = requester.clone();
+            let tracker = tracked.clone();
+            async move {
+                tracker.attempt();
+                let response = requester
+                    .request(Method::GET, &url)
+                    .send()
+                    .await
+                    .map_err(LazyJiraError::Network)?;
+                tracker.response(response.status());
+
+                let status = response.status();
+                if status.is_success() {
+                    let bytes = response.bytes().await.map_err(LazyJiraError::Network)?;
+                    Ok(bytes.to_vec())
+                } else {
+                    Err(match status {
+                        reqwest::StatusCode::UNAUTHORIZED => {
+                            LazyJiraError::Authentication("Unauthorized".to_string())
+                        }
+                        reqwest::StatusCode::FORBIDDEN => {
+                            LazyJiraError::Authentication("Forbidden".to_string())
+                        }
+                        _ => {
+                            let error_text = response.text().await.unwrap_or_default();
+                            LazyJiraError::Jira(JiraApiError::from_response(status.as_u16(), &error_text))
+                        }
+                    })
+                }
+            }
+        })
+        .await;
+        self.request_log.record(tracker.finish("GET", &request_url));
+        result
+    }
+
+    /// Make an authenticated POST request against the platform REST API
     async fn post(&self, endpoint: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+        self.post_url(format!("{}/{}", self.base_url, endpoint), body).await
+    }
+
+    /// Make an authenticated POST request against the Agile REST API
+    async fn agile_post(&self, endpoint: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+        self.post_url(format!("{}/{}", self.agile_url, endpoint), body).await
+    }
+
+    /// Make an authenticated POST request with rate limiting and retry
+    async fn post_url(&self, url: String, body: &serde_json::Value) -> Result<serde_json::Value> {
         // Wait for rate limiter token
         self.rate_limiter.wait_for_token().await?;
 
         // Retry with exponential backoff
-        let url = format!("{}/{}", self.base_url, endpoint);
-        let auth_header = self.auth_header.clone();
-        let client = self.client.clone();
+        let requester = self.requester.clone();
         let body = body.clone();
         
-        retry_with_backoff(&self.retry_config, move || {
+        let tracker = Arc::new(RequestTracker::new());
+        let tracked = tracker.clone();
+        let request_url = url.clone();
+
+        let result = retry_with_backoff(&self.retry_config, move || {
             let url = url.clone();
-            let auth_header = auth_header.clone();
-            let client = client.clone();
+            let requester = requester.clone();
+            let tracker = tracked.clone();
             let body = body.clone();
             async move {
-                let response = client
-                    .post(&url)
-                    .header("Authorization", &auth_header)
+                tracker.attempt();
+                let response = requester
+                    .request(Method::POST, &url)
                     .header("Accept", "application/json")
                     .header("Content-Type", "application/json")
                     .json(&body)
                     .send()
                     .await
-                    .map_err(|e| LazyJiraError::Network(e))?;
+                    .map_err(LazyJiraError::Network)?;
+                tracker.response(response.status());
 
                 let status = response.status();
                 if status.is_success() {
-                    response
-                        .json()
-                        .await
-                        .map_err(|e| LazyJiraError::Network(e))
+                    // Many write endpoints answer 204 No Content
+                    let text = response.text().await.map_err(LazyJiraError::Network)?;
+                    if text.trim().is_empty() {
+                        Ok(serde_json::Value::Null)
+                    } else {
+                        serde_json::from_str(&text).map_err(|e| {
+                            LazyJiraError::Parse(format!("Invalid JSON response: {}", e))
+                        })
+                    }
                 } else {
                     let error_text = response.text().await.unwrap_or_default();
-                    Err(LazyJiraError::Api(format!(
-                        "API error ({} {}): {}",
+                    Err(LazyJiraError::Jira(JiraApiError::from_response(
                         status.as_u16(),
-                        status.as_str(),
-                        error_text
+                        &error_text,
                     )))
                 }
             }
         })
-        .await
+        .await;
+        self.request_log.record(tracker.finish("POST", &request_url));
+        result
     }
 
-    /// Make an authenticated PUT request
+    /// Make an authenticated PUT request against the platform REST API
     #[allow(dead_code)] // Will be used when update_issue is fully implemented
     async fn put(&self, endpoint: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
-        let url = format!("{}/{}", self.base_url, endpoint);
-        let response = self
-            .client
-            .put(&url)
-            .header("Authorization", &self.auth_header)
+        self.put_url(format!("{}/{}", self.base_url, endpoint), body).await
+    }
+
+    /// Make an authenticated PUT request against the Agile REST API
+    async fn agile_put(&self, endpoint: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+        self.put_url(format!("{}/{}", self.agile_url, endpoint), body).await
+    }
+
+    /// Make an authenticated PUT request
+    async fn put_url(&self, url: String, body: &serde_json::Value) -> Result<serde_json::Value> {
+        self.rate_limiter.wait_for_token().await?;
+
+        let tracker = RequestTracker::new();
+        tracker.attempt();
+        let result = self
+            .requester
+            .request(Method::PUT, &url)
             .header("Accept", "application/json")
             .header("Content-Type", "application/json")
             .json(body)
             .send()
             .await
-            .map_err(|e| LazyJiraError::Network(e))?;
+            .map_err(LazyJiraError::Network);
+        let result = match result {
+            Ok(response) => {
+                tracker.response(response.status());
+                self.handle_response(response).await
+            }
+            Err(e) => Err(e),
+        };
+        self.request_log.record(tracker.finish("PUT", &url));
+        result
+    }
+
+    /// Make one authenticated GET against the platform REST API without retry
+    /// or error mapping, so `lazyjira doctor` can report exactly what came back
+    pub async fn probe(&self, endpoint: &str) -> Result<ProbeResponse> {
+        let started = std::time::Instant::now();
+        let response = self
+            .requester
+            .request(Method::GET, &format!("{}/{}", self.base_url, endpoint))
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(LazyJiraError::Network)?;
+        let latency = started.elapsed();
+
+        let header = |name: &str| -> Option<u64> {
+            response.headers().get(name)?.to_str().ok()?.trim().parse().ok()
+        };
+        let rate_limit = header("x-ratelimit-remaining").zip(header("x-ratelimit-limit"));
+        let retry_after = header("retry-after");
+        let status = response.status();
+        let body = response.json().await.unwrap_or(serde_json::Value::Null);
 
-        self.handle_response(response).await
+        Ok(ProbeResponse {
+            status,
+            latency,
+            rate_limit,
+            retry_after,
+            body,
+        })
     }
 
     /// Handle HTTP response and convert to Result
@@ -209,10 +649,14 @@ impl JiraApiClient {
         let status = response.status();
         
         if status.is_success() {
-            response
-                .json()
-                .await
-                .map_err(|e| LazyJiraError::Network(e))
+            // Write endpoints may answer 204 No Content
+            let text = response.text().await.map_err(LazyJiraError::Network)?;
+            if text.trim().is_empty() {
+                Ok(serde_json::Value::Null)
+            } else {
+                serde_json::from_str(&text)
+                    .map_err(|e| LazyJiraError::Parse(format!("Invalid JSON response: {}", e)))
+            }
         } else {
             let error_text = response.text().await.unwrap_or_default();
             Err(match status {
@@ -222,20 +666,134 @@ impl JiraApiClient {
                 reqwest::StatusCode::FORBIDDEN => {
                     LazyJiraError::Authentication("Forbidden".to_string())
                 }
-                reqwest::StatusCode::TOO_MANY_REQUESTS => {
-                    LazyJiraError::Api("429 Too Many Requests".to_string())
-                }
-                _ => LazyJiraError::Api(format!("API error ({}): {}", status, error_text)),
+                _ => LazyJiraError::Jira(JiraApiError::from_response(status.as_u16(), &error_text)),
             })
         }
     }
 }
 
+/// Parse a field of an expanded transition screen or of the edit metadata;
+/// returns None for field types the forms cannot edit
+fn parse_screen_field(key: &str, field: &serde_json::Value) -> Option<TransitionField> {
+    let schema_type = field
+        .get("schema")
+        .and_then(|schema| schema.get("type"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+
+    let kind = match field.get("allowedValues").and_then(|v| v.as_array()) {
+        Some(values) => FieldKind::Select {
+            options: values
+                .iter()
+                .filter_map(|value| {
+                    let id = value.get("id")?.as_str()?.to_string();
+                    // Custom select options carry "value" instead of "name"
+                    let name = value
+                        .get("name")
+                        .or_else(|| value.get("value"))
+                        .and_then(|v| v.as_str())?
+                        .to_string();
+                    Some(FieldOption { id, name })
+                })
+                .collect(),
+            multiple: schema_type == "array",
+        },
+        None => match schema_type {
+            "string" => FieldKind::Text,
+            "date" => FieldKind::Date,
+            "number" => FieldKind::Number,
+            "user" => FieldKind::User,
+            "comments-page" | "comment" => FieldKind::Comment,
+            _ => return None,
+        },
+    };
+
+    Some(TransitionField {
+        key: key.to_string(),
+        name: field
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or(key)
+            .to_string(),
+        required: field.get("required").and_then(|v| v.as_bool()).unwrap_or(false),
+        kind,
+    })
+}
+
+/// Whether a string field holds rich text, which the v3 API only takes as
+/// an ADF document
+fn is_rich_text(field: &serde_json::Value) -> bool {
+    let schema = &field["schema"];
+    matches!(schema["system"].as_str(), Some("description" | "environment"))
+        || schema["custom"].as_str().is_some_and(|custom| custom.ends_with(":textarea"))
+}
+
+/// Order screen fields with required ones first, then by name
+fn sort_screen_fields(mut fields: Vec<TransitionField>) -> Vec<TransitionField> {
+    fields.sort_by(|a, b| b.required.cmp(&a.required).then_with(|| a.name.cmp(&b.name)));
+    fields
+}
+
+/// Parse the transitions of a transitions response or an issue fetched
+/// with `expand=transitions.fields`
+fn parse_transitions(json: &serde_json::Value) -> Result<Vec<Transition>> {
+    let transitions_array = json
+        .get("transitions")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'transitions' array".to_string()))?;
+
+    let mut transitions = Vec::new();
+    for transition_json in transitions_array {
+        let id = transition_json
+            .get("id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| LazyJiraError::Parse("Missing transition 'id'".to_string()))?
+            .to_string();
+
+        let name = transition_json
+            .get("name")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| LazyJiraError::Parse("Missing transition 'name'".to_string()))?
+            .to_string();
+
+        let to_status = transition_json
+            .get("to")
+            .and_then(|v| v.get("name"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown")
+            .to_string();
+
+        let fields = transition_json
+            .get("fields")
+            .and_then(|v| v.as_object())
+            .map(|fields| {
+                fields
+                    .iter()
+                    .filter_map(|(key, field)| parse_screen_field(key, field))
+                    // The transition form has no user picker
+                    .filter(|field| field.kind != FieldKind::User)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        transitions.push(Transition {
+            id,
+            name,
+            to_status,
+            fields: sort_screen_fields(fields),
+        });
+    }
+
+    Ok(transitions)
+}
+
 #[async_trait::async_trait]
 impl ApiClient for JiraApiClient {
     async fn get_issue(&self, key: &str) -> Result<Ticket> {
         log::debug!("get_issue: Fetching issue {}", key);
-        let endpoint = format!("issue/{}", key);
+        // Field names identify the service desk fields, whose ids vary by
+        // site; rendered fields stand in for descriptions with media or macros
+        let endpoint = format!("issue/{}?expand=names,renderedFields", key);
         log::debug!("get_issue: Calling endpoint {}", endpoint);
         
         let json = match self.get(&endpoint).await {
@@ -262,6 +820,32 @@ impl ApiClient for JiraApiClient {
         }
     }
 
+    async fn get_issue_details(&self, key: &str) -> Result<IssueDetails> {
+        // The changelog and transitions come with the issue in one request
+        let endpoint = format!(
+            "issue/{}?expand=names,renderedFields,changelog,transitions.fields",
+            key
+        );
+        let json = self.get(&endpoint).await?;
+        let ticket = parse_issue(&json)?;
+        let transitions = parse_transitions(&json)?;
+
+        // The embedded changelog stops at a hundred entries; longer ones
+        // are paged through the changelog endpoint
+        let changelog = &json["changelog"];
+        let embedded = changelog["histories"].as_array().map_or(0, Vec::len) as u64;
+        let changes = match changelog["total"].as_u64() {
+            Some(total) if total <= embedded => parse_status_changes(changelog)?,
+            _ => self.get_status_changes(key).await?,
+        };
+
+        Ok(IssueDetails {
+            ticket,
+            changes,
+            transitions,
+        })
+    }
+
     async fn search_issues(
         &self,
         jql: &str,
@@ -282,40 +866,39 @@ impl ApiClient for JiraApiClient {
         let json = self.get(&endpoint).await?;
         
         // The /search/jql endpoint returns a different format - it returns issue IDs in a "values" array
-        // We need to fetch full issue details for each ID
-        let issue_ids: Vec<String> = json
+        // Items that already carry their fields are parsed directly; bare IDs are fetched individually
+        let items = json
             .get("values")
             .or_else(|| json.get("issues"))
             .and_then(|v| v.as_array())
             .ok_or_else(|| {
                 let available_keys: Vec<String> = json
                     .as_object()
-                    .map(|obj| obj.keys().map(|k| k.clone()).collect())
+                    .map(|obj| obj.keys().cloned().collect())
                     .unwrap_or_default();
                 LazyJiraError::Parse(format!(
                     "Missing 'values' or 'issues' array in search/jql response. Available keys: {:?}",
                     available_keys
                 ))
-            })?
-            .iter()
-            .filter_map(|item| {
-                // Handle both formats: {"id": "123"} or full issue objects
-                if let Some(id) = item.get("id").and_then(|v| v.as_str()) {
-                    Some(id.to_string())
-                } else if let Some(id) = item.as_str() {
-                    Some(id.to_string())
-                } else {
-                    None
-                }
-            })
-            .collect();
-        
-        // Fetch full issue details for each ID
-        // Note: Jira API accepts both issue keys (PROJ-123) and numeric IDs
-        // The /search/jql endpoint returns numeric IDs, so we fetch them directly
+            })?;
+
         let mut tickets = Vec::new();
-        for issue_id in issue_ids {
-            // Use get_issue which accepts both keys and IDs
+        for item in items {
+            if item.get("fields").is_some() {
+                match parse_issue(item) {
+                    Ok(ticket) => tickets.push(ticket),
+                    Err(e) => log::warn!("search_issues: Failed to parse issue: {}", e),
+                }
+                continue;
+            }
+
+            // Handle both formats: {"id": "123"} or a bare ID string
+            let issue_id = match item.get("id").and_then(|v| v.as_str()).or_else(|| item.as_str()) {
+                Some(id) => id.to_string(),
+                None => continue,
+            };
+
+            // Note: Jira API accepts both issue keys (PROJ-123) and numeric IDs
             match self.get_issue(&issue_id).await {
                 Ok(ticket) => tickets.push(ticket),
                 Err(e) => {
@@ -390,13 +973,27 @@ impl ApiClient for JiraApiClient {
             });
         }
 
-        let json = self.post(&endpoint, &body).await?;
+        if !data.labels.is_empty() {
+            body["fields"]["labels"] = serde_json::json!(data.labels);
+        }
+
+        if !data.components.is_empty() {
+            body["fields"]["components"] = data
+                .components
+                .iter()
+                .map(|name| serde_json::json!({ "name": name }))
+                .collect();
+        }
+
+        let json = self.post(endpoint, &body).await?;
         parse_issue(&json)
     }
 
-    async fn update_issue(&self, _key: &str, _data: UpdateIssueData) -> Result<()> {
-        // Placeholder implementation
-        Err(LazyJiraError::Internal("Not yet implemented".to_string()))
+    async fn update_issue(&self, key: &str, data: UpdateIssueData) -> Result<()> {
+        let endpoint = format!("issue/{}", key);
+        let body = serde_json::json!({ "fields": data.fields });
+        self.put(&endpoint, &body).await?;
+        Ok(())
     }
 
     async fn transition_issue(
@@ -404,6 +1001,7 @@ impl ApiClient for JiraApiClient {
         key: &str,
         transition_id: &str,
         comment: Option<String>,
+        fields: Option<serde_json::Value>,
     ) -> Result<()> {
         let endpoint = format!("issue/{}/transitions", key);
         
@@ -413,6 +1011,10 @@ impl ApiClient for JiraApiClient {
             }
         });
 
+        if let Some(fields) = fields {
+            body["fields"] = fields;
+        }
+
         // Add comment if provided
         if let Some(comment_text) = comment {
             body["update"]["comment"] = serde_json::json!([{
@@ -433,53 +1035,71 @@ impl ApiClient for JiraApiClient {
         }
 
         self.post(&endpoint, &body).await?;
+        self.forget_edit_meta(key);
         Ok(())
     }
 
     async fn get_transitions(&self, key: &str) -> Result<Vec<Transition>> {
-        let endpoint = format!("issue/{}/transitions", key);
+        // Expanding the fields reveals what the transition screen asks for
+        let endpoint = format!("issue/{}/transitions?expand=transitions.fields", key);
         let json = self.get(&endpoint).await?;
-        
-        let transitions_array = json
-            .get("transitions")
+        parse_transitions(&json)
+    }
+
+    async fn get_edit_fields(&self, key: &str) -> Result<Vec<TransitionField>> {
+        let endpoint = format!("issue/{}/editmeta", key);
+        let json = self.get_metadata(&endpoint, EDIT_META_TTL_HOURS).await?;
+        let fields = json
+            .get("fields")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| LazyJiraError::Parse("Missing 'fields' object".to_string()))?;
+
+        let fields = fields
+            .iter()
+            .filter(|(_, field)| !is_rich_text(field))
+            .filter_map(|(key, field)| parse_screen_field(key, field))
+            // Comments are added rather than edited
+            .filter(|field| field.kind != FieldKind::Comment)
+            .collect();
+        Ok(sort_screen_fields(fields))
+    }
+
+    async fn get_create_fields(&self, project_key: &str, issue_type_id: &str) -> Result<Vec<TransitionField>> {
+        let endpoint = format!("issue/createmeta/{}/issuetypes/{}", project_key, issue_type_id);
+        let json = self.get_metadata(&endpoint, CREATE_META_TTL_HOURS).await?;
+        let fields = json
+            .get("fields")
             .and_then(|v| v.as_array())
-            .ok_or_else(|| LazyJiraError::Parse("Missing 'transitions' array".to_string()))?;
-
-        let mut transitions = Vec::new();
-        for transition_json in transitions_array {
-            let id = transition_json
-                .get("id")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| LazyJiraError::Parse("Missing transition 'id'".to_string()))?
-                .to_string();
-
-            let name = transition_json
-                .get("name")
-                .and_then(|v| v.as_str())
-                .ok_or_else(|| LazyJiraError::Parse("Missing transition 'name'".to_string()))?
-                .to_string();
-
-            let to_status = transition_json
-                .get("to")
-                .and_then(|v| v.get("name"))
-                .and_then(|v| v.as_str())
-                .unwrap_or("Unknown")
-                .to_string();
-
-            transitions.push(Transition {
-                id,
-                name,
-                to_status,
-            });
-        }
+            .ok_or_else(|| LazyJiraError::Parse("Missing 'fields' array".to_string()))?;
+
+        let fields = fields
+            .iter()
+            .filter(|field| !is_rich_text(field))
+            .filter_map(|field| parse_screen_field(field.get("fieldId")?.as_str()?, field))
+            .filter(|field| field.kind != FieldKind::Comment)
+            .collect();
+        Ok(sort_screen_fields(fields))
+    }
 
-        Ok(transitions)
+    async fn get_field_names(&self) -> Result<HashMap<String, String>> {
+        let json = self.get_metadata("field", FIELDS_TTL_HOURS).await?;
+        let fields = json
+            .as_array()
+            .ok_or_else(|| LazyJiraError::Parse("Expected an array of fields".to_string()))?;
+        Ok(fields
+            .iter()
+            .filter_map(|field| {
+                let id = field.get("id")?.as_str()?;
+                let name = field.get("name")?.as_str()?;
+                Some((id.to_string(), name.to_string()))
+            })
+            .collect())
     }
 
-    async fn add_comment(&self, key: &str, comment: String) -> Result<()> {
+    async fn add_comment(&self, key: &str, comment: String, visibility: &CommentVisibility) -> Result<()> {
         let endpoint = format!("issue/{}/comment", key);
         
-        let body = serde_json::json!({
+        let mut body = serde_json::json!({
             "body": {
                 "type": "doc",
                 "version": 1,
@@ -493,13 +1113,31 @@ impl ApiClient for JiraApiClient {
             }
         });
 
+        match visibility {
+            CommentVisibility::Public => {}
+            // Jira Service Management hides comments from customers via this property
+            CommentVisibility::Internal => {
+                body["properties"] = serde_json::json!([{
+                    "key": "sd.public.comment",
+                    "value": { "internal": true }
+                }]);
+            }
+            CommentVisibility::Role(role) => {
+                body["visibility"] = serde_json::json!({ "type": "role", "value": role });
+            }
+            CommentVisibility::Group(group) => {
+                body["visibility"] = serde_json::json!({ "type": "group", "value": group });
+            }
+        }
+
         self.post(&endpoint, &body).await?;
         Ok(())
     }
 
-    async fn get_comments(&self, key: &str) -> Result<Vec<Comment>> {
+    async fn get_comments(&self, key: &str, order: CommentOrder) -> Result<Vec<Comment>> {
         log::debug!("get_comments: Fetching comments for issue {}", key);
-        let endpoint = format!("issue/{}/comment", key);
+        // Properties carry the Jira Service Management "internal" flag
+        let endpoint = format!("issue/{}/comment?expand=properties&orderBy={}", key, order.order_by());
         log::debug!("get_comments: Calling endpoint {}", endpoint);
         
         let json = match self.get(&endpoint).await {
@@ -525,4 +1163,457 @@ impl ApiClient for JiraApiClient {
             }
         }
     }
+
+    async fn download_attachment(&self, content_url: &str) -> Result<Vec<u8>> {
+        self.get_bytes(content_url.to_string()).await
+    }
+
+    async fn get_project_roles(&self, project_key: &str) -> Result<Vec<String>> {
+        let endpoint = format!("project/{}/role", project_key);
+        let json = self.get(&endpoint).await?;
+        parse_project_roles(&json)
+    }
+
+    async fn get_my_groups(&self) -> Result<Vec<String>> {
+        let json = self.get("myself?expand=groups").await?;
+        parse_user_groups(&json)
+    }
+
+    async fn get_my_permissions(&self, project_key: &str) -> Result<ProjectPermissions> {
+        let keys: Vec<&str> = Permission::ALL.iter().map(|permission| permission.key()).collect();
+        let endpoint = format!(
+            "mypermissions?projectKey={}&permissions={}",
+            urlencoding::encode(project_key),
+            keys.join(",")
+        );
+        let json = self.get(&endpoint).await?;
+        parse_permissions(&json)
+    }
+
+    async fn count_issues(&self, jql: &str) -> Result<usize> {
+        let body = serde_json::json!({ "jql": jql });
+        let json = self.post("search/approximate-count", &body).await?;
+        json.get("count")
+            .and_then(|v| v.as_u64())
+            .map(|count| count as usize)
+            .ok_or_else(|| LazyJiraError::Parse("Missing 'count' in approximate-count response".to_string()))
+    }
+
+    async fn get_myself(&self) -> Result<User> {
+        let json = self.get("myself").await?;
+        parse_user(&json)
+    }
+
+    async fn assign_issue(&self, key: &str, account_id: &str) -> Result<()> {
+        let endpoint = format!("issue/{}/assignee", key);
+        let body = serde_json::json!({ "accountId": account_id });
+        self.put(&endpoint, &body).await?;
+        Ok(())
+    }
+
+    async fn search_assignable_users(&self, key: &str, query: &str) -> Result<Vec<User>> {
+        let endpoint = format!(
+            "user/assignable/search?issueKey={}&query={}&maxResults=20",
+            key,
+            urlencoding::encode(query)
+        );
+        let json = self.get(&endpoint).await?;
+        parse_users(&json)
+    }
+
+    async fn get_projects(&self) -> Result<Vec<Project>> {
+        let mut projects = Vec::new();
+        loop {
+            let endpoint = format!(
+                "project/search?expand=issueTypes&startAt={}&maxResults=50",
+                projects.len()
+            );
+            let json = self.get(&endpoint).await?;
+            let page = parse_projects(&json)?;
+            let is_last = json.get("isLast").and_then(|v| v.as_bool()).unwrap_or(true);
+            if page.is_empty() {
+                break;
+            }
+            projects.extend(page);
+            if is_last {
+                break;
+            }
+        }
+        Ok(projects)
+    }
+
+    async fn get_versions(&self, project_key: &str) -> Result<Vec<Version>> {
+        let json = self.get(&format!("project/{}/versions", project_key)).await?;
+        parse_versions(&json)
+    }
+
+    async fn release_version(&self, version_id: &str, release_date: NaiveDate) -> Result<()> {
+        let body = serde_json::json!({
+            "released": true,
+            "releaseDate": release_date.format("%Y-%m-%d").to_string(),
+        });
+        self.put(&format!("version/{}", version_id), &body).await?;
+        Ok(())
+    }
+
+    async fn move_issue(&self, key: &str, project_key: &str, issue_type_id: &str) -> Result<Ticket> {
+        // Moves run as an asynchronous bulk task: submit it, then poll until it finishes
+        let mut mapping = serde_json::Map::new();
+        mapping.insert(
+            format!("{},{}", project_key, issue_type_id),
+            serde_json::json!({
+                "inferClassificationDefaults": true,
+                "inferFieldDefaults": true,
+                "inferStatusDefaults": true,
+                "inferSubtaskTypeDefault": true,
+                "issueIdsOrKeys": [key],
+            }),
+        );
+        let body = serde_json::json!({
+            "sendBulkNotification": true,
+            "targetToSourcesMapping": mapping,
+        });
+
+        let response = self.post("bulk/issues/move", &body).await?;
+        let task_id = response
+            .get("taskId")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| LazyJiraError::Parse("Missing 'taskId' in move response".to_string()))?
+            .to_string();
+
+        for _ in 0..MOVE_POLL_ATTEMPTS {
+            let task = self.get(&format!("bulk/queue/{}", task_id)).await?;
+            match task.get("status").and_then(|v| v.as_str()) {
+                Some("COMPLETE") => {
+                    let failures: Vec<String> = task
+                        .get("failedAccessibleIssues")
+                        .and_then(|v| v.as_object())
+                        .into_iter()
+                        .flat_map(|failed| failed.values())
+                        .filter_map(|errors| errors.as_array())
+                        .flatten()
+                        .filter_map(|e| e.as_str().map(String::from))
+                        .collect();
+                    if !failures.is_empty() {
+                        return Err(LazyJiraError::Api(format!(
+                            "Failed to move {}: {}",
+                            key,
+                            failures.join(", ")
+                        )));
+                    }
+                    self.forget_edit_meta(key);
+                    // The old key redirects to the moved issue
+                    return self.get_issue(key).await;
+                }
+                Some(status @ ("FAILED" | "CANCELLED" | "DEAD")) => {
+                    return Err(LazyJiraError::Api(format!(
+                        "Move of {} ended with status {}",
+                        key, status
+                    )));
+                }
+                _ => tokio::time::sleep(MOVE_POLL_INTERVAL).await,
+            }
+        }
+
+        Err(LazyJiraError::Api(format!(
+            "Timed out waiting for {} to move",
+            key
+        )))
+    }
+
+    async fn get_status_changes(&self, key: &str) -> Result<Vec<StatusChange>> {
+        let mut changes = Vec::new();
+        let mut start_at = 0;
+
+        loop {
+            let endpoint = format!("issue/{}/changelog?startAt={}&maxResults=100", key, start_at);
+            let json = self.get(&endpoint).await?;
+            let page_len = json
+                .get("values")
+                .and_then(|v| v.as_array())
+                .map(|values| values.len())
+                .unwrap_or(0);
+            let is_last = json.get("isLast").and_then(|v| v.as_bool()).unwrap_or(true);
+
+            changes.extend(parse_status_changes(&json)?);
+            start_at += page_len;
+            if is_last || page_len == 0 {
+                break;
+            }
+        }
+
+        Ok(changes)
+    }
+
+    async fn get_recent_changes(&self, key: &str) -> Result<Vec<FieldChange>> {
+        // The expanded issue carries the last hundred changelog entries,
+        // where the changelog endpoint would page from the oldest
+        let json = self.get(&format!("issue/{}?fields=summary&expand=changelog", key)).await?;
+        parse_field_changes(&json["changelog"])
+    }
+
+    async fn get_boards(&self) -> Result<Vec<Board>> {
+        let mut boards = Vec::new();
+
+        // Sites with many projects have more boards than fit in one page
+        loop {
+            let endpoint = format!("board?startAt={}&maxResults=50", boards.len());
+            let json = self.agile_get(&endpoint).await?;
+            let page = parse_boards(&json)?;
+            let is_last = json.get("isLast").and_then(|v| v.as_bool()).unwrap_or(true);
+
+            let page_empty = page.is_empty();
+            boards.extend(page);
+            if is_last || page_empty {
+                break;
+            }
+        }
+
+        Ok(boards)
+    }
+
+    async fn get_board_columns(&self, board_id: &str) -> Result<Vec<BoardColumnConfig>> {
+        let json = self.agile_get(&format!("board/{}/configuration", board_id)).await?;
+        parse_board_columns(&json)
+    }
+
+    async fn get_sprints(&self, board_id: &str, states: &[SprintState]) -> Result<Vec<Sprint>> {
+        let states: Vec<&str> = states.iter().map(|s| s.as_api_str()).collect();
+        let mut sprints = Vec::new();
+
+        // Boards accumulate many closed sprints, so follow the pagination
+        loop {
+            let endpoint = format!(
+                "board/{}/sprint?state={}&startAt={}&maxResults=50",
+                board_id,
+                states.join(","),
+                sprints.len()
+            );
+            let json = self.agile_get(&endpoint).await?;
+            let page = parse_sprints(&json)?;
+            let is_last = json.get("isLast").and_then(|v| v.as_bool()).unwrap_or(true);
+
+            let page_empty = page.is_empty();
+            sprints.extend(page);
+            if is_last || page_empty {
+                break;
+            }
+        }
+
+        Ok(sprints)
+    }
+
+    async fn get_sprint_issues(&self, sprint_id: &str) -> Result<Vec<Ticket>> {
+        self.agile_get_issues(&format!("sprint/{}/issue", sprint_id)).await
+    }
+
+    async fn start_sprint(
+        &self,
+        sprint_id: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> Result<()> {
+        let endpoint = format!("sprint/{}", sprint_id);
+        let body = serde_json::json!({
+            "state": SprintState::Active.as_api_str(),
+            "startDate": start_date.to_rfc3339(),
+            "endDate": end_date.to_rfc3339(),
+        });
+
+        self.agile_post(&endpoint, &body).await?;
+        Ok(())
+    }
+
+    async fn complete_sprint(&self, sprint_id: &str) -> Result<()> {
+        let endpoint = format!("sprint/{}", sprint_id);
+        let body = serde_json::json!({
+            "state": SprintState::Closed.as_api_str(),
+        });
+
+        self.agile_post(&endpoint, &body).await?;
+        Ok(())
+    }
+
+    async fn move_issues_to_sprint(&self, sprint_id: &str, keys: &[String]) -> Result<()> {
+        let endpoint = format!("sprint/{}/issue", sprint_id);
+
+        // The Agile API accepts at most 50 issues per request
+        for chunk in keys.chunks(MAX_ISSUES_PER_MOVE) {
+            let body = serde_json::json!({ "issues": chunk });
+            self.agile_post(&endpoint, &body).await?;
+        }
+        Ok(())
+    }
+
+    async fn move_issues_to_backlog(&self, keys: &[String]) -> Result<()> {
+        for chunk in keys.chunks(MAX_ISSUES_PER_MOVE) {
+            let body = serde_json::json!({ "issues": chunk });
+            self.agile_post("backlog/issue", &body).await?;
+        }
+        Ok(())
+    }
+
+    async fn get_backlog(&self, board_id: &str) -> Result<Vec<Ticket>> {
+        self.agile_get_issues(&format!("board/{}/backlog", board_id)).await
+    }
+
+    async fn rank_issue(&self, key: &str, position: RankPosition) -> Result<()> {
+        let body = match position {
+            RankPosition::Before(other) => serde_json::json!({
+                "issues": [key],
+                "rankBeforeIssue": other,
+            }),
+            RankPosition::After(other) => serde_json::json!({
+                "issues": [key],
+                "rankAfterIssue": other,
+            }),
+        };
+
+        // A 207 response lists per-issue failures instead of failing the request
+        let json = self.agile_put("issue/rank", &body).await?;
+        let errors: Vec<String> = json
+            .get("entries")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.get("errors").and_then(|v| v.as_array()))
+            .flatten()
+            .filter_map(|e| e.as_str().map(String::from))
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(LazyJiraError::Api(format!("Failed to rank {}: {}", key, errors.join(", "))))
+        }
+    }
+
+    async fn change_label(&self, key: &str, change: &LabelChange) -> Result<()> {
+        let operation = match change {
+            LabelChange::Add(label) => serde_json::json!({ "add": label }),
+            LabelChange::Remove(label) => serde_json::json!({ "remove": label }),
+        };
+        let body = serde_json::json!({ "update": { "labels": [operation] } });
+        self.put(&format!("issue/{}", key), &body).await?;
+        Ok(())
+    }
+
+    async fn get_remote_links(&self, key: &str) -> Result<Vec<RemoteLink>> {
+        let json = self.get(&format!("issue/{}/remotelink", key)).await?;
+        parse_remote_links(&json)
+    }
+
+    async fn get_issue_properties(&self, key: &str) -> Result<Vec<IssueProperty>> {
+        // The list only names the properties; each value is its own request
+        let json = self.get(&format!("issue/{}/properties", key)).await?;
+        let mut properties = Vec::new();
+        for property_key in parse_issue_property_keys(&json)? {
+            let json = self
+                .get(&format!("issue/{}/properties/{}", key, urlencoding::encode(&property_key)))
+                .await?;
+            properties.push(parse_issue_property(&json)?);
+        }
+        Ok(properties)
+    }
+
+    async fn get_dev_info(&self, issue_id: &str) -> Result<DevInfo> {
+        // The summary names the connected tools with data for the issue;
+        // details are only served per tool and kind of data
+        let summary = self
+            .get_url(format!(
+                "{}/rest/dev-status/latest/issue/summary?issueId={}",
+                self.site_url, issue_id
+            ))
+            .await?;
+        let mut info = DevInfo::default();
+        for instance in parse_dev_status_instances(&summary) {
+            for data_type in ["branch", "repository", "pullrequest"] {
+                let json = self
+                    .get_url(format!(
+                        "{}/rest/dev-status/1.0/issue/detail?issueId={}&applicationType={}&dataType={}",
+                        self.site_url, issue_id, instance, data_type
+                    ))
+                    .await?;
+                parse_dev_status_detail(&json, &mut info);
+            }
+        }
+        Ok(info)
+    }
+
+    async fn get_slas(&self, key: &str) -> Result<Vec<Sla>> {
+        let json = self
+            .get_url(format!("{}/rest/servicedeskapi/request/{}/sla", self.site_url, key))
+            .await?;
+        parse_slas(&json)
+    }
+
+    async fn get_approvals(&self, key: &str) -> Result<Vec<Approval>> {
+        let json = self
+            .get_url(format!("{}/rest/servicedeskapi/request/{}/approval", self.site_url, key))
+            .await?;
+        parse_approvals(&json)
+    }
+
+    async fn answer_approval(&self, key: &str, approval_id: &str, approve: bool) -> Result<()> {
+        let body = serde_json::json!({ "decision": if approve { "approve" } else { "decline" } });
+        self.post_url(
+            format!("{}/rest/servicedeskapi/request/{}/approval/{}", self.site_url, key, approval_id),
+            &body,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_worklogs(&self, key: &str) -> Result<Vec<Worklog>> {
+        let json = self.get(&format!("issue/{}/worklog", key)).await?;
+        parse_worklogs(&json)
+    }
+
+    async fn add_worklog(
+        &self,
+        key: &str,
+        started: DateTime<Utc>,
+        time_spent_seconds: u64,
+        comment: Option<&str>,
+    ) -> Result<()> {
+        let mut body = serde_json::json!({
+            "started": started.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string(),
+            "timeSpentSeconds": time_spent_seconds,
+        });
+        if let Some(comment) = comment {
+            body["comment"] = serde_json::json!({
+                "type": "doc",
+                "version": 1,
+                "content": [{
+                    "type": "paragraph",
+                    "content": [{ "type": "text", "text": comment }]
+                }]
+            });
+        }
+        self.post(&format!("issue/{}/worklog", key), &body).await?;
+        Ok(())
+    }
+
+    async fn get_confluence_page_title(&self, page_url: &str) -> Result<Option<String>> {
+        let on_site = page_url
+            .strip_prefix(&self.site_url)
+            .is_some_and(|path| path.starts_with("/wiki/"));
+        let Some(page_id) = confluence_page_id(page_url).filter(|_| on_site) else {
+            return Ok(None);
+        };
+        let json = self
+            .get_url(format!("{}/wiki/rest/api/content/{}", self.site_url, page_id))
+            .await?;
+        Ok(json["title"].as_str().map(str::to_string))
+    }
+
+    async fn add_remote_link(&self, key: &str, title: &str, url: &str) -> Result<()> {
+        let body = serde_json::json!({ "object": { "url": url, "title": title } });
+        self.post(&format!("issue/{}/remotelink", key), &body).await?;
+        Ok(())
+    }
+
+    fn recent_requests(&self) -> Vec<RequestRecord> {
+        self.request_log.records()
+    }
 }
@@ -1,16 +1,22 @@
+pub mod audited_client;
 pub mod client;
 pub mod connection;
+pub mod demo_client;
+pub mod hooked_client;
 pub mod jira_client;
 pub mod jira_cli_adapter;
 pub mod parser;
 pub mod rate_limiter;
+pub mod request_log;
 pub mod retry;
 
 pub use client::ApiClient;
 pub use connection::{ConnectionStatus, ConnectionValidator};
-pub use jira_client::JiraApiClient;
+pub use jira_client::{JiraApiClient, JiraApiClientBuilder, JiraAuth, Middleware};
+pub use rate_limiter::RateLimitProfile;
+pub use retry::RetryConfig;
 // Parser functions are used internally but not exported
 // pub use parser::{parse_issue, parse_search_results};
 // Rate limiter and retry utilities are used internally
 // pub use rate_limiter::RateLimiter;
-// pub use retry::{is_retryable_error, retry_with_backoff, RetryConfig};
+// pub use retry::{is_retryable_error, retry_with_backoff};
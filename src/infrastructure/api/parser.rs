@@ -1,9 +1,25 @@
-use crate::domain::models::ticket::{Priority, Status, StatusCategory, Ticket};
+use crate::domain::models::board::{Board, BoardColumnConfig, BoardType};
+use crate::domain::models::changelog::{FieldChange, StatusChange};
+use crate::domain::models::project::{IssueType, Project, Version};
+use crate::domain::models::sprint::{Sprint, SprintState};
+use crate::domain::models::ticket::{IssueRef, Priority, Status, StatusCategory, Ticket};
 use crate::domain::models::user::User;
-use crate::domain::models::comment::Comment;
+use crate::domain::models::attachment::Attachment;
+use crate::domain::models::comment::{Comment, CommentVisibility};
+use crate::domain::models::remote_link::RemoteLink;
+use crate::domain::models::issue_link::IssueLink;
+use crate::domain::models::dev_info::{Branch, Commit, DevInfo, PullRequest, PullRequestStatus};
+use crate::domain::models::service_request::ServiceRequest;
+use crate::domain::models::sla::Sla;
+use crate::domain::models::approval::{Approval, ApprovalDecision, Approver};
+use crate::domain::models::worklog::Worklog;
+use crate::domain::models::permission::{Permission, ProjectPermissions};
+use crate::domain::models::issue_property::IssueProperty;
+use crate::utils::html::html_to_text;
 use crate::utils::{LazyJiraError, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde_json::Value;
+use std::collections::BTreeMap;
 
 /// Parse a Jira issue JSON response into a Ticket
 pub fn parse_issue(json: &Value) -> Result<Ticket> {
@@ -32,9 +48,35 @@ pub fn parse_issue(json: &Value) -> Result<Ticket> {
     let status = parse_status(fields)?;
     let priority = parse_priority(fields)?;
     let assignee = parse_assignee(fields)?;
+    let reporter = match fields.get("reporter") {
+        None | Some(Value::Null) => None,
+        Some(reporter_obj) => Some(parse_user(reporter_obj)?),
+    };
     let issue_type = parse_issue_type(fields)?;
     let project_key = parse_project_key(fields)?;
-    let description = parse_description(fields)?;
+    let description = parse_description(fields, json["renderedFields"]["description"].as_str())?;
+    let parent = parse_parent(fields);
+    let story_points = parse_story_points(fields);
+    let resolved = parse_datetime(fields, "resolutiondate").ok();
+    let date = |field: &str| {
+        fields
+            .get(field)
+            .and_then(|v| v.as_str())
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+    };
+    let start_date = date(START_DATE_FIELD);
+    let due_date = date("duedate");
+    let attachments = parse_attachments(fields);
+    let labels = fields
+        .get("labels")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str().map(str::to_string))
+        .collect();
+    let service_request = parse_service_request(json, fields);
+    let links = parse_issue_links(fields);
+    let custom_fields = parse_custom_fields(fields);
     let created = parse_datetime(fields, "created")?;
     let updated = parse_datetime(fields, "updated")?;
 
@@ -44,15 +86,219 @@ pub fn parse_issue(json: &Value) -> Result<Ticket> {
         summary,
         status,
         assignee,
+        reporter,
         priority,
         issue_type,
         project_key,
         description,
+        parent,
+        story_points,
+        resolved,
+        start_date,
+        due_date,
+        attachments,
+        labels,
+        service_request,
+        links,
+        custom_fields,
         created,
         updated,
     })
 }
 
+/// Parse the custom fields set on the issue as display text, so aliased
+/// fields can be shown whatever their type
+fn parse_custom_fields(fields: &Value) -> BTreeMap<String, String> {
+    fields
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(id, _)| id.starts_with("customfield_"))
+        .filter_map(|(id, value)| Some((id.clone(), custom_field_text(value)?)))
+        .collect()
+}
+
+/// Text of a custom field's value: options and users by their name,
+/// several values joined; None when it is unset
+fn custom_field_text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(s) => s.trim().to_string(),
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if f.fract() == 0.0 && f.abs() < 1e15 => format!("{}", f as i64),
+            _ => n.to_string(),
+        },
+        Value::Bool(b) => if *b { "Yes" } else { "No" }.to_string(),
+        Value::Array(items) => items
+            .iter()
+            .filter_map(custom_field_text)
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(_) => ["value", "name", "displayName", "key"]
+            .iter()
+            .find_map(|key| value.get(*key).and_then(|v| v.as_str()))?
+            .to_string(),
+        Value::Null => return None,
+    };
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// Parse the issue's attachments, skipping entries without content
+fn parse_attachments(fields: &Value) -> Vec<Attachment> {
+    fields
+        .get("attachment")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|attachment| {
+            Some(Attachment {
+                id: parse_agile_id(attachment, "attachment").ok()?,
+                filename: attachment.get("filename")?.as_str()?.to_string(),
+                mime_type: attachment
+                    .get("mimeType")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("application/octet-stream")
+                    .to_string(),
+                size: attachment.get("size").and_then(|v| v.as_u64()).unwrap_or(0),
+                content_url: attachment.get("content")?.as_str()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Names Jira Service Management gives the fields holding a request's
+/// participants and organizations, whose ids differ per site
+const PARTICIPANTS_FIELD: &str = "Request participants";
+const ORGANIZATIONS_FIELD: &str = "Organizations";
+
+/// Parse the customer request of a Jira Service Management issue from its
+/// request type field, which carries the portal link alongside the type.
+/// Participants and organizations are found through the field names the
+/// issue is fetched with (`expand=names`)
+fn parse_service_request(json: &Value, fields: &Value) -> Option<ServiceRequest> {
+    let request = fields
+        .as_object()?
+        .values()
+        .find(|value| value.get("requestType").is_some())?;
+    let request_type = request["requestType"].get("name")?.as_str()?.to_string();
+    let portal_url = request
+        .pointer("/_links/web")
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+
+    let named = |name: &str| -> Vec<Value> {
+        json.get("names")
+            .and_then(|names| names.as_object())
+            .into_iter()
+            .flatten()
+            .filter(|(_, field_name)| field_name.as_str() == Some(name))
+            .filter_map(|(id, _)| fields.get(id)?.as_array().cloned())
+            .flatten()
+            .collect()
+    };
+    let participants = named(PARTICIPANTS_FIELD)
+        .iter()
+        .filter_map(|user| parse_user(user).ok())
+        .collect();
+    let organizations = named(ORGANIZATIONS_FIELD)
+        .iter()
+        .filter_map(|organization| organization.get("name")?.as_str().map(str::to_string))
+        .collect();
+
+    Some(ServiceRequest {
+        request_type,
+        portal_url,
+        participants,
+        organizations,
+    })
+}
+
+/// Custom field holding the "Start date" of an issue on Jira Cloud
+const START_DATE_FIELD: &str = "customfield_10015";
+
+/// Custom fields commonly holding story points on Jira Cloud
+/// ("Story point estimate" and classic "Story Points")
+const STORY_POINT_FIELDS: [&str; 2] = ["customfield_10016", "customfield_10026"];
+
+/// Parse story points from the first known story point field that is set
+fn parse_story_points(fields: &Value) -> Option<f64> {
+    STORY_POINT_FIELDS
+        .iter()
+        .find_map(|field| fields.get(*field).and_then(|v| v.as_f64()))
+}
+
+/// Parse parent issue reference from fields object
+fn parse_parent(fields: &Value) -> Option<IssueRef> {
+    let parent_obj = fields.get("parent").filter(|v| !v.is_null())?;
+    let key = parent_obj.get("key").and_then(|v| v.as_str())?.to_string();
+    let parent_fields = parent_obj.get("fields");
+
+    let summary = parent_fields
+        .and_then(|f| f.get("summary"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+
+    let issue_type = parent_fields
+        .and_then(|f| f.get("issuetype"))
+        .and_then(|t| t.get("name"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+
+    Some(IssueRef {
+        key,
+        summary,
+        issue_type,
+    })
+}
+
+/// Parse the issue's links to other issues, from whichever side of each
+/// link the issue is on
+fn parse_issue_links(fields: &Value) -> Vec<IssueLink> {
+    fields
+        .get("issuelinks")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|link| {
+            let link_type = link.get("type")?;
+            let (outward, other) = match link.get("outwardIssue") {
+                Some(issue) => (true, issue),
+                None => (false, link.get("inwardIssue")?),
+            };
+            let description = link_type
+                .get(if outward { "outward" } else { "inward" })?
+                .as_str()?
+                .to_string();
+            let other_fields = other.get("fields")?;
+            Some(IssueLink {
+                id: link.get("id")?.as_str()?.to_string(),
+                link_type: link_type.get("name")?.as_str()?.to_string(),
+                description,
+                outward,
+                issue: IssueRef {
+                    key: other.get("key")?.as_str()?.to_string(),
+                    summary: other_fields
+                        .get("summary")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string(),
+                    issue_type: other_fields
+                        .pointer("/issuetype/name")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string(),
+                },
+                status: parse_status(other_fields).ok()?,
+            })
+        })
+        .collect()
+}
+
 /// Parse status from fields object
 fn parse_status(fields: &Value) -> Result<Status> {
     let status_obj = fields
@@ -135,36 +381,45 @@ fn parse_priority(fields: &Value) -> Result<Priority> {
 
 /// Parse assignee from fields object
 fn parse_assignee(fields: &Value) -> Result<Option<User>> {
-    let assignee_obj = fields.get("assignee");
-
-    if assignee_obj.is_none() || assignee_obj.unwrap().is_null() {
-        return Ok(None);
+    match fields.get("assignee") {
+        None | Some(Value::Null) => Ok(None),
+        Some(assignee_obj) => parse_user(assignee_obj).map(Some),
     }
+}
 
-    let assignee_obj = assignee_obj.unwrap();
-
-    let account_id = assignee_obj
+/// Parse a user object
+pub fn parse_user(user_obj: &Value) -> Result<User> {
+    let account_id = user_obj
         .get("accountId")
         .and_then(|v| v.as_str())
-        .ok_or_else(|| LazyJiraError::Parse("Missing assignee 'accountId' field".to_string()))?
+        .ok_or_else(|| LazyJiraError::Parse("Missing user 'accountId' field".to_string()))?
         .to_string();
 
-    let display_name = assignee_obj
+    let display_name = user_obj
         .get("displayName")
         .and_then(|v| v.as_str())
         .unwrap_or("Unknown")
         .to_string();
 
-    let email_address = assignee_obj
+    let email_address = user_obj
         .get("emailAddress")
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
 
-    Ok(Some(User {
+    Ok(User {
         account_id,
         display_name,
         email_address,
-    }))
+    })
+}
+
+/// Parse the user array returned by GET /rest/api/3/user/assignable/search
+pub fn parse_users(json: &Value) -> Result<Vec<User>> {
+    json.as_array()
+        .ok_or_else(|| LazyJiraError::Parse("Expected an array of users".to_string()))?
+        .iter()
+        .map(parse_user)
+        .collect()
 }
 
 /// Parse issue type from fields object
@@ -193,8 +448,10 @@ fn parse_project_key(fields: &Value) -> Result<String> {
         .ok_or_else(|| LazyJiraError::Parse("Missing project 'key' field".to_string()))
 }
 
-/// Parse description from fields object (simplified - converts Atlassian Document Format to plain text)
-fn parse_description(fields: &Value) -> Result<Option<String>> {
+/// Parse description from fields object, converting Atlassian Document
+/// Format to Markdown. When it holds nodes only Jira can render, such as
+/// media or macros, the HTML Jira rendered is used instead if it was sent
+fn parse_description(fields: &Value, rendered: Option<&str>) -> Result<Option<String>> {
     let description_obj = fields.get("description");
 
     if description_obj.is_none() || description_obj.unwrap().is_null() {
@@ -206,45 +463,294 @@ fn parse_description(fields: &Value) -> Result<Option<String>> {
     // Try to extract text from Atlassian Document Format
     // This is a simplified version - full implementation would handle all ADF node types
     if let Some(content) = description_obj.get("content").and_then(|c| c.as_array()) {
-        let mut text_parts = Vec::new();
-        extract_text_from_adf(content, &mut text_parts);
-        if text_parts.is_empty() {
+        let text = AdfText::markdown(content);
+        if text.unrendered {
+            let rendered = rendered.map(html_to_text).filter(|text| !text.is_empty());
+            if rendered.is_some() {
+                return Ok(rendered);
+            }
+        }
+        if text.lines.is_empty() {
             Ok(None)
         } else {
-            Ok(Some(text_parts.join("\n")))
+            Ok(Some(text.text()))
         }
     } else {
         Ok(None)
     }
 }
 
-/// Recursively extract text from Atlassian Document Format
-fn extract_text_from_adf(content: &[Value], text_parts: &mut Vec<String>) {
-    for node in content {
-        if let Some(node_type) = node.get("type").and_then(|v| v.as_str()) {
-            match node_type {
-                "paragraph" | "heading" | "listItem" => {
-                    if let Some(node_content) = node.get("content").and_then(|c| c.as_array()) {
-                        extract_text_from_adf(node_content, text_parts);
+/// Node types that reference content held by Jira, such as attachments
+/// and macros, and so cannot be rendered from the document alone
+const UNRENDERED_NODES: &[&str] = &[
+    "media",
+    "mediaSingle",
+    "mediaGroup",
+    "mediaInline",
+    "extension",
+    "inlineExtension",
+    "bodiedExtension",
+    "multiBodiedExtension",
+];
+
+/// Common emoji shortcodes, for emoji nodes that carry no unicode text
+const EMOJI_SHORTCODES: &[(&str, &str)] = &[
+    (":+1:", "👍"),
+    (":thumbsup:", "👍"),
+    (":-1:", "👎"),
+    (":thumbsdown:", "👎"),
+    (":smile:", "😄"),
+    (":slight_smile:", "🙂"),
+    (":grinning:", "😀"),
+    (":laughing:", "😆"),
+    (":joy:", "😂"),
+    (":wink:", "😉"),
+    (":heart:", "❤️"),
+    (":tada:", "🎉"),
+    (":fire:", "🔥"),
+    (":rocket:", "🚀"),
+    (":eyes:", "👀"),
+    (":pray:", "🙏"),
+    (":clap:", "👏"),
+    (":thinking:", "🤔"),
+    (":warning:", "⚠️"),
+    (":white_check_mark:", "✅"),
+    (":check_mark:", "✔️"),
+    (":x:", "❌"),
+    (":cross_mark:", "❌"),
+    (":question:", "❓"),
+    (":bulb:", "💡"),
+    (":bug:", "🐛"),
+    (":sob:", "😭"),
+    (":slight_frown:", "🙁"),
+];
+
+/// Unicode emoji for a shortcode such as `:smile:`
+fn emoji_for_shortcode(short_name: &str) -> Option<&'static str> {
+    EMOJI_SHORTCODES
+        .iter()
+        .find(|(code, _)| *code == short_name)
+        .map(|(_, emoji)| *emoji)
+}
+
+/// Text of an Atlassian Document Format body, one line per block: plain, or
+/// Markdown keeping headings, lists, quotes, code blocks and inline marks
+#[derive(Debug, Default)]
+struct AdfText {
+    lines: Vec<String>,
+    line: String,
+    /// Mentions as rendered in the text, e.g. "@Jane Doe"
+    mentions: Vec<String>,
+    markdown: bool,
+    /// Open lists, innermost last, with the next number of ordered ones
+    lists: Vec<Option<u64>>,
+    /// Depth of blockquotes around the current block
+    quotes: usize,
+    /// A list item marker was written and awaits the item's text
+    item_open: bool,
+    /// Nodes were met that only Jira can render, e.g. media or macros
+    unrendered: bool,
+}
+
+impl AdfText {
+    fn from_content(content: &[Value]) -> Self {
+        let mut text = Self::default();
+        text.walk(content);
+        text.end_line();
+        text
+    }
+
+    fn markdown(content: &[Value]) -> Self {
+        let mut text = Self {
+            markdown: true,
+            ..Self::default()
+        };
+        text.walk(content);
+        text.end_line();
+        text
+    }
+
+    fn end_line(&mut self) {
+        if !self.line.is_empty() {
+            self.lines.push(std::mem::take(&mut self.line));
+        }
+    }
+
+    /// Append inline text, starting the line with the quote markers it is in
+    fn push(&mut self, text: &str) {
+        if self.line.is_empty() {
+            self.line.push_str(&"> ".repeat(self.quotes));
+        }
+        self.line.push_str(text);
+    }
+
+    /// Append inline nodes to the current line; other nodes are blocks that
+    /// end it once their content is written
+    fn walk(&mut self, content: &[Value]) {
+        for node in content {
+            let attrs = &node["attrs"];
+            match node.get("type").and_then(|v| v.as_str()) {
+                Some("text") => {
+                    if let Some(text) = node.get("text").and_then(|v| v.as_str()) {
+                        if self.markdown {
+                            self.push(&with_marks(text, node["marks"].as_array()));
+                        } else {
+                            self.push(text);
+                            // Keep the target of a link whose text hides it
+                            if let Some(href) = link_href(node["marks"].as_array()) {
+                                if href != text {
+                                    self.push(&format!(" ({})", href));
+                                }
+                            }
+                        }
                     }
                 }
-                "text" => {
-                    if let Some(text) = node.get("text").and_then(|v| v.as_str()) {
-                        text_parts.push(text.to_string());
+                Some("hardBreak") => self.end_line(),
+                Some("mention") => {
+                    let name = attrs["text"]
+                        .as_str()
+                        .filter(|t| !t.is_empty())
+                        .or_else(|| attrs["id"].as_str())
+                        .unwrap_or("unknown");
+                    let mention = if name.starts_with('@') {
+                        name.to_string()
+                    } else {
+                        format!("@{}", name)
+                    };
+                    self.push(&mention);
+                    self.mentions.push(mention);
+                }
+                Some("emoji") => {
+                    let short_name = attrs["shortName"].as_str().unwrap_or_default();
+                    let emoji = attrs["text"]
+                        .as_str()
+                        .filter(|t| !t.is_empty())
+                        .or_else(|| emoji_for_shortcode(short_name))
+                        .unwrap_or(short_name);
+                    self.push(emoji);
+                }
+                Some("inlineCard") => {
+                    if let Some(url) = attrs["url"].as_str() {
+                        self.push(url);
                     }
                 }
-                "hardBreak" => {
-                    text_parts.push("\n".to_string());
+                kind => {
+                    if kind.is_some_and(|kind| UNRENDERED_NODES.contains(&kind)) {
+                        self.unrendered = true;
+                    }
+                    let node_content = node
+                        .get("content")
+                        .and_then(|c| c.as_array())
+                        .map(Vec::as_slice)
+                        .unwrap_or_default();
+                    if self.markdown {
+                        self.markdown_block(kind.unwrap_or_default(), attrs, node_content);
+                    } else {
+                        self.walk(node_content);
+                        self.end_line();
+                    }
                 }
-                _ => {
-                    // Recursively process nested content
-                    if let Some(node_content) = node.get("content").and_then(|c| c.as_array()) {
-                        extract_text_from_adf(node_content, text_parts);
+            }
+        }
+    }
+
+    fn markdown_block(&mut self, kind: &str, attrs: &Value, content: &[Value]) {
+        match kind {
+            "heading" => {
+                self.end_line();
+                let level = attrs["level"].as_u64().unwrap_or(1).clamp(1, 6) as usize;
+                self.push(&format!("{} ", "#".repeat(level)));
+                self.walk(content);
+            }
+            "bulletList" | "orderedList" => {
+                self.end_line();
+                let start = (kind == "orderedList").then(|| attrs["order"].as_u64().unwrap_or(1));
+                self.lists.push(start);
+                self.walk(content);
+                self.lists.pop();
+            }
+            "listItem" => {
+                self.end_line();
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
                     }
+                    _ => "- ".to_string(),
+                };
+                self.push(&format!("{}{}", "  ".repeat(depth), marker));
+                self.item_open = true;
+                self.walk(content);
+                self.item_open = false;
+            }
+            "codeBlock" => {
+                self.end_line();
+                self.push(&format!("```{}", attrs["language"].as_str().unwrap_or_default()));
+                self.end_line();
+                let code: String = content
+                    .iter()
+                    .filter_map(|node| node.get("text").and_then(|v| v.as_str()))
+                    .collect();
+                for line in code.lines() {
+                    self.push(line);
+                    self.lines.push(std::mem::take(&mut self.line));
                 }
+                self.push("```");
+            }
+            "blockquote" => {
+                self.end_line();
+                self.quotes += 1;
+                self.walk(content);
+                self.end_line();
+                self.quotes -= 1;
+            }
+            "rule" => {
+                self.end_line();
+                self.push("---");
+            }
+            _ => {
+                // The first paragraph of a list item continues its marker
+                if !std::mem::take(&mut self.item_open) {
+                    self.end_line();
+                }
+                self.walk(content);
             }
         }
+        self.end_line();
+    }
+
+    fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+/// Text wrapped in the Markdown of its ADF marks
+fn with_marks(text: &str, marks: Option<&Vec<Value>>) -> String {
+    let mut text = text.to_string();
+    for mark in marks.into_iter().flatten() {
+        text = match mark["type"].as_str() {
+            Some("strong") => format!("**{}**", text),
+            Some("em") => format!("*{}*", text),
+            Some("code") => format!("`{}`", text),
+            Some("strike") => format!("~~{}~~", text),
+            Some("link") => match mark["attrs"]["href"].as_str() {
+                Some(href) => format!("[{}]({})", text, href),
+                None => text,
+            },
+            _ => text,
+        };
     }
+    text
+}
+
+/// Target of the link mark among `marks`, if any
+fn link_href(marks: Option<&Vec<Value>>) -> Option<&str> {
+    marks
+        .into_iter()
+        .flatten()
+        .find(|mark| mark["type"] == "link")
+        .and_then(|mark| mark["attrs"]["href"].as_str())
 }
 
 /// Parse datetime from fields object
@@ -275,6 +781,188 @@ fn parse_datetime(fields: &Value, field_name: &str) -> Result<DateTime<Utc>> {
         })
 }
 
+/// Parse the web links of an issue from the remote link API response
+pub fn parse_remote_links(json: &Value) -> Result<Vec<RemoteLink>> {
+    let links = json
+        .as_array()
+        .ok_or_else(|| LazyJiraError::Parse("Expected an array of remote links".to_string()))?;
+    Ok(links
+        .iter()
+        .filter_map(|link| {
+            let object = &link["object"];
+            let url = object["url"].as_str().filter(|url| !url.is_empty())?;
+            let id = match &link["id"] {
+                Value::Number(id) => id.to_string(),
+                id => id.as_str().unwrap_or_default().to_string(),
+            };
+            Some(RemoteLink {
+                id,
+                title: object["title"].as_str().unwrap_or(url).to_string(),
+                url: url.to_string(),
+                application: link["application"]["name"].as_str().map(str::to_string),
+                application_type: link["application"]["type"].as_str().map(str::to_string),
+            })
+        })
+        .collect())
+}
+
+/// Parse the property keys of GET /issue/{key}/properties
+pub fn parse_issue_property_keys(json: &Value) -> Result<Vec<String>> {
+    let keys = json["keys"]
+        .as_array()
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'keys' array in issue properties".to_string()))?;
+    Ok(keys
+        .iter()
+        .filter_map(|key| key["key"].as_str().map(str::to_string))
+        .collect())
+}
+
+/// Parse a property of GET /issue/{key}/properties/{propertyKey}
+pub fn parse_issue_property(json: &Value) -> Result<IssueProperty> {
+    let key = json["key"]
+        .as_str()
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'key' in issue property".to_string()))?;
+    Ok(IssueProperty {
+        key: key.to_string(),
+        value: json.get("value").cloned().unwrap_or(Value::Null),
+    })
+}
+
+/// Parse the SLAs of GET /rest/servicedeskapi/request/{key}/sla, from the
+/// ongoing cycle or else the last completed one; SLAs that have not started
+/// are left out
+pub fn parse_slas(json: &Value) -> Result<Vec<Sla>> {
+    let values = json["values"]
+        .as_array()
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'values' array in SLA response".to_string()))?;
+    let millis = |value: &Value| value["millis"].as_i64().map(Duration::milliseconds);
+    Ok(values
+        .iter()
+        .filter_map(|sla| {
+            let name = sla["name"].as_str()?.to_string();
+            let ongoing = sla.get("ongoingCycle").filter(|cycle| cycle.is_object());
+            let cycle = match ongoing {
+                Some(cycle) => cycle,
+                None => sla["completedCycles"].as_array()?.last()?,
+            };
+            Some(Sla {
+                name,
+                goal: millis(&cycle["goalDuration"]),
+                remaining: millis(&cycle["remainingTime"]),
+                breach_time: ongoing
+                    .and_then(|cycle| cycle["breachTime"]["epochMillis"].as_i64())
+                    .and_then(DateTime::from_timestamp_millis),
+                paused: cycle["paused"].as_bool().unwrap_or(false),
+                completed: ongoing.is_none(),
+                breached: cycle["breached"].as_bool().unwrap_or(false),
+            })
+        })
+        .collect())
+}
+
+/// Parse the approvals of GET /rest/servicedeskapi/request/{key}/approval
+pub fn parse_approvals(json: &Value) -> Result<Vec<Approval>> {
+    let values = json["values"].as_array().ok_or_else(|| {
+        LazyJiraError::Parse("Missing 'values' array in approval response".to_string())
+    })?;
+    let decision = |value: &Value| ApprovalDecision::from_api(value.as_str().unwrap_or_default());
+    values
+        .iter()
+        .map(|approval| {
+            let approvers = approval["approvers"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|approver| {
+                    Some(Approver {
+                        user: parse_user(&approver["approver"]).ok()?,
+                        decision: decision(&approver["approverDecision"]),
+                    })
+                })
+                .collect();
+            Ok(Approval {
+                id: parse_agile_id(approval, "approval")?,
+                name: approval["name"].as_str().unwrap_or_default().to_string(),
+                decision: decision(&approval["finalDecision"]),
+                can_answer: approval["canAnswerApprovalDecision"].as_bool().unwrap_or(false),
+                approvers,
+            })
+        })
+        .collect()
+}
+
+/// Parse the worklogs of GET /rest/api/3/issue/{key}/worklog; entries
+/// without an author or start time are skipped
+pub fn parse_worklogs(json: &Value) -> Result<Vec<Worklog>> {
+    let worklogs = json["worklogs"].as_array().ok_or_else(|| {
+        LazyJiraError::Parse("Missing 'worklogs' array in worklog response".to_string())
+    })?;
+    Ok(worklogs
+        .iter()
+        .filter_map(|worklog| {
+            Some(Worklog {
+                id: worklog["id"].as_str()?.to_string(),
+                author: parse_user(&worklog["author"]).ok()?,
+                started: parse_datetime(worklog, "started").ok()?,
+                time_spent_seconds: worklog["timeSpentSeconds"].as_u64().unwrap_or(0),
+            })
+        })
+        .collect())
+}
+
+/// Development tools (e.g. "GitHub") with branches, commits or pull
+/// requests for an issue, from the dev-status summary response
+pub fn parse_dev_status_instances(json: &Value) -> Vec<String> {
+    let mut instances: Vec<String> = Vec::new();
+    let summary = json["summary"].as_object().into_iter().flatten();
+    for (_, data) in summary {
+        let by_instance = data["byInstanceType"].as_object().into_iter().flatten();
+        for (instance, counts) in by_instance {
+            let count = counts["count"].as_u64().unwrap_or(0);
+            if count > 0 && !instances.contains(instance) {
+                instances.push(instance.clone());
+            }
+        }
+    }
+    instances
+}
+
+/// Add the branches, commits and pull requests of a dev-status detail
+/// response to `info`
+pub fn parse_dev_status_detail(json: &Value, info: &mut DevInfo) {
+    let text = |value: &Value| value.as_str().unwrap_or_default().to_string();
+    for detail in json["detail"].as_array().into_iter().flatten() {
+        for branch in detail["branches"].as_array().into_iter().flatten() {
+            info.branches.push(Branch {
+                name: text(&branch["name"]),
+                repository: text(&branch["repository"]["name"]),
+                url: text(&branch["url"]),
+            });
+        }
+        for repository in detail["repositories"].as_array().into_iter().flatten() {
+            for commit in repository["commits"].as_array().into_iter().flatten() {
+                let id = commit["displayId"].as_str().or_else(|| commit["id"].as_str());
+                let message = commit["message"].as_str().unwrap_or_default();
+                info.commits.push(Commit {
+                    id: id.unwrap_or_default().to_string(),
+                    message: message.lines().next().unwrap_or_default().to_string(),
+                    author: text(&commit["author"]["name"]),
+                    url: text(&commit["url"]),
+                });
+            }
+        }
+        for pull_request in detail["pullRequests"].as_array().into_iter().flatten() {
+            info.pull_requests.push(PullRequest {
+                id: text(&pull_request["id"]),
+                title: text(&pull_request["name"]),
+                status: PullRequestStatus::from_api(&text(&pull_request["status"])),
+                repository: text(&pull_request["repositoryName"]),
+                url: text(&pull_request["url"]),
+            });
+        }
+    }
+}
+
 /// Parse comments from Jira comments API response
 pub fn parse_comments(json: &Value) -> Result<Vec<Comment>> {
     log::debug!("parse_comments: Starting to parse comments");
@@ -300,7 +988,7 @@ pub fn parse_comments(json: &Value) -> Result<Vec<Comment>> {
                 } else {
                     let available_keys: Vec<String> = json
                         .as_object()
-                        .map(|obj| obj.keys().map(|k| k.clone()).collect())
+                        .map(|obj| obj.keys().cloned().collect())
                         .unwrap_or_default();
                     log::error!("parse_comments: 'comments' field is not an array. Available keys: {:?}", available_keys);
                     return Err(LazyJiraError::Parse(format!(
@@ -312,7 +1000,7 @@ pub fn parse_comments(json: &Value) -> Result<Vec<Comment>> {
             None => {
                 let available_keys: Vec<String> = json
                     .as_object()
-                    .map(|obj| obj.keys().map(|k| k.clone()).collect())
+                    .map(|obj| obj.keys().cloned().collect())
                     .unwrap_or_default();
                 log::error!("parse_comments: Missing 'comments' field. Available keys: {:?}", available_keys);
                 return Err(LazyJiraError::Parse(format!(
@@ -381,20 +1069,15 @@ pub fn parse_comments(json: &Value) -> Result<Vec<Comment>> {
         };
 
         // Extract text from Atlassian Document Format
-        let mut body_parts = Vec::new();
+        let mut adf_text = AdfText::default();
         if let Some(body_obj) = comment_json.get("body") {
             if let Some(content) = body_obj.get("content").and_then(|c| c.as_array()) {
-                extract_text_from_adf(content, &mut body_parts);
+                adf_text = AdfText::from_content(content);
             }
         } else {
             eprintln!("Warning: Comment {} has no body, using empty string", id);
         }
-        
-        let body = if body_parts.is_empty() {
-            "".to_string()
-        } else {
-            body_parts.join("\n")
-        };
+        let body = adf_text.text();
 
         let created = match parse_datetime(comment_json, "created") {
             Ok(dt) => dt,
@@ -411,12 +1094,16 @@ pub fn parse_comments(json: &Value) -> Result<Vec<Comment>> {
                 parse_datetime(comment_json, "updated").ok()
             });
 
+        let visibility = parse_comment_visibility(comment_json);
+
         comments.push(Comment {
             id,
             author,
             body,
             created,
             updated,
+            visibility,
+            mentions: adf_text.mentions,
         });
         log::debug!("parse_comments: Successfully parsed comment {}", comments.len());
     }
@@ -425,6 +1112,346 @@ pub fn parse_comments(json: &Value) -> Result<Vec<Comment>> {
     Ok(comments)
 }
 
+/// Read a comment's role/group restriction, or the Jira Service Management
+/// "internal" flag stored in its `sd.public.comment` property
+fn parse_comment_visibility(comment_json: &Value) -> CommentVisibility {
+    if let Some(visibility) = comment_json.get("visibility") {
+        let value = visibility
+            .get("value")
+            .or_else(|| visibility.get("identifier"))
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        match visibility.get("type").and_then(|v| v.as_str()) {
+            Some("role") => return CommentVisibility::Role(value),
+            Some("group") => return CommentVisibility::Group(value),
+            _ => {}
+        }
+    }
+
+    let internal = comment_json
+        .get("properties")
+        .and_then(|p| p.as_array())
+        .into_iter()
+        .flatten()
+        .any(|property| {
+            property.get("key").and_then(|k| k.as_str()) == Some("sd.public.comment")
+                && property["value"]["internal"].as_bool() == Some(true)
+        });
+    if internal {
+        CommentVisibility::Internal
+    } else {
+        CommentVisibility::Public
+    }
+}
+
+/// Parse role names from the project roles response, a map of name to URL
+pub fn parse_project_roles(json: &Value) -> Result<Vec<String>> {
+    let roles = json
+        .as_object()
+        .ok_or_else(|| LazyJiraError::Parse("Expected an object of project roles".to_string()))?;
+    let mut names: Vec<String> = roles.keys().cloned().collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Parse group names from a `myself?expand=groups` response
+pub fn parse_user_groups(json: &Value) -> Result<Vec<String>> {
+    let items = json["groups"]["items"]
+        .as_array()
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'groups.items' in user response".to_string()))?;
+    Ok(items
+        .iter()
+        .filter_map(|group| group.get("name").and_then(|n| n.as_str()))
+        .map(|name| name.to_string())
+        .collect())
+}
+
+/// Parse the permissions granted in a `mypermissions` response
+pub fn parse_permissions(json: &Value) -> Result<ProjectPermissions> {
+    let permissions = json["permissions"]
+        .as_object()
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'permissions' in mypermissions response".to_string()))?;
+    Ok(ProjectPermissions::new(permissions.iter().filter_map(|(key, permission)| {
+        permission["havePermission"]
+            .as_bool()
+            .unwrap_or(false)
+            .then(|| Permission::from_key(key))
+            .flatten()
+    })))
+}
+
+/// Get the "values" array of a paginated Agile API response
+fn agile_values<'a>(json: &'a Value, what: &str) -> Result<&'a Vec<Value>> {
+    json.get("values")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| LazyJiraError::Parse(format!("Missing 'values' array in {} response", what)))
+}
+
+/// Read an id that the Agile API may return as a number or a string
+fn parse_agile_id(json: &Value, what: &str) -> Result<String> {
+    match json.get("id") {
+        Some(Value::Number(n)) => Ok(n.to_string()),
+        Some(Value::String(s)) => Ok(s.clone()),
+        _ => Err(LazyJiraError::Parse(format!("Missing {} 'id' field", what))),
+    }
+}
+
+/// Parse a board from the Agile API
+pub fn parse_board(json: &Value) -> Result<Board> {
+    let id = parse_agile_id(json, "board")?;
+
+    let name = json
+        .get("name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| LazyJiraError::Parse("Missing board 'name' field".to_string()))?
+        .to_string();
+
+    let board_type = match json.get("type").and_then(|v| v.as_str()) {
+        Some("scrum") => BoardType::Scrum,
+        _ => BoardType::Kanban,
+    };
+
+    Ok(Board {
+        id,
+        name,
+        board_type,
+    })
+}
+
+/// Parse a list of boards from GET /rest/agile/1.0/board
+pub fn parse_boards(json: &Value) -> Result<Vec<Board>> {
+    agile_values(json, "board")?.iter().map(parse_board).collect()
+}
+
+/// Parse a sprint from the Agile API
+pub fn parse_sprint(json: &Value) -> Result<Sprint> {
+    let id = parse_agile_id(json, "sprint")?;
+
+    let name = json
+        .get("name")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| LazyJiraError::Parse("Missing sprint 'name' field".to_string()))?
+        .to_string();
+
+    let state = match json.get("state").and_then(|v| v.as_str()) {
+        Some("active") => SprintState::Active,
+        Some("closed") => SprintState::Closed,
+        Some("future") => SprintState::Future,
+        other => {
+            return Err(LazyJiraError::Parse(format!(
+                "Unknown sprint state: {:?}",
+                other
+            )));
+        }
+    };
+
+    let goal = json
+        .get("goal")
+        .and_then(|v| v.as_str())
+        .filter(|g| !g.is_empty())
+        .map(|g| g.to_string());
+
+    Ok(Sprint {
+        id,
+        name,
+        state,
+        start_date: parse_datetime(json, "startDate").ok(),
+        end_date: parse_datetime(json, "endDate").ok(),
+        complete_date: parse_datetime(json, "completeDate").ok(),
+        goal,
+    })
+}
+
+/// Parse a list of sprints from GET /rest/agile/1.0/board/{id}/sprint
+pub fn parse_sprints(json: &Value) -> Result<Vec<Sprint>> {
+    agile_values(json, "sprint")?.iter().map(parse_sprint).collect()
+}
+
+/// Parse a project with its issue types
+pub fn parse_project(json: &Value) -> Result<Project> {
+    let field = |name: &str| {
+        json.get(name)
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .ok_or_else(|| LazyJiraError::Parse(format!("Missing project '{}' field", name)))
+    };
+
+    let issue_types = json
+        .get("issueTypes")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|issue_type| {
+            Some(IssueType {
+                id: issue_type.get("id")?.as_str()?.to_string(),
+                name: issue_type.get("name")?.as_str()?.to_string(),
+                subtask: issue_type.get("subtask").and_then(|v| v.as_bool()).unwrap_or(false),
+            })
+        })
+        .collect();
+
+    Ok(Project {
+        id: field("id")?,
+        key: field("key")?,
+        name: field("name")?,
+        issue_types,
+    })
+}
+
+/// Parse a page of projects from GET /rest/api/3/project/search
+pub fn parse_projects(json: &Value) -> Result<Vec<Project>> {
+    agile_values(json, "project")?.iter().map(parse_project).collect()
+}
+
+/// Parse the versions of GET /rest/api/3/project/{key}/versions, leaving
+/// out archived ones
+pub fn parse_versions(json: &Value) -> Result<Vec<Version>> {
+    let versions = json
+        .as_array()
+        .ok_or_else(|| LazyJiraError::Parse("Expected an array of versions".to_string()))?;
+    let date = |version: &Value, field: &str| {
+        version[field]
+            .as_str()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+    };
+    versions
+        .iter()
+        .filter(|version| !version["archived"].as_bool().unwrap_or(false))
+        .map(|version| {
+            Ok(Version {
+                id: parse_agile_id(version, "version")?,
+                name: version["name"].as_str().unwrap_or_default().to_string(),
+                start_date: date(version, "startDate"),
+                release_date: date(version, "releaseDate"),
+                released: version["released"].as_bool().unwrap_or(false),
+            })
+        })
+        .collect()
+}
+
+/// Parse the column mapping from GET /rest/agile/1.0/board/{id}/configuration
+pub fn parse_board_columns(json: &Value) -> Result<Vec<BoardColumnConfig>> {
+    let columns = json
+        .get("columnConfig")
+        .and_then(|v| v.get("columns"))
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'columnConfig.columns' in board configuration".to_string()))?;
+    // Limits are kept but ignored by Jira when the board has no constraint
+    let constrained = json
+        .get("columnConfig")
+        .and_then(|v| v.get("constraintType"))
+        .and_then(|v| v.as_str())
+        .is_some_and(|constraint| constraint != "none");
+    let limit = |column: &Value, field: &str| {
+        column
+            .get(field)
+            .and_then(|v| v.as_u64())
+            .filter(|_| constrained)
+            .map(|limit| limit as u32)
+    };
+
+    columns
+        .iter()
+        .map(|column| {
+            let name = column
+                .get("name")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| LazyJiraError::Parse("Missing column 'name' field".to_string()))?
+                .to_string();
+            let status_ids = column
+                .get("statuses")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|status| parse_agile_id(status, "status").ok())
+                .collect();
+            Ok(BoardColumnConfig {
+                name,
+                status_ids,
+                min: limit(column, "min"),
+                max: limit(column, "max"),
+            })
+        })
+        .collect()
+}
+
+/// Parse status transitions from GET /rest/api/3/issue/{key}/changelog
+pub fn parse_status_changes(json: &Value) -> Result<Vec<StatusChange>> {
+    let histories = json
+        .get("values")
+        .or_else(|| json.get("histories"))
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'values' array in changelog response".to_string()))?;
+
+    let mut changes = Vec::new();
+    for history in histories {
+        let at = parse_datetime(history, "created")?;
+        let items = history.get("items").and_then(|v| v.as_array());
+        for item in items.into_iter().flatten() {
+            if item.get("field").and_then(|v| v.as_str()) != Some("status") {
+                continue;
+            }
+            changes.push(StatusChange {
+                at,
+                from: item.get("fromString").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                to: item.get("toString").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            });
+        }
+    }
+
+    changes.sort_by_key(|change| change.at);
+    Ok(changes)
+}
+
+/// Parse every field change of a changelog, newest first
+pub fn parse_field_changes(json: &Value) -> Result<Vec<FieldChange>> {
+    let histories = json
+        .get("values")
+        .or_else(|| json.get("histories"))
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'histories' array in changelog".to_string()))?;
+
+    let mut changes = Vec::new();
+    for history in histories {
+        let at = parse_datetime(history, "created")?;
+        let author = history.get("author").and_then(|author| parse_user(author).ok());
+        let items = history.get("items").and_then(|v| v.as_array());
+        for item in items.into_iter().flatten() {
+            let Some(field) = item.get("fieldId").or_else(|| item.get("field")).and_then(|v| v.as_str()) else {
+                continue;
+            };
+            changes.push(FieldChange {
+                at,
+                author: author.clone(),
+                field: field.to_string(),
+                from: item.get("fromString").and_then(|v| v.as_str()).map(str::to_string),
+                to: item.get("toString").and_then(|v| v.as_str()).map(str::to_string),
+            });
+        }
+    }
+
+    changes.sort_by_key(|change| std::cmp::Reverse(change.at));
+    Ok(changes)
+}
+
+/// Parse the "issues" array of an Agile API issue listing, skipping issues that fail to parse
+pub fn parse_agile_issues(json: &Value) -> Result<Vec<Ticket>> {
+    let issues = json
+        .get("issues")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| LazyJiraError::Parse("Missing 'issues' array in response".to_string()))?;
+
+    let mut tickets = Vec::new();
+    for issue in issues {
+        match parse_issue(issue) {
+            Ok(ticket) => tickets.push(ticket),
+            Err(e) => log::warn!("parse_agile_issues: Failed to parse issue: {}", e),
+        }
+    }
+    Ok(tickets)
+}
+
 /// Parse search results from Jira search API response
 /// Note: This function is kept for potential future use or testing
 #[allow(dead_code)]
@@ -453,7 +1480,7 @@ pub fn parse_search_results(json: &Value) -> Result<(usize, usize, usize, Vec<Ti
             // Debug: log what keys are available
             let available_keys: Vec<String> = json
                 .as_object()
-                .map(|obj| obj.keys().map(|k| k.clone()).collect())
+                .map(|obj| obj.keys().cloned().collect())
                 .unwrap_or_default();
             LazyJiraError::Parse(format!(
                 "Missing 'issues' or 'values' array. Available keys: {:?}",
@@ -544,6 +1571,64 @@ mod tests {
         assert_eq!(ticket.priority, Priority::High);
         assert!(ticket.assignee.is_some());
         assert_eq!(ticket.assignee.as_ref().unwrap().display_name, "John Doe");
+        assert!(ticket.service_request.is_none());
+    }
+
+    #[test]
+    fn test_parse_custom_fields() {
+        let fields = serde_json::json!({
+            "summary": "Ignored",
+            "customfield_10016": 5.0,
+            "customfield_10017": 2.5,
+            "customfield_10201": {"id": "7", "value": "Platform"},
+            "customfield_10202": [{"name": "api"}, {"name": "web"}],
+            "customfield_10203": {"accountId": "1", "displayName": "Alice"},
+            "customfield_10204": null,
+            "customfield_10205": []
+        });
+        let custom_fields = parse_custom_fields(&fields);
+
+        assert_eq!(custom_fields.len(), 5);
+        assert_eq!(custom_fields["customfield_10016"], "5");
+        assert_eq!(custom_fields["customfield_10017"], "2.5");
+        assert_eq!(custom_fields["customfield_10201"], "Platform");
+        assert_eq!(custom_fields["customfield_10202"], "api, web");
+        assert_eq!(custom_fields["customfield_10203"], "Alice");
+    }
+
+    #[test]
+    fn test_parse_service_request() {
+        let json = serde_json::json!({
+            "id": "10400",
+            "key": "HELP-7",
+            "names": {
+                "customfield_10010": "Request Type",
+                "customfield_10026": "Request participants",
+                "customfield_10002": "Organizations"
+            },
+            "fields": {
+                "summary": "VPN drops every hour",
+                "status": {"id": "1", "name": "Waiting for support", "statusCategory": {"key": "new"}},
+                "priority": {"name": "Medium"},
+                "issuetype": {"name": "Service Request"},
+                "project": {"key": "HELP"},
+                "customfield_10010": {
+                    "_links": {"web": "https://company.atlassian.net/servicedesk/customer/portal/2/HELP-7"},
+                    "requestType": {"id": "12", "name": "Get IT help"}
+                },
+                "customfield_10026": [{"accountId": "qm:1", "displayName": "Jane Customer"}],
+                "customfield_10002": [{"id": "1", "name": "Acme Corp"}],
+                "created": "2024-01-15T10:30:00.000+0000",
+                "updated": "2024-01-16T14:20:00.000+0000"
+            }
+        });
+        let request = parse_issue(&json).unwrap().service_request.unwrap();
+        assert_eq!(request.request_type, "Get IT help");
+        assert_eq!(
+            request.portal_url.as_deref(),
+            Some("https://company.atlassian.net/servicedesk/customer/portal/2/HELP-7")
+        );
+        assert_eq!(request.shared_with(), "Jane Customer, Acme Corp");
     }
 
     #[test]
@@ -585,6 +1670,595 @@ mod tests {
         assert!(ticket.assignee.is_none());
     }
 
+    #[test]
+    fn test_parse_issue_with_epic_parent() {
+        let json_str = r#"
+        {
+          "id": "10002",
+          "key": "PROJ-125",
+          "fields": {
+            "summary": "Child story",
+            "status": {
+              "id": "1",
+              "name": "To Do",
+              "statusCategory": { "key": "new" }
+            },
+            "issuetype": { "name": "Story" },
+            "project": { "key": "PROJ" },
+            "parent": {
+              "key": "PROJ-100",
+              "fields": {
+                "summary": "Authentication epic",
+                "issuetype": { "name": "Epic" }
+              }
+            },
+            "created": "2024-01-15T10:30:00.000+0000",
+            "updated": "2024-01-15T10:30:00.000+0000"
+          }
+        }
+        "#;
+        let json: Value = serde_json::from_str(json_str).unwrap();
+        let ticket = parse_issue(&json).unwrap();
+
+        let epic = ticket.epic().unwrap();
+        assert_eq!(epic.key, "PROJ-100");
+        assert_eq!(epic.summary, "Authentication epic");
+    }
+
+    #[test]
+    fn test_parse_issue_with_points_and_resolution() {
+        let json = serde_json::json!({
+            "id": "10003",
+            "key": "PROJ-126",
+            "fields": {
+                "summary": "Estimated story",
+                "status": {
+                    "id": "5",
+                    "name": "Done",
+                    "statusCategory": { "key": "done" }
+                },
+                "issuetype": { "name": "Story" },
+                "project": { "key": "PROJ" },
+                "customfield_10016": 3.0,
+                "resolutiondate": "2024-01-17T09:00:00.000+0000",
+                "duedate": "2024-01-20",
+                "created": "2024-01-15T10:30:00.000+0000",
+                "updated": "2024-01-17T09:00:00.000+0000"
+            }
+        });
+        let ticket = parse_issue(&json).unwrap();
+
+        assert_eq!(ticket.story_points, Some(3.0));
+        assert!(ticket.resolved.is_some());
+        assert_eq!(ticket.due_date, NaiveDate::from_ymd_opt(2024, 1, 20));
+    }
+
+    #[test]
+    fn test_parse_issue_attachments() {
+        let json = serde_json::json!({
+            "id": "10004",
+            "key": "PROJ-127",
+            "fields": {
+                "summary": "With screenshot",
+                "status": { "id": "1", "name": "To Do", "statusCategory": { "key": "new" } },
+                "issuetype": { "name": "Bug" },
+                "project": { "key": "PROJ" },
+                "attachment": [
+                    {
+                        "id": "10100",
+                        "filename": "crash.png",
+                        "mimeType": "image/png",
+                        "size": 2048,
+                        "content": "https://example.atlassian.net/rest/api/3/attachment/content/10100"
+                    },
+                    { "id": "10101", "filename": "broken.log" }
+                ],
+                "created": "2024-01-15T10:30:00.000+0000",
+                "updated": "2024-01-15T10:30:00.000+0000"
+            }
+        });
+        let ticket = parse_issue(&json).unwrap();
+
+        assert_eq!(ticket.attachments.len(), 1);
+        assert_eq!(ticket.attachments[0].filename, "crash.png");
+        assert!(ticket.attachments[0].is_image());
+    }
+
+    #[test]
+    fn test_parse_description_as_markdown() {
+        let json: Value = serde_json::from_str(
+            r#"{
+              "id": "10005",
+              "key": "PROJ-128",
+              "fields": {
+                "summary": "Formatted",
+                "status": { "id": "1", "name": "To Do", "statusCategory": { "key": "new" } },
+                "issuetype": { "name": "Bug" },
+                "project": { "key": "PROJ" },
+                "description": {
+                  "type": "doc",
+                  "content": [
+                    { "type": "heading", "attrs": { "level": 2 },
+                      "content": [{ "type": "text", "text": "Steps" }] },
+                    { "type": "orderedList", "content": [
+                      { "type": "listItem", "content": [{ "type": "paragraph", "content": [
+                        { "type": "text", "text": "Run " },
+                        { "type": "text", "text": "make", "marks": [{ "type": "code" }] }
+                      ] }] },
+                      { "type": "listItem", "content": [
+                        { "type": "paragraph", "content": [{ "type": "text", "text": "See" }] },
+                        { "type": "bulletList", "content": [
+                          { "type": "listItem", "content": [{ "type": "paragraph", "content": [
+                            { "type": "text", "text": "docs", "marks": [
+                              { "type": "link", "attrs": { "href": "https://example.com" } }
+                            ] }
+                          ] }] }
+                        ] }
+                      ] }
+                    ] },
+                    { "type": "codeBlock", "attrs": { "language": "rust" },
+                      "content": [{ "type": "text", "text": "fn main() {\n}" }] },
+                    { "type": "blockquote", "content": [{ "type": "paragraph", "content": [
+                      { "type": "text", "text": "Really", "marks": [{ "type": "strong" }] }
+                    ] }] }
+                  ]
+                },
+                "created": "2024-01-15T10:30:00.000+0000",
+                "updated": "2024-01-15T10:30:00.000+0000"
+              }
+            }"#,
+        )
+        .unwrap();
+        let ticket = parse_issue(&json).unwrap();
+
+        assert_eq!(
+            ticket.description.as_deref(),
+            Some(
+                "## Steps\n1. Run `make`\n2. See\n  - [docs](https://example.com)\n\
+                 ```rust\nfn main() {\n}\n```\n> **Really**"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_description_falls_back_to_rendered_html() {
+        let description = serde_json::json!({
+            "type": "doc",
+            "content": [
+                { "type": "paragraph", "content": [{ "type": "text", "text": "Screenshot:" }] },
+                { "type": "mediaSingle", "content": [
+                    { "type": "media", "attrs": { "id": "abc", "type": "file", "collection": "" } }
+                ] }
+            ]
+        });
+        let fields = serde_json::json!({ "description": description });
+        let html = "<p>Screenshot:</p>\n<p><span class=\"image-wrap\"><img src=\"/rest/api/3/attachment/content/1\" alt=\"crash.png\"></span></p>";
+
+        assert_eq!(
+            parse_description(&fields, Some(html)).unwrap().as_deref(),
+            Some("Screenshot:\n[image: crash.png]")
+        );
+        // Without rendered HTML the document's own text is still shown
+        assert_eq!(parse_description(&fields, None).unwrap().as_deref(), Some("Screenshot:"));
+
+        let plain = serde_json::json!({ "description": {
+            "type": "doc",
+            "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "Plain" }] }]
+        } });
+        assert_eq!(parse_description(&plain, Some("<p>Other</p>")).unwrap().as_deref(), Some("Plain"));
+    }
+
+    #[test]
+    fn test_parse_status_changes() {
+        let json = serde_json::json!({
+            "isLast": true,
+            "values": [
+                {
+                    "created": "2024-01-16T09:00:00.000+0000",
+                    "items": [
+                        { "field": "assignee", "fromString": null, "toString": "John" },
+                        { "field": "status", "fromString": "To Do", "toString": "In Progress" }
+                    ]
+                },
+                {
+                    "created": "2024-01-18T09:00:00.000+0000",
+                    "items": [
+                        { "field": "status", "fromString": "In Progress", "toString": "Done" }
+                    ]
+                }
+            ]
+        });
+
+        let changes = parse_status_changes(&json).unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].from, "To Do");
+        assert_eq!(changes[0].to, "In Progress");
+        assert_eq!(changes[1].to, "Done");
+    }
+
+    #[test]
+    fn test_parse_permissions() {
+        let json = serde_json::json!({
+            "permissions": {
+                "ASSIGN_ISSUES": { "key": "ASSIGN_ISSUES", "havePermission": true },
+                "TRANSITION_ISSUES": { "key": "TRANSITION_ISSUES", "havePermission": false },
+                "BROWSE_PROJECTS": { "key": "BROWSE_PROJECTS", "havePermission": true }
+            }
+        });
+        let permissions = parse_permissions(&json).unwrap();
+        assert!(permissions.allows(Permission::AssignIssues));
+        assert!(!permissions.allows(Permission::TransitionIssues));
+        assert!(!permissions.allows(Permission::AddComments));
+        assert!(parse_permissions(&serde_json::json!({})).is_err());
+    }
+
+    #[test]
+    fn test_parse_issue_properties() {
+        let keys = serde_json::json!({
+            "keys": [
+                { "self": "https://x/rest/api/3/issue/PROJ-1/properties/automation", "key": "automation" },
+                { "self": "https://x/rest/api/3/issue/PROJ-1/properties/app.meta", "key": "app.meta" }
+            ]
+        });
+        assert_eq!(parse_issue_property_keys(&keys).unwrap(), vec!["automation", "app.meta"]);
+        assert!(parse_issue_property_keys(&serde_json::json!({})).is_err());
+
+        let property = parse_issue_property(&serde_json::json!({
+            "key": "automation",
+            "value": { "rule": "Auto-assign", "runs": 3 }
+        }))
+        .unwrap();
+        assert_eq!(property.key, "automation");
+        assert_eq!(property.value["runs"], 3);
+        assert_eq!(property.pretty_lines().len(), 4);
+    }
+
+    #[test]
+    fn test_parse_field_changes() {
+        let json = serde_json::json!({
+            "histories": [
+                {
+                    "author": { "accountId": "abc", "displayName": "Jane Doe" },
+                    "created": "2024-01-16T09:00:00.000+0000",
+                    "items": [
+                        { "field": "assignee", "fieldId": "assignee", "fromString": null, "toString": "John" },
+                        { "field": "status", "fieldId": "status", "fromString": "To Do", "toString": "In Progress" }
+                    ]
+                },
+                {
+                    "created": "2024-01-18T09:00:00.000+0000",
+                    "items": [
+                        { "field": "status", "fromString": "In Progress", "toString": "Done" }
+                    ]
+                }
+            ]
+        });
+
+        let changes = parse_field_changes(&json).unwrap();
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[0].to.as_deref(), Some("Done"));
+        assert_eq!(changes[0].author, None);
+        assert_eq!(changes[1].field, "assignee");
+        assert_eq!(changes[1].from, None);
+        assert_eq!(changes[1].author.as_ref().map(|a| a.display_name.as_str()), Some("Jane Doe"));
+    }
+
+    #[test]
+    fn test_parse_boards() {
+        let json: Value = serde_json::from_str(
+            r#"{
+              "maxResults": 50,
+              "values": [
+                { "id": 84, "name": "Scrum Board", "type": "scrum" },
+                { "id": 92, "name": "Kanban Board", "type": "kanban" }
+              ]
+            }"#,
+        )
+        .unwrap();
+        let boards = parse_boards(&json).unwrap();
+
+        assert_eq!(boards.len(), 2);
+        assert_eq!(boards[0].id, "84");
+        assert!(boards[0].is_scrum());
+        assert_eq!(boards[1].board_type, BoardType::Kanban);
+    }
+
+    #[test]
+    fn test_parse_users() {
+        let json: Value = serde_json::from_str(
+            r#"[
+              { "accountId": "5b10a", "displayName": "Alice", "emailAddress": "alice@example.com" },
+              { "accountId": "5b10b", "displayName": "Bob" }
+            ]"#,
+        )
+        .unwrap();
+        let users = parse_users(&json).unwrap();
+
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].email_address.as_deref(), Some("alice@example.com"));
+        assert_eq!(users[1].display_name, "Bob");
+    }
+
+    #[test]
+    fn test_parse_comment_visibility() {
+        let json: Value = serde_json::from_str(
+            r#"{
+              "comments": [
+                {
+                  "id": "1",
+                  "author": { "accountId": "a1", "displayName": "Alice" },
+                  "body": { "type": "doc", "version": 1, "content": [] },
+                  "created": "2024-01-15T10:30:00.000+0000",
+                  "visibility": { "type": "role", "value": "Developers" }
+                },
+                {
+                  "id": "2",
+                  "author": { "accountId": "a1", "displayName": "Alice" },
+                  "body": { "type": "doc", "version": 1, "content": [] },
+                  "created": "2024-01-15T10:30:00.000+0000",
+                  "properties": [{ "key": "sd.public.comment", "value": { "internal": true } }]
+                },
+                {
+                  "id": "3",
+                  "author": { "accountId": "a1", "displayName": "Alice" },
+                  "body": { "type": "doc", "version": 1, "content": [] },
+                  "created": "2024-01-15T10:30:00.000+0000"
+                }
+              ]
+            }"#,
+        )
+        .unwrap();
+        let comments = parse_comments(&json).unwrap();
+
+        assert_eq!(comments[0].visibility, CommentVisibility::Role("Developers".to_string()));
+        assert_eq!(comments[1].visibility, CommentVisibility::Internal);
+        assert_eq!(comments[2].visibility, CommentVisibility::Public);
+    }
+
+    #[test]
+    fn test_parse_comment_mentions_and_emoji() {
+        let json = serde_json::json!({
+            "comments": [{
+                "id": "1",
+                "author": { "accountId": "a1", "displayName": "Alice" },
+                "created": "2024-01-15T10:30:00.000+0000",
+                "body": {
+                    "type": "doc",
+                    "version": 1,
+                    "content": [
+                        {
+                            "type": "paragraph",
+                            "content": [
+                                { "type": "mention", "attrs": { "id": "b2", "text": "@Bob Smith" } },
+                                { "type": "text", "text": " can you check this? " },
+                                { "type": "emoji", "attrs": { "shortName": ":eyes:" } }
+                            ]
+                        },
+                        {
+                            "type": "paragraph",
+                            "content": [
+                                { "type": "text", "text": "Thanks " },
+                                { "type": "emoji", "attrs": { "shortName": ":custom:", "text": "" } },
+                                { "type": "text", "text": " see " },
+                                { "type": "text", "text": "docs", "marks": [{ "type": "link", "attrs": { "href": "https://docs.example.com" } }] }
+                            ]
+                        }
+                    ]
+                }
+            }]
+        });
+        let comments = parse_comments(&json).unwrap();
+
+        assert_eq!(
+            comments[0].body,
+            "@Bob Smith can you check this? 👀\nThanks :custom: see docs (https://docs.example.com)"
+        );
+        assert_eq!(comments[0].mentions, vec!["@Bob Smith"]);
+    }
+
+    #[test]
+    fn test_parse_roles_and_groups() {
+        let roles = serde_json::json!({
+            "Developers": "https://x/rest/api/3/project/P/role/10001",
+            "Administrators": "https://x/rest/api/3/project/P/role/10002"
+        });
+        assert_eq!(parse_project_roles(&roles).unwrap(), vec!["Administrators", "Developers"]);
+
+        let myself = serde_json::json!({ "groups": { "size": 1, "items": [{ "name": "jira-users" }] } });
+        assert_eq!(parse_user_groups(&myself).unwrap(), vec!["jira-users"]);
+    }
+
+    #[test]
+    fn test_parse_projects() {
+        let json: Value = serde_json::from_str(
+            r#"{
+              "isLast": true,
+              "values": [
+                {
+                  "id": "10001",
+                  "key": "NEW",
+                  "name": "New project",
+                  "issueTypes": [
+                    { "id": "3", "name": "Task", "subtask": false },
+                    { "id": "5", "name": "Sub-task", "subtask": true }
+                  ]
+                }
+              ]
+            }"#,
+        )
+        .unwrap();
+        let projects = parse_projects(&json).unwrap();
+
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].key, "NEW");
+        assert_eq!(projects[0].issue_types.len(), 2);
+        assert!(projects[0].issue_types[1].subtask);
+    }
+
+    #[test]
+    fn test_parse_board_columns() {
+        let json: Value = serde_json::from_str(
+            r#"{
+              "id": 84,
+              "columnConfig": {
+                "columns": [
+                  { "name": "To Do", "statuses": [{ "id": "10000", "self": "x" }] },
+                  { "name": "In Review", "statuses": [{ "id": "10101" }, { "id": "10102" }], "max": 4 },
+                  { "name": "Parked", "statuses": [] }
+                ],
+                "constraintType": "issueCount"
+              }
+            }"#,
+        )
+        .unwrap();
+        let columns = parse_board_columns(&json).unwrap();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[1].name, "In Review");
+        assert_eq!(columns[1].status_ids, vec!["10101", "10102"]);
+        assert!(columns[2].status_ids.is_empty());
+        assert_eq!((columns[0].min, columns[0].max), (None, None));
+        assert_eq!(columns[1].max, Some(4));
+    }
+
+    #[test]
+    fn test_parse_sprints() {
+        let json: Value = serde_json::from_str(
+            r#"{
+              "values": [
+                {
+                  "id": 37,
+                  "state": "active",
+                  "name": "Sprint 1",
+                  "startDate": "2024-01-15T10:30:00.000Z",
+                  "endDate": "2024-01-29T10:30:00.000Z",
+                  "goal": "Ship login"
+                },
+                { "id": 38, "state": "future", "name": "Sprint 2", "goal": "" }
+              ]
+            }"#,
+        )
+        .unwrap();
+        let sprints = parse_sprints(&json).unwrap();
+
+        assert_eq!(sprints.len(), 2);
+        assert_eq!(sprints[0].id, "37");
+        assert!(sprints[0].is_active());
+        assert!(sprints[0].start_date.is_some());
+        assert_eq!(sprints[0].goal.as_deref(), Some("Ship login"));
+        assert!(sprints[1].is_future());
+        assert!(sprints[1].start_date.is_none());
+        assert!(sprints[1].goal.is_none());
+    }
+
+    #[test]
+    fn test_parse_issue_links() {
+        let json: Value = serde_json::from_str(
+            r#"{
+              "id": "1",
+              "key": "PROJ-1",
+              "fields": {
+                "summary": "Test",
+                "status": { "id": "1", "name": "To Do", "statusCategory": { "key": "new" } },
+                "priority": { "name": "Medium", "id": "3" },
+                "issuetype": { "name": "Task" },
+                "project": { "key": "PROJ" },
+                "issuelinks": [
+                  {
+                    "id": "100",
+                    "type": { "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                    "inwardIssue": {
+                      "key": "PROJ-2",
+                      "fields": {
+                        "summary": "Set up the database",
+                        "status": { "id": "3", "name": "In Progress", "statusCategory": { "key": "indeterminate" } },
+                        "issuetype": { "name": "Story" }
+                      }
+                    }
+                  },
+                  {
+                    "id": "101",
+                    "type": { "name": "Relates", "inward": "relates to", "outward": "relates to" },
+                    "outwardIssue": {
+                      "key": "PROJ-3",
+                      "fields": {
+                        "summary": "Related",
+                        "status": { "id": "1", "name": "To Do", "statusCategory": { "key": "new" } }
+                      }
+                    }
+                  }
+                ],
+                "created": "2024-01-15T10:30:00.000+0000",
+                "updated": "2024-01-15T10:30:00.000+0000"
+              }
+            }"#,
+        )
+        .unwrap();
+        let ticket = parse_issue(&json).unwrap();
+
+        assert_eq!(ticket.links.len(), 2);
+        let blocker = &ticket.links[0];
+        assert!(blocker.is_blocked_by());
+        assert_eq!(blocker.description, "is blocked by");
+        assert_eq!(blocker.issue.key, "PROJ-2");
+        assert_eq!(blocker.issue.issue_type, "Story");
+        assert_eq!(blocker.status.category, StatusCategory::InProgress);
+        assert!(ticket.links[1].outward);
+        assert!(!ticket.links[1].blocks());
+    }
+
+    #[test]
+    fn test_parse_versions() {
+        let json: Value = serde_json::from_str(
+            r#"[
+              { "id": "10000", "name": "1.0", "archived": false, "released": true, "releaseDate": "2024-02-01" },
+              { "id": "10001", "name": "0.9", "archived": true, "released": true },
+              {
+                "id": "10002",
+                "name": "2.0",
+                "archived": false,
+                "released": false,
+                "startDate": "2024-02-05",
+                "releaseDate": "2024-04-30"
+              }
+            ]"#,
+        )
+        .unwrap();
+        let versions = parse_versions(&json).unwrap();
+
+        assert_eq!(versions.len(), 2);
+        assert!(versions[0].released);
+        assert_eq!(versions[1].name, "2.0");
+        assert_eq!(versions[1].start_date, NaiveDate::from_ymd_opt(2024, 2, 5));
+        assert_eq!(versions[1].release_date, NaiveDate::from_ymd_opt(2024, 4, 30));
+    }
+
+    #[test]
+    fn test_parse_worklogs() {
+        let json: Value = serde_json::from_str(
+            r#"{
+              "startAt": 0,
+              "total": 2,
+              "worklogs": [
+                {
+                  "id": "100",
+                  "author": { "accountId": "5b10a", "displayName": "Jane Doe" },
+                  "started": "2024-01-15T09:00:00.000+0100",
+                  "timeSpentSeconds": 5400
+                },
+                { "id": "101", "started": "2024-01-16T09:00:00.000+0000", "timeSpentSeconds": 60 }
+              ]
+            }"#,
+        )
+        .unwrap();
+        let worklogs = parse_worklogs(&json).unwrap();
+
+        assert_eq!(worklogs.len(), 1);
+        assert_eq!(worklogs[0].author.account_id, "5b10a");
+        assert_eq!(worklogs[0].started.to_rfc3339(), "2024-01-15T08:00:00+00:00");
+        assert_eq!(worklogs[0].time_spent_seconds, 5400);
+    }
+
     #[test]
     fn test_parse_search_results() {
         let json_str = r#"
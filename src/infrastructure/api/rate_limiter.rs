@@ -3,6 +3,29 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 
+/// Request budget a client keeps to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitProfile {
+    /// Jira Cloud's 100 requests per minute
+    JiraCloud,
+    /// `requests` per `per`, e.g. for a Data Center instance with its own
+    /// limits; both must be non-zero
+    Custom { requests: usize, per: Duration },
+    /// No client-side limit, for tests and instances without one
+    Unlimited,
+}
+
+impl RateLimitProfile {
+    pub fn limiter(self) -> RateLimiter {
+        match self {
+            RateLimitProfile::JiraCloud => RateLimiter::jira_cloud(),
+            RateLimitProfile::Custom { requests, per } => RateLimiter::new(requests, per, requests),
+            // Never refilled, and never runs out in practice
+            RateLimitProfile::Unlimited => RateLimiter::new(usize::MAX, Duration::from_secs(60), 0),
+        }
+    }
+}
+
 /// Rate limiter for API requests
 /// Implements token bucket algorithm
 pub struct RateLimiter {
@@ -51,7 +74,8 @@ impl RateLimiter {
 
             if elapsed >= self.refill_interval {
                 let refills = (elapsed.as_secs_f64() / self.refill_interval.as_secs_f64()) as usize;
-                *tokens = (*tokens + refills * self.tokens_per_refill).min(self.max_tokens);
+                let refilled = refills.saturating_mul(self.tokens_per_refill);
+                *tokens = tokens.saturating_add(refilled).min(self.max_tokens);
                 *last_refill = now;
             }
 
@@ -81,7 +105,8 @@ impl RateLimiter {
 
         if elapsed >= self.refill_interval {
             let refills = (elapsed.as_secs_f64() / self.refill_interval.as_secs_f64()) as usize;
-            *tokens = (*tokens + refills * self.tokens_per_refill).min(self.max_tokens);
+            let refilled = refills.saturating_mul(self.tokens_per_refill);
+            *tokens = tokens.saturating_add(refilled).min(self.max_tokens);
             *last_refill = now;
         }
 
@@ -129,6 +154,14 @@ mod tests {
         assert!(limiter.try_acquire_token().await);
     }
 
+    #[tokio::test]
+    async fn test_rate_limiter_refill_saturates() {
+        let limiter = RateLimiter::new(usize::MAX, Duration::from_nanos(1), usize::MAX);
+        *limiter.tokens.lock().await = 0;
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        assert!(limiter.try_acquire_token().await);
+    }
+
     #[tokio::test]
     async fn test_rate_limiter_wait_for_token() {
         let limiter = RateLimiter::new(1, Duration::from_millis(50), 1);
@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU16, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Number of HTTP requests kept for the debug view
+pub const REQUEST_LOG_LIMIT: usize = 100;
+
+/// One HTTP request made to Jira, including its retries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestRecord {
+    pub timestamp: DateTime<Utc>,
+    pub method: &'static str,
+    /// Path and query of the request URL
+    pub endpoint: String,
+    /// Status of the last attempt; None when no response was received
+    pub status: Option<u16>,
+    /// Time from the first attempt until the final outcome
+    pub latency: Duration,
+    pub retries: usize,
+}
+
+/// Most recent HTTP requests, oldest first
+#[derive(Debug, Default)]
+pub struct RequestLog {
+    records: Mutex<VecDeque<RequestRecord>>,
+}
+
+impl RequestLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember a request, dropping the oldest beyond the limit
+    pub fn record(&self, record: RequestRecord) {
+        if let Ok(mut records) = self.records.lock() {
+            if records.len() == REQUEST_LOG_LIMIT {
+                records.pop_front();
+            }
+            records.push_back(record);
+        }
+    }
+
+    pub fn records(&self) -> Vec<RequestRecord> {
+        self.records
+            .lock()
+            .map(|records| records.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Measures one request across its retry attempts; shared with the retry
+/// closure, which reports each attempt and response status
+#[derive(Debug)]
+pub struct RequestTracker {
+    started: Instant,
+    attempts: AtomicUsize,
+    /// Last response status, 0 until one arrives
+    status: AtomicU16,
+}
+
+impl RequestTracker {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            attempts: AtomicUsize::new(0),
+            status: AtomicU16::new(0),
+        }
+    }
+
+    pub fn attempt(&self) {
+        self.attempts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn response(&self, status: reqwest::StatusCode) {
+        self.status.store(status.as_u16(), Ordering::Relaxed);
+    }
+
+    /// The finished request, for the request log
+    pub fn finish(&self, method: &'static str, url: &str) -> RequestRecord {
+        let status = self.status.load(Ordering::Relaxed);
+        RequestRecord {
+            timestamp: Utc::now(),
+            method,
+            endpoint: endpoint(url),
+            status: (status != 0).then_some(status),
+            latency: self.started.elapsed(),
+            retries: self.attempts.load(Ordering::Relaxed).saturating_sub(1),
+        }
+    }
+}
+
+impl Default for RequestTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Path and query of a URL, without the scheme and host
+fn endpoint(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => match parsed.query() {
+            Some(query) => format!("{}?{}", parsed.path(), query),
+            None => parsed.path().to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker_counts_retries_and_strips_host() {
+        let tracker = RequestTracker::new();
+        tracker.attempt();
+        tracker.response(reqwest::StatusCode::TOO_MANY_REQUESTS);
+        tracker.attempt();
+        tracker.response(reqwest::StatusCode::OK);
+
+        let record = tracker.finish("GET", "https://x.atlassian.net/rest/api/3/search?jql=a");
+        assert_eq!(record.endpoint, "/rest/api/3/search?jql=a");
+        assert_eq!(record.status, Some(200));
+        assert_eq!(record.retries, 1);
+    }
+
+    #[test]
+    fn test_request_log_keeps_recent_records() {
+        let log = RequestLog::new();
+        for i in 0..=REQUEST_LOG_LIMIT {
+            log.record(RequestTracker::new().finish("GET", &format!("https://x/issue/{}", i)));
+        }
+        let records = log.records();
+        assert_eq!(records.len(), REQUEST_LOG_LIMIT);
+        assert_eq!(records[0].endpoint, "/issue/1");
+    }
+}
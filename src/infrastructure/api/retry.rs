@@ -2,6 +2,7 @@ use crate::utils::{LazyJiraError, Result};
 use std::time::Duration;
 
 /// Retry configuration
+#[derive(Debug, Clone)]
 pub struct RetryConfig {
     pub max_retries: usize,
     pub initial_delay: Duration,
@@ -9,6 +10,16 @@ pub struct RetryConfig {
     pub backoff_multiplier: f64,
 }
 
+impl RetryConfig {
+    /// Send each request once
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+}
+
 impl Default for RetryConfig {
     fn default() -> Self {
         Self {
@@ -54,6 +65,13 @@ where
                     return Err(last_error.unwrap());
                 }
 
+                // Don't retry on Jira's 4xx errors, except 429
+                if let LazyJiraError::Jira(error) = last_error.as_ref().unwrap() {
+                    if !error.is_retryable() {
+                        return Err(last_error.unwrap());
+                    }
+                }
+
                 // Don't retry on 4xx errors (except 429 which is handled separately)
                 if let LazyJiraError::Api(msg) = last_error.as_ref().unwrap() {
                     if msg.contains("400") || msg.contains("401") || msg.contains("403") 
@@ -91,10 +109,12 @@ pub fn is_retryable_error(error: &LazyJiraError) -> bool {
             // Retry on 429 (Too Many Requests) and 5xx errors
             msg.contains("429") || msg.contains("500") || msg.contains("502") || msg.contains("503")
         }
+        LazyJiraError::Jira(error) => error.is_retryable(),
         LazyJiraError::Authentication(_) => false,
         LazyJiraError::Validation(_) => false,
         LazyJiraError::Config(_) => false,
         LazyJiraError::Parse(_) => false,
+        LazyJiraError::Script(_) => false,
         LazyJiraError::Internal(_) => false,
         LazyJiraError::Io(_) => true, // IO errors might be transient
     }
@@ -203,7 +223,14 @@ mod tests {
         assert!(is_retryable_error(&LazyJiraError::Api("500 Internal Server Error".to_string())));
         assert!(is_retryable_error(&LazyJiraError::Api("502 Bad Gateway".to_string())));
         
+        assert!(is_retryable_error(&LazyJiraError::Jira(crate::utils::JiraApiError::from_response(
+            503, ""
+        ))));
+
         // Test non-retryable errors
+        assert!(!is_retryable_error(&LazyJiraError::Jira(crate::utils::JiraApiError::from_response(
+            400, ""
+        ))));
         assert!(!is_retryable_error(&LazyJiraError::Authentication("Invalid".to_string())));
         assert!(!is_retryable_error(&LazyJiraError::Validation("Invalid".to_string())));
     }
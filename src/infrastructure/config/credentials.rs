@@ -0,0 +1,182 @@
+use super::JiraConfig;
+use crate::utils::{LazyJiraError, Result};
+use std::path::PathBuf;
+
+/// Environment variable read when `jira.credentials = "env"`
+pub const TOKEN_ENV_VAR: &str = "LAZYJIRA_TOKEN";
+
+/// Where the API token is read from, chosen with `jira.credentials`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialSource {
+    /// The token stored in the jira-cli config
+    JiraCli,
+    /// The `LAZYJIRA_TOKEN` environment variable
+    Env,
+    /// The `machine` entry for the instance in ~/.netrc (or `$NETRC`)
+    Netrc,
+    /// Output of a command, e.g. "pass show jira/token"
+    Command(String),
+}
+
+impl CredentialSource {
+    pub fn from_config(jira: &JiraConfig) -> Self {
+        match jira.credentials.to_lowercase().as_str() {
+            "env" => CredentialSource::Env,
+            "netrc" => CredentialSource::Netrc,
+            "command" => match &jira.token_command {
+                Some(command) => CredentialSource::Command(command.clone()),
+                None => CredentialSource::JiraCli,
+            },
+            _ => CredentialSource::JiraCli,
+        }
+    }
+
+    /// Short description for status output, e.g. "netrc"
+    pub fn label(&self) -> &'static str {
+        match self {
+            CredentialSource::JiraCli => "jira-cli config",
+            CredentialSource::Env => TOKEN_ENV_VAR,
+            CredentialSource::Netrc => "netrc",
+            CredentialSource::Command(_) => "token command",
+        }
+    }
+
+    /// Read the token, plus the login when the source stores one (netrc).
+    /// Not used for `JiraCli`, whose token comes with the jira-cli config
+    pub fn read(&self, instance: &str) -> Result<(Option<String>, String)> {
+        match self {
+            CredentialSource::JiraCli => Err(LazyJiraError::Config(
+                "The jira-cli token is read with the jira-cli config".to_string(),
+            )),
+            CredentialSource::Env => std::env::var(TOKEN_ENV_VAR)
+                .ok()
+                .filter(|token| !token.trim().is_empty())
+                .map(|token| (None, token.trim().to_string()))
+                .ok_or_else(|| LazyJiraError::Config(format!("{} is not set", TOKEN_ENV_VAR))),
+            CredentialSource::Netrc => {
+                let path = netrc_path().ok_or_else(|| {
+                    LazyJiraError::Config("Could not determine the .netrc location".to_string())
+                })?;
+                let content = std::fs::read_to_string(&path).map_err(|e| {
+                    LazyJiraError::Config(format!("Failed to read {}: {}", path.display(), e))
+                })?;
+                parse_netrc(&content, instance).ok_or_else(|| {
+                    LazyJiraError::Config(format!(
+                        "No password for machine {} in {}",
+                        instance,
+                        path.display()
+                    ))
+                })
+            }
+            CredentialSource::Command(command) => run_token_command(command).map(|token| (None, token)),
+        }
+    }
+}
+
+fn netrc_path() -> Option<PathBuf> {
+    std::env::var_os("NETRC")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".netrc")))
+}
+
+/// Login and password of the entry for `machine`, falling back to the
+/// `default` entry
+pub fn parse_netrc(content: &str, machine: &str) -> Option<(Option<String>, String)> {
+    /// One entry; `machine` is None for the default entry
+    #[derive(Default)]
+    struct Entry {
+        machine: Option<String>,
+        login: Option<String>,
+        password: Option<String>,
+    }
+
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut tokens = content.split_whitespace();
+    while let Some(token) = tokens.next() {
+        match token {
+            "machine" => entries.push(Entry {
+                machine: Some(tokens.next().unwrap_or_default().to_string()),
+                ..Entry::default()
+            }),
+            "default" => entries.push(Entry::default()),
+            "login" => {
+                if let Some(entry) = entries.last_mut() {
+                    entry.login = tokens.next().map(str::to_string);
+                }
+            }
+            "password" => {
+                if let Some(entry) = entries.last_mut() {
+                    entry.password = tokens.next().map(str::to_string);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let credentials =
+        |entry: &Entry| entry.password.clone().map(|password| (entry.login.clone(), password));
+    entries
+        .iter()
+        .filter(|entry| entry.machine.as_deref() == Some(machine))
+        .find_map(credentials)
+        .or_else(|| entries.iter().filter(|entry| entry.machine.is_none()).find_map(credentials))
+}
+
+/// Run a token command through the shell and return its trimmed output
+fn run_token_command(command: &str) -> Result<String> {
+    #[cfg(windows)]
+    let output = std::process::Command::new("cmd").arg("/C").arg(command).output();
+    #[cfg(not(windows))]
+    let output = std::process::Command::new("sh").arg("-c").arg(command).output();
+
+    let output = output.map_err(|e| {
+        LazyJiraError::Config(format!("Failed to run token command '{}': {}", command, e))
+    })?;
+    if !output.status.success() {
+        return Err(LazyJiraError::Config(format!(
+            "Token command '{}' failed: {}",
+            command,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    // Tools like `pass` print the secret on the first line
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match stdout.lines().next().map(str::trim) {
+        Some(token) if !token.is_empty() => Ok(token.to_string()),
+        _ => Err(LazyJiraError::Config(format!(
+            "Token command '{}' printed nothing",
+            command
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_netrc_matches_machine_then_default() {
+        let netrc = "machine github.com login me password gh\n\
+                     machine acme.atlassian.net\n  login jane@acme.com\n  password s3cret\n\
+                     default login anon password fallback";
+        assert_eq!(
+            parse_netrc(netrc, "acme.atlassian.net"),
+            Some((Some("jane@acme.com".to_string()), "s3cret".to_string()))
+        );
+        assert_eq!(
+            parse_netrc(netrc, "other.atlassian.net"),
+            Some((Some("anon".to_string()), "fallback".to_string()))
+        );
+        assert_eq!(parse_netrc("machine a login b", "a"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_token_command_uses_first_line() {
+        let source = CredentialSource::Command("printf 'tok3n\\nmetadata\\n'".to_string());
+        assert_eq!(source.read("x").unwrap(), (None, "tok3n".to_string()));
+
+        let failing = CredentialSource::Command("exit 1".to_string());
+        assert!(failing.read("x").is_err());
+    }
+}
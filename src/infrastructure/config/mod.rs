@@ -1,12 +1,41 @@
+pub mod credentials;
+pub mod watcher;
+
+use credentials::CredentialSource;
+
+use crate::utils::time::is_valid_date_format;
 use crate::utils::Result;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub jira: JiraConfig,
     pub ui: UiConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    /// User scripts bound to keys, declared as `[[commands]]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<CommandConfig>,
+    /// Saved queries on keys 1–9, declared as `[[filters]]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<FilterConfig>,
+    /// Defaults per project key, declared as `[projects.PROJ]`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub projects: BTreeMap<String, ProjectDefaults>,
+    /// Ticket templates by name, declared as `[templates.bug]`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, TicketTemplate>,
+    /// More Jira instances, each opened in a tab of its own, declared as
+    /// `[[instances]]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instances: Vec<InstanceConfig>,
+    /// Friendly names for custom field ids, declared under `[fields]` as
+    /// e.g. `team = "customfield_10201"`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
 }
 
 /// Jira-specific configuration
@@ -15,19 +44,186 @@ pub struct JiraConfig {
     pub instance: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
+    /// Agile board used for sprint actions; defaults to the first scrum board
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub board_id: Option<u64>,
+    /// File that write operations are appended to as JSON lines
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_log: Option<PathBuf>,
+    /// Address to accept Jira webhooks on (e.g. "127.0.0.1:8765"), so changes
+    /// show up without waiting for the next poll
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_listen: Option<String>,
+    /// Where the API token comes from: "jira-cli", "env" (`LAZYJIRA_TOKEN`),
+    /// "netrc" or "command"
+    #[serde(default = "default_credentials")]
+    pub credentials: String,
+    /// Command printing the token when `credentials = "command"`, e.g.
+    /// "pass show jira/token"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_command: Option<String>,
+    /// JQL for the ticket list
+    #[serde(default = "default_query")]
+    pub default_query: String,
+    /// JQL for the issues whose comments, transitions and assignments make
+    /// up the activity stream
+    #[serde(default = "default_activity_query")]
+    pub activity_query: String,
+}
+
+/// Shell commands run in the background after an action succeeds. Like
+/// `[[commands]]` they get the updated ticket as JSON on stdin and in
+/// `LAZYJIRA_*` environment variables
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HooksConfig {
+    /// After a transition; also gets `LAZYJIRA_TRANSITION_ID` and, when one
+    /// was entered, `LAZYJIRA_COMMENT`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_transition: Option<String>,
+    /// After assigning; also gets `LAZYJIRA_ASSIGNEE_ID`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_assign: Option<String>,
+    /// After commenting; also gets `LAZYJIRA_COMMENT`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_comment: Option<String>,
+}
+
+/// External command run on the focused ticket, which it receives as JSON on
+/// stdin and in `LAZYJIRA_*` environment variables
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandConfig {
+    /// Key that runs the command; keys with a built-in action cannot be bound
+    pub key: char,
+    pub name: String,
+    /// Shell command line, e.g. "~/bin/obsidian-note"
+    pub command: String,
+}
+
+/// Saved query for the ticket list; the first nine are bound to keys 1–9
+/// in the order they are declared
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterConfig {
+    /// Label in the filter bar, e.g. "Blocked tickets"
+    pub name: String,
+    pub jql: String,
+}
+
+/// Jira instance opened in a tab next to the one in `[jira]`, with the same
+/// keys as `[jira]`; everything outside `[jira]` is shared between tabs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceConfig {
+    /// Tab label, e.g. "Acme"
+    pub name: String,
+    #[serde(flatten)]
+    pub jira: JiraConfig,
+}
+
+/// Values suggested when creating tickets in, or acting on tickets of, a
+/// project
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectDefaults {
+    /// Issue type for new tickets, also preselected when moving a ticket here
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+    /// Labels for new tickets; the first one pre-fills the label prompt
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<String>,
+}
+
+/// Ticket created with `lazyjira create <template>`. Text may contain
+/// `{date}`, `{branch}` and `{user}`, filled in at creation time, and any
+/// other `{placeholder}`, which is asked for
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TicketTemplate {
+    pub project: String,
+    /// Falls back to the project's default issue type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+    pub summary: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
 }
 
 /// UI-specific configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UiConfig {
+    /// "default", "high-contrast" or "monochrome"; setting NO_COLOR forces monochrome
     #[serde(default = "default_theme")]
     pub theme: String,
     #[serde(default = "default_true")]
     pub show_avatars: bool,
     #[serde(default = "default_false")]
     pub compact_mode: bool,
+    /// Number the rows of the ticket list, for jumping to one with `:`
+    #[serde(default = "default_false")]
+    pub row_numbers: bool,
     #[serde(default = "default_refresh_interval")]
     pub refresh_interval: u64,
+    /// Swimlane grouping for the board view: "none", "assignee" or "epic"
+    #[serde(default = "default_swimlanes")]
+    pub swimlanes: String,
+    /// Remind about my open tickets due within this many days (0 = overdue and due today)
+    #[serde(default = "default_due_soon_days")]
+    pub due_soon_days: u32,
+    /// Minutes between due-date reminders; 0 only reminds on startup
+    #[serde(default = "default_reminder_interval")]
+    pub reminder_interval: u64,
+    /// Comment prompt when transitioning: "always", "never" or "ask"
+    #[serde(default = "default_transition_comment")]
+    pub transition_comment: String,
+    /// Created, updated and comment times: "relative" ("2h ago") or "absolute"
+    #[serde(default = "default_timestamps")]
+    pub timestamps: String,
+    /// IANA timezone for dates (e.g. "Europe/Berlin"); defaults to the system timezone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// strftime format for dates, e.g. "%d/%m/%Y" or "%G-W%V-%u"
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    /// Minutes of each pomodoro work interval
+    #[serde(default = "default_pomodoro_work_minutes")]
+    pub pomodoro_work_minutes: u64,
+    /// Minutes of each break between pomodoro work intervals
+    #[serde(default = "default_pomodoro_break_minutes")]
+    pub pomodoro_break_minutes: u64,
+    /// Comments listed "oldest" or "newest" first; toggling the order in the
+    /// detail view saves it here
+    #[serde(default = "default_comment_order")]
+    pub comment_order: String,
+    /// Whether to pick up where the last session left off: "ask" on start,
+    /// "always" or "never"
+    #[serde(default = "default_restore_session")]
+    pub restore_session: String,
+    /// Fields shown after the summary in the ticket list, by alias from
+    /// `[fields]` or by field id
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub list_columns: Vec<String>,
+}
+
+/// "Story points" for the alias `story_points`
+fn field_label(alias: &str) -> String {
+    let words = alias.replace('_', " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => words,
+    }
+}
+
+fn default_credentials() -> String {
+    "jira-cli".to_string()
+}
+
+fn default_query() -> String {
+    "assignee = currentUser() ORDER BY updated DESC".to_string()
+}
+
+fn default_activity_query() -> String {
+    "project in projectsWhereUserHasPermission(\"Browse Projects\") ORDER BY updated DESC".to_string()
 }
 
 fn default_theme() -> String {
@@ -46,14 +242,68 @@ fn default_refresh_interval() -> u64 {
     30
 }
 
+fn default_swimlanes() -> String {
+    "none".to_string()
+}
+
+fn default_due_soon_days() -> u32 {
+    2
+}
+
+fn default_reminder_interval() -> u64 {
+    60
+}
+
+fn default_transition_comment() -> String {
+    "ask".to_string()
+}
+
+fn default_timestamps() -> String {
+    "relative".to_string()
+}
+
+fn default_date_format() -> String {
+    "%Y-%m-%d".to_string()
+}
+
+fn default_pomodoro_work_minutes() -> u64 {
+    25
+}
+
+fn default_pomodoro_break_minutes() -> u64 {
+    5
+}
+
+fn default_comment_order() -> String {
+    "oldest".to_string()
+}
+
+fn default_restore_session() -> String {
+    "ask".to_string()
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             jira: JiraConfig {
                 instance: String::new(),
                 username: None,
+                board_id: None,
+                audit_log: None,
+                webhook_listen: None,
+                credentials: default_credentials(),
+                token_command: None,
+                default_query: default_query(),
+                activity_query: default_activity_query(),
             },
             ui: UiConfig::default(),
+            hooks: HooksConfig::default(),
+            commands: Vec::new(),
+            filters: Vec::new(),
+            templates: BTreeMap::new(),
+            projects: BTreeMap::new(),
+            instances: Vec::new(),
+            fields: BTreeMap::new(),
         }
     }
 }
@@ -64,12 +314,145 @@ impl Default for UiConfig {
             theme: default_theme(),
             show_avatars: default_true(),
             compact_mode: default_false(),
+            row_numbers: default_false(),
             refresh_interval: default_refresh_interval(),
+            swimlanes: default_swimlanes(),
+            due_soon_days: default_due_soon_days(),
+            reminder_interval: default_reminder_interval(),
+            transition_comment: default_transition_comment(),
+            timestamps: default_timestamps(),
+            timezone: None,
+            date_format: default_date_format(),
+            pomodoro_work_minutes: default_pomodoro_work_minutes(),
+            pomodoro_break_minutes: default_pomodoro_break_minutes(),
+            comment_order: default_comment_order(),
+            restore_session: default_restore_session(),
+            list_columns: Vec::new(),
         }
     }
 }
 
 impl Config {
+    /// Settings that would silently fall back to a default, described for
+    /// the user; empty when the config is fine
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let choices: [(&str, &str, &[&str]); 6] = [
+            ("credentials", &self.jira.credentials, &["jira-cli", "env", "netrc", "command"]),
+            ("swimlanes", &self.ui.swimlanes, &["none", "assignee", "epic"]),
+            ("transition_comment", &self.ui.transition_comment, &["always", "never", "ask"]),
+            ("timestamps", &self.ui.timestamps, &["relative", "absolute"]),
+            ("comment_order", &self.ui.comment_order, &["oldest", "newest"]),
+            ("restore_session", &self.ui.restore_session, &["ask", "always", "never"]),
+        ];
+        for (name, value, allowed) in choices {
+            if !allowed.contains(&value.to_lowercase().as_str()) {
+                problems.push(format!("{} must be one of {}", name, allowed.join(", ")));
+            }
+        }
+        if self.jira.credentials.eq_ignore_ascii_case("command") && self.jira.token_command.is_none() {
+            problems.push("credentials = \"command\" needs a token_command".to_string());
+        }
+        if self.jira.default_query.trim().is_empty() {
+            problems.push("default_query must not be empty".to_string());
+        }
+        if self.jira.activity_query.trim().is_empty() {
+            problems.push("activity_query must not be empty".to_string());
+        }
+        if let Some(timezone) = &self.ui.timezone {
+            if timezone.trim().parse::<chrono_tz::Tz>().is_err() {
+                problems.push(format!("unknown timezone '{}'", timezone));
+            }
+        }
+        if !is_valid_date_format(&self.ui.date_format) {
+            problems.push(format!("invalid date_format '{}'", self.ui.date_format));
+        }
+        for filter in &self.filters {
+            if filter.jql.trim().is_empty() {
+                problems.push(format!("filter '{}' has no jql", filter.name));
+            }
+        }
+        if self.filters.len() > 9 {
+            problems.push("only the first 9 filters get a key".to_string());
+        }
+        for (i, command) in self.commands.iter().enumerate() {
+            if self.commands[..i].iter().any(|other| other.key == command.key) {
+                problems.push(format!("key '{}' is bound to more than one command", command.key));
+            }
+        }
+        for (alias, id) in &self.fields {
+            if !alias.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                problems.push(format!("field alias '{}' may only use letters, digits and _", alias));
+            }
+            if id.trim().is_empty() {
+                problems.push(format!("field alias '{}' has no field id", alias));
+            }
+        }
+        for column in &self.ui.list_columns {
+            if !self.fields.contains_key(column) && !column.starts_with("customfield_") {
+                problems.push(format!("list column '{}' is neither a field alias nor a custom field id", column));
+            }
+        }
+        for instance in &self.instances {
+            if instance.jira.credentials.eq_ignore_ascii_case("command") && instance.jira.token_command.is_none() {
+                problems.push(format!("instance '{}' uses credentials = \"command\" without a token_command", instance.name));
+            }
+        }
+        problems
+    }
+
+    /// Defaults for a project, matching the key case-insensitively
+    pub fn project_defaults(&self, project_key: &str) -> Option<&ProjectDefaults> {
+        self.projects
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(project_key))
+            .map(|(_, defaults)| defaults)
+    }
+
+    /// Field id for an alias from `[fields]`; anything else is taken to be
+    /// an id already
+    pub fn field_id<'a>(&'a self, name: &'a str) -> &'a str {
+        self.fields.get(name).map(String::as_str).unwrap_or(name)
+    }
+
+    /// Label of a field with an alias, e.g. "Story points" for
+    /// `story_points`
+    pub fn field_label(&self, id: &str) -> Option<String> {
+        let (alias, _) = self.fields.iter().find(|(_, field)| field.as_str() == id)?;
+        Some(field_label(alias))
+    }
+
+    /// Labels and ids of the aliased fields, in alias order
+    pub fn aliased_fields(&self) -> Vec<(String, String)> {
+        self.fields
+            .iter()
+            .map(|(alias, id)| (field_label(alias), id.clone()))
+            .collect()
+    }
+
+    /// Labels and ids of the ticket list's extra columns
+    pub fn list_columns(&self) -> Vec<(String, String)> {
+        self.ui
+            .list_columns
+            .iter()
+            .map(|column| {
+                let id = self.field_id(column).to_string();
+                let label = self.field_label(&id).unwrap_or_else(|| column.clone());
+                (label, id)
+            })
+            .collect()
+    }
+
+    /// The configuration of a tab on another instance: its `[jira]` section
+    /// replaced by the instance's
+    pub fn for_instance(&self, instance: &InstanceConfig) -> Config {
+        Config {
+            jira: instance.jira.clone(),
+            instances: Vec::new(),
+            ..self.clone()
+        }
+    }
+
     /// Load configuration from file
     pub fn load() -> Result<Self> {
         let config_path = Self::config_path()?;
@@ -92,8 +475,53 @@ impl Config {
             )))
     }
 
+    /// Set one string setting in the config file, e.g. `comment_order` under
+    /// `[ui]`, leaving the rest of the file as the user wrote it
+    pub fn save_setting(table: &str, key: &str, value: &str) -> Result<()> {
+        Self::save_setting_to(&Self::config_path()?, table, key, value)
+    }
+
+    /// Set one string setting in a config file, creating the file or the
+    /// table when missing
+    pub fn save_setting_to(path: &Path, table: &str, key: &str, value: &str) -> Result<()> {
+        let content = if path.exists() {
+            std::fs::read_to_string(path).map_err(|e| {
+                crate::utils::LazyJiraError::Config(format!("Failed to read {}: {}", path.display(), e))
+            })?
+        } else {
+            String::new()
+        };
+        let mut document: toml_edit::DocumentMut = content.parse().map_err(|e| {
+            crate::utils::LazyJiraError::Config(format!("Failed to parse {}: {}", path.display(), e))
+        })?;
+        let section = document
+            .entry(table)
+            .or_insert_with(toml_edit::table)
+            .as_table_like_mut()
+            .ok_or_else(|| crate::utils::LazyJiraError::Config(format!("'{}' is not a table", table)))?;
+        match section.get_mut(key).and_then(|item| item.as_value_mut()) {
+            // Keeps the comment at the end of the line
+            Some(existing) => {
+                let decor = existing.decor().clone();
+                *existing = value.into();
+                *existing.decor_mut() = decor;
+            }
+            None => {
+                section.insert(key, toml_edit::value(value));
+            }
+        }
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| {
+                crate::utils::LazyJiraError::Config(format!("Failed to create {}: {}", dir.display(), e))
+            })?;
+        }
+        std::fs::write(path, document.to_string()).map_err(|e| {
+            crate::utils::LazyJiraError::Config(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+
     /// Save configuration to file
-    #[allow(dead_code)] // Will be used when config editing is implemented
     pub fn save(&self) -> Result<()> {
         let config_path = Self::config_path()?;
         let config_dir = config_path.parent().ok_or_else(|| {
@@ -206,29 +634,21 @@ impl Config {
                         .map(|s| s.trim().to_string())
                 });
                 
-                if let Some(user) = username {
-                    Some(JiraCliAuth {
-                        auth_type: "api-token".to_string(),
-                        username: user,
-                        token,
-                    })
-                } else {
-                    None
-                }
+                username.map(|user| JiraCliAuth {
+                    auth_type: "api-token".to_string(),
+                    username: user,
+                    token,
+                })
             } else {
                 // Basic authentication
                 let username = std::env::var("JIRA_USERNAME").ok();
                 let password = std::env::var("JIRA_PASSWORD").ok();
                 
-                if let Some(user) = username {
-                    Some(JiraCliAuth {
-                        auth_type: auth_type.to_string(),
-                        username: user,
-                        token: password, // Store password as token for basic auth
-                    })
-                } else {
-                    None
-                }
+                username.map(|user| JiraCliAuth {
+                    auth_type: auth_type.to_string(),
+                    username: user,
+                    token: password, // Store password as token for basic auth
+                })
             }
         } else {
             None
@@ -244,6 +664,58 @@ impl Config {
         }
     }
 
+    /// The jira-cli config with its token taken from the configured
+    /// credential source. The instance and username come from this config
+    /// when set, otherwise from jira-cli
+    pub fn load_credentials(&self) -> Result<Option<JiraCliConfig>> {
+        self.credentials_from(Self::load_jira_cli_config()?)
+    }
+
+    /// Credentials of `load_credentials`, given jira-cli's config. jira-cli
+    /// only knows one site, so its credentials are refused for any other
+    fn credentials_from(&self, jira_cli_config: Option<JiraCliConfig>) -> Result<Option<JiraCliConfig>> {
+        let source = CredentialSource::from_config(&self.jira);
+        if source == CredentialSource::JiraCli {
+            if let Some(config) = &jira_cli_config {
+                if !self.jira.instance.is_empty() && !same_host(&self.jira.instance, &config.instance) {
+                    return Err(crate::utils::LazyJiraError::Config(format!(
+                        "the jira-cli credentials are for {}, not {}; set credentials to env, netrc or command",
+                        config.instance, self.jira.instance
+                    )));
+                }
+            }
+            return Ok(jira_cli_config);
+        }
+
+        let instance = if self.jira.instance.is_empty() {
+            match &jira_cli_config {
+                Some(config) => config.instance.clone(),
+                None => return Ok(None),
+            }
+        } else {
+            self.jira.instance.clone()
+        };
+        let (login, token) = source.read(&instance)?;
+        let username = login
+            .or_else(|| self.jira.username.clone())
+            .or_else(|| jira_cli_config.map(|config| config.auth.username))
+            .ok_or_else(|| {
+                crate::utils::LazyJiraError::Config(format!(
+                    "No username for the token from {}; set jira.username",
+                    source.label()
+                ))
+            })?;
+
+        Ok(Some(JiraCliConfig {
+            instance,
+            auth: JiraCliAuth {
+                auth_type: "api-token".to_string(),
+                username,
+                token: Some(token),
+            },
+        }))
+    }
+
     /// Get the path to jira-cli configuration file
     /// Checks multiple possible locations:
     /// 1. ~/.config/.jira/.config.yml (jira CLI tool)
@@ -277,6 +749,19 @@ impl Config {
     }
 }
 
+/// Whether two instances, with or without a scheme or path, are one site
+fn same_host(a: &str, b: &str) -> bool {
+    fn host(instance: &str) -> &str {
+        let instance = instance.trim();
+        let instance = instance
+            .strip_prefix("https://")
+            .or_else(|| instance.strip_prefix("http://"))
+            .unwrap_or(instance);
+        instance.split('/').next().unwrap_or(instance)
+    }
+    host(a).eq_ignore_ascii_case(host(b))
+}
+
 /// jira-cli configuration structure
 #[derive(Debug, Clone)]
 pub struct JiraCliConfig {
@@ -298,10 +783,190 @@ mod tests {
     #[test]
     fn test_config_default() {
         let config = Config::default();
+        assert_eq!(config.jira.credentials, "jira-cli");
+        assert_eq!(config.jira.token_command, None);
+        assert_eq!(
+            config.jira.default_query,
+            "assignee = currentUser() ORDER BY updated DESC"
+        );
+        assert!(config.jira.activity_query.starts_with("project in projectsWhereUserHasPermission"));
         assert_eq!(config.ui.theme, "default");
         assert!(config.ui.show_avatars);
         assert!(!config.ui.compact_mode);
+        assert!(!config.ui.row_numbers);
         assert_eq!(config.ui.refresh_interval, 30);
+        assert_eq!(config.ui.swimlanes, "none");
+        assert_eq!(config.ui.due_soon_days, 2);
+        assert_eq!(config.ui.reminder_interval, 60);
+        assert_eq!(config.ui.transition_comment, "ask");
+        assert_eq!(config.ui.timestamps, "relative");
+        assert_eq!(config.ui.timezone, None);
+        assert_eq!(config.ui.date_format, "%Y-%m-%d");
+        assert_eq!(config.ui.pomodoro_work_minutes, 25);
+        assert_eq!(config.ui.pomodoro_break_minutes, 5);
+        assert_eq!(config.ui.comment_order, "oldest");
+        assert_eq!(config.ui.restore_session, "ask");
+        assert_eq!(config.hooks, HooksConfig::default());
+        assert!(config.commands.is_empty());
+        assert!(config.filters.is_empty());
+        assert!(config.projects.is_empty());
+    }
+
+    #[test]
+    fn test_project_defaults() {
+        let config: Config = toml::from_str(
+            r#"
+            [jira]
+            instance = "test.atlassian.net"
+            [ui]
+            [projects.OPS]
+            issue_type = "Incident"
+            labels = ["ops", "triage"]
+            "#,
+        )
+        .unwrap();
+
+        let defaults = config.project_defaults("ops").unwrap();
+        assert_eq!(defaults.issue_type.as_deref(), Some("Incident"));
+        assert_eq!(defaults.labels, vec!["ops", "triage"]);
+        assert_eq!(defaults.component, None);
+        assert!(config.project_defaults("PROJ").is_none());
+    }
+
+    #[test]
+    fn test_save_setting_keeps_the_rest_of_the_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        let original = "# My Jira\n[jira]\ninstance = \"home.atlassian.net\"\n\n[ui]\ntheme = \"dark\" # easier on the eyes\ncomment_order = \"oldest\" # first to last\n";
+        std::fs::write(&path, original).unwrap();
+
+        Config::save_setting_to(&path, "ui", "comment_order", "newest").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            original.replace("\"oldest\"", "\"newest\"")
+        );
+
+        Config::save_setting_to(&path, "ui", "timestamps", "absolute").unwrap();
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(saved.ends_with("comment_order = \"newest\" # first to last\ntimestamps = \"absolute\"\n"));
+        let config: Config = toml::from_str(&saved).unwrap();
+        assert_eq!(config.ui.comment_order, "newest");
+        assert_eq!(config.ui.timestamps, "absolute");
+
+        let missing = dir.path().join("new").join("config.toml");
+        Config::save_setting_to(&missing, "ui", "comment_order", "newest").unwrap();
+        assert_eq!(std::fs::read_to_string(&missing).unwrap(), "[ui]\ncomment_order = \"newest\"\n");
+    }
+
+    #[test]
+    fn test_instances() {
+        let config: Config = toml::from_str(
+            r#"
+            [jira]
+            instance = "home.atlassian.net"
+            [ui]
+            [[filters]]
+            name = "Mine"
+            jql = "assignee = currentUser()"
+            [[instances]]
+            name = "Acme"
+            instance = "acme.atlassian.net"
+            username = "me@acme.com"
+            credentials = "netrc"
+            [[instances]]
+            name = "Ops"
+            instance = "ops.atlassian.net"
+            default_query = "project = OPS ORDER BY created DESC"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.instances.len(), 2);
+        let acme = config.for_instance(&config.instances[0]);
+        assert_eq!(acme.jira.instance, "acme.atlassian.net");
+        assert_eq!(acme.jira.credentials, "netrc");
+        assert_eq!(acme.jira.default_query, default_query());
+        assert_eq!(acme.filters, config.filters);
+        assert!(acme.instances.is_empty());
+
+        // Each instance overrides the ticket list's query on its own
+        let ops = config.for_instance(&config.instances[1]);
+        assert_eq!(ops.jira.default_query, "project = OPS ORDER BY created DESC");
+
+        // Without credentials of its own, an instance must not be handed the
+        // jira-cli login of another site
+        let jira_cli = |instance: &str| JiraCliConfig {
+            instance: instance.to_string(),
+            auth: JiraCliAuth {
+                auth_type: "api-token".to_string(),
+                username: "me@home.com".to_string(),
+                token: Some("secret".to_string()),
+            },
+        };
+        assert!(matches!(
+            ops.credentials_from(Some(jira_cli("home.atlassian.net"))),
+            Err(crate::utils::LazyJiraError::Config(_))
+        ));
+        let credentials = ops
+            .credentials_from(Some(jira_cli("https://OPS.atlassian.net/")))
+            .unwrap()
+            .unwrap();
+        assert_eq!(credentials.auth.username, "me@home.com");
+        assert!(config.credentials_from(Some(jira_cli("home.atlassian.net"))).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_field_aliases() {
+        let config: Config = toml::from_str(
+            r#"
+            [jira]
+            instance = "home.atlassian.net"
+            [ui]
+            list_columns = ["story_points", "customfield_10300"]
+            [fields]
+            story_points = "customfield_10016"
+            team = "customfield_10201"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.validate().is_empty());
+        assert_eq!(config.field_id("team"), "customfield_10201");
+        assert_eq!(config.field_id("customfield_10300"), "customfield_10300");
+        assert_eq!(config.field_label("customfield_10016").as_deref(), Some("Story points"));
+        assert_eq!(config.field_label("customfield_10300"), None);
+        assert_eq!(
+            config.list_columns(),
+            vec![
+                ("Story points".to_string(), "customfield_10016".to_string()),
+                ("customfield_10300".to_string(), "customfield_10300".to_string()),
+            ]
+        );
+
+        let mut broken = config.clone();
+        broken.fields.insert("story points".to_string(), " ".to_string());
+        broken.ui.list_columns.push("sprint".to_string());
+        assert_eq!(broken.validate().len(), 3);
+    }
+
+    #[test]
+    fn test_config_validate() {
+        assert!(Config::default().validate().is_empty());
+
+        let mut config = Config::default();
+        config.ui.swimlanes = "Epic".to_string();
+        config.ui.timezone = Some("Europe/Berlin".to_string());
+        assert!(config.validate().is_empty());
+
+        config.ui.timestamps = "sometimes".to_string();
+        config.ui.timezone = Some("Mars/Olympus".to_string());
+        config.ui.date_format = "%Q".to_string();
+        config.jira.credentials = "command".to_string();
+        let problems = config.validate();
+        assert_eq!(problems.len(), 4);
+        assert!(problems[0].starts_with("timestamps"));
+        assert!(problems[1].contains("token_command"));
+        assert!(problems[2].contains("Mars/Olympus"));
     }
 
     #[test]
@@ -319,13 +984,67 @@ mod tests {
             jira: JiraConfig {
                 instance: "test.atlassian.net".to_string(),
                 username: Some("test@example.com".to_string()),
+                board_id: Some(42),
+                audit_log: Some(PathBuf::from("/tmp/lazyjira-audit.jsonl")),
+                webhook_listen: Some("127.0.0.1:8765".to_string()),
+                credentials: "command".to_string(),
+                token_command: Some("pass show jira/token".to_string()),
+                default_query: "project = PROJ AND resolution IS EMPTY".to_string(),
+                activity_query: "project in (PROJ, OPS) ORDER BY updated DESC".to_string(),
             },
             ui: UiConfig {
                 theme: "dark".to_string(),
                 show_avatars: false,
                 compact_mode: true,
+                row_numbers: true,
                 refresh_interval: 60,
+                swimlanes: "epic".to_string(),
+                due_soon_days: 5,
+                reminder_interval: 0,
+                transition_comment: "always".to_string(),
+                timestamps: "absolute".to_string(),
+                timezone: Some("Europe/Berlin".to_string()),
+                date_format: "%d/%m/%Y".to_string(),
+                pomodoro_work_minutes: 50,
+                pomodoro_break_minutes: 10,
+                comment_order: "newest".to_string(),
+                restore_session: "never".to_string(),
+                list_columns: vec!["team".to_string()],
+            },
+            hooks: HooksConfig {
+                on_transition: Some("notify-slack".to_string()),
+                on_assign: None,
+                on_comment: Some("echo \"$LAZYJIRA_COMMENT\" >> ~/comments.log".to_string()),
             },
+            commands: vec![CommandConfig {
+                key: 'O',
+                name: "Obsidian note".to_string(),
+                command: "obsidian-note".to_string(),
+            }],
+            filters: vec![FilterConfig {
+                name: "Blocked".to_string(),
+                jql: "status = Blocked".to_string(),
+            }],
+            projects: BTreeMap::from([(
+                "PROJ".to_string(),
+                ProjectDefaults {
+                    issue_type: Some("Bug".to_string()),
+                    labels: vec!["backend".to_string()],
+                    component: Some("API".to_string()),
+                },
+            )]),
+            templates: BTreeMap::from([(
+                "bug".to_string(),
+                TicketTemplate {
+                    project: "PROJ".to_string(),
+                    issue_type: Some("Bug".to_string()),
+                    summary: "{component}: {title}".to_string(),
+                    description: Some("Found on {branch} by {user}".to_string()),
+                    labels: vec!["triage".to_string()],
+                },
+            )]),
+            instances: Vec::new(),
+            fields: BTreeMap::from([("team".to_string(), "customfield_10201".to_string())]),
         };
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
@@ -333,9 +1052,33 @@ mod tests {
 
         assert_eq!(config.jira.instance, deserialized.jira.instance);
         assert_eq!(config.jira.username, deserialized.jira.username);
+        assert_eq!(config.jira.board_id, deserialized.jira.board_id);
+        assert_eq!(config.jira.audit_log, deserialized.jira.audit_log);
+        assert_eq!(config.jira.webhook_listen, deserialized.jira.webhook_listen);
+        assert_eq!(config.jira.credentials, deserialized.jira.credentials);
+        assert_eq!(config.jira.token_command, deserialized.jira.token_command);
+        assert_eq!(config.jira.default_query, deserialized.jira.default_query);
+        assert_eq!(config.jira.activity_query, deserialized.jira.activity_query);
         assert_eq!(config.ui.theme, deserialized.ui.theme);
         assert_eq!(config.ui.show_avatars, deserialized.ui.show_avatars);
         assert_eq!(config.ui.compact_mode, deserialized.ui.compact_mode);
+        assert_eq!(config.ui.row_numbers, deserialized.ui.row_numbers);
         assert_eq!(config.ui.refresh_interval, deserialized.ui.refresh_interval);
+        assert_eq!(config.ui.swimlanes, deserialized.ui.swimlanes);
+        assert_eq!(config.ui.due_soon_days, deserialized.ui.due_soon_days);
+        assert_eq!(config.ui.reminder_interval, deserialized.ui.reminder_interval);
+        assert_eq!(config.ui.transition_comment, deserialized.ui.transition_comment);
+        assert_eq!(config.ui.timestamps, deserialized.ui.timestamps);
+        assert_eq!(config.ui.timezone, deserialized.ui.timezone);
+        assert_eq!(config.ui.date_format, deserialized.ui.date_format);
+        assert_eq!(config.ui.comment_order, deserialized.ui.comment_order);
+        assert_eq!(config.ui.restore_session, deserialized.ui.restore_session);
+        assert_eq!(config.hooks, deserialized.hooks);
+        assert_eq!(config.commands, deserialized.commands);
+        assert_eq!(config.filters, deserialized.filters);
+        assert_eq!(config.projects, deserialized.projects);
+        assert_eq!(config.templates, deserialized.templates);
+        assert_eq!(config.fields, deserialized.fields);
+        assert_eq!(config.ui.list_columns, deserialized.ui.list_columns);
     }
 }
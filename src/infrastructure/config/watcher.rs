@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Notices edits to the config file by polling its modification time and
+/// size, which is cheap enough to do on every UI tick
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    path: PathBuf,
+    /// Modification time and size last seen; None while the file is missing
+    stamp: Option<(Option<SystemTime>, u64)>,
+}
+
+impl ConfigWatcher {
+    pub fn new(path: PathBuf) -> Self {
+        let stamp = Self::stamp(&path);
+        Self { path, stamp }
+    }
+
+    /// True once for every change, including the file being created or removed
+    pub fn changed(&mut self) -> bool {
+        let stamp = Self::stamp(&self.path);
+        if stamp == self.stamp {
+            return false;
+        }
+        self.stamp = stamp;
+        true
+    }
+
+    fn stamp(path: &PathBuf) -> Option<(Option<SystemTime>, u64)> {
+        std::fs::metadata(path)
+            .ok()
+            .map(|metadata| (metadata.modified().ok(), metadata.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reports_each_change_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let mut watcher = ConfigWatcher::new(path.clone());
+        assert!(!watcher.changed());
+
+        std::fs::write(&path, "[ui]\n").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        std::fs::write(&path, "[ui]\ntheme = \"dark\"\n").unwrap();
+        assert!(watcher.changed());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(watcher.changed());
+    }
+}
@@ -1,3 +1,5 @@
 pub mod api;
 pub mod config;
+pub mod scripts;
 pub mod storage;
+pub mod webhook;
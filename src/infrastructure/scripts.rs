@@ -0,0 +1,157 @@
+use crate::domain::models::ticket::Ticket;
+use crate::utils::{LazyJiraError, Result};
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// What a user script gets to know about a ticket: the ticket as JSON on
+/// stdin, and its main fields in `LAZYJIRA_*` environment variables
+#[derive(Debug, Clone)]
+pub struct ScriptContext {
+    json: String,
+    env: Vec<(String, String)>,
+}
+
+impl ScriptContext {
+    pub fn for_ticket(ticket: &Ticket, instance_url: &str) -> Result<Self> {
+        let url = format!("https://{}/browse/{}", instance_url, ticket.key);
+        let mut json = serde_json::to_value(ticket)
+            .map_err(|e| LazyJiraError::Parse(format!("Failed to serialize ticket: {}", e)))?;
+        json["url"] = serde_json::Value::String(url.clone());
+
+        let assignee = ticket
+            .assignee
+            .as_ref()
+            .map(|user| user.display_name.clone())
+            .unwrap_or_default();
+        Ok(Self {
+            json: json.to_string(),
+            env: vec![
+                ("LAZYJIRA_KEY".to_string(), ticket.key.clone()),
+                ("LAZYJIRA_SUMMARY".to_string(), ticket.summary.clone()),
+                ("LAZYJIRA_STATUS".to_string(), ticket.status.name.clone()),
+                ("LAZYJIRA_TYPE".to_string(), ticket.issue_type.clone()),
+                ("LAZYJIRA_ASSIGNEE".to_string(), assignee),
+                ("LAZYJIRA_URL".to_string(), url),
+            ],
+        })
+    }
+
+    /// Add an environment variable, replacing one of the same name
+    pub fn with_var(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.env.retain(|(existing, _)| existing != name);
+        self.env.push((name.to_string(), value.into()));
+        self
+    }
+}
+
+/// Run a command through the shell with the given context. Output is
+/// captured since the terminal belongs to the TUI; the first line of stdout
+/// is returned for the status bar
+pub async fn run(command: &str, context: &ScriptContext) -> Result<String> {
+    let mut child = shell(command)
+        .envs(context.env.iter().map(|(name, value)| (name, value)))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // Scripts that ignore stdin may exit before reading it
+        if let Err(e) = stdin.write_all(context.json.as_bytes()).await {
+            log::debug!("run: Script did not read stdin: {}", e);
+        }
+    }
+
+    let output = child.wait_with_output().await?;
+    if output.status.success() {
+        Ok(first_line(&output.stdout))
+    } else {
+        let stderr = first_line(&output.stderr);
+        Err(LazyJiraError::Script(if stderr.is_empty() {
+            format!("'{}' exited with {}", command, output.status)
+        } else {
+            stderr
+        }))
+    }
+}
+
+#[cfg(unix)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
+
+fn first_line(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{Priority, Status, StatusCategory};
+    use chrono::Utc;
+
+    fn create_test_ticket() -> Ticket {
+        Ticket {
+            id: "10000".to_string(),
+            key: "PROJ-1".to_string(),
+            summary: "Fix login".to_string(),
+            status: Status {
+                id: "1".to_string(),
+                name: "To Do".to_string(),
+                category: StatusCategory::ToDo,
+            },
+            assignee: None,
+            reporter: None,
+            priority: Priority::Medium,
+            issue_type: "Bug".to_string(),
+            project_key: "PROJ".to_string(),
+            description: None,
+            parent: None,
+            story_points: None,
+            resolved: None,
+            start_date: None,
+            due_date: None,
+            attachments: Vec::new(),
+            labels: Vec::new(),
+            service_request: None,
+            links: Vec::new(),
+            custom_fields: Default::default(),
+            created: Utc::now(),
+            updated: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_script_gets_ticket_on_stdin_and_env() {
+        let context = ScriptContext::for_ticket(&create_test_ticket(), "x.atlassian.net").unwrap();
+
+        let output = run("echo \"$LAZYJIRA_KEY $LAZYJIRA_STATUS\"", &context).await.unwrap();
+        assert_eq!(output, "PROJ-1 To Do");
+
+        let output = run("grep -o '\"url\":\"[^\"]*\"'", &context).await.unwrap();
+        assert_eq!(output, "\"url\":\"https://x.atlassian.net/browse/PROJ-1\"");
+    }
+
+    #[tokio::test]
+    async fn test_failing_script_reports_stderr() {
+        let context = ScriptContext::for_ticket(&create_test_ticket(), "x.atlassian.net").unwrap();
+        let error = run("echo 'no vault' >&2; exit 3", &context).await.unwrap_err();
+        assert!(error.to_string().contains("no vault"));
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// One write operation sent to Jira
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    /// Issue key(s) or sprint the operation applied to
+    pub target: String,
+    pub action: String,
+    /// Error message when the operation failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AuditEntry {
+    pub fn new(target: impl Into<String>, action: impl Into<String>, error: Option<String>) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            target: target.into(),
+            action: action.into(),
+            error,
+        }
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Write operations performed this session, optionally appended to a file
+/// as JSON lines
+#[derive(Debug, Default)]
+pub struct AuditLog {
+    entries: Mutex<Vec<AuditEntry>>,
+    file: Option<PathBuf>,
+}
+
+impl AuditLog {
+    pub fn new(file: Option<PathBuf>) -> Self {
+        Self {
+            entries: Mutex::new(Vec::new()),
+            file,
+        }
+    }
+
+    /// Remember an entry and append it to the log file, if any
+    pub fn record(&self, entry: AuditEntry) {
+        if let Some(path) = &self.file {
+            if let Err(e) = Self::append(path, &entry) {
+                log::warn!("Failed to write audit log {}: {}", path.display(), e);
+            }
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.push(entry);
+        }
+    }
+
+    /// Entries of this session, oldest first
+    pub fn entries(&self) -> Vec<AuditEntry> {
+        self.entries
+            .lock()
+            .map(|entries| entries.clone())
+            .unwrap_or_default()
+    }
+
+    fn append(path: &PathBuf, entry: &AuditEntry) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let line = serde_json::to_string(entry)?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{}", line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_audit_log_records_and_appends_to_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("logs").join("audit.jsonl");
+        let log = AuditLog::new(Some(path.clone()));
+
+        log.record(AuditEntry::new("PROJ-1", "transition 31", None));
+        log.record(AuditEntry::new("PROJ-2", "assign abc", Some("Forbidden".to_string())));
+
+        let entries = log.entries();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].succeeded());
+        assert_eq!(entries[1].error.as_deref(), Some("Forbidden"));
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let written: Vec<AuditEntry> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(written, entries);
+    }
+}
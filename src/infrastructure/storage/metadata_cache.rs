@@ -0,0 +1,175 @@
+use crate::utils::{LazyJiraError, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Entries older than this are dropped when the file is written, whatever
+/// their endpoint's TTL
+const MAX_AGE_DAYS: i64 = 7;
+
+/// A metadata response and when it was fetched
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct CachedResponse {
+    fetched: DateTime<Utc>,
+    value: Value,
+}
+
+/// Field, createmeta and editmeta responses kept between runs by URL, since
+/// they rarely change but are slow to fetch. Persisted next to the config
+/// file
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct MetadataCache {
+    entries: HashMap<String, CachedResponse>,
+}
+
+impl MetadataCache {
+    /// Load the cache from a file; a missing file is an empty cache
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to parse {}: {}", path.display(), e))
+        })
+    }
+
+    /// Save the cache to a file, creating its directory if needed
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| {
+                LazyJiraError::Config(format!("Failed to create {}: {}", dir.display(), e))
+            })?;
+        }
+        let content = serde_json::to_string(self)
+            .map_err(|e| LazyJiraError::Config(format!("Failed to serialize metadata cache: {}", e)))?;
+        std::fs::write(path, content).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+
+    /// Path of the metadata cache file
+    pub fn path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir().ok_or_else(|| {
+            LazyJiraError::Config("Could not determine config directory".to_string())
+        })?;
+        Ok(config_dir.join("lazyjira").join("metadata_cache.json"))
+    }
+
+    /// The response for `url` if it was fetched less than `ttl` ago
+    pub fn get(&self, url: &str, ttl: Duration, now: DateTime<Utc>) -> Option<&Value> {
+        self.entries
+            .get(url)
+            .filter(|entry| now - entry.fetched < ttl)
+            .map(|entry| &entry.value)
+    }
+
+    pub fn insert(&mut self, url: String, value: Value, now: DateTime<Utc>) {
+        self.entries.insert(url, CachedResponse { fetched: now, value });
+    }
+
+    pub fn remove(&mut self, url: &str) {
+        self.entries.remove(url);
+    }
+
+    /// Drop the entries no endpoint would use any more
+    pub fn prune(&mut self, now: DateTime<Utc>) {
+        self.entries
+            .retain(|_, entry| now - entry.fetched < Duration::days(MAX_AGE_DAYS));
+    }
+}
+
+/// A metadata cache backed by a file. Every change is written through,
+/// merged into what is on disk so clients of other instances sharing the
+/// file keep their entries
+#[derive(Debug)]
+pub struct MetadataStore {
+    path: PathBuf,
+    cache: std::sync::Mutex<MetadataCache>,
+}
+
+impl MetadataStore {
+    /// Open the cache file, starting empty when it is unreadable
+    pub fn open(path: PathBuf) -> Self {
+        let cache = MetadataCache::load_from(&path).unwrap_or_else(|e| {
+            log::warn!("Failed to load metadata cache: {}", e);
+            MetadataCache::default()
+        });
+        Self {
+            path,
+            cache: std::sync::Mutex::new(cache),
+        }
+    }
+
+    /// The response for `url` if it was fetched less than `ttl` ago
+    pub fn get(&self, url: &str, ttl: Duration) -> Option<Value> {
+        let cache = self.cache.lock().ok()?;
+        cache.get(url, ttl, Utc::now()).cloned()
+    }
+
+    pub fn insert(&self, url: &str, value: Value) {
+        let now = Utc::now();
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.insert(url.to_string(), value.clone(), now);
+        }
+        self.write(|on_disk| on_disk.insert(url.to_string(), value, now));
+    }
+
+    /// Forget the response for `url`, e.g. after a change that affects it
+    pub fn remove(&self, url: &str) {
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.remove(url);
+        }
+        self.write(|on_disk| on_disk.remove(url));
+    }
+
+    fn write(&self, change: impl FnOnce(&mut MetadataCache)) {
+        let mut on_disk = MetadataCache::load_from(&self.path).unwrap_or_default();
+        change(&mut on_disk);
+        on_disk.prune(Utc::now());
+        if let Err(e) = on_disk.save_to(&self.path) {
+            log::warn!("Failed to save metadata cache: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_entries_expire_after_their_ttl() {
+        let now = Utc::now();
+        let mut cache = MetadataCache::default();
+        cache.insert("a/field".to_string(), json!([]), now - Duration::hours(2));
+        assert_eq!(cache.get("a/field", Duration::days(1), now), Some(&json!([])));
+        assert_eq!(cache.get("a/field", Duration::hours(1), now), None);
+
+        cache.insert("b/field".to_string(), json!([]), now - Duration::days(MAX_AGE_DAYS));
+        cache.prune(now);
+        assert!(cache.get("a/field", Duration::days(1), now).is_some());
+        assert!(cache.get("b/field", Duration::days(30), now).is_none());
+    }
+
+    #[test]
+    fn test_store_merges_with_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("lazyjira").join("metadata_cache.json");
+        let first = MetadataStore::open(path.clone());
+        let second = MetadataStore::open(path.clone());
+        first.insert("a/field", json!(["a"]));
+        second.insert("b/field", json!(["b"]));
+
+        let reopened = MetadataStore::open(path);
+        assert_eq!(reopened.get("a/field", Duration::hours(1)), Some(json!(["a"])));
+        assert_eq!(reopened.get("b/field", Duration::hours(1)), Some(json!(["b"])));
+
+        reopened.remove("a/field");
+        assert_eq!(reopened.get("a/field", Duration::hours(1)), None);
+    }
+}
@@ -1 +1,6 @@
+pub mod audit_log;
 pub mod cache;
+pub mod metadata_cache;
+pub mod recent_assignees;
+pub mod recent_tickets;
+pub mod view_state;
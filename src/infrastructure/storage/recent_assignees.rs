@@ -0,0 +1,117 @@
+use crate::domain::models::user::User;
+use crate::utils::{LazyJiraError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Number of assignees remembered, one per digit key in the quick-assign popup
+pub const MAX_RECENT_ASSIGNEES: usize = 9;
+
+/// Most-recently-used assignees, persisted next to the config file
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RecentAssignees {
+    users: Vec<User>,
+}
+
+impl RecentAssignees {
+    /// Load the list from its default location, starting empty when it is
+    /// missing or unreadable
+    pub fn load() -> Self {
+        match Self::path().and_then(|path| Self::load_from(&path)) {
+            Ok(recent) => recent,
+            Err(e) => {
+                log::warn!("Failed to load recent assignees: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Load the list from a file; a missing file is an empty list
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to parse {}: {}", path.display(), e))
+        })
+    }
+
+    /// Save the list to its default location
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::path()?)
+    }
+
+    /// Save the list to a file, creating its directory if needed
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| {
+                LazyJiraError::Config(format!("Failed to create {}: {}", dir.display(), e))
+            })?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| LazyJiraError::Config(format!("Failed to serialize recent assignees: {}", e)))?;
+        std::fs::write(path, content).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+
+    /// Path of the recent assignees file
+    fn path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir().ok_or_else(|| {
+            LazyJiraError::Config("Could not determine config directory".to_string())
+        })?;
+        Ok(config_dir.join("lazyjira").join("recent_assignees.json"))
+    }
+
+    /// Move a user to the front of the list, dropping the oldest beyond the limit
+    pub fn record(&mut self, user: User) {
+        self.users.retain(|u| u.account_id != user.account_id);
+        self.users.insert(0, user);
+        self.users.truncate(MAX_RECENT_ASSIGNEES);
+    }
+
+    /// Users, most recent first
+    pub fn users(&self) -> &[User] {
+        &self.users
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str) -> User {
+        User::new(id.to_string(), format!("User {}", id))
+    }
+
+    #[test]
+    fn test_record_moves_user_to_front_and_caps_list() {
+        let mut recent = RecentAssignees::default();
+        for i in 0..12 {
+            recent.record(user(&i.to_string()));
+        }
+        recent.record(user("5"));
+
+        let ids: Vec<&str> = recent.users().iter().map(|u| u.account_id.as_str()).collect();
+        assert_eq!(ids.len(), MAX_RECENT_ASSIGNEES);
+        assert_eq!(ids[0], "5");
+        assert_eq!(ids[1], "11");
+        assert_eq!(ids.iter().filter(|id| **id == "5").count(), 1);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("lazyjira").join("recent_assignees.json");
+        assert_eq!(RecentAssignees::load_from(&path).unwrap(), RecentAssignees::default());
+
+        let mut recent = RecentAssignees::default();
+        recent.record(user("a"));
+        recent.record(user("b"));
+        recent.save_to(&path).unwrap();
+
+        assert_eq!(RecentAssignees::load_from(&path).unwrap(), recent);
+    }
+}
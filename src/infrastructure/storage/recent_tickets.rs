@@ -0,0 +1,107 @@
+use crate::utils::{LazyJiraError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Number of ticket keys remembered for shell completion
+pub const MAX_RECENT_TICKETS: usize = 20;
+
+/// Keys of the most recently opened tickets, persisted next to the config file
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RecentTickets {
+    keys: Vec<String>,
+}
+
+impl RecentTickets {
+    /// Load the list from its default location, starting empty when it is
+    /// missing or unreadable
+    pub fn load() -> Self {
+        match Self::path().and_then(|path| Self::load_from(&path)) {
+            Ok(recent) => recent,
+            Err(e) => {
+                log::warn!("Failed to load recent tickets: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Load the list from a file; a missing file is an empty list
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to parse {}: {}", path.display(), e))
+        })
+    }
+
+    /// Save the list to its default location
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::path()?)
+    }
+
+    /// Save the list to a file, creating its directory if needed
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| {
+                LazyJiraError::Config(format!("Failed to create {}: {}", dir.display(), e))
+            })?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| LazyJiraError::Config(format!("Failed to serialize recent tickets: {}", e)))?;
+        std::fs::write(path, content).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+
+    /// Path of the recent tickets file
+    fn path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir().ok_or_else(|| {
+            LazyJiraError::Config("Could not determine config directory".to_string())
+        })?;
+        Ok(config_dir.join("lazyjira").join("recent_tickets.json"))
+    }
+
+    /// Move a key to the front of the list, dropping the oldest beyond the
+    /// limit; returns whether the list changed
+    pub fn record(&mut self, key: &str) -> bool {
+        if self.keys.first().map(String::as_str) == Some(key) {
+            return false;
+        }
+        self.keys.retain(|k| k != key);
+        self.keys.insert(0, key.to_string());
+        self.keys.truncate(MAX_RECENT_TICKETS);
+        true
+    }
+
+    /// Ticket keys, most recent first
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_moves_key_to_front_and_caps_list() {
+        let mut recent = RecentTickets::default();
+        for i in 0..25 {
+            assert!(recent.record(&format!("PROJ-{}", i)));
+        }
+        assert!(!recent.record("PROJ-24"));
+        assert!(recent.record("PROJ-10"));
+
+        assert_eq!(recent.keys().len(), MAX_RECENT_TICKETS);
+        assert_eq!(recent.keys()[0], "PROJ-10");
+        assert_eq!(recent.keys()[1], "PROJ-24");
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("lazyjira").join("recent_tickets.json");
+        recent.save_to(&path).unwrap();
+        assert_eq!(RecentTickets::load_from(&path).unwrap(), recent);
+    }
+}
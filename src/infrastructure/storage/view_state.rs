@@ -0,0 +1,148 @@
+use crate::domain::services::filter_service::AssigneeScope;
+use crate::utils::{LazyJiraError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Ticket list settings changed with its keys
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ListState {
+    /// Name of the quick filter shown instead of `default_query`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quick_filter: Option<String>,
+    #[serde(default)]
+    pub assignee_scope: AssigneeScope,
+}
+
+/// Board settings changed with its keys
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BoardState {
+    /// Swimlane grouping, overriding the `swimlanes` setting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swimlanes: Option<String>,
+}
+
+/// Ticket detail settings changed with its keys
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DetailState {
+    #[serde(default)]
+    pub raw_description: bool,
+}
+
+/// Where the last session was left on quitting
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SessionState {
+    /// "dashboard", "list", "board" or "detail"
+    pub view: String,
+    /// Name and JQL of a query run instead of a quick filter or
+    /// `default_query`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<(String, String)>,
+    /// Ticket focused in the list, or open in the detail view
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<String>,
+}
+
+/// How each view was left, restored on the next start. Kept apart from
+/// config.toml, which is the user's to edit
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ViewState {
+    #[serde(default)]
+    pub list: ListState,
+    #[serde(default)]
+    pub board: BoardState,
+    #[serde(default)]
+    pub detail: DetailState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<SessionState>,
+}
+
+impl ViewState {
+    /// Load the state from its default location, starting afresh when it
+    /// is missing or unreadable
+    pub fn load() -> Self {
+        match Self::path().and_then(|path| Self::load_from(&path)) {
+            Ok(state) => state,
+            Err(e) => {
+                log::warn!("Failed to load view state: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Load the state from a file; a missing file is the default state
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to parse {}: {}", path.display(), e))
+        })
+    }
+
+    /// Save the state to its default location
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::path()?)
+    }
+
+    /// Save the state to a file, creating its directory if needed
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| {
+                LazyJiraError::Config(format!("Failed to create {}: {}", dir.display(), e))
+            })?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| LazyJiraError::Config(format!("Failed to serialize view state: {}", e)))?;
+        std::fs::write(path, content).map_err(|e| {
+            LazyJiraError::Config(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+
+    /// Path of the view state file
+    fn path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir().ok_or_else(|| {
+            LazyJiraError::Config("Could not determine config directory".to_string())
+        })?;
+        Ok(config_dir.join("lazyjira").join("view_state.json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view_state_round_trip() {
+        let state = ViewState {
+            list: ListState {
+                quick_filter: Some("Blocked".to_string()),
+                assignee_scope: AssigneeScope::Unassigned,
+            },
+            board: BoardState {
+                swimlanes: Some("epic".to_string()),
+            },
+            detail: DetailState { raw_description: true },
+            session: Some(SessionState {
+                view: "detail".to_string(),
+                query: Some(("Query".to_string(), "assignee = currentUser()".to_string())),
+                ticket: Some("PROJ-7".to_string()),
+            }),
+        };
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("lazyjira").join("view_state.json");
+        assert_eq!(ViewState::load_from(&path).unwrap(), ViewState::default());
+        state.save_to(&path).unwrap();
+        assert_eq!(ViewState::load_from(&path).unwrap(), state);
+
+        // Views added later start from their defaults
+        std::fs::write(&path, r#"{"list": {"assignee_scope": "me"}}"#).unwrap();
+        let state = ViewState::load_from(&path).unwrap();
+        assert_eq!(state.list.assignee_scope, AssigneeScope::Me);
+        assert_eq!(state.board, BoardState::default());
+        assert_eq!(state.session, None);
+    }
+}
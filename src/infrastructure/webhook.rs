@@ -0,0 +1,166 @@
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// Largest webhook body accepted; Jira payloads are a few kilobytes
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Change to an issue announced by a Jira webhook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookEvent {
+    /// Issue created or updated
+    IssueUpdated(String),
+    IssueDeleted(String),
+    /// Comment created, edited or deleted on the issue
+    CommentChanged(String),
+}
+
+impl WebhookEvent {
+    pub fn key(&self) -> &str {
+        match self {
+            WebhookEvent::IssueUpdated(key)
+            | WebhookEvent::IssueDeleted(key)
+            | WebhookEvent::CommentChanged(key) => key,
+        }
+    }
+}
+
+/// Accept Jira webhooks on the given address, e.g. "127.0.0.1:8765"
+pub async fn listen(address: &str) -> std::io::Result<UnboundedReceiver<WebhookEvent>> {
+    let listener = TcpListener::bind(address).await?;
+    log::info!("Listening for Jira webhooks on {}", address);
+    Ok(serve(listener))
+}
+
+/// Handle webhook requests in the background, forwarding the issue events
+fn serve(listener: TcpListener) -> UnboundedReceiver<WebhookEvent> {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        // Stops at the first request after the app has gone
+        while !sender.is_closed() {
+            let stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    log::warn!("Failed to accept webhook connection: {}", e);
+                    continue;
+                }
+            };
+            let sender = sender.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream, &sender).await {
+                    log::warn!("Failed to handle webhook request: {}", e);
+                }
+            });
+        }
+    });
+    receiver
+}
+
+/// Read one HTTP request and answer it; only POSTed issue events are used
+async fn handle_connection(
+    stream: TcpStream,
+    sender: &UnboundedSender<WebhookEvent>,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).await? == 0 {
+            break;
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+
+    let status = if !request_line.starts_with("POST ") {
+        "405 Method Not Allowed"
+    } else if content_length > MAX_BODY_BYTES {
+        "413 Payload Too Large"
+    } else {
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).await?;
+        match parse_event(&body) {
+            Some(event) => {
+                log::debug!("handle_connection: Webhook {:?}", event);
+                let _ = sender.send(event);
+            }
+            None => log::debug!("handle_connection: Ignoring webhook {}", request_line.trim()),
+        }
+        "204 No Content"
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        status
+    );
+    reader.into_inner().write_all(response.as_bytes()).await
+}
+
+/// The issue event in a webhook body; other events are ignored
+pub fn parse_event(body: &[u8]) -> Option<WebhookEvent> {
+    let payload: Value = serde_json::from_slice(body).ok()?;
+    let key = payload["issue"]["key"].as_str()?.to_string();
+    match payload["webhookEvent"].as_str()? {
+        "jira:issue_deleted" => Some(WebhookEvent::IssueDeleted(key)),
+        "comment_created" | "comment_updated" | "comment_deleted" => {
+            Some(WebhookEvent::CommentChanged(key))
+        }
+        "jira:issue_created" | "jira:issue_updated" => Some(WebhookEvent::IssueUpdated(key)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_event() {
+        let updated = json!({"webhookEvent": "jira:issue_updated", "issue": {"key": "PROJ-1"}});
+        assert_eq!(
+            parse_event(updated.to_string().as_bytes()),
+            Some(WebhookEvent::IssueUpdated("PROJ-1".to_string()))
+        );
+
+        let commented = json!({"webhookEvent": "comment_created", "issue": {"key": "PROJ-2"}});
+        assert_eq!(
+            parse_event(commented.to_string().as_bytes()),
+            Some(WebhookEvent::CommentChanged("PROJ-2".to_string()))
+        );
+
+        let sprint = json!({"webhookEvent": "sprint_started", "sprint": {"id": 1}});
+        assert_eq!(parse_event(sprint.to_string().as_bytes()), None);
+        assert_eq!(parse_event(b"not json"), None);
+    }
+
+    #[tokio::test]
+    async fn test_posted_webhook_reaches_receiver() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let mut events = serve(listener);
+
+        let response = reqwest::Client::new()
+            .post(format!("http://{}/webhook", address))
+            .json(&json!({"webhookEvent": "jira:issue_deleted", "issue": {"key": "PROJ-3"}}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+        assert_eq!(
+            events.recv().await,
+            Some(WebhookEvent::IssueDeleted("PROJ-3".to_string()))
+        );
+    }
+}
@@ -1,6 +1,50 @@
-// Library root - exports for integration tests
-pub mod app;
+//! LazyJira as a library: the typed Jira client and domain models behind the
+//! `lazyjira` TUI, for bots and scripts that want them without the UI.
+//!
+//! The stable API is:
+//!
+//! - [`domain`]: tickets, users, sprints, boards and the services working on
+//!   them (filtering, metrics, sprint planning)
+//! - [`infrastructure::api`]: the [`ApiClient`] trait, the Jira REST client
+//!   [`JiraApiClient`], the in-memory [`DemoApiClient`] and the wrappers that
+//!   audit or hook into requests
+//! - [`infrastructure::config`]: the lazyjira config file and the jira-cli
+//!   credentials it builds on
+//! - [`LazyJiraError`] and [`Result`], returned throughout
+//!
+//! The `app` and `ui` modules hold the terminal application and may change
+//! in any release.
+//!
+//! ```no_run
+//! use lazyjira::{ApiClient, Config, JiraApiClient};
+//!
+//! # async fn example() -> lazyjira::Result<()> {
+//! let config = Config::load()?;
+//! let credentials = config.load_credentials()?.expect("jira-cli is configured");
+//! let client = JiraApiClient::from_jira_cli_config(&credentials)?;
+//!
+//! let result = client
+//!     .search_issues("assignee = currentUser() ORDER BY updated DESC", 0, 20)
+//!     .await?;
+//! for ticket in result.issues {
+//!     println!("{} {}", ticket.key, ticket.summary);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
 pub mod domain;
 pub mod infrastructure;
-pub mod ui;
 pub mod utils;
+
+// Used by the binary; not part of the stable API
+#[doc(hidden)]
+pub mod app;
+#[doc(hidden)]
+pub mod ui;
+
+pub use domain::models::ticket::Ticket;
+pub use infrastructure::api::demo_client::DemoApiClient;
+pub use infrastructure::api::{ApiClient, JiraApiClient};
+pub use infrastructure::config::Config;
+pub use utils::{LazyJiraError, Result};
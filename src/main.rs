@@ -1,12 +1,17 @@
-mod app;
-mod domain;
-mod infrastructure;
-mod ui;
-mod utils;
-
-use infrastructure::api::{ConnectionStatus, ConnectionValidator};
-use infrastructure::config::Config;
-use utils::logger;
+use lazyjira::app::cli;
+use lazyjira::app::completions::{self, Shell};
+use lazyjira::domain::services::sprint_service::ChangelogFormat;
+use lazyjira::infrastructure::api::demo_client::DemoApiClient;
+use lazyjira::infrastructure::api::{ApiClient, ConnectionStatus, ConnectionValidator, JiraApiClient};
+use lazyjira::infrastructure::config::credentials::CredentialSource;
+use lazyjira::infrastructure::config::Config;
+use lazyjira::infrastructure::storage::metadata_cache::MetadataCache;
+use lazyjira::infrastructure::storage::recent_tickets::RecentTickets;
+use lazyjira::utils::color::{self, paint, AnsiColor, ColorChoice};
+use lazyjira::utils::logger;
+use lazyjira::utils::time::DisplayTimezone;
+use lazyjira::{app, infrastructure, ui};
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -30,7 +35,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .or_else(|| {
                 panic_info.payload()
                     .downcast_ref::<String>()
-                    .map(|s| s.clone())
+                    .cloned()
             })
             .unwrap_or_else(|| "unknown panic".to_string());
         
@@ -42,19 +47,225 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         log::error!("PANIC at {}: {}", location, message);
     }));
 
+    // `--color=always|never|auto` applies to the UI and everything printed
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    match ColorChoice::from_args(&mut args) {
+        Ok(choice) => color::set_choice(choice),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    }
+
+    // `lazyjira completions <shell>` prints a completion script, which calls
+    // `lazyjira complete <what>` for filter names and recent ticket keys
+    match args.first().map(String::as_str) {
+        Some("completions") => {
+            let Some(shell) = args.get(1).and_then(|name| Shell::parse(name)) else {
+                eprintln!("Usage: lazyjira completions bash|zsh|fish");
+                std::process::exit(2);
+            };
+            print!("{}", shell.script());
+            return Ok(());
+        }
+        Some("complete") => {
+            let what = args.get(1).map(String::as_str).unwrap_or_default();
+            let config = Config::load().unwrap_or_default();
+            for value in completions::candidates(what, &config, &RecentTickets::load()).unwrap_or_default() {
+                println!("{}", value);
+            }
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    // `lazyjira list <JQL | ->` or `lazyjira list --jql-file <path>` prints
+    // matching tickets instead of starting the UI
+    if args.first().map(String::as_str) == Some("list") {
+        let jql_file = take_option(&mut args, "--jql-file");
+        let demo = args.iter().any(|arg| arg == "--demo");
+        let query = args.iter().skip(1).find(|arg| *arg == "-" || !arg.starts_with("--"));
+        let jql = match cli::read_jql(
+            query.map(String::as_str),
+            jql_file.as_deref().map(std::path::Path::new),
+            std::io::stdin(),
+        ) {
+            Ok(jql) => jql,
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(2);
+            }
+        };
+        let client = cli_client(&Config::load()?, demo)?;
+        match cli::list(client.as_ref(), &jql).await {
+            Ok(listing) => {
+                for line in &listing.lines {
+                    println!("{}", line);
+                }
+                if let Some(notice) = listing.truncated_notice() {
+                    eprintln!("{}", notice);
+                }
+                return Ok(());
+            }
+            Err(e) => {
+                eprintln!("{} {}", paint("✗", AnsiColor::Red, color::stderr_color()), e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // `lazyjira release-notes --version <name> [--project <key>] | --jql <query>`
+    // prints Markdown release notes, or writes them to `--output <file>` or
+    // the clipboard with `--clipboard`
+    if args.first().map(String::as_str) == Some("release-notes") {
+        let version = take_option(&mut args, "--version");
+        let project = take_option(&mut args, "--project");
+        let jql = take_option(&mut args, "--jql");
+        let output = take_option(&mut args, "--output");
+        let (title, jql) = match cli::release_notes_query(version.as_deref(), project.as_deref(), jql.as_deref()) {
+            Ok(query) => query,
+            Err(_) => {
+                eprintln!("Usage: lazyjira release-notes --version <name> [--project <key>] | --jql <query> [--output <file> | --clipboard]");
+                std::process::exit(2);
+            }
+        };
+        let client = cli_client(&Config::load()?, args.iter().any(|arg| arg == "--demo"))?;
+        let notes = cli::release_notes(client.as_ref(), &title, &jql).await;
+        deliver_report(notes, output.as_deref(), args.iter().any(|arg| arg == "--clipboard"), "release notes");
+        return Ok(());
+    }
+
+    // `lazyjira sprint-report [<sprint id | name>] [--board <id>] [--csv]`
+    // exports what was resolved in a sprint, the active one by default, as
+    // Markdown or CSV; `--output` and `--clipboard` work as for release notes
+    if args.first().map(String::as_str) == Some("sprint-report") {
+        let board = take_option(&mut args, "--board");
+        let output = take_option(&mut args, "--output");
+        let sprint = args.iter().skip(1).find(|arg| !arg.starts_with("--")).cloned();
+        let format = if args.iter().any(|arg| arg == "--csv") {
+            ChangelogFormat::Csv
+        } else {
+            ChangelogFormat::Markdown
+        };
+        let config = Config::load()?;
+        let board = board.or(config.jira.board_id.map(|id| id.to_string()));
+        let client = cli_client(&config, args.iter().any(|arg| arg == "--demo"))?;
+        let report = match cli::find_sprint(client.as_ref(), board.as_deref(), sprint.as_deref()).await {
+            Ok(sprint) => cli::sprint_changelog(client.as_ref(), &sprint, format).await,
+            Err(e) => Err(e),
+        };
+        deliver_report(report, output.as_deref(), args.iter().any(|arg| arg == "--clipboard"), "sprint report");
+        return Ok(());
+    }
+
+    // `lazyjira timesheet [--week <YYYY-MM-DD>]` exports the hours I logged
+    // in a week, this one by default, as CSV
+    if args.first().map(String::as_str) == Some("timesheet") {
+        let week = take_option(&mut args, "--week");
+        let output = take_option(&mut args, "--output");
+        let config = Config::load()?;
+        let timezone = DisplayTimezone::from_config(config.ui.timezone.as_deref());
+        let client = cli_client(&config, args.iter().any(|arg| arg == "--demo"))?;
+        let report = cli::timesheet(client.as_ref(), week.as_deref(), &timezone).await;
+        deliver_report(report, output.as_deref(), args.iter().any(|arg| arg == "--clipboard"), "timesheet");
+        return Ok(());
+    }
+
+    // `lazyjira create <template>` creates a ticket from a configured template
+    if args.first().map(String::as_str) == Some("create") {
+        let config = Config::load()?;
+        let Some(template) = args.get(1).and_then(|name| config.templates.get(name)) else {
+            let names: Vec<&str> = config.templates.keys().map(String::as_str).collect();
+            eprintln!("Usage: lazyjira create <template>; configured: {}", names.join(", "));
+            std::process::exit(2);
+        };
+        let client = cli_client(&config, args.iter().any(|arg| arg == "--demo"))?;
+        let defaults = config.projects.get(&template.project);
+        match cli::create_from_template(client, template, defaults, std::io::stdin().lock(), std::io::stderr()).await {
+            Ok(ticket) => {
+                println!("{}", ticket.key);
+                return Ok(());
+            }
+            Err(e) => {
+                eprintln!("{} {}", paint("✗", AnsiColor::Red, color::stderr_color()), e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // `--filter <name>` and `--open <KEY>` choose where the UI starts
+    let start_filter = take_option(&mut args, "--filter");
+    let start_ticket = take_option(&mut args, "--open");
+
+    // `lazyjira doctor` diagnoses the setup instead of starting the UI
+    if args.first().map(String::as_str) == Some("doctor") {
+        println!("Checking the LazyJira setup...\n");
+        let checks = app::doctor::run_checks().await;
+        for check in &checks {
+            println!("{}", check.report(color::stdout_color()));
+        }
+        let failed = checks
+            .iter()
+            .filter(|check| check.status == app::doctor::CheckStatus::Fail)
+            .count();
+        if failed > 0 {
+            eprintln!(
+                "\n{} {} check(s) failed",
+                paint("✗", AnsiColor::Red, color::stderr_color()),
+                failed
+            );
+            std::process::exit(1);
+        }
+        println!("\n{} All checks passed", paint("✓", AnsiColor::Green, color::stdout_color()));
+        return Ok(());
+    }
+
+    // `lazyjira keys [--markdown]` prints the keymap as a cheat sheet
+    if args.first().map(String::as_str) == Some("keys") {
+        let groups = ui::keymap::keymap(&Config::load()?);
+        if args.iter().any(|arg| arg == "--markdown") {
+            print!("{}", ui::keymap::to_markdown(&groups));
+        } else {
+            print!("{}", ui::keymap::to_text(&groups));
+        }
+        return Ok(());
+    }
+
     println!("LazyJira starting...\n");
 
     // Load application configuration
     let config = Config::load()?;
     
-    // Try to load jira-cli config
-    match Config::load_jira_cli_config()? {
+    // Demo mode runs against in-memory sample data, without credentials
+    if args.iter().any(|arg| arg == "--demo") {
+        println!("Starting in demo mode with sample data...\n");
+        let client: std::sync::Arc<dyn infrastructure::api::ApiClient> =
+            std::sync::Arc::new(DemoApiClient::new()?);
+        let mut app = ui::App::new(
+            "Demo".to_string(),
+            client,
+            "demo.atlassian.net".to_string(),
+            config,
+        )?;
+        app.forget_recent_tickets();
+        app.start_at(start_filter, start_ticket);
+        app.run().await?;
+        return Ok(());
+    }
+
+    // Try to load jira-cli config, with the token from the configured source
+    match config.load_credentials()? {
         Some(jira_cli_config) => {
-            println!("✓ Found jira-cli config for instance: {}", jira_cli_config.instance);
+            println!(
+                "{} Found credentials for instance {} ({})",
+                paint("✓", AnsiColor::Green, color::stdout_color()),
+                jira_cli_config.instance,
+                CredentialSource::from_config(&config.jira).label()
+            );
             
             // Validate configuration
             if let Err(e) = ConnectionValidator::validate_config(&jira_cli_config) {
-                eprintln!("\n✗ Configuration validation failed:");
+                eprintln!("\n{} Configuration validation failed:", paint("✗", AnsiColor::Red, color::stderr_color()));
                 eprintln!("  {}", e);
                 eprintln!("\nPlease check your jira-cli configuration at:");
                 eprintln!("  ~/.config/jira-cli/config.yaml");
@@ -67,17 +278,54 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Ok((client, status)) => {
                     match status {
                         ConnectionStatus::Connected => {
-                            println!("✓ Successfully connected to Jira!\n");
+                            println!(
+                                "{} Successfully connected to Jira!\n",
+                                paint("✓", AnsiColor::Green, color::stdout_color())
+                            );
                             
                             // Initialize UI and start application
                             let client: std::sync::Arc<dyn infrastructure::api::ApiClient> = 
-                                std::sync::Arc::new(client);
+                                std::sync::Arc::new(with_metadata_cache(client));
                             let instance_url = jira_cli_config.instance.clone();
-                            let mut app = ui::App::new("Connected".to_string(), client, instance_url)?;
-                            app.run().await?;
+                            let mut app = ui::App::new(
+                                "Connected".to_string(),
+                                client,
+                                instance_url.clone(),
+                                config.clone(),
+                            )?;
+                            app.start_at(start_filter, start_ticket);
+                            if config.instances.is_empty() {
+                                app.run().await?;
+                                return Ok(());
+                            }
+
+                            // `[[instances]]` open in tabs next to this one
+                            let mut tabs = vec![(instance_url, app)];
+                            for instance in &config.instances {
+                                let tab_config = config.for_instance(instance);
+                                match connect_instance(&tab_config).await {
+                                    Ok((client, url)) => {
+                                        println!(
+                                            "{} Connected to {} ({})",
+                                            paint("✓", AnsiColor::Green, color::stdout_color()),
+                                            instance.name,
+                                            url
+                                        );
+                                        let app = ui::App::new("Connected".to_string(), client, url, tab_config)?;
+                                        tabs.push((instance.name.clone(), app));
+                                    }
+                                    Err(e) => eprintln!(
+                                        "{} Skipping instance {}: {}",
+                                        paint("✗", AnsiColor::Red, color::stderr_color()),
+                                        instance.name,
+                                        e
+                                    ),
+                                }
+                            }
+                            ui::app::run_tabs(tabs).await?;
                         }
                         _ => {
-                            eprintln!("\n✗ Connection failed:");
+                            eprintln!("\n{} Connection failed:", paint("✗", AnsiColor::Red, color::stderr_color()));
                             if let Some(msg) = status.error_message() {
                                 eprintln!("  {}", msg);
                             }
@@ -90,7 +338,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
                 Err(e) => {
-                    eprintln!("\n✗ Failed to create API client:");
+                    eprintln!("\n{} Failed to create API client:", paint("✗", AnsiColor::Red, color::stderr_color()));
                     eprintln!("  {}", e);
                     eprintln!("\nPlease check your jira-cli configuration.");
                     std::process::exit(1);
@@ -98,7 +346,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
         None => {
-            eprintln!("✗ No jira-cli config found.");
+            eprintln!("{} No jira-cli config found.", paint("✗", AnsiColor::Red, color::stderr_color()));
             eprintln!("\nPlease configure jira-cli first:");
             eprintln!("  1. Install jira-cli: https://github.com/go-jira/jira");
             eprintln!("  2. Configure it: jira-cli configure");
@@ -121,3 +369,83 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // For now, just exit successfully
     Ok(())
 }
+
+/// Print a generated report, or write it to `output` or the clipboard;
+/// exits when it could not be generated or written
+fn deliver_report(report: lazyjira::utils::Result<String>, output: Option<&str>, clipboard: bool, what: &str) {
+    let written = match report {
+        Ok(report) if clipboard => {
+            lazyjira::utils::clipboard::copy(&report).map(|_| format!("Copied the {}", what))
+        }
+        Ok(report) => match output {
+            Some(path) => std::fs::write(path, report)
+                .map(|_| format!("Wrote the {} to {}", what, path))
+                .map_err(Into::into),
+            None => {
+                print!("{}", report);
+                return;
+            }
+        },
+        Err(e) => Err(e),
+    };
+    match written {
+        Ok(message) => eprintln!("{} {}", paint("✓", AnsiColor::Green, color::stderr_color()), message),
+        Err(e) => {
+            eprintln!("{} {}", paint("✗", AnsiColor::Red, color::stderr_color()), e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Take `<name> <value>` or `<name>=<value>` out of the arguments
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let prefix = format!("{}=", name);
+    let i = args.iter().position(|arg| arg == name || arg.starts_with(&prefix))?;
+    let arg = args.remove(i);
+    match arg.strip_prefix(&prefix) {
+        Some(value) => Some(value.to_string()),
+        None if i < args.len() => Some(args.remove(i)),
+        None => None,
+    }
+}
+
+/// Connect to an `[[instances]]` entry for its tab, returning the client
+/// and the instance's URL
+async fn connect_instance(config: &Config) -> Result<(Arc<dyn ApiClient>, String), String> {
+    let credentials = config
+        .load_credentials()
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "no credentials found".to_string())?;
+    ConnectionValidator::validate_config(&credentials).map_err(|e| e.to_string())?;
+    let (client, status) = ConnectionValidator::connect_with_config(&credentials)
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Some(message) = status.error_message() {
+        return Err(message);
+    }
+    Ok((Arc::new(with_metadata_cache(client)), credentials.instance))
+}
+
+/// Keep field and screen metadata between runs, in a file next to the config
+fn with_metadata_cache(client: JiraApiClient) -> JiraApiClient {
+    match MetadataCache::path() {
+        Ok(path) => client.with_metadata_cache(path),
+        Err(e) => {
+            log::warn!("Metadata is not cached: {}", e);
+            client
+        }
+    }
+}
+
+/// Client for the command-line modes: the demo data, or Jira with the
+/// jira-cli credentials
+fn cli_client(config: &Config, demo: bool) -> Result<Arc<dyn ApiClient>, Box<dyn std::error::Error>> {
+    if demo {
+        return Ok(Arc::new(DemoApiClient::new()?));
+    }
+    let Some(credentials) = config.load_credentials()? else {
+        eprintln!("No jira-cli configuration found; run `lazyjira doctor` for details");
+        std::process::exit(1);
+    };
+    Ok(Arc::new(JiraApiClient::from_jira_cli_config(&credentials)?))
+}
@@ -1,24 +1,132 @@
 use crate::domain::models::ticket::Ticket;
-use crate::domain::models::comment::Comment;
+use crate::domain::models::attachment::Attachment;
+use crate::domain::models::remote_link::RemoteLink;
+use crate::domain::models::dev_info::DevInfo;
+use crate::domain::models::sla::Sla;
+use crate::domain::models::approval::Approval;
+use crate::domain::models::comment::{Comment, CommentOrder};
+use crate::domain::models::project::{IssueType, Project, Version};
+use crate::domain::models::smart_view::SmartView;
+use crate::domain::models::board::{Board, BoardColumnConfig, BoardType};
+use crate::domain::models::sprint::Sprint;
+use crate::domain::models::user::User;
+use crate::domain::models::pomodoro::{Pomodoro, PomodoroPhase};
+use crate::domain::models::worklog::WorkTimer;
+use crate::domain::models::permission::{Permission, ProjectPermissions};
+use crate::domain::models::issue_property::IssueProperty;
+use crate::domain::services::dashboard_service::DashboardService;
+use crate::domain::services::dependency_service::DependencyGraph;
+use crate::domain::services::release_notes_service::ReleaseNotesService;
+use crate::domain::models::activity::Activity;
+use crate::domain::services::cross_search_service::{CrossSearchResult, CrossSearchService};
+use crate::domain::services::timesheet_service::Timesheet;
+use crate::domain::services::diff_service::DiffService;
+use crate::domain::services::filter_service::{AssigneeScope, FilterService, MergeOrder, TicketFilter};
+use crate::domain::services::metrics_service::{MetricsService, StatusDuration};
+use crate::domain::services::reminder_service::{DueReminder, ReminderService};
+use crate::domain::services::sprint_service::{
+    ChangelogFormat, CompletionPlan, SprintProgress, SprintService, SprintVelocity,
+};
+use crate::infrastructure::api::client::{
+    FieldKind, IssueDetails, LabelChange, RankPosition, SearchResult, Transition, TransitionField,
+};
+use crate::infrastructure::api::audited_client::AuditedApiClient;
+use crate::infrastructure::api::hooked_client::HookedApiClient;
 use crate::infrastructure::api::ApiClient;
+use crate::infrastructure::config::watcher::ConfigWatcher;
+use crate::infrastructure::config::{Config, HooksConfig};
+use crate::infrastructure::scripts::{self, ScriptContext};
+use crate::infrastructure::storage::audit_log::AuditLog;
+use crate::infrastructure::storage::recent_assignees::RecentAssignees;
+use crate::infrastructure::storage::recent_tickets::RecentTickets;
+use crate::infrastructure::storage::view_state::{
+    BoardState, DetailState, ListState, SessionState, ViewState,
+};
+use crate::infrastructure::webhook::{self, WebhookEvent};
 // CreateIssueData and Transition are used in method signatures but not directly referenced
-use crate::ui::components::ticket_detail::TicketDetail;
-use crate::ui::components::ticket_list::{TicketList, TicketListState};
+use crate::ui::components::action_history::{ActionHistory, RepeatableAction};
+use crate::ui::components::nav_history::NavHistory;
+use crate::ui::components::assignee_picker::{AssigneePicker, AssigneePickerState};
+use crate::ui::components::attachment_list::{AttachmentList, AttachmentListState};
+use crate::ui::components::link_list::{LinkList, LinkListState};
+use crate::ui::components::approval_list::{ApprovalList, ApprovalListState};
+use crate::ui::components::detail_search::{DetailMatch, DetailSearchBar, DetailSearchState};
+use crate::ui::components::dependency_graph::{DependencyGraphState, DependencyGraphView};
+use crate::ui::components::issue_properties::{IssuePropertiesState, IssuePropertiesView};
+use crate::ui::components::remote_link_form::{RemoteLinkForm, RemoteLinkFormState};
+use crate::ui::components::audit_log_view::AuditLogView;
+use crate::ui::components::board_view::{build_swimlanes, BoardView, BoardViewState, SwimlaneMode};
+use crate::ui::components::confirm_dialog::{centered_rect, ConfirmDialog};
+use crate::ui::components::bulk_progress::{BulkProgress, BulkProgressState};
+use crate::ui::components::comment_composer::{CommentComposer, CommentComposerState};
+use crate::ui::components::comment_prompt::{CommentMode, CommentPrompt, CommentPromptState};
+use crate::ui::components::dashboard::{Dashboard, DashboardState};
+use crate::ui::components::image_preview::{GraphicsProtocol, ImagePreview, ImagePreviewState};
+use crate::ui::components::filter_bar::FilterBar;
+use crate::ui::components::filter_sidebar::{FilterSidebar, FilterSidebarState};
+use crate::ui::components::label_editor::{LabelEditor, LabelEditorState};
+use crate::ui::components::move_issue_picker::{MoveIssuePicker, MoveIssueState};
+use crate::ui::components::release_list::{release_label, ReleaseList, ReleaseListState};
+use crate::ui::components::query_builder::{QueryBuilder, QueryBuilderState};
+use crate::ui::components::reminder_popup::ReminderPopup;
+use crate::ui::components::search_bar::{SearchBar, SearchState};
+use crate::ui::components::request_log_view::RequestLogView;
+use crate::ui::components::sprint_list::{SprintList, SprintListState};
+use crate::ui::components::sprint_progress::SprintProgressPanel;
+use crate::ui::components::ticket_detail::{CommentsState, TicketDetail, TimestampFormat};
+use crate::ui::components::ticket_diff::TicketDiffView;
+use crate::ui::components::ticket_list::{RowJumpBar, TicketList, TicketListState};
+use crate::ui::components::transition_form::{TransitionForm, TransitionFormState};
+use crate::ui::components::edit_form::{EditForm, EditFormState};
 use crate::ui::components::transition_list::{TransitionList, TransitionListState};
+use crate::ui::components::timeline_view::{TimelineState, TimelineView};
+use crate::ui::components::timesheet_view::{TimesheetState, TimesheetView};
+use crate::ui::components::activity_feed::{ActivityFeed, ActivityState};
+use crate::ui::components::cross_search::{CrossSearchState, CrossSearchView};
+use crate::ui::components::velocity_chart::VelocityChart;
+use crate::ui::components::view_switcher::{ViewSwitcher, ViewSwitcherState};
+use crate::ui::components::worklog_prompt::{WorklogPrompt, WorklogPromptState};
 use crate::ui::events::{AppEvent, EventHandler};
+use crate::ui::message::{
+    Action, BoardPurpose, Instances, ListChanges, Message, SprintIssuesPurpose, TransitionPurpose,
+    UserPurpose, VersionIssuesPurpose, Write,
+};
 use crate::ui::renderer::Renderer;
+use crate::ui::theme::Theme;
+use crate::utils::time::{checked_date_format, format_duration, DisplayTimezone};
+use crate::utils::cancel::CancellationToken;
+use crate::utils::clipboard;
+use crate::utils::debounce::Debouncer;
+use crate::utils::links;
+use crate::utils::notify;
+use crate::utils::text::truncate_to_width;
+use crate::utils::LazyJiraError;
+use crate::ui::backend::AppBackend;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{EnableMouseCapture, KeyCode, KeyEvent, KeyModifiers},
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{enable_raw_mode, EnterAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
+    layout::Rect,
+    widgets::Clear,
     Terminal,
 };
+use std::collections::{HashMap, HashSet};
 use std::io::{stdout, Stdout};
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+/// Idle time after typing in the search bar before searching
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Name of the custom query a Jira search loads
+const SEARCH_QUERY_NAME: &str = "Search";
+
+/// Columns of the status and summary shown after a mentioned issue key
+const REFERENCE_WIDTH: usize = 40;
 
 /// Loading state for tickets
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -32,31 +140,335 @@ enum LoadingState {
 /// Current view mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ViewMode {
+    Dashboard,
     List,
     Detail,
     Transitions,
+    Diff,
+    Board,
+    Sprints,
+    Backlog,
+    Velocity,
+    /// Epics and versions across a time axis
+    Timeline,
+    /// Versions of a project
+    Releases,
+    /// Issues of the version focused in the releases view
+    ReleaseIssues,
+    /// Hours I logged over a week
+    Timesheet,
+    /// Recent comments, transitions and assignments across projects
+    Activity,
+    /// One query's results from every configured instance
+    CrossSearch,
+    AuditLog,
+    /// Recent API requests, for debugging
+    Debug,
     #[allow(dead_code)] // Will be used when ticket creation form is implemented
     CreateTicket,
 }
 
-/// Main UI application
-pub struct App {
-    terminal: Terminal<CrosstermBackend<Stdout>>,
+/// A place to come back to with the history keys: a view with the focus
+/// and scroll it was left with
+#[derive(Debug, Clone, PartialEq)]
+struct Place {
+    view: ViewMode,
+    list_filter: Option<TicketFilter>,
+    /// Ticket open in the detail view, or else focused in the list
+    ticket: Option<String>,
+    list_scroll: usize,
+    dashboard_tile: usize,
+    /// Comment focused in the detail view
+    comment: usize,
+}
+
+/// Action waiting for the user to confirm it in a dialog
+#[derive(Debug, Clone)]
+enum PendingAction {
+    StartSprint(Sprint),
+    CompleteSprint(CompletionPlan),
+    /// Release a version, warning about its unresolved issues
+    ReleaseVersion {
+        version: Version,
+        unresolved: usize,
+    },
+    MoveIssue {
+        key: String,
+        project: Project,
+        issue_type: IssueType,
+    },
+    /// Pick up the last session instead of the usual start; nothing is
+    /// loaded until the user answers
+    RestoreSession(SessionState),
+}
+
+/// Popup opened on the board's sprints once they loaded
+#[derive(Debug, Clone)]
+enum SprintPopup {
+    Filter,
+    /// The sprint picker for the tickets with these keys
+    Picker(Vec<String>),
+}
+
+impl PendingAction {
+    /// Dialog title
+    fn title(&self) -> &'static str {
+        match self {
+            PendingAction::StartSprint(_) => "Start sprint",
+            PendingAction::CompleteSprint(_) => "Complete sprint",
+            PendingAction::ReleaseVersion { .. } => "Release version",
+            PendingAction::MoveIssue { .. } => "Move issue",
+            PendingAction::RestoreSession(_) => "Restore session",
+        }
+    }
+
+    /// Dialog message
+    fn message(&self) -> String {
+        match self {
+            PendingAction::StartSprint(sprint) => format!("Start {}?", sprint.name),
+            PendingAction::CompleteSprint(plan) => plan.summary(),
+            PendingAction::ReleaseVersion { version, unresolved: 0 } => {
+                format!("Release {} today?", version.name)
+            }
+            PendingAction::ReleaseVersion { version, unresolved } => format!(
+                "Release {} today? {} of its issues are still unresolved.",
+                version.name, unresolved
+            ),
+            PendingAction::MoveIssue {
+                key,
+                project,
+                issue_type,
+            } => format!(
+                "Move {} to {} ({}) as {}? It will get a new key in {}.",
+                key, project.name, project.key, issue_type.name, project.key
+            ),
+            PendingAction::RestoreSession(session) => {
+                let mut message = format!("Pick up where you left off in the {} view", session.view);
+                if let Some((name, _)) = &session.query {
+                    message.push_str(&format!(" of {}", name));
+                }
+                if let Some(key) = &session.ticket {
+                    message.push_str(&format!(" at {}", key));
+                }
+                message + "?"
+            }
+        }
+    }
+}
+
+/// Main UI application; runs on the real terminal unless another backend
+/// is passed to `App::with_terminal`
+pub struct App<B: AppBackend = CrosstermBackend<Stdout>> {
+    terminal: Terminal<B>,
     event_handler: EventHandler,
     renderer: Renderer,
     running: bool,
     connection_status: String,
+    /// All loaded tickets; the list shows them filtered by `list_filter`
+    all_tickets: Vec<Ticket>,
+    list_filter: Option<TicketFilter>,
+    /// Whose tickets the list shows
+    assignee_scope: AssigneeScope,
+    /// The signed-in user, fetched when first needed
+    current_user: Option<User>,
+    /// Facet sidebar of the ticket list and the facets selected in it
+    filter_sidebar: FilterSidebarState,
+    /// Quick filter whose query is loaded instead of `default_query`
+    active_filter: Option<usize>,
+    /// Query builder popup
+    query_builder: Option<QueryBuilderState>,
+    /// View switcher popup
+    view_switcher: Option<ViewSwitcherState>,
+    /// Name and JQL of a query from the query builder or view switcher;
+    /// takes precedence over the quick filters
+    custom_query: Option<(String, String)>,
+    /// Search bar of the ticket list; its text keeps filtering once closed
+    search: SearchState,
+    search_debounce: Debouncer,
+    /// JQL of the Jira search in flight; cancelled when superseded
+    search_task: Option<(String, CancellationToken)>,
+    /// JQL of the loaded Jira search, the current query narrowed to the
+    /// search text; the list shows it until the search is cleared
+    jira_search: Option<String>,
+    /// Ticket to focus once the list loaded, e.g. the restored session's
+    focus_on_load: Option<String>,
+    /// Issue counts of the default query and quick filters, keyed by JQL
+    query_counts: HashMap<String, usize>,
+    /// Sprints the ticket list is restricted to, on top of the current query
+    sprint_filter: Vec<Sprint>,
+    /// Sprint filter picker popup
+    sprint_filter_picker: Option<SprintListState>,
+    /// Sprint popup to open once the sprints loaded
+    sprint_popup: Option<SprintPopup>,
+    dashboard_state: DashboardState,
     ticket_list_state: TicketListState,
     ticket_service: Arc<dyn ApiClient>,
+    /// Write operations sent through `ticket_service` this session
+    audit_log: Arc<AuditLog>,
+    /// View to return to when the debug view is closed
+    debug_previous: Option<ViewMode>,
     loading_state: LoadingState,
     view_mode: ViewMode,
     detail_ticket: Option<Ticket>,
     detail_comments: Vec<Comment>,
+    detail_comments_state: CommentsState,
+    detail_time_in_status: Vec<StatusDuration>,
+    /// Web links attached to the ticket in the detail view
+    detail_remote_links: Vec<RemoteLink>,
+    /// Branches, commits and pull requests of the ticket in the detail view
+    detail_dev_info: DevInfo,
+    /// SLAs of the service desk request in the detail view
+    detail_slas: Vec<Sla>,
+    /// Approvals of the service desk request in the detail view
+    detail_approvals: Vec<Approval>,
+    /// Show descriptions as their Markdown source instead of styled
+    raw_description: bool,
+    /// Text searched for in the description and comments of the detail view
+    detail_search: Option<DetailSearchState>,
+    /// Lines of the description scrolled past, to show a search match
+    detail_description_scroll: u16,
     detail_loading: bool,
+    /// Key of the ticket whose details are being fetched; cancelled when
+    /// the user leaves it first
+    detail_task: Option<(String, CancellationToken)>,
+    /// Key of the neighbouring ticket fetched ahead of `]`/`[` in the detail view
+    detail_prefetch: Option<(String, CancellationToken)>,
+    /// Places left for a jump elsewhere, for the back and forward keys
+    nav_history: NavHistory<Place>,
+    /// Details of that neighbour once fetched, shown without waiting when
+    /// the user steps onto it
+    prefetched_detail: Option<Message>,
+    /// Cached snapshot of the detail ticket from before its last detected change
+    detail_previous: Option<Ticket>,
+    /// Transitions fetched with the detail ticket, by its key and status id
+    detail_transitions: Option<(String, String, Vec<Transition>)>,
     transition_list_state: TransitionListState,
     transitions_loading: bool,
     current_ticket_key: Option<String>,
     instance_url: String,
+    board_state: BoardViewState,
+    board_columns_loaded: bool,
+    config: Config,
+    /// Timezone and strftime date format that dates are shown in
+    timezone: DisplayTimezone,
+    date_format: String,
+    last_refresh: Instant,
+    /// Query of the last load and when it started, for incremental refreshes
+    last_sync: Option<(String, Instant)>,
+    /// Boards of the site, fetched once when no board is configured
+    boards: Option<Vec<Board>>,
+    sprint_list_state: SprintListState,
+    sprints_loading: bool,
+    /// Burndown of the active sprint, keyed by sprint name
+    sprint_progress: Option<(String, SprintProgress)>,
+    backlog_state: TicketListState,
+    backlog_loading: bool,
+    velocity: Vec<SprintVelocity>,
+    velocity_loading: bool,
+    timeline: TimelineState,
+    timeline_loading: bool,
+    release_list: ReleaseListState,
+    releases_loading: bool,
+    /// Issues of the version shown in the release issues view
+    release_issues: TicketListState,
+    timesheet: TimesheetState,
+    timesheet_loading: bool,
+    activity: ActivityState,
+    cross_search: CrossSearchState,
+    cross_search_loading: bool,
+    activity_loading: bool,
+    pending_action: Option<PendingAction>,
+    /// Sprint picker overlay with the keys of the tickets to move
+    sprint_picker: Option<(SprintListState, Vec<String>)>,
+    /// Quick-assign popup with the key of the ticket to assign
+    assignee_picker: Option<(AssigneePickerState, String)>,
+    recent_assignees: RecentAssignees,
+    /// Tickets opened lately, offered by shell completion; not kept for
+    /// headless runs or the demo
+    recent_tickets: Option<RecentTickets>,
+    /// Quick filter name and ticket key given on the command line, applied
+    /// once the first tickets are loaded
+    start_at: (Option<String>, Option<String>),
+    /// Mutating actions that `.` can repeat
+    action_history: ActionHistory,
+    /// Lifecycle hooks shared with the API client, replaced on config reload
+    hooks: Arc<RwLock<HooksConfig>>,
+    /// Watches the config file for edits; not set up for headless runs
+    config_watcher: Option<ConfigWatcher>,
+    /// How the views were last saved as left; not kept for headless runs
+    view_state: Option<ViewState>,
+    /// Issue changes pushed by Jira, when the webhook listener is enabled
+    webhook_events: Option<UnboundedReceiver<WebhookEvent>>,
+    /// Sender handed to background actions, and where their messages arrive
+    messages: UnboundedSender<Message>,
+    inbox: UnboundedReceiver<Message>,
+    /// Project and issue type picker for moving a ticket
+    move_issue: Option<MoveIssueState>,
+    /// Label prompt with the keys of the tickets to edit
+    label_editor: Option<(LabelEditorState, Vec<String>)>,
+    /// Progress of a bulk edit, kept open afterwards when some tickets failed
+    bulk_progress: Option<BulkProgressState>,
+    /// Form for the fields of a transition screen
+    transition_form: Option<TransitionFormState>,
+    /// Form editing the fields of the detail ticket
+    edit_form: Option<EditFormState>,
+    /// Comment prompt shown before a transition runs
+    comment_prompt: Option<CommentPromptState>,
+    /// Comment composer for the ticket in the detail view
+    comment_composer: Option<CommentComposerState>,
+    /// Forms and the comment draft sent to Jira, kept until it answers so
+    /// they can be reopened when it rejects them
+    sent_transition_form: Option<TransitionFormState>,
+    sent_edit_form: Option<EditFormState>,
+    sent_comment: Option<CommentComposerState>,
+    sent_worklog: Option<WorklogPromptState>,
+    /// Attachment picker for the ticket in the detail view
+    attachment_list: Option<AttachmentListState>,
+    /// Link picker for the ticket in the detail view
+    link_list: Option<LinkListState>,
+    approval_list: Option<ApprovalListState>,
+    /// Blocker chains around the ticket in the detail view
+    dependency_graph: Option<DependencyGraphState>,
+    /// Entity properties of the ticket in the detail view
+    issue_properties: Option<IssuePropertiesState>,
+    /// Prompt attaching a web link to the ticket in the detail view
+    remote_link_form: Option<RemoteLinkFormState>,
+    /// Row number being typed to jump to in the list
+    row_jump: Option<String>,
+    /// Timer running on a ticket, shown in the status bar
+    work_timer: Option<WorkTimer>,
+    /// Pomodoro running on a ticket instead of the plain timer
+    pomodoro: Option<Pomodoro>,
+    /// Prompt logging the time of a stopped timer
+    worklog_prompt: Option<WorklogPromptState>,
+    /// Status and summary of the issues mentioned in details viewed so far
+    references: HashMap<String, String>,
+    /// Mentioned keys that could not be fetched, e.g. "UTF-8"
+    unknown_references: HashSet<String>,
+    /// What the user may do in each project seen so far; None while the
+    /// permissions load or when they could not be fetched
+    permissions: HashMap<String, Option<ProjectPermissions>>,
+    /// Titles of the linked Confluence pages seen so far by URL; None for
+    /// pages the credentials do not cover
+    page_titles: HashMap<String, Option<String>>,
+    /// Image attachment drawn inline, until any key is pressed
+    image_preview: Option<ImagePreviewState>,
+    /// Inline image support of the terminal, if any
+    graphics_protocol: Option<GraphicsProtocol>,
+    /// Due-soon reminder popup, shown until any key is pressed
+    due_reminders: Option<Vec<DueReminder>>,
+    last_reminder: Instant,
+    /// Feedback from the last action, shown in the status bar
+    status_message: Option<String>,
+    /// Names and clients of the instance tabs, empty with a single instance
+    tabs: Vec<(String, Arc<dyn ApiClient>)>,
+    /// Which of the tabs this app is
+    tab_index: usize,
+    /// Tab to show instead once the run loop returns, and the ticket to
+    /// open there
+    switch_to_tab: Option<(usize, Option<String>)>,
+    /// Whether `start` has run, so showing the tab again keeps it as it was
+    started: bool,
 }
 
 impl App {
@@ -65,6 +477,7 @@ impl App {
         connection_status: String,
         ticket_service: Arc<dyn ApiClient>,
         instance_url: String,
+        config: Config,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         // Setup terminal
         enable_raw_mode()?;
@@ -73,34 +486,203 @@ impl App {
         
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)?;
-        let renderer = Renderer::new();
+        let mut app = Self::with_terminal(
+            terminal,
+            connection_status,
+            ticket_service,
+            instance_url,
+            config,
+        );
+        app.config_watcher = Config::config_path().ok().map(ConfigWatcher::new);
+        app.restore_view_state(ViewState::load());
+        app.recent_tickets = Some(RecentTickets::load());
+        Ok(app)
+    }
+}
+
+impl<B: AppBackend> App<B> {
+    /// Create an application drawing on the given terminal
+    pub fn with_terminal(
+        terminal: Terminal<B>,
+        connection_status: String,
+        ticket_service: Arc<dyn ApiClient>,
+        instance_url: String,
+        config: Config,
+    ) -> Self {
+        let renderer = Renderer::with_theme(Theme::from_config(
+            &config.ui.theme,
+            Theme::no_color_requested(),
+        ));
+
+        let audit_log = Arc::new(AuditLog::new(config.jira.audit_log.clone()));
+        let ticket_service: Arc<dyn ApiClient> =
+            Arc::new(AuditedApiClient::new(ticket_service, audit_log.clone()));
+        let hooks = Arc::new(RwLock::new(config.hooks.clone()));
+        let ticket_service: Arc<dyn ApiClient> = Arc::new(HookedApiClient::new(
+            ticket_service,
+            hooks.clone(),
+            instance_url.clone(),
+        ));
+
+        for command in &config.commands {
+            let modifiers = if command.key.is_uppercase() {
+                KeyModifiers::SHIFT
+            } else {
+                KeyModifiers::NONE
+            };
+            if EventHandler::handle_key(KeyEvent::new(KeyCode::Char(command.key), modifiers))
+                != AppEvent::Unknown
+            {
+                log::warn!(
+                    "Command '{}' is bound to '{}', which already has an action; it will not run",
+                    command.name,
+                    command.key
+                );
+            }
+        }
         
-        Ok(Self {
+        let (messages, inbox) = unbounded_channel();
+        Self {
             terminal,
             event_handler: EventHandler::default(),
             renderer,
             running: true,
             connection_status,
+            all_tickets: Vec::new(),
+            list_filter: None,
+            assignee_scope: AssigneeScope::default(),
+            current_user: None,
+            filter_sidebar: FilterSidebarState::new(),
+            active_filter: None,
+            query_builder: None,
+            view_switcher: None,
+            custom_query: None,
+            focus_on_load: None,
+            search: SearchState::new(),
+            search_debounce: Debouncer::new(SEARCH_DEBOUNCE),
+            search_task: None,
+            jira_search: None,
+            query_counts: HashMap::new(),
+            sprint_filter: Vec::new(),
+            sprint_filter_picker: None,
+            sprint_popup: None,
+            dashboard_state: DashboardState::new(),
             ticket_list_state: TicketListState::new(),
             ticket_service,
+            audit_log,
+            debug_previous: None,
             loading_state: LoadingState::Idle,
-            view_mode: ViewMode::List,
+            view_mode: ViewMode::Dashboard,
             detail_ticket: None,
             detail_comments: Vec::new(),
+            detail_comments_state: CommentsState::default(),
+            detail_time_in_status: Vec::new(),
+            detail_remote_links: Vec::new(),
+            detail_dev_info: DevInfo::default(),
+            detail_slas: Vec::new(),
+            detail_approvals: Vec::new(),
+            raw_description: false,
+            detail_search: None,
+            detail_description_scroll: 0,
             detail_loading: false,
+            detail_task: None,
+            detail_prefetch: None,
+            nav_history: NavHistory::new(),
+            prefetched_detail: None,
+            detail_previous: None,
+            detail_transitions: None,
             transition_list_state: TransitionListState::new(),
             transitions_loading: false,
             current_ticket_key: None,
             instance_url,
-        })
+            board_state: BoardViewState::new(SwimlaneMode::from_config(&config.ui.swimlanes)),
+            board_columns_loaded: false,
+            boards: None,
+            timezone: DisplayTimezone::from_config(config.ui.timezone.as_deref()),
+            date_format: checked_date_format(&config.ui.date_format).to_string(),
+            config,
+            last_refresh: Instant::now(),
+            last_sync: None,
+            sprint_list_state: SprintListState::new(),
+            sprints_loading: false,
+            sprint_progress: None,
+            backlog_state: TicketListState::new(),
+            backlog_loading: false,
+            velocity: Vec::new(),
+            velocity_loading: false,
+            timeline: TimelineState::default(),
+            timeline_loading: false,
+            release_list: ReleaseListState::default(),
+            releases_loading: false,
+            release_issues: TicketListState::new(),
+            timesheet: TimesheetState::default(),
+            timesheet_loading: false,
+            activity: ActivityState::default(),
+            cross_search: CrossSearchState::default(),
+            cross_search_loading: false,
+            activity_loading: false,
+            pending_action: None,
+            sprint_picker: None,
+            assignee_picker: None,
+            recent_assignees: RecentAssignees::load(),
+            recent_tickets: None,
+            start_at: (None, None),
+            tabs: Vec::new(),
+            tab_index: 0,
+            switch_to_tab: None,
+            started: false,
+            action_history: ActionHistory::new(),
+            hooks,
+            config_watcher: None,
+            view_state: None,
+            webhook_events: None,
+            messages,
+            inbox,
+            move_issue: None,
+            label_editor: None,
+            bulk_progress: None,
+            transition_form: None,
+            edit_form: None,
+            comment_prompt: None,
+            comment_composer: None,
+            sent_transition_form: None,
+            sent_edit_form: None,
+            sent_comment: None,
+            sent_worklog: None,
+            attachment_list: None,
+            link_list: None,
+            approval_list: None,
+            dependency_graph: None,
+            issue_properties: None,
+            row_jump: None,
+            remote_link_form: None,
+            work_timer: None,
+            pomodoro: None,
+            worklog_prompt: None,
+            references: HashMap::new(),
+            unknown_references: HashSet::new(),
+            permissions: HashMap::new(),
+            page_titles: HashMap::new(),
+            image_preview: None,
+            graphics_protocol: GraphicsProtocol::detect(),
+            due_reminders: None,
+            last_reminder: Instant::now(),
+            status_message: None,
+        }
     }
 
     /// Run the application main loop
     pub async fn run(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        // Load initial tickets
-        self.load_tickets().await;
+        if self.started {
+            // Another tab drew over the screen in the meantime
+            self.terminal.clear()?;
+            self.apply_start_at();
+        } else {
+            self.started = true;
+            self.start().await;
+        }
 
-        while self.running {
+        while self.is_running() && self.switch_to_tab.is_none() {
             // Draw UI
             if let Err(e) = self.draw() {
                 log::error!("run: Error in draw(): {}", e);
@@ -108,314 +690,4675 @@ impl App {
 
             // Handle events with timeout
             if crossterm::event::poll(Duration::from_millis(100))? {
-                let event = match self.event_handler.next() {
-                    Ok(e) => e,
+                match self.event_handler.next_key() {
+                    Ok(Some(key)) => {
+                        self.handle_key(key).await;
+                        self.save_view_state();
+                    }
+                    Ok(None) => {}
                     Err(e) => {
                         log::error!("Failed to read event: {}", e);
                         continue;
                     }
-                };
-                
-                log::debug!("run: Received event: {:?}, current view_mode: {:?}", event, self.view_mode);
-                
-                match event {
-                    AppEvent::Quit => {
-                        log::debug!("run: Quit event received");
-                        self.running = false;
-                    }
-                    AppEvent::Refresh => {
-                        log::debug!("run: Refresh event received");
-                        self.load_tickets().await;
-                    }
-                    AppEvent::MoveUp => {
-                        match self.view_mode {
-                            ViewMode::List => {
-                                self.ticket_list_state.move_up();
-                            }
-                            ViewMode::Transitions => {
-                                self.transition_list_state.move_up();
-                            }
-                            _ => {}
-                        }
+                }
+            }
+
+            self.poll();
+
+            // Handle ticks
+            if self.event_handler.should_tick() {
+                if self.config_watcher.as_mut().is_some_and(|watcher| watcher.changed()) {
+                    self.reload_config();
+                }
+                if self.auto_refresh_due() {
+                    if self.view_mode == ViewMode::Activity {
+                        log::debug!("run: Polling for activity");
+                        self.load_activity();
+                    } else {
+                        log::debug!("run: Auto-refreshing tickets");
+                        self.refresh_changed_tickets();
                     }
-                    AppEvent::MoveDown => {
-                        match self.view_mode {
-                            ViewMode::List => {
-                                self.ticket_list_state.move_down();
-                            }
-                            ViewMode::Transitions => {
-                                self.transition_list_state.move_down();
-                            }
-                            _ => {}
-                        }
+                }
+                if self.reminder_due() {
+                    self.check_due_reminders();
+                }
+                self.check_pomodoro();
+            }
+        }
+
+        if !self.is_running() {
+            self.save_session();
+        }
+        Ok(())
+    }
+
+    /// Make this app tab `index` of the instances in `tabs`, whose clients
+    /// also serve the search across instances. Only the first tab
+    /// remembers its views, since the state file has room for one, and only
+    /// it follows config.toml, which would reset the other tabs' `[jira]`
+    /// section
+    pub fn set_tabs(&mut self, tabs: Vec<(String, Arc<dyn ApiClient>)>, index: usize) {
+        self.tabs = tabs;
+        self.tab_index = index;
+        if index > 0 {
+            self.view_state = None;
+            self.config_watcher = None;
+        }
+    }
+
+    /// Stop recording opened tickets for shell completion, for sessions
+    /// whose tickets are not on the user's Jira, like the demo's
+    pub fn forget_recent_tickets(&mut self) {
+        self.recent_tickets = None;
+    }
+
+    /// Tab the user switched to, which ended the run loop, with the ticket
+    /// to open there
+    pub fn take_tab_switch(&mut self) -> Option<(usize, Option<String>)> {
+        self.switch_to_tab.take()
+    }
+
+    /// Names of the tabs with the shown one bracketed, e.g. "[Home] Acme"
+    fn tab_bar(&self) -> String {
+        self.tabs
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| if i == self.tab_index { format!("[{}]", name) } else { name.clone() })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// False once the user has quit
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// The backend drawn on, for inspecting what was rendered
+    #[allow(dead_code)] // Used by the headless UI tests
+    pub fn backend(&self) -> &B {
+        self.terminal.backend()
+    }
+
+    /// Start with the quick filter of this name and/or this ticket open
+    pub fn start_at(&mut self, filter: Option<String>, ticket: Option<String>) {
+        self.start_at = (filter, ticket);
+    }
+
+    /// Apply what `start_at` asked for
+    pub fn apply_start_at(&mut self) {
+        let (filter, ticket) = std::mem::take(&mut self.start_at);
+        if let Some(name) = filter {
+            match self.config.filters.iter().position(|filter| filter.name == name) {
+                Some(index) if index < 9 => {
+                    self.view_mode = ViewMode::List;
+                    // The filter may already be restored, and selecting it
+                    // again would turn it off
+                    if self.active_filter != Some(index) {
+                        self.select_quick_filter(index);
                     }
-                    AppEvent::EnterDetail => {
-                        log::debug!("run: EnterDetail event received, view_mode: {:?}", self.view_mode);
-                        match self.view_mode {
-                            ViewMode::List => {
-                                log::debug!("run: Calling open_detail_view()");
-                                self.open_detail_view().await;
-                                log::debug!("run: open_detail_view() completed");
-                            }
-                            ViewMode::Transitions => {
-                                // Execute selected transition
-                                if let Some(transition) = self.transition_list_state.focused_transition() {
-                                    if let Some(ticket_key) = &self.current_ticket_key {
-                                        if let Err(_e) = self.ticket_service.transition_issue(
-                                            ticket_key,
-                                            &transition.id,
-                                            None,
-                                        ).await {
-                                            // Error handling
-                                        } else {
-                                            // Refresh ticket and return to detail view
-                                            self.view_mode = ViewMode::Detail;
-                                            if let Ok(updated_ticket) = self.ticket_service.get_issue(ticket_key).await {
-                                                self.detail_ticket = Some(updated_ticket);
-                                            }
-                                            self.load_tickets().await;
-                                        }
-                                    }
-                                }
-                            }
-                            _ => {}
-                        }
+                }
+                _ => self.status_message = Some(format!("No quick filter named '{}'", name)),
+            }
+        }
+        if let Some(key) = ticket {
+            self.open_detail(key.to_uppercase());
+        }
+    }
+
+    /// Restore the last session or offer to, depending on `restore_session`;
+    /// otherwise load as usual and apply `start_at`
+    pub async fn start(&mut self) {
+        let session = self.view_state.as_ref().and_then(|state| state.session.clone());
+        let restore = self.config.ui.restore_session.to_lowercase();
+        match session {
+            // A filter or ticket asked for on the command line wins
+            Some(session) if self.start_at == (None, None) && restore != "never" => {
+                if restore == "always" {
+                    self.restore_session(session).await;
+                } else {
+                    self.pending_action = Some(PendingAction::RestoreSession(session));
+                }
+            }
+            _ => {
+                self.load().await;
+                self.apply_start_at();
+            }
+        }
+    }
+
+    /// Load the session's query straight away and return to its view and
+    /// ticket
+    async fn restore_session(&mut self, session: SessionState) {
+        self.custom_query = session.query;
+        self.load().await;
+        match session.view.as_str() {
+            "list" => self.view_mode = ViewMode::List,
+            "board" => {
+                self.view_mode = ViewMode::Board;
+                let actions = self.board_columns_to_load();
+                self.dispatch(actions);
+            }
+            _ => {}
+        }
+        if let Some(key) = session.ticket {
+            self.focus_on_load = Some(key.clone());
+            if session.view == "detail" {
+                self.open_detail(key);
+            }
+        }
+    }
+
+    /// Remember the view, query and ticket the session ends on
+    fn save_session(&mut self) {
+        if self.config.ui.restore_session.eq_ignore_ascii_case("never") {
+            return;
+        }
+        let view = match self.view_mode {
+            ViewMode::Dashboard => "dashboard",
+            ViewMode::Board => "board",
+            ViewMode::Detail | ViewMode::Transitions | ViewMode::Diff => "detail",
+            // Views of their own data are left for the list they came from
+            _ => "list",
+        };
+        let ticket = match view {
+            "detail" => self.current_ticket_key.clone(),
+            "dashboard" => None,
+            _ => self.ticket_list_state.focused_ticket().map(|t| t.key.clone()),
+        };
+        let Some(state) = self.view_state.as_mut() else {
+            return;
+        };
+        state.session = Some(SessionState {
+            view: view.to_string(),
+            query: self.custom_query.clone(),
+            ticket,
+        });
+        if let Err(e) = state.save() {
+            log::warn!("save_session: {}", e);
+        }
+    }
+
+    /// Load the tickets and due reminders shown on startup, and start the
+    /// webhook listener if one is configured
+    pub async fn load(&mut self) {
+        // The assignee scope and the dashboard's work in progress tile
+        // filter by the signed-in user
+        if self.current_user.is_none() {
+            self.spawn_action(Action::LoadMyself(UserPurpose::Remember));
+        }
+        self.load_tickets();
+        self.check_due_reminders();
+
+        if let Some(address) = self.config.jira.webhook_listen.clone() {
+            match webhook::listen(&address).await {
+                Ok(events) => self.webhook_events = Some(events),
+                Err(e) => {
+                    log::error!("load: Failed to listen for webhooks on {}: {}", address, e);
+                    self.status_message =
+                        Some(format!("Webhook listener on {} failed: {}", address, e));
+                }
+            }
+        }
+    }
+
+    /// Handle a single key press: open dialogs get the key first, otherwise
+    /// it is mapped to an event for the current view
+    pub async fn handle_key(&mut self, key: KeyEvent) {
+        let event = match key {
+            _ if self.due_reminders.is_some() => {
+                // Any key dismisses the reminder
+                self.due_reminders = None;
+                AppEvent::Unknown
+            }
+            _ if self.pending_action.is_some() => {
+                // Confirmation dialogs capture all keys
+                self.handle_confirm_key(key).await;
+                AppEvent::Unknown
+            }
+            _ if self.sprint_picker.is_some() => {
+                self.handle_sprint_picker_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.assignee_picker.is_some() => {
+                self.handle_assignee_picker_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.bulk_progress.is_some() => {
+                // Any key closes the bulk edit report once every ticket is done
+                if self.bulk_progress.as_ref().is_some_and(BulkProgressState::is_finished) {
+                    self.bulk_progress = None;
+                }
+                AppEvent::Unknown
+            }
+            _ if self.transition_form.is_some() => {
+                self.handle_transition_form_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.edit_form.is_some() => {
+                self.handle_edit_form_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.comment_prompt.is_some() => {
+                self.handle_comment_prompt_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.image_preview.is_some() => {
+                // Any key closes the image preview
+                self.close_image_preview();
+                AppEvent::Unknown
+            }
+            _ if self.attachment_list.is_some() => {
+                self.handle_attachment_list_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.link_list.is_some() => {
+                self.handle_link_list_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.approval_list.is_some() => {
+                self.handle_approval_list_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.dependency_graph.is_some() => {
+                self.handle_dependency_graph_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.issue_properties.is_some() => {
+                self.handle_issue_properties_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.detail_search.as_ref().is_some_and(|search| search.typing) => {
+                self.handle_detail_search_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.row_jump.is_some() => {
+                self.handle_row_jump_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.remote_link_form.is_some() => {
+                self.handle_remote_link_form_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.worklog_prompt.is_some() => {
+                self.handle_worklog_prompt_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.comment_composer.is_some() => {
+                self.handle_comment_composer_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.label_editor.is_some() => {
+                self.handle_label_editor_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.move_issue.is_some() => {
+                self.handle_move_issue_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.query_builder.is_some() => {
+                self.handle_query_builder_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.view_mode == ViewMode::CrossSearch && self.cross_search.editing => {
+                self.handle_cross_search_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.view_switcher.is_some() => {
+                self.handle_view_switcher_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.sprint_filter_picker.is_some() => {
+                self.handle_sprint_filter_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.search.open && self.view_mode == ViewMode::List => {
+                self.handle_search_key(key);
+                AppEvent::Unknown
+            }
+            _ if self.filter_sidebar.open && self.view_mode == ViewMode::List => {
+                self.handle_filter_sidebar_key(key);
+                AppEvent::Unknown
+            }
+            _ => {
+                self.status_message = None;
+                match EventHandler::handle_key(key) {
+                    AppEvent::Unknown => {
+                        self.run_user_command(key).await;
+                        AppEvent::Unknown
+                    }
+                    event => event,
+                }
+            }
+        };
+        
+        log::debug!("handle_key: Received event: {:?}, current view_mode: {:?}", event, self.view_mode);
+        
+        match event {
+            AppEvent::Quit if self.work_timer.is_some() || self.pomodoro.is_some() => {
+                // Offer the timed work before it is lost
+                self.stop_timer();
+                self.status_message = Some("Log the timed work, or Esc to discard it, then quit".to_string());
+            }
+            AppEvent::Quit => {
+                log::debug!("run: Quit event received");
+                self.running = false;
+            }
+            AppEvent::NavigateBack => self.navigate_back(),
+            AppEvent::NavigateForward => self.navigate_forward(),
+            AppEvent::NextTab | AppEvent::PreviousTab if self.view_mode == ViewMode::Detail => {
+                self.open_neighbour(event == AppEvent::NextTab);
+            }
+            AppEvent::NextTab | AppEvent::PreviousTab if self.tabs.len() > 1 => {
+                let step = if event == AppEvent::NextTab { 1 } else { self.tabs.len() - 1 };
+                self.switch_to_tab = Some(((self.tab_index + step) % self.tabs.len(), None));
+            }
+            AppEvent::ToggleTimer | AppEvent::TogglePomodoro
+                if self.work_timer.is_some() || self.pomodoro.is_some() =>
+            {
+                self.stop_timer();
+            }
+            AppEvent::ToggleTimer
+                if matches!(
+                    self.view_mode,
+                    ViewMode::List | ViewMode::Detail | ViewMode::Backlog
+                ) =>
+            {
+                if let Some(key) = self.focused_key() {
+                    self.status_message = Some(format!("Timer started on {}", key));
+                    self.work_timer = Some(WorkTimer::start(key, chrono::Utc::now()));
+                }
+            }
+            AppEvent::TogglePomodoro
+                if matches!(
+                    self.view_mode,
+                    ViewMode::List | ViewMode::Detail | ViewMode::Backlog
+                ) =>
+            {
+                if let Some(key) = self.focused_key() {
+                    let (work, rest) = (self.config.ui.pomodoro_work_minutes, self.config.ui.pomodoro_break_minutes);
+                    self.status_message = Some(format!("Pomodoro started on {}: {}m work, {}m break", key, work, rest));
+                    self.pomodoro = Some(Pomodoro::start(key, work, rest, chrono::Utc::now()));
+                }
+            }
+            AppEvent::Refresh if self.view_mode == ViewMode::Sprints => {
+                let actions = self.sprints_to_load();
+                self.dispatch(actions);
+            }
+            AppEvent::Refresh if self.view_mode == ViewMode::Backlog => {
+                let actions = self.backlog_to_load();
+                self.dispatch(actions);
+            }
+            AppEvent::Refresh if self.view_mode == ViewMode::Velocity => {
+                let actions = self.velocity_to_load();
+                self.dispatch(actions);
+            }
+            AppEvent::Refresh if self.view_mode == ViewMode::Timeline => {
+                self.load_timeline();
+            }
+            AppEvent::Refresh if self.view_mode == ViewMode::Releases => {
+                let project_key = self.release_list.project_key.clone();
+                self.load_releases(project_key);
+            }
+            AppEvent::Refresh if self.view_mode == ViewMode::ReleaseIssues => {
+                self.load_release_issues();
+            }
+            AppEvent::Refresh if self.view_mode == ViewMode::Timesheet => {
+                self.load_timesheet(self.timesheet.timesheet.week_start);
+            }
+            AppEvent::Refresh if self.view_mode == ViewMode::Activity => {
+                self.load_activity();
+            }
+            AppEvent::Refresh => {
+                log::debug!("run: Refresh event received");
+                self.load_tickets();
+            }
+            AppEvent::MoveLeft if self.view_mode == ViewMode::Dashboard => {
+                self.dashboard_state.move_left();
+            }
+            AppEvent::MoveRight if self.view_mode == ViewMode::Dashboard => {
+                let tile_count = self.dashboard_tiles().len();
+                self.dashboard_state.move_right(tile_count);
+            }
+            AppEvent::MoveLeft if self.view_mode == ViewMode::Timesheet => {
+                self.load_timesheet(self.timesheet.timesheet.week_start - chrono::Duration::days(7));
+            }
+            AppEvent::MoveRight if self.view_mode == ViewMode::Timesheet => {
+                self.load_timesheet(self.timesheet.timesheet.week_start + chrono::Duration::days(7));
+            }
+            AppEvent::MoveUp => {
+                match self.view_mode {
+                    ViewMode::Dashboard => {
+                        self.dashboard_state.move_up();
                     }
-                    AppEvent::ToggleSelection => {
-                        self.ticket_list_state.toggle_selection();
+                    ViewMode::List => {
+                        self.ticket_list_state.move_up();
                     }
-                    AppEvent::ExitDetail => {
-                        match self.view_mode {
-                            ViewMode::Detail | ViewMode::Transitions | ViewMode::CreateTicket => {
-                                self.view_mode = ViewMode::List;
-                                self.detail_ticket = None;
-                                self.detail_comments = Vec::new();
-                                self.transition_list_state = TransitionListState::new();
-                                self.current_ticket_key = None;
-                            }
-                            _ => {}
-                        }
+                    ViewMode::Transitions => {
+                        self.transition_list_state.move_up();
                     }
-                    AppEvent::AssignToMe => {
-                        if self.view_mode == ViewMode::Detail {
-                            self.assign_to_me().await;
-                        }
+                    ViewMode::Board => {
+                        self.board_state.move_up();
                     }
-                    AppEvent::StartProgress => {
-                        if self.view_mode == ViewMode::Detail {
-                            self.start_progress().await;
-                        }
+                    ViewMode::Sprints => {
+                        self.sprint_list_state.move_up();
+                    }
+                    ViewMode::Detail => {
+                        self.detail_comments_state.move_up();
+                    }
+                    ViewMode::Backlog => {
+                        self.backlog_state.move_up();
+                    }
+                    ViewMode::Timeline => {
+                        self.timeline.move_up();
+                    }
+                    ViewMode::Releases => {
+                        self.release_list.move_up();
+                    }
+                    ViewMode::ReleaseIssues => {
+                        self.release_issues.move_up();
+                    }
+                    ViewMode::Timesheet => {
+                        self.timesheet.move_up();
+                    }
+                    ViewMode::Activity => {
+                        self.activity.move_up();
+                    }
+                    ViewMode::CrossSearch => {
+                        self.cross_search.move_up();
+                    }
+                    _ => {}
+                }
+            }
+            AppEvent::MoveDown => {
+                match self.view_mode {
+                    ViewMode::Dashboard => {
+                        let tile_count = self.dashboard_tiles().len();
+                        self.dashboard_state.move_down(tile_count);
+                    }
+                    ViewMode::List => {
+                        self.ticket_list_state.move_down();
+                    }
+                    ViewMode::Transitions => {
+                        self.transition_list_state.move_down();
                     }
-                    AppEvent::Resolve => {
-                        if self.view_mode == ViewMode::Detail {
-                            self.resolve_ticket().await;
+                    ViewMode::Board => {
+                        let lane_count = build_swimlanes(
+                            &self.ticket_list_state.tickets,
+                            self.board_state.swimlane_mode,
+                            &self.board_state.columns,
+                        )
+                        .len();
+                        self.board_state.move_down(lane_count);
+                    }
+                    ViewMode::Sprints => {
+                        self.sprint_list_state.move_down();
+                    }
+                    ViewMode::Detail => {
+                        self.detail_comments_state.move_down(self.detail_comments.len());
+                    }
+                    ViewMode::Backlog => {
+                        self.backlog_state.move_down();
+                    }
+                    ViewMode::Timeline => {
+                        self.timeline.move_down();
+                    }
+                    ViewMode::Releases => {
+                        self.release_list.move_down();
+                    }
+                    ViewMode::ReleaseIssues => {
+                        self.release_issues.move_down();
+                    }
+                    ViewMode::Timesheet => {
+                        self.timesheet.move_down();
+                    }
+                    ViewMode::Activity => {
+                        self.activity.move_down();
+                    }
+                    ViewMode::CrossSearch => {
+                        self.cross_search.move_down();
+                    }
+                    _ => {}
+                }
+            }
+            AppEvent::EnterDetail => {
+                log::debug!("run: EnterDetail event received, view_mode: {:?}", self.view_mode);
+                match self.view_mode {
+                    ViewMode::Dashboard => {
+                        let tiles = self.dashboard_tiles();
+                        if let Some(tile) = tiles.get(self.dashboard_state.focused_tile) {
+                            self.nav_history.leave(self.current_place());
+                            self.list_filter = Some(tile.filter.clone());
+                            self.apply_list_filter();
+                            self.view_mode = ViewMode::List;
                         }
                     }
-                    AppEvent::ShowTransitions => {
-                        if self.view_mode == ViewMode::Detail {
-                            self.show_transitions().await;
+                    ViewMode::List => {
+                        log::debug!("run: Calling open_detail_view()");
+                        self.open_detail_view();
+                        log::debug!("run: open_detail_view() completed");
+                    }
+                    ViewMode::Detail => self.open_reference(),
+                    ViewMode::Transitions => {
+                        // Execute selected transition
+                        if let Some(transition) = self.transition_list_state.focused_transition().cloned() {
+                            self.begin_transition(transition);
                         }
                     }
-                    AppEvent::CreateTicket => {
-                        if self.view_mode == ViewMode::List {
-                            // TODO: Open create ticket form
-                            // For now, just show a message
+                    ViewMode::Board => {
+                        // Collapse or expand the focused lane
+                        let lanes = build_swimlanes(
+                            &self.ticket_list_state.tickets,
+                            self.board_state.swimlane_mode,
+                            &self.board_state.columns,
+                        );
+                        if let Some(lane) = lanes.get(self.board_state.focused_lane) {
+                            let lane_id = lane.id.clone();
+                            self.board_state.toggle_lane(&lane_id);
                         }
                     }
-                    AppEvent::AddComment => {
-                        if self.view_mode == ViewMode::Detail {
-                            // TODO: Open comment input
-                            // For now, just show a message
+                    ViewMode::Timeline => self.toggle_timeline_epic(),
+                    ViewMode::Releases => {
+                        self.view_mode = ViewMode::ReleaseIssues;
+                        self.load_release_issues();
+                    }
+                    ViewMode::Timesheet => {
+                        if let Some(key) = self.timesheet.focused_key().map(str::to_string) {
+                            self.open_detail(key);
                         }
                     }
-                    AppEvent::OpenInBrowser => {
-                        if self.view_mode == ViewMode::Detail || self.view_mode == ViewMode::List {
-                            self.open_in_browser();
+                    ViewMode::Activity => {
+                        if let Some(key) = self.activity.focused_key().map(str::to_string) {
+                            self.open_detail(key);
                         }
                     }
-                    _ => {
-                        // Other events handled elsewhere
+                    ViewMode::CrossSearch => self.open_cross_search_hit(),
+                    _ => {}
+                }
+            }
+            AppEvent::ToggleSelection if self.view_mode == ViewMode::Backlog => {
+                self.backlog_state.toggle_selection();
+            }
+            AppEvent::ToggleSelection if self.view_mode == ViewMode::Detail => {
+                let focused = self.detail_comments.get(self.detail_comments_state.focused_index);
+                if let Some(comment) = focused {
+                    self.detail_comments_state.toggle(&comment.id);
+                }
+            }
+            AppEvent::ToggleSelection => {
+                self.ticket_list_state.toggle_selection();
+            }
+            AppEvent::ExitDetail if self.view_mode == ViewMode::Detail && self.detail_search.is_some() => {
+                self.detail_search = None;
+            }
+            AppEvent::ExitDetail => {
+                match self.view_mode {
+                    ViewMode::Diff => {
+                        self.view_mode = ViewMode::Detail;
+                    }
+                    ViewMode::Debug => {
+                        self.view_mode = self.debug_previous.take().unwrap_or(ViewMode::List);
+                    }
+                    ViewMode::ReleaseIssues => {
+                        self.view_mode = ViewMode::Releases;
                     }
+                    ViewMode::Board
+                    | ViewMode::Sprints
+                    | ViewMode::Backlog
+                    | ViewMode::Velocity
+                    | ViewMode::Timeline
+                    | ViewMode::Releases
+                    | ViewMode::Timesheet
+                    | ViewMode::Activity
+                    | ViewMode::CrossSearch
+                    | ViewMode::AuditLog => {
+                        self.view_mode = ViewMode::List;
+                    }
+                    ViewMode::List => {
+                        // Back to the dashboard, dropping any drill-down filter
+                        self.nav_history.leave(self.current_place());
+                        self.list_filter = None;
+                        self.apply_list_filter();
+                        self.view_mode = ViewMode::Dashboard;
+                    }
+                    ViewMode::Detail | ViewMode::Transitions | ViewMode::CreateTicket => {
+                        self.nav_history.leave(self.current_place());
+                        self.close_detail();
+                    }
+                    _ => {}
                 }
             }
-
-            // Handle ticks
-            if self.event_handler.should_tick() {
-                // Periodic updates can go here
+            AppEvent::AssignToMe if self.view_mode == ViewMode::Detail
+                && self.permitted(Permission::AssignIssues) =>
+            {
+                self.assign_to_me();
+            }
+            AppEvent::StartProgress if self.view_mode == ViewMode::Detail
+                && self.permitted(Permission::TransitionIssues) =>
+            {
+                self.start_progress();
+            }
+            AppEvent::Resolve if self.view_mode == ViewMode::Detail
+                && self.permitted(Permission::TransitionIssues) =>
+            {
+                self.resolve_ticket();
+            }
+            AppEvent::ShowTransitions if self.view_mode == ViewMode::Detail
+                && self.permitted(Permission::TransitionIssues) =>
+            {
+                self.show_transitions();
+            }
+            AppEvent::CreateTicket if self.view_mode == ViewMode::List => {
+                // TODO: Open create ticket form
+                // For now, just show a message
+            }
+            AppEvent::AddComment if self.view_mode == ViewMode::Detail
+                && self.permitted(Permission::AddComments) =>
+            {
+                self.open_comment_composer();
+            }
+            AppEvent::EditFields
+                if self.view_mode == ViewMode::Detail && self.permitted(Permission::EditIssues) =>
+            {
+                self.open_edit_form();
+            }
+            AppEvent::ToggleRawDescription if self.view_mode == ViewMode::Detail => {
+                self.raw_description = !self.raw_description;
+                // Description lines differ between the two
+                self.find_in_detail();
+            }
+            AppEvent::ToggleCommentOrder if self.view_mode == ViewMode::Detail => {
+                self.toggle_comment_order();
+            }
+            AppEvent::ShowAttachments if self.view_mode == ViewMode::Detail => {
+                self.show_attachments();
+            }
+            AppEvent::ShowLinks if self.view_mode == ViewMode::Detail => {
+                self.show_links();
+            }
+            AppEvent::ShowApprovals if self.view_mode == ViewMode::Detail => {
+                self.show_approvals();
+            }
+            AppEvent::ShowDependencies if self.view_mode == ViewMode::Detail => {
+                self.show_dependencies();
+            }
+            AppEvent::ShowProperties if self.view_mode == ViewMode::Detail => {
+                self.show_properties();
+            }
+            AppEvent::JumpToRow if self.view_mode == ViewMode::List => {
+                self.row_jump = Some(String::new());
+            }
+            AppEvent::AddRemoteLink if self.view_mode == ViewMode::Detail => {
+                if let Some(key) = self.current_ticket_key.clone() {
+                    self.remote_link_form = Some(RemoteLinkFormState::new(key));
+                }
+            }
+            AppEvent::ShowDiff
+                if self.view_mode == ViewMode::Detail && self.detail_previous.is_some() =>
+            {
+                self.view_mode = ViewMode::Diff;
+            }
+            AppEvent::ToggleBoard => match self.view_mode {
+                ViewMode::List => {
+                    self.view_mode = ViewMode::Board;
+                    let actions = self.board_columns_to_load();
+                    self.dispatch(actions);
+                }
+                ViewMode::Board => self.view_mode = ViewMode::List,
+                _ => {}
+            },
+            AppEvent::CycleSwimlanes if self.view_mode == ViewMode::Board => {
+                self.board_state.cycle_swimlanes();
+            }
+            AppEvent::ShowSprints
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.show_sprints();
+            }
+            AppEvent::ShowBacklog
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.show_backlog();
+            }
+            AppEvent::ShowVelocity
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board | ViewMode::Sprints
+                ) =>
+            {
+                self.view_mode = ViewMode::Velocity;
+                let actions = self.velocity_to_load();
+                self.dispatch(actions);
+            }
+            AppEvent::ShowTimeline
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.view_mode = ViewMode::Timeline;
+                self.load_timeline();
+            }
+            AppEvent::ShowReleases
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.show_releases();
+            }
+            AppEvent::ShowTimesheet
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.view_mode = ViewMode::Timesheet;
+                self.load_timesheet(self.timezone.today());
+            }
+            AppEvent::ShowActivity
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.view_mode = ViewMode::Activity;
+                self.load_activity();
+            }
+            AppEvent::ToggleDebug if self.view_mode == ViewMode::Debug => {
+                self.view_mode = self.debug_previous.take().unwrap_or(ViewMode::List);
+            }
+            AppEvent::ToggleDebug => {
+                self.debug_previous = Some(self.view_mode);
+                self.view_mode = ViewMode::Debug;
+            }
+            AppEvent::ShowAuditLog => {
+                self.view_mode = ViewMode::AuditLog;
+            }
+            AppEvent::BuildQuery if self.view_mode == ViewMode::Detail && self.detail_ticket.is_some() => {
+                self.detail_search = Some(DetailSearchState::new());
+            }
+            // n and N step through the matches of a search in the detail view
+            AppEvent::CreateTicket | AppEvent::CopyReleaseNotes
+                if self.view_mode == ViewMode::Detail && self.detail_search.is_some() =>
+            {
+                if let Some(search) = self.detail_search.as_mut() {
+                    search.step(event == AppEvent::CreateTicket);
+                }
+                self.show_search_match();
+            }
+            AppEvent::BuildQuery if self.view_mode == ViewMode::CrossSearch => {
+                self.cross_search.editing = true;
             }
+            AppEvent::SearchInstances
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.view_mode = ViewMode::CrossSearch;
+                self.cross_search.editing = true;
+            }
+            AppEvent::BuildQuery
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.query_builder = Some(QueryBuilderState::new());
+            }
+            AppEvent::SwitchView
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.view_switcher = Some(ViewSwitcherState::new());
+            }
+            AppEvent::FilterBySprint
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.open_sprint_filter();
+            }
+            AppEvent::Search if self.view_mode == ViewMode::List => {
+                self.search.open = true;
+            }
+            AppEvent::CycleAssigneeFilter if self.view_mode == ViewMode::List => {
+                self.cycle_assignee_scope();
+            }
+            AppEvent::ToggleFilterSidebar if self.view_mode == ViewMode::List => {
+                self.filter_sidebar.open = true;
+            }
+            AppEvent::QuickFilter(index)
+                if matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                ) =>
+            {
+                self.select_quick_filter(index);
+            }
+            AppEvent::RankUp if self.view_mode == ViewMode::Backlog => {
+                self.rank_focused(true);
+            }
+            AppEvent::RankDown if self.view_mode == ViewMode::Backlog => {
+                self.rank_focused(false);
+            }
+            AppEvent::MoveToSprint
+                if matches!(
+                    self.view_mode,
+                    ViewMode::List | ViewMode::Detail | ViewMode::Backlog
+                ) =>
+            {
+                self.open_sprint_picker();
+            }
+            AppEvent::QuickAssign
+                if matches!(
+                    self.view_mode,
+                    ViewMode::List | ViewMode::Detail | ViewMode::Backlog
+                ) && self.permitted(Permission::AssignIssues) =>
+            {
+                self.open_assignee_picker();
+            }
+            AppEvent::ChangeReporter
+                if matches!(
+                    self.view_mode,
+                    ViewMode::List | ViewMode::Detail | ViewMode::Backlog
+                ) && self.permitted(Permission::ModifyReporter) =>
+            {
+                self.open_assignee_picker();
+                if let Some((picker, _)) = self.assignee_picker.as_mut() {
+                    picker.reporter = true;
+                }
+            }
+            AppEvent::EditLabels
+                if matches!(
+                    self.view_mode,
+                    ViewMode::List | ViewMode::Detail | ViewMode::Backlog
+                ) =>
+            {
+                let keys = self.target_keys();
+                if !keys.is_empty() && self.permitted(Permission::EditIssues) {
+                    let mut editor = LabelEditorState::new();
+                    if let Some(label) = self.default_label(&keys) {
+                        editor.input = label;
+                    }
+                    self.label_editor = Some((editor, keys));
+                }
+            }
+            AppEvent::MoveIssue
+                if matches!(self.view_mode, ViewMode::List | ViewMode::Detail)
+                    && self.permitted(Permission::MoveIssues) =>
+            {
+                self.open_move_issue();
+            }
+            AppEvent::RepeatLastAction
+                if matches!(
+                    self.view_mode,
+                    ViewMode::List | ViewMode::Detail | ViewMode::Backlog
+                ) =>
+            {
+                self.repeat_last_action();
+            }
+            AppEvent::StartProgress if self.view_mode == ViewMode::Sprints => {
+                self.request_start_sprint();
+            }
+            AppEvent::Resolve if self.view_mode == ViewMode::Sprints => {
+                self.request_complete_sprint();
+            }
+            AppEvent::Export if self.view_mode == ViewMode::Sprints => {
+                self.export_sprint_changelog();
+            }
+            AppEvent::Export if self.view_mode == ViewMode::Timesheet => {
+                let timesheet = &self.timesheet.timesheet;
+                let what = format!("the timesheet of the week of {}", timesheet.week_start);
+                let file_name = format!("timesheet-{}.csv", timesheet.week_start);
+                self.status_message = Some(copy_or_save(&timesheet.csv(), &file_name, &what));
+            }
+            AppEvent::Resolve
+                if matches!(self.view_mode, ViewMode::Releases | ViewMode::ReleaseIssues) =>
+            {
+                self.request_release_version();
+            }
+            AppEvent::CopyReleaseNotes
+                if matches!(self.view_mode, ViewMode::Releases | ViewMode::ReleaseIssues) =>
+            {
+                self.copy_release_notes();
+            }
+            AppEvent::OpenInBrowser
+                if self.view_mode == ViewMode::Detail || self.view_mode == ViewMode::List =>
+            {
+                self.open_in_browser();
+            }
+            _ => {
+                // Other events handled elsewhere
+            }
+        }
+    }
+
+    /// Apply an edited config file without restarting; an invalid file is
+    /// reported and the current settings are kept
+    fn reload_config(&mut self) {
+        let config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                log::warn!("reload_config: {}", e);
+                self.status_message = Some(format!("Config not reloaded: {}", e));
+                return;
+            }
+        };
+        let problems = config.validate();
+        if !problems.is_empty() {
+            log::warn!("reload_config: {}", problems.join("; "));
+            self.status_message = Some(format!("Config not reloaded: {}", problems.join("; ")));
+            return;
+        }
+
+        self.timezone = DisplayTimezone::from_config(config.ui.timezone.as_deref());
+        if config.ui.theme != self.config.ui.theme {
+            self.renderer
+                .set_theme(Theme::from_config(&config.ui.theme, Theme::no_color_requested()));
+        }
+        self.date_format = config.ui.date_format.clone();
+        // Keep a swimlane mode cycled with 'g' unless the setting itself changed
+        if config.ui.swimlanes != self.config.ui.swimlanes {
+            self.board_state.swimlane_mode = SwimlaneMode::from_config(&config.ui.swimlanes);
+        }
+        if let Ok(mut hooks) = self.hooks.write() {
+            *hooks = config.hooks.clone();
+        }
+
+        let needs_restart = config.jira.audit_log != self.config.jira.audit_log
+            || config.jira.webhook_listen != self.config.jira.webhook_listen;
+        self.status_message = Some(if needs_restart {
+            "Config reloaded; restart to apply audit_log and webhook_listen".to_string()
+        } else {
+            "Config reloaded".to_string()
+        });
+        let query = self.current_query();
+        self.config = config;
+        if self.active_filter.is_some_and(|index| index >= self.config.filters.len()) {
+            self.active_filter = None;
+        }
+        if self.current_query() != query {
+            self.load_tickets();
+        }
+    }
+
+    /// Write a setting changed from the UI to the config file, when the app
+    /// was started from one. Only that key is rewritten, so the user's
+    /// comments and layout stay
+    fn save_preference(&mut self, table: &str, key: &str, value: &str) {
+        if self.config_watcher.is_none() {
+            return;
+        }
+        match Config::save_setting(table, key, value) {
+            // Do not reload the config over our own write
+            Ok(()) => self.config_watcher = Config::config_path().ok().map(ConfigWatcher::new),
+            Err(e) => {
+                log::warn!("save_preference: {}", e);
+                self.status_message = Some(format!("Setting not saved: {}", e));
+            }
+        }
+    }
+
+    fn comment_order(&self) -> CommentOrder {
+        CommentOrder::from_config(&self.config.ui.comment_order)
+    }
+
+    /// List the detail view's comments the other way round, remembering the
+    /// order in the config
+    fn toggle_comment_order(&mut self) {
+        let order = self.comment_order().toggled();
+        self.status_message = Some(format!("Comments {}", order.label()));
+        self.config.ui.comment_order = order.config_value().to_string();
+        self.save_preference("ui", "comment_order", order.config_value());
+        if let Some(key) = self.current_ticket_key.clone() {
+            self.spawn_action(Action::LoadComments { key, order });
+        }
+    }
+
+    /// Show the comments fetched in a new order, unless the order or the
+    /// ticket changed again since
+    fn comments_loaded(
+        &mut self,
+        key: String,
+        order: CommentOrder,
+        comments: crate::utils::Result<Vec<Comment>>,
+    ) {
+        if self.current_ticket_key.as_deref() != Some(key.as_str()) || order != self.comment_order() {
+            log::debug!("comments_loaded: Dropping stale comments of {}", key);
+            return;
+        }
+        match comments {
+            Ok(comments) => {
+                self.detail_comments = comments;
+                self.detail_comments_state = CommentsState::default();
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load comments: {}", e));
+            }
+        }
+    }
+
+    /// Whether a popup, picker or form is open over the view, which
+    /// background refreshes must not pull the tickets from under
+    fn overlay_open(&self) -> bool {
+        self.pending_action.is_some()
+            || self.sprint_picker.is_some()
+            || self.assignee_picker.is_some()
+            || self.move_issue.is_some()
+            || self.label_editor.is_some()
+            || self.query_builder.is_some()
+            || self.view_switcher.is_some()
+            || self.sprint_filter_picker.is_some()
+            || self.bulk_progress.is_some()
+            || self.transition_form.is_some()
+            || self.edit_form.is_some()
+            || self.comment_prompt.is_some()
+            || self.comment_composer.is_some()
+            || self.attachment_list.is_some()
+            || self.link_list.is_some()
+            || self.approval_list.is_some()
+            || self.dependency_graph.is_some()
+            || self.issue_properties.is_some()
+            || self.row_jump.is_some()
+            || self.remote_link_form.is_some()
+            || self.worklog_prompt.is_some()
+            || self.image_preview.is_some()
+    }
+
+    /// Check whether the configured auto-refresh interval has elapsed
+    fn auto_refresh_due(&self) -> bool {
+        self.config.ui.refresh_interval > 0
+            && !self.overlay_open()
+            && matches!(
+                self.view_mode,
+                ViewMode::Dashboard | ViewMode::List | ViewMode::Board | ViewMode::Activity
+            )
+            && self.last_refresh.elapsed() >= Duration::from_secs(self.config.ui.refresh_interval)
+    }
+
+    /// Check whether the configured reminder interval has elapsed
+    fn reminder_due(&self) -> bool {
+        self.config.ui.reminder_interval > 0
+            && self.due_reminders.is_none()
+            && !self.overlay_open()
+            && self.last_reminder.elapsed()
+                >= Duration::from_secs(self.config.ui.reminder_interval * 60)
+    }
+
+    /// Look up my open tickets that are overdue or due soon and show them in a popup
+    fn check_due_reminders(&mut self) {
+        self.last_reminder = Instant::now();
+        let jql = ReminderService::due_soon_jql(self.config.ui.due_soon_days);
+        self.spawn_action(Action::LoadDueTickets(jql));
+    }
+
+    /// Pop up the reminders for the due tickets found, if any
+    fn due_tickets_loaded(&mut self, result: crate::utils::Result<SearchResult>) {
+        match result {
+            Ok(result) => {
+                let window = self.config.ui.due_soon_days;
+                let today = self.timezone.today();
+                let reminders = ReminderService::due_reminders(&result.issues, today, window);
+                if !reminders.is_empty() {
+                    self.due_reminders = Some(reminders);
+                }
+            }
+            Err(e) => {
+                log::warn!("Failed to check due tickets: {}", e);
+            }
+        }
+    }
+
+    /// Reload the tickets of the current query in the background
+    fn load_tickets(&mut self) {
+        let action = self.reload_tickets();
+        self.dispatch(vec![action]);
+    }
+
+    /// Show the tickets found by the search for `jql` started at `started`,
+    /// returning the actions that fetch their projects' permissions and the
+    /// filter bar counts
+    fn tickets_loaded(
+        &mut self,
+        jql: String,
+        started: Instant,
+        result: crate::utils::Result<SearchResult>,
+    ) -> Vec<Action> {
+        let mut actions = Vec::new();
+        match result {
+            Ok(result) => {
+                self.last_sync = Some((jql, started));
+                self.all_tickets = result.issues;
+                self.apply_list_filter();
+                if let Some(key) = self.focus_on_load.take() {
+                    self.ticket_list_state.focus_key(&key);
+                }
+                self.loading_state = LoadingState::Loaded;
+                let projects: Vec<String> =
+                    self.all_tickets.iter().map(|t| t.project_key.clone()).collect();
+                actions.extend(self.permissions_to_load(projects));
+            }
+            Err(e) => {
+                self.loading_state = LoadingState::Error(format!("Failed to load tickets: {}", e));
+            }
+        }
+        actions.extend(self.query_counts_to_load());
+        actions
+    }
+
+    /// Fetch only the issues updated since the last load, to merge them
+    /// into the list. Falls back to a full load when the query changed
+    fn refresh_changed_tickets(&mut self) {
+        let jql = self.current_query();
+        let Some(since) = self
+            .last_sync
+            .as_ref()
+            .filter(|(synced, _)| *synced == jql)
+            .map(|(_, at)| *at)
+        else {
+            let action = self.reload_tickets();
+            self.dispatch(vec![action]);
+            return;
+        };
+        self.last_refresh = Instant::now();
+        // Relative dates avoid depending on the Jira profile's timezone; the
+        // extra minutes cover clock skew and edits made while loading
+        let changed_since = format!("updated >= -{}m", since.elapsed().as_secs() / 60 + 2);
+        let keys = self.all_tickets.iter().map(|t| t.key.clone()).collect();
+        self.dispatch(vec![Action::LoadChanges {
+            jql,
+            changed_since,
+            keys,
+        }]);
+    }
+
+    /// Show the list as loading and return the action reloading it
+    fn reload_tickets(&mut self) -> Action {
+        self.loading_state = LoadingState::Loading;
+        self.last_refresh = Instant::now();
+        Action::Reload(self.current_query())
+    }
+
+    /// Merge the issues changed since the last sync into the list, leaving
+    /// it untouched when nothing changed. The list is reloaded instead when
+    /// a new issue matches, the listed issues could not be searched, or the
+    /// changes may move issues in a way only Jira can sort
+    fn changes_loaded(
+        &mut self,
+        jql: String,
+        started: Instant,
+        result: crate::utils::Result<ListChanges>,
+    ) -> Vec<Action> {
+        let changes = match result {
+            Ok(changes) => changes,
+            Err(e) => {
+                log::warn!("changes_loaded: {}", e);
+                return Vec::new();
+            }
+        };
+        if changes.matching.iter().any(|ticket| !self.all_tickets.iter().any(|t| t.key == ticket.key)) {
+            return vec![self.reload_tickets()];
+        }
+        // A listed issue that was deleted or hidden makes Jira reject the
+        // whole search of the listed keys
+        let touched = match changes.touched {
+            Ok(touched) => touched,
+            Err(e) => {
+                log::warn!("changes_loaded: Reloading, the listed issues failed: {}", e);
+                return vec![self.reload_tickets()];
+            }
+        };
+
+        let mut replaced = false;
+        for ticket in changes.matching.iter() {
+            if let Some(existing) = self.all_tickets.iter_mut().find(|t| t.key == ticket.key) {
+                if existing.updated != ticket.updated {
+                    *existing = ticket.clone();
+                    replaced = true;
+                }
+            }
+        }
+        if replaced {
+            match FilterService::merge_order(&jql) {
+                MergeOrder::InPlace => {}
+                MergeOrder::Updated { descending: true } => {
+                    self.all_tickets.sort_by_key(|ticket| std::cmp::Reverse(ticket.updated));
+                }
+                MergeOrder::Updated { descending: false } => {
+                    self.all_tickets.sort_by_key(|ticket| ticket.updated);
+                }
+                MergeOrder::Reload => return vec![self.reload_tickets()],
+            }
+        }
+        let gone: Vec<&str> = touched
+            .iter()
+            .filter(|ticket| !changes.matching.iter().any(|t| t.key == ticket.key))
+            .map(|ticket| ticket.key.as_str())
+            .collect();
+        let removed = !gone.is_empty();
+        if removed {
+            self.all_tickets.retain(|ticket| !gone.contains(&ticket.key.as_str()));
+        }
+
+        if replaced || removed {
+            self.apply_list_filter();
+        }
+        self.last_sync = Some((jql, started));
+        self.query_counts_to_load().into_iter().collect()
+    }
+
+    /// The action counting the issues of the default query and each quick
+    /// filter for the filter bar; none when the bar is hidden
+    fn query_counts_to_load(&self) -> Option<Action> {
+        if self.config.filters.is_empty() {
+            return None;
+        }
+        let queries = std::iter::once(self.config.jira.default_query.clone())
+            .chain(self.config.filters.iter().map(|filter| filter.jql.clone()))
+            .collect();
+        Some(Action::CountIssues(queries))
+    }
+
+    /// JQL of the loaded Jira search, else of the list's own query
+    fn current_query(&self) -> String {
+        match &self.jira_search {
+            Some(jql) => jql.clone(),
+            None => self.list_query(),
+        }
+    }
+
+    /// JQL from the query builder, view switcher or active quick filter, else
+    /// `default_query`, restricted to the sprint filter
+    fn list_query(&self) -> String {
+        let jql = match &self.custom_query {
+            Some((_, jql)) => jql.clone(),
+            None => self
+                .active_filter
+                .and_then(|index| self.config.filters.get(index))
+                .map(|filter| filter.jql.clone())
+                .unwrap_or_else(|| self.config.jira.default_query.clone()),
+        };
+        SprintService::restrict_to_sprints(&jql, &self.sprint_filter)
+    }
+
+    /// Handle a key while the query builder is open
+    fn handle_query_builder_key(&mut self, key: KeyEvent) {
+        let Some(builder) = self.query_builder.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Char(c) => builder.push(c),
+            KeyCode::Backspace => builder.pop(),
+            KeyCode::Tab | KeyCode::Down => builder.next_field(),
+            KeyCode::BackTab | KeyCode::Up => builder.previous_field(),
+            KeyCode::Esc => self.query_builder = None,
+            KeyCode::Enter => {
+                let jql = builder.to_jql();
+                self.query_builder = None;
+                self.status_message = Some(match &jql {
+                    Some(jql) => format!("Query: {}", jql),
+                    None => "Query cleared".to_string(),
+                });
+                self.custom_query = jql.map(|jql| ("Query".to_string(), jql));
+                self.jira_search = None;
+                self.load_tickets();
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle a key while the view switcher is open
+    fn handle_view_switcher_key(&mut self, key: KeyEvent) {
+        let Some(switcher) = self.view_switcher.as_mut() else {
+            return;
+        };
+
+        match EventHandler::handle_key(key) {
+            AppEvent::MoveUp => switcher.move_up(),
+            AppEvent::MoveDown => switcher.move_down(),
+            AppEvent::ExitDetail => self.view_switcher = None,
+            AppEvent::EnterDetail => {
+                let view = switcher.selected();
+                self.view_switcher = None;
+                self.switch_view(view);
+            }
+            _ => {}
+        }
+    }
+
+    /// Open the sprint filter picker on the board's active and future sprints,
+    /// ticking the sprints currently filtered on, once they loaded
+    fn open_sprint_filter(&mut self) {
+        self.sprint_popup = Some(SprintPopup::Filter);
+        let actions = self.sprints_to_load();
+        self.dispatch(actions);
+    }
+
+    /// Handle a key while the sprint filter picker is open
+    fn handle_sprint_filter_key(&mut self, key: KeyEvent) {
+        let Some(picker) = self.sprint_filter_picker.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => picker.move_up(),
+            KeyCode::Down | KeyCode::Char('j') => picker.move_down(),
+            KeyCode::Char(' ') => picker.toggle_checked(),
+            KeyCode::Esc => self.sprint_filter_picker = None,
+            KeyCode::Backspace => {
+                self.sprint_filter_picker = None;
+                self.sprint_filter.clear();
+                self.status_message = Some("Sprint filter cleared".to_string());
+                self.load_tickets();
+            }
+            KeyCode::Enter => {
+                self.sprint_filter = picker.checked_sprints();
+                self.sprint_filter_picker = None;
+                self.status_message = Some(format!("Sprint: {}", self.sprint_filter_names()));
+                self.load_tickets();
+            }
+            _ => {}
+        }
+    }
+
+    /// Names of the filtered sprints, e.g. "Sprint 4, Sprint 5"
+    fn sprint_filter_names(&self) -> String {
+        self.sprint_filter
+            .iter()
+            .map(|sprint| sprint.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Load a built-in view's tickets, or the default query for None; the
+    /// built-in views wait for the signed-in user
+    fn switch_view(&mut self, view: Option<SmartView>) {
+        match view {
+            Some(view) => self.with_current_user(UserPurpose::SmartView(view)),
+            None => {
+                self.custom_query = None;
+                self.active_filter = None;
+                self.status_message = Some("View: default".to_string());
+                self.jira_search = None;
+                self.load_tickets();
+            }
+        }
+    }
+
+    /// Show a built-in view's tickets for `user`, returning the action
+    /// loading them
+    fn apply_smart_view(&mut self, view: SmartView, user: &User) -> Action {
+        self.custom_query = Some((view.name().to_string(), view.jql(user)));
+        self.status_message = Some(format!("View: {}", view.name()));
+        self.jira_search = None;
+        self.reload_tickets()
+    }
+
+    /// Load a quick filter's tickets; its key again goes back to `default_query`
+    fn select_quick_filter(&mut self, index: usize) {
+        let Some(filter) = self.config.filters.get(index) else {
+            self.status_message = Some(format!("No filter on key {}", index + 1));
+            return;
+        };
+        let had_query = self.custom_query.take().is_some() | self.jira_search.take().is_some();
+        self.status_message = Some(if self.active_filter == Some(index) && !had_query {
+            self.active_filter = None;
+            "Filter: default".to_string()
+        } else {
+            self.active_filter = Some(index);
+            format!("Filter: {}", filter.name)
+        });
+        self.load_tickets();
+    }
+
+    /// Refresh the issues Jira announced changes to since the last loop
+    fn apply_webhook_events(&mut self) {
+        let Some(receiver) = self.webhook_events.as_mut() else {
+            return;
+        };
+        let mut events = Vec::new();
+        while let Ok(event) = receiver.try_recv() {
+            if !events.contains(&event) {
+                events.push(event);
+            }
+        }
+        for event in events {
+            self.apply_webhook_event(event);
+        }
+    }
+
+    /// Refetch just the issue a webhook is about; a deleted one is dropped
+    /// from the list right away
+    fn apply_webhook_event(&mut self, event: WebhookEvent) {
+        let key = event.key().to_string();
+        log::debug!("apply_webhook_event: {:?}", event);
+        if let WebhookEvent::IssueDeleted(_) = event {
+            self.all_tickets.retain(|ticket| ticket.key != key);
+            self.apply_list_filter();
+            return;
+        }
+        let comments = (matches!(event, WebhookEvent::CommentChanged(_))
+            && self.current_ticket_key.as_deref() == Some(key.as_str()))
+        .then(|| self.comment_order());
+        self.spawn_action(Action::FetchIssue { key, comments });
+    }
+
+    /// Show an issue refetched after a webhook, keeping the list focus
+    fn issue_fetched(
+        &mut self,
+        key: String,
+        ticket: crate::utils::Result<Ticket>,
+        comments: Option<crate::utils::Result<Vec<Comment>>>,
+    ) -> Vec<Action> {
+        let ticket = match ticket {
+            Ok(ticket) => ticket,
+            Err(e) => {
+                log::warn!("issue_fetched: Failed to fetch {}: {}", key, e);
+                return Vec::new();
+            }
+        };
+        if self.current_ticket_key.as_deref() == Some(key.as_str()) {
+            self.detail_ticket = Some(ticket.clone());
+            if let Some(Ok(comments)) = comments {
+                self.detail_comments = comments;
+            }
+        }
+        self.backlog_state.update_ticket(ticket.clone());
+        match self.all_tickets.iter_mut().find(|existing| existing.key == key) {
+            Some(existing) => {
+                *existing = ticket.clone();
+                self.ticket_list_state.update_ticket(ticket);
+                Vec::new()
+            }
+            // A new or newly assigned issue may now belong in the list
+            None => vec![Action::Reload(self.current_query())],
+        }
+    }
+
+    /// Start a background action, reporting back through `messages`;
+    /// cancelling the returned token drops its result
+    fn spawn_action(&self, action: Action) -> CancellationToken {
+        log::debug!("spawn_action: {:?}", action);
+        let token = CancellationToken::new();
+        action.spawn(Arc::clone(&self.ticket_service), token.clone(), self.messages.clone());
+        token
+    }
+
+    /// Report how Jira took a write and refresh what it changed. A rejected
+    /// form or comment is reopened so it can be corrected and sent again
+    fn written(&mut self, write: Write, result: crate::utils::Result<()>) -> Vec<Action> {
+        match write {
+            Write::Transition {
+                key,
+                transition,
+                fields,
+                comment,
+            } => {
+                let form = self.sent_transition_form.take();
+                if let Err(e) = result {
+                    self.status_message = Some(format!("{} failed: {}", transition.name, e));
+                    // Reopen the form with the fields Jira complained about
+                    if let (Some(mut form), LazyJiraError::Jira(error)) = (form, &e) {
+                        if form.ticket_key == key
+                            && self.transition_form.is_none()
+                            && form.show_errors(&error.field_errors)
+                        {
+                            self.transition_form = Some(form);
+                        }
+                    }
+                    return Vec::new();
+                }
+                self.status_message = Some(format!("{} → {}", key, transition.to_status));
+                self.detail_transitions = None;
+                self.action_history.record(RepeatableAction::Transition {
+                    transition,
+                    fields,
+                    comment,
+                });
+                if self.view_mode == ViewMode::Transitions {
+                    self.view_mode = ViewMode::Detail;
+                }
+                self.refresh_written(key)
+            }
+            Write::Update { key, .. } => {
+                let form = self.sent_edit_form.take();
+                if let Err(e) = result {
+                    self.status_message = Some(format!("Failed to update {}: {}", key, e));
+                    // Keep the form open at the fields Jira complained about
+                    if let (Some(mut form), LazyJiraError::Jira(error)) = (form, &e) {
+                        if form.ticket_key == key
+                            && self.edit_form.is_none()
+                            && form.show_errors(&error.field_errors)
+                        {
+                            self.edit_form = Some(form);
+                        }
+                    }
+                    return Vec::new();
+                }
+                self.status_message = Some(format!("Updated {}", key));
+                self.refresh_written(key)
+            }
+            Write::SetReporter { key, user } => match result {
+                Ok(()) => {
+                    self.status_message =
+                        Some(format!("Set reporter of {} to {}", key, user.display_name));
+                    self.refresh_written(key)
+                }
+                Err(LazyJiraError::Authentication(_)) => {
+                    self.status_message = Some(
+                        "Changing the reporter needs the Modify Reporter permission".to_string(),
+                    );
+                    Vec::new()
+                }
+                Err(e) => {
+                    self.status_message =
+                        Some(format!("Failed to change the reporter of {}: {}", key, e));
+                    Vec::new()
+                }
+            },
+            Write::Assign { key, user } => {
+                if let Err(e) = result {
+                    self.status_message = Some(format!("Failed to assign {}: {}", key, e));
+                    return Vec::new();
+                }
+                self.status_message = Some(format!("Assigned {} to {}", key, user.display_name));
+                self.action_history.record(RepeatableAction::Assign(user.clone()));
+                self.recent_assignees.record(user);
+                if let Err(e) = self.recent_assignees.save() {
+                    log::warn!("Failed to save recent assignees: {}", e);
+                }
+                self.refresh_written(key)
+            }
+            Write::Comment { key, visibility, .. } => {
+                let composer = self.sent_comment.take();
+                if let Err(e) = result {
+                    self.status_message = Some(format!("Comment failed: {}", e));
+                    // Keep the draft so it can be retried
+                    if self.comment_composer.is_none() {
+                        self.comment_composer = composer;
+                    }
+                    return Vec::new();
+                }
+                let restricted = composer.filter(|composer| !visibility.is_public() || composer.service_desk);
+                self.status_message = Some(match restricted {
+                    Some(composer) => format!("Commented on {} ({})", key, composer.visibility_label()),
+                    None => format!("Commented on {}", key),
+                });
+                vec![Action::FetchIssue {
+                    key,
+                    comments: Some(self.comment_order()),
+                }]
+            }
+            Write::Label { key, change } => {
+                let Some(progress) = self.bulk_progress.as_mut() else {
+                    return Vec::new();
+                };
+                progress.record(&key, result);
+                if !progress.is_finished() {
+                    return Vec::new();
+                }
+                let Some(progress) = self.bulk_progress.take() else {
+                    return Vec::new();
+                };
+                self.status_message = Some(progress.summary());
+                if progress.succeeded() > 0 {
+                    self.action_history.record(RepeatableAction::Label(change));
+                }
+                if progress.failures.is_empty() {
+                    self.ticket_list_state.selected_keys.clear();
+                    self.backlog_state.selected_keys.clear();
+                } else {
+                    // Keep the report open so the per-ticket errors can be read
+                    self.bulk_progress = Some(progress);
+                }
+                Vec::new()
+            }
+            Write::Rank { key, position } => {
+                // The backlog showed the new order right away; put it back
+                if let Err(e) = result {
+                    self.status_message = Some(format!("Failed to rank {}: {}", key, e));
+                    let (RankPosition::Before(neighbour) | RankPosition::After(neighbour)) = &position;
+                    self.backlog_state.swap_tickets(&key, neighbour);
+                }
+                Vec::new()
+            }
+            Write::MoveToSprint { sprint, keys } => {
+                match result {
+                    Ok(()) => {
+                        self.status_message =
+                            Some(format!("Moved {} issue(s) to {}", keys.len(), sprint.name));
+                        self.action_history.record(RepeatableAction::MoveToSprint(sprint));
+                        self.ticket_list_state.selected_keys.clear();
+                        // Moved issues leave the backlog
+                        let backlog = self
+                            .backlog_state
+                            .tickets
+                            .iter()
+                            .filter(|ticket| !keys.contains(&ticket.key))
+                            .cloned()
+                            .collect();
+                        self.backlog_state.set_tickets(backlog);
+                    }
+                    Err(e) => self.status_message = Some(format!("Failed to move issues: {}", e)),
+                }
+                Vec::new()
+            }
+            Write::Worklog { key, seconds, .. } => {
+                let prompt = self.sent_worklog.take();
+                match result {
+                    Ok(()) => {
+                        self.status_message =
+                            Some(format!("Logged {} on {}", format_duration(seconds), key));
+                    }
+                    Err(e) => {
+                        self.status_message = Some(format!("Failed to log work: {}", e));
+                        // Keep the time so it can be logged again
+                        if self.worklog_prompt.is_none() {
+                            self.worklog_prompt = prompt;
+                        }
+                    }
+                }
+                Vec::new()
+            }
+            Write::StartSprint { sprint, .. } => {
+                self.status_message = Some(match result {
+                    Ok(()) => format!("Started {}", sprint.name),
+                    Err(e) => format!("Start sprint failed: {}", e),
+                });
+                self.sprints_to_load()
+            }
+            Write::CompleteSprint(plan) => {
+                self.status_message = Some(match result {
+                    Ok(()) => format!("Completed {}", plan.sprint.name),
+                    Err(e) => format!("Complete sprint failed: {}", e),
+                });
+                self.sprints_to_load()
+            }
+            Write::ReleaseVersion { version, .. } => {
+                self.status_message = Some(match result {
+                    Ok(()) => format!("Released {}", version.name),
+                    Err(e) => format!("Release version failed: {}", e),
+                });
+                self.releases_loading = true;
+                vec![Action::LoadVersions(self.release_list.project_key.clone())]
+            }
+            Write::RemoteLink { key, title, .. } => {
+                if let Err(e) = result {
+                    self.status_message = Some(format!("Failed to add web link: {}", e));
+                    return Vec::new();
+                }
+                self.status_message = Some(format!("Added web link '{}'", title));
+                vec![Action::LoadRemoteLinks(key)]
+            }
+            Write::AnswerApproval {
+                key,
+                approval,
+                approve,
+            } => {
+                if let Err(e) = result {
+                    self.status_message = Some(format!("Failed to answer '{}': {}", approval.name, e));
+                    return Vec::new();
+                }
+                let decision = if approve { "Approved" } else { "Declined" };
+                self.status_message = Some(format!("{} '{}' on {}", decision, approval.name, key));
+                // Answering often moves the request on in its workflow
+                vec![
+                    Action::LoadApprovals(key.clone()),
+                    Action::FetchIssue {
+                        key,
+                        comments: None,
+                    },
+                ]
+            }
+        }
+    }
+
+    /// Refetch the ticket a write changed when it is shown on its own or in
+    /// the backlog, and reload the list it may have joined or left
+    fn refresh_written(&self, key: String) -> Vec<Action> {
+        let mut actions = vec![Action::Reload(self.current_query())];
+        if self.current_ticket_key.as_ref() == Some(&key) || self.view_mode == ViewMode::Backlog {
+            actions.push(Action::FetchIssue {
+                key,
+                comments: None,
+            });
+        }
+        actions
+    }
+
+    /// Follow a ticket moved to another project to its new key
+    fn issue_moved(&mut self, key: String, moved: crate::utils::Result<Ticket>) -> Vec<Action> {
+        let moved = match moved {
+            Ok(moved) => moved,
+            Err(e) => {
+                self.status_message = Some(format!("Move issue failed: {}", e));
+                return Vec::new();
+            }
+        };
+        self.status_message = Some(format!("Moved {} to {}", key, moved.key));
+        if self.view_mode == ViewMode::Detail && self.current_ticket_key.as_deref() == Some(key.as_str()) {
+            self.cancel_detail_fetch();
+            self.detail_loading = false;
+            self.current_ticket_key = Some(moved.key.clone());
+            self.detail_ticket = Some(moved);
+        }
+        vec![Action::Reload(self.current_query())]
+    }
+
+    /// Start the actions `update` asked for
+    fn dispatch(&self, actions: Vec<Action>) {
+        for action in actions {
+            self.spawn_action(action);
+        }
+    }
+
+    /// Run a search once typing paused, pick up webhook events and apply the
+    /// messages background actions sent since the last loop
+    pub fn poll(&mut self) {
+        if self.search_debounce.ready() {
+            self.run_search();
+        }
+        self.apply_webhook_events();
+        while let Ok(message) = self.inbox.try_recv() {
+            let actions = self.update(message);
+            self.dispatch(actions);
+        }
+    }
+
+    /// Apply the result of a background action to the state, returning the
+    /// actions it leads to. Results for a ticket or search the user has
+    /// since left are dropped
+    pub fn update(&mut self, message: Message) -> Vec<Action> {
+        match message {
+            Message::DetailLoaded {
+                key,
+                details,
+                comments,
+                remote_links,
+            } => {
+                let current = self.detail_task.as_ref().is_some_and(|(task, _)| *task == key)
+                    && self.current_ticket_key.as_deref() == Some(key.as_str());
+                if !current && self.detail_prefetch.as_ref().is_some_and(|(task, _)| *task == key) {
+                    log::debug!("update: Keeping prefetched details of {}", key);
+                    self.detail_prefetch = None;
+                    self.prefetched_detail = Some(Message::DetailLoaded {
+                        key,
+                        details,
+                        comments,
+                        remote_links,
+                    });
+                    return Vec::new();
+                }
+                if !current {
+                    log::debug!("update: Dropping stale details of {}", key);
+                    return Vec::new();
+                }
+                self.detail_task = None;
+                self.show_detail(&key, details, comments, remote_links);
+                let mut actions = Vec::new();
+                let keys: Vec<String> = self
+                    .referenced_keys()
+                    .into_iter()
+                    .filter(|key| {
+                        !self.references.contains_key(key) && !self.unknown_references.contains(key)
+                    })
+                    .collect();
+                if !keys.is_empty() {
+                    actions.push(Action::LoadReferences(keys));
+                }
+                let pages = self.untitled_pages();
+                if !pages.is_empty() {
+                    actions.push(Action::LoadPageTitles(pages));
+                }
+                if let Some(ticket) = &self.detail_ticket {
+                    if ticket.service_request.is_some() {
+                        actions.push(Action::LoadSlas(key.clone()));
+                        actions.push(Action::LoadApprovals(key.clone()));
+                    }
+                    actions.push(Action::LoadDevInfo {
+                        key,
+                        issue_id: ticket.id.clone(),
+                    });
+                }
+                actions
+            }
+            Message::SearchLoaded { jql, result } => {
+                let current = self.search_task.as_ref().is_some_and(|(task, _)| *task == jql);
+                if !current {
+                    log::debug!("update: Dropping stale search {}", jql);
+                    return Vec::new();
+                }
+                self.search_task = None;
+                match result {
+                    Ok(result) => {
+                        self.jira_search = Some(jql);
+                        self.last_sync = None;
+                        self.all_tickets = result.issues;
+                        self.apply_list_filter();
+                        self.loading_state = LoadingState::Loaded;
+                    }
+                    Err(e) => self.status_message = Some(format!("Search failed: {}", e)),
+                }
+                Vec::new()
+            }
+            Message::IssueFetched {
+                key,
+                ticket,
+                comments,
+            } => self.issue_fetched(key, ticket, comments),
+            Message::DevInfoLoaded { key, info } => {
+                if self.current_ticket_key.as_deref() != Some(key.as_str()) {
+                    return Vec::new();
+                }
+                match info {
+                    Ok(info) => self.detail_dev_info = info,
+                    // Instances without connected tools or the permission
+                    // to view them just have no development info
+                    Err(e) => log::debug!("update: No development info for {}: {}", key, e),
+                }
+                Vec::new()
+            }
+            Message::RemoteLinksLoaded { key, links } => {
+                if self.current_ticket_key.as_deref() != Some(key.as_str()) {
+                    return Vec::new();
+                }
+                match links {
+                    Ok(links) => {
+                        self.detail_remote_links = links;
+                        self.apply_page_titles();
+                        let pages = self.untitled_pages();
+                        if !pages.is_empty() {
+                            return vec![Action::LoadPageTitles(pages)];
+                        }
+                    }
+                    Err(e) => log::warn!("update: Failed to reload web links of {}: {}", key, e),
+                }
+                Vec::new()
+            }
+            Message::SlasLoaded { key, slas } => {
+                if self.current_ticket_key.as_deref() != Some(key.as_str()) {
+                    return Vec::new();
+                }
+                match slas {
+                    Ok(slas) => self.detail_slas = slas,
+                    Err(e) => log::warn!("update: Failed to load SLAs for {}: {}", key, e),
+                }
+                Vec::new()
+            }
+            Message::ApprovalsLoaded { key, approvals } => {
+                if self.current_ticket_key.as_deref() != Some(key.as_str()) {
+                    return Vec::new();
+                }
+                match approvals {
+                    Ok(approvals) => {
+                        let waiting = approvals.iter().filter(|a| a.is_answerable()).count();
+                        if waiting > 0 {
+                            self.status_message =
+                                Some(format!("{} approval(s) waiting on you — [y] to answer", waiting));
+                        }
+                        self.detail_approvals = approvals;
+                    }
+                    Err(e) => log::warn!("update: Failed to load approvals for {}: {}", key, e),
+                }
+                Vec::new()
+            }
+            Message::PageTitlesLoaded(titles) => {
+                for (url, title) in titles {
+                    let title = title.unwrap_or_else(|e| {
+                        log::debug!("update: No title for {}: {}", url, e);
+                        None
+                    });
+                    self.page_titles.insert(url, title);
+                }
+                self.apply_page_titles();
+                Vec::new()
+            }
+            Message::PermissionsLoaded(permissions) => {
+                for (project, granted) in permissions {
+                    match granted {
+                        Ok(granted) => {
+                            self.permissions.insert(project, Some(granted));
+                        }
+                        Err(e) => log::warn!("update: No permissions for {}: {}", project, e),
+                    }
+                }
+                Vec::new()
+            }
+            Message::TicketsLoaded {
+                jql,
+                started,
+                result,
+            } => {
+                if jql != self.current_query() {
+                    log::debug!("update: Dropping stale reload of {}", jql);
+                    return Vec::new();
+                }
+                self.tickets_loaded(jql, started, result)
+            }
+            Message::ChangesLoaded {
+                jql,
+                started,
+                result,
+            } => {
+                if jql != self.current_query() {
+                    log::debug!("update: Dropping stale changes to {}", jql);
+                    return Vec::new();
+                }
+                self.changes_loaded(jql, started, result)
+            }
+            Message::SprintsLoaded {
+                sprints,
+                active_issues,
+            } => {
+                self.sprints_loaded(sprints, active_issues);
+                Vec::new()
+            }
+            Message::VersionsLoaded {
+                project_key,
+                versions,
+            } => {
+                self.versions_loaded(project_key, versions);
+                Vec::new()
+            }
+            Message::CommentsLoaded {
+                key,
+                order,
+                comments,
+            } => {
+                self.comments_loaded(key, order, comments);
+                Vec::new()
+            }
+            Message::DueTicketsLoaded(result) => {
+                self.due_tickets_loaded(result);
+                Vec::new()
+            }
+            Message::MyselfLoaded { user, purpose } => self.myself_loaded(user, purpose),
+            Message::TransitionsLoaded {
+                key,
+                purpose,
+                transitions,
+            } => {
+                self.transitions_loaded(key, purpose, transitions);
+                Vec::new()
+            }
+            Message::EditFieldsLoaded { key, fields } => {
+                self.edit_fields_loaded(key, fields);
+                Vec::new()
+            }
+            Message::AssignableUsersFound { key, query, users } => {
+                self.assignable_users_found(key, query, users);
+                Vec::new()
+            }
+            Message::CommentVisibilitiesLoaded {
+                key,
+                project_key,
+                roles,
+                groups,
+            } => {
+                self.comment_visibilities_loaded(key, project_key, roles, groups);
+                Vec::new()
+            }
+            Message::BoardsLoaded { boards, purpose } => self.boards_loaded(boards, purpose),
+            Message::BoardColumnsLoaded(columns) => {
+                self.board_columns_loaded(columns);
+                Vec::new()
+            }
+            Message::BacklogLoaded(tickets) => {
+                self.backlog_loaded(tickets);
+                Vec::new()
+            }
+            Message::VelocityLoaded(sprints) => {
+                self.velocity_loaded(sprints);
+                Vec::new()
+            }
+            Message::SprintIssuesLoaded {
+                sprint,
+                purpose,
+                issues,
+            } => {
+                self.sprint_issues_loaded(sprint, purpose, issues);
+                Vec::new()
+            }
+            Message::TimelineLoaded { epics, versions } => {
+                self.timeline_loaded(epics, versions);
+                Vec::new()
+            }
+            Message::EpicChildrenLoaded { epic_key, children } => {
+                self.epic_children_loaded(epic_key, children);
+                Vec::new()
+            }
+            Message::VersionIssuesLoaded {
+                version,
+                purpose,
+                issues,
+            } => {
+                self.version_issues_loaded(version, purpose, issues);
+                Vec::new()
+            }
+            Message::TimesheetLoaded(timesheet) => {
+                self.timesheet_loaded(timesheet);
+                Vec::new()
+            }
+            Message::ActivityLoaded { jql, activities } => {
+                self.activity_loaded(jql, activities);
+                Vec::new()
+            }
+            Message::InstancesSearched { instances, result } => {
+                self.instances_searched(instances, result);
+                Vec::new()
+            }
+            Message::ProjectsLoaded { key, projects } => {
+                self.projects_loaded(key, projects);
+                Vec::new()
+            }
+            Message::IssuePropertiesLoaded { key, properties } => {
+                self.issue_properties_loaded(key, properties);
+                Vec::new()
+            }
+            Message::DependenciesLoaded(graph) => {
+                if self.current_ticket_key.as_deref() == Some(graph.root.as_str()) {
+                    self.dependency_graph = Some(DependencyGraphState::new(graph));
+                }
+                Vec::new()
+            }
+            Message::AttachmentDownloaded { attachment, bytes } => {
+                self.attachment_downloaded(attachment, bytes);
+                Vec::new()
+            }
+            Message::IssuesCounted(counts) => {
+                self.query_counts = counts
+                    .into_iter()
+                    .filter_map(|(jql, count)| match count {
+                        Ok(count) => Some((jql, count)),
+                        Err(e) => {
+                            log::warn!("Failed to count issues for '{}': {}", jql, e);
+                            None
+                        }
+                    })
+                    .collect();
+                Vec::new()
+            }
+            Message::Written { write, result } => self.written(write, result),
+            Message::IssueMoved { key, moved } => self.issue_moved(key, moved),
+            Message::ReferencesLoaded(tickets) => {
+                for (key, ticket) in tickets {
+                    match ticket {
+                        Ok(ticket) => {
+                            let summary = format!("{}: {}", ticket.status.name, ticket.summary);
+                            let summary = truncate_to_width(&summary, REFERENCE_WIDTH);
+                            self.references.insert(key, summary);
+                        }
+                        Err(e) => {
+                            log::debug!("update: No issue {} to reference: {}", key, e);
+                            self.unknown_references.insert(key);
+                        }
+                    }
+                }
+                Vec::new()
+            }
+        }
+    }
+
+    /// The action fetching the permissions of the projects not asked about
+    /// yet, if any
+    fn permissions_to_load(&mut self, projects: Vec<String>) -> Option<Action> {
+        let mut missing = Vec::new();
+        for project in projects {
+            if !self.permissions.contains_key(&project) {
+                self.permissions.insert(project.clone(), None);
+                missing.push(project);
+            }
+        }
+        (!missing.is_empty()).then_some(Action::LoadPermissions(missing))
+    }
+
+    /// Project of the detail ticket or the focused one
+    fn focused_project(&self) -> Option<String> {
+        let key = self.focused_key()?;
+        key.split_once('-').map(|(project, _)| project.to_string())
+    }
+
+    /// Whether the user may do what `permission` covers on the focused
+    /// ticket, telling them when not. Projects whose permissions are not
+    /// known yet let the request through
+    fn permitted(&mut self, permission: Permission) -> bool {
+        let Some(project) = self.focused_project() else {
+            return true;
+        };
+        let denied = self
+            .permissions
+            .get(&project)
+            .and_then(|granted| granted.as_ref())
+            .is_some_and(|granted| !granted.allows(permission));
+        if denied {
+            self.status_message = Some(format!(
+                "You don't have permission to {} in {}",
+                permission.label(),
+                project
+            ));
+        }
+        !denied
+    }
+
+    /// Help bar keys of the actions the user cannot perform on the focused
+    /// ticket
+    fn denied_hints(&self) -> Vec<&'static str> {
+        let Some(granted) = self
+            .focused_project()
+            .and_then(|project| self.permissions.get(&project).cloned().flatten())
+        else {
+            return Vec::new();
+        };
+        granted
+            .denied()
+            .flat_map(|permission| match permission {
+                Permission::AssignIssues => &["a"][..],
+                Permission::TransitionIssues => &["s", "R", "t"][..],
+                _ => &[][..],
+            })
+            .copied()
+            .collect()
+    }
+
+    /// URLs of the Confluence pages linked to the ticket in the detail view
+    /// whose titles were not fetched yet
+    fn untitled_pages(&self) -> Vec<String> {
+        self.detail_remote_links
+            .iter()
+            .filter(|link| link.is_confluence() && !self.page_titles.contains_key(&link.url))
+            .map(|link| link.url.clone())
+            .collect()
+    }
+
+    /// Show the fetched titles of Confluence pages in place of the generic
+    /// ones Jira gives their links
+    fn apply_page_titles(&mut self) {
+        for link in &mut self.detail_remote_links {
+            if let Some(Some(title)) = self.page_titles.get(&link.url) {
+                link.title = title.clone();
+            }
+        }
+    }
+
+    /// Issue keys mentioned by the ticket in the detail view: the focused
+    /// comment's first, then the description's and the other comments'
+    fn referenced_keys(&self) -> Vec<String> {
+        let own = self.current_ticket_key.as_deref();
+        let focused = self.detail_comments.get(self.detail_comments_state.focused_index);
+        let description = self.detail_ticket.as_ref().and_then(|ticket| ticket.description.as_deref());
+        let mut keys: Vec<String> = Vec::new();
+        let texts = focused
+            .map(|comment| comment.body.as_str())
+            .into_iter()
+            .chain(description)
+            .chain(self.detail_comments.iter().map(|comment| comment.body.as_str()));
+        for text in texts {
+            for key in links::issue_keys(text) {
+                if Some(key.as_str()) != own && !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+        keys
+    }
+
+    /// Open the first issue the focused comment mentions, or else the first
+    /// one the description mentions
+    fn open_reference(&mut self) {
+        let key = self
+            .referenced_keys()
+            .into_iter()
+            .find(|key| self.references.contains_key(key));
+        match key {
+            Some(key) => self.open_detail(key),
+            None => self.status_message = Some("No referenced issues".to_string()),
+        }
+    }
+
+    /// Show the loaded tickets in the list, narrowed by the drill-down filter
+    fn apply_list_filter(&mut self) {
+        let mut tickets = match &self.list_filter {
+            Some(filter) => {
+                FilterService::apply(&self.all_tickets, filter, self.timezone.today())
+            }
+            None => self.all_tickets.clone(),
+        };
+        if !self.filter_sidebar.selected.is_empty() {
+            tickets = FilterService::apply_facets(&tickets, &self.filter_sidebar.selected);
+        }
+        if !self.search.jira && !self.search.query.trim().is_empty() {
+            tickets = FilterService::apply_text(&tickets, &self.search.query);
+        }
+        if let Some(user) = &self.current_user {
+            tickets = FilterService::apply_assignee_scope(&tickets, self.assignee_scope, &user.account_id);
+        }
+        self.ticket_list_state.set_tickets(tickets);
+    }
+
+    /// Go on with `purpose` once the signed-in user is known; it is
+    /// fetched once and then cached
+    fn with_current_user(&mut self, purpose: UserPurpose) {
+        match self.current_user.clone() {
+            Some(user) => {
+                let actions = self.user_known(&user, purpose);
+                self.dispatch(actions);
+            }
+            None => {
+                self.spawn_action(Action::LoadMyself(purpose));
+            }
+        }
+    }
+
+    /// Remember the signed-in user and go on with what it was fetched for
+    fn myself_loaded(&mut self, user: crate::utils::Result<User>, purpose: UserPurpose) -> Vec<Action> {
+        match user {
+            Ok(user) => {
+                self.current_user = Some(user.clone());
+                // A restored assignee scope applies from now on
+                if self.assignee_scope != AssigneeScope::Everyone {
+                    self.apply_list_filter();
+                }
+                self.user_known(&user, purpose)
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load current user: {}", e));
+                if let UserPurpose::Timesheet(_) = purpose {
+                    self.timesheet_loading = false;
+                }
+                Vec::new()
+            }
+        }
+    }
+
+    /// Go on with what the signed-in user was needed for
+    fn user_known(&mut self, user: &User, purpose: UserPurpose) -> Vec<Action> {
+        match purpose {
+            UserPurpose::Remember => Vec::new(),
+            UserPurpose::SmartView(view) => vec![self.apply_smart_view(view, user)],
+            UserPurpose::CycleAssigneeScope => {
+                self.assignee_scope = self.assignee_scope.next();
+                self.status_message = Some(format!("Showing tickets: {}", self.assignee_scope.label()));
+                self.apply_list_filter();
+                Vec::new()
+            }
+            UserPurpose::Timesheet(date) if self.view_mode == ViewMode::Timesheet => {
+                vec![Action::LoadTimesheet {
+                    user: user.clone(),
+                    date,
+                    timezone: self.timezone,
+                }]
+            }
+            UserPurpose::Timesheet(_) => Vec::new(),
+        }
+    }
+
+    /// Account id of the signed-in user, once it has been fetched
+    fn account_id(&self) -> Option<&str> {
+        self.current_user.as_ref().map(|user| user.account_id.as_str())
+    }
+
+    /// Pick up the views as they were left, and save them whenever they
+    /// change from now on
+    pub fn restore_view_state(&mut self, state: ViewState) {
+        self.active_filter = state.list.quick_filter.as_ref().and_then(|name| {
+            self.config.filters.iter().take(9).position(|filter| &filter.name == name)
+        });
+        self.assignee_scope = state.list.assignee_scope;
+        if let Some(swimlanes) = &state.board.swimlanes {
+            self.board_state.swimlane_mode = SwimlaneMode::from_config(swimlanes);
+        }
+        self.raw_description = state.detail.raw_description;
+        self.view_state = Some(state);
+    }
+
+    /// Save how the views are set up when it changed since the last save
+    fn save_view_state(&mut self) {
+        let Some(saved) = &self.view_state else {
+            return;
+        };
+        let swimlanes = self.board_state.swimlane_mode;
+        let state = ViewState {
+            list: ListState {
+                quick_filter: self
+                    .active_filter
+                    .and_then(|index| self.config.filters.get(index))
+                    .map(|filter| filter.name.clone()),
+                assignee_scope: self.assignee_scope,
+            },
+            board: BoardState {
+                // Only a grouping cycled away from the setting overrides it
+                swimlanes: (swimlanes != SwimlaneMode::from_config(&self.config.ui.swimlanes))
+                    .then(|| swimlanes.config_value().to_string()),
+            },
+            detail: DetailState {
+                raw_description: self.raw_description,
+            },
+            session: saved.session.clone(),
+        };
+        if state == *saved {
+            return;
+        }
+        if let Err(e) = state.save() {
+            log::warn!("save_view_state: {}", e);
+        }
+        self.view_state = Some(state);
+    }
+
+    /// Show my tickets, then unassigned ones, then everyone's again
+    fn cycle_assignee_scope(&mut self) {
+        self.with_current_user(UserPurpose::CycleAssigneeScope);
+    }
+
+    /// Handle a key while the search bar is open; searching waits for typing
+    /// to pause
+    fn handle_search_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(c) => {
+                self.search.query.push(c);
+                self.search_debounce.input();
+            }
+            KeyCode::Backspace => {
+                self.search.query.pop();
+                self.search_debounce.input();
+            }
+            KeyCode::Tab => {
+                self.search.jira = !self.search.jira;
+                if !self.search.jira {
+                    self.clear_jira_search();
+                }
+                self.search_debounce.input();
+            }
+            KeyCode::Enter => {
+                self.search.open = false;
+                self.search_debounce.cancel();
+                self.run_search();
+            }
+            KeyCode::Esc => {
+                self.search = SearchState::new();
+                self.search_debounce.cancel();
+                if !self.clear_jira_search() {
+                    self.apply_list_filter();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Filter the loaded tickets, or start a Jira search in the background,
+    /// cancelling one still running for older input
+    fn run_search(&mut self) {
+        if !self.search.jira {
+            self.apply_list_filter();
+            return;
+        }
+        if let Some((_, token)) = self.search_task.take() {
+            token.cancel();
+        }
+        match self.search.to_jql(&self.list_query()) {
+            Some(jql) => {
+                let token = self.spawn_action(Action::Search(jql.clone()));
+                self.search_task = Some((jql, token));
+            }
+            None => {
+                self.clear_jira_search();
+            }
+        }
+    }
+
+    /// Drop a Jira search and reload the previous query; false when no
+    /// search was loaded
+    fn clear_jira_search(&mut self) -> bool {
+        if let Some((_, token)) = self.search_task.take() {
+            token.cancel();
+        }
+        let searching = self.jira_search.take().is_some();
+        if searching {
+            self.load_tickets();
+        }
+        searching
+    }
+
+    /// Handle a key while the facet sidebar is open; the list keeps its
+    /// facets once the sidebar is collapsed
+    fn handle_filter_sidebar_key(&mut self, key: KeyEvent) {
+        let facets = FilterService::facet_counts(&self.all_tickets);
+        match EventHandler::handle_key(key) {
+            AppEvent::MoveUp => self.filter_sidebar.move_up(),
+            AppEvent::MoveDown => self.filter_sidebar.move_down(facets.len()),
+            AppEvent::ToggleSelection => {
+                if let Some((facet, _)) = facets.get(self.filter_sidebar.focused_index) {
+                    self.filter_sidebar.toggle(facet);
+                    self.apply_list_filter();
+                }
+            }
+            AppEvent::ToggleFilterSidebar | AppEvent::ExitDetail => {
+                self.filter_sidebar.open = false;
+            }
+            AppEvent::Quit => self.running = false,
+            _ => {}
+        }
+    }
+
+    /// Dashboard tiles for the loaded tickets
+    fn dashboard_tiles(&self) -> Vec<crate::domain::services::dashboard_service::DashboardTile> {
+        DashboardService::tiles(&self.all_tickets, self.timezone.today(), self.account_id())
+    }
+
+    /// Open detail view for focused ticket; its details load in the background
+    fn open_detail_view(&mut self) {
+        log::debug!("open_detail_view: Starting");
+        
+        if let Some(ticket) = self.ticket_list_state.focused_ticket() {
+            self.open_detail(ticket.key.clone());
+        } else {
+            log::warn!("open_detail_view: No focused ticket found");
+        }
+    }
+
+    /// Show a ticket in the detail view, remembering where the user came
+    /// from for the back key
+    fn open_detail(&mut self, ticket_key: String) {
+        self.nav_history.leave(self.current_place());
+        self.switch_detail(ticket_key);
+    }
+
+    /// Show a ticket in the detail view, fetching it in the background
+    fn switch_detail(&mut self, ticket_key: String) {
+        log::debug!("switch_detail: Opening ticket {}", ticket_key);
+        self.detail_previous = self.ticket_list_state.mark_viewed(&ticket_key);
+        if let Some(recent) = self.recent_tickets.as_mut() {
+            if recent.record(&ticket_key) {
+                if let Err(e) = recent.save() {
+                    log::warn!("Failed to save recent tickets: {}", e);
+                }
+            }
+        }
+
+        self.view_mode = ViewMode::Detail;
+        self.detail_loading = true;
+        self.detail_ticket = None;
+        self.detail_comments = Vec::new();
+        self.detail_comments_state = CommentsState::default();
+        self.detail_search = None;
+        self.detail_description_scroll = 0;
+        self.detail_time_in_status = Vec::new();
+        self.detail_remote_links = Vec::new();
+        self.detail_dev_info = DevInfo::default();
+        self.detail_slas = Vec::new();
+        self.detail_approvals = Vec::new();
+        self.current_ticket_key = Some(ticket_key.clone());
+        if let Some((project, _)) = ticket_key.split_once('-') {
+            if let Some(action) = self.permissions_to_load(vec![project.to_string()]) {
+                self.spawn_action(action);
+            }
+        }
+
+        log::debug!("switch_detail: Set view mode to Detail, loading state set");
+
+        // Fetch full ticket details, comments and changelog in the background,
+        // unless they were fetched ahead
+        self.cancel_detail_fetch();
+        let prefetched = match self.prefetched_detail.take() {
+            Some(Message::DetailLoaded { key, .. }) if key != ticket_key => None,
+            prefetched => prefetched,
+        };
+        match prefetched {
+            Some(message) => {
+                // Applied through the inbox so it leads to the same follow-up
+                // fetches as a fresh load
+                let _ = self.messages.send(message);
+                self.detail_task = Some((ticket_key, CancellationToken::new()));
+            }
+            None => {
+                let token = self.spawn_action(Action::LoadDetail {
+                    key: ticket_key.clone(),
+                    comment_order: self.comment_order(),
+                });
+                self.detail_task = Some((ticket_key, token));
+            }
+        }
+    }
+
+    /// Step the detail view to the next or previous ticket of the list,
+    /// fetching the one after it ahead
+    fn open_neighbour(&mut self, forward: bool) {
+        let Some(current) = self.current_ticket_key.clone() else {
+            return;
+        };
+        let Some(next) = self.ticket_list_state.neighbour(&current, forward).map(|t| t.key.clone()) else {
+            let end = if forward { "last" } else { "first" };
+            self.status_message = Some(format!("{} is the {} ticket in the list", current, end));
+            return;
+        };
+        self.ticket_list_state.focus_key(&next);
+        self.switch_detail(next.clone());
+
+        if let Some((_, token)) = self.detail_prefetch.take() {
+            token.cancel();
+        }
+        if let Some(ahead) = self.ticket_list_state.neighbour(&next, forward).map(|t| t.key.clone()) {
+            let token = self.spawn_action(Action::LoadDetail {
+                key: ahead.clone(),
+                comment_order: self.comment_order(),
+            });
+            self.detail_prefetch = Some((ahead, token));
+        }
+    }
+
+    /// Leave the detail view for the list, dropping the ticket's details
+    fn close_detail(&mut self) {
+        self.cancel_detail_fetch();
+        if let Some((_, token)) = self.detail_prefetch.take() {
+            token.cancel();
+        }
+        self.prefetched_detail = None;
+        self.view_mode = ViewMode::List;
+        self.detail_search = None;
+        self.detail_description_scroll = 0;
+        self.detail_ticket = None;
+        self.detail_comments = Vec::new();
+        self.detail_time_in_status = Vec::new();
+        self.detail_remote_links = Vec::new();
+        self.detail_dev_info = DevInfo::default();
+        self.detail_slas = Vec::new();
+        self.detail_approvals = Vec::new();
+        self.detail_previous = None;
+        self.transition_list_state = TransitionListState::new();
+        self.current_ticket_key = None;
+    }
+
+    /// Where the user is now, to come back to with the history keys
+    fn current_place(&self) -> Place {
+        let view = match self.view_mode {
+            ViewMode::Transitions => ViewMode::Detail,
+            view => view,
+        };
+        let ticket = match view {
+            ViewMode::Detail => self.current_ticket_key.clone(),
+            _ => self.ticket_list_state.focused_ticket().map(|t| t.key.clone()),
+        };
+        Place {
+            view,
+            list_filter: self.list_filter.clone(),
+            ticket,
+            list_scroll: self.ticket_list_state.scroll_offset.get(),
+            dashboard_tile: self.dashboard_state.focused_tile,
+            comment: self.detail_comments_state.focused_index,
+        }
+    }
+
+    /// Go back to a place from the history, with the focus and scroll it
+    /// was left with
+    fn go_to_place(&mut self, place: Place) {
+        if matches!(self.view_mode, ViewMode::Detail | ViewMode::Transitions) {
+            self.close_detail();
+        }
+        if self.list_filter != place.list_filter {
+            self.list_filter = place.list_filter;
+            self.apply_list_filter();
+        }
+        self.dashboard_state.focused_tile = place.dashboard_tile;
+        if let Some(key) = &place.ticket {
+            self.ticket_list_state.focus_key(key);
+        }
+        self.ticket_list_state.scroll_offset.set(place.list_scroll);
+
+        match (place.view, place.ticket) {
+            (ViewMode::Detail, Some(key)) => {
+                self.switch_detail(key);
+                self.detail_comments_state.focused_index = place.comment;
+            }
+            (ViewMode::Detail, None) => self.view_mode = ViewMode::List,
+            (view, _) => self.view_mode = view,
+        }
+    }
+
+    fn navigate_back(&mut self) {
+        let current = self.current_place();
+        match self.nav_history.back(current) {
+            Some(place) => self.go_to_place(place),
+            None => self.status_message = Some("Nothing to go back to".to_string()),
+        }
+    }
+
+    fn navigate_forward(&mut self) {
+        let current = self.current_place();
+        match self.nav_history.forward(current) {
+            Some(place) => self.go_to_place(place),
+            None => self.status_message = Some("Nothing to go forward to".to_string()),
+        }
+    }
+
+    /// Stop the detail fetch in flight, if any, so its result is never shown
+    fn cancel_detail_fetch(&mut self) {
+        if let Some((key, token)) = self.detail_task.take() {
+            log::debug!("cancel_detail_fetch: Cancelling fetch of {}", key);
+            token.cancel();
+        }
+    }
+
+    /// Fill the detail view from a finished fetch
+    fn show_detail(
+        &mut self,
+        ticket_key: &str,
+        details_result: crate::utils::Result<IssueDetails>,
+        comments_result: crate::utils::Result<Vec<Comment>>,
+        remote_links_result: crate::utils::Result<Vec<RemoteLink>>,
+    ) {
+        let changes = match details_result {
+            Ok(details) => {
+                log::debug!("show_detail: Successfully fetched ticket {}", details.ticket.key);
+                self.detail_transitions = Some((
+                    details.ticket.key.clone(),
+                    details.ticket.status.id.clone(),
+                    details.transitions,
+                ));
+                self.detail_ticket = Some(details.ticket);
+                Some(details.changes)
+            }
+            Err(e) => {
+                log::error!("show_detail: Failed to fetch ticket {}: {}", ticket_key, e);
+                // On error, use the ticket from the list (may be incomplete)
+                self.detail_ticket = self
+                    .all_tickets
+                    .iter()
+                    .find(|ticket| ticket.key == ticket_key)
+                    .cloned();
+                None
+            }
+        };
+
+        match comments_result {
+            Ok(comments) => {
+                log::debug!("show_detail: Successfully fetched {} comments", comments.len());
+                self.detail_comments = comments;
+            }
+            Err(e) => {
+                // On error, leave comments empty and log the error
+                log::warn!("show_detail: Failed to load comments for {}: {}", ticket_key, e);
+                self.detail_comments = Vec::new();
+            }
+        }
+
+        self.detail_time_in_status = match (&self.detail_ticket, changes) {
+            (Some(ticket), Some(changes)) => {
+                MetricsService::time_in_status(ticket, &changes, chrono::Utc::now())
+            }
+            // Without the changelog the time in status is unknown
+            _ => Vec::new(),
+        };
+
+        self.detail_remote_links = remote_links_result.unwrap_or_else(|e| {
+            log::warn!("show_detail: Failed to load web links for {}: {}", ticket_key, e);
+            Vec::new()
+        });
+        self.apply_page_titles();
+        let pages = self.detail_remote_links.iter().filter(|link| link.is_confluence()).count();
+        if pages > 0 {
+            self.status_message = Some(format!("{} Confluence page(s) linked — [w] to open", pages));
+        }
+
+        self.detail_loading = false;
+        log::debug!("show_detail: Completed, loading state cleared");
+    }
+
+    /// Assign ticket to current user
+    fn assign_to_me(&mut self) {
+        if let Some(ticket_key) = self.current_ticket_key.clone() {
+            // Get current user from config or API
+            // For now, use a placeholder - in real implementation, get from config
+            let _assignee = "currentUser()"; // This would need to be the actual account ID
+            
+            // Update ticket assignee
+            // Note: This requires update_issue to be implemented
+            // For now, just refresh the ticket
+            self.spawn_action(Action::FetchIssue {
+                key: ticket_key,
+                comments: None,
+            });
+        }
+    }
+
+    /// Go on with `purpose` once the transitions of a ticket are known:
+    /// those fetched with the detail ticket while it keeps its status, or
+    /// else fetched in the background
+    fn with_transitions(&mut self, ticket_key: String, purpose: TransitionPurpose) {
+        let status_id = self
+            .detail_ticket
+            .as_ref()
+            .filter(|ticket| ticket.key == ticket_key)
+            .map(|ticket| ticket.status.id.as_str());
+        if let Some((key, status, transitions)) = &self.detail_transitions {
+            if *key == ticket_key && Some(status.as_str()) == status_id {
+                let transitions = transitions.clone();
+                self.transitions_known(&ticket_key, purpose, transitions);
+                return;
+            }
+        }
+        self.spawn_action(Action::LoadTransitions {
+            key: ticket_key,
+            purpose,
+        });
+    }
+
+    /// Go on with the transitions fetched for `purpose`, unless the user
+    /// left the ticket since
+    fn transitions_loaded(
+        &mut self,
+        key: String,
+        purpose: TransitionPurpose,
+        transitions: crate::utils::Result<Vec<Transition>>,
+    ) {
+        let current = match purpose {
+            TransitionPurpose::Repeat { .. } => true,
+            TransitionPurpose::List => {
+                self.view_mode == ViewMode::Transitions
+                    && self.current_ticket_key.as_deref() == Some(key.as_str())
+            }
+            _ => self.current_ticket_key.as_deref() == Some(key.as_str()),
+        };
+        if !current {
+            log::debug!("transitions_loaded: Dropping transitions of {}", key);
+            return;
+        }
+        match transitions {
+            Ok(transitions) => self.transitions_known(&key, purpose, transitions),
+            Err(e) => {
+                self.transitions_loading = false;
+                self.status_message = Some(format!("Failed to load transitions: {}", e));
+            }
+        }
+    }
+
+    /// Do what the transitions of a ticket were wanted for
+    fn transitions_known(&mut self, ticket_key: &str, purpose: TransitionPurpose, transitions: Vec<Transition>) {
+        match purpose {
+            TransitionPurpose::List => {
+                self.transition_list_state.set_transitions(transitions);
+                self.transitions_loading = false;
+            }
+            TransitionPurpose::StartProgress => {
+                // Find "Start Progress" or "In Progress"
+                if let Some(transition) = transitions.iter().find(|t| {
+                    t.name.to_lowercase().contains("start") ||
+                    t.to_status.to_lowercase().contains("progress")
+                }) {
+                    self.execute_transition(ticket_key, transition, None, None);
+                }
+            }
+            TransitionPurpose::Resolve => {
+                // Find "Resolve" or "Done"
+                let transition = transitions.into_iter().find(|t| {
+                    t.name.to_lowercase().contains("resolve")
+                        || t.name.to_lowercase().contains("done")
+                        || t.to_status.to_lowercase().contains("done")
+                });
+                match transition {
+                    Some(transition) => self.begin_transition(transition),
+                    None => {
+                        self.status_message =
+                            Some(format!("No resolving transition available for {}", ticket_key));
+                    }
+                }
+            }
+            TransitionPurpose::Repeat {
+                transition,
+                fields,
+                comment,
+            } => {
+                // Transition ids differ between workflows, so fall back to the name
+                let available = transitions
+                    .into_iter()
+                    .find(|t| t.id == transition.id || t.name == transition.name);
+                match available {
+                    Some(available) => {
+                        self.execute_transition(ticket_key, &available, fields, comment);
+                    }
+                    None => {
+                        self.status_message =
+                            Some(format!("{} is not available for {}", transition.name, ticket_key));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Start progress (transition to In Progress)
+    fn start_progress(&mut self) {
+        if let Some(ticket_key) = self.current_ticket_key.clone() {
+            self.with_transitions(ticket_key, TransitionPurpose::StartProgress);
+        }
+    }
+
+    /// Resolve ticket, asking for a resolution and other screen fields when required
+    fn resolve_ticket(&mut self) {
+        if let Some(ticket_key) = self.current_ticket_key.clone() {
+            self.with_transitions(ticket_key, TransitionPurpose::Resolve);
+        }
+    }
+
+    /// Run a transition on the current ticket, first showing a form when
+    /// its screen has fields (e.g. a resolution)
+    fn begin_transition(&mut self, transition: Transition) {
+        let Some(ticket_key) = self.current_ticket_key.clone() else {
+            return;
+        };
+        if transition.fields.is_empty() {
+            self.finish_transition(ticket_key, transition, None, None);
+        } else {
+            self.transition_form = Some(TransitionFormState::new(ticket_key, transition));
+        }
+    }
+
+    /// Handle a key while the transition form is open
+    fn handle_transition_form_key(&mut self, key: KeyEvent) {
+        let Some(form) = self.transition_form.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Up | KeyCode::BackTab => form.previous_field(),
+            KeyCode::Down | KeyCode::Tab => form.next_field(),
+            KeyCode::Left => form.cycle_option(false),
+            KeyCode::Right => form.cycle_option(true),
+            KeyCode::Char(c) => form.push(c),
+            KeyCode::Backspace => form.pop(),
+            KeyCode::Esc => self.transition_form = None,
+            KeyCode::Enter => {
+                let missing = form.missing_required();
+                if !missing.is_empty() {
+                    self.status_message = Some(format!("Required: {}", missing.join(", ")));
+                    return;
+                }
+                match form.to_request() {
+                    Ok((fields, comment)) => {
+                        if let Some(form) = self.transition_form.take() {
+                            self.finish_transition(
+                                form.ticket_key.clone(),
+                                form.transition.clone(),
+                                fields,
+                                comment,
+                            );
+                            self.sent_transition_form = Some(form);
+                        }
+                    }
+                    Err(message) => self.status_message = Some(message),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Open the form editing the fields Jira lets the user change on the
+    /// detail ticket
+    fn open_edit_form(&mut self) {
+        if let Some(ticket_key) = self.current_ticket_key.clone() {
+            self.spawn_action(Action::LoadEditFields(ticket_key));
+        }
+    }
+
+    /// Open the edit form on the fields fetched, unless the user left the
+    /// ticket since
+    fn edit_fields_loaded(&mut self, ticket_key: String, fields: crate::utils::Result<Vec<TransitionField>>) {
+        if self.current_ticket_key.as_deref() != Some(ticket_key.as_str()) {
+            log::debug!("edit_fields_loaded: Dropping fields of {}", ticket_key);
+            return;
+        }
+        match fields {
+            Ok(fields) if fields.is_empty() => {
+                self.status_message = Some(format!("No fields of {} can be edited here", ticket_key));
+            }
+            Ok(mut fields) => {
+                // Fields with an alias go first, under their alias
+                for field in &mut fields {
+                    if let Some(label) = self.config.field_label(&field.key) {
+                        field.name = label;
+                    }
+                }
+                fields.sort_by_key(|field| self.config.field_label(&field.key).is_none());
+                self.edit_form = Some(EditFormState::new(ticket_key, fields));
+            }
+            Err(e) => self.status_message = Some(format!("Failed to load editable fields: {}", e)),
+        }
+    }
+
+    /// Handle a key while the edit form is open
+    fn handle_edit_form_key(&mut self, key: KeyEvent) {
+        let Some(form) = self.edit_form.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Up | KeyCode::BackTab => form.previous_field(),
+            KeyCode::Down | KeyCode::Tab => form.next_field(),
+            KeyCode::Left | KeyCode::Right => {
+                let Some(query) = form.pending_user_search().map(str::to_string) else {
+                    form.cycle_option(key.code == KeyCode::Right);
+                    return;
+                };
+                let key = form.ticket_key.clone();
+                self.spawn_action(Action::SearchAssignableUsers { key, query });
+            }
+            KeyCode::Char(c) => form.push(c),
+            KeyCode::Backspace => form.pop(),
+            KeyCode::Esc => self.edit_form = None,
+            KeyCode::Enter => match form.to_update() {
+                Ok(None) => {
+                    self.status_message = Some("Nothing to change".to_string());
+                    self.edit_form = None;
+                }
+                Ok(Some(data)) => {
+                    let Some(form) = self.edit_form.take() else {
+                        return;
+                    };
+                    self.spawn_action(Action::Write(Write::Update {
+                        key: form.ticket_key.clone(),
+                        data,
+                    }));
+                    self.sent_edit_form = Some(form);
+                }
+                Err(message) => self.status_message = Some(message),
+            },
+            _ => {}
+        }
+    }
+
+    /// Offer the users found to the edit form or quick-assign popup still
+    /// searching for them
+    fn assignable_users_found(&mut self, key: String, query: String, users: crate::utils::Result<Vec<User>>) {
+        let users = match users {
+            Ok(users) => users,
+            Err(e) => {
+                self.status_message = Some(format!("Failed to search users: {}", e));
+                return;
+            }
+        };
+        if let Some(form) = self
+            .edit_form
+            .as_mut()
+            .filter(|form| form.ticket_key == key && form.pending_user_search() == Some(query.as_str()))
+        {
+            if users.is_empty() {
+                self.status_message = Some(format!("No user matches \"{}\"", query));
+            } else {
+                form.set_user_matches(users);
+            }
+        } else if let Some((picker, _)) = self
+            .assignee_picker
+            .as_mut()
+            .filter(|(picker, ticket_key)| *ticket_key == key && picker.query.trim() == query)
+        {
+            picker.set_results(users);
+        }
+    }
+
+    /// Run a transition once its fields are known, prompting for a comment
+    /// first unless disabled or the screen already asked for one
+    fn finish_transition(
+        &mut self,
+        ticket_key: String,
+        transition: Transition,
+        fields: Option<serde_json::Value>,
+        comment: Option<String>,
+    ) {
+        let mode = CommentMode::from_config(&self.config.ui.transition_comment);
+        let screen_has_comment = transition
+            .fields
+            .iter()
+            .any(|field| field.kind == FieldKind::Comment);
+
+        if mode == CommentMode::Never || screen_has_comment || comment.is_some() {
+            self.execute_transition(&ticket_key, &transition, fields, comment);
+        } else {
+            self.comment_prompt = Some(CommentPromptState::new(
+                ticket_key,
+                transition,
+                fields,
+                mode == CommentMode::Always,
+            ));
+        }
+    }
+
+    /// Handle a key while the comment prompt is open
+    fn handle_comment_prompt_key(&mut self, key: KeyEvent) {
+        let Some(prompt) = self.comment_prompt.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Char(c) => prompt.push(c),
+            KeyCode::Backspace => prompt.pop(),
+            KeyCode::Esc => self.comment_prompt = None,
+            KeyCode::Enter => {
+                if prompt.required && prompt.comment().is_none() {
+                    self.status_message = Some("A comment is required".to_string());
+                    return;
+                }
+                if let Some(prompt) = self.comment_prompt.take() {
+                    let comment = prompt.comment();
+                    self.execute_transition(&prompt.ticket_key, &prompt.transition, prompt.fields, comment);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Send a transition of a ticket to Jira; `written` returns to the
+    /// detail view and refreshes the ticket once it went through
+    fn execute_transition(
+        &self,
+        ticket_key: &str,
+        transition: &Transition,
+        fields: Option<serde_json::Value>,
+        comment: Option<String>,
+    ) {
+        self.spawn_action(Action::Write(Write::Transition {
+            key: ticket_key.to_string(),
+            transition: transition.clone(),
+            fields,
+            comment,
+        }));
+    }
+
+    /// Open the comment composer, offering the project's roles and my groups
+    /// as visibility restrictions
+    fn open_comment_composer(&mut self) {
+        let Some(key) = self.current_ticket_key.clone() else {
+            return;
+        };
+        let project_key = match &self.detail_ticket {
+            Some(ticket) => ticket.project_key.clone(),
+            None => key.split('-').next().unwrap_or_default().to_string(),
+        };
+        self.spawn_action(Action::LoadCommentVisibilities { key, project_key });
+    }
+
+    /// Open the comment composer once its restrictions are known, unless
+    /// the user left the ticket since
+    fn comment_visibilities_loaded(
+        &mut self,
+        ticket_key: String,
+        project_key: String,
+        roles: crate::utils::Result<Vec<String>>,
+        groups: crate::utils::Result<Vec<String>>,
+    ) {
+        if self.current_ticket_key.as_deref() != Some(ticket_key.as_str()) {
+            log::debug!("comment_visibilities_loaded: Dropping restrictions for {}", ticket_key);
+            return;
+        }
+        let service_desk = self
+            .detail_ticket
+            .as_ref()
+            .is_some_and(|ticket| ticket.service_request.is_some());
+        // Restrictions are optional; fall back to the defaults
+        let roles = roles.unwrap_or_else(|e| {
+            log::warn!("comment_visibilities_loaded: Failed to load roles for {}: {}", project_key, e);
+            Vec::new()
+        });
+        let groups = groups.unwrap_or_else(|e| {
+            log::warn!("comment_visibilities_loaded: Failed to load groups: {}", e);
+            Vec::new()
+        });
+
+        self.comment_composer =
+            Some(CommentComposerState::new(ticket_key, service_desk, roles, groups));
+    }
+
+    /// Handle a key while the comment composer is open
+    fn handle_comment_composer_key(&mut self, key: KeyEvent) {
+        let Some(composer) = self.comment_composer.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Tab => composer.cycle_visibility(true),
+            KeyCode::BackTab => composer.cycle_visibility(false),
+            KeyCode::Char(c) => composer.push(c),
+            KeyCode::Backspace => composer.pop(),
+            KeyCode::Esc => self.comment_composer = None,
+            KeyCode::Enter => {
+                let Some(comment) = composer.comment() else {
+                    return;
+                };
+                let Some(composer) = self.comment_composer.take() else {
+                    return;
+                };
+                self.spawn_action(Action::Write(Write::Comment {
+                    key: composer.ticket_key.clone(),
+                    body: comment,
+                    visibility: composer.visibility().clone(),
+                }));
+                self.sent_comment = Some(composer);
+            }
+            _ => {}
+        }
+    }
+
+    /// Show available transitions
+    fn show_transitions(&mut self) {
+        if let Some(ticket_key) = self.current_ticket_key.clone() {
+            self.view_mode = ViewMode::Transitions;
+            self.transitions_loading = true;
+            self.with_transitions(ticket_key, TransitionPurpose::List);
+        }
+    }
+
+    /// Show the sprints of the configured board
+    fn show_sprints(&mut self) {
+        self.view_mode = ViewMode::Sprints;
+        let actions = self.sprints_to_load();
+        self.dispatch(actions);
+    }
+
+    /// The board to act on: the configured one, or else the first board of
+    /// `board_type`, or of any type when it is `None`. Without one the error
+    /// holds the action fetching the boards to go on with `purpose`, or
+    /// nothing when they are known and none fits
+    fn resolve_board(
+        &mut self,
+        board_type: Option<BoardType>,
+        purpose: BoardPurpose,
+    ) -> Result<String, Vec<Action>> {
+        if self.config.jira.board_id.is_none() && self.boards.is_none() {
+            return Err(vec![Action::LoadBoards(purpose)]);
+        }
+        let board = self.known_board(board_type);
+        if board.is_none() {
+            let kind = match board_type {
+                Some(BoardType::Scrum) => "scrum ",
+                Some(BoardType::Kanban) => "kanban ",
+                None => "",
+            };
+            self.status_message = Some(format!(
+                "No {}board found; set jira.board_id in the config",
+                kind
+            ));
+        }
+        board.ok_or_else(Vec::new)
+    }
+
+    /// Remember the boards and go on with what one was needed for
+    fn boards_loaded(&mut self, boards: crate::utils::Result<Vec<Board>>, purpose: BoardPurpose) -> Vec<Action> {
+        match boards {
+            Ok(boards) => self.boards = Some(boards),
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load boards: {}", e));
+                self.sprint_popup = None;
+                return Vec::new();
+            }
+        }
+        match purpose {
+            BoardPurpose::Columns => self.board_columns_to_load(),
+            BoardPurpose::Sprints => self.sprints_to_load(),
+            BoardPurpose::Backlog => self.backlog_to_load(),
+            BoardPurpose::Velocity => self.velocity_to_load(),
+        }
+    }
+
+    /// The configured board, or else the first loaded board of `board_type`
+    fn known_board(&self, board_type: Option<BoardType>) -> Option<String> {
+        if let Some(board_id) = self.config.jira.board_id {
+            return Some(board_id.to_string());
+        }
+        self.boards
+            .iter()
+            .flatten()
+            .find(|board| board_type.is_none_or(|board_type| board.board_type == board_type))
+            .map(|board| board.id.clone())
+    }
+
+    /// The action loading the column mapping of the configured board once,
+    /// so the kanban view shows the team's columns; status categories are
+    /// used otherwise
+    fn board_columns_to_load(&mut self) -> Vec<Action> {
+        if self.board_columns_loaded {
+            return Vec::new();
+        }
+        match self.resolve_board(None, BoardPurpose::Columns) {
+            Ok(board_id) => vec![Action::LoadBoardColumns(board_id)],
+            Err(actions) => actions,
+        }
+    }
+
+    fn board_columns_loaded(&mut self, columns: crate::utils::Result<Vec<BoardColumnConfig>>) {
+        match columns {
+            Ok(columns) => {
+                self.board_state.columns = columns;
+                self.board_columns_loaded = true;
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load board columns: {}", e));
+            }
+        }
+    }
+
+    /// Show the loaded sprints, with the burndown of the active one computed
+    /// from its issues
+    fn sprints_loaded(
+        &mut self,
+        sprints: crate::utils::Result<Vec<Sprint>>,
+        active_issues: Option<crate::utils::Result<Vec<Ticket>>>,
+    ) {
+        self.sprints_loading = false;
+        self.sprint_progress = None;
+        match sprints {
+            Ok(sprints) => self.sprint_list_state.set_sprints(sprints),
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load sprints: {}", e));
+                self.sprint_popup = None;
+                return;
+            }
+        }
+        if let Some(popup) = self.sprint_popup.take() {
+            self.open_sprint_popup(popup);
+        }
+        let Some(active) = self.sprint_list_state.sprints.iter().find(|s| s.is_active()).cloned() else {
+            return;
+        };
+        match active_issues {
+            Some(Ok(issues)) => {
+                self.sprint_progress = SprintService::progress(&active, &issues, chrono::Utc::now())
+                    .map(|progress| (active.name.clone(), progress));
+            }
+            Some(Err(e)) => {
+                log::warn!("sprints_loaded: Failed to load issues of {}: {}", active.name, e);
+            }
+            None => {}
+        }
+    }
+
+    /// The action loading the active and future sprints of the scrum board
+    fn sprints_to_load(&mut self) -> Vec<Action> {
+        match self.resolve_board(Some(BoardType::Scrum), BoardPurpose::Sprints) {
+            Ok(board_id) => {
+                self.sprints_loading = true;
+                vec![Action::LoadSprints(board_id)]
+            }
+            Err(actions) => {
+                if actions.is_empty() {
+                    self.sprint_popup = None;
+                }
+                actions
+            }
+        }
+    }
+
+    /// Ask for confirmation before starting the focused sprint
+    fn request_start_sprint(&mut self) {
+        match self.sprint_list_state.focused_sprint() {
+            Some(sprint) if sprint.is_future() => {
+                self.pending_action = Some(PendingAction::StartSprint(sprint.clone()));
+            }
+            Some(_) => {
+                self.status_message = Some("Only future sprints can be started".to_string());
+            }
+            None => {}
+        }
+    }
+
+    /// Summarize incomplete work and ask for confirmation before completing the focused sprint
+    fn request_complete_sprint(&mut self) {
+        let sprint = match self.sprint_list_state.focused_sprint() {
+            Some(sprint) if sprint.is_active() => sprint.clone(),
+            Some(_) => {
+                self.status_message = Some("Only active sprints can be completed".to_string());
+                return;
+            }
+            None => return,
+        };
+        self.spawn_action(Action::LoadSprintIssues {
+            sprint,
+            purpose: SprintIssuesPurpose::Complete,
+        });
+    }
+
+    /// Go on with what the issues of a sprint were fetched for; completing
+    /// it is only offered while the sprints are still shown
+    fn sprint_issues_loaded(
+        &mut self,
+        sprint: Sprint,
+        purpose: SprintIssuesPurpose,
+        issues: crate::utils::Result<Vec<Ticket>>,
+    ) {
+        let issues = match issues {
+            Ok(issues) => issues,
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load sprint issues: {}", e));
+                return;
+            }
+        };
+        match purpose {
+            SprintIssuesPurpose::Complete if self.view_mode == ViewMode::Sprints => {
+                let plan = SprintService::plan_completion(
+                    &sprint,
+                    &issues,
+                    &self.sprint_list_state.sprints,
+                );
+                self.pending_action = Some(PendingAction::CompleteSprint(plan));
+            }
+            SprintIssuesPurpose::Complete => {}
+            SprintIssuesPurpose::Changelog => {
+                let changelog = SprintService::changelog(&sprint, &issues, ChangelogFormat::Markdown);
+                let what = format!("the changelog of {}", sprint.name);
+                self.status_message = Some(copy_or_save(&changelog, &format!("{}.md", sprint.name), &what));
+            }
+        }
+    }
+
+    /// Show the backlog of the sprint board
+    fn show_backlog(&mut self) {
+        self.view_mode = ViewMode::Backlog;
+        let actions = self.backlog_to_load();
+        self.dispatch(actions);
+    }
+
+    /// The action loading the backlog of the sprint board in rank order
+    fn backlog_to_load(&mut self) -> Vec<Action> {
+        match self.resolve_board(Some(BoardType::Scrum), BoardPurpose::Backlog) {
+            Ok(board_id) => {
+                self.backlog_loading = true;
+                vec![Action::LoadBacklog(board_id)]
+            }
+            Err(actions) => actions,
+        }
+    }
+
+    fn backlog_loaded(&mut self, tickets: crate::utils::Result<Vec<Ticket>>) {
+        match tickets {
+            Ok(tickets) => {
+                self.backlog_state.set_tickets(tickets);
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load backlog: {}", e));
+            }
+        }
+        self.backlog_loading = false;
+    }
+
+    /// The action loading the most recent closed sprints to compute their
+    /// completed work
+    fn velocity_to_load(&mut self) -> Vec<Action> {
+        match self.resolve_board(Some(BoardType::Scrum), BoardPurpose::Velocity) {
+            Ok(board_id) => {
+                self.velocity_loading = true;
+                self.velocity.clear();
+                vec![Action::LoadVelocity(board_id)]
+            }
+            Err(actions) => actions,
+        }
+    }
+
+    fn velocity_loaded(&mut self, sprints: crate::utils::Result<Vec<(Sprint, crate::utils::Result<Vec<Ticket>>)>>) {
+        self.velocity.clear();
+        match sprints {
+            Ok(sprints) => {
+                for (sprint, issues) in sprints {
+                    match issues {
+                        Ok(issues) => self.velocity.push(SprintService::velocity(&sprint, &issues)),
+                        Err(e) => {
+                            self.status_message =
+                                Some(format!("Failed to load issues of {}: {}", sprint.name, e));
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load closed sprints: {}", e));
+            }
+        }
+        self.velocity_loading = false;
+    }
+
+    /// Load the open epics of the projects in view, and their upcoming versions
+    fn load_timeline(&mut self) {
+        let mut projects: Vec<String> = self
+            .all_tickets
+            .iter()
+            .map(|ticket| ticket.project_key.clone())
+            .collect();
+        projects.sort();
+        projects.dedup();
+        let project_clause = if projects.is_empty() {
+            String::new()
+        } else {
+            format!("project in ({}) AND ", projects.join(", "))
+        };
+        let jql = format!(
+            "{}issuetype = Epic AND statusCategory != Done ORDER BY duedate ASC",
+            project_clause
+        );
+
+        self.timeline_loading = true;
+        self.spawn_action(Action::LoadTimeline { jql, projects });
+    }
+
+    /// Show the epics found on the roadmap, with the versions due for
+    /// release
+    fn timeline_loaded(
+        &mut self,
+        epics: crate::utils::Result<SearchResult>,
+        versions: Vec<(String, crate::utils::Result<Vec<Version>>)>,
+    ) {
+        let epics = match epics {
+            Ok(result) => result
+                .issues
+                .into_iter()
+                .filter(|ticket| ticket.issue_type.eq_ignore_ascii_case("epic"))
+                .collect(),
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load epics: {}", e));
+                Vec::new()
+            }
+        };
+        let mut upcoming = Vec::new();
+        for (project, found) in versions {
+            match found {
+                Ok(found) => upcoming.extend(
+                    found
+                        .into_iter()
+                        .filter(|version| !version.released && version.release_date.is_some()),
+                ),
+                Err(e) => {
+                    self.status_message =
+                        Some(format!("Failed to load versions of {}: {}", project, e));
+                }
+            }
+        }
+        upcoming.sort_by_key(|version| version.release_date);
+        self.timeline = TimelineState::new(epics, upcoming);
+        self.timeline_loading = false;
+    }
+
+    /// Show the versions of the focused ticket's project, or of the first
+    /// loaded ticket's
+    fn show_releases(&mut self) {
+        let project_key = self
+            .ticket_list_state
+            .focused_ticket()
+            .or(self.all_tickets.first())
+            .map(|ticket| ticket.project_key.clone());
+        let Some(project_key) = project_key else {
+            self.status_message = Some("No tickets loaded to pick a project from".to_string());
+            return;
+        };
+        self.view_mode = ViewMode::Releases;
+        self.load_releases(project_key);
+    }
+
+    fn load_releases(&mut self, project_key: String) {
+        self.releases_loading = true;
+        self.spawn_action(Action::LoadVersions(project_key));
+    }
+
+    fn versions_loaded(&mut self, project_key: String, versions: crate::utils::Result<Vec<Version>>) {
+        match versions {
+            Ok(versions) => self.release_list.set_versions(project_key, versions),
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load versions of {}: {}", project_key, e));
+            }
+        }
+        self.releases_loading = false;
+    }
+
+    /// Load the issues whose fix version is the focused one
+    fn load_release_issues(&mut self) {
+        let Some(version) = self.release_list.focused_version().cloned() else {
+            return;
+        };
+        self.releases_loading = true;
+        self.spawn_action(Action::LoadVersionIssues {
+            version,
+            purpose: VersionIssuesPurpose::List,
+        });
+    }
+
+    /// Ask for confirmation before releasing the focused version, once its
+    /// unresolved issues are counted
+    fn request_release_version(&mut self) {
+        let version = match self.release_list.focused_version() {
+            Some(version) if !version.released => version.clone(),
+            Some(version) => {
+                self.status_message = Some(format!("{} is already released", version.name));
+                return;
+            }
+            None => return,
+        };
+        self.spawn_action(Action::LoadVersionIssues {
+            version,
+            purpose: VersionIssuesPurpose::Release,
+        });
+    }
+
+    /// Copy the release notes of the focused version, or write them to a
+    /// file in the working directory when there is no clipboard
+    fn copy_release_notes(&mut self) {
+        if let Some(version) = self.release_list.focused_version().cloned() {
+            self.spawn_action(Action::LoadVersionIssues {
+                version,
+                purpose: VersionIssuesPurpose::Notes,
+            });
+        }
+    }
+
+    /// Go on with what the issues of a version were fetched for; releasing
+    /// it is only offered while the versions are still shown
+    fn version_issues_loaded(
+        &mut self,
+        version: Version,
+        purpose: VersionIssuesPurpose,
+        issues: crate::utils::Result<Vec<Ticket>>,
+    ) {
+        if purpose == VersionIssuesPurpose::List {
+            self.releases_loading = false;
+        }
+        let issues = match issues {
+            Ok(issues) => issues,
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load issues of {}: {}", version.name, e));
+                return;
+            }
+        };
+        match purpose {
+            VersionIssuesPurpose::List => self.release_issues.set_tickets(issues),
+            VersionIssuesPurpose::Release if self.view_mode == ViewMode::Releases => {
+                let unresolved = issues.iter().filter(|ticket| !ticket.is_done()).count();
+                self.pending_action = Some(PendingAction::ReleaseVersion { version, unresolved });
+            }
+            VersionIssuesPurpose::Release => {}
+            VersionIssuesPurpose::Notes => {
+                let notes =
+                    ReleaseNotesService::markdown(&format!("Release notes — {}", version.name), &issues);
+                let what = format!("the release notes of {}", version.name);
+                self.status_message =
+                    Some(copy_or_save(&notes, &format!("release-notes-{}.md", version.name), &what));
+            }
+        }
+    }
+
+    /// Load the hours I logged in the week containing `date`
+    fn load_timesheet(&mut self, date: chrono::NaiveDate) {
+        self.timesheet_loading = true;
+        self.with_current_user(UserPurpose::Timesheet(date));
+    }
+
+    fn timesheet_loaded(&mut self, timesheet: crate::utils::Result<Timesheet>) {
+        match timesheet {
+            Ok(timesheet) => self.timesheet.set_timesheet(timesheet),
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load worklogs: {}", e));
+            }
+        }
+        self.timesheet_loading = false;
+    }
+
+    /// Load the activity stream of the configured query; the loading screen
+    /// only shows the first time, so polls do not blank the stream
+    fn load_activity(&mut self) {
+        self.activity_loading = self.activity.activities.is_empty();
+        self.last_refresh = Instant::now();
+        self.spawn_action(Action::LoadActivity(self.config.jira.activity_query.clone()));
+    }
+
+    fn activity_loaded(&mut self, jql: String, activities: crate::utils::Result<Vec<Activity>>) {
+        if jql != self.config.jira.activity_query {
+            log::debug!("activity_loaded: Dropping activity of {}", jql);
+            return;
+        }
+        match activities {
+            Ok(activities) => self.activity.set_activities(activities),
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load activity: {}", e));
+            }
+        }
+        self.activity_loading = false;
+        self.last_refresh = Instant::now();
+    }
+
+    /// Handle a key while typing the query of the search across instances
+    fn handle_cross_search_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(c) => self.cross_search.query.push(c),
+            KeyCode::Backspace => {
+                self.cross_search.query.pop();
+            }
+            KeyCode::Esc if self.cross_search.result.hits.is_empty() => {
+                self.cross_search.editing = false;
+                self.view_mode = ViewMode::List;
+            }
+            KeyCode::Esc => self.cross_search.editing = false,
+            KeyCode::Enter => {
+                if let Some(jql) = CrossSearchService::jql(&self.cross_search.query) {
+                    self.cross_search.editing = false;
+                    self.search_instances(jql);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Run the query on every instance, or on this one when there are no
+    /// tabs
+    fn search_instances(&mut self, jql: String) {
+        let instances = if self.tabs.is_empty() {
+            vec![(self.instance_url.clone(), self.ticket_service.clone())]
+        } else {
+            self.tabs.clone()
+        };
+        self.cross_search_loading = true;
+        self.spawn_action(Action::SearchInstances {
+            jql,
+            instances: Instances(instances),
+        });
+    }
+
+    fn instances_searched(&mut self, instances: usize, result: CrossSearchResult) {
+        self.status_message = Some(format!(
+            "Found {} issues on {} of {} instances",
+            result.hits.len(),
+            instances - result.failures.len(),
+            instances
+        ));
+        self.cross_search.set_result(result);
+        self.cross_search_loading = false;
+    }
+
+    /// Open the focused result, switching to its instance's tab when it is
+    /// on another one
+    fn open_cross_search_hit(&mut self) {
+        let Some(hit) = self.cross_search.focused_hit() else {
+            return;
+        };
+        let key = hit.ticket.key.clone();
+        match self.tabs.iter().position(|(name, _)| *name == hit.instance) {
+            Some(index) if index != self.tab_index => {
+                self.switch_to_tab = Some((index, Some(key)));
+            }
+            _ => self.open_detail(key),
+        }
+    }
+
+    /// Copy the resolved issues of the focused sprint as a Markdown table,
+    /// or write them to a file when there is no clipboard
+    fn export_sprint_changelog(&mut self) {
+        if let Some(sprint) = self.sprint_list_state.focused_sprint().cloned() {
+            self.spawn_action(Action::LoadSprintIssues {
+                sprint,
+                purpose: SprintIssuesPurpose::Changelog,
+            });
+        }
+    }
+
+    /// Expand or collapse the focused epic, loading its children the first time
+    fn toggle_timeline_epic(&mut self) {
+        let Some(epic_key) = self.timeline.selected_epic() else {
+            return;
+        };
+        if self.timeline.children.contains_key(&epic_key) {
+            self.timeline.toggle(&epic_key);
+        } else {
+            self.spawn_action(Action::LoadEpicChildren(epic_key));
+        }
+    }
+
+    /// Expand an epic once its children loaded
+    fn epic_children_loaded(&mut self, epic_key: String, children: crate::utils::Result<SearchResult>) {
+        match children {
+            Ok(result) => {
+                let children = result
+                    .issues
+                    .into_iter()
+                    .filter(|ticket| ticket.parent.as_ref().is_some_and(|parent| parent.key == epic_key))
+                    .collect();
+                self.timeline.children.insert(epic_key.clone(), children);
+                self.timeline.toggle(&epic_key);
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load issues of {}: {}", epic_key, e));
+            }
+        }
+    }
+
+    /// Rank the focused backlog issue one position up or down, moving it
+    /// in the backlog right away
+    fn rank_focused(&mut self, up: bool) {
+        let Some((ticket, neighbour)) = self.backlog_state.rank_neighbour(up) else {
+            return;
+        };
+        let key = ticket.key.clone();
+        let position = if up {
+            RankPosition::Before(neighbour.key.clone())
+        } else {
+            RankPosition::After(neighbour.key.clone())
+        };
+
+        self.backlog_state.swap_focused(up);
+        self.spawn_action(Action::Write(Write::Rank { key, position }));
+    }
+
+    /// Open the sprint picker for the focused/selected tickets once the
+    /// sprints loaded
+    fn open_sprint_picker(&mut self) {
+        let keys = self.target_keys();
+        if keys.is_empty() {
+            return;
+        }
+        self.sprint_popup = Some(SprintPopup::Picker(keys));
+        let actions = self.sprints_to_load();
+        self.dispatch(actions);
+    }
+
+    /// Open the popup that waited for the sprints to load
+    fn open_sprint_popup(&mut self, popup: SprintPopup) {
+        if self.sprint_list_state.sprints.is_empty() {
+            let purpose = match popup {
+                SprintPopup::Filter => "filter by",
+                SprintPopup::Picker(_) => "move to",
+            };
+            self.status_message = Some(format!("No active or future sprints to {}", purpose));
+            return;
+        }
+        match popup {
+            SprintPopup::Filter => {
+                let mut picker = self.sprint_list_state.clone();
+                picker.checked = self.sprint_filter.iter().map(|sprint| sprint.id.clone()).collect();
+                self.sprint_filter_picker = Some(picker);
+            }
+            SprintPopup::Picker(keys) => {
+                self.sprint_picker = Some((self.sprint_list_state.clone(), keys));
+            }
+        }
+    }
+
+    /// Handle a key while the sprint picker is open
+    fn handle_sprint_picker_key(&mut self, key: KeyEvent) {
+        let Some((picker, _)) = self.sprint_picker.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => picker.move_up(),
+            KeyCode::Down | KeyCode::Char('j') => picker.move_down(),
+            KeyCode::Esc => self.sprint_picker = None,
+            KeyCode::Enter => {
+                if let Some((picker, keys)) = self.sprint_picker.take() {
+                    if let Some(sprint) = picker.focused_sprint() {
+                        self.move_to_sprint(sprint, &keys);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Move tickets into a sprint; `written` reports the outcome
+    fn move_to_sprint(&self, sprint: &Sprint, keys: &[String]) {
+        self.spawn_action(Action::Write(Write::MoveToSprint {
+            sprint: sprint.clone(),
+            keys: keys.to_vec(),
+        }));
+    }
+
+    /// Open the quick-assign popup for the focused ticket
+    fn open_assignee_picker(&mut self) {
+        if let Some(key) = self.focused_key() {
+            let picker = AssigneePickerState::new(self.recent_assignees.users());
+            self.assignee_picker = Some((picker, key));
+        }
+    }
+
+    /// Handle a key while the quick-assign popup is open
+    fn handle_assignee_picker_key(&mut self, key: KeyEvent) {
+        let Some((picker, ticket_key)) = self.assignee_picker.as_mut() else {
+            return;
+        };
+
+        if picker.editing {
+            match key.code {
+                KeyCode::Char(c) => picker.query.push(c),
+                KeyCode::Backspace => {
+                    picker.query.pop();
+                }
+                KeyCode::Esc => picker.editing = false,
+                KeyCode::Enter => {
+                    let query = picker.query.trim().to_string();
+                    let key = ticket_key.clone();
+                    self.spawn_action(Action::SearchAssignableUsers { key, query });
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        let chosen = match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                picker.move_up();
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                picker.move_down();
+                None
+            }
+            KeyCode::Char('/') => {
+                picker.editing = true;
+                None
+            }
+            KeyCode::Esc => {
+                self.assignee_picker = None;
+                None
+            }
+            KeyCode::Enter => picker.focused_user().cloned(),
+            KeyCode::Char(c) if c.is_ascii_digit() && !picker.showing_results => {
+                picker.user_for_digit(c).cloned()
+            }
+            _ => None,
+        };
+
+        if let Some(user) = chosen {
+            if let Some((picker, ticket_key)) = self.assignee_picker.take() {
+                if picker.reporter {
+                    self.set_reporter(&ticket_key, user);
+                } else {
+                    self.assign_ticket(&ticket_key, user);
+                }
+            }
+        }
+    }
+
+    /// Change a ticket's reporter, which Jira only allows with the Modify
+    /// Reporter permission
+    fn set_reporter(&self, ticket_key: &str, user: User) {
+        self.spawn_action(Action::Write(Write::SetReporter {
+            key: ticket_key.to_string(),
+            user,
+        }));
+    }
+
+    /// Assign a ticket; `written` remembers the assignee and refreshes the
+    /// current view
+    fn assign_ticket(&self, ticket_key: &str, user: User) {
+        self.spawn_action(Action::Write(Write::Assign {
+            key: ticket_key.to_string(),
+            user,
+        }));
+    }
+
+    /// Key of the detail ticket, or of the focused ticket in the current list
+    fn focused_key(&self) -> Option<String> {
+        match self.view_mode {
+            ViewMode::Detail => self.current_ticket_key.clone(),
+            ViewMode::Backlog => self.backlog_state.focused_ticket().map(|t| t.key.clone()),
+            _ => self.ticket_list_state.focused_ticket().map(|t| t.key.clone()),
+        }
+    }
+
+    /// Ticket shown or focused in the current view, for user commands
+    fn focused_ticket(&self) -> Option<&Ticket> {
+        match self.view_mode {
+            ViewMode::Detail => self.detail_ticket.as_ref(),
+            ViewMode::Backlog => self.backlog_state.focused_ticket(),
+            ViewMode::List => self.ticket_list_state.focused_ticket(),
+            _ => None,
+        }
+    }
+
+    /// Run the user command bound to a key, if any, on the focused ticket
+    async fn run_user_command(&mut self, key: KeyEvent) {
+        let KeyCode::Char(c) = key.code else {
+            return;
+        };
+        if !key.modifiers.difference(KeyModifiers::SHIFT).is_empty() {
+            return;
+        }
+        let Some(command) = self.config.commands.iter().find(|command| command.key == c).cloned()
+        else {
+            return;
+        };
+        let Some(ticket) = self.focused_ticket() else {
+            self.status_message = Some(format!("{}: no ticket selected", command.name));
+            return;
+        };
+
+        log::debug!("run_user_command: Running '{}' for {}", command.name, ticket.key);
+        let key = ticket.key.clone();
+        let result = match ScriptContext::for_ticket(ticket, &self.instance_url) {
+            Ok(context) => scripts::run(&command.command, &context).await,
+            Err(e) => Err(e),
+        };
+        self.status_message = Some(match result {
+            Ok(output) if output.is_empty() => format!("{}: done for {}", command.name, key),
+            Ok(output) => format!("{}: {}", command.name, output),
+            Err(e) => {
+                log::error!("run_user_command: '{}' failed: {}", command.name, e);
+                format!("{} failed: {}", command.name, e)
+            }
+        });
+    }
+
+    /// Keys of the tickets an action applies to: the detail ticket, or the
+    /// selected (else focused) tickets of the current list
+    fn target_keys(&self) -> Vec<String> {
+        match self.view_mode {
+            ViewMode::Detail => self.current_ticket_key.iter().cloned().collect(),
+            ViewMode::Backlog => self.backlog_state.target_keys(),
+            _ => self.ticket_list_state.target_keys(),
+        }
+    }
+
+    /// Handle a key while the label prompt is open
+    fn handle_label_editor_key(&mut self, key: KeyEvent) {
+        let Some((editor, _)) = self.label_editor.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Char(c) => editor.push(c),
+            KeyCode::Backspace => editor.pop(),
+            KeyCode::Tab => editor.toggle_mode(),
+            KeyCode::Esc => self.label_editor = None,
+            KeyCode::Enter => {
+                if let Some(change) = editor.change() {
+                    if let Some((_, keys)) = self.label_editor.take() {
+                        self.apply_label_change(&keys, change);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Send a label change for each ticket; the progress popup fills in
+    /// as Jira answers
+    fn apply_label_change(&mut self, keys: &[String], change: LabelChange) {
+        let verb = match &change {
+            LabelChange::Add(_) => "Add",
+            LabelChange::Remove(_) => "Remove",
+        };
+        let title = format!("{} label '{}'", verb, change.label());
+        self.bulk_progress = Some(BulkProgressState::new(title, keys.len()));
+        for key in keys {
+            self.spawn_action(Action::Write(Write::Label {
+                key: key.clone(),
+                change: change.clone(),
+            }));
+        }
+    }
+
+    /// Repeat the last mutating action on the focused ticket, vim-style
+    fn repeat_last_action(&mut self) {
+        let Some(action) = self.action_history.last().cloned() else {
+            self.status_message = Some("No action to repeat".to_string());
+            return;
+        };
+        let Some(key) = self.focused_key() else {
+            return;
+        };
+        log::debug!("repeat_last_action: {} on {}", action.describe(), key);
+
+        match action {
+            RepeatableAction::Transition {
+                transition,
+                fields,
+                comment,
+            } => {
+                self.with_transitions(
+                    key,
+                    TransitionPurpose::Repeat {
+                        transition,
+                        fields,
+                        comment,
+                    },
+                );
+            }
+            RepeatableAction::Label(change) => self.apply_label_change(&[key], change),
+            RepeatableAction::Assign(user) => self.assign_ticket(&key, user),
+            RepeatableAction::MoveToSprint(sprint) => self.move_to_sprint(&sprint, &[key]),
+        }
+    }
+
+    /// First configured default label when all the tickets are in one project
+    fn default_label(&self, keys: &[String]) -> Option<String> {
+        let project_key = |key: &String| key.rsplit_once('-').map(|(project, _)| project.to_string());
+        let project = project_key(keys.first()?)?;
+        if keys.iter().any(|key| project_key(key).as_ref() != Some(&project)) {
+            return None;
+        }
+        self.config.project_defaults(&project)?.labels.first().cloned()
+    }
+
+    /// Open the move picker with the projects the focused ticket can move to
+    fn open_move_issue(&mut self) {
+        if let Some(ticket) = self.movable_ticket() {
+            self.spawn_action(Action::LoadProjects(ticket.key.clone()));
+        }
+    }
+
+    /// The ticket in the detail view, or the focused one in the list
+    fn movable_ticket(&self) -> Option<&Ticket> {
+        match self.view_mode {
+            ViewMode::Detail => self.detail_ticket.as_ref(),
+            _ => self.ticket_list_state.focused_ticket(),
+        }
+    }
+
+    /// Open the move picker on the projects fetched, unless the user moved
+    /// on from the ticket since
+    fn projects_loaded(&mut self, key: String, projects: crate::utils::Result<Vec<Project>>) {
+        let Some(ticket) = self.movable_ticket().filter(|ticket| ticket.key == key).cloned() else {
+            log::debug!("projects_loaded: Dropping projects for {}", key);
+            return;
+        };
+
+        match projects {
+            Ok(projects) => {
+                let projects: Vec<Project> = projects
+                    .into_iter()
+                    .filter(|p| p.key != ticket.project_key && !p.standard_issue_types().is_empty())
+                    .collect();
+                if projects.is_empty() {
+                    self.status_message = Some("No other projects to move to".to_string());
+                    return;
+                }
+                let default_issue_types = projects
+                    .iter()
+                    .filter_map(|project| {
+                        let issue_type = self.config.project_defaults(&project.key)?.issue_type.clone()?;
+                        Some((project.key.clone(), issue_type))
+                    })
+                    .collect();
+                self.move_issue = Some(MoveIssueState::new(
+                    ticket.key,
+                    ticket.issue_type,
+                    projects,
+                    default_issue_types,
+                ));
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load projects: {}", e));
+            }
+        }
+    }
+
+    /// Handle a key while the move picker is open
+    fn handle_move_issue_key(&mut self, key: KeyEvent) {
+        let Some(state) = self.move_issue.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => state.move_up(),
+            KeyCode::Down | KeyCode::Char('j') => state.move_down(),
+            // Esc steps back to the project list first, then closes the picker
+            KeyCode::Esc if !state.back() => self.move_issue = None,
+            KeyCode::Enter => {
+                if let Some((project, issue_type)) = state.select() {
+                    let key = state.ticket_key.clone();
+                    self.move_issue = None;
+                    self.pending_action = Some(PendingAction::MoveIssue {
+                        key,
+                        project,
+                        issue_type,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle a key while a confirmation dialog is open
+    async fn handle_confirm_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                if let Some(action) = self.pending_action.take() {
+                    self.execute_action(action).await;
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                if let Some(PendingAction::RestoreSession(_)) = self.pending_action.take() {
+                    self.load().await;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Execute a confirmed action
+    async fn execute_action(&mut self, action: PendingAction) {
+        let write = match action {
+            PendingAction::StartSprint(sprint) => {
+                let (start, end) = SprintService::start_window(&sprint, chrono::Utc::now());
+                Write::StartSprint { sprint, start, end }
+            }
+            PendingAction::CompleteSprint(plan) => Write::CompleteSprint(plan),
+            PendingAction::ReleaseVersion { version, .. } => Write::ReleaseVersion {
+                version,
+                date: self.timezone.today(),
+            },
+            PendingAction::MoveIssue {
+                key,
+                project,
+                issue_type,
+            } => {
+                self.spawn_action(Action::MoveIssue {
+                    key,
+                    project,
+                    issue_type,
+                });
+                return;
+            }
+            PendingAction::RestoreSession(session) => {
+                self.restore_session(session).await;
+                return;
+            }
+        };
+        self.spawn_action(Action::Write(write));
+    }
+
+    /// List the attachments of the ticket in the detail view
+    fn show_attachments(&mut self) {
+        let attachments = self
+            .detail_ticket
+            .as_ref()
+            .map(|ticket| ticket.attachments.clone())
+            .unwrap_or_default();
+        if attachments.is_empty() {
+            self.status_message = Some("No attachments".to_string());
+        } else {
+            self.attachment_list = Some(AttachmentListState::new(attachments));
+        }
+    }
+
+    /// Handle a key while the attachment picker is open
+    fn handle_attachment_list_key(&mut self, key: KeyEvent) {
+        let Some(state) = self.attachment_list.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => state.move_up(),
+            KeyCode::Down | KeyCode::Char('j') => state.move_down(),
+            KeyCode::Esc => self.attachment_list = None,
+            KeyCode::Enter => {
+                if let Some(attachment) = state.focused().cloned() {
+                    self.open_attachment(attachment);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// List the links of the ticket in the detail view: the description's,
+    /// numbered as rendered, then the customer portal page of a service desk
+    /// request, its web links, pull requests and branches and the comments'. The first link of the focused comment starts
+    /// focused
+    fn show_links(&mut self) {
+        let mut urls = self
+            .detail_ticket
+            .as_ref()
+            .and_then(|ticket| ticket.description.as_deref())
+            .map(links::links)
+            .unwrap_or_default();
+        let mut titles = HashMap::new();
+        let portal = self
+            .detail_ticket
+            .as_ref()
+            .and_then(|ticket| ticket.service_request.as_ref())
+            .and_then(|request| Some((request.portal_url.clone()?, &request.request_type)));
+        if let Some((portal_url, request_type)) = portal {
+            if !urls.contains(&portal_url) {
+                urls.push(portal_url.clone());
+            }
+            titles.insert(portal_url, format!("Customer portal ({})", request_type));
+        }
+        for link in &self.detail_remote_links {
+            if !urls.contains(&link.url) {
+                urls.push(link.url.clone());
+            }
+            let title = match link.application_label() {
+                Some(application) => format!("{} ({})", link.title, application),
+                None => link.title.clone(),
+            };
+            titles.insert(link.url.clone(), title);
+        }
+        let dev_links = self
+            .detail_dev_info
+            .pull_requests
+            .iter()
+            .map(|pr| (&pr.url, format!("{} {} ({})", pr.id, pr.title, pr.status.label())))
+            .chain(
+                self.detail_dev_info
+                    .branches
+                    .iter()
+                    .map(|branch| (&branch.url, format!("{} ({})", branch.name, branch.repository))),
+            );
+        for (url, title) in dev_links.filter(|(url, _)| !url.is_empty()) {
+            if !urls.contains(url) {
+                urls.push(url.clone());
+            }
+            titles.insert(url.clone(), title);
+        }
+        let mut focused = 0;
+        for (i, comment) in self.detail_comments.iter().enumerate() {
+            let comment_links = links::links(&comment.body);
+            if i == self.detail_comments_state.focused_index {
+                focused = comment_links
+                    .first()
+                    .and_then(|first| urls.iter().position(|url| url == first))
+                    .unwrap_or(urls.len());
+            }
+            for url in comment_links {
+                if !urls.contains(&url) {
+                    urls.push(url);
+                }
+            }
+        }
+        if urls.is_empty() {
+            self.status_message = Some("No links".to_string());
+        } else {
+            self.link_list = Some(LinkListState::new(urls, titles, focused));
+        }
+    }
+
+    /// Handle a key while the link picker is open
+    fn handle_link_list_key(&mut self, key: KeyEvent) {
+        let Some(state) = self.link_list.as_mut() else {
+            return;
+        };
+
+        let url = match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                state.move_up();
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                state.move_down();
+                None
+            }
+            KeyCode::Esc => {
+                self.link_list = None;
+                None
+            }
+            KeyCode::Enter => state.focused().cloned(),
+            KeyCode::Char(c @ '1'..='9') => state.links.get(c as usize - '1' as usize).cloned(),
+            _ => None,
+        };
+        if let Some(url) = url {
+            self.link_list = None;
+            if let Err(e) = open::that(&url) {
+                log::error!("Failed to open browser: {}", e);
+                self.status_message = Some(format!("Failed to open {}: {}", url, e));
+            } else {
+                self.status_message = Some(format!("Opened {}", url));
+            }
+        }
+    }
+
+    /// Handle a key while the web link prompt is open
+    fn handle_remote_link_form_key(&mut self, key: KeyEvent) {
+        let Some(form) = self.remote_link_form.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Char(c) => form.push(c),
+            KeyCode::Backspace => form.pop(),
+            KeyCode::Tab | KeyCode::BackTab => form.switch_field(),
+            KeyCode::Esc => self.remote_link_form = None,
+            KeyCode::Enter => match form.link() {
+                Some((title, url)) => {
+                    let key = form.ticket_key.clone();
+                    self.remote_link_form = None;
+                    self.spawn_action(Action::Write(Write::RemoteLink { key, title, url }));
+                }
+                None => self.status_message = Some("Enter an http(s) URL".to_string()),
+            },
+            _ => {}
+        }
+    }
+
+    /// Stop the work timer or pomodoro and offer its work time as a worklog
+    fn stop_timer(&mut self) {
+        let now = chrono::Utc::now();
+        if let Some(timer) = self.work_timer.take() {
+            let seconds = timer.elapsed_seconds(now);
+            self.worklog_prompt = Some(WorklogPromptState::new(timer.key, timer.started, seconds));
+        } else if let Some(pomodoro) = self.pomodoro.take() {
+            let seconds = pomodoro.worked_seconds(now);
+            self.worklog_prompt = Some(WorklogPromptState::new(pomodoro.key, pomodoro.started, seconds));
+        }
+    }
+
+    /// Start the next pomodoro interval when one is over, with a desktop
+    /// notification
+    fn check_pomodoro(&mut self) {
+        let Some(pomodoro) = self.pomodoro.as_mut() else {
+            return;
+        };
+        let Some(phase) = pomodoro.advance(chrono::Utc::now()) else {
+            return;
+        };
+        let message = match phase {
+            PomodoroPhase::Break => format!(
+                "Pomodoro {} on {} done, take {}m off",
+                pomodoro.completed,
+                pomodoro.key,
+                pomodoro.break_seconds / 60
+            ),
+            PomodoroPhase::Work => format!("Break over, back to {}", pomodoro.key),
+        };
+        if let Err(e) = notify::desktop("LazyJira", &message) {
+            log::warn!("check_pomodoro: {}", e);
+        }
+        self.status_message = Some(message);
+    }
+
+    /// Handle a key while the worklog prompt is open
+    fn handle_worklog_prompt_key(&mut self, key: KeyEvent) {
+        let Some(prompt) = self.worklog_prompt.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Char(c) => prompt.push(c),
+            KeyCode::Backspace => prompt.pop(),
+            KeyCode::Tab | KeyCode::BackTab => prompt.switch_field(),
+            KeyCode::Esc => {
+                self.status_message = Some(format!("Discarded the time on {}", prompt.ticket_key));
+                self.worklog_prompt = None;
+            }
+            KeyCode::Enter => match prompt.time_spent_seconds() {
+                Some(seconds) => {
+                    let write = Write::Worklog {
+                        key: prompt.ticket_key.clone(),
+                        started: prompt.started,
+                        seconds,
+                        comment: prompt.comment(),
+                    };
+                    self.spawn_action(Action::Write(write));
+                    self.sent_worklog = self.worklog_prompt.take();
+                }
+                None => self.status_message = Some("Enter the time spent, e.g. 1h 30m".to_string()),
+            },
+            _ => {}
+        }
+    }
+
+    /// List the approvals of the service desk request in the detail view
+    fn show_approvals(&mut self) {
+        let Some(key) = self.current_ticket_key.clone() else {
+            return;
+        };
+        if self.detail_approvals.is_empty() {
+            self.status_message = Some("No approvals on this ticket".to_string());
+        } else {
+            self.approval_list = Some(ApprovalListState::new(key, self.detail_approvals.clone()));
         }
-
-        Ok(())
     }
 
-    /// Load tickets from API
-    async fn load_tickets(&mut self) {
-        self.loading_state = LoadingState::Loading;
-        
-        // Default JQL: get assigned tickets, ordered by updated date
-        let jql = "assignee = currentUser() ORDER BY updated DESC";
-        match self
-            .ticket_service
-            .search_issues(jql, 0, 50)
-            .await
-        {
-            Ok(result) => {
-                self.ticket_list_state.set_tickets(result.issues);
-                self.loading_state = LoadingState::Loaded;
+    /// Handle a key while the approval list is open
+    fn handle_approval_list_key(&mut self, key: KeyEvent) {
+        let Some(state) = self.approval_list.as_mut() else {
+            return;
+        };
+        let approve = match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                state.move_up();
+                return;
             }
-            Err(e) => {
-                self.loading_state = LoadingState::Error(format!("Failed to load tickets: {}", e));
+            KeyCode::Down | KeyCode::Char('j') => {
+                state.move_down();
+                return;
+            }
+            KeyCode::Esc => {
+                self.approval_list = None;
+                return;
             }
+            KeyCode::Char('a') => true,
+            KeyCode::Char('d') => false,
+            _ => return,
+        };
+        let Some(approval) = state.selected().cloned() else {
+            return;
+        };
+        if !approval.is_answerable() {
+            self.status_message = Some(if approval.is_pending() {
+                format!("'{}' is not waiting on you", approval.name)
+            } else {
+                format!("'{}' was already {}", approval.name, approval.decision.label())
+            });
+            return;
         }
-    }
-
-    /// Open detail view for focused ticket
-    async fn open_detail_view(&mut self) {
-        log::debug!("open_detail_view: Starting");
-        
-        if let Some(ticket) = self.ticket_list_state.focused_ticket() {
-            let ticket_key = ticket.key.clone();
-            log::debug!("open_detail_view: Opening ticket {}", ticket_key);
-            
-            self.view_mode = ViewMode::Detail;
-            self.detail_loading = true;
-            self.detail_ticket = None;
-            self.detail_comments = Vec::new();
-            self.current_ticket_key = Some(ticket_key.clone());
-            
-            log::debug!("open_detail_view: Set view mode to Detail, loading state set");
 
-            // Fetch full ticket details and comments in parallel
-            log::debug!("open_detail_view: Starting parallel fetch for ticket and comments");
-            let ticket_future = self.ticket_service.get_issue(&ticket_key);
-            let comments_future = self.ticket_service.get_comments(&ticket_key);
+        let key = state.ticket_key.clone();
+        self.approval_list = None;
+        self.spawn_action(Action::Write(Write::AnswerApproval {
+            key,
+            approval,
+            approve,
+        }));
+    }
 
-            // Wait for both to complete
-            log::debug!("open_detail_view: Waiting for futures to complete");
-            let (ticket_result, comments_result) = tokio::join!(ticket_future, comments_future);
-            log::debug!("open_detail_view: Futures completed");
+    /// Follow the blocker links of the ticket in the detail view, a few
+    /// levels each way, and show them as a graph
+    fn show_dependencies(&mut self) {
+        let Some(ticket) = self.detail_ticket.as_ref() else {
+            return;
+        };
+        let graph = DependencyGraph::new(ticket);
+        if graph.is_empty() {
+            self.status_message = Some(format!("{} neither blocks nor is blocked by anything", ticket.key));
+            return;
+        }
+        self.spawn_action(Action::LoadDependencies(graph));
+    }
 
-            match ticket_result {
-                Ok(full_ticket) => {
-                    log::debug!("open_detail_view: Successfully fetched ticket {}", full_ticket.key);
-                    self.detail_ticket = Some(full_ticket);
-                }
-                Err(e) => {
-                    log::error!("open_detail_view: Failed to fetch ticket {}: {}", ticket_key, e);
-                    // On error, use the ticket from list (may be incomplete)
-                    self.detail_ticket = Some(ticket.clone());
-                    // Could set an error state here if needed
+    /// Handle a key while the dependency graph is open
+    fn handle_dependency_graph_key(&mut self, key: KeyEvent) {
+        let Some(state) = self.dependency_graph.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => state.move_up(),
+            KeyCode::Down | KeyCode::Char('j') => state.move_down(),
+            KeyCode::Esc => self.dependency_graph = None,
+            KeyCode::Enter => {
+                let selected = state.selected_key().map(str::to_string);
+                self.dependency_graph = None;
+                if let Some(selected) = selected.filter(|key| Some(key) != self.current_ticket_key.as_ref()) {
+                    self.open_detail(selected);
                 }
             }
+            _ => {}
+        }
+    }
 
-            match comments_result {
-                Ok(comments) => {
-                    log::debug!("open_detail_view: Successfully fetched {} comments", comments.len());
-                    self.detail_comments = comments;
-                }
-                Err(e) => {
-                    // On error, leave comments empty and log the error
-                    log::warn!("open_detail_view: Failed to load comments for {}: {}", ticket_key, e);
-                    self.detail_comments = Vec::new();
-                }
+    /// Fetch the entity properties of the ticket in the detail view and
+    /// show them as JSON
+    fn show_properties(&mut self) {
+        if let Some(ticket_key) = self.current_ticket_key.clone() {
+            self.spawn_action(Action::LoadIssueProperties(ticket_key));
+        }
+    }
+
+    /// Show the properties fetched, unless the user left the ticket since
+    fn issue_properties_loaded(
+        &mut self,
+        ticket_key: String,
+        properties: crate::utils::Result<Vec<IssueProperty>>,
+    ) {
+        if self.current_ticket_key.as_deref() != Some(ticket_key.as_str()) {
+            log::debug!("issue_properties_loaded: Dropping properties of {}", ticket_key);
+            return;
+        }
+        match properties {
+            Ok(properties) if properties.is_empty() => {
+                self.status_message = Some(format!("{} has no properties", ticket_key));
+            }
+            Ok(properties) => {
+                self.issue_properties = Some(IssuePropertiesState::new(ticket_key, properties));
             }
+            Err(e) => self.status_message = Some(format!("Failed to load properties: {}", e)),
+        }
+    }
 
-            self.detail_loading = false;
-            log::debug!("open_detail_view: Completed, loading state cleared");
-        } else {
-            log::warn!("open_detail_view: No focused ticket found");
+    /// Handle a key while the issue properties are open
+    fn handle_issue_properties_key(&mut self, key: KeyEvent) {
+        let Some(state) = self.issue_properties.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => state.scroll_up(),
+            KeyCode::Down | KeyCode::Char('j') => state.scroll_down(),
+            KeyCode::Esc | KeyCode::Char('x') => self.issue_properties = None,
+            _ => {}
         }
     }
 
-    /// Assign ticket to current user
-    async fn assign_to_me(&mut self) {
-        if let Some(ticket_key) = &self.current_ticket_key {
-            // Get current user from config or API
-            // For now, use a placeholder - in real implementation, get from config
-            let _assignee = "currentUser()"; // This would need to be the actual account ID
-            
-            // Update ticket assignee
-            // Note: This requires update_issue to be implemented
-            // For now, just refresh the ticket
-            if let Ok(updated_ticket) = self.ticket_service.get_issue(ticket_key).await {
-                self.detail_ticket = Some(updated_ticket);
+    /// Handle a key while the text to find in the detail view is typed
+    fn handle_detail_search_key(&mut self, key: KeyEvent) {
+        let Some(search) = self.detail_search.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(c) => search.query.push(c),
+            KeyCode::Backspace => {
+                search.query.pop();
             }
+            KeyCode::Enter if search.query.is_empty() => self.detail_search = None,
+            KeyCode::Enter => search.typing = false,
+            KeyCode::Esc => self.detail_search = None,
+            _ => return,
         }
+        self.find_in_detail();
     }
 
-    /// Start progress (transition to In Progress)
-    async fn start_progress(&mut self) {
-        if let Some(ticket_key) = &self.current_ticket_key {
-            // Get transitions and find "Start Progress" or "In Progress"
-            if let Ok(transitions) = self.ticket_service.get_transitions(ticket_key).await {
-                if let Some(transition) = transitions.iter().find(|t| {
-                    t.name.to_lowercase().contains("start") || 
-                    t.to_status.to_lowercase().contains("progress")
-                }) {
-                    if let Err(_e) = self.ticket_service.transition_issue(
-                        ticket_key,
-                        &transition.id,
-                        None,
-                    ).await {
-                        // Error handling - could show message
-                    } else {
-                        // Refresh ticket after transition
-                        if let Ok(updated_ticket) = self.ticket_service.get_issue(ticket_key).await {
-                            self.detail_ticket = Some(updated_ticket);
-                            // Refresh list as well
-                            self.load_tickets().await;
-                        }
-                    }
+    /// Look for the search text in the ticket in the detail view, showing
+    /// the first match
+    fn find_in_detail(&mut self) {
+        let (Some(search), Some(ticket)) = (self.detail_search.as_mut(), self.detail_ticket.as_ref()) else {
+            return;
+        };
+        let matches = TicketDetail::new(ticket, &self.detail_comments, self.renderer.theme())
+            .raw_description(self.raw_description)
+            .references(&self.references)
+            .search_matches(&search.query);
+        search.set_matches(matches);
+        self.show_search_match();
+    }
+
+    /// Bring the current search match into view: scroll the description to
+    /// its line, or focus and expand its comment
+    fn show_search_match(&mut self) {
+        match self.detail_search.as_ref().and_then(|search| search.current_match()) {
+            Some(DetailMatch::Description { line }) => {
+                self.detail_description_scroll = line.try_into().unwrap_or(u16::MAX);
+            }
+            Some(DetailMatch::Comment { index }) => {
+                self.detail_comments_state.focused_index = index;
+                if let Some(comment) = self.detail_comments.get(index) {
+                    self.detail_comments_state.collapsed.remove(&comment.id);
                 }
             }
+            None => self.detail_description_scroll = 0,
         }
     }
 
-    /// Resolve ticket
-    async fn resolve_ticket(&mut self) {
-        if let Some(ticket_key) = &self.current_ticket_key {
-            // Get transitions and find "Resolve" or "Done"
-            if let Ok(transitions) = self.ticket_service.get_transitions(ticket_key).await {
-                if let Some(transition) = transitions.iter().find(|t| {
-                    t.name.to_lowercase().contains("resolve") || 
-                    t.name.to_lowercase().contains("done") ||
-                    t.to_status.to_lowercase().contains("done")
-                }) {
-                    if let Err(_e) = self.ticket_service.transition_issue(
-                        ticket_key,
-                        &transition.id,
-                        None,
-                    ).await {
-                        // Error handling - could show message
-                    } else {
-                        // Refresh ticket after transition
-                        if let Ok(updated_ticket) = self.ticket_service.get_issue(ticket_key).await {
-                            self.detail_ticket = Some(updated_ticket);
-                            // Refresh list as well
-                            self.load_tickets().await;
-                        }
-                    }
+    /// Handle a key while a row number is typed in the list
+    fn handle_row_jump_key(&mut self, key: KeyEvent) {
+        let Some(input) = self.row_jump.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Enter => {
+                let row = input.parse::<usize>().ok();
+                self.row_jump = None;
+                let len = self.ticket_list_state.tickets.len();
+                match row {
+                    Some(row) if (1..=len).contains(&row) => self.ticket_list_state.focus_index(row - 1),
+                    Some(row) => self.status_message = Some(format!("No row {}; the list has {}", row, len)),
+                    None => {}
                 }
             }
+            KeyCode::Esc => self.row_jump = None,
+            _ => {}
         }
     }
 
-    /// Show available transitions
-    async fn show_transitions(&mut self) {
-        if let Some(ticket_key) = &self.current_ticket_key {
-            self.view_mode = ViewMode::Transitions;
-            self.transitions_loading = true;
-            
-            match self.ticket_service.get_transitions(ticket_key).await {
-                Ok(transitions) => {
-                    self.transition_list_state.set_transitions(transitions);
-                    self.transitions_loading = false;
-                }
-                Err(_e) => {
-                    self.transitions_loading = false;
-                    // Could show error message
-                }
+    /// Preview an image attachment inline when the terminal can draw it,
+    /// otherwise save it to a temporary file and open it externally
+    fn open_attachment(&mut self, attachment: Attachment) {
+        self.status_message = Some(format!("Downloading {}...", attachment.filename));
+        self.spawn_action(Action::DownloadAttachment(attachment));
+    }
+
+    /// Preview or open an attachment once downloaded, unless the user left
+    /// its ticket since
+    fn attachment_downloaded(&mut self, attachment: Attachment, bytes: crate::utils::Result<Vec<u8>>) {
+        let current = self
+            .detail_ticket
+            .as_ref()
+            .is_some_and(|ticket| ticket.attachments.contains(&attachment));
+        if !current {
+            log::debug!("attachment_downloaded: Dropping {}", attachment.filename);
+            return;
+        }
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                self.status_message = Some(format!("Download failed: {}", e));
+                return;
             }
+        };
+
+        if let Some(protocol) = self
+            .graphics_protocol
+            .filter(|protocol| protocol.supports(&attachment.mime_type))
+        {
+            self.status_message = None;
+            self.image_preview = Some(ImagePreviewState::new(attachment, bytes, protocol));
+            return;
+        }
+
+        // Keep only the file name in case the attachment name contains a path
+        let filename = std::path::Path::new(&attachment.filename)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| attachment.id.clone());
+        let dir = std::env::temp_dir().join("lazyjira");
+        let path = dir.join(format!("{}-{}", attachment.id, filename));
+        let result = std::fs::create_dir_all(&dir)
+            .and_then(|_| std::fs::write(&path, &bytes))
+            .and_then(|_| open::that(&path));
+        self.status_message = Some(match result {
+            Ok(()) => format!("Opened {}", attachment.filename),
+            Err(e) => format!("Could not open {}: {}", attachment.filename, e),
+        });
+    }
+
+    /// Close the image preview and repaint over the image
+    fn close_image_preview(&mut self) {
+        let Some(preview) = self.image_preview.take() else {
+            return;
+        };
+        if let Some(sequence) = preview.protocol.clear_sequence() {
+            let _ = self.terminal.backend_mut().write_raw(None, sequence);
+        }
+        if let Err(e) = self.terminal.clear() {
+            log::error!("close_image_preview: Failed to clear terminal: {}", e);
         }
     }
 
@@ -446,28 +5389,101 @@ impl App {
     }
 
     /// Draw the UI
-    fn draw(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn draw(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let mut image_area = None;
+        let timezone = self.timezone;
+        let date_format = self.date_format.as_str();
+        let sprint_filter_names = self.sprint_filter_names();
+        let tab_bar = self.tab_bar();
+        let list_columns = self.config.list_columns();
+        let aliased_fields = self.config.aliased_fields();
+        let denied_hints = self.denied_hints();
+        self.renderer.set_hidden_hints(denied_hints);
         self.terminal.draw(|frame| {
             let area = frame.size();
             
             // Render main layout
-            if let Err(e) = self.renderer.render_main_layout(frame, area, &self.connection_status) {
+            let mut status = match &self.status_message {
+                Some(message) => format!("{} | {}", self.connection_status, message),
+                None => self.connection_status.clone(),
+            };
+            if let Some(timer) = &self.work_timer {
+                status = format!("⏱ {} {} | {}", timer.key, timer.clock(chrono::Utc::now()), status);
+            }
+            if let Some(pomodoro) = &self.pomodoro {
+                status = format!("🍅 {} {} | {}", pomodoro.key, pomodoro.clock(chrono::Utc::now()), status);
+            }
+            if !tab_bar.is_empty() {
+                status = format!("{} | {}", tab_bar, status);
+            }
+            if let Err(e) = self.renderer.render_main_layout(frame, area, &status) {
                 log::error!("Error rendering: {}", e);
             }
 
             // Content area is already split in render_main_layout
             // We just need to get the middle chunk for content
-            let chunks = ratatui::layout::Layout::default()
+            let mut chunks = ratatui::layout::Layout::default()
                 .direction(ratatui::layout::Direction::Vertical)
                 .constraints([
                     ratatui::layout::Constraint::Length(1),
                     ratatui::layout::Constraint::Min(1),
                     ratatui::layout::Constraint::Length(1),
                 ])
-                .split(area);
+                .split(area)
+                .to_vec();
+
+            if (!self.config.filters.is_empty()
+                || self.custom_query.is_some()
+                || self.jira_search.is_some())
+                && matches!(
+                    self.view_mode,
+                    ViewMode::Dashboard | ViewMode::List | ViewMode::Board
+                )
+            {
+                let bar = ratatui::layout::Layout::default()
+                    .direction(ratatui::layout::Direction::Vertical)
+                    .constraints([
+                        ratatui::layout::Constraint::Length(1),
+                        ratatui::layout::Constraint::Min(1),
+                    ])
+                    .split(chunks[1]);
+                FilterBar::new(&self.config.filters, self.active_filter, self.renderer.theme())
+                    .custom_query(match &self.jira_search {
+                        Some(_) => Some(SEARCH_QUERY_NAME),
+                        None => self.custom_query.as_ref().map(|(name, _)| name.as_str()),
+                    })
+                    .counts(&self.query_counts, &self.config.jira.default_query)
+                    .render(frame, bar[0]);
+                chunks[1] = bar[1];
+            }
 
             // Render based on view mode
             match self.view_mode {
+                ViewMode::Dashboard => match &self.loading_state {
+                    LoadingState::Loading => {
+                        if let Err(e) = self.renderer.render_content_area(
+                            frame,
+                            chunks[1],
+                            "Loading tickets...",
+                        ) {
+                            log::error!("Error rendering content: {}", e);
+                        }
+                    }
+                    LoadingState::Error(msg) => {
+                        if let Err(e) = self.renderer.render_content_area(frame, chunks[1], msg) {
+                            log::error!("Error rendering content: {}", e);
+                        }
+                    }
+                    _ => {
+                        let tiles = DashboardService::tiles(
+                            &self.all_tickets,
+                            timezone.today(),
+                            self.current_user.as_ref().map(|user| user.account_id.as_str()),
+                        );
+                        Dashboard::new(&tiles, &self.dashboard_state, self.renderer.theme())
+                            .render(frame, chunks[1]);
+                    }
+                },
                 ViewMode::List => {
                     // Render ticket list or loading/error state
                     match &self.loading_state {
@@ -486,9 +5502,71 @@ impl App {
                             }
                         }
                         _ => {
+                            let list_area = if self.filter_sidebar.open {
+                                let columns = ratatui::layout::Layout::default()
+                                    .direction(ratatui::layout::Direction::Horizontal)
+                                    .constraints([
+                                        ratatui::layout::Constraint::Length(32),
+                                        ratatui::layout::Constraint::Min(1),
+                                    ])
+                                    .split(chunks[1]);
+                                let facets = FilterService::facet_counts(&self.all_tickets);
+                                FilterSidebar::new(&self.filter_sidebar, &facets, self.renderer.theme())
+                                    .render(frame, columns[0]);
+                                columns[1]
+                            } else {
+                                chunks[1]
+                            };
+
                             // Render ticket list
-                            let ticket_list = TicketList::new(&self.ticket_list_state, self.renderer.theme());
-                            ticket_list.render(frame, chunks[1]);
+                            let mut title = match &self.list_filter {
+                                Some(filter) => format!("Tickets — {}", filter.label()),
+                                None => "Tickets".to_string(),
+                            };
+                            if self.assignee_scope != AssigneeScope::Everyone {
+                                title.push_str(&format!(" — {}", self.assignee_scope.label()));
+                            }
+                            if !self.sprint_filter.is_empty() {
+                                title.push_str(&format!(" [{}]", sprint_filter_names));
+                            }
+                            if !self.filter_sidebar.selected.is_empty() {
+                                title.push_str(&format!(
+                                    " [{} facet(s)]",
+                                    self.filter_sidebar.selected.len()
+                                ));
+                            }
+                            if !self.search.query.is_empty() && !self.search.jira {
+                                title.push_str(&format!(" [\"{}\"]", self.search.query));
+                            }
+                            let list_area = if let Some(input) = &self.row_jump {
+                                let rows = ratatui::layout::Layout::default()
+                                    .direction(ratatui::layout::Direction::Vertical)
+                                    .constraints([
+                                        ratatui::layout::Constraint::Min(1),
+                                        ratatui::layout::Constraint::Length(1),
+                                    ])
+                                    .split(list_area);
+                                RowJumpBar::new(input, self.renderer.theme()).render(frame, rows[1]);
+                                rows[0]
+                            } else if self.search.open {
+                                let rows = ratatui::layout::Layout::default()
+                                    .direction(ratatui::layout::Direction::Vertical)
+                                    .constraints([
+                                        ratatui::layout::Constraint::Min(1),
+                                        ratatui::layout::Constraint::Length(1),
+                                    ])
+                                    .split(list_area);
+                                SearchBar::new(&self.search, self.renderer.theme()).render(frame, rows[1]);
+                                rows[0]
+                            } else {
+                                list_area
+                            };
+                            let ticket_list = TicketList::new(&self.ticket_list_state, self.renderer.theme())
+                                .title(&title)
+                                .show_avatars(self.config.ui.show_avatars)
+                                .columns(&list_columns)
+                                .row_numbers(self.config.ui.row_numbers || self.row_jump.is_some());
+                            ticket_list.render(frame, list_area);
                         }
                     }
                 }
@@ -507,9 +5585,42 @@ impl App {
                     } else if let Some(ticket) = &self.detail_ticket {
                         log::debug!("draw: Rendering ticket detail for {}", ticket.key);
                         log::debug!("draw: Comments count: {}", self.detail_comments.len());
-                        let detail = TicketDetail::new(ticket, &self.detail_comments, self.renderer.theme());
+                        let detail = TicketDetail::new(ticket, &self.detail_comments, self.renderer.theme())
+                            .time_in_status(&self.detail_time_in_status)
+                            .comments_state(&self.detail_comments_state)
+                            .comment_order(CommentOrder::from_config(&self.config.ui.comment_order))
+                            .timestamp_format(TimestampFormat::from_config(&self.config.ui.timestamps))
+                            .timezone(timezone)
+                            .date_format(date_format)
+                            .show_avatars(self.config.ui.show_avatars)
+                            .raw_description(self.raw_description)
+                            .references(&self.references)
+                            .remote_links(&self.detail_remote_links)
+                            .dev_info(&self.detail_dev_info)
+                            .slas(&self.detail_slas)
+                            .approvals(&self.detail_approvals)
+                            .custom_fields(&aliased_fields)
+                            .description_scroll(self.detail_description_scroll);
+                        let detail_area = match &self.detail_search {
+                            Some(search) => {
+                                let rows = ratatui::layout::Layout::default()
+                                    .direction(ratatui::layout::Direction::Vertical)
+                                    .constraints([
+                                        ratatui::layout::Constraint::Min(1),
+                                        ratatui::layout::Constraint::Length(1),
+                                    ])
+                                    .split(chunks[1]);
+                                DetailSearchBar::new(search, self.renderer.theme()).render(frame, rows[1]);
+                                rows[0]
+                            }
+                            None => chunks[1],
+                        };
+                        let detail = match &self.detail_search {
+                            Some(search) => detail.search(&search.query),
+                            None => detail,
+                        };
                         log::debug!("draw: Calling detail.render()");
-                        detail.render(frame, chunks[1]);
+                        detail.render(frame, detail_area);
                         log::debug!("draw: detail.render() completed");
                     } else {
                         log::warn!("draw: No ticket selected in detail view");
@@ -537,6 +5648,190 @@ impl App {
                         transition_list.render(frame, chunks[1]);
                     }
                 }
+                ViewMode::Board => {
+                    let lanes = build_swimlanes(
+                        &self.ticket_list_state.tickets,
+                        self.board_state.swimlane_mode,
+                        &self.board_state.columns,
+                    );
+                    let board = BoardView::new(&lanes, &self.board_state, self.renderer.theme());
+                    board.render(frame, chunks[1]);
+                }
+                ViewMode::Diff => {
+                    if let (Some(previous), Some(latest)) = (&self.detail_previous, &self.detail_ticket) {
+                        let diff = DiffService::diff_tickets(previous, latest);
+                        let diff_view = TicketDiffView::new(&latest.key, &diff, self.renderer.theme());
+                        diff_view.render(frame, chunks[1]);
+                    }
+                }
+                ViewMode::Sprints => {
+                    if self.sprints_loading {
+                        if let Err(e) = self.renderer.render_content_area(
+                            frame,
+                            chunks[1],
+                            "Loading sprints...",
+                        ) {
+                            log::error!("draw: Error rendering loading content: {}", e);
+                        }
+                    } else {
+                        let sprint_list = SprintList::new(
+                            &self.sprint_list_state,
+                            "Sprints — [s]tart [R] complete [X] export",
+                            self.renderer.theme(),
+                        )
+                        .timezone(timezone)
+                        .date_format(date_format);
+                        match &self.sprint_progress {
+                            Some((name, progress)) => {
+                                let sprint_chunks = ratatui::layout::Layout::default()
+                                    .direction(ratatui::layout::Direction::Vertical)
+                                    .constraints([
+                                        ratatui::layout::Constraint::Min(3),
+                                        ratatui::layout::Constraint::Length(8),
+                                    ])
+                                    .split(chunks[1]);
+                                sprint_list.render(frame, sprint_chunks[0]);
+                                SprintProgressPanel::new(name, progress, self.renderer.theme())
+                                    .render(frame, sprint_chunks[1]);
+                            }
+                            None => sprint_list.render(frame, chunks[1]),
+                        }
+                    }
+                }
+                ViewMode::Backlog => {
+                    if self.backlog_loading {
+                        if let Err(e) = self.renderer.render_content_area(
+                            frame,
+                            chunks[1],
+                            "Loading backlog...",
+                        ) {
+                            log::error!("draw: Error rendering loading content: {}", e);
+                        }
+                    } else {
+                        TicketList::new(&self.backlog_state, self.renderer.theme())
+                            .title("Backlog — [K/J] rank up/down [m]ove to sprint")
+                            .show_avatars(self.config.ui.show_avatars)
+                            .columns(&list_columns)
+                            .render(frame, chunks[1]);
+                    }
+                }
+                ViewMode::Velocity => {
+                    if self.velocity_loading {
+                        if let Err(e) = self.renderer.render_content_area(
+                            frame,
+                            chunks[1],
+                            "Loading velocity...",
+                        ) {
+                            log::error!("draw: Error rendering loading content: {}", e);
+                        }
+                    } else {
+                        VelocityChart::new(&self.velocity, self.renderer.theme()).render(frame, chunks[1]);
+                    }
+                }
+                ViewMode::Releases | ViewMode::ReleaseIssues if self.releases_loading => {
+                    if let Err(e) = self.renderer.render_content_area(
+                        frame,
+                        chunks[1],
+                        "Loading releases...",
+                    ) {
+                        log::error!("draw: Error rendering loading content: {}", e);
+                    }
+                }
+                ViewMode::Releases => {
+                    ReleaseList::new(&self.release_list, timezone.today(), self.renderer.theme())
+                        .render(frame, chunks[1]);
+                }
+                ViewMode::ReleaseIssues => {
+                    let title = match self.release_list.focused_version() {
+                        Some(version) => {
+                            let tickets = &self.release_issues.tickets;
+                            let done = tickets.iter().filter(|ticket| ticket.is_done()).count();
+                            format!(
+                                "{} — {} done, {} remaining — {} — [R]elease [N]otes",
+                                version.name,
+                                done,
+                                tickets.len() - done,
+                                release_label(version, timezone.today())
+                            )
+                        }
+                        None => "Release".to_string(),
+                    };
+                    TicketList::new(&self.release_issues, self.renderer.theme())
+                        .title(&title)
+                        .show_avatars(self.config.ui.show_avatars)
+                        .columns(&list_columns)
+                        .render(frame, chunks[1]);
+                }
+                ViewMode::Timesheet => {
+                    if self.timesheet_loading {
+                        if let Err(e) = self.renderer.render_content_area(
+                            frame,
+                            chunks[1],
+                            "Loading timesheet...",
+                        ) {
+                            log::error!("draw: Error rendering loading content: {}", e);
+                        }
+                    } else {
+                        TimesheetView::new(&self.timesheet, timezone.today(), self.renderer.theme())
+                            .render(frame, chunks[1]);
+                    }
+                }
+                ViewMode::Activity => {
+                    if self.activity_loading {
+                        if let Err(e) = self.renderer.render_content_area(
+                            frame,
+                            chunks[1],
+                            "Loading activity...",
+                        ) {
+                            log::error!("draw: Error rendering loading content: {}", e);
+                        }
+                    } else {
+                        ActivityFeed::new(
+                            &self.activity,
+                            chrono::Utc::now(),
+                            timezone,
+                            date_format,
+                            self.renderer.theme(),
+                        )
+                        .render(frame, chunks[1]);
+                    }
+                }
+                ViewMode::CrossSearch => {
+                    if self.cross_search_loading {
+                        if let Err(e) = self.renderer.render_content_area(
+                            frame,
+                            chunks[1],
+                            "Searching all instances...",
+                        ) {
+                            log::error!("draw: Error rendering loading content: {}", e);
+                        }
+                    } else {
+                        CrossSearchView::new(&self.cross_search, self.renderer.theme())
+                            .render(frame, chunks[1]);
+                    }
+                }
+                ViewMode::Timeline => {
+                    if self.timeline_loading {
+                        if let Err(e) = self.renderer.render_content_area(
+                            frame,
+                            chunks[1],
+                            "Loading roadmap...",
+                        ) {
+                            log::error!("draw: Error rendering loading content: {}", e);
+                        }
+                    } else {
+                        TimelineView::new(&self.timeline, timezone.today(), self.renderer.theme())
+                            .render(frame, chunks[1]);
+                    }
+                }
+                ViewMode::AuditLog => {
+                    let entries = self.audit_log.entries();
+                    AuditLogView::new(&entries, timezone, self.renderer.theme()).render(frame, chunks[1]);
+                }
+                ViewMode::Debug => {
+                    let records = self.ticket_service.recent_requests();
+                    RequestLogView::new(&records, timezone, self.renderer.theme()).render(frame, chunks[1]);
+                }
                 ViewMode::CreateTicket => {
                     // TODO: Render create ticket form
                     if let Err(e) = self.renderer.render_content_area(
@@ -548,21 +5843,210 @@ impl App {
                     }
                 }
             }
+
+            if let Some((picker, keys)) = &self.sprint_picker {
+                let popup = centered_rect(60, 50, chunks[1]);
+                let title = format!("Move {} issue(s) to sprint — [Enter] move [Esc] cancel", keys.len());
+                frame.render_widget(Clear, popup);
+                SprintList::new(picker, &title, self.renderer.theme())
+                    .timezone(timezone)
+                    .date_format(date_format)
+                    .render(frame, popup);
+            }
+
+            if let Some((picker, ticket_key)) = &self.assignee_picker {
+                let popup = centered_rect(50, 60, chunks[1]);
+                AssigneePicker::new(picker, ticket_key, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(form) = &self.transition_form {
+                let popup = centered_rect(60, 50, chunks[1]);
+                TransitionForm::new(form, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(form) = &self.edit_form {
+                let popup = centered_rect(60, 50, chunks[1]);
+                EditForm::new(form, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(prompt) = &self.comment_prompt {
+                let popup = centered_rect(60, 25, chunks[1]);
+                CommentPrompt::new(prompt, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(composer) = &self.comment_composer {
+                let popup = centered_rect(60, 40, chunks[1]);
+                CommentComposer::new(composer, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(form) = &self.remote_link_form {
+                let popup = centered_rect(60, 25, chunks[1]);
+                RemoteLinkForm::new(form, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(prompt) = &self.worklog_prompt {
+                let popup = centered_rect(60, 25, chunks[1]);
+                WorklogPrompt::new(prompt, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some((editor, keys)) = &self.label_editor {
+                let popup = centered_rect(50, 25, chunks[1]);
+                LabelEditor::new(editor, keys.len(), self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(builder) = &self.query_builder {
+                let popup = centered_rect(60, 50, chunks[1]);
+                QueryBuilder::new(builder, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(switcher) = &self.view_switcher {
+                let popup = centered_rect(40, 40, chunks[1]);
+                ViewSwitcher::new(switcher, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(picker) = &self.sprint_filter_picker {
+                let popup = centered_rect(60, 50, chunks[1]);
+                frame.render_widget(Clear, popup);
+                SprintList::new(
+                    picker,
+                    "Filter by sprint — [Space] tick [Enter] apply [Backspace] clear [Esc] cancel",
+                    self.renderer.theme(),
+                )
+                .timezone(timezone)
+                .date_format(date_format)
+                .checkboxes(true)
+                .render(frame, popup);
+            }
+
+            if let Some(progress) = &self.bulk_progress {
+                let popup = centered_rect(60, 50, chunks[1]);
+                BulkProgress::new(progress, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(state) = &self.move_issue {
+                let popup = centered_rect(60, 50, chunks[1]);
+                MoveIssuePicker::new(state, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(state) = &self.attachment_list {
+                let popup = centered_rect(60, 50, chunks[1]);
+                AttachmentList::new(state, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(state) = &self.link_list {
+                let popup = centered_rect(60, 50, chunks[1]);
+                LinkList::new(state, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(state) = &self.approval_list {
+                let popup = centered_rect(60, 40, chunks[1]);
+                ApprovalList::new(state, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(state) = &self.dependency_graph {
+                let popup = centered_rect(70, 60, chunks[1]);
+                DependencyGraphView::new(state, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(state) = &self.issue_properties {
+                let popup = centered_rect(70, 60, chunks[1]);
+                IssuePropertiesView::new(state, self.renderer.theme()).render(frame, popup);
+            }
+
+            if let Some(preview) = &self.image_preview {
+                let popup = centered_rect(80, 80, chunks[1]);
+                image_area = Some(ImagePreview::new(preview, self.renderer.theme()).render(frame, popup));
+            }
+
+            if let Some(reminders) = &self.due_reminders {
+                let today = timezone.today();
+                ReminderPopup::new(reminders, today, self.renderer.theme()).render(frame, chunks[1]);
+            }
+
+            if let Some(action) = &self.pending_action {
+                let message = action.message();
+                let dialog = ConfirmDialog::new(action.title(), &message, self.renderer.theme());
+                dialog.render(frame, chunks[1]);
+            }
         })?;
 
+        if let Some(area) = image_area {
+            self.draw_image(area)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the previewed image into its frame once ratatui has drawn it;
+    /// the image is not part of ratatui's buffer, so it is only sent once
+    fn draw_image(&mut self, area: Rect) -> std::io::Result<()> {
+        let Some(preview) = self.image_preview.as_mut() else {
+            return Ok(());
+        };
+        if preview.drawn {
+            return Ok(());
+        }
+        preview.drawn = true;
+
+        if let Some(sequence) = preview.sequence(area) {
+            self.terminal
+                .backend_mut()
+                .write_raw(Some((area.x, area.y)), &sequence)?;
+        }
         Ok(())
     }
 }
 
-impl Drop for App {
+/// Run an app per Jira instance as tabs, each with its own client and
+/// loaded tickets. Only the shown tab runs; the others wait as they were
+/// left until shown again, and quitting any tab quits them all
+pub async fn run_tabs<B: AppBackend>(
+    mut tabs: Vec<(String, App<B>)>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let clients: Vec<(String, Arc<dyn ApiClient>)> = tabs
+        .iter()
+        .map(|(name, app)| (name.clone(), app.ticket_service.clone()))
+        .collect();
+    for (index, (_, app)) in tabs.iter_mut().enumerate() {
+        app.set_tabs(clients.clone(), index);
+    }
+    let mut shown = 0;
+    while let Some((_, app)) = tabs.get_mut(shown) {
+        app.run().await?;
+        let Some((index, ticket)) = app.take_tab_switch() else {
+            break;
+        };
+        shown = index;
+        if let Some((_, app)) = tabs.get_mut(shown) {
+            app.start_at(None, ticket);
+        }
+    }
+    Ok(())
+}
+
+impl<B: AppBackend> Drop for App<B> {
     fn drop(&mut self) {
         // Restore terminal state
-        let _ = disable_raw_mode();
-        let _ = execute!(
-            self.terminal.backend_mut(),
-            LeaveAlternateScreen,
-            DisableMouseCapture
-        );
+        let _ = self.terminal.backend_mut().restore();
+    }
+}
+
+/// Copy `text` to the clipboard, or write it to `file_name` in the working
+/// directory when there is none; returns the status to show
+fn copy_or_save(text: &str, file_name: &str, what: &str) -> String {
+    match clipboard::copy(text) {
+        Ok(()) => format!("Copied {}", what),
+        Err(e) => {
+            log::warn!("copy_or_save: {}", e);
+            let path: String = file_name
+                .chars()
+                .map(|c| if c.is_alphanumeric() || c == '.' { c } else { '-' })
+                .collect();
+            match std::fs::write(&path, text) {
+                Ok(()) => format!("No clipboard; wrote {} to {}", what, path),
+                Err(e) => format!("Failed to write {}: {}", path, e),
+            }
+        }
     }
 }
 
@@ -0,0 +1,46 @@
+use crossterm::{
+    cursor::MoveTo,
+    event::DisableMouseCapture,
+    execute, queue,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+};
+use ratatui::backend::{Backend, CrosstermBackend, TestBackend};
+use std::io::{self, Write};
+
+/// Terminal backend the app draws on. Besides ratatui's drawing it takes
+/// raw escape sequences, which inline images need, and restores the
+/// terminal when the app exits
+pub trait AppBackend: Backend {
+    /// Write an escape sequence at a cell, or at the cursor when `None`
+    fn write_raw(&mut self, position: Option<(u16, u16)>, sequence: &str) -> io::Result<()>;
+
+    /// Leave raw mode and the alternate screen
+    fn restore(&mut self) -> io::Result<()>;
+}
+
+impl<W: Write> AppBackend for CrosstermBackend<W> {
+    fn write_raw(&mut self, position: Option<(u16, u16)>, sequence: &str) -> io::Result<()> {
+        if let Some((x, y)) = position {
+            queue!(self, MoveTo(x, y))?;
+        }
+        self.write_all(sequence.as_bytes())?;
+        Write::flush(self)
+    }
+
+    fn restore(&mut self) -> io::Result<()> {
+        disable_raw_mode()?;
+        execute!(self, LeaveAlternateScreen, DisableMouseCapture)
+    }
+}
+
+/// Headless backend for tests; raw output is dropped since the buffer
+/// cannot represent it
+impl AppBackend for TestBackend {
+    fn write_raw(&mut self, _position: Option<(u16, u16)>, _sequence: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn restore(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::domain::models::sprint::Sprint;
+use crate::domain::models::user::User;
+use crate::infrastructure::api::client::{LabelChange, Transition};
+use serde_json::Value;
+use std::collections::VecDeque;
+
+/// Number of actions kept in the history
+const HISTORY_LIMIT: usize = 10;
+
+/// A mutating action that can be repeated on another ticket
+#[derive(Debug, Clone)]
+pub enum RepeatableAction {
+    Transition {
+        transition: Transition,
+        fields: Option<Value>,
+        comment: Option<String>,
+    },
+    Label(LabelChange),
+    Assign(User),
+    MoveToSprint(Sprint),
+}
+
+impl RepeatableAction {
+    /// Short description, e.g. for the status bar
+    pub fn describe(&self) -> String {
+        match self {
+            RepeatableAction::Transition { transition, .. } => {
+                format!("{} → {}", transition.name, transition.to_status)
+            }
+            RepeatableAction::Label(LabelChange::Add(label)) => format!("add label '{}'", label),
+            RepeatableAction::Label(LabelChange::Remove(label)) => {
+                format!("remove label '{}'", label)
+            }
+            RepeatableAction::Assign(user) => format!("assign to {}", user.display_name),
+            RepeatableAction::MoveToSprint(sprint) => format!("move to {}", sprint.name),
+        }
+    }
+}
+
+/// Recent mutating actions, newest last, for the repeat key
+#[derive(Debug, Clone, Default)]
+pub struct ActionHistory {
+    actions: VecDeque<RepeatableAction>,
+}
+
+impl ActionHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember an action, dropping the oldest beyond the limit
+    pub fn record(&mut self, action: RepeatableAction) {
+        if self.actions.len() == HISTORY_LIMIT {
+            self.actions.pop_front();
+        }
+        self.actions.push_back(action);
+    }
+
+    /// The most recent action, if any
+    pub fn last(&self) -> Option<&RepeatableAction> {
+        self.actions.back()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_keeps_recent_actions() {
+        let mut history = ActionHistory::new();
+        assert!(history.last().is_none());
+
+        for i in 0..=HISTORY_LIMIT {
+            history.record(RepeatableAction::Label(LabelChange::Add(format!("l{}", i))));
+        }
+        assert_eq!(history.actions.len(), HISTORY_LIMIT);
+        assert_eq!(
+            history.last().map(RepeatableAction::describe).as_deref(),
+            Some("add label 'l10'")
+        );
+        assert!(matches!(
+            history.actions.front(),
+            Some(RepeatableAction::Label(LabelChange::Add(label))) if label == "l1"
+        ));
+    }
+}
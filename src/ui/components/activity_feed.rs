@@ -0,0 +1,119 @@
+use crate::domain::models::activity::{Activity, ActivityKind};
+use crate::ui::components::ticket_detail::format_relative;
+use crate::ui::theme::Theme;
+use crate::utils::time::DisplayTimezone;
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// State of the activity stream
+#[derive(Debug, Clone, Default)]
+pub struct ActivityState {
+    pub activities: Vec<Activity>,
+    pub focused_index: usize,
+}
+
+impl ActivityState {
+    /// Replace the stream, keeping the focus in range across polls
+    pub fn set_activities(&mut self, activities: Vec<Activity>) {
+        self.activities = activities;
+        self.focused_index = self.focused_index.min(self.activities.len().saturating_sub(1));
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.focused_index + 1 < self.activities.len() {
+            self.focused_index += 1;
+        }
+    }
+
+    pub fn focused_key(&self) -> Option<&str> {
+        self.activities.get(self.focused_index).map(|activity| activity.key.as_str())
+    }
+}
+
+/// Recent comments, transitions and assignments, newest first
+pub struct ActivityFeed<'a> {
+    state: &'a ActivityState,
+    now: DateTime<Utc>,
+    timezone: DisplayTimezone,
+    date_format: &'a str,
+    theme: &'a Theme,
+}
+
+impl<'a> ActivityFeed<'a> {
+    pub fn new(
+        state: &'a ActivityState,
+        now: DateTime<Utc>,
+        timezone: DisplayTimezone,
+        date_format: &'a str,
+        theme: &'a Theme,
+    ) -> Self {
+        Self {
+            state,
+            now,
+            timezone,
+            date_format,
+            theme,
+        }
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Activity — [Enter] open [r]efresh")
+            .title_style(self.theme.focused);
+        if self.state.activities.is_empty() {
+            let paragraph = Paragraph::new("No activity in the last week")
+                .style(self.theme.normal)
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .state
+            .activities
+            .iter()
+            .map(|activity| ListItem::new(self.activity_line(activity)))
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(self.theme.selected)
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.state.focused_index));
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+
+    /// "2h ago  PROJ-1  Jane Doe moved In Progress → Done  Fix login"
+    fn activity_line(&self, activity: &'a Activity) -> Line<'a> {
+        let style = match activity.kind {
+            ActivityKind::Comment { .. } => self.theme.normal,
+            ActivityKind::Transition { .. } => self.theme.status_done,
+            ActivityKind::Assignment { .. } => self.theme.warning,
+        };
+        Line::from(vec![
+            Span::styled(
+                format!("{:>9}  ", format_relative(&activity.at, self.now, self.timezone, self.date_format)),
+                self.theme.normal,
+            ),
+            Span::styled(format!("{:<12}", activity.key), self.theme.focused),
+            Span::styled(
+                format!("{} ", activity.author.as_deref().unwrap_or("Automation")),
+                self.theme.focused,
+            ),
+            Span::styled(activity.describe(), style),
+            Span::styled(format!("  {}", activity.summary), self.theme.normal),
+        ])
+    }
+}
@@ -0,0 +1,128 @@
+use crate::domain::models::approval::{Approval, ApprovalDecision};
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// State for answering the approvals of a service desk request
+#[derive(Debug, Clone)]
+pub struct ApprovalListState {
+    pub ticket_key: String,
+    pub approvals: Vec<Approval>,
+    pub selected: usize,
+}
+
+impl ApprovalListState {
+    /// Start on the first approval waiting on me
+    pub fn new(ticket_key: String, approvals: Vec<Approval>) -> Self {
+        let selected = approvals.iter().position(Approval::is_answerable).unwrap_or(0);
+        Self {
+            ticket_key,
+            approvals,
+            selected,
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.approvals.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn selected(&self) -> Option<&Approval> {
+        self.approvals.get(self.selected)
+    }
+}
+
+/// Approval list widget
+pub struct ApprovalList<'a> {
+    state: &'a ApprovalListState,
+    theme: &'a Theme,
+}
+
+impl<'a> ApprovalList<'a> {
+    pub fn new(state: &'a ApprovalListState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the list over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .state
+            .approvals
+            .iter()
+            .map(|approval| {
+                let (label, style) = match approval.decision {
+                    ApprovalDecision::Pending if approval.can_answer => ("waiting on you", self.theme.warning),
+                    ApprovalDecision::Pending => ("pending", self.theme.normal),
+                    ApprovalDecision::Approved => ("approved", self.theme.success),
+                    ApprovalDecision::Declined => ("declined", self.theme.error),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("[{}] ", label), style),
+                    Span::styled(approval.name.clone(), self.theme.focused),
+                    Span::styled(format!(" — {}", approval.approvers_summary()), self.theme.normal),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        "Approvals on {} — [a]pprove [d]ecline [Esc] close",
+                        self.state.ticket_key
+                    ))
+                    .title_style(self.theme.focused),
+            )
+            .highlight_style(self.theme.selected)
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.state.selected));
+
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approval(id: &str, decision: ApprovalDecision, can_answer: bool) -> Approval {
+        Approval {
+            id: id.to_string(),
+            name: format!("Approval {}", id),
+            decision,
+            can_answer,
+            approvers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_approval_list_starts_on_mine() {
+        let mut state = ApprovalListState::new(
+            "HELP-7".to_string(),
+            vec![
+                approval("1", ApprovalDecision::Approved, false),
+                approval("2", ApprovalDecision::Pending, false),
+                approval("3", ApprovalDecision::Pending, true),
+            ],
+        );
+        assert_eq!(state.selected().unwrap().id, "3");
+        state.move_down();
+        assert_eq!(state.selected, 2);
+        state.move_up();
+        state.move_up();
+        assert_eq!(state.selected().unwrap().id, "1");
+    }
+}
@@ -0,0 +1,195 @@
+use crate::domain::models::user::User;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// State for the quick-assign popup
+#[derive(Debug, Clone, Default)]
+pub struct AssigneePickerState {
+    /// Recent assignees, or search results once a search ran
+    pub users: Vec<User>,
+    pub focused_index: Option<usize>,
+    pub query: String,
+    /// Whether keys are typed into the search query
+    pub editing: bool,
+    pub showing_results: bool,
+    /// Whether the chosen user becomes the reporter instead of the assignee
+    pub reporter: bool,
+}
+
+impl AssigneePickerState {
+    /// Open the popup on the recent assignees, starting in search when there are none
+    pub fn new(recent: &[User]) -> Self {
+        let mut state = Self {
+            editing: recent.is_empty(),
+            ..Self::default()
+        };
+        state.set_users(recent.to_vec());
+        state
+    }
+
+    fn set_users(&mut self, users: Vec<User>) {
+        self.users = users;
+        self.focused_index = if self.users.is_empty() { None } else { Some(0) };
+    }
+
+    /// Replace the list with search results
+    pub fn set_results(&mut self, users: Vec<User>) {
+        self.set_users(users);
+        self.showing_results = true;
+        self.editing = false;
+    }
+
+    pub fn move_up(&mut self) {
+        if let Some(idx) = self.focused_index {
+            self.focused_index = Some(idx.saturating_sub(1));
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if let Some(idx) = self.focused_index {
+            if idx + 1 < self.users.len() {
+                self.focused_index = Some(idx + 1);
+            }
+        }
+    }
+
+    pub fn focused_user(&self) -> Option<&User> {
+        self.focused_index.and_then(|idx| self.users.get(idx))
+    }
+
+    /// User for a digit shortcut, 1-based
+    pub fn user_for_digit(&self, digit: char) -> Option<&User> {
+        let number = digit.to_digit(10)? as usize;
+        number.checked_sub(1).and_then(|idx| self.users.get(idx))
+    }
+}
+
+/// Quick-assign popup: numbered recent assignees and a user search field
+pub struct AssigneePicker<'a> {
+    state: &'a AssigneePickerState,
+    ticket_key: &'a str,
+    theme: &'a Theme,
+}
+
+impl<'a> AssigneePicker<'a> {
+    pub fn new(state: &'a AssigneePickerState, ticket_key: &'a str, theme: &'a Theme) -> Self {
+        Self {
+            state,
+            ticket_key,
+            theme,
+        }
+    }
+
+    /// Render the popup over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(if self.state.reporter {
+                format!("Set reporter of {}", self.ticket_key)
+            } else {
+                format!("Assign {}", self.ticket_key)
+            })
+            .title_style(self.theme.focused);
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let cursor = if self.state.editing { "_" } else { "" };
+        let search = Paragraph::new(format!("{}{}", self.state.query, cursor))
+            .style(if self.state.editing {
+                self.theme.selected
+            } else {
+                self.theme.normal
+            })
+            .block(Block::default().borders(Borders::ALL).title("Search users"));
+        frame.render_widget(search, parts[0]);
+
+        if self.state.users.is_empty() {
+            let message = if self.state.showing_results {
+                "No matching users"
+            } else {
+                "No recent assignees — type a name and press Enter"
+            };
+            let paragraph = Paragraph::new(message)
+                .style(self.theme.normal)
+                .alignment(Alignment::Center);
+            frame.render_widget(paragraph, parts[1]);
+        } else {
+            let items: Vec<ListItem> = self
+                .state
+                .users
+                .iter()
+                .enumerate()
+                .map(|(idx, user)| ListItem::new(self.user_line(idx, user)))
+                .collect();
+            let list = List::new(items)
+                .highlight_style(self.theme.selected)
+                .highlight_symbol("> ");
+            let mut list_state = ListState::default();
+            list_state.select(self.state.focused_index);
+            frame.render_stateful_widget(list, parts[1], &mut list_state);
+        }
+
+        let help = if self.state.editing {
+            "[Enter] search [Esc] stop typing"
+        } else {
+            "[1-9/Enter] assign [/] search [Esc] cancel"
+        };
+        frame.render_widget(Paragraph::new(help).style(self.theme.normal), parts[2]);
+    }
+
+    /// A user row, numbered while showing recent assignees
+    fn user_line(&self, idx: usize, user: &'a User) -> Line<'a> {
+        let number = if !self.state.showing_results && idx < 9 {
+            format!("{} ", idx + 1)
+        } else {
+            "  ".to_string()
+        };
+        let mut spans = vec![
+            Span::styled(number, self.theme.focused),
+            Span::styled(user.display_name.as_str(), self.theme.normal),
+        ];
+        if let Some(email) = &user.email_address {
+            spans.push(Span::styled(format!("  {}", email), self.theme.normal));
+        }
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_picker_digits_and_search_fallback() {
+        let recent = vec![
+            User::new("a".to_string(), "Alice".to_string()),
+            User::new("b".to_string(), "Bob".to_string()),
+        ];
+        let state = AssigneePickerState::new(&recent);
+        assert!(!state.editing);
+        assert_eq!(state.user_for_digit('2').map(|u| u.display_name.as_str()), Some("Bob"));
+        assert!(state.user_for_digit('0').is_none());
+        assert!(state.user_for_digit('3').is_none());
+
+        let mut empty = AssigneePickerState::new(&[]);
+        assert!(empty.editing);
+        empty.set_results(recent);
+        assert!(!empty.editing);
+        assert_eq!(empty.focused_user().map(|u| u.account_id.as_str()), Some("a"));
+    }
+}
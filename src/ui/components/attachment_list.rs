@@ -0,0 +1,83 @@
+use crate::domain::models::attachment::Attachment;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// State for the attachment picker in the detail view
+#[derive(Debug, Clone)]
+pub struct AttachmentListState {
+    pub attachments: Vec<Attachment>,
+    pub focused_index: usize,
+}
+
+impl AttachmentListState {
+    pub fn new(attachments: Vec<Attachment>) -> Self {
+        Self {
+            attachments,
+            focused_index: 0,
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.focused_index + 1 < self.attachments.len() {
+            self.focused_index += 1;
+        }
+    }
+
+    pub fn focused(&self) -> Option<&Attachment> {
+        self.attachments.get(self.focused_index)
+    }
+}
+
+/// Attachment picker widget
+pub struct AttachmentList<'a> {
+    state: &'a AttachmentListState,
+    theme: &'a Theme,
+}
+
+impl<'a> AttachmentList<'a> {
+    pub fn new(state: &'a AttachmentListState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the picker over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .state
+            .attachments
+            .iter()
+            .map(|attachment| {
+                let kind = if attachment.is_image() { "image" } else { "file" };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:<6}", kind), self.theme.focused),
+                    Span::styled(attachment.filename.as_str(), self.theme.normal),
+                    Span::styled(format!("  {}", attachment.size_label()), self.theme.normal),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Attachments — [Enter] open [Esc] close")
+                    .title_style(self.theme.focused),
+            )
+            .highlight_style(self.theme.selected)
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.state.focused_index));
+
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
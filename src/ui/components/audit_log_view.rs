@@ -0,0 +1,72 @@
+use crate::infrastructure::storage::audit_log::AuditEntry;
+use crate::ui::theme::Theme;
+use crate::utils::time::DisplayTimezone;
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Screen listing the write operations of this session, newest first
+pub struct AuditLogView<'a> {
+    entries: &'a [AuditEntry],
+    timezone: DisplayTimezone,
+    theme: &'a Theme,
+}
+
+impl<'a> AuditLogView<'a> {
+    pub fn new(entries: &'a [AuditEntry], timezone: DisplayTimezone, theme: &'a Theme) -> Self {
+        Self {
+            entries,
+            timezone,
+            theme,
+        }
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let failed = self.entries.iter().filter(|e| !e.succeeded()).count();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Session audit log — {} change(s), {} failed",
+                self.entries.len(),
+                failed
+            ))
+            .title_style(self.theme.focused);
+
+        if self.entries.is_empty() {
+            let paragraph = Paragraph::new("No changes made this session")
+                .style(self.theme.normal)
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .rev()
+            .map(|entry| ListItem::new(self.entry_line(entry)))
+            .collect();
+        frame.render_widget(List::new(items).block(block), area);
+    }
+
+    /// "12:04:31  PROJ-1  transition 31  ok"
+    fn entry_line(&self, entry: &'a AuditEntry) -> Line<'a> {
+        let result = match &entry.error {
+            None => Span::styled("ok", self.theme.status_done),
+            Some(error) => Span::styled(format!("failed: {}", error), self.theme.error),
+        };
+        Line::from(vec![
+            Span::styled(
+                format!("{}  ", self.timezone.format(&entry.timestamp, "%H:%M:%S")),
+                self.theme.normal,
+            ),
+            Span::styled(format!("{:<12}", entry.target), self.theme.focused),
+            Span::styled(format!("{}  ", entry.action), self.theme.normal),
+            result,
+        ])
+    }
+}
@@ -0,0 +1,66 @@
+use crate::domain::models::user::User;
+use crate::ui::highlight::ColorDepth;
+use crate::ui::theme::Theme;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+/// Badge colors, readable with black initials on top
+const AVATAR_COLORS: [Color; 8] = [
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::LightRed,
+    Color::LightGreen,
+];
+
+/// Stable badge color for an account, so a user keeps the same color everywhere
+pub fn avatar_color(account_id: &str) -> Color {
+    // FNV-1a, which unlike the std hasher is stable across releases
+    let hash = account_id.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    AVATAR_COLORS[(hash % AVATAR_COLORS.len() as u64) as usize]
+}
+
+/// The user's initials in a colored badge, or a reversed one when the
+/// theme shows no colors
+pub fn avatar_span(user: &User, theme: &Theme) -> Span<'static> {
+    let style = match theme.colors {
+        ColorDepth::None => Style::default().add_modifier(Modifier::REVERSED),
+        _ => Style::default()
+            .fg(Color::Black)
+            .bg(avatar_color(&user.account_id)),
+    };
+    Span::styled(
+        format!(" {:<2} ", user.initials()),
+        style.add_modifier(Modifier::BOLD),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_avatar_span_is_stable_per_account() {
+        let alice = User::new("5b10a2844c20165700ede21g".to_string(), "Alice Liddell".to_string());
+        let theme = Theme {
+            colors: ColorDepth::Ansi16,
+            ..Theme::default()
+        };
+        let span = avatar_span(&alice, &theme);
+
+        assert_eq!(span.content, " AL ");
+        assert_eq!(span.style.bg, Some(avatar_color(&alice.account_id)));
+        assert_eq!(avatar_color(&alice.account_id), avatar_color("5b10a2844c20165700ede21g"));
+
+        let span = avatar_span(&alice, &Theme::monochrome());
+        assert_eq!(span.style.bg, None);
+        assert!(span.style.add_modifier.contains(Modifier::REVERSED));
+    }
+}
@@ -0,0 +1,497 @@
+use crate::domain::models::board::{BoardColumnConfig, WipStatus};
+use crate::domain::models::ticket::{StatusCategory, Ticket};
+use crate::ui::theme::Theme;
+use crate::utils::text::{display_width, truncate_to_width};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::collections::HashSet;
+
+/// How tickets are grouped into horizontal lanes on the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwimlaneMode {
+    None,
+    Assignee,
+    Epic,
+}
+
+impl SwimlaneMode {
+    /// Parse the swimlane mode from its config value
+    pub fn from_config(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "assignee" => SwimlaneMode::Assignee,
+            "epic" => SwimlaneMode::Epic,
+            _ => SwimlaneMode::None,
+        }
+    }
+
+    /// Value of the `swimlanes` setting for this mode
+    pub fn config_value(self) -> &'static str {
+        match self {
+            SwimlaneMode::None => "none",
+            SwimlaneMode::Assignee => "assignee",
+            SwimlaneMode::Epic => "epic",
+        }
+    }
+
+    /// Cycle to the next swimlane mode
+    pub fn next(self) -> Self {
+        match self {
+            SwimlaneMode::None => SwimlaneMode::Assignee,
+            SwimlaneMode::Assignee => SwimlaneMode::Epic,
+            SwimlaneMode::Epic => SwimlaneMode::None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SwimlaneMode::None => "No swimlanes",
+            SwimlaneMode::Assignee => "By assignee",
+            SwimlaneMode::Epic => "By epic",
+        }
+    }
+}
+
+/// A board column with the tickets it contains
+#[derive(Debug, Clone)]
+pub struct BoardColumn<'a> {
+    pub name: String,
+    pub tickets: Vec<&'a Ticket>,
+}
+
+/// A horizontal lane of the board
+#[derive(Debug, Clone)]
+pub struct Swimlane<'a> {
+    /// Stable identifier used to remember collapsed lanes
+    pub id: String,
+    pub title: String,
+    pub columns: Vec<BoardColumn<'a>>,
+}
+
+impl Swimlane<'_> {
+    pub fn ticket_count(&self) -> usize {
+        self.columns.iter().map(|c| c.tickets.len()).sum()
+    }
+}
+
+/// State for the board view
+#[derive(Debug, Clone)]
+pub struct BoardViewState {
+    pub swimlane_mode: SwimlaneMode,
+    pub collapsed_lanes: HashSet<String>,
+    pub focused_lane: usize,
+    /// Columns from the board configuration; empty means status categories
+    pub columns: Vec<BoardColumnConfig>,
+}
+
+impl BoardViewState {
+    pub fn new(swimlane_mode: SwimlaneMode) -> Self {
+        Self {
+            swimlane_mode,
+            collapsed_lanes: HashSet::new(),
+            focused_lane: 0,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Cycle the swimlane grouping
+    pub fn cycle_swimlanes(&mut self) {
+        self.swimlane_mode = self.swimlane_mode.next();
+        self.focused_lane = 0;
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_lane = self.focused_lane.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self, lane_count: usize) {
+        if self.focused_lane + 1 < lane_count {
+            self.focused_lane += 1;
+        }
+    }
+
+    /// Collapse or expand a lane
+    pub fn toggle_lane(&mut self, lane_id: &str) {
+        if !self.collapsed_lanes.remove(lane_id) {
+            self.collapsed_lanes.insert(lane_id.to_string());
+        }
+    }
+
+    pub fn is_collapsed(&self, lane_id: &str) -> bool {
+        self.collapsed_lanes.contains(lane_id)
+    }
+}
+
+/// Group tickets into swimlanes, each split into the board's configured
+/// columns, or into status category columns when none are configured.
+/// Tickets whose status is not mapped to any column are left off the board.
+pub fn build_swimlanes<'a>(
+    tickets: &'a [Ticket],
+    mode: SwimlaneMode,
+    columns: &[BoardColumnConfig],
+) -> Vec<Swimlane<'a>> {
+    let mut lanes: Vec<Swimlane> = Vec::new();
+
+    for ticket in tickets {
+        let column_idx = if columns.is_empty() {
+            match ticket.status.category {
+                StatusCategory::ToDo => 0,
+                StatusCategory::InProgress => 1,
+                StatusCategory::Done => 2,
+            }
+        } else {
+            match columns
+                .iter()
+                .position(|column| column.status_ids.contains(&ticket.status.id))
+            {
+                Some(idx) => idx,
+                None => continue,
+            }
+        };
+
+        let (id, title) = lane_for(ticket, mode);
+        let lane_idx = match lanes.iter().position(|lane| lane.id == id) {
+            Some(idx) => idx,
+            None => {
+                lanes.push(Swimlane {
+                    id,
+                    title,
+                    columns: empty_columns(columns),
+                });
+                lanes.len() - 1
+            }
+        };
+
+        lanes[lane_idx].columns[column_idx].tickets.push(ticket);
+    }
+
+    // Keep the catch-all lane ("Unassigned" / "No epic") at the bottom
+    lanes.sort_by_key(|lane| lane.id.is_empty());
+    lanes
+}
+
+fn empty_columns<'a>(columns: &[BoardColumnConfig]) -> Vec<BoardColumn<'a>> {
+    let names: Vec<&str> = if columns.is_empty() {
+        vec!["To Do", "In Progress", "Done"]
+    } else {
+        columns.iter().map(|column| column.name.as_str()).collect()
+    };
+    names
+        .into_iter()
+        .map(|name| BoardColumn {
+            name: name.to_string(),
+            tickets: Vec::new(),
+        })
+        .collect()
+}
+
+fn lane_for(ticket: &Ticket, mode: SwimlaneMode) -> (String, String) {
+    match mode {
+        SwimlaneMode::None => ("all".to_string(), "All issues".to_string()),
+        SwimlaneMode::Assignee => match &ticket.assignee {
+            Some(user) => (user.account_id.clone(), user.display_name.clone()),
+            None => (String::new(), "Unassigned".to_string()),
+        },
+        SwimlaneMode::Epic => match ticket.epic() {
+            Some(epic) => (epic.key.clone(), format!("{} {}", epic.key, epic.summary)),
+            None => (String::new(), "No epic".to_string()),
+        },
+    }
+}
+
+/// Kanban board widget
+pub struct BoardView<'a> {
+    lanes: &'a [Swimlane<'a>],
+    state: &'a BoardViewState,
+    theme: &'a Theme,
+}
+
+impl<'a> BoardView<'a> {
+    pub fn new(lanes: &'a [Swimlane<'a>], state: &'a BoardViewState, theme: &'a Theme) -> Self {
+        Self {
+            lanes,
+            state,
+            theme,
+        }
+    }
+
+    /// Render the board
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Board - {}", self.state.swimlane_mode.label()))
+            .title_style(self.theme.focused);
+        let mut inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.lanes.is_empty() {
+            frame.render_widget(Paragraph::new("No tickets on the board."), inner);
+            return;
+        }
+
+        if self.state.columns.iter().any(BoardColumnConfig::has_limits) {
+            let parts = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(inner);
+            self.render_wip_limits(frame, parts[0]);
+            inner = parts[1];
+        }
+
+        // Lane heights: collapsed lanes only show their header
+        let mut constraints = Vec::new();
+        let mut visible = Vec::new();
+        let mut used = 0u16;
+        let first = self.state.focused_lane.min(self.lanes.len() - 1);
+        for lane in &self.lanes[first..] {
+            let height = if self.state.is_collapsed(&lane.id) {
+                1
+            } else {
+                let rows = lane.columns.iter().map(|c| c.tickets.len()).max().unwrap_or(0);
+                (rows as u16 + 2).max(3)
+            };
+            if used + height > inner.height && !visible.is_empty() {
+                break;
+            }
+            used += height;
+            constraints.push(Constraint::Length(height));
+            visible.push(lane);
+        }
+        constraints.push(Constraint::Min(0));
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner);
+
+        for (offset, lane) in visible.iter().enumerate() {
+            let is_focused = first + offset == self.state.focused_lane;
+            self.render_lane(frame, rows[offset], lane, is_focused);
+        }
+    }
+
+    /// Issues per column across all lanes, which is what WIP limits count
+    fn column_totals(&self) -> Vec<usize> {
+        let mut totals = Vec::new();
+        for lane in self.lanes {
+            totals.resize(lane.columns.len(), 0);
+            for (total, column) in totals.iter_mut().zip(&lane.columns) {
+                *total += column.tickets.len();
+            }
+        }
+        totals
+    }
+
+    /// Red above the column maximum, yellow below its minimum
+    fn wip_style(&self, column_idx: usize, total: usize) -> Style {
+        match self.state.columns.get(column_idx).map(|c| c.wip_status(total)) {
+            Some(WipStatus::AboveMax) => self.theme.error,
+            Some(WipStatus::BelowMin) => self.theme.warning,
+            _ => self.theme.normal,
+        }
+    }
+
+    /// Render one line with each column's issue count against its limits
+    fn render_wip_limits(&self, frame: &mut Frame, area: Rect) {
+        let column_areas = column_layout(self.state.columns.len(), area);
+        let totals = self.column_totals();
+        for (idx, (column, column_area)) in self.state.columns.iter().zip(column_areas.iter()).enumerate() {
+            let total = totals.get(idx).copied().unwrap_or(0);
+            let count = match (column.min, column.max) {
+                (Some(min), Some(max)) => format!("{} ({}–{})", total, min, max),
+                (None, Some(max)) => format!("{}/{}", total, max),
+                (Some(min), None) => format!("{} (min {})", total, min),
+                (None, None) => total.to_string(),
+            };
+            let line = Paragraph::new(Line::from(Span::styled(
+                format!("{} {}", column.name, count),
+                self.wip_style(idx, total),
+            )));
+            frame.render_widget(line, *column_area);
+        }
+    }
+
+    /// Render a single lane: a header line followed by its columns
+    fn render_lane(&self, frame: &mut Frame, area: Rect, lane: &Swimlane, is_focused: bool) {
+        let collapsed = self.state.is_collapsed(&lane.id);
+        let marker = if collapsed { "▶" } else { "▼" };
+        let header_style = if is_focused {
+            self.theme.selected
+        } else {
+            self.theme.focused
+        };
+        let header = Paragraph::new(Line::from(Span::styled(
+            format!("{} {} ({})", marker, lane.title, lane.ticket_count()),
+            header_style,
+        )));
+
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        frame.render_widget(header, parts[0]);
+
+        if collapsed {
+            return;
+        }
+
+        let column_areas = column_layout(lane.columns.len(), parts[1]);
+        let totals = self.column_totals();
+
+        for (idx, (column, column_area)) in lane.columns.iter().zip(column_areas.iter()).enumerate() {
+            let width = column_area.width as usize;
+            let lines: Vec<Line> = column
+                .tickets
+                .iter()
+                .map(|ticket| {
+                    let key = format!("{} ", ticket.key);
+                    let summary_width = width.saturating_sub(display_width(&key));
+                    Line::from(vec![
+                        Span::styled(key, self.theme.focused),
+                        Span::styled(truncate_to_width(&ticket.summary, summary_width), self.theme.normal),
+                    ])
+                })
+                .collect();
+            let paragraph = Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::TOP)
+                    .title(format!("{} ({})", column.name, column.tickets.len()))
+                    .title_style(self.wip_style(idx, totals.get(idx).copied().unwrap_or(0))),
+            );
+            frame.render_widget(paragraph, *column_area);
+        }
+    }
+}
+
+/// Split `area` into equal-width columns
+fn column_layout(count: usize, area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            (0..count)
+                .map(|_| Constraint::Ratio(1, count as u32))
+                .collect::<Vec<_>>(),
+        )
+        .split(area)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::{IssueRef, Status};
+    use crate::domain::models::user::User;
+
+    fn create_test_ticket(key: &str, category: StatusCategory) -> Ticket {
+        Ticket::new(
+            key.to_string(),
+            format!("Summary {}", key),
+            Status {
+                id: "1".to_string(),
+                name: "Status".to_string(),
+                category,
+            },
+        )
+    }
+
+    #[test]
+    fn test_swimlane_mode_from_config() {
+        assert_eq!(SwimlaneMode::from_config("assignee"), SwimlaneMode::Assignee);
+        assert_eq!(SwimlaneMode::from_config("Epic"), SwimlaneMode::Epic);
+        assert_eq!(SwimlaneMode::from_config("none"), SwimlaneMode::None);
+        assert_eq!(SwimlaneMode::from_config("bogus"), SwimlaneMode::None);
+        assert_eq!(SwimlaneMode::None.next().next().next(), SwimlaneMode::None);
+    }
+
+    #[test]
+    fn test_build_swimlanes_without_grouping() {
+        let tickets = vec![
+            create_test_ticket("P-1", StatusCategory::ToDo),
+            create_test_ticket("P-2", StatusCategory::InProgress),
+            create_test_ticket("P-3", StatusCategory::Done),
+        ];
+        let lanes = build_swimlanes(&tickets, SwimlaneMode::None, &[]);
+        assert_eq!(lanes.len(), 1);
+        assert_eq!(lanes[0].ticket_count(), 3);
+        assert_eq!(lanes[0].columns[1].tickets[0].key, "P-2");
+    }
+
+    #[test]
+    fn test_build_swimlanes_by_assignee() {
+        let mut assigned = create_test_ticket("P-1", StatusCategory::ToDo);
+        assigned.assignee = Some(User::new("u1".to_string(), "Alice".to_string()));
+        let unassigned = create_test_ticket("P-2", StatusCategory::ToDo);
+        let tickets = vec![unassigned, assigned];
+
+        let lanes = build_swimlanes(&tickets, SwimlaneMode::Assignee, &[]);
+        assert_eq!(lanes.len(), 2);
+        assert_eq!(lanes[0].title, "Alice");
+        assert_eq!(lanes[1].title, "Unassigned");
+    }
+
+    #[test]
+    fn test_build_swimlanes_by_epic() {
+        let mut in_epic = create_test_ticket("P-1", StatusCategory::Done);
+        in_epic.parent = Some(IssueRef {
+            key: "P-100".to_string(),
+            summary: "Login".to_string(),
+            issue_type: "Epic".to_string(),
+        });
+        let mut subtask = create_test_ticket("P-2", StatusCategory::ToDo);
+        subtask.parent = Some(IssueRef {
+            key: "P-1".to_string(),
+            summary: "Story".to_string(),
+            issue_type: "Story".to_string(),
+        });
+        let tickets = vec![in_epic, subtask];
+
+        let lanes = build_swimlanes(&tickets, SwimlaneMode::Epic, &[]);
+        assert_eq!(lanes.len(), 2);
+        assert_eq!(lanes[0].id, "P-100");
+        assert_eq!(lanes[0].columns[2].tickets.len(), 1);
+        assert_eq!(lanes[1].title, "No epic");
+    }
+
+    #[test]
+    fn test_build_swimlanes_with_board_columns() {
+        let mut in_review = create_test_ticket("P-1", StatusCategory::InProgress);
+        in_review.status.id = "10101".to_string();
+        let mut unmapped = create_test_ticket("P-2", StatusCategory::InProgress);
+        unmapped.status.id = "999".to_string();
+        let tickets = vec![in_review, unmapped];
+        let columns = vec![
+            BoardColumnConfig {
+                name: "In Progress".to_string(),
+                status_ids: vec!["3".to_string()],
+                min: None,
+                max: None,
+            },
+            BoardColumnConfig {
+                name: "In Review".to_string(),
+                status_ids: vec!["10101".to_string()],
+                min: None,
+                max: None,
+            },
+        ];
+
+        let lanes = build_swimlanes(&tickets, SwimlaneMode::None, &columns);
+        assert_eq!(lanes.len(), 1);
+        assert_eq!(lanes[0].columns.len(), 2);
+        assert_eq!(lanes[0].columns[1].name, "In Review");
+        assert_eq!(lanes[0].columns[1].tickets[0].key, "P-1");
+        assert_eq!(lanes[0].ticket_count(), 1);
+    }
+
+    #[test]
+    fn test_toggle_lane() {
+        let mut state = BoardViewState::new(SwimlaneMode::Assignee);
+        assert!(!state.is_collapsed("u1"));
+        state.toggle_lane("u1");
+        assert!(state.is_collapsed("u1"));
+        state.toggle_lane("u1");
+        assert!(!state.is_collapsed("u1"));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph, Wrap},
+    Frame,
+};
+
+/// Progress of an operation applied to several tickets one by one
+#[derive(Debug, Clone)]
+pub struct BulkProgressState {
+    pub title: String,
+    pub total: usize,
+    pub done: usize,
+    /// Ticket key and error message for each failed ticket
+    pub failures: Vec<(String, String)>,
+}
+
+impl BulkProgressState {
+    pub fn new(title: String, total: usize) -> Self {
+        Self {
+            title,
+            total,
+            done: 0,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Record the outcome for one ticket
+    pub fn record<E: std::fmt::Display>(&mut self, key: &str, result: Result<(), E>) {
+        self.done += 1;
+        if let Err(e) = result {
+            self.failures.push((key.to_string(), e.to_string()));
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.done >= self.total
+    }
+
+    pub fn succeeded(&self) -> usize {
+        self.done - self.failures.len()
+    }
+
+    /// One-line outcome, e.g. for the status bar
+    pub fn summary(&self) -> String {
+        if self.failures.is_empty() {
+            format!("{}: {} issue(s) updated", self.title, self.succeeded())
+        } else {
+            format!(
+                "{}: {} updated, {} failed",
+                self.title,
+                self.succeeded(),
+                self.failures.len()
+            )
+        }
+    }
+}
+
+/// Popup showing bulk progress and per-ticket errors
+pub struct BulkProgress<'a> {
+    state: &'a BulkProgressState,
+    theme: &'a Theme,
+}
+
+impl<'a> BulkProgress<'a> {
+    pub fn new(state: &'a BulkProgressState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the popup over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.state.title.as_str())
+            .title_style(self.theme.focused);
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(inner);
+
+        let ratio = if self.state.total == 0 {
+            1.0
+        } else {
+            self.state.done as f64 / self.state.total as f64
+        };
+        let gauge = Gauge::default()
+            .gauge_style(self.theme.status_done)
+            .ratio(ratio.min(1.0))
+            .label(format!("{}/{}", self.state.done, self.state.total));
+        frame.render_widget(gauge, parts[0]);
+
+        let mut lines: Vec<Line> = self
+            .state
+            .failures
+            .iter()
+            .map(|(key, error)| {
+                Line::from(vec![
+                    Span::styled(format!("{} ", key), self.theme.error),
+                    Span::styled(error.as_str(), self.theme.normal),
+                ])
+            })
+            .collect();
+        if self.state.is_finished() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("{} — press any key to close", self.state.summary()),
+                self.theme.normal,
+            )));
+        }
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), parts[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bulk_progress_records_failures() {
+        let mut state = BulkProgressState::new("Add label 'x'".to_string(), 3);
+        state.record::<String>("P-1", Ok(()));
+        state.record("P-2", Err("Forbidden"));
+        assert!(!state.is_finished());
+        state.record::<String>("P-3", Ok(()));
+
+        assert!(state.is_finished());
+        assert_eq!(state.succeeded(), 2);
+        assert_eq!(state.failures, vec![("P-2".to_string(), "Forbidden".to_string())]);
+        assert_eq!(state.summary(), "Add label 'x': 2 updated, 1 failed");
+    }
+}
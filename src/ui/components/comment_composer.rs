@@ -0,0 +1,181 @@
+use crate::domain::models::comment::CommentVisibility;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// State for the comment composer in the detail view
+#[derive(Debug, Clone)]
+pub struct CommentComposerState {
+    pub ticket_key: String,
+    pub input: String,
+    /// Visibilities to choose from, public first
+    pub visibilities: Vec<CommentVisibility>,
+    pub visibility_index: usize,
+    /// Whether the ticket is a service desk request, whose public comments
+    /// are shared with the customer
+    pub service_desk: bool,
+}
+
+impl CommentComposerState {
+    /// Offer public, internal on service desk requests, then the given
+    /// project roles and groups
+    pub fn new(
+        ticket_key: String,
+        service_desk: bool,
+        roles: Vec<String>,
+        groups: Vec<String>,
+    ) -> Self {
+        let mut visibilities = vec![CommentVisibility::Public];
+        if service_desk {
+            visibilities.push(CommentVisibility::Internal);
+        }
+        visibilities.extend(roles.into_iter().map(CommentVisibility::Role));
+        visibilities.extend(groups.into_iter().map(CommentVisibility::Group));
+
+        Self {
+            ticket_key,
+            input: String::new(),
+            visibilities,
+            visibility_index: 0,
+            service_desk,
+        }
+    }
+
+    pub fn visibility(&self) -> &CommentVisibility {
+        &self.visibilities[self.visibility_index]
+    }
+
+    /// Label of the chosen visibility, telling customer-visible comments
+    /// apart on service desk requests
+    pub fn visibility_label(&self) -> String {
+        if self.service_desk {
+            self.visibility().service_desk_label()
+        } else {
+            self.visibility().label()
+        }
+    }
+
+    pub fn cycle_visibility(&mut self, forward: bool) {
+        let count = self.visibilities.len();
+        self.visibility_index = if forward {
+            (self.visibility_index + 1) % count
+        } else {
+            (self.visibility_index + count - 1) % count
+        };
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.input.push(c);
+    }
+
+    pub fn pop(&mut self) {
+        self.input.pop();
+    }
+
+    /// The comment to post, if anything was typed
+    pub fn comment(&self) -> Option<String> {
+        let comment = self.input.trim();
+        if comment.is_empty() {
+            None
+        } else {
+            Some(comment.to_string())
+        }
+    }
+}
+
+/// Comment composer widget
+pub struct CommentComposer<'a> {
+    state: &'a CommentComposerState,
+    theme: &'a Theme,
+}
+
+impl<'a> CommentComposer<'a> {
+    pub fn new(state: &'a CommentComposerState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the composer over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Comment on {}", self.state.ticket_key))
+            .title_style(self.theme.focused);
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let visibility = self.state.visibility();
+        let visibility_style = if visibility.is_public() {
+            self.theme.normal
+        } else {
+            self.theme.warning
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("Visibility: ", self.theme.focused),
+                Span::styled(format!("◀ {} ▶", self.state.visibility_label()), visibility_style),
+            ])),
+            parts[0],
+        );
+
+        frame.render_widget(
+            Paragraph::new(format!("{}_", self.state.input))
+                .style(self.theme.selected)
+                .wrap(Wrap { trim: false }),
+            parts[1],
+        );
+
+        let help = "[Tab] visibility [Enter] post [Esc] cancel";
+        frame.render_widget(Paragraph::new(help).style(self.theme.normal), parts[2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_composer_cycles_visibility() {
+        let mut state = CommentComposerState::new(
+            "PROJ-1".to_string(),
+            true,
+            vec!["Developers".to_string()],
+            vec!["jira-users".to_string()],
+        );
+        assert!(state.visibility().is_public());
+        assert_eq!(state.visibility_label(), "customer-visible");
+        assert_eq!(state.comment(), None);
+
+        state.cycle_visibility(true);
+        assert_eq!(state.visibility(), &CommentVisibility::Internal);
+        state.cycle_visibility(true);
+        assert_eq!(state.visibility(), &CommentVisibility::Role("Developers".to_string()));
+        state.cycle_visibility(false);
+        state.cycle_visibility(false);
+        state.cycle_visibility(false);
+        assert_eq!(state.visibility(), &CommentVisibility::Group("jira-users".to_string()));
+
+        for c in "Looks good ".chars() {
+            state.push(c);
+        }
+        assert_eq!(state.comment().as_deref(), Some("Looks good"));
+
+        // Outside a service desk there are no customers to hide comments from
+        let state = CommentComposerState::new("PROJ-1".to_string(), false, Vec::new(), Vec::new());
+        assert_eq!(state.visibilities, vec![CommentVisibility::Public]);
+        assert_eq!(state.visibility_label(), "public");
+    }
+}
@@ -0,0 +1,142 @@
+use crate::infrastructure::api::client::Transition;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use serde_json::Value;
+
+/// When to prompt for a comment while transitioning a ticket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentMode {
+    /// Always prompt and require a comment
+    Always,
+    /// Prompt, but allow submitting without a comment
+    Ask,
+    Never,
+}
+
+impl CommentMode {
+    /// Parse the comment mode from its config value
+    pub fn from_config(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "always" => CommentMode::Always,
+            "never" => CommentMode::Never,
+            _ => CommentMode::Ask,
+        }
+    }
+}
+
+/// State for the comment prompt shown before a transition runs
+#[derive(Debug, Clone)]
+pub struct CommentPromptState {
+    pub ticket_key: String,
+    pub transition: Transition,
+    /// Screen fields already filled in for the transition
+    pub fields: Option<Value>,
+    pub input: String,
+    pub required: bool,
+}
+
+impl CommentPromptState {
+    pub fn new(
+        ticket_key: String,
+        transition: Transition,
+        fields: Option<Value>,
+        required: bool,
+    ) -> Self {
+        Self {
+            ticket_key,
+            transition,
+            fields,
+            input: String::new(),
+            required,
+        }
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.input.push(c);
+    }
+
+    pub fn pop(&mut self) {
+        self.input.pop();
+    }
+
+    /// The entered comment, if any
+    pub fn comment(&self) -> Option<String> {
+        let comment = self.input.trim();
+        if comment.is_empty() {
+            None
+        } else {
+            Some(comment.to_string())
+        }
+    }
+}
+
+/// Comment prompt widget
+pub struct CommentPrompt<'a> {
+    state: &'a CommentPromptState,
+    theme: &'a Theme,
+}
+
+impl<'a> CommentPrompt<'a> {
+    pub fn new(state: &'a CommentPromptState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the prompt over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let help = if self.state.required {
+            "[Enter] transition [Esc] cancel — a comment is required"
+        } else {
+            "[Enter] transition (empty to skip) [Esc] cancel"
+        };
+        let lines = vec![
+            Line::from(Span::styled(format!("{}_", self.state.input), self.theme.selected)),
+            Line::from(""),
+            Line::from(Span::styled(help, self.theme.normal)),
+        ];
+
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "Comment on {} → {}",
+                    self.state.ticket_key, self.state.transition.to_status
+                ))
+                .title_style(self.theme.focused),
+        );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_mode_and_prompt_input() {
+        assert_eq!(CommentMode::from_config("Always"), CommentMode::Always);
+        assert_eq!(CommentMode::from_config("never"), CommentMode::Never);
+        assert_eq!(CommentMode::from_config("anything"), CommentMode::Ask);
+
+        let transition = Transition {
+            id: "21".to_string(),
+            name: "Done".to_string(),
+            to_status: "Done".to_string(),
+            fields: Vec::new(),
+        };
+        let mut state = CommentPromptState::new("PROJ-1".to_string(), transition, None, false);
+        state.push(' ');
+        assert_eq!(state.comment(), None);
+        for c in "ok!".chars() {
+            state.push(c);
+        }
+        state.pop();
+        assert_eq!(state.comment().as_deref(), Some("ok"));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Modal yes/no confirmation popup drawn over the current view
+pub struct ConfirmDialog<'a> {
+    title: &'a str,
+    message: &'a str,
+    theme: &'a Theme,
+}
+
+impl<'a> ConfirmDialog<'a> {
+    pub fn new(title: &'a str, message: &'a str, theme: &'a Theme) -> Self {
+        Self {
+            title,
+            message,
+            theme,
+        }
+    }
+
+    /// Render the dialog centered in the given area
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let popup = centered_rect(60, 40, area);
+
+        let lines = vec![
+            Line::from(self.message),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("[y]", self.theme.focused),
+                Span::styled(" confirm   ", self.theme.normal),
+                Span::styled("[n/Esc]", self.theme.focused),
+                Span::styled(" cancel", self.theme.normal),
+            ]),
+        ];
+
+        let paragraph = Paragraph::new(lines)
+            .style(self.theme.normal)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.title)
+                    .title_style(self.theme.warning),
+            );
+
+        frame.render_widget(Clear, popup);
+        frame.render_widget(paragraph, popup);
+    }
+}
+
+/// Compute a rectangle centered in `area` using percentages of its size
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_centered_rect_is_inside_area() {
+        let area = Rect::new(0, 0, 100, 50);
+        let popup = centered_rect(60, 40, area);
+        assert_eq!(popup.width, 60);
+        assert_eq!(popup.height, 20);
+        assert_eq!(popup.x, 20);
+        assert_eq!(popup.y, 15);
+    }
+}
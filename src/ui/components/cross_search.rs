@@ -0,0 +1,117 @@
+use crate::domain::services::cross_search_service::{CrossSearchHit, CrossSearchResult};
+use crate::ui::theme::Theme;
+use crate::utils::text::truncate_to_width;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Width of the instance column
+const INSTANCE_WIDTH: usize = 14;
+
+/// State of the search across instances
+#[derive(Debug, Clone, Default)]
+pub struct CrossSearchState {
+    pub query: String,
+    /// Typing goes to the query rather than moving through the results
+    pub editing: bool,
+    pub result: CrossSearchResult,
+    pub focused_index: usize,
+}
+
+impl CrossSearchState {
+    pub fn set_result(&mut self, result: CrossSearchResult) {
+        self.result = result;
+        self.focused_index = 0;
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.focused_index + 1 < self.result.hits.len() {
+            self.focused_index += 1;
+        }
+    }
+
+    pub fn focused_hit(&self) -> Option<&CrossSearchHit> {
+        self.result.hits.get(self.focused_index)
+    }
+}
+
+/// Query line over the merged results of all instances
+pub struct CrossSearchView<'a> {
+    state: &'a CrossSearchState,
+    theme: &'a Theme,
+}
+
+impl<'a> CrossSearchView<'a> {
+    pub fn new(state: &'a CrossSearchState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .split(area);
+
+        let (query, style, keys) = if self.state.editing {
+            (format!("{}_", self.state.query), self.theme.selected, "[Enter] search [Esc] done")
+        } else {
+            (self.state.query.clone(), self.theme.normal, "[/] edit [Enter] open [Esc] back")
+        };
+        let input = Paragraph::new(Line::from(vec![
+            Span::styled("JQL or text: ", self.theme.focused),
+            Span::styled(query, style),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Search all instances — {}", keys))
+                .title_style(self.theme.focused),
+        );
+        frame.render_widget(input, chunks[0]);
+
+        let hits = &self.state.result.hits;
+        let mut title = format!("Results ({})", hits.len());
+        for (instance, error) in &self.state.result.failures {
+            title.push_str(&format!(" — {} failed: {}", instance, error));
+        }
+        let block = Block::default().borders(Borders::ALL).title(title);
+        if hits.is_empty() {
+            let paragraph = Paragraph::new("No issues found")
+                .style(self.theme.normal)
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(paragraph, chunks[1]);
+            return;
+        }
+
+        let items: Vec<ListItem> = hits
+            .iter()
+            .map(|hit| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$} ", truncate_to_width(&hit.instance, INSTANCE_WIDTH), width = INSTANCE_WIDTH),
+                        self.theme.warning,
+                    ),
+                    Span::styled(format!("{:<12}", hit.ticket.key), self.theme.focused),
+                    Span::styled(format!("[{}] ", hit.ticket.status.name), self.theme.normal),
+                    Span::styled(hit.ticket.summary.trim().to_string(), self.theme.normal),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(self.theme.selected)
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.state.focused_index));
+        frame.render_stateful_widget(list, chunks[1], &mut list_state);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::domain::services::dashboard_service::DashboardTile;
+use crate::domain::services::filter_service::TicketFilter;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Number of tiles per dashboard row
+pub const TILES_PER_ROW: usize = 4;
+
+/// State for the dashboard view
+#[derive(Debug, Clone, Default)]
+pub struct DashboardState {
+    pub focused_tile: usize,
+}
+
+impl DashboardState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn move_left(&mut self) {
+        self.focused_tile = self.focused_tile.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self, tile_count: usize) {
+        if self.focused_tile + 1 < tile_count {
+            self.focused_tile += 1;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_tile = self.focused_tile.saturating_sub(TILES_PER_ROW);
+    }
+
+    pub fn move_down(&mut self, tile_count: usize) {
+        if self.focused_tile + TILES_PER_ROW < tile_count {
+            self.focused_tile += TILES_PER_ROW;
+        }
+    }
+}
+
+/// Dashboard widget: a grid of count tiles
+pub struct Dashboard<'a> {
+    tiles: &'a [DashboardTile],
+    state: &'a DashboardState,
+    theme: &'a Theme,
+}
+
+impl<'a> Dashboard<'a> {
+    pub fn new(tiles: &'a [DashboardTile], state: &'a DashboardState, theme: &'a Theme) -> Self {
+        Self {
+            tiles,
+            state,
+            theme,
+        }
+    }
+
+    /// Render the tile grid
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Dashboard — [Enter] drill in [r]efresh")
+            .title_style(self.theme.focused);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let row_count = self.tiles.len().div_ceil(TILES_PER_ROW);
+        if row_count == 0 {
+            return;
+        }
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                (0..row_count)
+                    .map(|_| Constraint::Length(5))
+                    .chain(std::iter::once(Constraint::Min(0)))
+                    .collect::<Vec<_>>(),
+            )
+            .split(inner);
+
+        for (row_idx, row_tiles) in self.tiles.chunks(TILES_PER_ROW).enumerate() {
+            let cells = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(
+                    (0..TILES_PER_ROW)
+                        .map(|_| Constraint::Ratio(1, TILES_PER_ROW as u32))
+                        .collect::<Vec<_>>(),
+                )
+                .split(rows[row_idx]);
+
+            for (col_idx, tile) in row_tiles.iter().enumerate() {
+                let idx = row_idx * TILES_PER_ROW + col_idx;
+                self.render_tile(frame, cells[col_idx], tile, idx == self.state.focused_tile);
+            }
+        }
+    }
+
+    /// Render a single tile with its count
+    fn render_tile(&self, frame: &mut Frame, area: Rect, tile: &DashboardTile, is_focused: bool) {
+        let border_style = if is_focused {
+            self.theme.selected
+        } else {
+            self.theme.normal
+        };
+        let count_style = if tile.count > 0 && tile.filter == TicketFilter::Overdue {
+            self.theme.error
+        } else {
+            self.theme.focused
+        };
+
+        let paragraph = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled(tile.count.to_string(), count_style)),
+        ])
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(border_style)
+                .title(tile.label.as_str()),
+        );
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dashboard_navigation_stays_in_bounds() {
+        let mut state = DashboardState::new();
+        state.move_left();
+        state.move_up();
+        assert_eq!(state.focused_tile, 0);
+
+        state.move_down(6);
+        assert_eq!(state.focused_tile, 4);
+        state.move_down(6);
+        assert_eq!(state.focused_tile, 4);
+
+        state.move_right(6);
+        state.move_right(6);
+        assert_eq!(state.focused_tile, 5);
+
+        state.move_up();
+        assert_eq!(state.focused_tile, 1);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::domain::models::ticket::StatusCategory;
+use crate::domain::services::dependency_service::{
+    DependencyDirection, DependencyGraph, DependencyRow,
+};
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// A line of the dependency popup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphLine {
+    Heading(&'static str),
+    /// The focused issue, between its blockers and what it blocks
+    Root,
+    Issue(DependencyRow),
+}
+
+/// State for the graph of blocker chains around the focused issue
+#[derive(Debug, Clone)]
+pub struct DependencyGraphState {
+    pub graph: DependencyGraph,
+    pub lines: Vec<GraphLine>,
+    /// Index into `lines`, never on a heading
+    pub selected: usize,
+}
+
+impl DependencyGraphState {
+    pub fn new(graph: DependencyGraph) -> Self {
+        let mut lines = Vec::new();
+        let blocked_by = graph.rows(DependencyDirection::BlockedBy);
+        if !blocked_by.is_empty() {
+            lines.push(GraphLine::Heading("Blocked by"));
+            lines.extend(blocked_by.into_iter().map(GraphLine::Issue));
+        }
+        lines.push(GraphLine::Root);
+        let blocks = graph.rows(DependencyDirection::Blocks);
+        if !blocks.is_empty() {
+            lines.push(GraphLine::Heading("Blocks"));
+            lines.extend(blocks.into_iter().map(GraphLine::Issue));
+        }
+        let selected = lines.iter().position(|line| *line == GraphLine::Root).unwrap_or(0);
+        Self {
+            graph,
+            lines,
+            selected,
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        if let Some(i) = (0..self.selected).rev().find(|&i| self.is_issue(i)) {
+            self.selected = i;
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if let Some(i) = (self.selected + 1..self.lines.len()).find(|&i| self.is_issue(i)) {
+            self.selected = i;
+        }
+    }
+
+    fn is_issue(&self, index: usize) -> bool {
+        !matches!(self.lines.get(index), Some(GraphLine::Heading(_)) | None)
+    }
+
+    /// Key of the issue on the selected line
+    pub fn selected_key(&self) -> Option<&str> {
+        match self.lines.get(self.selected)? {
+            GraphLine::Root => Some(&self.graph.root),
+            GraphLine::Issue(row) => Some(&row.key),
+            GraphLine::Heading(_) => None,
+        }
+    }
+}
+
+/// Popup drawing the blocker chains as trees above and below the issue
+pub struct DependencyGraphView<'a> {
+    state: &'a DependencyGraphState,
+    theme: &'a Theme,
+}
+
+impl<'a> DependencyGraphView<'a> {
+    pub fn new(state: &'a DependencyGraphState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the graph over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .state
+            .lines
+            .iter()
+            .map(|line| match line {
+                GraphLine::Heading(heading) => {
+                    ListItem::new(Line::from(Span::styled(*heading, self.theme.focused)))
+                }
+                GraphLine::Root => ListItem::new(self.issue_line(&self.state.graph.root, "● ")),
+                GraphLine::Issue(row) => {
+                    let mut line = self.issue_line(&row.key, &format!("  {}", row.prefix));
+                    if row.repeated {
+                        line.spans.push(Span::styled(" (see above)", self.theme.normal));
+                    } else if row.truncated {
+                        line.spans.push(Span::styled(" …", self.theme.normal));
+                    }
+                    ListItem::new(line)
+                }
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        "Dependencies of {} — [Enter] open [Esc] close",
+                        self.state.graph.root
+                    ))
+                    .title_style(self.theme.focused),
+            )
+            .highlight_style(self.theme.selected);
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.state.selected));
+
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+
+    /// Key, status and summary of an issue after `prefix`
+    fn issue_line(&self, key: &str, prefix: &str) -> Line<'static> {
+        let Some(node) = self.state.graph.node(key) else {
+            return Line::from(format!("{}{}", prefix, key));
+        };
+        let category = match node.status.category {
+            StatusCategory::ToDo => "new",
+            StatusCategory::InProgress => "indeterminate",
+            StatusCategory::Done => "done",
+        };
+        Line::from(vec![
+            Span::styled(prefix.to_string(), self.theme.normal),
+            Span::styled(
+                format!("{}{} ", self.theme.status_symbol(category), node.key),
+                self.theme.status_style(category),
+            ),
+            Span::styled(format!("[{}] ", node.status.name), self.theme.status_style(category)),
+            Span::styled(node.summary.clone(), self.theme.normal),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::issue_link::IssueLink;
+    use crate::domain::models::ticket::{IssueRef, Status, Ticket};
+
+    #[test]
+    fn test_selection_skips_headings() {
+        let status = Status {
+            id: "1".to_string(),
+            name: "To Do".to_string(),
+            category: StatusCategory::ToDo,
+        };
+        let mut ticket = Ticket::new("PROJ-1".to_string(), "Checkout".to_string(), status.clone());
+        ticket.links = [("PROJ-2", false), ("PROJ-3", true)]
+            .into_iter()
+            .map(|(key, outward)| IssueLink {
+                id: key.to_string(),
+                link_type: "Blocks".to_string(),
+                description: String::new(),
+                outward,
+                issue: IssueRef {
+                    key: key.to_string(),
+                    summary: String::new(),
+                    issue_type: "Task".to_string(),
+                },
+                status: status.clone(),
+            })
+            .collect();
+
+        let mut state = DependencyGraphState::new(DependencyGraph::new(&ticket));
+        assert_eq!(state.lines.len(), 5);
+        assert_eq!(state.selected_key(), Some("PROJ-1"));
+        state.move_down();
+        assert_eq!(state.selected_key(), Some("PROJ-3"));
+        state.move_up();
+        state.move_up();
+        state.move_up();
+        assert_eq!(state.selected_key(), Some("PROJ-2"));
+    }
+}
@@ -0,0 +1,283 @@
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Where a search of the detail view found its text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailMatch {
+    /// A line of the description as shown
+    Description { line: usize },
+    /// A comment, by its position in the list
+    Comment { index: usize },
+}
+
+/// State for searching the description and comments of the ticket in the
+/// detail view
+#[derive(Debug, Clone, Default)]
+pub struct DetailSearchState {
+    pub query: String,
+    /// The query is being typed; once kept, n and N step through the matches
+    pub typing: bool,
+    pub matches: Vec<DetailMatch>,
+    pub current: usize,
+}
+
+impl DetailSearchState {
+    pub fn new() -> Self {
+        Self {
+            typing: true,
+            ..Self::default()
+        }
+    }
+
+    /// Replace the matches, starting over from the first
+    pub fn set_matches(&mut self, matches: Vec<DetailMatch>) {
+        self.matches = matches;
+        self.current = 0;
+    }
+
+    pub fn current_match(&self) -> Option<DetailMatch> {
+        self.matches.get(self.current).copied()
+    }
+
+    /// Step to the next match, or the previous one, wrapping around
+    pub fn step(&mut self, forward: bool) {
+        let len = self.matches.len();
+        if len == 0 {
+            return;
+        }
+        self.current = if forward {
+            (self.current + 1) % len
+        } else {
+            (self.current + len - 1) % len
+        };
+    }
+}
+
+/// Whether `text` contains `query`, ignoring case
+pub fn contains_ignore_case(text: &str, query: &str) -> bool {
+    !query.is_empty() && text.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// The line with each occurrence of `query`, ignoring case, patched with
+/// `style`. Matches are found in the text of the whole line, so one can run
+/// across spans
+pub fn highlight<'a>(line: Line<'a>, query: &str, style: Style) -> Line<'a> {
+    let matches = match_ranges(&line, query);
+    if matches.is_empty() {
+        return line;
+    }
+    let mut spans = Vec::new();
+    let mut matches = matches.into_iter().peekable();
+    let mut offset = 0;
+    for span in line.spans {
+        let content = span.content.to_string();
+        let span_end = offset + content.len();
+        let mut start = offset;
+        while let Some(&(begin, end)) = matches.peek() {
+            if begin >= span_end {
+                break;
+            }
+            let begin = begin.max(start);
+            if begin > start {
+                spans.push(Span::styled(
+                    content[start - offset..begin - offset].to_string(),
+                    span.style,
+                ));
+            }
+            let stop = end.min(span_end);
+            spans.push(Span::styled(
+                content[begin - offset..stop - offset].to_string(),
+                span.style.patch(style),
+            ));
+            start = stop;
+            if end > span_end {
+                // Carries on into the next span
+                break;
+            }
+            matches.next();
+        }
+        if start == offset {
+            spans.push(span);
+        } else if start < span_end {
+            spans.push(Span::styled(content[start - offset..].to_string(), span.style));
+        }
+        offset = span_end;
+    }
+    Line::from(spans)
+}
+
+/// Byte ranges of the line's text where `query` occurs, ignoring case
+fn match_ranges(line: &Line, query: &str) -> Vec<(usize, usize)> {
+    let query = query.to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    // Lowercasing can change byte lengths, as with "İ", so each byte of the
+    // lowercased text remembers the character it came from
+    let mut lower = String::new();
+    let mut chars = Vec::new();
+    let mut offset = 0;
+    for span in &line.spans {
+        for (index, c) in span.content.char_indices() {
+            let char_range = (offset + index, offset + index + c.len_utf8());
+            for lowered in c.to_lowercase() {
+                lower.push(lowered);
+                chars.resize(lower.len(), char_range);
+            }
+        }
+        offset += span.content.len();
+    }
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (begin, _) in lower.match_indices(&query) {
+        let (start, _) = chars[begin];
+        let (_, end) = chars[begin + query.len() - 1];
+        match ranges.last_mut() {
+            // Both matches touch one character
+            Some(last) if start < last.1 => last.1 = last.1.max(end),
+            _ => ranges.push((start, end)),
+        }
+    }
+    ranges
+}
+
+/// Prompt for the text to find in the detail view, with the match count
+pub struct DetailSearchBar<'a> {
+    state: &'a DetailSearchState,
+    theme: &'a Theme,
+}
+
+impl<'a> DetailSearchBar<'a> {
+    pub fn new(state: &'a DetailSearchState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let count = match self.state.matches.len() {
+            0 if self.state.query.is_empty() => String::new(),
+            0 => "  no matches".to_string(),
+            len => format!("  {}/{}", self.state.current + 1, len),
+        };
+        let (query, help) = if self.state.typing {
+            (format!("{}_", self.state.query), "  [Enter] keep [Esc] clear")
+        } else {
+            (self.state.query.clone(), "  [n/N] next/previous [Esc] clear")
+        };
+        let line = Line::from(vec![
+            Span::styled("Find: ", self.theme.focused),
+            Span::styled(query, self.theme.selected),
+            Span::styled(count, self.theme.focused),
+            Span::styled(help, self.theme.normal),
+        ]);
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::{Color, Modifier};
+
+    #[test]
+    fn test_highlight_matches_ignoring_case() {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let line = Line::from(vec![
+            Span::raw("Login fails on the "),
+            Span::styled("login page", bold),
+        ]);
+        let mark = Style::default().bg(Color::Yellow);
+        let spans: Vec<(String, Style)> = highlight(line, "LOGIN", mark)
+            .spans
+            .into_iter()
+            .map(|span| (span.content.to_string(), span.style))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                ("Login".to_string(), mark),
+                (" fails on the ".to_string(), Style::default()),
+                ("login".to_string(), bold.patch(mark)),
+                (" page".to_string(), bold),
+            ]
+        );
+    }
+
+    fn styled(line: Line) -> Vec<(String, Style)> {
+        line.spans
+            .into_iter()
+            .map(|span| (span.content.to_string(), span.style))
+            .collect()
+    }
+
+    #[test]
+    fn test_highlight_match_across_spans() {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let line = Line::from(vec![
+            Span::raw("Fails on "),
+            Span::styled("log", bold),
+            Span::raw("in page"),
+        ]);
+        let mark = Style::default().bg(Color::Yellow);
+        assert_eq!(
+            styled(highlight(line, "on login", mark)),
+            vec![
+                ("Fails ".to_string(), Style::default()),
+                ("on ".to_string(), mark),
+                ("log".to_string(), bold.patch(mark)),
+                ("in".to_string(), mark),
+                (" page".to_string(), Style::default()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlight_where_lowercase_changes_length() {
+        let mark = Style::default().bg(Color::Yellow);
+        // "İ" lowercases to two characters, three bytes instead of two
+        let line = Line::from(vec![Span::raw("İstanbul "), Span::raw("İSTANBUL")]);
+        assert_eq!(
+            styled(highlight(line, "stanbul", mark)),
+            vec![
+                ("İ".to_string(), Style::default()),
+                ("stanbul".to_string(), mark),
+                (" ".to_string(), Style::default()),
+                ("İ".to_string(), Style::default()),
+                ("STANBUL".to_string(), mark),
+            ]
+        );
+        let line = Line::from("Go to İzmir");
+        assert_eq!(
+            styled(highlight(line, "İzmir", mark)),
+            vec![
+                ("Go to ".to_string(), Style::default()),
+                ("İzmir".to_string(), mark)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_step_wraps_around() {
+        let mut search = DetailSearchState::new();
+        search.step(true);
+        assert_eq!(search.current_match(), None);
+
+        search.set_matches(vec![
+            DetailMatch::Description { line: 2 },
+            DetailMatch::Comment { index: 0 },
+        ]);
+        search.step(true);
+        assert_eq!(search.current_match(), Some(DetailMatch::Comment { index: 0 }));
+        search.step(true);
+        assert_eq!(search.current_match(), Some(DetailMatch::Description { line: 2 }));
+        search.step(false);
+        assert_eq!(search.current_match(), Some(DetailMatch::Comment { index: 0 }));
+        assert!(contains_ignore_case("Reproduced on the Login page", "login"));
+        assert!(!contains_ignore_case("anything", ""));
+    }
+}
@@ -0,0 +1,349 @@
+use crate::domain::models::user::User;
+use crate::infrastructure::api::client::{FieldKind, TransitionField, UpdateIssueData};
+use crate::ui::components::transition_form::FieldInput;
+use crate::ui::theme::Theme;
+use chrono::NaiveDate;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// State for the form editing the fields Jira's edit metadata offers
+#[derive(Debug, Clone)]
+pub struct EditFormState {
+    pub ticket_key: String,
+    pub fields: Vec<TransitionField>,
+    pub inputs: Vec<FieldInput>,
+    pub focused_field: usize,
+    /// Jira's messages from the last rejected save, by field key
+    pub errors: BTreeMap<String, String>,
+}
+
+impl EditFormState {
+    /// Build a blank form; fields left blank keep their value
+    pub fn new(ticket_key: String, fields: Vec<TransitionField>) -> Self {
+        let inputs = fields
+            .iter()
+            .map(|field| match field.kind {
+                FieldKind::Select { .. } => FieldInput::Choice(None),
+                FieldKind::User => FieldInput::User {
+                    query: String::new(),
+                    matches: Vec::new(),
+                    choice: None,
+                },
+                _ => FieldInput::Text(String::new()),
+            })
+            .collect();
+
+        Self {
+            ticket_key,
+            fields,
+            inputs,
+            focused_field: 0,
+            errors: BTreeMap::new(),
+        }
+    }
+
+    /// Attach Jira's field messages to the fields on this form; false when
+    /// none of them is about a field here
+    pub fn show_errors(&mut self, field_errors: &BTreeMap<String, String>) -> bool {
+        self.errors = field_errors
+            .iter()
+            .filter(|(key, _)| self.fields.iter().any(|field| &field.key == *key))
+            .map(|(key, message)| (key.clone(), message.clone()))
+            .collect();
+        !self.errors.is_empty()
+    }
+
+    pub fn next_field(&mut self) {
+        if self.focused_field + 1 < self.inputs.len() {
+            self.focused_field += 1;
+        }
+    }
+
+    pub fn previous_field(&mut self) {
+        self.focused_field = self.focused_field.saturating_sub(1);
+    }
+
+    /// Cycle the focused select field's options or the users found for the
+    /// focused user field, passing through "unchanged"
+    pub fn cycle_option(&mut self, forward: bool) {
+        let Some(field) = self.fields.get(self.focused_field) else {
+            return;
+        };
+        let (choice, count) = match (&field.kind, self.inputs.get_mut(self.focused_field)) {
+            (FieldKind::Select { options, .. }, Some(FieldInput::Choice(choice))) => (choice, options.len()),
+            (_, Some(FieldInput::User { matches, choice, .. })) => (choice, matches.len()),
+            _ => return,
+        };
+
+        // Slot 0 leaves the field unchanged
+        let slots = count + 1;
+        let current = choice.map(|idx| idx + 1).unwrap_or(0);
+        let next = if forward {
+            (current + 1) % slots
+        } else {
+            (current + slots - 1) % slots
+        };
+        *choice = next.checked_sub(1);
+    }
+
+    /// Type into the focused text field or user search
+    pub fn push(&mut self, c: char) {
+        match self.inputs.get_mut(self.focused_field) {
+            Some(FieldInput::Text(text)) => text.push(c),
+            Some(FieldInput::User { query, matches, choice }) => {
+                query.push(c);
+                matches.clear();
+                *choice = None;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn pop(&mut self) {
+        match self.inputs.get_mut(self.focused_field) {
+            Some(FieldInput::Text(text)) => {
+                text.pop();
+            }
+            Some(FieldInput::User { query, matches, choice }) => {
+                query.pop();
+                matches.clear();
+                *choice = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Name to look up when the focused user field was typed into but not
+    /// searched yet
+    pub fn pending_user_search(&self) -> Option<&str> {
+        match self.inputs.get(self.focused_field) {
+            Some(FieldInput::User { query, matches, .. })
+                if matches.is_empty() && !query.trim().is_empty() =>
+            {
+                Some(query.trim())
+            }
+            _ => None,
+        }
+    }
+
+    /// Offer the users found for the focused user field, choosing the first
+    pub fn set_user_matches(&mut self, users: Vec<User>) {
+        if let Some(FieldInput::User { matches, choice, .. }) = self.inputs.get_mut(self.focused_field) {
+            *choice = if users.is_empty() { None } else { Some(0) };
+            *matches = users;
+        }
+    }
+
+    /// The fields to update; None when nothing was changed
+    pub fn to_update(&self) -> Result<Option<UpdateIssueData>, String> {
+        let mut fields = HashMap::new();
+
+        for (field, input) in self.fields.iter().zip(&self.inputs) {
+            if input.is_empty() {
+                continue;
+            }
+            let value = match (&field.kind, input) {
+                (FieldKind::Select { options, multiple }, FieldInput::Choice(Some(idx))) => {
+                    let option = json!({ "id": options[*idx].id });
+                    if *multiple {
+                        json!([option])
+                    } else {
+                        option
+                    }
+                }
+                (_, FieldInput::User { matches, choice: Some(idx), .. }) => {
+                    json!({ "accountId": matches[*idx].account_id })
+                }
+                (FieldKind::Number, FieldInput::Text(text)) => {
+                    let number: f64 = text
+                        .trim()
+                        .parse()
+                        .map_err(|_| format!("{} must be a number", field.name))?;
+                    json!(number)
+                }
+                (FieldKind::Date, FieldInput::Text(text)) => {
+                    let date = NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d")
+                        .map_err(|_| format!("{} must be a date like 2024-05-31", field.name))?;
+                    json!(date.format("%Y-%m-%d").to_string())
+                }
+                (_, FieldInput::Text(text)) => Value::String(text.trim().to_string()),
+                _ => continue,
+            };
+            fields.insert(field.key.clone(), value);
+        }
+
+        if fields.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(UpdateIssueData { fields }))
+        }
+    }
+}
+
+/// Edit form widget
+pub struct EditForm<'a> {
+    state: &'a EditFormState,
+    theme: &'a Theme,
+}
+
+impl<'a> EditForm<'a> {
+    pub fn new(state: &'a EditFormState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the form over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Edit {} — blank fields keep their value", self.state.ticket_key))
+            .title_style(self.theme.focused);
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let lines: Vec<Line> = self
+            .state
+            .fields
+            .iter()
+            .zip(&self.state.inputs)
+            .enumerate()
+            .map(|(idx, (field, input))| self.field_line(field, input, idx == self.state.focused_field))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), parts[0]);
+
+        let help = "[↑↓/Tab] field [←→] option, find user [Enter] save [Esc] cancel";
+        frame.render_widget(Paragraph::new(help).style(self.theme.normal), parts[1]);
+    }
+
+    /// One "Name*: value" row
+    fn field_line(&self, field: &'a TransitionField, input: &'a FieldInput, focused: bool) -> Line<'a> {
+        let marker = if field.required { "*" } else { "" };
+        let value = match (&field.kind, input) {
+            (FieldKind::Select { options, .. }, FieldInput::Choice(choice)) => {
+                let name = choice
+                    .and_then(|idx| options.get(idx))
+                    .map(|o| o.name.as_str())
+                    .unwrap_or("(unchanged)");
+                format!("◀ {} ▶", name)
+            }
+            (_, FieldInput::User { matches, choice: Some(idx), .. }) => {
+                format!("◀ {} ▶", matches[*idx].display_name)
+            }
+            (_, FieldInput::User { query, .. }) if focused => format!("{}_", query),
+            (_, FieldInput::User { query, .. }) => query.clone(),
+            (FieldKind::Date, FieldInput::Text(text)) if focused && text.is_empty() => "YYYY-MM-DD".to_string(),
+            (_, FieldInput::Text(text)) if focused => format!("{}_", text),
+            (_, FieldInput::Text(text)) => text.clone(),
+            _ => String::new(),
+        };
+
+        let value_style = if focused {
+            self.theme.selected
+        } else {
+            self.theme.normal
+        };
+        let mut spans = vec![
+            Span::styled(format!("{}{}: ", field.name, marker), self.theme.focused),
+            Span::styled(value, value_style),
+        ];
+        if let Some(error) = self.state.errors.get(&field.key) {
+            spans.push(Span::styled(format!("  ✗ {}", error), self.theme.error));
+        }
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::api::client::FieldOption;
+
+    fn field(key: &str, kind: FieldKind) -> TransitionField {
+        TransitionField {
+            key: key.to_string(),
+            name: key.to_string(),
+            required: false,
+            kind,
+        }
+    }
+
+    fn form() -> EditFormState {
+        EditFormState::new(
+            "PROJ-1".to_string(),
+            vec![
+                field("summary", FieldKind::Text),
+                field(
+                    "priority",
+                    FieldKind::Select {
+                        options: vec![FieldOption {
+                            id: "3".to_string(),
+                            name: "Medium".to_string(),
+                        }],
+                        multiple: false,
+                    },
+                ),
+                field("duedate", FieldKind::Date),
+                field("assignee", FieldKind::User),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_blank_form_changes_nothing() {
+        let mut form = form();
+        assert!(form.to_update().unwrap().is_none());
+        form.next_field();
+        form.cycle_option(true);
+        form.cycle_option(true);
+        assert!(form.to_update().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_form_builds_update_of_entered_fields() {
+        let mut form = form();
+        form.push('x');
+        form.next_field();
+        form.cycle_option(false);
+        form.next_field();
+        for c in "2024-13-01".chars() {
+            form.push(c);
+        }
+        assert_eq!(form.to_update().unwrap_err(), "duedate must be a date like 2024-05-31");
+        for _ in 0..5 {
+            form.pop();
+        }
+        for c in "05-31".chars() {
+            form.push(c);
+        }
+
+        form.next_field();
+        for c in "ali".chars() {
+            form.push(c);
+        }
+        assert_eq!(form.pending_user_search(), Some("ali"));
+        form.set_user_matches(vec![User::new("abc".to_string(), "Alice".to_string())]);
+        assert_eq!(form.pending_user_search(), None);
+
+        let update = form.to_update().unwrap().unwrap();
+        assert_eq!(
+            update.fields,
+            HashMap::from([
+                ("summary".to_string(), json!("x")),
+                ("priority".to_string(), json!({ "id": "3" })),
+                ("duedate".to_string(), json!("2024-05-31")),
+                ("assignee".to_string(), json!({ "accountId": "abc" })),
+            ])
+        );
+    }
+}
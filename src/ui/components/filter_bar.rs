@@ -0,0 +1,117 @@
+use crate::infrastructure::config::FilterConfig;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use std::collections::HashMap;
+
+/// One-line bar of the quick filters on keys 1–9, the active one highlighted
+pub struct FilterBar<'a> {
+    filters: &'a [FilterConfig],
+    /// Index of the active filter; None while `default_query` is used
+    active: Option<usize>,
+    /// Name of the query from the query builder or view switcher in use
+    /// instead of any filter
+    custom_query: Option<&'a str>,
+    /// Issue counts by JQL, shown next to the queries they were counted for
+    counts: Option<&'a HashMap<String, usize>>,
+    default_query: &'a str,
+    theme: &'a Theme,
+}
+
+impl<'a> FilterBar<'a> {
+    pub fn new(filters: &'a [FilterConfig], active: Option<usize>, theme: &'a Theme) -> Self {
+        Self {
+            filters,
+            active,
+            custom_query: None,
+            counts: None,
+            default_query: "",
+            theme,
+        }
+    }
+
+    /// Show each query's issue count from `counts`, keyed by JQL
+    pub fn counts(mut self, counts: &'a HashMap<String, usize>, default_query: &'a str) -> Self {
+        self.counts = Some(counts);
+        self.default_query = default_query;
+        self
+    }
+
+    /// " (3)" when the query has been counted
+    fn count_label(&self, jql: &str) -> String {
+        self.counts
+            .and_then(|counts| counts.get(jql))
+            .map(|count| format!(" ({})", count))
+            .unwrap_or_default()
+    }
+
+    pub fn custom_query(mut self, custom_query: Option<&'a str>) -> Self {
+        self.custom_query = custom_query;
+        self
+    }
+
+    /// Render the bar into `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(Paragraph::new(self.line()), area);
+    }
+
+    fn line(&self) -> Line<'a> {
+        let style = |active: bool| {
+            if active {
+                self.theme.selected
+            } else {
+                self.theme.normal
+            }
+        };
+        let active = self.active.filter(|_| self.custom_query.is_none());
+        let mut spans = vec![Span::styled(
+            format!(" Default{} ", self.count_label(self.default_query)),
+            style(active.is_none() && self.custom_query.is_none()),
+        )];
+        for (index, filter) in self.filters.iter().take(9).enumerate() {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(
+                format!(" {} {}{} ", index + 1, filter.name, self.count_label(&filter.jql)),
+                style(active == Some(index)),
+            ));
+        }
+        if let Some(name) = self.custom_query {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(format!(" {} ", name), style(true)));
+        }
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_shown_next_to_filters() {
+        let filters = vec![
+            FilterConfig {
+                name: "Needs review".to_string(),
+                jql: "status = Review".to_string(),
+            },
+            FilterConfig {
+                name: "Bugs".to_string(),
+                jql: "type = Bug".to_string(),
+            },
+        ];
+        let counts = HashMap::from([
+            ("status = Review".to_string(), 3),
+            ("assignee = currentUser()".to_string(), 12),
+        ]);
+        let theme = Theme::default();
+        let line = FilterBar::new(&filters, None, &theme)
+            .counts(&counts, "assignee = currentUser()")
+            .line();
+        let text: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(text, " Default (12)   1 Needs review (3)   2 Bugs ");
+    }
+}
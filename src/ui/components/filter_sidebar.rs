@@ -0,0 +1,123 @@
+use crate::domain::services::filter_service::{Facet, FacetKind};
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+/// State for the facet sidebar; selected facets keep filtering the list
+/// while it is collapsed
+#[derive(Debug, Clone, Default)]
+pub struct FilterSidebarState {
+    pub open: bool,
+    /// Index into the facet rows, not counting the group headers
+    pub focused_index: usize,
+    pub selected: Vec<Facet>,
+}
+
+impl FilterSidebarState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self, facet_count: usize) {
+        if self.focused_index + 1 < facet_count {
+            self.focused_index += 1;
+        }
+    }
+
+    /// Select the facet, or deselect it when already selected
+    pub fn toggle(&mut self, facet: &Facet) {
+        match self.selected.iter().position(|selected| selected == facet) {
+            Some(idx) => {
+                self.selected.remove(idx);
+            }
+            None => self.selected.push(facet.clone()),
+        }
+    }
+
+    pub fn is_selected(&self, facet: &Facet) -> bool {
+        self.selected.contains(facet)
+    }
+}
+
+/// Sidebar listing the facets of the loaded tickets with their counts
+pub struct FilterSidebar<'a> {
+    state: &'a FilterSidebarState,
+    facets: &'a [(Facet, usize)],
+    theme: &'a Theme,
+}
+
+impl<'a> FilterSidebar<'a> {
+    pub fn new(state: &'a FilterSidebarState, facets: &'a [(Facet, usize)], theme: &'a Theme) -> Self {
+        Self {
+            state,
+            facets,
+            theme,
+        }
+    }
+
+    /// Render the facets grouped under a header per kind
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let mut items = Vec::new();
+        let mut focused_row = None;
+        let mut kind: Option<FacetKind> = None;
+        for (idx, (facet, count)) in self.facets.iter().enumerate() {
+            if kind != Some(facet.kind) {
+                kind = Some(facet.kind);
+                items.push(ListItem::new(Line::from(Span::styled(
+                    facet.kind.label(),
+                    self.theme.focused,
+                ))));
+            }
+            if idx == self.state.focused_index {
+                focused_row = Some(items.len());
+            }
+            let (check, style) = if self.state.is_selected(facet) {
+                ("[x]", self.theme.status_done)
+            } else {
+                ("[ ]", self.theme.normal)
+            };
+            items.push(ListItem::new(Line::from(vec![
+                Span::styled(format!(" {} {}", check, facet.value), style),
+                Span::styled(format!(" ({})", count), self.theme.normal),
+            ])));
+        }
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Filters — [Space] toggle [F] close")
+                    .title_style(self.theme.focused),
+            )
+            .highlight_style(self.theme.selected);
+        let mut list_state = ListState::default();
+        list_state.select(focused_row);
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_facet() {
+        let facet = Facet {
+            kind: FacetKind::Label,
+            value: "backend".to_string(),
+        };
+        let mut state = FilterSidebarState::new();
+        state.toggle(&facet);
+        assert!(state.is_selected(&facet));
+        state.toggle(&facet);
+        assert!(state.selected.is_empty());
+    }
+}
@@ -0,0 +1,223 @@
+use crate::domain::models::attachment::Attachment;
+use crate::ui::theme::Theme;
+use base64::Engine;
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, Clear},
+    Frame,
+};
+
+/// Size of a base64 chunk in a kitty graphics command
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+/// Terminal graphics protocol used to draw images inline. Sixel is not
+/// among them: it takes pixels, and images are only ever sent as the bytes
+/// Jira stores
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    ITerm2,
+}
+
+impl GraphicsProtocol {
+    /// Detect the protocol the terminal supports from its environment
+    pub fn detect() -> Option<Self> {
+        Self::from_env(|name| std::env::var(name).ok())
+    }
+
+    fn from_env(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let term = var("TERM").unwrap_or_default();
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+
+        if var("KITTY_WINDOW_ID").is_some() || term == "xterm-kitty" || program == "ghostty" {
+            Some(GraphicsProtocol::Kitty)
+        } else if program == "iTerm.app"
+            || program == "WezTerm"
+            || var("LC_TERMINAL").as_deref() == Some("iTerm2")
+        {
+            Some(GraphicsProtocol::ITerm2)
+        } else {
+            None
+        }
+    }
+
+    /// Whether images of this type can be sent as is; kitty takes PNG
+    /// directly (f=100) and other formats would need decoding first
+    pub fn supports(self, mime_type: &str) -> bool {
+        match self {
+            GraphicsProtocol::Kitty => mime_type == "image/png",
+            GraphicsProtocol::ITerm2 => mime_type.starts_with("image/"),
+        }
+    }
+
+    /// Escape sequence drawing the image at the cursor within `cols` x `rows`
+    /// cells, or None when this protocol can't show the image as is
+    pub fn encode(self, bytes: &[u8], mime_type: &str, cols: u16, rows: u16) -> Option<String> {
+        if !self.supports(mime_type) {
+            return None;
+        }
+        let payload = base64::engine::general_purpose::STANDARD.encode(bytes);
+        match self {
+            GraphicsProtocol::Kitty => {
+                let (cols, rows) = match png_dimensions(bytes) {
+                    Some((width, height)) => fit_cells(width, height, cols, rows),
+                    None => (cols, rows),
+                };
+                let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+                let mut sequence = String::new();
+                for (idx, chunk) in chunks.iter().enumerate() {
+                    let more = u8::from(idx + 1 < chunks.len());
+                    let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+                    if idx == 0 {
+                        sequence.push_str(&format!(
+                            "\x1b_Ga=T,f=100,q=2,C=1,c={},r={},m={};{}\x1b\\",
+                            cols, rows, more, chunk
+                        ));
+                    } else {
+                        sequence.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+                    }
+                }
+                Some(sequence)
+            }
+            GraphicsProtocol::ITerm2 => Some(format!(
+                "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{}\x07",
+                bytes.len(),
+                cols,
+                rows,
+                payload
+            )),
+        }
+    }
+
+    /// Escape sequence removing drawn images, for protocols that keep them
+    /// apart from the text cells
+    pub fn clear_sequence(self) -> Option<&'static str> {
+        match self {
+            GraphicsProtocol::Kitty => Some("\x1b_Ga=d,q=2\x1b\\"),
+            GraphicsProtocol::ITerm2 => None,
+        }
+    }
+}
+
+/// Width and height from a PNG header
+fn png_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+    if bytes.len() < 24 || !bytes.starts_with(SIGNATURE) {
+        return None;
+    }
+    let width = u32::from_be_bytes(bytes[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(bytes[20..24].try_into().ok()?);
+    Some((width, height))
+}
+
+/// Largest cell area within `cols` x `rows` keeping the image's aspect
+/// ratio, assuming cells twice as tall as they are wide
+fn fit_cells(width: u32, height: u32, cols: u16, rows: u16) -> (u16, u16) {
+    if width == 0 || height == 0 {
+        return (cols, rows);
+    }
+    // Columns per row that keep the image undistorted
+    let aspect = width as f64 * 2.0 / height as f64;
+    let fitted_cols = (rows as f64 * aspect).round() as u16;
+    if fitted_cols <= cols {
+        (fitted_cols.max(1), rows)
+    } else {
+        (cols, ((cols as f64 / aspect).round() as u16).clamp(1, rows))
+    }
+}
+
+/// An image attachment shown inline
+#[derive(Debug, Clone)]
+pub struct ImagePreviewState {
+    pub attachment: Attachment,
+    pub bytes: Vec<u8>,
+    pub protocol: GraphicsProtocol,
+    /// Whether the image has been written to the terminal since it was opened
+    pub drawn: bool,
+}
+
+impl ImagePreviewState {
+    pub fn new(attachment: Attachment, bytes: Vec<u8>, protocol: GraphicsProtocol) -> Self {
+        Self {
+            attachment,
+            bytes,
+            protocol,
+            drawn: false,
+        }
+    }
+
+    /// Escape sequence drawing the image inside `area`
+    pub fn sequence(&self, area: Rect) -> Option<String> {
+        self.protocol
+            .encode(&self.bytes, &self.attachment.mime_type, area.width, area.height)
+    }
+}
+
+/// Frame around an inline image; the image itself is written to the
+/// terminal after the frame is drawn
+pub struct ImagePreview<'a> {
+    state: &'a ImagePreviewState,
+    theme: &'a Theme,
+}
+
+impl<'a> ImagePreview<'a> {
+    pub fn new(state: &'a ImagePreviewState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the frame over `area`, returning the area left for the image
+    pub fn render(self, frame: &mut Frame, area: Rect) -> Rect {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "{} ({}) — press any key to close",
+                self.state.attachment.filename,
+                self.state.attachment.size_label()
+            ))
+            .title_style(self.theme.focused);
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+        inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn test_detect_protocol_from_env() {
+        let detect = |vars: &[(&str, &str)]| {
+            GraphicsProtocol::from_env(|name| {
+                vars.iter().find(|(k, _)| *k == name).map(|(_, v)| v.to_string())
+            })
+        };
+
+        assert_eq!(detect(&[("TERM", "xterm-kitty")]), Some(GraphicsProtocol::Kitty));
+        assert_eq!(detect(&[("TERM_PROGRAM", "iTerm.app")]), Some(GraphicsProtocol::ITerm2));
+        assert_eq!(detect(&[("TERM", "foot")]), None);
+        assert_eq!(detect(&[("TERM", "xterm-256color")]), None);
+    }
+
+    #[test]
+    fn test_encode_keeps_aspect_ratio_and_format_support() {
+        let image = png(400, 100);
+        assert_eq!(png_dimensions(&image), Some((400, 100)));
+        assert_eq!(fit_cells(400, 100, 40, 20), (40, 5));
+
+        let kitty = GraphicsProtocol::Kitty.encode(&image, "image/png", 40, 20).unwrap();
+        assert!(kitty.starts_with("\x1b_Ga=T,f=100,q=2,C=1,c=40,r=5,m=0;"));
+        assert!(GraphicsProtocol::Kitty.encode(&image, "image/jpeg", 40, 20).is_none());
+
+        let iterm = GraphicsProtocol::ITerm2.encode(&image, "image/jpeg", 40, 20).unwrap();
+        assert!(iterm.starts_with("\x1b]1337;File=inline=1;size=24;width=40;height=20;"));
+    }
+}
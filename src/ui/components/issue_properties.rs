@@ -0,0 +1,86 @@
+use crate::domain::models::issue_property::IssueProperty;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// State for the panel showing an issue's entity properties
+#[derive(Debug, Clone)]
+pub struct IssuePropertiesState {
+    pub ticket_key: String,
+    pub properties: Vec<IssueProperty>,
+    /// First line shown
+    pub scroll: usize,
+}
+
+impl IssuePropertiesState {
+    pub fn new(ticket_key: String, properties: Vec<IssueProperty>) -> Self {
+        Self {
+            ticket_key,
+            properties,
+            scroll: 0,
+        }
+    }
+
+    /// Each property's key followed by its value as indented JSON
+    pub fn lines(&self) -> Vec<(bool, String)> {
+        let mut lines = Vec::new();
+        for property in &self.properties {
+            lines.push((true, property.key.clone()));
+            lines.extend(property.pretty_lines().into_iter().map(|line| (false, format!("  {}", line))));
+        }
+        lines
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        if self.scroll + 1 < self.lines().len() {
+            self.scroll += 1;
+        }
+    }
+}
+
+/// Popup listing the properties automations and apps stored on an issue
+pub struct IssuePropertiesView<'a> {
+    state: &'a IssuePropertiesState,
+    theme: &'a Theme,
+}
+
+impl<'a> IssuePropertiesView<'a> {
+    pub fn new(state: &'a IssuePropertiesState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the panel over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = self
+            .state
+            .lines()
+            .into_iter()
+            .skip(self.state.scroll)
+            .map(|(heading, text)| {
+                let style = if heading { self.theme.focused } else { self.theme.normal };
+                Line::from(Span::styled(text, style))
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "Properties of {} ({}) — [↑↓] scroll [Esc] close",
+                    self.state.ticket_key,
+                    self.state.properties.len()
+                ))
+                .title_style(self.theme.focused),
+        );
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
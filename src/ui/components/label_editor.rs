@@ -0,0 +1,117 @@
+use crate::infrastructure::api::client::LabelChange;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// State for the label add/remove prompt
+#[derive(Debug, Clone, Default)]
+pub struct LabelEditorState {
+    pub input: String,
+    /// Remove the label instead of adding it
+    pub remove: bool,
+}
+
+impl LabelEditorState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a typed character; Jira labels cannot contain spaces
+    pub fn push(&mut self, c: char) {
+        if !c.is_whitespace() {
+            self.input.push(c);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        self.input.pop();
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.remove = !self.remove;
+    }
+
+    /// The change to apply, if a label was entered
+    pub fn change(&self) -> Option<LabelChange> {
+        let label = self.input.trim();
+        if label.is_empty() {
+            None
+        } else if self.remove {
+            Some(LabelChange::Remove(label.to_string()))
+        } else {
+            Some(LabelChange::Add(label.to_string()))
+        }
+    }
+}
+
+/// Label prompt widget
+pub struct LabelEditor<'a> {
+    state: &'a LabelEditorState,
+    ticket_count: usize,
+    theme: &'a Theme,
+}
+
+impl<'a> LabelEditor<'a> {
+    pub fn new(state: &'a LabelEditorState, ticket_count: usize, theme: &'a Theme) -> Self {
+        Self {
+            state,
+            ticket_count,
+            theme,
+        }
+    }
+
+    /// Render the prompt over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let (mode, mode_style) = if self.state.remove {
+            ("Remove", self.theme.error)
+        } else {
+            ("Add", self.theme.status_done)
+        };
+
+        let lines = vec![
+            Line::from(vec![
+                Span::styled(format!("{} label: ", mode), mode_style),
+                Span::styled(format!("{}_", self.state.input), self.theme.selected),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                "[Tab] add/remove [Enter] apply [Esc] cancel",
+                self.theme.normal,
+            )),
+        ];
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Labels on {} issue(s)", self.ticket_count))
+                .title_style(self.theme.focused),
+        );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_editor_change() {
+        let mut state = LabelEditorState::new();
+        assert_eq!(state.change(), None);
+
+        for c in "needs review".chars() {
+            state.push(c);
+        }
+        assert_eq!(state.change(), Some(LabelChange::Add("needsreview".to_string())));
+
+        state.toggle_mode();
+        state.pop();
+        assert_eq!(state.change(), Some(LabelChange::Remove("needsrevie".to_string())));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+use std::collections::HashMap;
+
+/// State for the link picker in the detail view
+#[derive(Debug, Clone)]
+pub struct LinkListState {
+    /// URLs of the description, numbered as rendered, then of the comments
+    pub links: Vec<String>,
+    /// Titles of the links that have one, e.g. web links, by URL
+    pub titles: HashMap<String, String>,
+    pub focused_index: usize,
+}
+
+impl LinkListState {
+    pub fn new(links: Vec<String>, titles: HashMap<String, String>, focused_index: usize) -> Self {
+        Self {
+            focused_index: focused_index.min(links.len().saturating_sub(1)),
+            links,
+            titles,
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.focused_index + 1 < self.links.len() {
+            self.focused_index += 1;
+        }
+    }
+
+    pub fn focused(&self) -> Option<&String> {
+        self.links.get(self.focused_index)
+    }
+}
+
+/// Link picker widget
+pub struct LinkList<'a> {
+    state: &'a LinkListState,
+    theme: &'a Theme,
+}
+
+impl<'a> LinkList<'a> {
+    pub fn new(state: &'a LinkListState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the picker over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .state
+            .links
+            .iter()
+            .enumerate()
+            .map(|(i, url)| {
+                let mut spans = vec![Span::styled(
+                    format!("{:<4}", format!("[{}]", i + 1)),
+                    self.theme.focused,
+                )];
+                if let Some(title) = self.state.titles.get(url) {
+                    spans.push(Span::styled(format!("{} — ", title), self.theme.normal));
+                }
+                spans.push(Span::styled(url.as_str(), self.theme.link));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Links — [Enter/1-9] open [Esc] close")
+                    .title_style(self.theme.focused),
+            )
+            .highlight_style(self.theme.selected)
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.state.focused_index));
+
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::ui::highlight::CodeHighlighter;
+use crate::ui::theme::Theme;
+use crate::utils::links;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use std::collections::HashMap;
+
+/// Styled lines for the Markdown of a description: headings, lists, quotes,
+/// code blocks highlighted for their language, and bold, italic, code,
+/// struck-out and linked text. Links, including bare URLs, are followed by
+/// their number in `links::links(text)`, and issue keys found in
+/// `references` by its text for them
+pub fn render(
+    text: &str,
+    theme: &Theme,
+    references: &HashMap<String, String>,
+) -> Vec<Line<'static>> {
+    let urls = links::links(text);
+    let inline = |text: &str, style: Style| inline_spans(text, style, theme, &urls, references);
+    let mut lines = Vec::new();
+    // Inside a code block, with its highlighter when the language is known
+    let mut code: Option<Option<CodeHighlighter>> = None;
+    for line in text.lines() {
+        if let Some(fence) = line.trim_start_matches("> ").strip_prefix("```") {
+            code = match code {
+                Some(_) => None,
+                None => Some(CodeHighlighter::new(fence, theme.colors)),
+            };
+            continue;
+        }
+        if let Some(highlighter) = code.as_mut() {
+            let spans = highlighter
+                .as_mut()
+                .and_then(|highlighter| highlighter.line(line))
+                .unwrap_or_else(|| vec![Span::styled(line.to_string(), theme.code)]);
+            let mut indented = vec![Span::raw("  ")];
+            indented.extend(spans);
+            lines.push(Line::from(indented));
+            continue;
+        }
+
+        let mut rest = line;
+        let mut prefix = String::new();
+        let mut style = theme.normal;
+        while let Some(quoted) = rest.strip_prefix("> ") {
+            prefix.push_str("│ ");
+            style = style.add_modifier(Modifier::ITALIC);
+            rest = quoted;
+        }
+
+        let hashes = rest.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&hashes) && rest[hashes..].starts_with(' ') {
+            let heading = theme.focused.add_modifier(if hashes <= 2 {
+                Modifier::UNDERLINED
+            } else {
+                Modifier::empty()
+            });
+            let mut spans = vec![Span::raw(prefix)];
+            spans.extend(inline(&rest[hashes + 1..], heading));
+            lines.push(Line::from(spans));
+            continue;
+        }
+        if rest == "---" {
+            lines.push(Line::from(format!("{}{}", prefix, "─".repeat(20))));
+            continue;
+        }
+
+        let indent = rest.len() - rest.trim_start().len();
+        if let Some(item) = rest.trim_start().strip_prefix("- ") {
+            prefix.push_str(&" ".repeat(indent));
+            prefix.push_str("• ");
+            rest = item;
+        }
+        let mut spans = vec![Span::styled(prefix, style)];
+        spans.extend(inline(rest, style));
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Spans of a line with its inline marks styled; unmatched markers stay text
+fn inline_spans(
+    text: &str,
+    base: Style,
+    theme: &Theme,
+    urls: &[String],
+    references: &HashMap<String, String>,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+    let mut previous: Option<char> = None;
+    while let Some(c) = rest.chars().next() {
+        let mut found = marked(rest, base, theme, urls);
+        if found.is_none() && !previous.is_some_and(|p| p.is_alphanumeric() || p == '-') {
+            found = reference(rest, base, theme, references);
+        }
+        if let Some((marked, after)) = found {
+            if !plain.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut plain), base));
+            }
+            spans.extend(marked);
+            previous = rest[..rest.len() - after.len()].chars().last();
+            rest = after;
+        } else {
+            plain.push(c);
+            previous = Some(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if !plain.is_empty() {
+        spans.push(Span::styled(plain, base));
+    }
+    spans
+}
+
+/// Spans of the referenced issue key `rest` starts with, followed by the
+/// text for it in `references`, and what follows the key
+pub fn reference<'t>(
+    rest: &'t str,
+    base: Style,
+    theme: &Theme,
+    references: &HashMap<String, String>,
+) -> Option<(Vec<Span<'static>>, &'t str)> {
+    let len = links::issue_key_len(rest)?;
+    let summary = references.get(&rest[..len])?;
+    Some((
+        vec![
+            Span::styled(rest[..len].to_string(), theme.link),
+            Span::styled(format!(" ⟨{}⟩", summary), base.add_modifier(Modifier::DIM)),
+        ],
+        &rest[len..],
+    ))
+}
+
+/// Spans of the marked text `rest` starts with, if any, and what follows it
+fn marked<'t>(
+    rest: &'t str,
+    base: Style,
+    theme: &Theme,
+    urls: &[String],
+) -> Option<(Vec<Span<'static>>, &'t str)> {
+    let link = |label: &str, url: &str| {
+        let mut spans = vec![Span::styled(label.to_string(), theme.link)];
+        if let Some(i) = urls.iter().position(|u| u == url) {
+            spans.push(Span::styled(format!("[{}]", i + 1), base));
+        }
+        spans
+    };
+    let delimited = |open: &str, close: &str| -> Option<(&'t str, &'t str)> {
+        let inner = rest.strip_prefix(open)?;
+        let end = inner.find(close).filter(|end| *end > 0)?;
+        Some((&inner[..end], &inner[end + close.len()..]))
+    };
+    if let Some((code, after)) = delimited("`", "`") {
+        return Some((vec![Span::styled(code.to_string(), theme.code)], after));
+    }
+    if let Some((bold, after)) = delimited("**", "**") {
+        return Some((vec![Span::styled(bold.to_string(), base.add_modifier(Modifier::BOLD))], after));
+    }
+    if let Some((struck, after)) = delimited("~~", "~~") {
+        return Some((
+            vec![Span::styled(struck.to_string(), base.add_modifier(Modifier::CROSSED_OUT))],
+            after,
+        ));
+    }
+    if !rest.starts_with("**") {
+        if let Some((em, after)) = delimited("*", "*") {
+            return Some((vec![Span::styled(em.to_string(), base.add_modifier(Modifier::ITALIC))], after));
+        }
+    }
+    if let Some((url, len)) = links::markdown_link(rest) {
+        let label = &rest[1..rest[1..].find("](")? + 1];
+        return Some((link(label, url), &rest[len..]));
+    }
+    if let Some(len) = links::bare_url_len(rest) {
+        return Some((link(&rest[..len], &rest[..len]), &rest[len..]));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::highlight::ColorDepth;
+    use ratatui::style::Color;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|span| span.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let theme = Theme::monochrome();
+        let lines = render(
+            "## Steps\n1. Run `make`\n  - [docs](https://example.com) *now*\n```rust\nfn main() {}\n```\n> **Really** 2 * 3",
+            &theme,
+            &HashMap::new(),
+        );
+        let texts: Vec<String> = lines.iter().map(text).collect();
+        assert_eq!(
+            texts,
+            vec!["Steps", "1. Run make", "  • docs[1] now", "  fn main() {}", "│ Really 2 * 3"]
+        );
+
+        assert!(lines[0].spans[1].style.add_modifier.contains(Modifier::UNDERLINED));
+        assert_eq!(lines[1].spans[2].style, theme.code);
+        assert_eq!(lines[2].spans[1].style, theme.link);
+        assert!(lines[2].spans[4].style.add_modifier.contains(Modifier::ITALIC));
+        assert!(lines[4].spans[1].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(lines[3].spans[1].style, theme.code);
+
+        let lines = render("See https://a.example, then [b](https://b.example).", &theme, &HashMap::new());
+        assert_eq!(text(&lines[0]), "See https://a.example[1], then b[2].");
+
+        let references = HashMap::from([("PROJ-7".to_string(), "Done: Fix login".to_string())]);
+        let lines = render("Needs **PROJ-7**, PROJ-7 and PROJ-8", &theme, &references);
+        assert_eq!(text(&lines[0]), "Needs PROJ-7, PROJ-7 ⟨Done: Fix login⟩ and PROJ-8");
+        assert_eq!(lines[0].spans[4].style, theme.link);
+
+        let theme = Theme {
+            colors: ColorDepth::TrueColor,
+            ..Theme::default()
+        };
+        let lines = render("```rust\nlet x = 1;\n```", &theme, &HashMap::new());
+        assert!(lines[0].spans.len() > 2);
+        assert!(lines[0].spans.iter().any(|span| matches!(span.style.fg, Some(Color::Rgb(..)))));
+    }
+}
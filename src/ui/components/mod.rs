@@ -1,6 +1,47 @@
+pub mod action_history;
+pub mod activity_feed;
+pub mod approval_list;
+pub mod assignee_picker;
+pub mod audit_log_view;
+pub mod attachment_list;
+pub mod avatar;
+pub mod board_view;
+pub mod bulk_progress;
+pub mod comment_composer;
+pub mod comment_prompt;
+pub mod confirm_dialog;
+pub mod cross_search;
+pub mod dashboard;
+pub mod dependency_graph;
+pub mod detail_search;
+pub mod edit_form;
+pub mod filter_bar;
+pub mod filter_sidebar;
+pub mod image_preview;
+pub mod issue_properties;
+pub mod label_editor;
+pub mod link_list;
+pub mod markdown;
+pub mod move_issue_picker;
+pub mod nav_history;
+pub mod query_builder;
+pub mod release_list;
+pub mod reminder_popup;
+pub mod remote_link_form;
+pub mod search_bar;
+pub mod request_log_view;
+pub mod sprint_list;
+pub mod sprint_progress;
 pub mod ticket_detail;
+pub mod ticket_diff;
 pub mod ticket_list;
+pub mod timeline_view;
+pub mod timesheet_view;
+pub mod transition_form;
 pub mod transition_list;
+pub mod velocity_chart;
+pub mod view_switcher;
+pub mod worklog_prompt;
 
 // Components are used directly, not through re-exports
 // pub use ticket_detail::TicketDetail;
@@ -0,0 +1,223 @@
+use crate::domain::models::project::{IssueType, Project};
+use crate::ui::theme::Theme;
+use std::collections::HashMap;
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// State for the move-issue picker: a project, then an issue type in it
+#[derive(Debug, Clone)]
+pub struct MoveIssueState {
+    pub ticket_key: String,
+    /// Issue type of the ticket, used to preselect the mapped type
+    pub issue_type: String,
+    pub projects: Vec<Project>,
+    /// Configured default issue type per project key, preselected when the
+    /// project has no type matching the ticket's
+    pub default_issue_types: HashMap<String, String>,
+    /// Chosen project; the picker lists its issue types once set
+    pub project_index: Option<usize>,
+    pub focused_index: usize,
+}
+
+impl MoveIssueState {
+    pub fn new(
+        ticket_key: String,
+        issue_type: String,
+        projects: Vec<Project>,
+        default_issue_types: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            ticket_key,
+            issue_type,
+            projects,
+            default_issue_types,
+            project_index: None,
+            focused_index: 0,
+        }
+    }
+
+    /// Chosen project, if any
+    pub fn project(&self) -> Option<&Project> {
+        self.project_index.and_then(|idx| self.projects.get(idx))
+    }
+
+    /// Labels of the rows currently listed
+    pub fn labels(&self) -> Vec<String> {
+        match self.project() {
+            Some(project) => project
+                .standard_issue_types()
+                .iter()
+                .map(|t| t.name.clone())
+                .collect(),
+            None => self
+                .projects
+                .iter()
+                .map(|p| format!("{:<10} {}", p.key, p.name))
+                .collect(),
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.focused_index + 1 < self.labels().len() {
+            self.focused_index += 1;
+        }
+    }
+
+    /// Confirm the focused row: picks the project first, then returns the
+    /// chosen project and issue type
+    pub fn select(&mut self) -> Option<(Project, IssueType)> {
+        match self.project() {
+            Some(project) => {
+                let issue_type = project
+                    .standard_issue_types()
+                    .get(self.focused_index)
+                    .map(|t| (*t).clone())?;
+                Some((project.clone(), issue_type))
+            }
+            None => {
+                let project_index = self.focused_index;
+                let project = self.projects.get(project_index)?;
+                // Preselect the issue type matching the ticket's current one,
+                // else the project's default
+                let has_match = project
+                    .standard_issue_types()
+                    .iter()
+                    .any(|t| t.name.eq_ignore_ascii_case(&self.issue_type));
+                let preferred = match self.default_issue_types.get(&project.key) {
+                    Some(default) if !has_match => default,
+                    _ => &self.issue_type,
+                };
+                let mapped = project.matching_issue_type(preferred).map(|t| t.id.clone());
+                self.focused_index = project
+                    .standard_issue_types()
+                    .iter()
+                    .position(|t| Some(&t.id) == mapped.as_ref())
+                    .unwrap_or(0);
+                self.project_index = Some(project_index);
+                None
+            }
+        }
+    }
+
+    /// Step back to the project list; returns false when already there
+    pub fn back(&mut self) -> bool {
+        match self.project_index.take() {
+            Some(idx) => {
+                self.focused_index = idx;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Move-issue picker widget
+pub struct MoveIssuePicker<'a> {
+    state: &'a MoveIssueState,
+    theme: &'a Theme,
+}
+
+impl<'a> MoveIssuePicker<'a> {
+    pub fn new(state: &'a MoveIssueState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the picker over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let title = match self.state.project() {
+            Some(project) => format!(
+                "Move {} to {} as — [Enter] choose [Esc] back",
+                self.state.ticket_key, project.key
+            ),
+            None => format!(
+                "Move {} to project — [Enter] choose [Esc] cancel",
+                self.state.ticket_key
+            ),
+        };
+
+        let items: Vec<ListItem> = self.state.labels().into_iter().map(ListItem::new).collect();
+        let list = List::new(items)
+            .style(self.theme.normal)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .title_style(self.theme.focused),
+            )
+            .highlight_style(self.theme.selected)
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.state.focused_index));
+
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(id: &str, key: &str, types: &[(&str, &str)]) -> Project {
+        Project {
+            id: id.to_string(),
+            key: key.to_string(),
+            name: format!("Project {}", key),
+            issue_types: types
+                .iter()
+                .map(|(id, name)| IssueType {
+                    id: id.to_string(),
+                    name: name.to_string(),
+                    subtask: false,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_select_project_then_mapped_issue_type() {
+        let mut state = MoveIssueState::new(
+            "OLD-1".to_string(),
+            "Bug".to_string(),
+            vec![
+                project("1", "ONE", &[("10", "Task")]),
+                project("2", "TWO", &[("20", "Task"), ("21", "Bug")]),
+            ],
+            HashMap::new(),
+        );
+
+        state.move_down();
+        assert!(state.select().is_none());
+        assert_eq!(state.project().map(|p| p.key.as_str()), Some("TWO"));
+        assert_eq!(state.focused_index, 1);
+
+        let (project, issue_type) = state.select().unwrap();
+        assert_eq!(project.key, "TWO");
+        assert_eq!(issue_type.id, "21");
+
+        assert!(state.back());
+        assert_eq!(state.focused_index, 1);
+        assert!(!state.back());
+    }
+
+    #[test]
+    fn test_select_project_preselects_default_issue_type() {
+        let mut state = MoveIssueState::new(
+            "OLD-1".to_string(),
+            "Bug".to_string(),
+            vec![project("1", "OPS", &[("10", "Task"), ("11", "Incident")])],
+            HashMap::from([("OPS".to_string(), "Incident".to_string())]),
+        );
+
+        assert!(state.select().is_none());
+        assert_eq!(state.focused_index, 1);
+    }
+}
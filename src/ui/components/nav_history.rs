@@ -0,0 +1,79 @@
+/// Number of places kept in each direction
+const HISTORY_LIMIT: usize = 50;
+
+/// Places visited before and after the current one, for the back and
+/// forward keys, like a browser's history
+#[derive(Debug, Clone)]
+pub struct NavHistory<P> {
+    back: Vec<P>,
+    forward: Vec<P>,
+}
+
+impl<P> Default for NavHistory<P> {
+    fn default() -> Self {
+        Self {
+            back: Vec::new(),
+            forward: Vec::new(),
+        }
+    }
+}
+
+impl<P> NavHistory<P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember the place being left for somewhere new, which drops the
+    /// places gone back from
+    pub fn leave(&mut self, place: P) {
+        if self.back.len() == HISTORY_LIMIT {
+            self.back.remove(0);
+        }
+        self.back.push(place);
+        self.forward.clear();
+    }
+
+    /// The place before `current`, which becomes the next one forward
+    pub fn back(&mut self, current: P) -> Option<P> {
+        let place = self.back.pop()?;
+        self.forward.push(current);
+        Some(place)
+    }
+
+    /// The place gone back from last, with `current` becoming the previous one
+    pub fn forward(&mut self, current: P) -> Option<P> {
+        let place = self.forward.pop()?;
+        self.back.push(current);
+        Some(place)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_back_and_forward() {
+        let mut history = NavHistory::new();
+        assert_eq!(history.back("list"), None);
+
+        history.leave("list");
+        history.leave("PROJ-1");
+        assert_eq!(history.back("PROJ-2"), Some("PROJ-1"));
+        assert_eq!(history.back("PROJ-1"), Some("list"));
+        assert_eq!(history.back("list"), None);
+        assert_eq!(history.forward("list"), Some("PROJ-1"));
+        assert_eq!(history.forward("PROJ-1"), Some("PROJ-2"));
+        assert_eq!(history.forward("PROJ-2"), None);
+
+        // Going somewhere new forgets the way forward
+        history.back("PROJ-2");
+        history.leave("PROJ-1");
+        assert_eq!(history.forward("PROJ-3"), None);
+
+        for _ in 0..HISTORY_LIMIT + 5 {
+            history.leave("x");
+        }
+        assert_eq!(history.back.len(), HISTORY_LIMIT);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Form fields in display order
+const FIELDS: [&str; 5] = ["Project", "Status", "Assignee", "Label", "Text"];
+
+/// State for the query builder: one text input per field, compiled to JQL.
+/// Fields take comma-separated values, e.g. "To Do, In Progress"
+#[derive(Debug, Clone, Default)]
+pub struct QueryBuilderState {
+    pub inputs: [String; 5],
+    pub focused_field: usize,
+}
+
+impl QueryBuilderState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next_field(&mut self) {
+        self.focused_field = (self.focused_field + 1) % FIELDS.len();
+    }
+
+    pub fn previous_field(&mut self) {
+        self.focused_field = (self.focused_field + FIELDS.len() - 1) % FIELDS.len();
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.inputs[self.focused_field].push(c);
+    }
+
+    pub fn pop(&mut self) {
+        self.inputs[self.focused_field].pop();
+    }
+
+    /// The JQL for the filled-in fields, or None when the form is empty
+    pub fn to_jql(&self) -> Option<String> {
+        let [project, status, assignee, label, text] = &self.inputs;
+        let mut clauses = Vec::new();
+        if let Some(clause) = field_clause("project", project, quote) {
+            clauses.push(clause);
+        }
+        if let Some(clause) = field_clause("status", status, quote) {
+            clauses.push(clause);
+        }
+        if let Some(clause) = field_clause("assignee", assignee, assignee_value) {
+            clauses.push(clause);
+        }
+        if let Some(clause) = field_clause("labels", label, quote) {
+            clauses.push(clause);
+        }
+        let text = text.trim();
+        if !text.is_empty() {
+            clauses.push(format!("text ~ {}", quote(text)));
+        }
+
+        if clauses.is_empty() {
+            None
+        } else {
+            Some(format!("{} ORDER BY updated DESC", clauses.join(" AND ")))
+        }
+    }
+}
+
+/// `field = value` for one value, `field in (...)` for several
+fn field_clause(field: &str, input: &str, value: fn(&str) -> String) -> Option<String> {
+    let values: Vec<String> = input
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(value)
+        .collect();
+    match values.as_slice() {
+        [] => None,
+        [value] if value == "EMPTY" => Some(format!("{} is EMPTY", field)),
+        [value] => Some(format!("{} = {}", field, value)),
+        _ => Some(format!("{} in ({})", field, values.join(", "))),
+    }
+}
+
+/// "me" and "none" stand for the current user and unassigned
+fn assignee_value(value: &str) -> String {
+    match value.to_lowercase().as_str() {
+        "me" => "currentUser()".to_string(),
+        "none" | "unassigned" => "EMPTY".to_string(),
+        _ => quote(value),
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Query builder popup
+pub struct QueryBuilder<'a> {
+    state: &'a QueryBuilderState,
+    theme: &'a Theme,
+}
+
+impl<'a> QueryBuilder<'a> {
+    pub fn new(state: &'a QueryBuilderState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the form and the JQL it compiles to over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let mut lines: Vec<Line> = FIELDS
+            .iter()
+            .zip(&self.state.inputs)
+            .enumerate()
+            .map(|(idx, (name, input))| {
+                let focused = idx == self.state.focused_field;
+                let value = if focused {
+                    format!("{}_", input)
+                } else {
+                    input.clone()
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:<10}", name), self.theme.focused),
+                    Span::styled(
+                        value,
+                        if focused {
+                            self.theme.selected
+                        } else {
+                            self.theme.normal
+                        },
+                    ),
+                ])
+            })
+            .collect();
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            self.state.to_jql().unwrap_or_default(),
+            self.theme.status_in_progress,
+        )));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Assignee: me/none/name, commas for several  [Tab] next [Enter] search [Esc] cancel",
+            self.theme.normal,
+        )));
+
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Query builder")
+                .title_style(self.theme.focused),
+        );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder(inputs: [&str; 5]) -> QueryBuilderState {
+        QueryBuilderState {
+            inputs: inputs.map(str::to_string),
+            focused_field: 0,
+        }
+    }
+
+    #[test]
+    fn test_to_jql() {
+        assert_eq!(builder(["", "", "", "", ""]).to_jql(), None);
+        assert_eq!(
+            builder(["PROJ", "To Do, In Progress", "me", "", ""]).to_jql().unwrap(),
+            "project = \"PROJ\" AND status in (\"To Do\", \"In Progress\") \
+             AND assignee = currentUser() ORDER BY updated DESC"
+        );
+        assert_eq!(
+            builder(["", "", "none", "backend", "say \"hi\""]).to_jql().unwrap(),
+            "assignee is EMPTY AND labels = \"backend\" AND text ~ \"say \\\"hi\\\"\" \
+             ORDER BY updated DESC"
+        );
+    }
+}
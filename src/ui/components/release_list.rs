@@ -0,0 +1,167 @@
+use crate::domain::models::project::Version;
+use crate::ui::theme::Theme;
+use chrono::NaiveDate;
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::cmp::Reverse;
+
+/// Release dates are shown in ISO form whatever the configured format,
+/// which may carry a time of day
+const RELEASE_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// State for the list of a project's versions
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseListState {
+    pub project_key: String,
+    pub versions: Vec<Version>,
+    pub focused_index: usize,
+}
+
+impl ReleaseListState {
+    /// Unreleased versions first, soonest due first, then released ones
+    /// latest first
+    pub fn set_versions(&mut self, project_key: String, mut versions: Vec<Version>) {
+        versions.sort_by_key(|version| {
+            (
+                version.released,
+                if version.released {
+                    Reverse(version.release_date)
+                } else {
+                    Reverse(None)
+                },
+                version.release_date.is_none(),
+                version.release_date,
+            )
+        });
+        self.project_key = project_key;
+        self.versions = versions;
+        self.focused_index = 0;
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.focused_index + 1 < self.versions.len() {
+            self.focused_index += 1;
+        }
+    }
+
+    pub fn focused_version(&self) -> Option<&Version> {
+        self.versions.get(self.focused_index)
+    }
+}
+
+/// When a version was or is due to be released, e.g. "due 2024-04-30"
+pub fn release_label(version: &Version, today: NaiveDate) -> String {
+    match (version.release_date, version.released) {
+        (Some(date), true) => format!("released {}", date.format(RELEASE_DATE_FORMAT)),
+        (None, true) => "released".to_string(),
+        (Some(date), false) if date < today => format!("overdue since {}", date.format(RELEASE_DATE_FORMAT)),
+        (Some(date), false) => format!("due {}", date.format(RELEASE_DATE_FORMAT)),
+        (None, false) => "unscheduled".to_string(),
+    }
+}
+
+/// Release list widget
+pub struct ReleaseList<'a> {
+    state: &'a ReleaseListState,
+    today: NaiveDate,
+    theme: &'a Theme,
+}
+
+impl<'a> ReleaseList<'a> {
+    pub fn new(state: &'a ReleaseListState, today: NaiveDate, theme: &'a Theme) -> Self {
+        Self { state, today, theme }
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let title = format!(
+            "Releases of {} — [Enter] issues [R]elease [N]otes",
+            self.state.project_key
+        );
+        if self.state.versions.is_empty() {
+            let paragraph = Paragraph::new("No versions in this project")
+                .style(self.theme.normal)
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::ALL).title(title));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .state
+            .versions
+            .iter()
+            .map(|version| {
+                let style = match version.release_date {
+                    _ if version.released => self.theme.success,
+                    Some(date) if date < self.today => self.theme.error,
+                    _ => self.theme.normal,
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:<24}", version.name), self.theme.focused),
+                    Span::styled(release_label(version, self.today), style),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .title_style(self.theme.focused),
+            )
+            .highlight_style(self.theme.selected)
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.state.focused_index));
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(name: &str, release_date: Option<NaiveDate>, released: bool) -> Version {
+        Version {
+            id: name.to_string(),
+            name: name.to_string(),
+            start_date: None,
+            release_date,
+            released,
+        }
+    }
+
+    #[test]
+    fn test_versions_sorted_by_release() {
+        let date = |month| NaiveDate::from_ymd_opt(2024, month, 1);
+        let mut state = ReleaseListState::default();
+        state.set_versions(
+            "PROJ".to_string(),
+            vec![
+                version("1.0", date(1), true),
+                version("3.0", None, false),
+                version("1.1", date(2), true),
+                version("2.1", date(6), false),
+                version("2.0", date(4), false),
+            ],
+        );
+        let names: Vec<&str> = state.versions.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["2.0", "2.1", "3.0", "1.1", "1.0"]);
+
+        let today = date(5).unwrap();
+        assert_eq!(release_label(&state.versions[0], today), "overdue since 2024-04-01");
+        assert_eq!(release_label(&state.versions[1], today), "due 2024-06-01");
+        assert_eq!(release_label(&state.versions[2], today), "unscheduled");
+        assert_eq!(release_label(&state.versions[3], today), "released 2024-02-01");
+    }
+}
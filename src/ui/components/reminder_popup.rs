@@ -0,0 +1,66 @@
+use crate::domain::services::reminder_service::DueReminder;
+use crate::ui::components::confirm_dialog::centered_rect;
+use crate::ui::theme::Theme;
+use chrono::NaiveDate;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Popup listing my tickets that are overdue or due soon
+pub struct ReminderPopup<'a> {
+    reminders: &'a [DueReminder],
+    today: NaiveDate,
+    theme: &'a Theme,
+}
+
+impl<'a> ReminderPopup<'a> {
+    pub fn new(reminders: &'a [DueReminder], today: NaiveDate, theme: &'a Theme) -> Self {
+        Self {
+            reminders,
+            today,
+            theme,
+        }
+    }
+
+    /// Render the popup centered in the given area
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let popup = centered_rect(70, 50, area);
+
+        let mut lines: Vec<Line> = self
+            .reminders
+            .iter()
+            .map(|reminder| {
+                let due_style = if reminder.is_overdue(self.today) {
+                    self.theme.error
+                } else {
+                    self.theme.warning
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:<12}", reminder.key), self.theme.focused),
+                    Span::styled(format!("{:<15}", reminder.due_label(self.today)), due_style),
+                    Span::styled(reminder.summary.as_str(), self.theme.normal),
+                ])
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Press any key to dismiss",
+            self.theme.normal,
+        )));
+
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Due soon ({})", self.reminders.len()))
+                    .title_style(self.theme.warning),
+            );
+
+        frame.render_widget(Clear, popup);
+        frame.render_widget(paragraph, popup);
+    }
+}
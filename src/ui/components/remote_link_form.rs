@@ -0,0 +1,149 @@
+use crate::ui::theme::Theme;
+use crate::utils::links;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// State for the prompt attaching a web link to the ticket in the detail view
+#[derive(Debug, Clone)]
+pub struct RemoteLinkFormState {
+    pub ticket_key: String,
+    pub title: String,
+    pub url: String,
+    /// Typing goes to the URL rather than the title
+    pub editing_url: bool,
+}
+
+impl RemoteLinkFormState {
+    /// Form starting at the URL, the field usually pasted first
+    pub fn new(ticket_key: String) -> Self {
+        Self {
+            ticket_key,
+            title: String::new(),
+            url: String::new(),
+            editing_url: true,
+        }
+    }
+
+    pub fn push(&mut self, c: char) {
+        if self.editing_url {
+            // URLs cannot contain whitespace, so drop what pasting adds
+            if !c.is_whitespace() {
+                self.url.push(c);
+            }
+        } else {
+            self.title.push(c);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        if self.editing_url {
+            self.url.pop();
+        } else {
+            self.title.pop();
+        }
+    }
+
+    pub fn switch_field(&mut self) {
+        self.editing_url = !self.editing_url;
+    }
+
+    /// Title and URL to attach, once the URL is an http(s) URL; the title
+    /// defaults to the URL
+    pub fn link(&self) -> Option<(String, String)> {
+        let url = self.url.trim();
+        if links::bare_url_len(url) != Some(url.len()) {
+            return None;
+        }
+        let title = match self.title.trim() {
+            "" => url,
+            title => title,
+        };
+        Some((title.to_string(), url.to_string()))
+    }
+}
+
+/// Web link prompt widget
+pub struct RemoteLinkForm<'a> {
+    state: &'a RemoteLinkFormState,
+    theme: &'a Theme,
+}
+
+impl<'a> RemoteLinkForm<'a> {
+    pub fn new(state: &'a RemoteLinkFormState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the prompt over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let field = |label: &str, value: &str, focused: bool| {
+            let (value, style) = if focused {
+                (format!("{}_", value), self.theme.selected)
+            } else {
+                (value.to_string(), self.theme.normal)
+            };
+            Line::from(vec![
+                Span::styled(format!("{:<7}", label), self.theme.focused),
+                Span::styled(value, style),
+            ])
+        };
+
+        let lines = vec![
+            field("URL:", &self.state.url, self.state.editing_url),
+            field("Title:", &self.state.title, !self.state.editing_url),
+            Line::from(""),
+            Line::from(Span::styled(
+                "[Tab] switch field [Enter] attach [Esc] cancel",
+                self.theme.normal,
+            )),
+        ];
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Add web link to {}", self.state.ticket_key))
+                .title_style(self.theme.focused),
+        );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_link_form_input() {
+        let mut state = RemoteLinkFormState::new("PROJ-1".to_string());
+        for c in "https://example.com/ design".chars() {
+            state.push(c);
+        }
+        assert_eq!(state.url, "https://example.com/design");
+        assert_eq!(
+            state.link(),
+            Some(("https://example.com/design".to_string(), "https://example.com/design".to_string()))
+        );
+
+        state.switch_field();
+        for c in "Design doc!".chars() {
+            state.push(c);
+        }
+        state.pop();
+        assert_eq!(
+            state.link(),
+            Some(("Design doc".to_string(), "https://example.com/design".to_string()))
+        );
+
+        state.switch_field();
+        for _ in 0.."/design".len() + 11 {
+            state.pop();
+        }
+        assert_eq!(state.url, "https://");
+        assert_eq!(state.link(), None);
+    }
+}
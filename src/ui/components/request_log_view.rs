@@ -0,0 +1,82 @@
+use crate::infrastructure::api::request_log::RequestRecord;
+use crate::ui::theme::Theme;
+use crate::utils::time::DisplayTimezone;
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Debug screen listing recent HTTP requests, newest first
+pub struct RequestLogView<'a> {
+    records: &'a [RequestRecord],
+    timezone: DisplayTimezone,
+    theme: &'a Theme,
+}
+
+impl<'a> RequestLogView<'a> {
+    pub fn new(records: &'a [RequestRecord], timezone: DisplayTimezone, theme: &'a Theme) -> Self {
+        Self {
+            records,
+            timezone,
+            theme,
+        }
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Debug — last {} API request(s) [D] close",
+                self.records.len()
+            ))
+            .title_style(self.theme.focused);
+
+        if self.records.is_empty() {
+            let paragraph = Paragraph::new("No API requests yet")
+                .style(self.theme.normal)
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .records
+            .iter()
+            .rev()
+            .map(|record| ListItem::new(self.record_line(record)))
+            .collect();
+        frame.render_widget(List::new(items).block(block), area);
+    }
+
+    /// "12:04:31 GET  200   184ms  0 retries  /rest/api/3/search?jql=…"
+    fn record_line(&self, record: &'a RequestRecord) -> Line<'a> {
+        let (status, status_style) = match record.status {
+            Some(status) if status < 400 => (status.to_string(), self.theme.status_done),
+            Some(status) => (status.to_string(), self.theme.error),
+            None => ("ERR".to_string(), self.theme.error),
+        };
+        let retries_style = if record.retries > 0 {
+            self.theme.warning
+        } else {
+            self.theme.normal
+        };
+
+        Line::from(vec![
+            Span::styled(
+                format!("{} ", self.timezone.format(&record.timestamp, "%H:%M:%S")),
+                self.theme.normal,
+            ),
+            Span::styled(format!("{:<5}", record.method), self.theme.focused),
+            Span::styled(format!("{:<4}", status), status_style),
+            Span::styled(
+                format!("{:>6}ms ", record.latency.as_millis()),
+                self.theme.normal,
+            ),
+            Span::styled(format!("{} retries  ", record.retries), retries_style),
+            Span::styled(record.endpoint.as_str(), self.theme.normal),
+        ])
+    }
+}
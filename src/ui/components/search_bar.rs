@@ -0,0 +1,78 @@
+use crate::domain::services::filter_service::FilterService;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// State for the search bar; the text keeps filtering the list while the
+/// bar is closed
+#[derive(Debug, Clone, Default)]
+pub struct SearchState {
+    pub open: bool,
+    pub query: String,
+    /// Search Jira with `text ~` instead of filtering the loaded tickets
+    pub jira: bool,
+}
+
+impl SearchState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// JQL narrowing `base` to issues containing the text, or None when
+    /// there is nothing to search
+    pub fn to_jql(&self, base: &str) -> Option<String> {
+        let text = self.query.trim();
+        if text.is_empty() {
+            return None;
+        }
+        let clause = format!(
+            "text ~ \"{}\"",
+            text.replace('\\', "\\\\").replace('"', "\\\"")
+        );
+        Some(FilterService::restrict_query(base, &clause))
+    }
+}
+
+/// One-line search input shown under the ticket list
+pub struct SearchBar<'a> {
+    state: &'a SearchState,
+    theme: &'a Theme,
+}
+
+impl<'a> SearchBar<'a> {
+    pub fn new(state: &'a SearchState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the bar into `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let scope = if self.state.jira { "Search Jira: " } else { "Filter: " };
+        let line = Line::from(vec![
+            Span::styled(scope, self.theme.focused),
+            Span::styled(format!("{}_", self.state.query), self.theme.selected),
+            Span::styled("  [Tab] local/Jira [Enter] keep [Esc] clear", self.theme.normal),
+        ]);
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_jql_escapes_quotes() {
+        let mut state = SearchState::new();
+        let base = "assignee = currentUser() ORDER BY updated DESC";
+        assert_eq!(state.to_jql(base), None);
+        state.query = " login \"bug\" ".to_string();
+        assert_eq!(
+            state.to_jql(base).unwrap(),
+            "text ~ \"login \\\"bug\\\"\" AND (assignee = currentUser()) ORDER BY updated DESC"
+        );
+    }
+}
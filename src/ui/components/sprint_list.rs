@@ -0,0 +1,255 @@
+use crate::domain::models::sprint::{Sprint, SprintState};
+use crate::ui::theme::Theme;
+use crate::utils::time::{DisplayTimezone, DEFAULT_DATE_FORMAT};
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// State for sprint list widget
+#[derive(Debug, Clone, Default)]
+pub struct SprintListState {
+    pub sprints: Vec<Sprint>,
+    pub focused_index: Option<usize>,
+    /// Ids of the sprints ticked in the sprint filter picker
+    pub checked: Vec<String>,
+}
+
+impl SprintListState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_sprints(&mut self, sprints: Vec<Sprint>) {
+        self.sprints = sprints;
+        self.focused_index = if self.sprints.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+    }
+
+    pub fn move_up(&mut self) {
+        if let Some(idx) = self.focused_index {
+            if idx > 0 {
+                self.focused_index = Some(idx - 1);
+            }
+        } else if !self.sprints.is_empty() {
+            self.focused_index = Some(0);
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if let Some(idx) = self.focused_index {
+            if idx < self.sprints.len().saturating_sub(1) {
+                self.focused_index = Some(idx + 1);
+            }
+        } else if !self.sprints.is_empty() {
+            self.focused_index = Some(0);
+        }
+    }
+
+    pub fn focused_sprint(&self) -> Option<&Sprint> {
+        self.focused_index
+            .and_then(|idx| self.sprints.get(idx))
+    }
+
+    /// Tick the focused sprint, or untick it when already ticked
+    pub fn toggle_checked(&mut self) {
+        let Some(id) = self.focused_sprint().map(|sprint| sprint.id.clone()) else {
+            return;
+        };
+        match self.checked.iter().position(|checked| *checked == id) {
+            Some(idx) => {
+                self.checked.remove(idx);
+            }
+            None => self.checked.push(id),
+        }
+    }
+
+    /// The ticked sprints, or the focused one when none are ticked
+    pub fn checked_sprints(&self) -> Vec<Sprint> {
+        if self.checked.is_empty() {
+            return self.focused_sprint().cloned().into_iter().collect();
+        }
+        self.sprints
+            .iter()
+            .filter(|sprint| self.checked.contains(&sprint.id))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Sprint list widget
+pub struct SprintList<'a> {
+    state: &'a SprintListState,
+    title: &'a str,
+    theme: &'a Theme,
+    timezone: DisplayTimezone,
+    date_format: &'a str,
+    checkboxes: bool,
+}
+
+impl<'a> SprintList<'a> {
+    pub fn new(state: &'a SprintListState, title: &'a str, theme: &'a Theme) -> Self {
+        Self {
+            state,
+            title,
+            theme,
+            timezone: DisplayTimezone::Local,
+            date_format: DEFAULT_DATE_FORMAT,
+            checkboxes: false,
+        }
+    }
+
+    /// Prefix each sprint with a tick box showing whether it is checked
+    pub fn checkboxes(mut self, checkboxes: bool) -> Self {
+        self.checkboxes = checkboxes;
+        self
+    }
+
+    /// Show sprint dates with this strftime format
+    pub fn date_format(mut self, date_format: &'a str) -> Self {
+        self.date_format = date_format;
+        self
+    }
+
+    /// Show sprint dates in this timezone
+    pub fn timezone(mut self, timezone: DisplayTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        if self.state.sprints.is_empty() {
+            let paragraph = Paragraph::new("No active or future sprints")
+                .style(self.theme.normal)
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(self.title),
+                );
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .state
+            .sprints
+            .iter()
+            .map(|sprint| ListItem::new(self.sprint_line(sprint)))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.title)
+                    .title_style(self.theme.focused),
+            )
+            .highlight_style(self.theme.selected)
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default();
+        if let Some(focused_idx) = self.state.focused_index {
+            list_state.select(Some(focused_idx));
+        }
+
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+
+    /// Build a single sprint row: state, name, dates and goal
+    fn sprint_line(&self, sprint: &'a Sprint) -> Line<'a> {
+        let (state_label, state_style) = match sprint.state {
+            SprintState::Active => ("ACTIVE", self.theme.status_in_progress),
+            SprintState::Future => ("FUTURE", self.theme.status_todo),
+            SprintState::Closed => ("CLOSED", self.theme.status_done),
+        };
+
+        let dates = match (sprint.start_date, sprint.end_date) {
+            (Some(start), Some(end)) => format!(
+                "  {} → {}",
+                self.timezone.format(&start, self.date_format),
+                self.timezone.format(&end, self.date_format)
+            ),
+            _ => String::new(),
+        };
+
+        let mut spans = Vec::new();
+        if self.checkboxes {
+            let check = if self.state.checked.contains(&sprint.id) {
+                "[x] "
+            } else {
+                "[ ] "
+            };
+            spans.push(Span::styled(check, self.theme.normal));
+        }
+        spans.extend([
+            Span::styled(format!("{:<7}", state_label), state_style),
+            Span::styled(sprint.name.as_str(), self.theme.normal),
+            Span::styled(dates, self.theme.normal),
+        ]);
+        if let Some(goal) = sprint.goal.as_deref().filter(|g| !g.is_empty()) {
+            spans.push(Span::styled(format!("  — {}", goal), self.theme.normal));
+        }
+
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sprint(id: &str, state: SprintState) -> Sprint {
+        Sprint {
+            id: id.to_string(),
+            name: format!("Sprint {}", id),
+            state,
+            start_date: None,
+            end_date: None,
+            complete_date: None,
+            goal: None,
+        }
+    }
+
+    #[test]
+    fn test_sprint_list_navigation() {
+        let mut state = SprintListState::new();
+        assert!(state.focused_sprint().is_none());
+
+        state.set_sprints(vec![
+            sprint("1", SprintState::Active),
+            sprint("2", SprintState::Future),
+        ]);
+        assert_eq!(state.focused_sprint().map(|s| s.id.as_str()), Some("1"));
+
+        state.move_down();
+        state.move_down();
+        assert_eq!(state.focused_sprint().map(|s| s.id.as_str()), Some("2"));
+
+        state.move_up();
+        assert_eq!(state.focused_sprint().map(|s| s.id.as_str()), Some("1"));
+    }
+
+    #[test]
+    fn test_checked_sprints_fall_back_to_focused() {
+        let mut state = SprintListState::new();
+        state.set_sprints(vec![
+            sprint("1", SprintState::Active),
+            sprint("2", SprintState::Future),
+        ]);
+        assert_eq!(state.checked_sprints(), vec![sprint("1", SprintState::Active)]);
+
+        state.move_down();
+        state.toggle_checked();
+        state.move_up();
+        assert_eq!(state.checked_sprints(), vec![sprint("2", SprintState::Future)]);
+        state.move_down();
+        state.toggle_checked();
+        assert!(state.checked.is_empty());
+    }
+}
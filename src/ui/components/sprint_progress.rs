@@ -0,0 +1,115 @@
+use crate::domain::services::sprint_service::SprintProgress;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Sparkline},
+    Frame,
+};
+
+/// Burndown panel for the active sprint
+pub struct SprintProgressPanel<'a> {
+    sprint_name: &'a str,
+    progress: &'a SprintProgress,
+    theme: &'a Theme,
+}
+
+impl<'a> SprintProgressPanel<'a> {
+    pub fn new(sprint_name: &'a str, progress: &'a SprintProgress, theme: &'a Theme) -> Self {
+        Self {
+            sprint_name,
+            progress,
+            theme,
+        }
+    }
+
+    /// Render the stats line and the remaining-work sparkline
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Progress — {}", self.sprint_name))
+            .title_style(self.theme.focused);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1)])
+            .split(inner);
+
+        frame.render_widget(Paragraph::new(self.summary_line()), chunks[0]);
+
+        let data = self.progress.remaining_series();
+        let sparkline = Sparkline::default()
+            .data(&data)
+            .style(self.theme.status_in_progress);
+        frame.render_widget(sparkline, chunks[1]);
+    }
+
+    /// Done vs total issues/points and the tracked day count
+    fn summary_line(&self) -> Line<'a> {
+        let mut spans = vec![
+            Span::styled("Issues ", self.theme.normal),
+            Span::styled(
+                format!("{}/{}", self.progress.done_issues(), self.progress.total_issues),
+                self.theme.status_done,
+            ),
+        ];
+
+        if self.progress.uses_points() {
+            spans.push(Span::styled("  Points ", self.theme.normal));
+            spans.push(Span::styled(
+                format!(
+                    "{}/{}",
+                    self.progress.done_points(),
+                    self.progress.total_points
+                ),
+                self.theme.status_done,
+            ));
+        }
+
+        let unit = if self.progress.uses_points() {
+            "points"
+        } else {
+            "issues"
+        };
+        spans.push(Span::styled(
+            format!("  Day {} — remaining {} per day", self.progress.days.len(), unit),
+            self.theme.normal,
+        ));
+
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::services::sprint_service::DailyProgress;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_summary_line_includes_points_when_estimated() {
+        let progress = SprintProgress {
+            total_issues: 4,
+            total_points: 10.0,
+            days: vec![DailyProgress {
+                date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                done_issues: 1,
+                done_points: 2.5,
+            }],
+        };
+        let theme = Theme::default();
+        let panel = SprintProgressPanel::new("Sprint 1", &progress, &theme);
+
+        let text: String = panel
+            .summary_line()
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert!(text.contains("Issues 1/4"));
+        assert!(text.contains("Points 2.5/10"));
+        assert!(text.contains("remaining points"));
+    }
+}
@@ -1,24 +1,258 @@
 use crate::domain::models::ticket::Ticket;
-use crate::domain::models::comment::Comment;
+use crate::domain::models::comment::{Comment, CommentOrder};
+use crate::domain::models::dev_info::{DevInfo, PullRequestStatus};
+use crate::domain::models::remote_link::RemoteLink;
+use crate::domain::models::sla::{Sla, SlaUrgency};
+use crate::domain::models::approval::{Approval, ApprovalDecision};
+use crate::domain::services::metrics_service::StatusDuration;
+use crate::ui::components::avatar::avatar_span;
+use crate::ui::components::detail_search::{self, DetailMatch};
+use crate::ui::components::markdown;
 use crate::ui::theme::Theme;
-use chrono::{DateTime, Utc};
+use crate::utils::links;
+use crate::utils::text::{truncate_to_width, wrap_to_width};
+use crate::utils::time::{DisplayTimezone, DEFAULT_DATE_FORMAT};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, HashSet};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
+/// Lines of a collapsed comment body shown in the sidebar
+const COMMENT_PREVIEW_LINES: usize = 2;
+
+/// Most web links listed under the description before the rest are cut
+const WEB_LINK_ROWS: usize = 4;
+
+/// Most pull requests, branches and commits listed in the sidebar
+const DEV_INFO_ROWS: usize = 6;
+
+/// Indentation of comment bodies under their author line
+const COMMENT_INDENT: &str = "  ";
+
+/// How created, updated and comment times are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// "2h ago", "3d ago"
+    Relative,
+    Absolute,
+}
+
+impl TimestampFormat {
+    /// Parse the timestamp format from its config value
+    pub fn from_config(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "absolute" => TimestampFormat::Absolute,
+            _ => TimestampFormat::Relative,
+        }
+    }
+}
+
+/// Focus and collapsed comments in the detail view's comment sidebar
+#[derive(Debug, Clone, Default)]
+pub struct CommentsState {
+    pub focused_index: usize,
+    /// Ids of comments collapsed to a preview
+    pub collapsed: HashSet<String>,
+}
+
+impl CommentsState {
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self, comment_count: usize) {
+        if self.focused_index + 1 < comment_count {
+            self.focused_index += 1;
+        }
+    }
+
+    /// Expand or collapse a comment
+    pub fn toggle(&mut self, comment_id: &str) {
+        if !self.collapsed.remove(comment_id) {
+            self.collapsed.insert(comment_id.to_string());
+        }
+    }
+
+    pub fn is_collapsed(&self, comment_id: &str) -> bool {
+        self.collapsed.contains(comment_id)
+    }
+}
+
 /// Ticket detail widget
 pub struct TicketDetail<'a> {
     ticket: &'a Ticket,
     comments: &'a [Comment],
+    remote_links: &'a [RemoteLink],
+    dev_info: Option<&'a DevInfo>,
+    slas: &'a [Sla],
+    approvals: &'a [Approval],
     theme: &'a Theme,
+    time_in_status: &'a [StatusDuration],
+    show_avatars: bool,
+    comments_state: Option<&'a CommentsState>,
+    comment_order: CommentOrder,
+    timestamp_format: TimestampFormat,
+    timezone: DisplayTimezone,
+    date_format: &'a str,
+    raw_description: bool,
+    /// Text shown after the issue keys the description and comments mention
+    references: Option<&'a HashMap<String, String>>,
+    /// Custom fields listed with the others, as label and field id
+    custom_fields: &'a [(String, String)],
+    /// Text highlighted in the description and comments
+    search: Option<&'a str>,
+    /// Lines of the description scrolled past
+    description_scroll: u16,
 }
 
 impl<'a> TicketDetail<'a> {
     pub fn new(ticket: &'a Ticket, comments: &'a [Comment], theme: &'a Theme) -> Self {
-        Self { ticket, comments, theme }
+        Self {
+            ticket,
+            comments,
+            theme,
+            time_in_status: &[],
+            remote_links: &[],
+            dev_info: None,
+            slas: &[],
+            approvals: &[],
+            show_avatars: false,
+            comments_state: None,
+            comment_order: CommentOrder::OldestFirst,
+            timestamp_format: TimestampFormat::Absolute,
+            timezone: DisplayTimezone::Local,
+            date_format: DEFAULT_DATE_FORMAT,
+            raw_description: false,
+            references: None,
+            custom_fields: &[],
+            search: None,
+            description_scroll: 0,
+        }
+    }
+
+    /// Highlight this text in the description and comments
+    pub fn search(mut self, query: &'a str) -> Self {
+        self.search = Some(query).filter(|query| !query.is_empty());
+        self
+    }
+
+    pub fn description_scroll(mut self, description_scroll: u16) -> Self {
+        self.description_scroll = description_scroll;
+        self
+    }
+
+    /// The description lines, as shown, and the comments containing `query`
+    pub fn search_matches(&self, query: &str) -> Vec<DetailMatch> {
+        let (text, _) = self.description_text();
+        let description = text.lines.iter().enumerate().filter_map(|(line, text)| {
+            let content: String = text.spans.iter().map(|span| span.content.as_ref()).collect();
+            detail_search::contains_ignore_case(&content, query).then_some(DetailMatch::Description { line })
+        });
+        let comments = self.comments.iter().enumerate().filter_map(|(index, comment)| {
+            detail_search::contains_ignore_case(&comment.body, query).then_some(DetailMatch::Comment { index })
+        });
+        description.chain(comments).collect()
+    }
+
+    /// List these custom fields, as label and field id, where they are set
+    pub fn custom_fields(mut self, custom_fields: &'a [(String, String)]) -> Self {
+        self.custom_fields = custom_fields;
+        self
+    }
+
+    /// Show dates with this strftime format
+    pub fn date_format(mut self, date_format: &'a str) -> Self {
+        self.date_format = date_format;
+        self
+    }
+
+    /// Show times in this timezone
+    pub fn timezone(mut self, timezone: DisplayTimezone) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    /// Show times relative to now or as absolute dates
+    pub fn timestamp_format(mut self, timestamp_format: TimestampFormat) -> Self {
+        self.timestamp_format = timestamp_format;
+        self
+    }
+
+    /// A time in the configured format; relative times are computed at
+    /// render, so they stay current as the view redraws
+    fn timestamp(&self, dt: &DateTime<Utc>) -> String {
+        match self.timestamp_format {
+            TimestampFormat::Relative => format_relative(dt, Utc::now(), self.timezone, self.date_format),
+            TimestampFormat::Absolute => format_date(dt, self.timezone, self.date_format),
+        }
+    }
+
+    /// Focus and collapse comments as tracked by the caller; all comments
+    /// are shown expanded otherwise
+    pub fn comments_state(mut self, comments_state: &'a CommentsState) -> Self {
+        self.comments_state = Some(comments_state);
+        self
+    }
+
+    /// Order the comments were fetched in, named in the sidebar title
+    pub fn comment_order(mut self, comment_order: CommentOrder) -> Self {
+        self.comment_order = comment_order;
+        self
+    }
+
+    /// Show assignee and comment author initials in colored badges
+    pub fn show_avatars(mut self, show_avatars: bool) -> Self {
+        self.show_avatars = show_avatars;
+        self
+    }
+
+    /// Show the description's Markdown source instead of styling it
+    /// Web links attached to the ticket, listed under the description
+    pub fn remote_links(mut self, remote_links: &'a [RemoteLink]) -> Self {
+        self.remote_links = remote_links;
+        self
+    }
+
+    /// Branches, commits and pull requests of the ticket, listed in the
+    /// sidebar when there are any
+    pub fn dev_info(mut self, dev_info: &'a DevInfo) -> Self {
+        self.dev_info = Some(dev_info);
+        self
+    }
+
+    /// SLAs of a service desk request, counting down in the sidebar
+    pub fn slas(mut self, slas: &'a [Sla]) -> Self {
+        self.slas = slas;
+        self
+    }
+
+    /// Approvals of a service desk request, listed in the sidebar
+    pub fn approvals(mut self, approvals: &'a [Approval]) -> Self {
+        self.approvals = approvals;
+        self
+    }
+
+    /// Follow the issue keys mentioned in the description and comments
+    /// with the text for them, e.g. their status and summary
+    pub fn references(mut self, references: &'a HashMap<String, String>) -> Self {
+        self.references = Some(references);
+        self
+    }
+
+    pub fn raw_description(mut self, raw_description: bool) -> Self {
+        self.raw_description = raw_description;
+        self
+    }
+
+    /// Show time-in-status metrics in the sidebar
+    pub fn time_in_status(mut self, time_in_status: &'a [StatusDuration]) -> Self {
+        self.time_in_status = time_in_status;
+        self
     }
 
     /// Render the ticket detail view
@@ -41,31 +275,213 @@ impl<'a> TicketDetail<'a> {
         log::debug!("TicketDetail::render: Rendering ticket details");
         self.render_ticket_details(frame, horizontal_chunks[0]);
         log::debug!("TicketDetail::render: Ticket details rendered");
-        
-        // Render comments sidebar on the right
-        //log::debug!("TicketDetail::render: Rendering comments sidebar");
-        //self.render_comments(frame, horizontal_chunks[1]);
-        //log::debug!("TicketDetail::render: Comments sidebar rendered");
-        //log::debug!("TicketDetail::render: Render completed");
+
+        // Sidebar: time in status, SLAs, approvals and development info
+        // above the comments
+        let sla_lines = self.sla_lines(Utc::now());
+        let approval_lines = self.approval_lines();
+        let dev_lines = self.dev_info_lines();
+        let rows = |lines: &[Line]| match lines.len() {
+            0 => 0,
+            rows => rows as u16 + 2,
+        };
+        let (sla_rows, approval_rows, dev_rows) =
+            (rows(&sla_lines), rows(&approval_lines), rows(&dev_lines));
+        let sidebar_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(40),
+                Constraint::Length(sla_rows),
+                Constraint::Length(approval_rows),
+                Constraint::Length(dev_rows),
+                Constraint::Min(5),
+            ])
+            .split(horizontal_chunks[1]);
+        self.render_time_in_status(frame, sidebar_chunks[0]);
+        if sla_rows > 0 {
+            let paragraph = Paragraph::new(sla_lines)
+                .style(self.theme.normal)
+                .block(Block::default().borders(Borders::ALL).title("SLAs"));
+            frame.render_widget(paragraph, sidebar_chunks[1]);
+        }
+        if approval_rows > 0 {
+            let paragraph = Paragraph::new(approval_lines).style(self.theme.normal).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Approvals — [y] answer"),
+            );
+            frame.render_widget(paragraph, sidebar_chunks[2]);
+        }
+        if let (Some(dev_info), true) = (self.dev_info, dev_rows > 0) {
+            let paragraph = Paragraph::new(dev_lines).style(self.theme.normal).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Development — {}", dev_info.summary())),
+            );
+            frame.render_widget(paragraph, sidebar_chunks[3]);
+        }
+        self.render_comments(frame, sidebar_chunks[4]);
+        log::debug!("TicketDetail::render: Render completed");
     }
 
     /// Render ticket details (left side)
     fn render_ticket_details(&self, frame: &mut Frame, area: Rect) {
-        // Split into sections: header, fields, description, metadata
+        // Web links take no room when there are none
+        let web_link_rows = match self.remote_links.len() {
+            0 => 0,
+            count => count.min(WEB_LINK_ROWS) as u16 + 2,
+        };
+        // Service desk requests and aliased custom fields add their lines
+        // to the fields
+        let field_rows =
+            8 + (self.service_request_lines().len() + self.custom_field_lines().len()) as u16;
+        // Split into sections: header, fields, description, web links, metadata
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Header (key, summary)
-                Constraint::Length(6), // Fields (status, assignee, priority, type)
-                Constraint::Min(5),    // Description (flexible)
-                Constraint::Length(2), // Metadata (created, updated)
+                Constraint::Length(3),             // Header (key, summary)
+                Constraint::Length(field_rows),    // Fields (status, assignee, priority, type)
+                Constraint::Min(5),                // Description (flexible)
+                Constraint::Length(web_link_rows), // Web links
+                Constraint::Length(4),             // Metadata (created, updated)
             ])
             .split(area);
 
         self.render_header(frame, chunks[0]);
         self.render_fields(frame, chunks[1]);
         self.render_description(frame, chunks[2]);
-        self.render_metadata(frame, chunks[3]);
+        if web_link_rows > 0 {
+            self.render_web_links(frame, chunks[3]);
+        }
+        self.render_metadata(frame, chunks[4]);
+    }
+
+    /// Render time spent in each status (right side)
+    fn render_time_in_status(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Time in status");
+
+        if self.time_in_status.is_empty() {
+            let paragraph = Paragraph::new("No status history")
+                .style(self.theme.normal)
+                .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let paragraph = Paragraph::new(self.time_in_status_lines(area.width.saturating_sub(2)))
+            .block(block);
+        frame.render_widget(paragraph, area);
+    }
+
+    /// One line per SLA with the time left at `now`, colored as it nears
+    /// its breach
+    fn sla_lines(&self, now: DateTime<Utc>) -> Vec<Line<'a>> {
+        self.slas
+            .iter()
+            .map(|sla| {
+                let urgency = sla.urgency(now);
+                let style = match urgency {
+                    SlaUrgency::Breached => self.theme.error,
+                    SlaUrgency::AtRisk => self.theme.warning,
+                    SlaUrgency::Met => self.theme.success,
+                    SlaUrgency::OnTrack | SlaUrgency::Paused => self.theme.normal,
+                };
+                Line::from(vec![
+                    Span::styled(format!("◷ {} — ", sla.name), self.theme.normal),
+                    Span::styled(sla_countdown(sla, urgency, now), style),
+                ])
+            })
+            .collect()
+    }
+
+    /// One line per approval with its outcome and approvers; the ones
+    /// waiting on the current user stand out
+    fn approval_lines(&self) -> Vec<Line<'a>> {
+        self.approvals
+            .iter()
+            .map(|approval| {
+                let (symbol, style) = match approval.decision {
+                    ApprovalDecision::Pending if approval.can_answer => ("?", self.theme.warning),
+                    ApprovalDecision::Pending => ("…", self.theme.normal),
+                    ApprovalDecision::Approved => ("✓", self.theme.success),
+                    ApprovalDecision::Declined => ("✗", self.theme.error),
+                };
+                Line::from(vec![
+                    Span::styled(format!("{} {}", symbol, approval.name), style),
+                    Span::styled(format!(" — {}", approval.approvers_summary()), self.theme.normal),
+                ])
+            })
+            .collect()
+    }
+
+    /// Pull requests with their state, then branches, then the latest
+    /// commits, up to `DEV_INFO_ROWS` lines
+    fn dev_info_lines(&self) -> Vec<Line<'a>> {
+        let Some(dev_info) = self.dev_info else {
+            return Vec::new();
+        };
+        let pull_requests = dev_info.pull_requests.iter().map(|pr| {
+            let style = match pr.status {
+                PullRequestStatus::Open => self.theme.status_in_progress,
+                PullRequestStatus::Merged => self.theme.status_done,
+                PullRequestStatus::Declined => self.theme.error,
+                PullRequestStatus::Draft => self.theme.normal,
+            };
+            Line::from(vec![
+                Span::styled(format!("⇄ {} {} ", pr.id, pr.title), self.theme.normal),
+                Span::styled(format!("[{}]", pr.status.label()), style),
+            ])
+        });
+        let branches = dev_info.branches.iter().map(|branch| {
+            Line::from(vec![
+                Span::styled(format!("⎇ {}", branch.name), self.theme.focused),
+                Span::styled(format!(" ({})", branch.repository), self.theme.normal),
+            ])
+        });
+        let commits = dev_info.commits.iter().map(|commit| {
+            Line::from(vec![
+                Span::styled(format!("● {} ", commit.id), self.theme.code),
+                Span::styled(format!("{} — {}", commit.message, commit.author), self.theme.normal),
+            ])
+        });
+        pull_requests.chain(branches).chain(commits).take(DEV_INFO_ROWS).collect()
+    }
+
+    /// One line per status: name, duration and a bar relative to the longest one
+    fn time_in_status_lines(&self, width: u16) -> Vec<Line<'a>> {
+        let name_width = self
+            .time_in_status
+            .iter()
+            .map(|d| d.status.chars().count())
+            .max()
+            .unwrap_or(0);
+        let longest = self
+            .time_in_status
+            .iter()
+            .map(|d| d.duration.num_seconds())
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let bar_width = (width as usize).saturating_sub(name_width + 10).max(1);
+
+        self.time_in_status
+            .iter()
+            .map(|entry| {
+                let bar_len = (entry.duration.num_seconds() * bar_width as i64 / longest) as usize;
+                let style = if entry.current {
+                    self.theme.focused
+                } else {
+                    self.theme.normal
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:<name_width$} ", entry.status), style),
+                    Span::styled(format!("{:>7} ", format_duration(entry.duration)), style),
+                    Span::styled("█".repeat(bar_len), self.theme.status_in_progress),
+                ])
+            })
+            .collect()
     }
 
     /// Render comments sidebar (right side)
@@ -82,30 +498,71 @@ impl<'a> TicketDetail<'a> {
             return;
         }
 
-        // Create list items for comments
+        let default_state = CommentsState::default();
+        let comments_state = self.comments_state.unwrap_or(&default_state);
+        let no_references = HashMap::new();
+        let references = self.references.unwrap_or(&no_references);
+
+        // Body lines are indented under the author line
+        let body_width = area.width.saturating_sub(2 + COMMENT_INDENT.len() as u16) as usize;
         let items: Vec<ListItem> = self
             .comments
             .iter()
-            .map(|comment| {
+            .enumerate()
+            .map(|(idx, comment)| {
                 let author_name = &comment.author.display_name;
-                let created_str = format_date(&comment.created);
-                let body_preview = if comment.body.len() > 50 {
-                    format!("{}...", &comment.body[..50])
+                let created_str = self.timestamp(&comment.created);
+                let collapsed = comments_state.is_collapsed(&comment.id);
+                let body_lines = if collapsed {
+                    preview_lines(&comment.body, body_width, COMMENT_PREVIEW_LINES)
                 } else {
-                    comment.body.clone()
+                    wrap_to_width(&comment.body, body_width)
                 };
 
-                // Create a multi-line item
-                let lines = vec![
-                    Line::from(vec![
-                        Span::styled(
-                            format!("{} - ", author_name),
-                            self.theme.focused,
-                        ),
-                        Span::styled(created_str, self.theme.normal),
-                    ]),
-                    Line::from(vec![Span::styled(body_preview, self.theme.normal)]),
-                ];
+                let mut header = Vec::new();
+                let marker = if collapsed { "▶ " } else { "▼ " };
+                header.push(Span::styled(marker, self.theme.normal));
+                if self.show_avatars {
+                    header.push(avatar_span(&comment.author, self.theme));
+                    header.push(Span::raw(" "));
+                }
+                let author_style = if idx == comments_state.focused_index {
+                    self.theme.selected
+                } else {
+                    self.theme.focused
+                };
+                header.push(Span::styled(author_name.clone(), author_style));
+                header.push(Span::styled(format!(" - {}", created_str), self.theme.normal));
+                // Badge restricted and internal comments so they are not quoted to
+                // customers, and on service desk requests the ones customers see
+                if !comment.visibility.is_public() {
+                    header.push(Span::styled(
+                        format!(" [{}]", comment.visibility.label()),
+                        self.theme.warning,
+                    ));
+                } else if self.ticket.service_request.is_some() {
+                    header.push(Span::styled(
+                        format!(" [{}]", comment.visibility.service_desk_label()),
+                        self.theme.focused,
+                    ));
+                }
+
+                // Create a multi-line item, separated from the next comment
+                let mut lines = vec![Line::from(header)];
+                lines.extend(body_lines.iter().map(|line| {
+                    let mut spans = vec![Span::raw(COMMENT_INDENT)];
+                    let mentions =
+                        mention_spans(line, &comment.mentions, self.theme.normal, self.theme.mention);
+                    spans.extend(mentions.into_iter().flat_map(|span| {
+                        if span.style == self.theme.normal {
+                            link_spans(&span.content, self.theme, references)
+                        } else {
+                            vec![span]
+                        }
+                    }));
+                    self.highlight(Line::from(spans))
+                }));
+                lines.push(Line::from(""));
 
                 ListItem::new(lines)
             })
@@ -115,11 +572,18 @@ impl<'a> TicketDetail<'a> {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(format!("Comments ({})", self.comments.len())),
+                    .title(format!(
+                        "Comments ({}, {}) — [↑↓] comment [space] expand/collapse [O]rder",
+                        self.comments.len(),
+                        self.comment_order.label()
+                    )),
             )
             .style(self.theme.normal);
 
-        frame.render_widget(list, area);
+        // Keep the focused comment in view
+        let mut list_state = ListState::default();
+        list_state.select(Some(comments_state.focused_index));
+        frame.render_stateful_widget(list, area, &mut list_state);
     }
 
     /// Render header with key and summary
@@ -159,31 +623,56 @@ impl<'a> TicketDetail<'a> {
             .map(|u| u.display_name.clone())
             .unwrap_or_else(|| "Unassigned".to_string());
 
-        let fields_text = vec![
+        let mut assignee_spans = vec![Span::styled("Assignee: ", self.theme.normal)];
+        if let (true, Some(assignee)) = (self.show_avatars, &self.ticket.assignee) {
+            assignee_spans.push(avatar_span(assignee, self.theme));
+            assignee_spans.push(Span::raw(" "));
+        }
+        assignee_spans.push(Span::styled(assignee_name, self.theme.normal));
+
+        let mut reporter_spans = vec![Span::styled("Reporter: ", self.theme.normal)];
+        if let (true, Some(reporter)) = (self.show_avatars, &self.ticket.reporter) {
+            reporter_spans.push(avatar_span(reporter, self.theme));
+            reporter_spans.push(Span::raw(" "));
+        }
+        reporter_spans.push(Span::styled(
+            self.ticket
+                .reporter
+                .as_ref()
+                .map(|u| u.display_name.clone())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            self.theme.normal,
+        ));
+
+        let mut fields_text = vec![
             Line::from(vec![
                 Span::styled("Status: ", self.theme.normal),
                 Span::styled(
-                    format!("{}", self.ticket.status.name),
+                    format!(
+                        "{}{}",
+                        self.theme.status_symbol(status_category_str),
+                        self.ticket.status.name
+                    ),
                     status_style,
                 ),
             ]),
             Line::from(vec![
                 Span::styled("Priority: ", self.theme.normal),
-                Span::styled(priority_str, priority_style),
+                Span::styled(self.theme.priority_label(&priority_str), priority_style),
             ]),
             Line::from(vec![
                 Span::styled("Type: ", self.theme.normal),
                 Span::styled(self.ticket.issue_type.clone(), self.theme.normal),
             ]),
-            Line::from(vec![
-                Span::styled("Assignee: ", self.theme.normal),
-                Span::styled(assignee_name, self.theme.normal),
-            ]),
+            Line::from(assignee_spans),
+            Line::from(reporter_spans),
             Line::from(vec![
                 Span::styled("Project: ", self.theme.normal),
                 Span::styled(self.ticket.project_key.clone(), self.theme.normal),
             ]),
         ];
+        fields_text.extend(self.service_request_lines());
+        fields_text.extend(self.custom_field_lines());
 
         let paragraph = Paragraph::new(fields_text)
             .block(Block::default().borders(Borders::ALL).title("Fields"));
@@ -191,31 +680,127 @@ impl<'a> TicketDetail<'a> {
         frame.render_widget(paragraph, area);
     }
 
-    /// Render description
+    /// The aliased custom fields set on the ticket
+    fn custom_field_lines(&self) -> Vec<Line<'static>> {
+        self.custom_fields
+            .iter()
+            .filter_map(|(label, id)| {
+                let value = self.ticket.custom_field(id)?;
+                Some(Line::from(vec![
+                    Span::styled(format!("{}: ", label), self.theme.normal),
+                    Span::styled(value.to_string(), self.theme.normal),
+                ]))
+            })
+            .collect()
+    }
+
+    /// Request type, portal link and who the request is shared with, for
+    /// issues raised through a service desk
+    fn service_request_lines(&self) -> Vec<Line<'static>> {
+        let Some(request) = &self.ticket.service_request else {
+            return Vec::new();
+        };
+        let mut request_spans = vec![
+            Span::styled("Request: ", self.theme.normal),
+            Span::styled(request.request_type.clone(), self.theme.normal),
+        ];
+        if let Some(portal_url) = &request.portal_url {
+            request_spans.push(Span::styled(" — ", self.theme.normal));
+            request_spans.push(Span::styled(portal_url.clone(), self.theme.link));
+        }
+        let mut lines = vec![Line::from(request_spans)];
+        let shared_with = request.shared_with();
+        if !shared_with.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled("Shared with: ", self.theme.normal),
+                Span::styled(shared_with, self.theme.normal),
+            ]));
+        }
+        lines
+    }
+
+    /// Render the web links attached to the ticket
+    fn render_web_links(&self, frame: &mut Frame, area: Rect) {
+        let mut lines: Vec<Line> = self
+            .remote_links
+            .iter()
+            .take(WEB_LINK_ROWS)
+            .map(|link| {
+                let mut spans = vec![Span::styled(link.title.clone(), self.theme.normal)];
+                if let Some(application) = link.application_label() {
+                    spans.push(Span::styled(format!(" ({})", application), self.theme.normal));
+                }
+                spans.push(Span::styled(" — ", self.theme.normal));
+                spans.push(Span::styled(link.url.clone(), self.theme.link));
+                Line::from(spans)
+            })
+            .collect();
+        if self.remote_links.len() > WEB_LINK_ROWS {
+            // Make room to say how many more the link picker lists
+            lines.truncate(WEB_LINK_ROWS - 1);
+            lines.push(Line::from(Span::styled(
+                format!("… {} more — [w] links", self.remote_links.len() - lines.len()),
+                self.theme.normal,
+            )));
+        }
+
+        let paragraph = Paragraph::new(lines).style(self.theme.normal).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Web links ({}) — [w] open [W] add", self.remote_links.len())),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    /// The description, styled or as its Markdown source, and its title
+    fn description_text(&self) -> (Text<'a>, &'static str) {
+        let no_references = HashMap::new();
+        let references = self.references.unwrap_or(&no_references);
+        match self.ticket.description.as_deref() {
+            None => (Text::from("No description provided."), "Description"),
+            Some(source) if self.raw_description => {
+                (Text::from(source), "Description (raw) — [p] rendered")
+            }
+            Some(source) => (
+                Text::from(markdown::render(source, self.theme, references)),
+                "Description — [p] raw",
+            ),
+        }
+    }
+
+    /// The line with the search text highlighted, if any
+    fn highlight(&self, line: Line<'a>) -> Line<'a> {
+        match self.search {
+            Some(query) => detail_search::highlight(line, query, self.theme.selected),
+            None => line,
+        }
+    }
+
+    /// Render description, styled or as its Markdown source
     fn render_description(&self, frame: &mut Frame, area: Rect) {
-        let description_text = self
-            .ticket
-            .description
-            .as_ref()
-            .map(|d| d.as_str())
-            .unwrap_or("No description provided.");
+        let (text, title) = self.description_text();
+        let text = Text::from(text.lines.into_iter().map(|line| self.highlight(line)).collect::<Vec<_>>());
 
-        let paragraph = Paragraph::new(description_text)
+        let paragraph = Paragraph::new(text)
             .style(self.theme.normal)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Description"),
-            )
-            .wrap(Wrap { trim: true });
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: false })
+            .scroll((self.description_scroll, 0));
 
         frame.render_widget(paragraph, area);
     }
 
     /// Render metadata (created, updated)
     fn render_metadata(&self, frame: &mut Frame, area: Rect) {
-        let created_str = format_date(&self.ticket.created);
-        let updated_str = format_date(&self.ticket.updated);
+        // Relative times keep the exact time alongside
+        let describe = |dt: &DateTime<Utc>| match self.timestamp_format {
+            TimestampFormat::Relative => {
+                format!("{} ({})", self.timestamp(dt), format_date(dt, self.timezone, self.date_format))
+            }
+            TimestampFormat::Absolute => format_date(dt, self.timezone, self.date_format),
+        };
+        let created_str = describe(&self.ticket.created);
+        let updated_str = describe(&self.ticket.updated);
 
         let metadata_text = vec![
             Line::from(vec![
@@ -235,15 +820,148 @@ impl<'a> TicketDetail<'a> {
     }
 }
 
-/// Format a datetime for display
-fn format_date(dt: &DateTime<Utc>) -> String {
-    dt.format("%Y-%m-%d %H:%M:%S UTC").to_string()
+/// Time left on an SLA, e.g. "1h 20m left", "paused, 3h 0m left" or
+/// "breached 35m ago"
+fn sla_countdown(sla: &Sla, urgency: SlaUrgency, now: DateTime<Utc>) -> String {
+    let remaining = sla.remaining_at(now);
+    match (urgency, remaining) {
+        (SlaUrgency::Met, _) => "met".to_string(),
+        (SlaUrgency::Breached, Some(left)) if left < Duration::zero() && !sla.completed => {
+            format!("breached {} ago", format_duration(-left))
+        }
+        (SlaUrgency::Breached, _) => "breached".to_string(),
+        (SlaUrgency::Paused, Some(left)) => format!("paused, {} left", format_duration(left)),
+        (SlaUrgency::Paused, None) => "paused".to_string(),
+        (_, Some(left)) => format!("{} left", format_duration(left)),
+        (_, None) => "running".to_string(),
+    }
+}
+
+/// Format a duration compactly, e.g. "3d 4h", "5h 12m" or "42m"
+fn format_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes();
+    let (days, hours, mins) = (minutes / 1440, (minutes % 1440) / 60, minutes % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
+}
+
+/// Wrap text to `width` and keep its first `max_lines` lines, marking the
+/// last one with an ellipsis when text was cut
+fn preview_lines(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    let mut lines = wrap_to_width(text, width);
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            *last = truncate_to_width(&format!("{} …", last), width);
+        }
+    }
+    lines
+}
+
+/// Split text into spans, styling each known mention (e.g. "@Jane Doe")
+fn mention_spans(text: &str, mentions: &[String], normal: Style, mention: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+    loop {
+        // Earliest mention in the remaining text, preferring the longest name
+        let next = mentions
+            .iter()
+            .filter(|m| !m.is_empty())
+            .filter_map(|m| rest.find(m.as_str()).map(|idx| (idx, m.len())))
+            .min_by_key(|&(idx, len)| (idx, std::cmp::Reverse(len)));
+        let Some((idx, len)) = next else {
+            break;
+        };
+        if idx > 0 {
+            spans.push(Span::styled(rest[..idx].to_string(), normal));
+        }
+        spans.push(Span::styled(rest[idx..idx + len].to_string(), mention));
+        rest = &rest[idx + len..];
+    }
+    if !rest.is_empty() {
+        spans.push(Span::styled(rest.to_string(), normal));
+    }
+    spans
+}
+
+/// Spans of `text` with its bare URLs styled as links and the issue keys
+/// found in `references` followed by the text for them
+fn link_spans(text: &str, theme: &Theme, references: &HashMap<String, String>) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+    let mut previous: Option<char> = None;
+    while let Some(c) = rest.chars().next() {
+        let found = match links::bare_url_len(rest) {
+            Some(len) => Some((vec![Span::styled(rest[..len].to_string(), theme.link)], &rest[len..])),
+            None if !previous.is_some_and(|p| p.is_alphanumeric() || p == '-') => {
+                markdown::reference(rest, theme.normal, theme, references)
+            }
+            None => None,
+        };
+        if let Some((linked, after)) = found {
+            if !plain.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut plain), theme.normal));
+            }
+            spans.extend(linked);
+            previous = Some('0');
+            rest = after;
+        } else {
+            plain.push(c);
+            previous = Some(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if !plain.is_empty() {
+        spans.push(Span::styled(plain, theme.normal));
+    }
+    spans
+}
+
+/// Format a datetime for display in the given timezone and date format
+fn format_date(dt: &DateTime<Utc>, timezone: DisplayTimezone, date_format: &str) -> String {
+    timezone.format(dt, &format!("{} %H:%M:%S %Z", date_format))
+}
+
+/// Format a datetime relative to `now`, e.g. "2h ago" or "in 3d"; older
+/// times fall back to the date in the given timezone
+pub fn format_relative(
+    dt: &DateTime<Utc>,
+    now: DateTime<Utc>,
+    timezone: DisplayTimezone,
+    date_format: &str,
+) -> String {
+    let elapsed = now.signed_duration_since(*dt);
+    let (seconds, future) = if elapsed < Duration::zero() {
+        (-elapsed.num_seconds(), true)
+    } else {
+        (elapsed.num_seconds(), false)
+    };
+
+    let amount = match seconds {
+        s if s < 60 => return "just now".to_string(),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86_400 => format!("{}h", s / 3600),
+        s if s < 30 * 86_400 => format!("{}d", s / 86_400),
+        _ => return timezone.format(dt, date_format),
+    };
+    if future {
+        format!("in {}", amount)
+    } else {
+        format!("{} ago", amount)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::models::ticket::{Priority, Status, StatusCategory};
+    use ratatui::style::Modifier;
     use chrono::Utc;
 
     fn create_test_ticket() -> Ticket {
@@ -257,10 +975,21 @@ mod tests {
                 category: StatusCategory::ToDo,
             },
             assignee: None,
+            reporter: None,
             priority: Priority::Medium,
             issue_type: "Task".to_string(),
             project_key: "TEST".to_string(),
             description: Some("This is a test description.".to_string()),
+            parent: None,
+            story_points: None,
+            resolved: None,
+            start_date: None,
+            due_date: None,
+            attachments: Vec::new(),
+            labels: Vec::new(),
+            service_request: None,
+            links: Vec::new(),
+            custom_fields: Default::default(),
             created: Utc::now(),
             updated: Utc::now(),
         }
@@ -276,10 +1005,122 @@ mod tests {
         assert_eq!(detail.ticket.key, "TEST-123");
     }
 
+    #[test]
+    fn test_sla_countdown() {
+        let now = Utc::now();
+        let sla = Sla {
+            name: "Time to resolution".to_string(),
+            goal: Some(Duration::hours(8)),
+            remaining: Some(Duration::hours(5)),
+            breach_time: Some(now + Duration::minutes(80)),
+            paused: false,
+            completed: false,
+            breached: false,
+        };
+        let countdown = |sla: &Sla, now| sla_countdown(sla, sla.urgency(now), now);
+        assert_eq!(countdown(&sla, now), "1h 20m left");
+        assert_eq!(countdown(&sla, now + Duration::minutes(115)), "breached 35m ago");
+        let paused = Sla { paused: true, ..sla.clone() };
+        assert_eq!(countdown(&paused, now), "paused, 5h 0m left");
+        let met = Sla { completed: true, ..sla };
+        assert_eq!(countdown(&met, now), "met");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::minutes(42)), "42m");
+        assert_eq!(format_duration(Duration::minutes(5 * 60 + 12)), "5h 12m");
+        assert_eq!(format_duration(Duration::hours(76)), "3d 4h");
+    }
+
+    #[test]
+    fn test_comments_state_focus_and_collapse() {
+        let mut state = CommentsState::default();
+        state.move_down(2);
+        state.move_down(2);
+        assert_eq!(state.focused_index, 1);
+
+        state.toggle("10001");
+        assert!(state.is_collapsed("10001"));
+        state.toggle("10001");
+        assert!(!state.is_collapsed("10001"));
+    }
+
+    #[test]
+    fn test_preview_lines() {
+        assert_eq!(preview_lines("short", 20, 2), vec!["short"]);
+        assert_eq!(
+            preview_lines("one two three four five six", 9, 2),
+            vec!["one two", "three …"]
+        );
+    }
+
+    #[test]
+    fn test_mention_spans() {
+        let theme = Theme::default();
+        let spans = mention_spans(
+            "@Bob Smith ping @Bob",
+            &["@Bob".to_string(), "@Bob Smith".to_string()],
+            theme.normal,
+            theme.mention,
+        );
+
+        let parts: Vec<(&str, Style)> = spans.iter().map(|s| (s.content.as_ref(), s.style)).collect();
+        assert_eq!(
+            parts,
+            vec![
+                ("@Bob Smith", theme.mention),
+                (" ping ", theme.normal),
+                ("@Bob", theme.mention),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_link_spans() {
+        let theme = Theme::default();
+        let references = HashMap::from([("PROJ-7".to_string(), "Done: Fix login".to_string())]);
+        let spans = link_spans("see https://example.com/x. PROJ-7", &theme, &references);
+
+        let parts: Vec<(&str, Style)> = spans.iter().map(|s| (s.content.as_ref(), s.style)).collect();
+        assert_eq!(
+            parts,
+            vec![
+                ("see ", theme.normal),
+                ("https://example.com/x", theme.link),
+                (". ", theme.normal),
+                ("PROJ-7", theme.link),
+                (" ⟨Done: Fix login⟩", theme.normal.add_modifier(Modifier::DIM)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_relative() {
+        let now = Utc::now();
+        let utc = DisplayTimezone::Named(chrono_tz::UTC);
+        assert_eq!(format_relative(&(now - Duration::seconds(20)), now, utc, DEFAULT_DATE_FORMAT), "just now");
+        assert_eq!(format_relative(&(now - Duration::minutes(5)), now, utc, DEFAULT_DATE_FORMAT), "5m ago");
+        assert_eq!(format_relative(&(now - Duration::hours(2)), now, utc, DEFAULT_DATE_FORMAT), "2h ago");
+        assert_eq!(format_relative(&(now - Duration::days(3)), now, utc, DEFAULT_DATE_FORMAT), "3d ago");
+        assert_eq!(format_relative(&(now + Duration::hours(1)), now, utc, DEFAULT_DATE_FORMAT), "in 1h");
+
+        let old = now - Duration::days(90);
+        assert_eq!(
+            format_relative(&old, now, utc, DEFAULT_DATE_FORMAT),
+            old.format("%Y-%m-%d").to_string()
+        );
+        assert_eq!(
+            format_relative(&old, now, utc, "%d/%m/%Y"),
+            old.format("%d/%m/%Y").to_string()
+        );
+        assert_eq!(TimestampFormat::from_config("Absolute"), TimestampFormat::Absolute);
+    }
+
     #[test]
     fn test_format_date() {
         let dt = Utc::now();
-        let formatted = format_date(&dt);
+        let formatted = format_date(&dt, DisplayTimezone::Named(chrono_tz::UTC), DEFAULT_DATE_FORMAT);
         assert!(formatted.contains("UTC"));
     }
 }
@@ -0,0 +1,122 @@
+use crate::domain::services::diff_service::{DiffLineKind, TicketDiff};
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Inline diff widget comparing a cached ticket with its latest version
+pub struct TicketDiffView<'a> {
+    ticket_key: &'a str,
+    diff: &'a TicketDiff,
+    theme: &'a Theme,
+}
+
+impl<'a> TicketDiffView<'a> {
+    pub fn new(ticket_key: &'a str, diff: &'a TicketDiff, theme: &'a Theme) -> Self {
+        Self {
+            ticket_key,
+            diff,
+            theme,
+        }
+    }
+
+    /// Render the diff view
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let paragraph = Paragraph::new(self.build_lines())
+            .style(self.theme.normal)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Changes to {}", self.ticket_key))
+                    .title_style(self.theme.focused),
+            )
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(paragraph, area);
+    }
+
+    /// Build the lines of the diff
+    fn build_lines(&self) -> Vec<Line<'a>> {
+        if self.diff.is_empty() {
+            return vec![Line::from("No changes since the cached version.")];
+        }
+
+        let mut lines = Vec::new();
+
+        if !self.diff.field_changes.is_empty() {
+            lines.push(Line::from(Span::styled("Fields", self.theme.focused)));
+            for change in &self.diff.field_changes {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {}: ", change.field), self.theme.normal),
+                    Span::styled(change.old_value.clone(), self.theme.diff_removed),
+                    Span::styled(" → ", self.theme.normal),
+                    Span::styled(change.new_value.clone(), self.theme.diff_added),
+                ]));
+            }
+            lines.push(Line::from(""));
+        }
+
+        if self.diff.description_changed() {
+            lines.push(Line::from(Span::styled("Description", self.theme.focused)));
+            for line in &self.diff.description_lines {
+                let (prefix, style) = match line.kind {
+                    DiffLineKind::Unchanged => ("  ", self.theme.normal),
+                    DiffLineKind::Added => ("+ ", self.theme.diff_added),
+                    DiffLineKind::Removed => ("- ", self.theme.diff_removed),
+                };
+                lines.push(Line::from(Span::styled(
+                    format!("{}{}", prefix, line.text),
+                    style,
+                )));
+            }
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::services::diff_service::{DiffLine, FieldChange};
+
+    #[test]
+    fn test_build_lines_empty_diff() {
+        let diff = TicketDiff::default();
+        let theme = Theme::default();
+        let view = TicketDiffView::new("TEST-1", &diff, &theme);
+        assert_eq!(view.build_lines().len(), 1);
+    }
+
+    #[test]
+    fn test_build_lines_with_changes() {
+        let diff = TicketDiff {
+            field_changes: vec![FieldChange {
+                field: "Status".to_string(),
+                old_value: "To Do".to_string(),
+                new_value: "Done".to_string(),
+            }],
+            description_lines: vec![
+                DiffLine {
+                    kind: DiffLineKind::Removed,
+                    text: "old".to_string(),
+                },
+                DiffLine {
+                    kind: DiffLineKind::Added,
+                    text: "new".to_string(),
+                },
+            ],
+        };
+        let theme = Theme::default();
+        let view = TicketDiffView::new("TEST-1", &diff, &theme);
+        let lines = view.build_lines();
+
+        // Fields header, one change, spacer, description header, two lines
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[4].spans[0].content, "- old");
+        assert_eq!(lines[5].spans[0].content, "+ new");
+    }
+}
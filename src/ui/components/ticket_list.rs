@@ -1,31 +1,51 @@
 use crate::domain::models::ticket::Ticket;
+use crate::ui::components::avatar::avatar_span;
 use crate::ui::theme::Theme;
+use crate::utils::text::{display_width, truncate_to_width};
 use ratatui::{
     layout::{Alignment, Rect},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
-use std::collections::HashSet;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+
+/// Columns always given to the summary, even on narrow screens
+const MIN_SUMMARY_WIDTH: usize = 10;
+
+/// Rows built beyond each edge of the viewport
+const VIEWPORT_MARGIN: usize = 10;
+
+/// First visible row for a viewport of `height` rows: the previous `offset`,
+/// scrolled just enough to keep `focused` visible
+fn scroll_offset_for(len: usize, focused: Option<usize>, offset: usize, height: usize) -> usize {
+    let height = height.max(1);
+    let mut offset = offset.min(len.saturating_sub(height));
+    if let Some(focused) = focused {
+        if focused < offset {
+            offset = focused;
+        } else if focused >= offset + height {
+            offset = focused + 1 - height;
+        }
+    }
+    offset
+}
 
 /// State for the ticket list widget
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TicketListState {
     pub tickets: Vec<Ticket>,
-    pub selected_indices: HashSet<usize>,
+    /// Keys of the selected tickets, so selections survive refreshes,
+    /// filtering and re-sorting
+    pub selected_keys: HashSet<String>,
     pub focused_index: Option<usize>,
-    pub scroll_offset: usize,
-}
-
-impl Default for TicketListState {
-    fn default() -> Self {
-        Self {
-            tickets: vec![],
-            selected_indices: HashSet::new(),
-            focused_index: None,
-            scroll_offset: 0,
-        }
-    }
+    /// First visible row; written back by the widget on render so the list
+    /// only scrolls when focus leaves the viewport
+    pub scroll_offset: Cell<usize>,
+    /// Tickets that changed on refresh and haven't been viewed yet,
+    /// keyed by ticket key and holding the snapshot from before the change
+    pub changed_tickets: HashMap<String, Ticket>,
 }
 
 impl TicketListState {
@@ -33,16 +53,41 @@ impl TicketListState {
         Self::default()
     }
 
-    /// Set tickets and reset selection/focus
+    /// Set tickets, keeping focus on the same ticket by key
+    ///
+    /// When the focused ticket is gone, focus stays at the same position.
+    /// Selected tickets stay selected, also while filtered out of the list.
+    /// Tickets whose status, assignee or summary differ from the previous
+    /// snapshot are marked as changed until they are viewed.
     pub fn set_tickets(&mut self, tickets: Vec<Ticket>) {
+        let previous: HashMap<&str, &Ticket> = self
+            .tickets
+            .iter()
+            .map(|t| (t.key.as_str(), t))
+            .collect();
+        let mut changed = HashMap::new();
+        for ticket in &tickets {
+            if let Some(snapshot) = self.changed_tickets.remove(&ticket.key) {
+                changed.insert(ticket.key.clone(), snapshot);
+            } else if let Some(prev) = previous.get(ticket.key.as_str()) {
+                if ticket.has_tracked_changes(prev) {
+                    changed.insert(ticket.key.clone(), (*prev).clone());
+                }
+            }
+        }
+        self.changed_tickets = changed;
+
+        let focused_key = self.focused_ticket().map(|t| t.key.clone());
         self.tickets = tickets;
-        self.selected_indices.clear();
         self.focused_index = if self.tickets.is_empty() {
             None
         } else {
-            Some(0)
+            let by_key = focused_key.and_then(|key| self.tickets.iter().position(|t| t.key == key));
+            let last = self.tickets.len() - 1;
+            Some(by_key.unwrap_or_else(|| self.focused_index.unwrap_or(0).min(last)))
         };
-        self.scroll_offset = 0;
+        self.scroll_offset
+            .set(self.scroll_offset.get().min(self.tickets.len().saturating_sub(1)));
     }
 
     /// Move focus up
@@ -50,7 +95,6 @@ impl TicketListState {
         if let Some(idx) = self.focused_index {
             if idx > 0 {
                 self.focused_index = Some(idx - 1);
-                self.ensure_focused_visible();
             }
         } else if !self.tickets.is_empty() {
             self.focused_index = Some(0);
@@ -62,7 +106,6 @@ impl TicketListState {
         if let Some(idx) = self.focused_index {
             if idx < self.tickets.len().saturating_sub(1) {
                 self.focused_index = Some(idx + 1);
-                self.ensure_focused_visible();
             }
         } else if !self.tickets.is_empty() {
             self.focused_index = Some(0);
@@ -71,19 +114,127 @@ impl TicketListState {
 
     /// Toggle selection of focused ticket
     pub fn toggle_selection(&mut self) {
-        if let Some(idx) = self.focused_index {
-            if self.selected_indices.contains(&idx) {
-                self.selected_indices.remove(&idx);
-            } else {
-                self.selected_indices.insert(idx);
+        if let Some(key) = self.focused_ticket().map(|t| t.key.clone()) {
+            if !self.selected_keys.remove(&key) {
+                self.selected_keys.insert(key);
             }
         }
     }
 
-    /// Ensure focused item is visible in viewport
-    fn ensure_focused_visible(&mut self) {
-        // This will be used when we have a viewport height
-        // For now, just ensure scroll_offset is reasonable
+    pub fn is_selected(&self, key: &str) -> bool {
+        self.selected_keys.contains(key)
+    }
+
+    /// Keys of the tickets an action applies to: the listed selected
+    /// tickets in list order, or the focused ticket when none are listed
+    pub fn target_keys(&self) -> Vec<String> {
+        let selected: Vec<String> = self
+            .tickets
+            .iter()
+            .filter(|ticket| self.is_selected(&ticket.key))
+            .map(|ticket| ticket.key.clone())
+            .collect();
+        if selected.is_empty() {
+            return self.focused_ticket().map(|t| vec![t.key.clone()]).unwrap_or_default();
+        }
+        selected
+    }
+
+    /// The focused ticket and the neighbour it would be ranked against
+    /// when moved one position up or down
+    pub fn rank_neighbour(&self, up: bool) -> Option<(&Ticket, &Ticket)> {
+        let idx = self.focused_index?;
+        let other = if up { idx.checked_sub(1)? } else { idx + 1 };
+        Some((self.tickets.get(idx)?, self.tickets.get(other)?))
+    }
+
+    /// Swap the focused ticket with its neighbour, keeping focus on the
+    /// same ticket
+    pub fn swap_focused(&mut self, up: bool) {
+        let Some(idx) = self.focused_index else {
+            return;
+        };
+        let other = if up {
+            match idx.checked_sub(1) {
+                Some(other) => other,
+                None => return,
+            }
+        } else {
+            idx + 1
+        };
+        if other >= self.tickets.len() {
+            return;
+        }
+
+        self.tickets.swap(idx, other);
+        self.focused_index = Some(other);
+    }
+
+    /// Swap two tickets by key, keeping focus on the same ticket
+    pub fn swap_tickets(&mut self, a: &str, b: &str) {
+        let position = |key: &str| self.tickets.iter().position(|t| t.key == key);
+        let (Some(i), Some(j)) = (position(a), position(b)) else {
+            return;
+        };
+        self.tickets.swap(i, j);
+        self.focused_index = self.focused_index.map(|focused| match focused {
+            _ if focused == i => j,
+            _ if focused == j => i,
+            _ => focused,
+        });
+    }
+
+    /// Replace a single ticket in place, keeping focus and selection; marks
+    /// it changed like `set_tickets` does. Returns false if it isn't listed
+    pub fn update_ticket(&mut self, ticket: Ticket) -> bool {
+        let Some(existing) = self.tickets.iter_mut().find(|t| t.key == ticket.key) else {
+            return false;
+        };
+        if ticket.has_tracked_changes(existing) && !self.changed_tickets.contains_key(&ticket.key) {
+            self.changed_tickets.insert(ticket.key.clone(), existing.clone());
+        }
+        *existing = ticket;
+        true
+    }
+
+    /// Check whether a ticket changed on refresh and hasn't been viewed yet
+    pub fn is_changed(&self, key: &str) -> bool {
+        self.changed_tickets.contains_key(key)
+    }
+
+    /// Clear the change marker for a ticket once it has been viewed,
+    /// returning the snapshot from before the change
+    pub fn mark_viewed(&mut self, key: &str) -> Option<Ticket> {
+        self.changed_tickets.remove(key)
+    }
+
+    /// Focus the ticket with this key, if it is listed
+    pub fn focus_key(&mut self, key: &str) -> bool {
+        match self.tickets.iter().position(|t| t.key == key) {
+            Some(index) => {
+                self.focused_index = Some(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The ticket listed after (or before) the one with this key; None at
+    /// the ends of the list or when the key is not listed
+    pub fn neighbour(&self, key: &str, forward: bool) -> Option<&Ticket> {
+        let index = self.tickets.iter().position(|t| t.key == key)?;
+        if forward {
+            self.tickets.get(index + 1)
+        } else {
+            index.checked_sub(1).and_then(|idx| self.tickets.get(idx))
+        }
+    }
+
+    /// Focus the row at this index, if there is one
+    pub fn focus_index(&mut self, index: usize) {
+        if index < self.tickets.len() {
+            self.focused_index = Some(index);
+        }
     }
 
     /// Get the focused ticket
@@ -97,11 +248,47 @@ impl TicketListState {
 pub struct TicketList<'a> {
     state: &'a TicketListState,
     theme: &'a Theme,
+    title: &'a str,
+    show_avatars: bool,
+    /// Extra fields shown after the summary, as label and field id
+    columns: &'a [(String, String)],
+    row_numbers: bool,
 }
 
 impl<'a> TicketList<'a> {
     pub fn new(state: &'a TicketListState, theme: &'a Theme) -> Self {
-        Self { state, theme }
+        Self {
+            state,
+            theme,
+            title: "Tickets",
+            show_avatars: false,
+            columns: &[],
+            row_numbers: false,
+        }
+    }
+
+    /// Number the rows from 1, for jumping to one by its number
+    pub fn row_numbers(mut self, row_numbers: bool) -> Self {
+        self.row_numbers = row_numbers;
+        self
+    }
+
+    /// Show these custom fields, as label and field id, where they are set
+    pub fn columns(mut self, columns: &'a [(String, String)]) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Show assignee initials in a colored badge
+    pub fn show_avatars(mut self, show_avatars: bool) -> Self {
+        self.show_avatars = show_avatars;
+        self
+    }
+
+    /// Override the block title (defaults to "Tickets")
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = title;
+        self
     }
 
     /// Render the ticket list
@@ -111,13 +298,19 @@ impl<'a> TicketList<'a> {
             return;
         }
 
-        // Create list items
-        let items: Vec<ListItem> = self
-            .state
-            .tickets
+        // Space inside the borders, after the highlight symbol
+        let item_width = area.width.saturating_sub(4) as usize;
+
+        // Only build the rows in view plus a margin, so large results stay fast
+        let height = area.height.saturating_sub(2) as usize;
+        let len = self.state.tickets.len();
+        let offset = scroll_offset_for(len, self.state.focused_index, self.state.scroll_offset.get(), height);
+        let start = offset.saturating_sub(VIEWPORT_MARGIN);
+        let end = (offset + height + VIEWPORT_MARGIN).min(len);
+        let items: Vec<ListItem> = self.state.tickets[start..end]
             .iter()
             .enumerate()
-            .map(|(idx, ticket)| self.create_list_item(idx, ticket))
+            .map(|(idx, ticket)| self.create_list_item(start + idx, ticket, item_width))
             .collect();
 
         // Create list with state
@@ -125,29 +318,37 @@ impl<'a> TicketList<'a> {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Tickets")
+                    .title(self.title)
                     .title_style(self.theme.focused),
             )
             .highlight_style(self.theme.selected)
             .highlight_symbol("> ");
 
-        // Convert to ListState for rendering
-        let mut list_state = ListState::default();
+        // Offsets into the built rows
+        let mut list_state = ListState::default().with_offset(offset - start);
         if let Some(focused_idx) = self.state.focused_index {
-            list_state.select(Some(focused_idx));
+            list_state.select(Some(focused_idx - start));
         }
 
         frame.render_stateful_widget(list, area, &mut list_state);
+        self.state.scroll_offset.set(start + list_state.offset());
     }
 
-    /// Create a list item for a ticket
-    fn create_list_item(&self, idx: usize, ticket: &Ticket) -> ListItem<'_> {
-        let is_selected = self.state.selected_indices.contains(&idx);
+    /// Create a list item for a ticket, shortening the summary so the
+    /// item fits in `width` columns
+    fn create_list_item(&self, idx: usize, ticket: &Ticket, width: usize) -> ListItem<'_> {
+        let is_selected = self.state.is_selected(&ticket.key);
         let is_focused = self.state.focused_index == Some(idx);
+        let is_changed = self.state.is_changed(&ticket.key);
 
         // Build the line with ticket information
         let mut spans = vec![];
 
+        if self.row_numbers {
+            let digits = self.state.tickets.len().to_string().len();
+            spans.push(Span::styled(format!("{:>digits$} ", idx + 1), self.theme.normal));
+        }
+
         // Selection indicator
         if is_selected {
             spans.push(Span::styled("✓ ", self.theme.success));
@@ -158,13 +359,18 @@ impl<'a> TicketList<'a> {
         // Ticket key
         spans.push(Span::styled(
             format!("{} ", ticket.key),
-            if is_focused {
+            if is_focused || is_changed {
                 self.theme.focused
             } else {
                 self.theme.normal
             },
         ));
 
+        // Badge for tickets changed since the previous refresh
+        if is_changed {
+            spans.push(Span::styled("updated ", self.theme.updated_badge));
+        }
+
         // Status (color-coded)
         let status_category_str = match ticket.status.category {
             crate::domain::models::ticket::StatusCategory::ToDo => "new",
@@ -173,28 +379,50 @@ impl<'a> TicketList<'a> {
         };
         let status_style = self.theme.status_style(status_category_str);
         spans.push(Span::styled(
-            format!("[{}] ", ticket.status.name),
+            format!(
+                "[{}{}] ",
+                self.theme.status_symbol(status_category_str),
+                ticket.status.name
+            ),
             status_style,
         ));
 
-        // Summary
+        // Assignee and extra columns (if present)
+        let mut trailing_spans = vec![];
+        if let Some(assignee) = &ticket.assignee {
+            trailing_spans.push(Span::raw(" • "));
+            if self.show_avatars {
+                trailing_spans.push(avatar_span(assignee, self.theme));
+                trailing_spans.push(Span::raw(" "));
+            }
+            trailing_spans.push(Span::styled(
+                assignee.display_name.clone(),
+                self.theme.normal,
+            ));
+        }
+        for (label, id) in self.columns {
+            if let Some(value) = ticket.custom_field(id) {
+                trailing_spans.push(Span::raw(" • "));
+                trailing_spans.push(Span::styled(format!("{}: {}", label, value), self.theme.normal));
+            }
+        }
+
+        // Summary, in whatever space is left
+        let used: usize = spans
+            .iter()
+            .chain(trailing_spans.iter())
+            .map(|span| display_width(&span.content))
+            .sum();
+        let summary_width = width.saturating_sub(used).max(MIN_SUMMARY_WIDTH);
         spans.push(Span::styled(
-            ticket.summary.clone(),
+            truncate_to_width(&ticket.summary, summary_width),
             if is_focused {
                 self.theme.focused
             } else {
                 self.theme.normal
             },
         ));
-
-        // Assignee (if present)
-        if let Some(assignee) = &ticket.assignee {
-            spans.push(Span::raw(" • "));
-            spans.push(Span::styled(
-                assignee.display_name.clone(),
-                self.theme.normal,
-            ));
-        }
+        spans.extend(trailing_spans);
 
         ListItem::new(Line::from(spans))
     }
@@ -208,13 +436,34 @@ impl<'a> TicketList<'a> {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Tickets"),
+                    .title(self.title),
             );
 
         frame.render_widget(paragraph, area);
     }
 }
 
+/// Prompt for the number of the row to jump to, under the list
+pub struct RowJumpBar<'a> {
+    input: &'a str,
+    theme: &'a Theme,
+}
+
+impl<'a> RowJumpBar<'a> {
+    pub fn new(input: &'a str, theme: &'a Theme) -> Self {
+        Self { input, theme }
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let line = Line::from(vec![
+            Span::styled("Go to row: ", self.theme.focused),
+            Span::styled(format!("{}_", self.input), self.theme.selected),
+            Span::styled("  [Enter] jump [Esc] cancel", self.theme.normal),
+        ]);
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,7 +472,7 @@ mod tests {
 
     fn create_test_ticket(key: &str, summary: &str) -> Ticket {
         Ticket {
-            id: format!("{}", key),
+            id: key.to_string(),
             key: key.to_string(),
             summary: summary.to_string(),
             status: Status {
@@ -232,10 +481,21 @@ mod tests {
                 category: StatusCategory::ToDo,
             },
             assignee: None,
+            reporter: None,
             priority: Priority::Medium,
             issue_type: "Task".to_string(),
             project_key: "TEST".to_string(),
             description: None,
+            parent: None,
+            story_points: None,
+            resolved: None,
+            start_date: None,
+            due_date: None,
+            attachments: Vec::new(),
+            labels: Vec::new(),
+            service_request: None,
+            links: Vec::new(),
+            custom_fields: Default::default(),
             created: Utc::now(),
             updated: Utc::now(),
         }
@@ -245,7 +505,7 @@ mod tests {
     fn test_ticket_list_state_new() {
         let state = TicketListState::new();
         assert!(state.tickets.is_empty());
-        assert!(state.selected_indices.is_empty());
+        assert!(state.selected_keys.is_empty());
         assert!(state.focused_index.is_none());
     }
 
@@ -260,7 +520,86 @@ mod tests {
         state.set_tickets(tickets);
         assert_eq!(state.tickets.len(), 2);
         assert_eq!(state.focused_index, Some(0));
-        assert!(state.selected_indices.is_empty());
+        assert!(state.selected_keys.is_empty());
+    }
+
+    #[test]
+    fn test_set_tickets_keeps_focus_and_selection_by_key() {
+        let mut state = TicketListState::new();
+        state.set_tickets(vec![
+            create_test_ticket("TEST-1", "One"),
+            create_test_ticket("TEST-2", "Two"),
+            create_test_ticket("TEST-3", "Three"),
+        ]);
+        state.move_down();
+        state.move_down();
+        state.toggle_selection();
+        state.scroll_offset.set(1);
+
+        // TEST-3 moved to the top on refresh
+        state.set_tickets(vec![
+            create_test_ticket("TEST-3", "Three"),
+            create_test_ticket("TEST-1", "One"),
+            create_test_ticket("TEST-2", "Two"),
+        ]);
+        assert_eq!(state.focused_ticket().map(|t| t.key.as_str()), Some("TEST-3"));
+        assert_eq!(state.target_keys(), vec!["TEST-3".to_string()]);
+        assert_eq!(state.scroll_offset.get(), 1);
+
+        // The focused ticket is gone: focus stays in place
+        state.move_down();
+        state.set_tickets(vec![
+            create_test_ticket("TEST-3", "Three"),
+            create_test_ticket("TEST-2", "Two"),
+        ]);
+        assert_eq!(state.focused_ticket().map(|t| t.key.as_str()), Some("TEST-2"));
+    }
+
+    #[test]
+    fn test_neighbour() {
+        let mut state = TicketListState::new();
+        state.set_tickets(vec![
+            create_test_ticket("TEST-1", "One"),
+            create_test_ticket("TEST-2", "Two"),
+        ]);
+        let key = |ticket: Option<&Ticket>| ticket.map(|t| t.key.clone());
+        assert_eq!(key(state.neighbour("TEST-1", true)), Some("TEST-2".to_string()));
+        assert_eq!(key(state.neighbour("TEST-2", false)), Some("TEST-1".to_string()));
+        assert_eq!(key(state.neighbour("TEST-2", true)), None);
+        assert_eq!(key(state.neighbour("TEST-1", false)), None);
+        assert_eq!(key(state.neighbour("OTHER-1", true)), None);
+    }
+
+    #[test]
+    fn test_scroll_offset_follows_focus() {
+        // Focus inside the viewport keeps the offset
+        assert_eq!(scroll_offset_for(100, Some(12), 10, 5), 10);
+        // Below the viewport: scroll until it is the last visible row
+        assert_eq!(scroll_offset_for(100, Some(20), 10, 5), 16);
+        // Above the viewport: scroll until it is the first
+        assert_eq!(scroll_offset_for(100, Some(3), 10, 5), 3);
+        // The list shrank under the offset
+        assert_eq!(scroll_offset_for(8, Some(7), 50, 5), 3);
+    }
+
+    #[test]
+    fn test_render_large_list_shows_focused_ticket() {
+        let mut state = TicketListState::new();
+        state.set_tickets(
+            (1..=5000)
+                .map(|n| create_test_ticket(&format!("TEST-{}", n), "Ticket"))
+                .collect(),
+        );
+        state.focused_index = Some(2999);
+
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(60, 12)).unwrap();
+        let theme = Theme::default();
+        terminal
+            .draw(|frame| TicketList::new(&state, &theme).render(frame, frame.size()))
+            .unwrap();
+        let screen: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+        assert!(screen.contains(">   TEST-3000"));
+        assert_eq!(state.scroll_offset.get(), 2990);
     }
 
     #[test]
@@ -301,13 +640,124 @@ mod tests {
         state.set_tickets(tickets);
         state.focused_index = Some(0);
 
-        assert!(!state.selected_indices.contains(&0));
+        assert!(!state.is_selected("TEST-1"));
+
+        state.toggle_selection();
+        assert!(state.is_selected("TEST-1"));
+
+        state.toggle_selection();
+        assert!(!state.is_selected("TEST-1"));
+    }
+
+    #[test]
+    fn test_selection_survives_filtering_and_resorting() {
+        let mut state = TicketListState::new();
+        state.set_tickets(vec![
+            create_test_ticket("TEST-1", "Test ticket 1"),
+            create_test_ticket("TEST-2", "Test ticket 2"),
+        ]);
+        state.move_down();
+        state.toggle_selection();
+
+        // Filtered out: nothing listed is selected, so the focused ticket is the target
+        state.set_tickets(vec![create_test_ticket("TEST-1", "Test ticket 1")]);
+        assert_eq!(state.target_keys(), vec!["TEST-1"]);
+
+        state.set_tickets(vec![
+            create_test_ticket("TEST-2", "Test ticket 2"),
+            create_test_ticket("TEST-1", "Test ticket 1"),
+        ]);
+        assert_eq!(state.target_keys(), vec!["TEST-2"]);
+    }
+
+    #[test]
+    fn test_target_keys() {
+        let mut state = TicketListState::new();
+        assert!(state.target_keys().is_empty());
 
+        state.set_tickets(vec![
+            create_test_ticket("TEST-1", "Test ticket 1"),
+            create_test_ticket("TEST-2", "Test ticket 2"),
+            create_test_ticket("TEST-3", "Test ticket 3"),
+        ]);
+        assert_eq!(state.target_keys(), vec!["TEST-1"]);
+
+        state.move_down();
+        state.move_down();
         state.toggle_selection();
-        assert!(state.selected_indices.contains(&0));
+        state.move_up();
+        state.move_up();
+        state.toggle_selection();
+        assert_eq!(state.target_keys(), vec!["TEST-1", "TEST-3"]);
+    }
+
+    #[test]
+    fn test_swap_focused_keeps_focus_and_selection() {
+        let mut state = TicketListState::new();
+        state.set_tickets(vec![
+            create_test_ticket("TEST-1", "Test ticket 1"),
+            create_test_ticket("TEST-2", "Test ticket 2"),
+        ]);
+        assert!(state.rank_neighbour(true).is_none());
 
         state.toggle_selection();
-        assert!(!state.selected_indices.contains(&0));
+        let (ticket, neighbour) = state.rank_neighbour(false).unwrap();
+        assert_eq!((ticket.key.as_str(), neighbour.key.as_str()), ("TEST-1", "TEST-2"));
+
+        state.swap_focused(false);
+        assert_eq!(state.tickets[1].key, "TEST-1");
+        assert_eq!(state.focused_ticket().unwrap().key, "TEST-1");
+        assert_eq!(state.target_keys(), vec!["TEST-1"]);
+        assert!(state.rank_neighbour(false).is_none());
+
+        // Undone by key, as when Jira rejects the rank
+        state.swap_tickets("TEST-1", "TEST-2");
+        assert_eq!(state.tickets[0].key, "TEST-1");
+        assert_eq!(state.focused_ticket().unwrap().key, "TEST-1");
+    }
+
+    #[test]
+    fn test_set_tickets_marks_changed_tickets() {
+        let mut state = TicketListState::new();
+        state.set_tickets(vec![
+            create_test_ticket("TEST-1", "Test ticket 1"),
+            create_test_ticket("TEST-2", "Test ticket 2"),
+        ]);
+        assert!(state.changed_tickets.is_empty());
+
+        state.set_tickets(vec![
+            create_test_ticket("TEST-1", "Renamed ticket 1"),
+            create_test_ticket("TEST-2", "Test ticket 2"),
+            create_test_ticket("TEST-3", "New ticket"),
+        ]);
+        assert!(state.is_changed("TEST-1"));
+        assert!(!state.is_changed("TEST-2"));
+        assert!(!state.is_changed("TEST-3"));
+
+        // Marker survives an unchanged refresh until the ticket is viewed
+        let tickets = state.tickets.clone();
+        state.set_tickets(tickets.clone());
+        assert!(state.is_changed("TEST-1"));
+
+        let snapshot = state.mark_viewed("TEST-1").unwrap();
+        assert_eq!(snapshot.summary, "Test ticket 1");
+        state.set_tickets(tickets);
+        assert!(!state.is_changed("TEST-1"));
+    }
+
+    #[test]
+    fn test_update_ticket_keeps_focus() {
+        let mut state = TicketListState::new();
+        state.set_tickets(vec![
+            create_test_ticket("TEST-1", "Test ticket 1"),
+            create_test_ticket("TEST-2", "Test ticket 2"),
+        ]);
+        state.focused_index = Some(1);
+
+        assert!(state.update_ticket(create_test_ticket("TEST-2", "Renamed ticket 2")));
+        assert_eq!(state.focused_ticket().unwrap().summary, "Renamed ticket 2");
+        assert!(state.is_changed("TEST-2"));
+        assert!(!state.update_ticket(create_test_ticket("TEST-9", "Elsewhere")));
     }
 
     #[test]
@@ -0,0 +1,368 @@
+use crate::domain::models::project::Version;
+use crate::domain::models::ticket::{StatusCategory, Ticket};
+use crate::ui::theme::Theme;
+use crate::utils::text::truncate_to_width;
+use chrono::{Datelike, Duration, NaiveDate};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Width of the name column left of the time axis
+const LABEL_WIDTH: usize = 36;
+
+/// Shortest stretch of time the axis covers, so a lone date is not drawn
+/// across the whole width
+const MIN_AXIS_DAYS: i64 = 28;
+
+/// State of the roadmap: epics, the children of the ones expanded and the
+/// upcoming versions
+#[derive(Debug, Clone, Default)]
+pub struct TimelineState {
+    pub epics: Vec<Ticket>,
+    pub versions: Vec<Version>,
+    /// Child issues by epic key, for the epics expanded so far
+    pub children: HashMap<String, Vec<Ticket>>,
+    pub expanded: HashSet<String>,
+    pub selected: usize,
+}
+
+/// A row of the roadmap
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimelineRow<'a> {
+    Epic(&'a Ticket),
+    Child(&'a Ticket),
+    Version(&'a Version),
+}
+
+impl TimelineState {
+    pub fn new(epics: Vec<Ticket>, versions: Vec<Version>) -> Self {
+        Self {
+            epics,
+            versions,
+            ..Self::default()
+        }
+    }
+
+    /// Epics, each followed by its children when expanded, then versions
+    pub fn rows(&self) -> Vec<TimelineRow<'_>> {
+        let mut rows = Vec::new();
+        for epic in &self.epics {
+            rows.push(TimelineRow::Epic(epic));
+            if self.expanded.contains(&epic.key) {
+                let children = self.children.get(&epic.key).into_iter().flatten();
+                rows.extend(children.map(TimelineRow::Child));
+            }
+        }
+        rows.extend(self.versions.iter().map(TimelineRow::Version));
+        rows
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.rows().len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Key of the epic on the selected row, if it is one
+    pub fn selected_epic(&self) -> Option<String> {
+        match self.rows().get(self.selected) {
+            Some(TimelineRow::Epic(epic)) => Some(epic.key.clone()),
+            _ => None,
+        }
+    }
+
+    /// Expand a collapsed epic or collapse an expanded one
+    pub fn toggle(&mut self, epic_key: &str) {
+        if !self.expanded.remove(epic_key) {
+            self.expanded.insert(epic_key.to_string());
+        }
+    }
+}
+
+/// Maps dates to columns of the time axis
+#[derive(Debug, Clone, Copy)]
+struct Axis {
+    start: NaiveDate,
+    days: i64,
+    width: usize,
+}
+
+impl Axis {
+    /// Axis covering `dates` and `today`, at least `MIN_AXIS_DAYS` long
+    fn new(dates: impl Iterator<Item = NaiveDate>, today: NaiveDate, width: usize) -> Self {
+        let (mut start, mut end) = (today, today);
+        for date in dates {
+            start = start.min(date);
+            end = end.max(date);
+        }
+        let days = (end - start).num_days().max(MIN_AXIS_DAYS);
+        Self { start, days, width }
+    }
+
+    fn column(&self, date: NaiveDate) -> usize {
+        let offset = (date - self.start).num_days().clamp(0, self.days);
+        (offset as usize * (self.width.saturating_sub(1))) / self.days as usize
+    }
+
+    /// Month names at the columns the months start in
+    fn header(&self) -> String {
+        let mut header = vec![' '; self.width];
+        let mut month = self.start.with_day(1).unwrap_or(self.start);
+        let end = self.start + Duration::days(self.days);
+        while month <= end {
+            if month >= self.start {
+                let column = self.column(month);
+                let label = month.format("%b").to_string();
+                let fits = header[column..].iter().take(label.len() + 1).all(|c| *c == ' ');
+                if fits && column + label.len() <= self.width {
+                    for (i, c) in label.chars().enumerate() {
+                        header[column + i] = c;
+                    }
+                }
+            }
+            month = next_month(month);
+        }
+        header.into_iter().collect()
+    }
+}
+
+fn next_month(date: NaiveDate) -> NaiveDate {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1).unwrap_or(date + Duration::days(31))
+}
+
+/// Cells of a row's bar: `━` from start to due, `┄` from a start without
+/// due date up to today, `◆` for a due date alone, and `│` for today
+fn bar_cells(
+    axis: &Axis,
+    start: Option<NaiveDate>,
+    due: Option<NaiveDate>,
+    today: NaiveDate,
+) -> Vec<char> {
+    let mut cells = vec![' '; axis.width];
+    if axis.width == 0 {
+        return cells;
+    }
+    cells[axis.column(today)] = '│';
+    match (start, due) {
+        (Some(start), Some(due)) => {
+            let (from, to) = (axis.column(start.min(due)), axis.column(due.max(start)));
+            cells[from..=to].fill('━');
+        }
+        (Some(start), None) => {
+            let (from, to) = (axis.column(start.min(today)), axis.column(start.max(today)));
+            cells[from..=to].fill('┄');
+        }
+        (None, Some(due)) => cells[axis.column(due)] = '◆',
+        (None, None) => {}
+    }
+    cells
+}
+
+fn category_key(category: &StatusCategory) -> &'static str {
+    match category {
+        StatusCategory::ToDo => "new",
+        StatusCategory::InProgress => "indeterminate",
+        StatusCategory::Done => "done",
+    }
+}
+
+/// Roadmap of epics, and optionally versions, across a horizontal time axis
+pub struct TimelineView<'a> {
+    state: &'a TimelineState,
+    today: NaiveDate,
+    theme: &'a Theme,
+}
+
+impl<'a> TimelineView<'a> {
+    pub fn new(state: &'a TimelineState, today: NaiveDate, theme: &'a Theme) -> Self {
+        Self { state, today, theme }
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Roadmap — [Enter] expand epic [r]efresh [Esc] back")
+            .title_style(self.theme.focused);
+        if self.state.epics.is_empty() && self.state.versions.is_empty() {
+            let paragraph = Paragraph::new("No open epics in these projects")
+                .style(self.theme.normal)
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let inner = block.inner(area);
+        let rows = self.state.rows();
+        let dates = rows.iter().flat_map(|row| {
+            let (start, due) = row_dates(row);
+            start.into_iter().chain(due)
+        });
+        let axis = Axis::new(
+            dates,
+            self.today,
+            (inner.width as usize).saturating_sub(LABEL_WIDTH + 1),
+        );
+
+        let mut lines = vec![Line::from(Span::styled(
+            format!("{:width$} {}", "", axis.header(), width = LABEL_WIDTH),
+            self.theme.focused,
+        ))];
+        // Keep the selected row in view below the header
+        let visible = (inner.height as usize).saturating_sub(1).max(1);
+        let offset = self.state.selected.saturating_sub(visible - 1);
+        for (i, row) in rows.iter().enumerate().skip(offset).take(visible) {
+            lines.push(self.row_line(row, &axis, i == self.state.selected));
+        }
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn row_line(&self, row: &TimelineRow, axis: &Axis, selected: bool) -> Line<'static> {
+        let (label, style) = match row {
+            TimelineRow::Epic(epic) => {
+                let marker = if self.state.expanded.contains(&epic.key) { "▼" } else { "▶" };
+                (
+                    format!("{} {} {}", marker, epic.key, epic.summary),
+                    self.ticket_style(epic),
+                )
+            }
+            TimelineRow::Child(child) => {
+                let category = category_key(&child.status.category);
+                (
+                    format!(
+                        "    {}{} [{}] {}",
+                        self.theme.status_symbol(category),
+                        child.key,
+                        child.status.name,
+                        child.summary
+                    ),
+                    self.theme.status_style(category),
+                )
+            }
+            TimelineRow::Version(version) => (
+                format!("◆ {}", version.name),
+                self.theme.focused,
+            ),
+        };
+        let label_style = if selected { self.theme.selected } else { style };
+        let mut spans = vec![Span::styled(
+            format!(
+                "{:width$} ",
+                truncate_to_width(&label, LABEL_WIDTH),
+                width = LABEL_WIDTH
+            ),
+            label_style,
+        )];
+
+        let (start, due) = row_dates(row);
+        let cells = bar_cells(axis, start, due, self.today);
+        let mut run = String::new();
+        let mut run_style = self.theme.normal;
+        for c in cells {
+            let cell_style = match c {
+                '│' => self.theme.warning,
+                ' ' => self.theme.normal,
+                _ => style,
+            };
+            if cell_style != run_style && !run.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut run), run_style));
+            }
+            run_style = cell_style;
+            run.push(c);
+        }
+        if !run.is_empty() {
+            spans.push(Span::styled(run, run_style));
+        }
+        Line::from(spans)
+    }
+
+    /// Status color, or the error color for overdue issues
+    fn ticket_style(&self, ticket: &Ticket) -> Style {
+        if ticket.is_overdue(self.today) {
+            self.theme.error
+        } else {
+            self.theme.status_style(category_key(&ticket.status.category))
+        }
+    }
+}
+
+/// Start and end of a row on the time axis
+fn row_dates(row: &TimelineRow) -> (Option<NaiveDate>, Option<NaiveDate>) {
+    match row {
+        TimelineRow::Epic(ticket) | TimelineRow::Child(ticket) => (ticket.start_date, ticket.due_date),
+        TimelineRow::Version(version) => (version.start_date, version.release_date),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::ticket::Status;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_bar_cells_on_axis() {
+        let axis = Axis::new([date(1), date(29)].into_iter(), date(15), 29);
+        assert_eq!(axis.days, MIN_AXIS_DAYS);
+        assert_eq!(axis.column(date(1)), 0);
+        assert_eq!(axis.column(date(29)), 28);
+
+        let bar: String = bar_cells(&axis, Some(date(3)), Some(date(8)), date(15)).into_iter().collect();
+        assert_eq!(bar, "  ━━━━━━      │              ");
+        let bar: String = bar_cells(&axis, Some(date(11)), None, date(15)).into_iter().collect();
+        assert_eq!(bar, "          ┄┄┄┄┄              ");
+        let bar: String = bar_cells(&axis, None, Some(date(20)), date(15)).into_iter().collect();
+        assert_eq!(bar, "              │    ◆         ");
+        assert!(axis.header().starts_with("Mar"));
+    }
+
+    #[test]
+    fn test_rows_expand_epics() {
+        let status = Status {
+            id: "1".to_string(),
+            name: "To Do".to_string(),
+            category: StatusCategory::ToDo,
+        };
+        let epic = Ticket::new("PROJ-1".to_string(), "Checkout".to_string(), status.clone());
+        let child = Ticket::new("PROJ-2".to_string(), "Pay by card".to_string(), status);
+        let version = Version {
+            id: "1".to_string(),
+            name: "1.0".to_string(),
+            start_date: None,
+            release_date: Some(date(20)),
+            released: false,
+        };
+        let mut state = TimelineState::new(vec![epic], vec![version]);
+        state.children.insert("PROJ-1".to_string(), vec![child]);
+        assert_eq!(state.rows().len(), 2);
+        assert_eq!(state.selected_epic().as_deref(), Some("PROJ-1"));
+
+        state.toggle("PROJ-1");
+        assert!(matches!(state.rows()[1], TimelineRow::Child(child) if child.key == "PROJ-2"));
+        state.move_down();
+        state.move_down();
+        state.move_down();
+        assert_eq!(state.selected, 2);
+        assert_eq!(state.selected_epic(), None);
+        state.toggle("PROJ-1");
+        assert_eq!(state.rows().len(), 2);
+    }
+}
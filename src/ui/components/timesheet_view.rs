@@ -0,0 +1,140 @@
+use crate::domain::services::timesheet_service::{format_hours, Timesheet};
+use crate::ui::theme::Theme;
+use crate::utils::text::{display_width, truncate_to_width};
+use chrono::NaiveDate;
+use ratatui::{
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Width of the issue key column
+const KEY_WIDTH: usize = 12;
+
+/// Width of each day's column and of the total
+const HOURS_WIDTH: usize = 7;
+
+/// State of the timesheet view
+#[derive(Debug, Clone, Default)]
+pub struct TimesheetState {
+    pub timesheet: Timesheet,
+    pub focused_index: usize,
+}
+
+impl TimesheetState {
+    pub fn set_timesheet(&mut self, timesheet: Timesheet) {
+        self.timesheet = timesheet;
+        self.focused_index = 0;
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.focused_index + 1 < self.timesheet.rows.len() {
+            self.focused_index += 1;
+        }
+    }
+
+    pub fn focused_key(&self) -> Option<&str> {
+        self.timesheet.rows.get(self.focused_index).map(|row| row.key.as_str())
+    }
+}
+
+/// Grid of the hours logged per issue and day of a week
+pub struct TimesheetView<'a> {
+    state: &'a TimesheetState,
+    today: NaiveDate,
+    theme: &'a Theme,
+}
+
+impl<'a> TimesheetView<'a> {
+    pub fn new(state: &'a TimesheetState, today: NaiveDate, theme: &'a Theme) -> Self {
+        Self { state, today, theme }
+    }
+
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let timesheet = &self.state.timesheet;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Timesheet — week of {} — {}h — [h/l] week [Enter] open [X] export CSV",
+                timesheet.week_start,
+                format_hours(timesheet.total())
+            ))
+            .title_style(self.theme.focused);
+        if timesheet.rows.is_empty() {
+            let paragraph = Paragraph::new("No work logged this week")
+                .style(self.theme.normal)
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let inner = block.inner(area);
+        let summary_width = (inner.width as usize)
+            .saturating_sub(KEY_WIDTH + 8 * HOURS_WIDTH + 2)
+            .max(10);
+
+        let mut header = vec![Span::styled(
+            format!("{:key$} {:summary$} ", "Issue", "Summary", key = KEY_WIDTH, summary = summary_width),
+            self.theme.focused,
+        )];
+        for day in timesheet.days() {
+            let style = if day == self.today { self.theme.warning } else { self.theme.focused };
+            header.push(Span::styled(
+                format!("{:>width$}", day.format("%a %d").to_string(), width = HOURS_WIDTH),
+                style,
+            ));
+        }
+        header.push(Span::styled(format!("{:>width$}", "Total", width = HOURS_WIDTH), self.theme.focused));
+
+        let mut lines = vec![Line::from(header)];
+        // Keep the focused row in view between the header and the totals
+        let visible = (inner.height as usize).saturating_sub(2).max(1);
+        let offset = self.state.focused_index.saturating_sub(visible - 1);
+        for (i, row) in timesheet.rows.iter().enumerate().skip(offset).take(visible) {
+            let style = if i == self.state.focused_index { self.theme.selected } else { self.theme.normal };
+            let summary = truncate_to_width(&row.summary, summary_width);
+            let padding = " ".repeat(summary_width.saturating_sub(display_width(&summary)));
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{:key$} {}{} {}",
+                    row.key,
+                    summary,
+                    padding,
+                    hours_cells(&row.seconds, row.total()),
+                    key = KEY_WIDTH
+                ),
+                style,
+            )));
+        }
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{:width$} {}",
+                "Total",
+                hours_cells(&timesheet.day_totals(), timesheet.total()),
+                width = KEY_WIDTH + summary_width + 1
+            ),
+            self.theme.focused,
+        )));
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// Right-aligned hours of each day and the total; days without work are
+/// left blank
+fn hours_cells(seconds: &[u64; 7], total: u64) -> String {
+    seconds
+        .iter()
+        .chain([&total])
+        .map(|&s| {
+            let hours = if s == 0 { String::new() } else { format_hours(s) };
+            format!("{:>width$}", hours, width = HOURS_WIDTH)
+        })
+        .collect()
+}
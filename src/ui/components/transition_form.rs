@@ -0,0 +1,375 @@
+use crate::domain::models::user::User;
+use crate::infrastructure::api::client::{FieldKind, Transition, TransitionField};
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// Value entered for one screen field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldInput {
+    /// Index of the chosen option, if any
+    Choice(Option<usize>),
+    Text(String),
+    /// Name typed to look users up, the users found and the chosen one
+    User {
+        query: String,
+        matches: Vec<User>,
+        choice: Option<usize>,
+    },
+}
+
+impl FieldInput {
+    /// Whether nothing was entered or chosen
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FieldInput::Choice(choice) => choice.is_none(),
+            FieldInput::Text(text) => text.trim().is_empty(),
+            FieldInput::User { choice, .. } => choice.is_none(),
+        }
+    }
+}
+
+/// State for the transition screen form
+#[derive(Debug, Clone)]
+pub struct TransitionFormState {
+    pub ticket_key: String,
+    pub transition: Transition,
+    pub inputs: Vec<FieldInput>,
+    pub focused_field: usize,
+    /// Jira's messages from the last rejected submit, by field key
+    pub errors: BTreeMap<String, String>,
+}
+
+impl TransitionFormState {
+    /// Build an empty form; required select fields start on their "Done"
+    /// option when there is one, else on the first option
+    pub fn new(ticket_key: String, transition: Transition) -> Self {
+        let inputs = transition
+            .fields
+            .iter()
+            .map(|field| match &field.kind {
+                FieldKind::Select { options, .. } => {
+                    let done = options.iter().position(|o| o.name.eq_ignore_ascii_case("done"));
+                    let default = if field.required && !options.is_empty() {
+                        Some(done.unwrap_or(0))
+                    } else {
+                        done
+                    };
+                    FieldInput::Choice(default)
+                }
+                _ => FieldInput::Text(String::new()),
+            })
+            .collect();
+
+        Self {
+            ticket_key,
+            transition,
+            inputs,
+            focused_field: 0,
+            errors: BTreeMap::new(),
+        }
+    }
+
+    /// Attach Jira's field messages to the fields on this form; false when
+    /// none of them is about a field here
+    pub fn show_errors(&mut self, field_errors: &BTreeMap<String, String>) -> bool {
+        self.errors = field_errors
+            .iter()
+            .filter(|(key, _)| self.transition.fields.iter().any(|field| &field.key == *key))
+            .map(|(key, message)| (key.clone(), message.clone()))
+            .collect();
+        !self.errors.is_empty()
+    }
+
+    pub fn next_field(&mut self) {
+        if self.focused_field + 1 < self.inputs.len() {
+            self.focused_field += 1;
+        }
+    }
+
+    pub fn previous_field(&mut self) {
+        self.focused_field = self.focused_field.saturating_sub(1);
+    }
+
+    /// Cycle the focused select field's option; optional fields can be cleared
+    pub fn cycle_option(&mut self, forward: bool) {
+        let Some(field) = self.transition.fields.get(self.focused_field) else {
+            return;
+        };
+        let FieldKind::Select { options, .. } = &field.kind else {
+            return;
+        };
+        let Some(FieldInput::Choice(choice)) = self.inputs.get_mut(self.focused_field) else {
+            return;
+        };
+
+        // Optional fields get an extra "none" slot before the first option
+        let slots = options.len() + usize::from(!field.required);
+        if slots == 0 {
+            return;
+        }
+        let offset = usize::from(!field.required);
+        let current = choice.map(|idx| idx + offset).unwrap_or(0);
+        let next = if forward {
+            (current + 1) % slots
+        } else {
+            (current + slots - 1) % slots
+        };
+        *choice = if next < offset { None } else { Some(next - offset) };
+    }
+
+    /// Type into the focused text field
+    pub fn push(&mut self, c: char) {
+        if let Some(FieldInput::Text(text)) = self.inputs.get_mut(self.focused_field) {
+            text.push(c);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        if let Some(FieldInput::Text(text)) = self.inputs.get_mut(self.focused_field) {
+            text.pop();
+        }
+    }
+
+    /// Names of required fields that are still empty
+    pub fn missing_required(&self) -> Vec<&str> {
+        self.transition
+            .fields
+            .iter()
+            .zip(&self.inputs)
+            .filter(|(field, input)| field.required && input.is_empty())
+            .map(|(field, _)| field.name.as_str())
+            .collect()
+    }
+
+    /// The `fields` object and comment to send with the transition
+    pub fn to_request(&self) -> Result<(Option<Value>, Option<String>), String> {
+        let mut fields = Map::new();
+        let mut comment = None;
+
+        for (field, input) in self.transition.fields.iter().zip(&self.inputs) {
+            if input.is_empty() {
+                continue;
+            }
+            let value = match (&field.kind, input) {
+                (FieldKind::Select { options, multiple }, FieldInput::Choice(Some(idx))) => {
+                    let option = json!({ "id": options[*idx].id });
+                    if *multiple {
+                        json!([option])
+                    } else {
+                        option
+                    }
+                }
+                (FieldKind::Number, FieldInput::Text(text)) => {
+                    let number: f64 = text
+                        .trim()
+                        .parse()
+                        .map_err(|_| format!("{} must be a number", field.name))?;
+                    json!(number)
+                }
+                (FieldKind::Comment, FieldInput::Text(text)) => {
+                    comment = Some(text.trim().to_string());
+                    continue;
+                }
+                (_, FieldInput::Text(text)) => json!(text.trim()),
+                _ => continue,
+            };
+            fields.insert(field.key.clone(), value);
+        }
+
+        let fields = if fields.is_empty() {
+            None
+        } else {
+            Some(Value::Object(fields))
+        };
+        Ok((fields, comment))
+    }
+}
+
+/// Transition screen form widget
+pub struct TransitionForm<'a> {
+    state: &'a TransitionFormState,
+    theme: &'a Theme,
+}
+
+impl<'a> TransitionForm<'a> {
+    pub fn new(state: &'a TransitionFormState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the form over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "{} {} → {}",
+                self.state.transition.name, self.state.ticket_key, self.state.transition.to_status
+            ))
+            .title_style(self.theme.focused);
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let lines: Vec<Line> = self
+            .state
+            .transition
+            .fields
+            .iter()
+            .zip(&self.state.inputs)
+            .enumerate()
+            .map(|(idx, (field, input))| self.field_line(field, input, idx == self.state.focused_field))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), parts[0]);
+
+        let help = "[↑↓/Tab] field [←→] option [Enter] submit [Esc] cancel";
+        frame.render_widget(Paragraph::new(help).style(self.theme.normal), parts[1]);
+    }
+
+    /// One "Name*: value" row
+    fn field_line(&self, field: &'a TransitionField, input: &'a FieldInput, focused: bool) -> Line<'a> {
+        let marker = if field.required { "*" } else { "" };
+        let value = match (&field.kind, input) {
+            (FieldKind::Select { options, .. }, FieldInput::Choice(choice)) => {
+                let name = choice
+                    .and_then(|idx| options.get(idx))
+                    .map(|o| o.name.as_str())
+                    .unwrap_or("(none)");
+                format!("◀ {} ▶", name)
+            }
+            (_, FieldInput::Text(text)) if focused => format!("{}_", text),
+            (_, FieldInput::Text(text)) => text.clone(),
+            _ => String::new(),
+        };
+
+        let value_style = if focused {
+            self.theme.selected
+        } else {
+            self.theme.normal
+        };
+        let mut spans = vec![
+            Span::styled(format!("{}{}: ", field.name, marker), self.theme.focused),
+            Span::styled(value, value_style),
+        ];
+        if let Some(error) = self.state.errors.get(&field.key) {
+            spans.push(Span::styled(format!("  ✗ {}", error), self.theme.error));
+        }
+        Line::from(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::api::client::FieldOption;
+
+    fn option(id: &str, name: &str) -> FieldOption {
+        FieldOption {
+            id: id.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    fn transition() -> Transition {
+        Transition {
+            id: "31".to_string(),
+            name: "Resolve".to_string(),
+            to_status: "Resolved".to_string(),
+            fields: vec![
+                TransitionField {
+                    key: "resolution".to_string(),
+                    name: "Resolution".to_string(),
+                    required: true,
+                    kind: FieldKind::Select {
+                        options: vec![option("1", "Won't Do"), option("2", "Done")],
+                        multiple: false,
+                    },
+                },
+                TransitionField {
+                    key: "customfield_10020".to_string(),
+                    name: "Root cause".to_string(),
+                    required: true,
+                    kind: FieldKind::Text,
+                },
+                TransitionField {
+                    key: "fixVersions".to_string(),
+                    name: "Fix versions".to_string(),
+                    required: false,
+                    kind: FieldKind::Select {
+                        options: vec![option("100", "1.0")],
+                        multiple: true,
+                    },
+                },
+                TransitionField {
+                    key: "comment".to_string(),
+                    name: "Comment".to_string(),
+                    required: false,
+                    kind: FieldKind::Comment,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_form_defaults_and_required_fields() {
+        let mut form = TransitionFormState::new("PROJ-1".to_string(), transition());
+        assert_eq!(form.inputs[0], FieldInput::Choice(Some(1)));
+        assert_eq!(form.inputs[2], FieldInput::Choice(None));
+        assert_eq!(form.missing_required(), vec!["Root cause"]);
+
+        form.next_field();
+        for c in "config".chars() {
+            form.push(c);
+        }
+        assert!(form.missing_required().is_empty());
+    }
+
+    #[test]
+    fn test_form_builds_request_fields_and_comment() {
+        let mut form = TransitionFormState::new("PROJ-1".to_string(), transition());
+        form.cycle_option(false);
+        form.next_field();
+        form.push('x');
+        form.next_field();
+        form.cycle_option(true);
+        form.next_field();
+        for c in "fixed".chars() {
+            form.push(c);
+        }
+
+        let (fields, comment) = form.to_request().unwrap();
+        assert_eq!(
+            fields,
+            Some(json!({
+                "resolution": { "id": "1" },
+                "customfield_10020": "x",
+                "fixVersions": [{ "id": "100" }]
+            }))
+        );
+        assert_eq!(comment.as_deref(), Some("fixed"));
+    }
+
+    #[test]
+    fn test_show_errors_keeps_fields_on_the_form() {
+        let mut form = TransitionFormState::new("PROJ-1".to_string(), transition());
+        let errors = BTreeMap::from([
+            ("resolution".to_string(), "Resolution is required.".to_string()),
+            ("customfield_1".to_string(), "Unknown field".to_string()),
+        ]);
+        assert!(form.show_errors(&errors));
+        assert_eq!(form.errors.len(), 1);
+        assert_eq!(form.errors["resolution"], "Resolution is required.");
+
+        assert!(!form.show_errors(&BTreeMap::from([("x".to_string(), "y".to_string())])));
+    }
+}
@@ -8,21 +8,12 @@ use ratatui::{
 };
 
 /// State for transition list widget
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TransitionListState {
     pub transitions: Vec<Transition>,
     pub focused_index: Option<usize>,
 }
 
-impl Default for TransitionListState {
-    fn default() -> Self {
-        Self {
-            transitions: vec![],
-            focused_index: None,
-        }
-    }
-}
-
 impl TransitionListState {
     pub fn new() -> Self {
         Self::default()
@@ -92,8 +83,7 @@ impl<'a> TransitionList<'a> {
             .state
             .transitions
             .iter()
-            .enumerate()
-            .map(|(_idx, transition)| {
+            .map(|transition| {
                 let text = format!("{} → {}", transition.name, transition.to_status);
                 ListItem::new(Line::from(text))
             })
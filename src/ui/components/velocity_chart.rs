@@ -0,0 +1,107 @@
+use crate::domain::services::sprint_service::SprintVelocity;
+use crate::ui::theme::Theme;
+use crate::utils::text::truncate_to_width;
+use ratatui::{
+    layout::{Alignment, Rect},
+    widgets::{BarChart, Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Width of each bar, also used to truncate sprint names
+const BAR_WIDTH: u16 = 9;
+
+/// Bar chart of completed work per closed sprint
+pub struct VelocityChart<'a> {
+    velocities: &'a [SprintVelocity],
+    theme: &'a Theme,
+}
+
+impl<'a> VelocityChart<'a> {
+    pub fn new(velocities: &'a [SprintVelocity], theme: &'a Theme) -> Self {
+        Self { velocities, theme }
+    }
+
+    /// Render the chart, falling back to issue counts when nothing is estimated
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        if self.velocities.is_empty() {
+            let paragraph = Paragraph::new("No closed sprints on this board")
+                .style(self.theme.normal)
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::ALL).title("Velocity"));
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let (unit, bars) = self.bars();
+        let average = bars.iter().map(|(_, v)| *v).sum::<u64>() as f64 / bars.len() as f64;
+        let data: Vec<(&str, u64)> = bars.iter().map(|(label, v)| (label.as_str(), *v)).collect();
+
+        let chart = BarChart::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        "Velocity — completed {} per sprint (avg {:.1})",
+                        unit, average
+                    ))
+                    .title_style(self.theme.focused),
+            )
+            .data(&data)
+            .bar_width(BAR_WIDTH)
+            .bar_gap(1)
+            .bar_style(self.theme.status_done)
+            .value_style(self.theme.selected)
+            .label_style(self.theme.normal);
+
+        frame.render_widget(chart, area);
+    }
+
+    /// Bar labels and values, in points when any sprint has estimates
+    fn bars(&self) -> (&'static str, Vec<(String, u64)>) {
+        let uses_points = self.velocities.iter().any(|v| v.completed_points > 0.0);
+        let unit = if uses_points { "points" } else { "issues" };
+
+        let bars = self
+            .velocities
+            .iter()
+            .map(|v| {
+                let label = truncate_to_width(&v.sprint_name, BAR_WIDTH as usize);
+                let value = if uses_points {
+                    v.completed_points.round() as u64
+                } else {
+                    v.completed_issues as u64
+                };
+                (label, value)
+            })
+            .collect();
+
+        (unit, bars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bars_fall_back_to_issue_counts() {
+        let velocities = vec![
+            SprintVelocity {
+                sprint_name: "Sprint with a long name".to_string(),
+                completed_issues: 4,
+                completed_points: 0.0,
+            },
+            SprintVelocity {
+                sprint_name: "Sprint 2".to_string(),
+                completed_issues: 6,
+                completed_points: 0.0,
+            },
+        ];
+        let theme = Theme::default();
+        let (unit, bars) = VelocityChart::new(&velocities, &theme).bars();
+
+        assert_eq!(unit, "issues");
+        assert_eq!(bars[0], ("Sprint w…".to_string(), 4));
+        assert_eq!(bars[1], ("Sprint 2".to_string(), 6));
+    }
+}
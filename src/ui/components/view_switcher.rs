@@ -0,0 +1,72 @@
+use crate::domain::models::smart_view::SmartView;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// State for the view switcher: the built-in smart views, then "Default"
+/// to go back to `default_query`
+#[derive(Debug, Clone, Default)]
+pub struct ViewSwitcherState {
+    pub focused_index: usize,
+}
+
+impl ViewSwitcherState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn move_up(&mut self) {
+        self.focused_index = self.focused_index.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.focused_index < SmartView::ALL.len() {
+            self.focused_index += 1;
+        }
+    }
+
+    /// The focused view; None for "Default"
+    pub fn selected(&self) -> Option<SmartView> {
+        SmartView::ALL.get(self.focused_index).copied()
+    }
+}
+
+/// View switcher popup
+pub struct ViewSwitcher<'a> {
+    state: &'a ViewSwitcherState,
+    theme: &'a Theme,
+}
+
+impl<'a> ViewSwitcher<'a> {
+    pub fn new(state: &'a ViewSwitcherState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the views over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = SmartView::ALL
+            .iter()
+            .map(|view| ListItem::new(view.name()))
+            .chain(std::iter::once(ListItem::new("Default")))
+            .collect();
+        let list = List::new(items)
+            .style(self.theme.normal)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Views — [Enter] switch [Esc] cancel")
+                    .title_style(self.theme.focused),
+            )
+            .highlight_style(self.theme.selected)
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(self.state.focused_index));
+
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::ui::theme::Theme;
+use crate::utils::time::{format_duration, parse_duration};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// State for the prompt logging timed work on a ticket
+#[derive(Debug, Clone)]
+pub struct WorklogPromptState {
+    pub ticket_key: String,
+    pub started: DateTime<Utc>,
+    /// Time spent in Jira's notation, e.g. "1h 25m"
+    pub time_spent: String,
+    pub comment: String,
+    /// Typing goes to the time spent rather than the comment
+    pub editing_time: bool,
+}
+
+impl WorklogPromptState {
+    /// Prompt filled in with the timed work, at least a minute, starting at
+    /// the comment since the time is usually right
+    pub fn new(ticket_key: String, started: DateTime<Utc>, seconds: u64) -> Self {
+        Self {
+            ticket_key,
+            started,
+            time_spent: format_duration(seconds.max(60)),
+            comment: String::new(),
+            editing_time: false,
+        }
+    }
+
+    pub fn push(&mut self, c: char) {
+        if self.editing_time {
+            self.time_spent.push(c);
+        } else {
+            self.comment.push(c);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        if self.editing_time {
+            self.time_spent.pop();
+        } else {
+            self.comment.pop();
+        }
+    }
+
+    pub fn switch_field(&mut self) {
+        self.editing_time = !self.editing_time;
+    }
+
+    /// Seconds to log, once the time spent is a duration of a minute or more
+    pub fn time_spent_seconds(&self) -> Option<u64> {
+        parse_duration(&self.time_spent).filter(|&seconds| seconds >= 60)
+    }
+
+    /// The entered comment, if any
+    pub fn comment(&self) -> Option<String> {
+        let comment = self.comment.trim();
+        if comment.is_empty() {
+            None
+        } else {
+            Some(comment.to_string())
+        }
+    }
+}
+
+/// Worklog prompt widget
+pub struct WorklogPrompt<'a> {
+    state: &'a WorklogPromptState,
+    theme: &'a Theme,
+}
+
+impl<'a> WorklogPrompt<'a> {
+    pub fn new(state: &'a WorklogPromptState, theme: &'a Theme) -> Self {
+        Self { state, theme }
+    }
+
+    /// Render the prompt over `area`
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let field = |label: &str, value: &str, focused: bool| {
+            let (value, style) = if focused {
+                (format!("{}_", value), self.theme.selected)
+            } else {
+                (value.to_string(), self.theme.normal)
+            };
+            Line::from(vec![
+                Span::styled(format!("{:<10}", label), self.theme.focused),
+                Span::styled(value, style),
+            ])
+        };
+
+        let lines = vec![
+            field("Spent:", &self.state.time_spent, self.state.editing_time),
+            field("Comment:", &self.state.comment, !self.state.editing_time),
+            Line::from(""),
+            Line::from(Span::styled(
+                "[Tab] switch field [Enter] log work [Esc] discard",
+                self.theme.normal,
+            )),
+        ];
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Log work on {}", self.state.ticket_key))
+                .title_style(self.theme.focused),
+        );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_fields() {
+        let mut state = WorklogPromptState::new("PROJ-1".to_string(), Utc::now(), 20);
+        assert_eq!(state.time_spent, "1m");
+        for c in "Review".chars() {
+            state.push(c);
+        }
+        assert_eq!(state.comment().as_deref(), Some("Review"));
+
+        state.switch_field();
+        state.pop();
+        state.pop();
+        assert_eq!(state.time_spent_seconds(), None);
+        for c in "45m".chars() {
+            state.push(c);
+        }
+        assert_eq!(state.time_spent_seconds(), Some(2700));
+    }
+}
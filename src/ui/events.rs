@@ -37,8 +37,100 @@ pub enum AppEvent {
     ShowTransitions,
     /// Add comment
     AddComment,
+    /// Edit the ticket's fields in a form
+    EditFields,
     /// Open ticket in browser
     OpenInBrowser,
+    /// Show changes since the cached version
+    ShowDiff,
+    /// Flip the description between rendered and raw Markdown
+    ToggleRawDescription,
+    /// Toggle between the list and the board view
+    ToggleBoard,
+    /// Cycle board swimlane grouping
+    CycleSwimlanes,
+    /// Show sprints of the configured board
+    ShowSprints,
+    /// Move focused/selected tickets into a sprint
+    MoveToSprint,
+    /// Show the board backlog
+    ShowBacklog,
+    /// Rank the focused issue one position higher
+    RankUp,
+    /// Rank the focused issue one position lower
+    RankDown,
+    /// Show the velocity report of the sprint board
+    ShowVelocity,
+    /// Show the roadmap of epics across a time axis
+    ShowTimeline,
+    /// Show the versions of the focused ticket's project
+    ShowReleases,
+    /// Copy the release notes of the focused version
+    CopyReleaseNotes,
+    /// Show my logged hours of the week
+    ShowTimesheet,
+    /// List comments newest or oldest first
+    ToggleCommentOrder,
+    /// Show recent comments, transitions and assignments across projects
+    ShowActivity,
+    /// Run one query on every configured instance
+    SearchInstances,
+    /// Start the work timer on the focused ticket, or stop it and log the time
+    ToggleTimer,
+    /// Start a pomodoro on the focused ticket, or stop it and log the work
+    TogglePomodoro,
+    /// Show the tab of the next Jira instance
+    NextTab,
+    /// Show the tab of the previous Jira instance
+    PreviousTab,
+    /// Export what was resolved in the focused sprint, or the timesheet
+    Export,
+    /// Open the quick-assign popup for the focused ticket
+    QuickAssign,
+    /// Pick a new reporter for the focused ticket
+    ChangeReporter,
+    /// Move the focused ticket to another project
+    MoveIssue,
+    /// Add or remove a label on the selected tickets
+    EditLabels,
+    /// List the ticket's attachments
+    ShowAttachments,
+    /// List the links in the ticket's description and comments
+    ShowLinks,
+    /// Attach a web link to the ticket
+    AddRemoteLink,
+    /// Approve or decline the service desk request's approvals
+    ShowApprovals,
+    /// Show the chains of blockers around the ticket
+    ShowDependencies,
+    /// Show the entity properties stored on the ticket
+    ShowProperties,
+    /// Type the number of the list row to focus
+    JumpToRow,
+    /// Go back to where I was before the last jump
+    NavigateBack,
+    /// Return to where I went back from
+    NavigateForward,
+    /// Repeat the last mutating action on the focused ticket
+    RepeatLastAction,
+    /// Show the write operations of this session
+    ShowAuditLog,
+    /// Toggle the debug view of recent API requests
+    ToggleDebug,
+    /// Open the query builder
+    BuildQuery,
+    /// Open or collapse the facet sidebar of the ticket list
+    ToggleFilterSidebar,
+    /// Open the switcher of built-in views
+    SwitchView,
+    /// Pick the sprints the ticket list is restricted to
+    FilterBySprint,
+    /// Open the search bar of the ticket list
+    Search,
+    /// Cycle the list through my, unassigned and everyone's tickets
+    CycleAssigneeFilter,
+    /// Switch to the configured quick filter with this index (keys 1–9)
+    QuickFilter(usize),
     /// Unknown/unhandled key
     Unknown,
 }
@@ -68,17 +160,15 @@ impl EventHandler {
         }
     }
 
-    /// Read the next event from the terminal
-    pub fn next(&self) -> Result<AppEvent, std::io::Error> {
+    /// Read the next key press from the terminal
+    ///
+    /// Returns the raw key so modal widgets (e.g. confirmation dialogs) can
+    /// interpret it before it is mapped with [`EventHandler::handle_key`].
+    pub fn next_key(&self) -> Result<Option<KeyEvent>, std::io::Error> {
         match event::read()? {
-            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                Ok(Self::handle_key(key_event))
-            }
-            Event::Resize(_, _) => {
-                // Resize events are handled separately
-                Ok(AppEvent::Unknown)
-            }
-            _ => Ok(AppEvent::Unknown),
+            Event::Key(key_event) if key_event.kind == KeyEventKind::Press => Ok(Some(key_event)),
+            // Resize events are handled separately
+            _ => Ok(None),
         }
     }
 
@@ -95,14 +185,64 @@ impl EventHandler {
             KeyCode::Enter => AppEvent::EnterDetail,
             KeyCode::Char(' ') => AppEvent::ToggleSelection,
             KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => AppEvent::Quit,
+            KeyCode::Char('f') if key_event.modifiers.contains(KeyModifiers::CONTROL) => AppEvent::Search,
+            KeyCode::Char('o') if key_event.modifiers.contains(KeyModifiers::CONTROL) => AppEvent::NavigateBack,
+            // Terminals send Ctrl+i as Tab
+            KeyCode::Char('i') if key_event.modifiers.contains(KeyModifiers::CONTROL) => AppEvent::NavigateForward,
+            KeyCode::Tab if key_event.modifiers.is_empty() => AppEvent::NavigateForward,
             KeyCode::Char('R') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::Resolve,
+            KeyCode::Char('S') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ShowSprints,
+            KeyCode::Char('B') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ShowBacklog,
+            KeyCode::Char('K') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::RankUp,
+            KeyCode::Char('J') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::RankDown,
+            KeyCode::Char('V') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ShowVelocity,
+            KeyCode::Char('G') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ShowDependencies,
+            KeyCode::Char('T') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ShowTimeline,
+            KeyCode::Char('E') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ShowReleases,
+            KeyCode::Char('N') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::CopyReleaseNotes,
+            KeyCode::Char('X') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::Export,
+            KeyCode::Char('C') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ShowTimesheet,
+            KeyCode::Char('U') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ShowActivity,
+            KeyCode::Char('I') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::SearchInstances,
+            KeyCode::Char('O') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ToggleCommentOrder,
+            KeyCode::Char('A') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::QuickAssign,
+            KeyCode::Char('P') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ChangeReporter,
+            KeyCode::Char('M') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::MoveIssue,
+            KeyCode::Char('L') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::EditLabels,
+            KeyCode::Char('H') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ShowAuditLog,
+            KeyCode::Char('W') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::AddRemoteLink,
+            KeyCode::Char('D') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ToggleDebug,
+            KeyCode::Char('F') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::ToggleFilterSidebar,
+            KeyCode::Char('Z') if key_event.modifiers.contains(KeyModifiers::SHIFT) => AppEvent::TogglePomodoro,
             KeyCode::Char('r') if key_event.modifiers.is_empty() => AppEvent::Refresh,
             KeyCode::Char('a') if key_event.modifiers.is_empty() => AppEvent::AssignToMe,
             KeyCode::Char('s') if key_event.modifiers.is_empty() => AppEvent::StartProgress,
             KeyCode::Char('n') if key_event.modifiers.is_empty() => AppEvent::CreateTicket,
             KeyCode::Char('t') if key_event.modifiers.is_empty() => AppEvent::ShowTransitions,
             KeyCode::Char('c') if key_event.modifiers.is_empty() => AppEvent::AddComment,
+            KeyCode::Char('e') if key_event.modifiers.is_empty() => AppEvent::EditFields,
             KeyCode::Char('o') if key_event.modifiers.is_empty() => AppEvent::OpenInBrowser,
+            KeyCode::Char('d') if key_event.modifiers.is_empty() => AppEvent::ShowDiff,
+            KeyCode::Char('p') if key_event.modifiers.is_empty() => AppEvent::ToggleRawDescription,
+            KeyCode::Char('b') if key_event.modifiers.is_empty() => AppEvent::ToggleBoard,
+            KeyCode::Char('g') if key_event.modifiers.is_empty() => AppEvent::CycleSwimlanes,
+            KeyCode::Char('m') if key_event.modifiers.is_empty() => AppEvent::MoveToSprint,
+            KeyCode::Char('f') if key_event.modifiers.is_empty() => AppEvent::ShowAttachments,
+            KeyCode::Char('w') if key_event.modifiers.is_empty() => AppEvent::ShowLinks,
+            KeyCode::Char('y') if key_event.modifiers.is_empty() => AppEvent::ShowApprovals,
+            KeyCode::Char('x') if key_event.modifiers.is_empty() => AppEvent::ShowProperties,
+            KeyCode::Char('.') if key_event.modifiers.is_empty() => AppEvent::RepeatLastAction,
+            KeyCode::Char('/') if key_event.modifiers.is_empty() => AppEvent::BuildQuery,
+            KeyCode::Char(':') => AppEvent::JumpToRow,
+            KeyCode::Char('u') if key_event.modifiers.is_empty() => AppEvent::CycleAssigneeFilter,
+            KeyCode::Char('v') if key_event.modifiers.is_empty() => AppEvent::SwitchView,
+            KeyCode::Char('i') if key_event.modifiers.is_empty() => AppEvent::FilterBySprint,
+            KeyCode::Char('z') if key_event.modifiers.is_empty() => AppEvent::ToggleTimer,
+            KeyCode::Char(']') if key_event.modifiers.is_empty() => AppEvent::NextTab,
+            KeyCode::Char('[') if key_event.modifiers.is_empty() => AppEvent::PreviousTab,
+            KeyCode::Char(c @ '1'..='9') if key_event.modifiers.is_empty() => {
+                AppEvent::QuickFilter(c as usize - '1' as usize)
+            }
             _ => AppEvent::Unknown,
         }
     }
@@ -179,6 +319,230 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_handle_key_show_sprints() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('S'), KeyModifiers::SHIFT)),
+            AppEvent::ShowSprints
+        );
+    }
+
+    #[test]
+    fn test_handle_key_move_to_sprint() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('m'), KeyModifiers::empty())),
+            AppEvent::MoveToSprint
+        );
+    }
+
+    #[test]
+    fn test_handle_key_search() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('f'), KeyModifiers::CONTROL)),
+            AppEvent::Search
+        );
+    }
+
+    #[test]
+    fn test_handle_key_filter_by_sprint() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('i'), KeyModifiers::empty())),
+            AppEvent::FilterBySprint
+        );
+    }
+
+    #[test]
+    fn test_handle_key_toggle_timer() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('z'), KeyModifiers::empty())),
+            AppEvent::ToggleTimer
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('Z'), KeyModifiers::SHIFT)),
+            AppEvent::TogglePomodoro
+        );
+    }
+
+    #[test]
+    fn test_handle_key_tabs() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char(']'), KeyModifiers::empty())),
+            AppEvent::NextTab
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('['), KeyModifiers::empty())),
+            AppEvent::PreviousTab
+        );
+    }
+
+    #[test]
+    fn test_handle_key_quick_filter() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('1'), KeyModifiers::empty())),
+            AppEvent::QuickFilter(0)
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('9'), KeyModifiers::empty())),
+            AppEvent::QuickFilter(8)
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('0'), KeyModifiers::empty())),
+            AppEvent::Unknown
+        );
+    }
+
+    #[test]
+    fn test_handle_key_quick_assign() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('A'), KeyModifiers::SHIFT)),
+            AppEvent::QuickAssign
+        );
+    }
+
+    #[test]
+    fn test_handle_key_change_reporter() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('P'), KeyModifiers::SHIFT)),
+            AppEvent::ChangeReporter
+        );
+    }
+
+    #[test]
+    fn test_handle_key_move_issue() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('M'), KeyModifiers::SHIFT)),
+            AppEvent::MoveIssue
+        );
+    }
+
+    #[test]
+    fn test_handle_key_edit_labels() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('L'), KeyModifiers::SHIFT)),
+            AppEvent::EditLabels
+        );
+    }
+
+    #[test]
+    fn test_handle_key_show_attachments() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('f'), KeyModifiers::empty())),
+            AppEvent::ShowAttachments
+        );
+    }
+
+    #[test]
+    fn test_handle_key_show_links() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('w'), KeyModifiers::empty())),
+            AppEvent::ShowLinks
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('W'), KeyModifiers::SHIFT)),
+            AppEvent::AddRemoteLink
+        );
+    }
+
+    #[test]
+    fn test_handle_key_show_approvals() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('y'), KeyModifiers::empty())),
+            AppEvent::ShowApprovals
+        );
+    }
+
+    #[test]
+    fn test_handle_key_show_dependencies() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            AppEvent::ShowDependencies
+        );
+    }
+
+    #[test]
+    fn test_handle_key_show_properties() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('x'), KeyModifiers::empty())),
+            AppEvent::ShowProperties
+        );
+    }
+
+    #[test]
+    fn test_handle_key_jump_to_row() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char(':'), KeyModifiers::SHIFT)),
+            AppEvent::JumpToRow
+        );
+    }
+
+    #[test]
+    fn test_handle_key_navigation_history() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('o'), KeyModifiers::CONTROL)),
+            AppEvent::NavigateBack
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('i'), KeyModifiers::CONTROL)),
+            AppEvent::NavigateForward
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Tab, KeyModifiers::empty())),
+            AppEvent::NavigateForward
+        );
+    }
+
+    #[test]
+    fn test_handle_key_backlog_ranking() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('B'), KeyModifiers::SHIFT)),
+            AppEvent::ShowBacklog
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('K'), KeyModifiers::SHIFT)),
+            AppEvent::RankUp
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('J'), KeyModifiers::SHIFT)),
+            AppEvent::RankDown
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('V'), KeyModifiers::SHIFT)),
+            AppEvent::ShowVelocity
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('T'), KeyModifiers::SHIFT)),
+            AppEvent::ShowTimeline
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('E'), KeyModifiers::SHIFT)),
+            AppEvent::ShowReleases
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('N'), KeyModifiers::SHIFT)),
+            AppEvent::CopyReleaseNotes
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('X'), KeyModifiers::SHIFT)),
+            AppEvent::Export
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('C'), KeyModifiers::SHIFT)),
+            AppEvent::ShowTimesheet
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('U'), KeyModifiers::SHIFT)),
+            AppEvent::ShowActivity
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('I'), KeyModifiers::SHIFT)),
+            AppEvent::SearchInstances
+        );
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('O'), KeyModifiers::SHIFT)),
+            AppEvent::ToggleCommentOrder
+        );
+    }
+
     #[test]
     fn test_should_tick() {
         let mut handler = EventHandler::new(Duration::from_millis(100));
@@ -188,4 +552,44 @@ mod tests {
         assert!(handler.should_tick());
         assert!(!handler.should_tick()); // Should reset
     }
+
+    #[test]
+    fn test_handle_key_repeat_last_action() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('.'), KeyModifiers::empty())),
+            AppEvent::RepeatLastAction
+        );
+    }
+
+    #[test]
+    fn test_handle_key_toggle_raw_description() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('p'), KeyModifiers::empty())),
+            AppEvent::ToggleRawDescription
+        );
+    }
+
+    #[test]
+    fn test_handle_key_edit_fields() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('e'), KeyModifiers::empty())),
+            AppEvent::EditFields
+        );
+    }
+
+    #[test]
+    fn test_handle_key_show_audit_log() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('H'), KeyModifiers::SHIFT)),
+            AppEvent::ShowAuditLog
+        );
+    }
+
+    #[test]
+    fn test_handle_key_toggle_debug() {
+        assert_eq!(
+            EventHandler::handle_key(create_key_event(KeyCode::Char('D'), KeyModifiers::SHIFT)),
+            AppEvent::ToggleDebug
+        );
+    }
 }
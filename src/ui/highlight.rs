@@ -0,0 +1,205 @@
+//! Syntax highlighting of code blocks, in as many colors as the terminal
+//! shows
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+use std::sync::OnceLock;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{FontStyle, Theme as SyntaxTheme, ThemeSet};
+use syntect::parsing::SyntaxSet;
+
+/// Color theme of the bundled ones that code is highlighted with
+const SYNTAX_THEME: &str = "base16-ocean.dark";
+
+/// Colors the terminal can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// 24-bit RGB, announced by COLORTERM=truecolor or 24bit
+    TrueColor,
+    /// The xterm 256-color palette, for a TERM like xterm-256color
+    Ansi256,
+    /// The eight standard colors
+    Ansi16,
+    /// No colors at all
+    None,
+}
+
+impl ColorDepth {
+    /// Depth of the terminal we run in, from COLORTERM and TERM
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default().to_lowercase();
+        let term = std::env::var("TERM").unwrap_or_default();
+        Self::from_env(&colorterm, &term)
+    }
+
+    fn from_env(colorterm: &str, term: &str) -> Self {
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else if term == "dumb" {
+            ColorDepth::None
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    /// The closest color this depth can show
+    fn color(self, r: u8, g: u8, b: u8) -> Option<Color> {
+        match self {
+            ColorDepth::TrueColor => Some(Color::Rgb(r, g, b)),
+            ColorDepth::Ansi256 => Some(Color::Indexed(ansi256(r, g, b))),
+            ColorDepth::Ansi16 => Some(ansi16(r, g, b)),
+            ColorDepth::None => None,
+        }
+    }
+}
+
+struct Syntaxes {
+    syntaxes: SyntaxSet,
+    theme: SyntaxTheme,
+}
+
+/// Bundled syntaxes and theme, loaded on first use
+fn syntaxes() -> &'static Syntaxes {
+    static SYNTAXES: OnceLock<Syntaxes> = OnceLock::new();
+    SYNTAXES.get_or_init(|| {
+        let mut themes = ThemeSet::load_defaults();
+        Syntaxes {
+            syntaxes: SyntaxSet::load_defaults_newlines(),
+            theme: themes.themes.remove(SYNTAX_THEME).unwrap_or_default(),
+        }
+    })
+}
+
+/// Highlights the lines of one code block in turn, since a line's colors
+/// depend on the ones before it (e.g. inside a multi-line string)
+pub struct CodeHighlighter {
+    lines: HighlightLines<'static>,
+    depth: ColorDepth,
+}
+
+impl CodeHighlighter {
+    /// Highlighter for a block in `language` (a name or file extension such
+    /// as "rust" or "py"); None when the language is unknown or there are
+    /// no colors to use
+    pub fn new(language: &str, depth: ColorDepth) -> Option<Self> {
+        if depth == ColorDepth::None || language.trim().is_empty() {
+            return None;
+        }
+        let syntaxes = syntaxes();
+        let syntax = syntaxes
+            .syntaxes
+            .find_syntax_by_token(language.trim())
+            .or_else(|| syntaxes.syntaxes.find_syntax_by_name(language.trim()))?;
+        Some(Self {
+            lines: HighlightLines::new(syntax, &syntaxes.theme),
+            depth,
+        })
+    }
+
+    /// Spans of the next line of the block, or None when it cannot be
+    /// highlighted
+    pub fn line(&mut self, line: &str) -> Option<Vec<Span<'static>>> {
+        let with_newline = format!("{}\n", line);
+        let ranges = self.lines.highlight_line(&with_newline, &syntaxes().syntaxes).ok()?;
+        Some(
+            ranges
+                .into_iter()
+                .map(|(style, text)| {
+                    let fg = style.foreground;
+                    let mut span_style = Style::default();
+                    if let Some(color) = self.depth.color(fg.r, fg.g, fg.b) {
+                        span_style = span_style.fg(color);
+                    }
+                    if style.font_style.contains(FontStyle::BOLD) {
+                        span_style = span_style.add_modifier(Modifier::BOLD);
+                    }
+                    if style.font_style.contains(FontStyle::ITALIC) {
+                        span_style = span_style.add_modifier(Modifier::ITALIC);
+                    }
+                    Span::styled(text.trim_end_matches('\n').to_string(), span_style)
+                })
+                .filter(|span| !span.content.is_empty())
+                .collect(),
+        )
+    }
+}
+
+/// Index of the closest color in the xterm 256-color palette: the 6×6×6
+/// cube or the gray ramp
+fn ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let cube = |v: u8| -> u8 {
+        if v < 48 {
+            0
+        } else if v < 115 {
+            1
+        } else {
+            (v - 35) / 40
+        }
+    };
+    let level = |i: u8| if i == 0 { 0 } else { 55 + 40 * i as u32 };
+    let (cr, cg, cb) = (cube(r), cube(g), cube(b));
+    let cube_distance = distance((r, g, b), (level(cr), level(cg), level(cb)));
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_index = if average > 238 { 23 } else { average.saturating_sub(3) / 10 };
+    let gray = 8 + 10 * gray_index;
+    if distance((r, g, b), (gray, gray, gray)) < cube_distance {
+        232 + gray_index as u8
+    } else {
+        16 + 36 * cr + 6 * cg + cb
+    }
+}
+
+/// Closest of the eight standard terminal colors
+fn ansi16(r: u8, g: u8, b: u8) -> Color {
+    const COLORS: [(Color, (u32, u32, u32)); 8] = [
+        (Color::Black, (0, 0, 0)),
+        (Color::Red, (205, 0, 0)),
+        (Color::Green, (0, 205, 0)),
+        (Color::Yellow, (205, 205, 0)),
+        (Color::Blue, (0, 0, 238)),
+        (Color::Magenta, (205, 0, 205)),
+        (Color::Cyan, (0, 205, 205)),
+        (Color::Gray, (229, 229, 229)),
+    ];
+    COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+fn distance((r, g, b): (u8, u8, u8), (r2, g2, b2): (u32, u32, u32)) -> u32 {
+    let d = |a: u8, b: u32| (a as i64 - b as i64).pow(2) as u32;
+    d(r, r2) + d(g, g2) + d(b, b2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_within_color_depth() {
+        assert_eq!(ColorDepth::from_env("truecolor", "xterm"), ColorDepth::TrueColor);
+        assert_eq!(ColorDepth::from_env("", "xterm-256color"), ColorDepth::Ansi256);
+        assert_eq!(ColorDepth::from_env("", "xterm"), ColorDepth::Ansi16);
+        assert_eq!(ColorDepth::from_env("", "dumb"), ColorDepth::None);
+
+        assert_eq!(ansi256(0, 0, 0), 16);
+        assert_eq!(ansi256(255, 0, 0), 196);
+        assert_eq!(ansi256(128, 128, 128), 244);
+        assert_eq!(ansi16(200, 10, 10), Color::Red);
+
+        assert!(CodeHighlighter::new("rust", ColorDepth::None).is_none());
+        assert!(CodeHighlighter::new("no-such-language", ColorDepth::TrueColor).is_none());
+
+        let mut highlighter = CodeHighlighter::new("rust", ColorDepth::Ansi256).unwrap();
+        let spans = highlighter.line("fn main() {}").unwrap();
+        let text: String = spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(text, "fn main() {}");
+        assert!(spans.iter().all(|span| matches!(span.style.fg, Some(Color::Indexed(_)))));
+        assert!(spans.len() > 1);
+    }
+}
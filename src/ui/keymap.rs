@@ -0,0 +1,300 @@
+//! Key bindings grouped by view. The help bar and `lazyjira keys` are both
+//! generated from this table, so keep it in step with
+//! `EventHandler::handle_key` and the view checks in `App::handle_key`
+
+use crate::infrastructure::config::Config;
+
+/// A built-in key binding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Binding {
+    pub keys: &'static str,
+    pub action: &'static str,
+    /// Short label in the help bar, for the bindings shown there
+    pub hint: Option<&'static str>,
+}
+
+const fn bind(keys: &'static str, action: &'static str) -> Binding {
+    Binding { keys, action, hint: None }
+}
+
+const fn hint(keys: &'static str, action: &'static str, hint: &'static str) -> Binding {
+    Binding { keys, action, hint: Some(hint) }
+}
+
+/// Built-in bindings by view, in the order they are listed
+pub const BUILTIN: &[(&str, &[Binding])] = &[
+    (
+        "Global",
+        &[
+            hint("q, Ctrl+c", "Quit", "[q]uit"),
+            hint("↑ ↓, j k", "Move the selection", "[↑↓/jk]move"),
+            hint("Esc", "Go back", "[Esc]back"),
+            hint("r", "Refresh the current view", "[r]efresh"),
+            bind("H", "Show the write operations of this session"),
+            bind("z", "Start a work timer on the ticket, or stop it and log the time"),
+            bind("Z", "Start a pomodoro on the ticket, or stop it and log the work"),
+            bind("D", "Toggle the debug view of API requests"),
+            bind("[ ]", "Show the previous or next instance's tab outside the detail view"),
+            bind("Ctrl+o", "Go back to where I was, list focus and comment included"),
+            bind("Ctrl+i", "Go forward again after going back"),
+        ],
+    ),
+    (
+        "Dashboard, list and board",
+        &[
+            hint("Enter", "Open the ticket or status column", "[Enter]detail"),
+            bind("← →, h l", "Move between dashboard columns"),
+            bind("b", "Toggle between the list and the board"),
+            bind("/", "Open the query builder"),
+            bind("v", "Switch to a built-in view"),
+            bind("i", "Restrict the list to sprints"),
+            bind("1–9", "Apply a quick filter"),
+            hint("S", "Show sprints", "[S]prints"),
+            hint("B", "Show the backlog", "[B]acklog"),
+            hint("V", "Show the velocity report", "[V]elocity"),
+            bind("T", "Show the roadmap of open epics"),
+            bind("E", "Show the releases of the focused ticket's project"),
+            bind("C", "Show the hours I logged this week"),
+            bind("U", "Show recent activity across my projects"),
+            bind("I", "Search all configured instances at once"),
+        ],
+    ),
+    (
+        "Ticket list",
+        &[
+            bind("Space", "Select the ticket"),
+            bind("Ctrl+f", "Search"),
+            bind("u", "Cycle my, unassigned and everyone's tickets"),
+            bind("F", "Open the filter sidebar"),
+            bind(":", "Jump to a row by its number"),
+            bind("n", "Create a ticket"),
+            bind("o", "Open in the browser"),
+        ],
+    ),
+    ("Board", &[bind("g", "Cycle swimlane grouping")]),
+    (
+        "Ticket detail",
+        &[
+            hint("a", "Assign to me", "[a]ssign"),
+            hint("s", "Start progress", "[s]tart"),
+            hint("R", "Resolve", "[R]esolve"),
+            hint("t", "Show transitions", "[t]ransitions"),
+            bind("c", "Add a comment"),
+            bind("e", "Edit the fields Jira lets me change"),
+            bind("f", "List attachments"),
+            bind("w", "List links to open"),
+            bind("W", "Attach a web link"),
+            bind("y", "Approve or decline service desk approvals"),
+            bind("G", "Show the chains of blockers around the ticket"),
+            bind("x", "Show the properties automations and apps stored on the ticket"),
+            bind("[ ]", "Step to the previous or next ticket of the list"),
+            bind("/", "Find text in the description and comments, then n and N step through it"),
+            bind("Enter", "Open the issue the focused comment or the description mentions"),
+            bind("d", "Show changes since the cached version"),
+            bind("p", "Toggle the description between rendered and raw"),
+            bind("O", "List comments newest or oldest first"),
+            bind("o", "Open in the browser"),
+        ],
+    ),
+    (
+        "List, detail and backlog",
+        &[
+            hint("m", "Move to a sprint", "[m]ove to sprint"),
+            hint("A", "Quick assign", "[A] quick assign"),
+            bind("P", "Change the reporter"),
+            bind("L", "Add or remove a label"),
+            bind("M", "Move to another project (not in the backlog)"),
+            bind(".", "Repeat the last action"),
+        ],
+    ),
+    (
+        "Backlog",
+        &[
+            bind("Space", "Select the ticket"),
+            bind("K", "Rank up"),
+            bind("J", "Rank down"),
+        ],
+    ),
+    (
+        "Sprints",
+        &[
+            bind("s", "Start the sprint"),
+            bind("R", "Complete the sprint"),
+            bind("X", "Copy the resolved issues as a Markdown table"),
+            bind("V", "Show the velocity report"),
+        ],
+    ),
+    (
+        "Activity",
+        &[
+            bind("Enter", "Open the issue of the focused entry"),
+            bind("r", "Check for new activity now"),
+        ],
+    ),
+    (
+        "Search all instances",
+        &[
+            bind("Enter", "Search, or open the focused issue in its instance's tab"),
+            bind("/", "Edit the query"),
+        ],
+    ),
+    (
+        "Timesheet",
+        &[
+            bind("← →, h l", "Show the previous or next week"),
+            bind("Enter", "Open the focused issue"),
+            bind("X", "Copy the timesheet as CSV"),
+        ],
+    ),
+    (
+        "Releases",
+        &[
+            bind("Enter", "Show the issues of the version"),
+            bind("R", "Release the version"),
+            bind("N", "Copy the version's release notes as Markdown"),
+        ],
+    ),
+];
+
+/// Bindings of one view in the effective keymap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyGroup {
+    pub view: String,
+    /// Keys and what they do
+    pub bindings: Vec<(String, String)>,
+}
+
+/// The keymap in effect with `config`: the built-in bindings, then the
+/// quick filters and user commands it declares
+pub fn keymap(config: &Config) -> Vec<KeyGroup> {
+    let mut groups: Vec<KeyGroup> = BUILTIN
+        .iter()
+        .map(|(view, bindings)| KeyGroup {
+            view: view.to_string(),
+            bindings: bindings
+                .iter()
+                .map(|binding| (binding.keys.to_string(), binding.action.to_string()))
+                .collect(),
+        })
+        .collect();
+
+    if !config.filters.is_empty() {
+        groups.push(KeyGroup {
+            view: "Quick filters".to_string(),
+            bindings: config
+                .filters
+                .iter()
+                .take(9)
+                .enumerate()
+                .map(|(i, filter)| ((i + 1).to_string(), filter.name.clone()))
+                .collect(),
+        });
+    }
+    if !config.commands.is_empty() {
+        groups.push(KeyGroup {
+            view: "Commands".to_string(),
+            bindings: config
+                .commands
+                .iter()
+                .map(|command| (command.key.to_string(), command.name.clone()))
+                .collect(),
+        });
+    }
+    groups
+}
+
+/// Text of the help bar at the bottom of the screen
+pub fn help_bar() -> String {
+    help_bar_without(&[])
+}
+
+/// Help bar without the hints of the given keys
+pub fn help_bar_without(hidden: &[&str]) -> String {
+    let hints: Vec<&str> = BUILTIN
+        .iter()
+        .flat_map(|(_, bindings)| bindings.iter())
+        .filter(|binding| !hidden.contains(&binding.keys))
+        .filter_map(|binding| binding.hint)
+        .collect();
+    format!(" {} ", hints.join(" "))
+}
+
+/// The keymap as plain text, one aligned line per binding
+pub fn to_text(groups: &[KeyGroup]) -> String {
+    let width = groups
+        .iter()
+        .flat_map(|group| group.bindings.iter().map(|(keys, _)| keys.chars().count()))
+        .max()
+        .unwrap_or(0);
+    let mut out = String::new();
+    for (i, group) in groups.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&group.view);
+        out.push('\n');
+        for (keys, action) in &group.bindings {
+            let padding = width - keys.chars().count();
+            out.push_str(&format!("  {}{}  {}\n", keys, " ".repeat(padding), action));
+        }
+    }
+    out
+}
+
+/// The keymap as Markdown, a table per view
+pub fn to_markdown(groups: &[KeyGroup]) -> String {
+    let mut out = String::new();
+    for (i, group) in groups.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!("## {}\n\n| Key | Action |\n| --- | --- |\n", group.view));
+        for (keys, action) in &group.bindings {
+            let keys: Vec<String> = keys
+                .split(", ")
+                .map(|key| format!("`{}`", key.replace('|', "\\|")))
+                .collect();
+            out.push_str(&format!("| {} | {} |\n", keys.join(", "), action.replace('|', "\\|")));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::config::{CommandConfig, FilterConfig};
+
+    #[test]
+    fn test_keymap_includes_configured_keys() {
+        let mut config = Config::default();
+        config.filters.push(FilterConfig {
+            name: "Blocked".to_string(),
+            jql: "status = Blocked".to_string(),
+        });
+        config.commands.push(CommandConfig {
+            key: 'x',
+            name: "Notes".to_string(),
+            command: "notes".to_string(),
+        });
+
+        let groups = keymap(&config);
+        assert_eq!(groups[0].view, "Global");
+        let filters = &groups[groups.len() - 2];
+        assert_eq!(filters.bindings, vec![("1".to_string(), "Blocked".to_string())]);
+        let commands = groups.last().unwrap();
+        assert_eq!(commands.bindings, vec![("x".to_string(), "Notes".to_string())]);
+
+        let text = to_text(&groups);
+        assert!(text.starts_with("Global\n  q, Ctrl+c"));
+        assert!(text.ends_with("Commands\n  x          Notes\n"));
+
+        let markdown = to_markdown(&groups);
+        assert!(markdown.contains("## Commands\n\n| Key | Action |\n| --- | --- |\n| `x` | Notes |\n"));
+        assert!(markdown.contains("| `q`, `Ctrl+c` | Quit |"));
+
+        assert!(help_bar().starts_with(" [q]uit [↑↓/jk]move [Esc]back"));
+        assert!(help_bar().contains("[a]ssign [s]tart"));
+        assert!(help_bar_without(&["a"]).contains("[V]elocity [s]tart"));
+    }
+}
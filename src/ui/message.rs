@@ -0,0 +1,906 @@
+//! Message passing between the UI and background work. Handling a key may
+//! start an `Action`; its task reports back with a `Message` over the app's
+//! channel, and `App::update` applies the message to the state without
+//! awaiting anything
+
+use crate::domain::models::comment::{Comment, CommentOrder, CommentVisibility};
+use crate::domain::models::dev_info::DevInfo;
+use crate::domain::models::permission::ProjectPermissions;
+use crate::domain::models::project::{IssueType, Project, Version};
+use crate::domain::models::remote_link::RemoteLink;
+use crate::domain::models::sla::Sla;
+use crate::domain::models::sprint::{Sprint, SprintState};
+use crate::domain::models::approval::Approval;
+use crate::domain::models::activity::Activity;
+use crate::domain::models::attachment::Attachment;
+use crate::domain::models::board::{Board, BoardColumnConfig};
+use crate::domain::models::issue_property::IssueProperty;
+use crate::domain::models::smart_view::SmartView;
+use crate::domain::services::activity_service::ActivityService;
+use crate::domain::services::cross_search_service::{CrossSearchResult, CrossSearchService};
+use crate::domain::services::dependency_service::DependencyGraph;
+use crate::domain::services::timesheet_service::{Timesheet, TimesheetService};
+use crate::domain::services::filter_service::FilterService;
+use crate::domain::services::sprint_service::CompletionPlan;
+use crate::domain::models::ticket::Ticket;
+use crate::domain::models::user::User;
+use crate::infrastructure::api::client::{
+    IssueDetails, LabelChange, RankPosition, SearchResult, Transition, TransitionField,
+    UpdateIssueData,
+};
+use crate::infrastructure::api::ApiClient;
+use crate::utils::cancel::CancellationToken;
+use crate::utils::time::DisplayTimezone;
+use crate::utils::Result;
+use std::sync::Arc;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::time::Instant;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Most epics, and most children of an epic, shown on the roadmap
+const TIMELINE_EPICS: usize = 50;
+
+/// How many links of blockers the dependency graph follows each way
+const DEPENDENCY_DEPTH: usize = 4;
+
+/// Most issues fetched to draw the dependency graph
+const DEPENDENCY_ISSUES: usize = 40;
+
+/// Most issues of a version loaded in the releases view
+const RELEASE_ISSUES: usize = 200;
+
+/// Number of closed sprints shown in the velocity report
+const VELOCITY_SPRINTS: usize = 7;
+
+/// Work requested by the UI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Fetch a ticket with its changelog and transitions, its comments and
+    /// web links for the detail view
+    LoadDetail { key: String, comment_order: CommentOrder },
+    /// Run a Jira search for the search bar
+    Search(String),
+    /// Refetch an issue Jira announced a change to, with its comments in
+    /// the given order when they changed
+    FetchIssue { key: String, comments: Option<CommentOrder> },
+    /// Fetch the issues a ticket's description and comments mention
+    LoadReferences(Vec<String>),
+    /// Fetch the branches, commits and pull requests of the ticket in the
+    /// detail view, which the dev-status API looks up by issue id
+    LoadDevInfo { key: String, issue_id: String },
+    /// Fetch the titles of linked Confluence pages
+    LoadPageTitles(Vec<String>),
+    /// Fetch the web links of a ticket after one was added
+    LoadRemoteLinks(String),
+    /// Fetch the SLAs of the service desk request in the detail view
+    LoadSlas(String),
+    /// Fetch the approvals of the service desk request in the detail view
+    LoadApprovals(String),
+    /// Fetch what the user may do in each of the projects
+    LoadPermissions(Vec<String>),
+    /// Fetch the active and future sprints of a board, with the issues of
+    /// the active one for its burndown
+    LoadSprints(String),
+    /// Fetch the versions of a project for the releases view
+    LoadVersions(String),
+    /// Fetch the comments of the ticket in the detail view in a new order
+    LoadComments { key: String, order: CommentOrder },
+    /// Search my open issues with a due date, for the reminders
+    LoadDueTickets(String),
+    /// Fetch the signed-in user
+    LoadMyself(UserPurpose),
+    /// Fetch the transitions a ticket can take now
+    LoadTransitions { key: String, purpose: TransitionPurpose },
+    /// Fetch the fields the user may edit on a ticket
+    LoadEditFields(String),
+    /// Search the users a ticket can be assigned to
+    SearchAssignableUsers { key: String, query: String },
+    /// Fetch the project roles and my groups a comment on a ticket can be
+    /// restricted to
+    LoadCommentVisibilities { key: String, project_key: String },
+    /// Fetch the boards, to pick one when none is configured
+    LoadBoards(BoardPurpose),
+    /// Fetch the column mapping of a board for the kanban view
+    LoadBoardColumns(String),
+    /// Fetch the backlog of a board in rank order
+    LoadBacklog(String),
+    /// Fetch the last closed sprints of a board with their issues
+    LoadVelocity(String),
+    /// Fetch the issues of a sprint
+    LoadSprintIssues { sprint: Sprint, purpose: SprintIssuesPurpose },
+    /// Fetch the epics of the roadmap query and the versions of the
+    /// projects
+    LoadTimeline { jql: String, projects: Vec<String> },
+    /// Fetch the children of an epic on the roadmap
+    LoadEpicChildren(String),
+    /// Fetch the issues whose fix version is `version`
+    LoadVersionIssues {
+        version: Version,
+        purpose: VersionIssuesPurpose,
+    },
+    /// Build the timesheet of `user` for the week containing `date`
+    LoadTimesheet {
+        user: User,
+        date: NaiveDate,
+        timezone: DisplayTimezone,
+    },
+    /// Fetch the activity stream of a query
+    LoadActivity(String),
+    /// Run a query on every instance
+    SearchInstances { jql: String, instances: Instances },
+    /// Fetch the projects a ticket could move to
+    LoadProjects(String),
+    /// Fetch the entity properties of a ticket
+    LoadIssueProperties(String),
+    /// Follow the blocker links of a dependency graph's unloaded issues
+    LoadDependencies(DependencyGraph),
+    /// Download an attachment to preview or open it
+    DownloadAttachment(Attachment),
+    /// Reload the ticket list with its query
+    Reload(String),
+    /// Fetch the issues of the list's query matching `changed_since`, and
+    /// those of the listed `keys` that changed at all
+    LoadChanges {
+        jql: String,
+        changed_since: String,
+        keys: Vec<String>,
+    },
+    /// Count the issues of each query for the filter bar
+    CountIssues(Vec<String>),
+    /// Send a change the user made to Jira
+    Write(Write),
+    /// Move an issue to another project, which gives it a new key
+    MoveIssue {
+        key: String,
+        project: Project,
+        issue_type: IssueType,
+    },
+}
+
+/// A change to Jira made from the UI. The app learns how it went from
+/// `Message::Written`, which carries the write back with Jira's answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Write {
+    /// Run a transition with the values of its screen fields and a comment
+    Transition {
+        key: String,
+        transition: Transition,
+        fields: Option<serde_json::Value>,
+        comment: Option<String>,
+    },
+    /// Change fields from the edit form
+    Update { key: String, data: UpdateIssueData },
+    SetReporter { key: String, user: User },
+    Assign { key: String, user: User },
+    Comment {
+        key: String,
+        body: String,
+        visibility: CommentVisibility,
+    },
+    /// One issue's share of a bulk label change
+    Label { key: String, change: LabelChange },
+    Rank { key: String, position: RankPosition },
+    MoveToSprint { sprint: Sprint, keys: Vec<String> },
+    Worklog {
+        key: String,
+        started: DateTime<Utc>,
+        seconds: u64,
+        comment: Option<String>,
+    },
+    RemoteLink {
+        key: String,
+        title: String,
+        url: String,
+    },
+    AnswerApproval {
+        key: String,
+        approval: Approval,
+        approve: bool,
+    },
+    StartSprint {
+        sprint: Sprint,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+    /// Move the incomplete issues out of the sprint, then close it
+    CompleteSprint(CompletionPlan),
+    ReleaseVersion { version: Version, date: NaiveDate },
+}
+
+impl Write {
+    async fn send(&self, client: &dyn ApiClient) -> Result<()> {
+        match self {
+            Write::Transition {
+                key,
+                transition,
+                fields,
+                comment,
+            } => {
+                client
+                    .transition_issue(key, &transition.id, comment.clone(), fields.clone())
+                    .await
+            }
+            Write::Update { key, data } => client.update_issue(key, data.clone()).await,
+            Write::SetReporter { key, user } => {
+                client.update_issue(key, UpdateIssueData::reporter(&user.account_id)).await
+            }
+            Write::Assign { key, user } => client.assign_issue(key, &user.account_id).await,
+            Write::Comment {
+                key,
+                body,
+                visibility,
+            } => client.add_comment(key, body.clone(), visibility).await,
+            Write::Label { key, change } => client.change_label(key, change).await,
+            Write::Rank { key, position } => client.rank_issue(key, position.clone()).await,
+            Write::MoveToSprint { sprint, keys } => client.move_issues_to_sprint(&sprint.id, keys).await,
+            Write::Worklog {
+                key,
+                started,
+                seconds,
+                comment,
+            } => client.add_worklog(key, *started, *seconds, comment.as_deref()).await,
+            Write::RemoteLink { key, title, url } => client.add_remote_link(key, title, url).await,
+            Write::AnswerApproval {
+                key,
+                approval,
+                approve,
+            } => client.answer_approval(key, &approval.id, *approve).await,
+            Write::StartSprint { sprint, start, end } => {
+                client.start_sprint(&sprint.id, *start, *end).await
+            }
+            Write::CompleteSprint(plan) => {
+                if !plan.incomplete_keys.is_empty() {
+                    match &plan.target {
+                        Some(target) => {
+                            client.move_issues_to_sprint(&target.id, &plan.incomplete_keys).await?
+                        }
+                        None => client.move_issues_to_backlog(&plan.incomplete_keys).await?,
+                    }
+                }
+                client.complete_sprint(&plan.sprint.id).await
+            }
+            Write::ReleaseVersion { version, date } => client.release_version(&version.id, *date).await,
+        }
+    }
+}
+
+/// What the signed-in user was fetched for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserPurpose {
+    /// Only to know it, for the assignee scope and the dashboard
+    Remember,
+    SmartView(SmartView),
+    CycleAssigneeScope,
+    /// The timesheet of the week containing the date
+    Timesheet(NaiveDate),
+}
+
+/// What the transitions of a ticket were fetched for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransitionPurpose {
+    /// The transitions view
+    List,
+    StartProgress,
+    Resolve,
+    /// Repeat a transition made on another ticket; its id may differ in
+    /// this ticket's workflow, so it is also matched by name
+    Repeat {
+        transition: Transition,
+        fields: Option<serde_json::Value>,
+        comment: Option<String>,
+    },
+}
+
+/// What a board was picked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardPurpose {
+    Columns,
+    Sprints,
+    Backlog,
+    Velocity,
+}
+
+/// What the issues of a sprint were fetched for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SprintIssuesPurpose {
+    /// Plan what happens to its incomplete issues before completing it
+    Complete,
+    /// Copy its resolved issues as a changelog
+    Changelog,
+}
+
+/// What the issues of a version were fetched for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionIssuesPurpose {
+    /// The releases view's issue list
+    List,
+    /// Count the unresolved ones before releasing it
+    Release,
+    /// Copy them as release notes
+    Notes,
+}
+
+/// Instances searched by name, each with its client
+#[derive(Clone)]
+pub struct Instances(pub Vec<(String, Arc<dyn ApiClient>)>);
+
+impl std::fmt::Debug for Instances {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.0.iter().map(|(name, _)| name)).finish()
+    }
+}
+
+impl PartialEq for Instances {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|((name, client), (other_name, other_client))| {
+                    name == other_name && Arc::ptr_eq(client, other_client)
+                })
+    }
+}
+
+impl Eq for Instances {}
+
+/// Result of an action, sent back to the UI
+#[derive(Debug)]
+pub enum Message {
+    DetailLoaded {
+        key: String,
+        details: Result<IssueDetails>,
+        comments: Result<Vec<Comment>>,
+        remote_links: Result<Vec<RemoteLink>>,
+    },
+    SearchLoaded {
+        jql: String,
+        result: Result<SearchResult>,
+    },
+    IssueFetched {
+        key: String,
+        ticket: Result<Ticket>,
+        comments: Option<Result<Vec<Comment>>>,
+    },
+    ReferencesLoaded(Vec<(String, Result<Ticket>)>),
+    DevInfoLoaded {
+        key: String,
+        info: Result<DevInfo>,
+    },
+    /// Page titles by URL; None for pages the client cannot read
+    PageTitlesLoaded(Vec<(String, Result<Option<String>>)>),
+    RemoteLinksLoaded {
+        key: String,
+        links: Result<Vec<RemoteLink>>,
+    },
+    SlasLoaded {
+        key: String,
+        slas: Result<Vec<Sla>>,
+    },
+    ApprovalsLoaded {
+        key: String,
+        approvals: Result<Vec<Approval>>,
+    },
+    /// Permissions by project key
+    PermissionsLoaded(Vec<(String, Result<ProjectPermissions>)>),
+    /// The ticket list for `jql`, searched from `started` on
+    TicketsLoaded {
+        jql: String,
+        started: Instant,
+        result: Result<SearchResult>,
+    },
+    /// Changes to the ticket list for `jql`, searched from `started` on
+    ChangesLoaded {
+        jql: String,
+        started: Instant,
+        result: Result<ListChanges>,
+    },
+    SprintsLoaded {
+        sprints: Result<Vec<Sprint>>,
+        active_issues: Option<Result<Vec<Ticket>>>,
+    },
+    VersionsLoaded {
+        project_key: String,
+        versions: Result<Vec<Version>>,
+    },
+    CommentsLoaded {
+        key: String,
+        order: CommentOrder,
+        comments: Result<Vec<Comment>>,
+    },
+    DueTicketsLoaded(Result<SearchResult>),
+    MyselfLoaded {
+        user: Result<User>,
+        purpose: UserPurpose,
+    },
+    TransitionsLoaded {
+        key: String,
+        purpose: TransitionPurpose,
+        transitions: Result<Vec<Transition>>,
+    },
+    EditFieldsLoaded {
+        key: String,
+        fields: Result<Vec<TransitionField>>,
+    },
+    AssignableUsersFound {
+        key: String,
+        query: String,
+        users: Result<Vec<User>>,
+    },
+    CommentVisibilitiesLoaded {
+        key: String,
+        project_key: String,
+        roles: Result<Vec<String>>,
+        groups: Result<Vec<String>>,
+    },
+    BoardsLoaded {
+        boards: Result<Vec<Board>>,
+        purpose: BoardPurpose,
+    },
+    BoardColumnsLoaded(Result<Vec<BoardColumnConfig>>),
+    BacklogLoaded(Result<Vec<Ticket>>),
+    /// The last closed sprints, each with its issues
+    VelocityLoaded(Result<Vec<(Sprint, Result<Vec<Ticket>>)>>),
+    SprintIssuesLoaded {
+        sprint: Sprint,
+        purpose: SprintIssuesPurpose,
+        issues: Result<Vec<Ticket>>,
+    },
+    /// The roadmap's epics, and the versions of its projects by project key
+    TimelineLoaded {
+        epics: Result<SearchResult>,
+        versions: Vec<(String, Result<Vec<Version>>)>,
+    },
+    EpicChildrenLoaded {
+        epic_key: String,
+        children: Result<SearchResult>,
+    },
+    VersionIssuesLoaded {
+        version: Version,
+        purpose: VersionIssuesPurpose,
+        issues: Result<Vec<Ticket>>,
+    },
+    TimesheetLoaded(Result<Timesheet>),
+    ActivityLoaded {
+        jql: String,
+        activities: Result<Vec<Activity>>,
+    },
+    /// What the query found on each of `instances` instances
+    InstancesSearched {
+        instances: usize,
+        result: CrossSearchResult,
+    },
+    ProjectsLoaded {
+        key: String,
+        projects: Result<Vec<Project>>,
+    },
+    IssuePropertiesLoaded {
+        key: String,
+        properties: Result<Vec<IssueProperty>>,
+    },
+    DependenciesLoaded(DependencyGraph),
+    AttachmentDownloaded {
+        attachment: Attachment,
+        bytes: Result<Vec<u8>>,
+    },
+    /// Issue counts by query
+    IssuesCounted(Vec<(String, Result<usize>)>),
+    Written {
+        write: Write,
+        result: Result<()>,
+    },
+    IssueMoved {
+        key: String,
+        moved: Result<Ticket>,
+    },
+}
+
+/// Issues that changed since the ticket list was last synced
+#[derive(Debug)]
+pub struct ListChanges {
+    /// Changed issues matching the list's query
+    pub matching: Vec<Ticket>,
+    /// Changed issues of the list, matching or not; left unsearched when a
+    /// new issue matches, as the list is reloaded anyway
+    pub touched: Result<Vec<Ticket>>,
+}
+
+impl Action {
+    /// Run the action in a background task that sends its message, unless
+    /// `token` is cancelled first
+    pub fn spawn(
+        self,
+        client: Arc<dyn ApiClient>,
+        token: CancellationToken,
+        sender: UnboundedSender<Message>,
+    ) {
+        tokio::spawn(async move {
+            if let Some(Some(message)) = token.run(self.run(client.as_ref())).await {
+                // The receiver is gone once the app quit
+                let _ = sender.send(message);
+            }
+        });
+    }
+
+    async fn run(self, client: &dyn ApiClient) -> Option<Message> {
+        match self {
+            Action::LoadDetail { key, comment_order } => {
+                let (details, comments, remote_links) = tokio::join!(
+                    client.get_issue_details(&key),
+                    client.get_comments(&key, comment_order),
+                    client.get_remote_links(&key)
+                );
+                Some(Message::DetailLoaded {
+                    key,
+                    details,
+                    comments,
+                    remote_links,
+                })
+            }
+            Action::Search(jql) => {
+                let result = client.search_issues(&jql, 0, 50).await;
+                Some(Message::SearchLoaded { jql, result })
+            }
+            Action::FetchIssue { key, comments } => {
+                let ticket = client.get_issue(&key).await;
+                let comments = match comments {
+                    Some(order) => Some(client.get_comments(&key, order).await),
+                    None => None,
+                };
+                Some(Message::IssueFetched {
+                    key,
+                    ticket,
+                    comments,
+                })
+            }
+            Action::LoadReferences(keys) => {
+                let mut tickets = Vec::new();
+                for key in keys {
+                    let ticket = client.get_issue(&key).await;
+                    tickets.push((key, ticket));
+                }
+                Some(Message::ReferencesLoaded(tickets))
+            }
+            Action::LoadDevInfo { key, issue_id } => {
+                let info = client.get_dev_info(&issue_id).await;
+                Some(Message::DevInfoLoaded { key, info })
+            }
+            Action::LoadPageTitles(urls) => {
+                let mut titles = Vec::new();
+                for url in urls {
+                    let title = client.get_confluence_page_title(&url).await;
+                    titles.push((url, title));
+                }
+                Some(Message::PageTitlesLoaded(titles))
+            }
+            Action::LoadPermissions(projects) => {
+                let mut permissions = Vec::new();
+                for project in projects {
+                    let granted = client.get_my_permissions(&project).await;
+                    permissions.push((project, granted));
+                }
+                Some(Message::PermissionsLoaded(permissions))
+            }
+            Action::LoadRemoteLinks(key) => {
+                let links = client.get_remote_links(&key).await;
+                Some(Message::RemoteLinksLoaded { key, links })
+            }
+            Action::LoadSlas(key) => {
+                let slas = client.get_slas(&key).await;
+                Some(Message::SlasLoaded { key, slas })
+            }
+            Action::LoadApprovals(key) => {
+                let approvals = client.get_approvals(&key).await;
+                Some(Message::ApprovalsLoaded { key, approvals })
+            }
+            Action::LoadSprints(board_id) => {
+                let (sprints, active_issues) = fetch_sprints(client, &board_id).await;
+                Some(Message::SprintsLoaded {
+                    sprints,
+                    active_issues,
+                })
+            }
+            Action::LoadVersions(project_key) => {
+                let versions = client.get_versions(&project_key).await;
+                Some(Message::VersionsLoaded {
+                    project_key,
+                    versions,
+                })
+            }
+            Action::LoadComments { key, order } => {
+                let comments = client.get_comments(&key, order).await;
+                Some(Message::CommentsLoaded {
+                    key,
+                    order,
+                    comments,
+                })
+            }
+            Action::LoadDueTickets(jql) => {
+                Some(Message::DueTicketsLoaded(client.search_issues(&jql, 0, 50).await))
+            }
+            Action::LoadMyself(purpose) => {
+                let user = client.get_myself().await;
+                Some(Message::MyselfLoaded { user, purpose })
+            }
+            Action::LoadTransitions { key, purpose } => {
+                let transitions = client.get_transitions(&key).await;
+                Some(Message::TransitionsLoaded {
+                    key,
+                    purpose,
+                    transitions,
+                })
+            }
+            Action::LoadEditFields(key) => {
+                let fields = client.get_edit_fields(&key).await;
+                Some(Message::EditFieldsLoaded { key, fields })
+            }
+            Action::SearchAssignableUsers { key, query } => {
+                let users = client.search_assignable_users(&key, &query).await;
+                Some(Message::AssignableUsersFound { key, query, users })
+            }
+            Action::LoadCommentVisibilities { key, project_key } => {
+                let (roles, groups) = tokio::join!(
+                    client.get_project_roles(&project_key),
+                    client.get_my_groups()
+                );
+                Some(Message::CommentVisibilitiesLoaded {
+                    key,
+                    project_key,
+                    roles,
+                    groups,
+                })
+            }
+            Action::LoadBoards(purpose) => {
+                let boards = client.get_boards().await;
+                Some(Message::BoardsLoaded { boards, purpose })
+            }
+            Action::LoadBoardColumns(board_id) => {
+                Some(Message::BoardColumnsLoaded(client.get_board_columns(&board_id).await))
+            }
+            Action::LoadBacklog(board_id) => {
+                Some(Message::BacklogLoaded(client.get_backlog(&board_id).await))
+            }
+            Action::LoadVelocity(board_id) => {
+                Some(Message::VelocityLoaded(fetch_velocity(client, &board_id).await))
+            }
+            Action::LoadSprintIssues { sprint, purpose } => {
+                let issues = client.get_sprint_issues(&sprint.id).await;
+                Some(Message::SprintIssuesLoaded {
+                    sprint,
+                    purpose,
+                    issues,
+                })
+            }
+            Action::LoadTimeline { jql, projects } => {
+                let epics = client.search_issues(&jql, 0, TIMELINE_EPICS).await;
+                let mut versions = Vec::new();
+                for project in projects {
+                    let found = client.get_versions(&project).await;
+                    versions.push((project, found));
+                }
+                Some(Message::TimelineLoaded { epics, versions })
+            }
+            Action::LoadEpicChildren(epic_key) => {
+                let jql = format!("parent = {} ORDER BY rank ASC", epic_key);
+                let children = client.search_issues(&jql, 0, TIMELINE_EPICS).await;
+                Some(Message::EpicChildrenLoaded { epic_key, children })
+            }
+            Action::LoadVersionIssues { version, purpose } => {
+                let jql = format!("fixVersion = {} ORDER BY status ASC, key ASC", version.id);
+                let issues = client
+                    .search_issues(&jql, 0, RELEASE_ISSUES)
+                    .await
+                    .map(|result| result.issues);
+                Some(Message::VersionIssuesLoaded {
+                    version,
+                    purpose,
+                    issues,
+                })
+            }
+            Action::LoadTimesheet {
+                user,
+                date,
+                timezone,
+            } => {
+                let timesheet = TimesheetService::load(client, &user, date, &timezone).await;
+                Some(Message::TimesheetLoaded(timesheet))
+            }
+            Action::LoadActivity(jql) => {
+                let activities = ActivityService::load(client, &jql, Utc::now()).await;
+                Some(Message::ActivityLoaded { jql, activities })
+            }
+            Action::SearchInstances { jql, instances } => {
+                let result = CrossSearchService::search(&instances.0, &jql).await;
+                Some(Message::InstancesSearched {
+                    instances: instances.0.len(),
+                    result,
+                })
+            }
+            Action::LoadProjects(key) => {
+                let projects = client.get_projects().await;
+                Some(Message::ProjectsLoaded { key, projects })
+            }
+            Action::LoadIssueProperties(key) => {
+                let properties = client.get_issue_properties(&key).await;
+                Some(Message::IssuePropertiesLoaded { key, properties })
+            }
+            Action::LoadDependencies(graph) => {
+                Some(Message::DependenciesLoaded(fetch_dependencies(client, graph).await))
+            }
+            Action::DownloadAttachment(attachment) => {
+                let bytes = client.download_attachment(&attachment.content_url).await;
+                Some(Message::AttachmentDownloaded { attachment, bytes })
+            }
+            Action::Reload(jql) => {
+                let started = Instant::now();
+                let result = client.search_issues(&jql, 0, 50).await;
+                Some(Message::TicketsLoaded {
+                    jql,
+                    started,
+                    result,
+                })
+            }
+            Action::LoadChanges {
+                jql,
+                changed_since,
+                keys,
+            } => {
+                let started = Instant::now();
+                let result = fetch_changes(client, &jql, &changed_since, &keys).await;
+                Some(Message::ChangesLoaded {
+                    jql,
+                    started,
+                    result,
+                })
+            }
+            Action::CountIssues(queries) => {
+                let mut counts = Vec::new();
+                for jql in queries {
+                    let count = client.count_issues(&jql).await;
+                    counts.push((jql, count));
+                }
+                Some(Message::IssuesCounted(counts))
+            }
+            Action::Write(write) => {
+                let result = write.send(client).await;
+                Some(Message::Written { write, result })
+            }
+            Action::MoveIssue {
+                key,
+                project,
+                issue_type,
+            } => {
+                let moved = client.move_issue(&key, &project.key, &issue_type.id).await;
+                Some(Message::IssueMoved { key, moved })
+            }
+        }
+    }
+}
+
+async fn fetch_changes(
+    client: &dyn ApiClient,
+    jql: &str,
+    changed_since: &str,
+    keys: &[String],
+) -> Result<ListChanges> {
+    let matching = client
+        .search_issues(&FilterService::restrict_query(jql, changed_since), 0, 50)
+        .await?
+        .issues;
+    let touched = if keys.is_empty() || matching.iter().any(|ticket| !keys.contains(&ticket.key)) {
+        Ok(Vec::new())
+    } else {
+        let listed = format!("{} AND key in ({})", changed_since, keys.join(", "));
+        client.search_issues(&listed, 0, keys.len()).await.map(|result| result.issues)
+    };
+    Ok(ListChanges { matching, touched })
+}
+
+/// The last closed sprints of a board, oldest first, each with its issues
+async fn fetch_velocity(
+    client: &dyn ApiClient,
+    board_id: &str,
+) -> Result<Vec<(Sprint, Result<Vec<Ticket>>)>> {
+    let sprints = client.get_sprints(board_id, &[SprintState::Closed]).await?;
+    let older = sprints.len().saturating_sub(VELOCITY_SPRINTS);
+    let mut velocity = Vec::new();
+    for sprint in sprints.into_iter().skip(older) {
+        let issues = client.get_sprint_issues(&sprint.id).await;
+        velocity.push((sprint, issues));
+    }
+    Ok(velocity)
+}
+
+/// Fetch the issues of `graph` whose links are not known yet, a few levels
+/// each way; issues that fail to load are left without their links
+async fn fetch_dependencies(client: &dyn ApiClient, mut graph: DependencyGraph) -> DependencyGraph {
+    let mut fetched = 0;
+    loop {
+        let missing = graph.unloaded(DEPENDENCY_DEPTH);
+        if missing.is_empty() || fetched >= DEPENDENCY_ISSUES {
+            return graph;
+        }
+        for key in missing.into_iter().take(DEPENDENCY_ISSUES - fetched) {
+            fetched += 1;
+            match client.get_issue(&key).await {
+                Ok(issue) => graph.insert(&issue),
+                Err(e) => {
+                    log::warn!("fetch_dependencies: Failed to fetch {}: {}", key, e);
+                    graph.mark_loaded(&key);
+                }
+            }
+        }
+    }
+}
+
+/// The active and future sprints of a board, and the issues of the active
+/// one when they loaded
+pub async fn fetch_sprints(
+    client: &dyn ApiClient,
+    board_id: &str,
+) -> (Result<Vec<Sprint>>, Option<Result<Vec<Ticket>>>) {
+    let sprints = client
+        .get_sprints(board_id, &[SprintState::Active, SprintState::Future])
+        .await;
+    let active = match &sprints {
+        Ok(sprints) => sprints.iter().find(|sprint| sprint.is_active()),
+        Err(_) => None,
+    };
+    let active_issues = match active {
+        Some(active) => Some(client.get_sprint_issues(&active.id).await),
+        None => None,
+    };
+    (sprints, active_issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::api::demo_client::DemoApiClient;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    #[tokio::test]
+    async fn test_cancelled_action_sends_nothing() {
+        let client: Arc<dyn ApiClient> = Arc::new(DemoApiClient::new().unwrap());
+        let (sender, mut receiver) = unbounded_channel();
+
+        Action::LoadDetail {
+            key: "PROJ-123".to_string(),
+            comment_order: CommentOrder::OldestFirst,
+        }
+        .spawn(
+            Arc::clone(&client),
+            CancellationToken::new(),
+            sender.clone(),
+        );
+        match receiver.recv().await {
+            Some(Message::DetailLoaded { key, details, .. }) => {
+                assert_eq!(key, "PROJ-123");
+                assert!(details.is_ok());
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+
+        Action::LoadReferences(vec!["PROJ-123".to_string(), "UTF-8".to_string()]).spawn(
+            Arc::clone(&client),
+            CancellationToken::new(),
+            sender.clone(),
+        );
+        match receiver.recv().await {
+            Some(Message::ReferencesLoaded(tickets)) => {
+                assert_eq!(tickets[0].0, "PROJ-123");
+                assert!(tickets[0].1.is_ok());
+                assert_eq!(tickets[1].0, "UTF-8");
+                assert!(tickets[1].1.is_err());
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+
+        let token = CancellationToken::new();
+        token.cancel();
+        Action::Search("text ~ \"x\"".to_string()).spawn(client, token, sender);
+        tokio::task::yield_now().await;
+        assert!(receiver.try_recv().is_err());
+    }
+}
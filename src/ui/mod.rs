@@ -1,6 +1,10 @@
 pub mod app;
+pub mod backend;
 pub mod components;
 pub mod events;
+pub mod highlight;
+pub mod keymap;
+pub mod message;
 pub mod renderer;
 pub mod theme;
 
@@ -1,3 +1,4 @@
+use crate::ui::keymap;
 use crate::ui::theme::Theme;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -8,16 +9,40 @@ use ratatui::{
 /// Terminal renderer
 pub struct Renderer {
     theme: Theme,
+    /// Keys whose hints are left out of the help bar
+    hidden_hints: Vec<&'static str>,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Renderer {
     /// Create a new renderer
     pub fn new() -> Self {
+        Self::with_theme(Theme::default())
+    }
+
+    pub fn with_theme(theme: Theme) -> Self {
         Self {
-            theme: Theme::default(),
+            theme,
+            hidden_hints: Vec::new(),
         }
     }
 
+    /// Switch themes, e.g. after the config changed
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Leave the hints of these keys out of the help bar, e.g. for actions
+    /// the user lacks permission for
+    pub fn set_hidden_hints(&mut self, keys: Vec<&'static str>) {
+        self.hidden_hints = keys;
+    }
+
     /// Get the theme
     pub fn theme(&self) -> &Theme {
         &self.theme
@@ -58,7 +83,7 @@ impl Renderer {
 
     /// Render the help bar
     fn render_help_bar(&mut self, frame: &mut Frame, area: Rect) -> Result<(), std::io::Error> {
-        let help_text = " [q]uit [↑↓/jk]move [Enter]detail [Esc]back [a]ssign [s]tart [R]esolve [t]ransitions [r]efresh ";
+        let help_text = keymap::help_bar_without(&self.hidden_hints);
         let paragraph = Paragraph::new(help_text)
             .style(self.theme.help_bar)
             .alignment(Alignment::Left)
@@ -1,3 +1,5 @@
+use crate::ui::highlight::ColorDepth;
+use crate::utils::color;
 use ratatui::style::{Color, Modifier, Style};
 
 /// Theme configuration for the application
@@ -16,12 +18,23 @@ pub struct Theme {
     pub priority_medium: Style,
     pub priority_high: Style,
     pub priority_highest: Style,
-    #[allow(dead_code)] // Will be used for error messages
+    pub updated_badge: Style,
+    pub diff_added: Style,
+    pub diff_removed: Style,
     pub error: Style,
     #[allow(dead_code)] // Will be used for success messages
     pub success: Style,
-    #[allow(dead_code)] // Will be used for warning messages
     pub warning: Style,
+    /// @mentions in comments
+    pub mention: Style,
+    /// Code spans and blocks in descriptions
+    pub code: Style,
+    /// Links and URLs in descriptions and comments
+    pub link: Style,
+    /// Mark status and priority with symbols, so they read without color
+    pub symbols: bool,
+    /// Colors available for highlighting code
+    pub colors: ColorDepth,
 }
 
 impl Default for Theme {
@@ -54,6 +67,14 @@ impl Default for Theme {
                 .fg(Color::Magenta),
             priority_highest: Style::default()
                 .fg(Color::Red),
+            updated_badge: Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow),
+            diff_added: Style::default()
+                .fg(Color::Green),
+            diff_removed: Style::default()
+                .fg(Color::Red)
+                .add_modifier(Modifier::CROSSED_OUT),
             error: Style::default()
                 .fg(Color::Red)
                 .add_modifier(Modifier::BOLD),
@@ -63,11 +84,132 @@ impl Default for Theme {
             warning: Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
+            mention: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            code: Style::default()
+                .fg(Color::LightBlue),
+            link: Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::UNDERLINED),
+            symbols: false,
+            colors: ColorDepth::detect(),
         }
     }
 }
 
 impl Theme {
+    /// Theme for `ui.theme`: "high-contrast", "monochrome", or the default.
+    /// `no_color` (the NO_COLOR convention) always gives monochrome
+    pub fn from_config(name: &str, no_color: bool) -> Self {
+        if no_color {
+            return Self::monochrome();
+        }
+        match name.to_lowercase().as_str() {
+            "high-contrast" => Self::high_contrast(),
+            "monochrome" => Self::monochrome(),
+            _ => Self::default(),
+        }
+    }
+
+    /// True when `--color=never` or NO_COLOR ask for no color in the UI,
+    /// unless `--color=always` overrides it
+    pub fn no_color_requested() -> bool {
+        !color::choice().use_color(true)
+    }
+
+    /// Bright colors in bold, and symbols next to status and priority
+    pub fn high_contrast() -> Self {
+        let bold = |color| Style::default().fg(color).add_modifier(Modifier::BOLD);
+        Self {
+            status_bar: Style::default().fg(Color::Black).bg(Color::White),
+            help_bar: Style::default().fg(Color::Black).bg(Color::LightYellow),
+            selected: Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+            focused: Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            normal: Style::default().fg(Color::White),
+            status_todo: bold(Color::LightCyan),
+            status_in_progress: bold(Color::LightYellow),
+            status_done: bold(Color::LightGreen),
+            priority_lowest: Style::default().fg(Color::White),
+            priority_low: bold(Color::LightCyan),
+            priority_medium: bold(Color::LightYellow),
+            priority_high: bold(Color::LightMagenta),
+            priority_highest: bold(Color::LightRed),
+            updated_badge: Style::default().fg(Color::Black).bg(Color::LightYellow),
+            diff_added: bold(Color::LightGreen).add_modifier(Modifier::UNDERLINED),
+            diff_removed: bold(Color::LightRed).add_modifier(Modifier::CROSSED_OUT),
+            error: bold(Color::LightRed).add_modifier(Modifier::UNDERLINED),
+            success: bold(Color::LightGreen),
+            warning: bold(Color::LightYellow),
+            mention: bold(Color::LightCyan).add_modifier(Modifier::UNDERLINED),
+            code: Style::default().fg(Color::LightGreen),
+            link: bold(Color::LightBlue).add_modifier(Modifier::UNDERLINED),
+            symbols: true,
+            colors: ColorDepth::detect(),
+        }
+    }
+
+    /// No colors at all: meaning is carried by text attributes and symbols
+    pub fn monochrome() -> Self {
+        let plain = Style::default();
+        let bold = plain.add_modifier(Modifier::BOLD);
+        Self {
+            status_bar: plain.add_modifier(Modifier::REVERSED),
+            help_bar: plain.add_modifier(Modifier::REVERSED),
+            selected: plain.add_modifier(Modifier::REVERSED),
+            focused: bold,
+            normal: plain,
+            status_todo: plain,
+            status_in_progress: bold,
+            status_done: plain.add_modifier(Modifier::DIM),
+            priority_lowest: plain.add_modifier(Modifier::DIM),
+            priority_low: plain,
+            priority_medium: plain,
+            priority_high: bold,
+            priority_highest: bold.add_modifier(Modifier::UNDERLINED),
+            updated_badge: plain.add_modifier(Modifier::REVERSED),
+            diff_added: plain.add_modifier(Modifier::UNDERLINED),
+            diff_removed: plain.add_modifier(Modifier::CROSSED_OUT),
+            error: bold.add_modifier(Modifier::UNDERLINED),
+            success: bold,
+            warning: bold,
+            mention: bold.add_modifier(Modifier::UNDERLINED),
+            code: plain.add_modifier(Modifier::DIM),
+            link: plain.add_modifier(Modifier::UNDERLINED),
+            symbols: true,
+            colors: ColorDepth::None,
+        }
+    }
+
+    /// "● " style marker for a status category when symbols are on
+    pub fn status_symbol(&self, category: &str) -> &'static str {
+        if !self.symbols {
+            return "";
+        }
+        match category {
+            "new" | "To Do" => "○ ",
+            "indeterminate" | "In Progress" => "◐ ",
+            "done" | "Done" => "● ",
+            _ => "",
+        }
+    }
+
+    /// Priority name, led by arrows for its level when symbols are on
+    pub fn priority_label(&self, priority: &str) -> String {
+        if !self.symbols {
+            return priority.to_string();
+        }
+        let symbol = match priority {
+            "Lowest" => "▼▼",
+            "Low" => "▼",
+            "Medium" => "=",
+            "High" => "▲",
+            "Highest" => "▲▲",
+            _ => return priority.to_string(),
+        };
+        format!("{} {}", symbol, priority)
+    }
+
     /// Get style for a status category
     pub fn status_style(&self, category: &str) -> Style {
         match category {
@@ -126,4 +268,21 @@ mod tests {
         assert_eq!(theme.priority_style("High").fg, Some(Color::Magenta));
         assert_eq!(theme.priority_style("Highest").fg, Some(Color::Red));
     }
+
+    #[test]
+    fn test_monochrome_uses_symbols_instead_of_color() {
+        // NO_COLOR and --color=never
+        let theme = Theme::from_config("default", true);
+        assert!(theme.symbols);
+        assert_eq!(theme.colors, ColorDepth::None);
+        assert_eq!(theme.status_style("done").fg, None);
+        assert_eq!(theme.priority_style("Highest").fg, None);
+        assert_eq!(theme.status_symbol("done"), "● ");
+        assert_eq!(theme.priority_label("Highest"), "▲▲ Highest");
+
+        let default = Theme::from_config("default", false);
+        assert_eq!(default.status_symbol("done"), "");
+        assert_eq!(default.priority_label("High"), "High");
+        assert!(Theme::from_config("high-contrast", false).symbols);
+    }
 }
@@ -0,0 +1,138 @@
+use std::sync::OnceLock;
+
+/// When to use color, chosen with `--color=always|never|auto`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// Choice made on the command line for this process
+static CHOICE: OnceLock<ColorChoice> = OnceLock::new();
+
+/// ANSI colors used in command-line output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnsiColor {
+    Red,
+    Green,
+    Yellow,
+}
+
+impl ColorChoice {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "auto" => Some(ColorChoice::Auto),
+            "always" => Some(ColorChoice::Always),
+            "never" => Some(ColorChoice::Never),
+            _ => None,
+        }
+    }
+
+    /// Take `--color=<when>` or `--color <when>` out of the arguments; the
+    /// last one given wins
+    pub fn from_args(args: &mut Vec<String>) -> Result<Self, String> {
+        let mut choice = ColorChoice::Auto;
+        let mut i = 0;
+        while i < args.len() {
+            let value = if let Some(value) = args[i].strip_prefix("--color=") {
+                let value = value.to_string();
+                args.remove(i);
+                value
+            } else if args[i] == "--color" {
+                args.remove(i);
+                if i < args.len() {
+                    args.remove(i)
+                } else {
+                    String::new()
+                }
+            } else {
+                i += 1;
+                continue;
+            };
+            choice = Self::parse(&value).ok_or_else(|| {
+                format!("--color must be always, never or auto, not '{}'", value)
+            })?;
+        }
+        Ok(choice)
+    }
+
+    /// Whether to color output going to a terminal (or not): the flag wins,
+    /// then NO_COLOR, then CLICOLOR_FORCE
+    pub fn use_color(self, is_terminal: bool) -> bool {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let force = std::env::var("CLICOLOR_FORCE").is_ok_and(|value| !value.is_empty() && value != "0");
+        self.resolve(no_color, force, is_terminal)
+    }
+
+    fn resolve(self, no_color: bool, force: bool, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => !no_color && (force || is_terminal),
+        }
+    }
+}
+
+/// Record the command-line choice; later calls are ignored
+pub fn set_choice(choice: ColorChoice) {
+    let _ = CHOICE.set(choice);
+}
+
+/// The command-line choice, `Auto` when none was made
+pub fn choice() -> ColorChoice {
+    CHOICE.get().copied().unwrap_or_default()
+}
+
+/// Whether to color what is printed to stdout
+pub fn stdout_color() -> bool {
+    use std::io::IsTerminal;
+    choice().use_color(std::io::stdout().is_terminal())
+}
+
+/// Whether to color what is printed to stderr
+pub fn stderr_color() -> bool {
+    use std::io::IsTerminal;
+    choice().use_color(std::io::stderr().is_terminal())
+}
+
+/// `text` in `color` when `enabled`
+pub fn paint(text: &str, color: AnsiColor, enabled: bool) -> String {
+    if !enabled {
+        return text.to_string();
+    }
+    let code = match color {
+        AnsiColor::Red => 31,
+        AnsiColor::Green => 32,
+        AnsiColor::Yellow => 33,
+    };
+    format!("\x1b[{}m{}\x1b[0m", code, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_flag_parsing_and_precedence() {
+        let mut args: Vec<String> = ["doctor", "--color", "never", "--demo"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(ColorChoice::from_args(&mut args), Ok(ColorChoice::Never));
+        assert_eq!(args, vec!["doctor", "--demo"]);
+
+        let mut args = vec!["--color=sometimes".to_string()];
+        assert!(ColorChoice::from_args(&mut args).is_err());
+
+        assert!(ColorChoice::Always.resolve(true, false, false));
+        assert!(!ColorChoice::Never.resolve(false, true, true));
+        assert!(!ColorChoice::Auto.resolve(true, true, true));
+        assert!(ColorChoice::Auto.resolve(false, true, false));
+        assert!(!ColorChoice::Auto.resolve(false, false, false));
+        assert!(ColorChoice::Auto.resolve(false, false, true));
+
+        assert_eq!(paint("ok", AnsiColor::Green, false), "ok");
+        assert_eq!(paint("ok", AnsiColor::Green, true), "\x1b[32mok\x1b[0m");
+    }
+}
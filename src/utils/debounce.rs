@@ -0,0 +1,65 @@
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Delays an action until input has been idle for a while, so typing
+/// quickly triggers it once instead of on every keystroke. Time is read from
+/// tokio's clock, so tests can pause and advance it
+#[derive(Debug, Clone)]
+pub struct Debouncer {
+    delay: Duration,
+    pending_since: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            pending_since: None,
+        }
+    }
+
+    /// Record input, restarting the wait
+    pub fn input(&mut self) {
+        self.pending_since = Some(Instant::now());
+    }
+
+    /// Drop the pending action
+    pub fn cancel(&mut self) {
+        self.pending_since = None;
+    }
+
+    /// True once the input has been idle for the delay; the action then
+    /// stops being pending
+    pub fn ready(&mut self) -> bool {
+        match self.pending_since {
+            Some(since) if since.elapsed() >= self.delay => {
+                self.pending_since = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_ready_once_after_idle_delay() {
+        let mut debouncer = Debouncer::new(Duration::from_millis(20));
+        assert!(!debouncer.ready());
+
+        debouncer.input();
+        tokio::time::advance(Duration::from_millis(15)).await;
+        assert!(!debouncer.ready());
+        tokio::time::advance(Duration::from_millis(5)).await;
+        assert!(debouncer.ready());
+        assert!(!debouncer.ready());
+
+        debouncer.input();
+        debouncer.cancel();
+        tokio::time::advance(Duration::from_millis(30)).await;
+        assert!(!debouncer.ready());
+    }
+}
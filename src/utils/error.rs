@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+use std::fmt;
 use thiserror::Error;
 
 /// Main error type for LazyJira
@@ -10,12 +12,15 @@ pub enum LazyJiraError {
     Authentication(String),
 
     #[error("Validation error: {0}")]
-    #[allow(dead_code)] // Will be used for validation errors
     Validation(String),
 
     #[error("API error: {0}")]
     Api(String),
 
+    /// Error response from Jira with its messages parsed out
+    #[error("API error {0}")]
+    Jira(JiraApiError),
+
     #[error("Configuration error: {0}")]
     Config(String),
 
@@ -25,10 +30,100 @@ pub enum LazyJiraError {
     #[error("Parse error: {0}")]
     Parse(String),
 
+    #[error("Script error: {0}")]
+    Script(String),
+
     #[error("Internal error: {0}")]
     #[allow(dead_code)] // Will be used for internal errors
     Internal(String),
 }
 
+/// Error response from Jira, parsed from its `errorMessages` and `errors`
+/// fields
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraApiError {
+    pub status: u16,
+    /// Messages about the request as a whole
+    pub messages: Vec<String>,
+    /// Messages by field id, e.g. "summary" → "You must specify a summary"
+    pub field_errors: BTreeMap<String, String>,
+}
+
+impl JiraApiError {
+    /// Parse an error body; a body that is not Jira's error JSON becomes
+    /// the only message
+    pub fn from_response(status: u16, body: &str) -> Self {
+        let json: serde_json::Value = serde_json::from_str(body).unwrap_or_default();
+        let mut messages: Vec<String> = json
+            .get("errorMessages")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|message| message.as_str().map(String::from))
+            .collect();
+        let field_errors: BTreeMap<String, String> = json
+            .get("errors")
+            .and_then(|v| v.as_object())
+            .into_iter()
+            .flatten()
+            .filter_map(|(field, message)| Some((field.clone(), message.as_str()?.to_string())))
+            .collect();
+        let body = body.trim();
+        if messages.is_empty() && field_errors.is_empty() && !body.is_empty() {
+            messages.push(body.to_string());
+        }
+        Self {
+            status,
+            messages,
+            field_errors,
+        }
+    }
+
+    /// Rate limiting and server errors may go away on their own
+    pub fn is_retryable(&self) -> bool {
+        self.status == 429 || self.status >= 500
+    }
+}
+
+impl fmt::Display for JiraApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = self.messages.clone();
+        parts.extend(
+            self.field_errors
+                .iter()
+                .map(|(field, message)| format!("{}: {}", field, message)),
+        );
+        if parts.is_empty() {
+            write!(f, "({})", self.status)
+        } else {
+            write!(f, "({}): {}", self.status, parts.join("; "))
+        }
+    }
+}
+
 /// Result type alias for LazyJira operations
 pub type Result<T> = std::result::Result<T, LazyJiraError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_jira_error_response() {
+        let error = JiraApiError::from_response(
+            400,
+            r#"{"errorMessages":["Bad request"],"errors":{"summary":"You must specify a summary"}}"#,
+        );
+        assert_eq!(error.messages, vec!["Bad request"]);
+        assert_eq!(error.field_errors["summary"], "You must specify a summary");
+        assert!(!error.is_retryable());
+        assert_eq!(
+            error.to_string(),
+            "(400): Bad request; summary: You must specify a summary"
+        );
+
+        let gateway = JiraApiError::from_response(502, "<html>Bad Gateway</html>");
+        assert_eq!(gateway.messages, vec!["<html>Bad Gateway</html>"]);
+        assert!(gateway.is_retryable());
+    }
+}
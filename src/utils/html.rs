@@ -0,0 +1,287 @@
+/// Named entities Jira's rendered HTML uses
+const ENTITIES: &[(&str, &str)] = &[
+    ("amp", "&"),
+    ("lt", "<"),
+    ("gt", ">"),
+    ("quot", "\""),
+    ("apos", "'"),
+    ("nbsp", " "),
+];
+
+/// Markdown for server-rendered HTML, such as Jira's `renderedFields`:
+/// headings, lists, code blocks, links and emphasis are kept, images are
+/// named, and any other markup is dropped
+pub fn html_to_text(html: &str) -> String {
+    let mut text = HtmlText::default();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            text.text(rest);
+            break;
+        };
+        text.text(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map(|end| &comment[end + 3..]).unwrap_or_default();
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            text.text(rest);
+            break;
+        };
+        text.tag(&rest[1..end]);
+        rest = &rest[end + 1..];
+    }
+    text.end_line();
+    text.lines.join("\n")
+}
+
+/// Lines of Markdown built while walking the HTML
+#[derive(Debug, Default)]
+struct HtmlText {
+    lines: Vec<String>,
+    line: String,
+    /// Depth of the `<pre>` blocks around the text, whose spacing is kept
+    pre: usize,
+    /// Depth of the `<script>` and `<style>` elements around the text
+    hidden: usize,
+    /// Open lists, innermost last, with the next number of ordered ones
+    lists: Vec<Option<u64>>,
+    /// Targets of the open links; None for anchors without one
+    links: Vec<Option<String>>,
+}
+
+impl HtmlText {
+    fn end_line(&mut self) {
+        let line = std::mem::take(&mut self.line);
+        let line = line.trim_end();
+        if !line.is_empty() {
+            self.lines.push(line.to_string());
+        }
+    }
+
+    /// Append text, collapsing whitespace outside of code blocks
+    fn text(&mut self, raw: &str) {
+        if self.hidden > 0 {
+            return;
+        }
+        let decoded = decode_entities(raw);
+        if self.pre > 0 {
+            for (idx, part) in decoded.split('\n').enumerate() {
+                if idx > 0 {
+                    self.lines.push(std::mem::take(&mut self.line));
+                }
+                self.line.push_str(part);
+            }
+            return;
+        }
+        for c in decoded.chars() {
+            if c.is_whitespace() {
+                if !self.line.is_empty() && !self.line.ends_with(' ') {
+                    self.line.push(' ');
+                }
+            } else {
+                self.line.push(c);
+            }
+        }
+    }
+
+    /// Handle the inside of a tag, e.g. `a href="…"` or `/p`
+    fn tag(&mut self, tag: &str) {
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name_end = tag
+            .find(|c: char| c.is_whitespace() || c == '/')
+            .unwrap_or(tag.len());
+        let name = tag[..name_end].to_lowercase();
+        let attrs = &tag[name_end..];
+
+        match (name.as_str(), closing) {
+            ("script" | "style", false) => self.hidden += 1,
+            ("script" | "style", true) => self.hidden = self.hidden.saturating_sub(1),
+            _ if self.hidden > 0 => {}
+            ("br", _) if self.pre > 0 => self.lines.push(std::mem::take(&mut self.line)),
+            ("br", _) => self.end_line(),
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", false) => {
+                self.end_line();
+                let level = name[1..].parse().unwrap_or(1);
+                self.line.push_str(&format!("{} ", "#".repeat(level)));
+            }
+            ("ul", false) => {
+                self.end_line();
+                self.lists.push(None);
+            }
+            ("ol", false) => {
+                self.end_line();
+                self.lists.push(Some(1));
+            }
+            ("ul" | "ol", true) => {
+                self.end_line();
+                self.lists.pop();
+            }
+            ("li", false) => {
+                self.end_line();
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "- ".to_string(),
+                };
+                self.line.push_str(&format!("{}{}", "  ".repeat(depth), marker));
+            }
+            ("pre", false) => {
+                self.end_line();
+                self.lines.push("```".to_string());
+                self.pre += 1;
+            }
+            ("pre", true) => {
+                if !self.line.is_empty() {
+                    self.lines.push(std::mem::take(&mut self.line));
+                }
+                self.lines.push("```".to_string());
+                self.pre = self.pre.saturating_sub(1);
+            }
+            _ if self.pre > 0 => {}
+            ("hr", _) => {
+                self.end_line();
+                self.lines.push("---".to_string());
+            }
+            ("td" | "th", false) if !self.line.is_empty() => self.line.push_str(" | "),
+            ("img", _) => {
+                let name = attr(attrs, "alt")
+                    .filter(|alt| !alt.is_empty())
+                    .or_else(|| attr(attrs, "src"))
+                    .unwrap_or_default();
+                self.text(&format!("[image: {}]", name));
+            }
+            ("a", false) => {
+                let href = attr(attrs, "href").filter(|href| !href.starts_with('#'));
+                if href.is_some() {
+                    self.line.push('[');
+                }
+                self.links.push(href.map(|href| decode_entities(&href)));
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = self.links.pop() {
+                    let label = self.line.trim_end().len();
+                    self.line.truncate(label);
+                    self.line.push_str(&format!("]({})", href));
+                }
+            }
+            ("strong" | "b", _) => self.line.push_str("**"),
+            ("em" | "i", _) => self.line.push('*'),
+            ("code" | "tt", _) => self.line.push('`'),
+            ("p" | "div" | "table" | "tr" | "blockquote" | "dl" | "dt" | "dd", _) => self.end_line(),
+            ("li" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6", true) => self.end_line(),
+            _ => {}
+        }
+    }
+}
+
+/// Value of an attribute in the inside of a tag
+fn attr(attrs: &str, name: &str) -> Option<String> {
+    let mut rest = attrs;
+    while let Some(start) = rest.find(name) {
+        let before = rest[..start].chars().last();
+        let after = rest[start + name.len()..].trim_start();
+        rest = &rest[start + name.len()..];
+        if !matches!(before, None | Some(' ' | '\t' | '\n')) {
+            continue;
+        }
+        let Some(value) = after.strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let quote = value.chars().next()?;
+        if quote == '"' || quote == '\'' {
+            let value = &value[1..];
+            return Some(value[..value.find(quote)?].to_string());
+        }
+        let end = value.find(char::is_whitespace).unwrap_or(value.len());
+        return Some(value[..end].to_string());
+    }
+    None
+}
+
+/// Replace `&amp;`-style and numeric character references
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        let replacement = entity.and_then(|entity| {
+            let numeric = match entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => entity.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+            };
+            match numeric.and_then(char::from_u32) {
+                Some(c) => Some(c.to_string()),
+                None => ENTITIES
+                    .iter()
+                    .find(|(name, _)| *name == entity)
+                    .map(|(_, text)| text.to_string()),
+            }
+        });
+        match (entity, replacement) {
+            (Some(entity), Some(replacement)) => {
+                decoded.push_str(&replacement);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text_blocks() {
+        let html = "<h2>Steps</h2>\n<ol>\n<li>Open the <b>login</b> page</li>\n<li>Submit</li>\n</ol>\
+            <p>See <a href=\"https://example.com/a?x=1&amp;y=2\">the docs</a> &amp; logs.</p>\
+            <pre>fn main() {\n    run();\n}</pre>\
+            <p><span class=\"image-wrap\"><img src=\"/secure/attachment/1/shot.png\" alt=\"shot.png\"></span></p>\
+            <script>alert(1)</script><!-- macro --><table><tr><th>Env</th><th>OK</th></tr><tr><td>prod</td><td>no</td></tr></table>";
+        assert_eq!(
+            html_to_text(html),
+            [
+                "## Steps",
+                "1. Open the **login** page",
+                "2. Submit",
+                "See [the docs](https://example.com/a?x=1&y=2) & logs.",
+                "```",
+                "fn main() {",
+                "    run();",
+                "}",
+                "```",
+                "[image: shot.png]",
+                "Env | OK",
+                "prod | no",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("a &lt;b&gt; &#39;c&#x27; &unknown; & d"), "a <b> 'c' &unknown; & d");
+        assert_eq!(attr(" class=\"x\" href='/y' data-alt=z alt=w", "alt"), Some("w".to_string()));
+        assert_eq!(attr(" src=\"a.png\"", "alt"), None);
+    }
+}
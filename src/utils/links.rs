@@ -0,0 +1,135 @@
+/// Characters that end a URL written in running text
+const URL_END: &[char] = &['<', '>', '"', '`'];
+
+/// Punctuation that closes the sentence around a URL rather than the URL
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', ')', ']', '\''];
+
+/// Length of the bare http(s) URL `text` starts with, if it starts with one
+pub fn bare_url_len(text: &str) -> Option<usize> {
+    let scheme = ["https://", "http://"]
+        .into_iter()
+        .find(|scheme| text.starts_with(scheme))?;
+    let end = text
+        .find(|c: char| c.is_whitespace() || URL_END.contains(&c))
+        .unwrap_or(text.len());
+    let url = text[..end].trim_end_matches(TRAILING_PUNCTUATION);
+    (url.len() > scheme.len()).then_some(url.len())
+}
+
+/// URLs of the Markdown links (`[label](url)`) and bare URLs in `text`,
+/// each once, in the order they appear
+pub fn links(text: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let found = markdown_link(rest)
+            .map(|(url, len)| (url.to_string(), len))
+            .or_else(|| bare_url_len(rest).map(|len| (rest[..len].to_string(), len)));
+        match found {
+            Some((url, len)) => {
+                if !urls.contains(&url) {
+                    urls.push(url);
+                }
+                rest = &rest[len..];
+            }
+            None => rest = &rest[c.len_utf8()..],
+        }
+    }
+    urls
+}
+
+/// URL of the Markdown link `text` starts with, and the link's length
+pub fn markdown_link(text: &str) -> Option<(&str, usize)> {
+    let inner = text.strip_prefix('[')?;
+    let label_end = inner.find("](").filter(|end| *end > 0)?;
+    let target = &inner[label_end + 2..];
+    let url_end = target.find(')')?;
+    let url = &target[..url_end];
+    (!url.is_empty()).then_some((url, 1 + label_end + 2 + url_end + 1))
+}
+
+/// Length of the issue key (`PROJ-456`) `text` starts with, if it starts
+/// with one; the character before it is for the caller to check
+pub fn issue_key_len(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let project = bytes
+        .iter()
+        .take_while(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || **b == b'_')
+        .count();
+    if project < 2 || !bytes[0].is_ascii_uppercase() || bytes.get(project) != Some(&b'-') {
+        return None;
+    }
+    let digits = bytes[project + 1..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let len = project + 1 + digits;
+    let word_ends = text[len..].chars().next().is_none_or(|c| !c.is_alphanumeric());
+    (digits > 0 && word_ends).then_some(len)
+}
+
+/// Issue keys mentioned in `text` outside of links, each once, in the order
+/// they appear
+pub fn issue_keys(text: &str) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    let mut previous: Option<char> = None;
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let skip = markdown_link(rest).map(|(_, len)| len).or_else(|| bare_url_len(rest));
+        if let Some(len) = skip {
+            previous = rest[..len].chars().last();
+            rest = &rest[len..];
+            continue;
+        }
+        if !previous.is_some_and(|p| p.is_alphanumeric() || p == '-') {
+            if let Some(len) = issue_key_len(rest) {
+                if !keys.iter().any(|key| key == &rest[..len]) {
+                    keys.push(rest[..len].to_string());
+                }
+                previous = Some('0');
+                rest = &rest[len..];
+                continue;
+            }
+        }
+        previous = Some(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_links_in_markdown_and_bare() {
+        assert_eq!(bare_url_len("https://example.com/a?b=1. Next"), Some(25));
+        assert_eq!(bare_url_len("https://"), None);
+        assert_eq!(bare_url_len("see https://example.com"), None);
+
+        let text = "Read [the docs](https://docs.example.com) or https://example.com/x, \
+                    then (https://example.com/y). Again https://example.com/x";
+        assert_eq!(
+            links(text),
+            vec![
+                "https://docs.example.com",
+                "https://example.com/x",
+                "https://example.com/y",
+            ]
+        );
+        assert_eq!(markdown_link("[a](b) c"), Some(("b", 6)));
+        assert_eq!(markdown_link("[a] (b)"), None);
+    }
+
+    #[test]
+    fn test_issue_keys_outside_links() {
+        assert_eq!(issue_key_len("PROJ-456, then"), Some(8));
+        assert_eq!(issue_key_len("A2_B-1"), Some(6));
+        assert_eq!(issue_key_len("P-1"), None);
+        assert_eq!(issue_key_len("PROJ-"), None);
+        assert_eq!(issue_key_len("PROJ-12a"), None);
+        assert_eq!(issue_key_len("proj-1"), None);
+
+        let text = "Blocked by PROJ-456 (see https://jira.example.com/browse/PROJ-9 and \
+                    [PROJ-10](https://jira.example.com/browse/PROJ-10)); xPROJ-1 \
+                    is not one, OPS-7 and PROJ-456 again";
+        assert_eq!(issue_keys(text), vec!["PROJ-456", "OPS-7"]);
+    }
+}
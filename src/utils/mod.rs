@@ -1,4 +1,13 @@
+pub mod cancel;
+pub mod clipboard;
+pub mod color;
+pub mod debounce;
 pub mod error;
+pub mod html;
+pub mod links;
 pub mod logger;
+pub mod notify;
+pub mod text;
+pub mod time;
 
-pub use error::{LazyJiraError, Result};
+pub use error::{JiraApiError, LazyJiraError, Result};
@@ -0,0 +1,32 @@
+use crate::utils::{LazyJiraError, Result};
+use std::process::{Command, Stdio};
+
+/// Show a desktop notification with notify-send, or osascript on macOS
+pub fn desktop(title: &str, body: &str) -> Result<()> {
+    let script = format!(
+        "display notification \"{}\" with title \"{}\"",
+        escape_applescript(body),
+        escape_applescript(title)
+    );
+    let commands: [(&str, Vec<&str>); 2] = [
+        ("notify-send", vec!["--app-name=LazyJira", title, body]),
+        ("osascript", vec!["-e", &script]),
+    ];
+    for (program, args) in commands {
+        let status = Command::new(program)
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+        if status.is_ok_and(|status| status.success()) {
+            return Ok(());
+        }
+    }
+    Err(LazyJiraError::Config(
+        "no notification tool found; install notify-send".to_string(),
+    ))
+}
+
+fn escape_applescript(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
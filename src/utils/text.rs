@@ -0,0 +1,116 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Marker appended to truncated text
+const ELLIPSIS: &str = "…";
+
+/// Number of terminal columns the text takes, counting wide (e.g. CJK)
+/// characters as two
+pub fn display_width(text: &str) -> usize {
+    UnicodeWidthStr::width(text)
+}
+
+/// Cut text to at most `max_width` columns, ending with an ellipsis when
+/// anything was cut
+pub fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if display_width(text) <= max_width {
+        return text.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    let budget = max_width - display_width(ELLIPSIS);
+    let mut truncated = String::new();
+    let mut width = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if width + char_width > budget {
+            break;
+        }
+        truncated.push(c);
+        width += char_width;
+    }
+    truncated.push_str(ELLIPSIS);
+    truncated
+}
+
+/// Wrap text into lines of at most `width` columns, breaking between words
+/// where possible and keeping the text's own line breaks
+pub fn wrap_to_width(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        let mut line_width = 0;
+
+        for word in paragraph.split_whitespace() {
+            let word_width = display_width(word);
+            if line_width > 0 && line_width + 1 + word_width <= width {
+                line.push(' ');
+                line.push_str(word);
+                line_width += 1 + word_width;
+                continue;
+            }
+            if line_width > 0 {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            if word_width <= width {
+                line.push_str(word);
+                line_width = word_width;
+                continue;
+            }
+
+            // Split words wider than a line, e.g. URLs or CJK text without spaces
+            for c in word.chars() {
+                let char_width = c.width().unwrap_or(0);
+                if line_width + char_width > width && line_width > 0 {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0;
+                }
+                line.push(c);
+                line_width += char_width;
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// A CSV field, quoted when it holds a comma, quote or line break
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_to_width_counts_wide_characters() {
+        assert_eq!(truncate_to_width("short", 10), "short");
+        assert_eq!(truncate_to_width("a longer summary", 8), "a longe…");
+        // Each CJK character takes two columns
+        assert_eq!(display_width("日本語テキスト"), 14);
+        assert_eq!(truncate_to_width("日本語テキスト", 8), "日本語…");
+        assert_eq!(truncate_to_width("héllo wörld", 6), "héllo…");
+        assert_eq!(truncate_to_width("anything", 0), "");
+    }
+
+    #[test]
+    fn test_wrap_to_width() {
+        assert_eq!(
+            wrap_to_width("the quick brown fox\njumps", 10),
+            vec!["the quick", "brown fox", "jumps"]
+        );
+        assert_eq!(wrap_to_width("日本語テキスト", 6), vec!["日本語", "テキス", "ト"]);
+        assert_eq!(wrap_to_width("a\n\nb", 5), vec!["a", "", "b"]);
+    }
+}
@@ -0,0 +1,152 @@
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+/// Date format used when the configured one is missing or invalid
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Whether a strftime pattern is non-empty and free of unknown specifiers
+pub fn is_valid_date_format(pattern: &str) -> bool {
+    !pattern.trim().is_empty()
+        && !StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+}
+
+/// The configured strftime date format, or the default when it is empty or
+/// not a valid pattern
+pub fn checked_date_format(pattern: &str) -> &str {
+    if is_valid_date_format(pattern) {
+        pattern
+    } else {
+        log::warn!("Invalid date_format '{}', using {}", pattern, DEFAULT_DATE_FORMAT);
+        DEFAULT_DATE_FORMAT
+    }
+}
+
+/// Timezone that dates and times are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayTimezone {
+    /// The system timezone
+    #[default]
+    Local,
+    /// An IANA timezone set in the config, e.g. "Europe/Berlin"
+    Named(Tz),
+}
+
+impl DisplayTimezone {
+    /// Use the configured IANA timezone, or the system one when unset or
+    /// unknown
+    pub fn from_config(name: Option<&str>) -> Self {
+        match name.map(str::trim).filter(|n| !n.is_empty()) {
+            Some(name) => match name.parse::<Tz>() {
+                Ok(tz) => DisplayTimezone::Named(tz),
+                Err(_) => {
+                    log::warn!("Unknown timezone '{}', using the system timezone", name);
+                    DisplayTimezone::Local
+                }
+            },
+            None => DisplayTimezone::Local,
+        }
+    }
+
+    /// Format a UTC time in this timezone with a strftime pattern
+    pub fn format(&self, dt: &DateTime<Utc>, pattern: &str) -> String {
+        match self {
+            DisplayTimezone::Local => dt.with_timezone(&Local).format(pattern).to_string(),
+            DisplayTimezone::Named(tz) => dt.with_timezone(tz).format(pattern).to_string(),
+        }
+    }
+
+    /// Calendar date of a UTC time in this timezone
+    pub fn date(&self, dt: &DateTime<Utc>) -> NaiveDate {
+        match self {
+            DisplayTimezone::Local => dt.with_timezone(&Local).date_naive(),
+            DisplayTimezone::Named(tz) => dt.with_timezone(tz).date_naive(),
+        }
+    }
+
+    /// Today's date in this timezone
+    pub fn today(&self) -> NaiveDate {
+        self.date(&Utc::now())
+    }
+}
+
+/// Seconds in Jira's duration notation, rounded to minutes, e.g. "1h 25m"
+pub fn format_duration(seconds: u64) -> String {
+    let minutes = (seconds + 30) / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{}m", minutes),
+        (hours, 0) => format!("{}h", hours),
+        (hours, minutes) => format!("{}h {}m", hours, minutes),
+    }
+}
+
+/// Seconds of a duration in Jira's notation, e.g. "1h 30m", "45m" or
+/// "1.5h"; a bare number counts as minutes
+pub fn parse_duration(text: &str) -> Option<u64> {
+    let mut seconds = 0.0;
+    let mut parts = text.split_whitespace().peekable();
+    parts.peek()?;
+    for part in parts {
+        let (number, unit) = match part.find(|c: char| c.is_alphabetic()) {
+            Some(i) => part.split_at(i),
+            None => (part, "m"),
+        };
+        let number: f64 = number.parse().ok().filter(|n: &f64| *n >= 0.0)?;
+        seconds += number
+            * match unit {
+                "w" => 5.0 * 8.0 * 3600.0,
+                "d" => 8.0 * 3600.0,
+                "h" => 3600.0,
+                "m" => 60.0,
+                _ => return None,
+            };
+    }
+    Some(seconds.round() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_named_timezone_conversion() {
+        let tz = DisplayTimezone::from_config(Some("Asia/Tokyo"));
+        assert_eq!(tz, DisplayTimezone::Named(chrono_tz::Asia::Tokyo));
+
+        let dt = Utc.with_ymd_and_hms(2024, 1, 15, 20, 30, 0).unwrap();
+        assert_eq!(tz.format(&dt, "%Y-%m-%d %H:%M %Z"), "2024-01-16 05:30 JST");
+        assert_eq!(tz.date(&dt), NaiveDate::from_ymd_opt(2024, 1, 16).unwrap());
+    }
+
+    #[test]
+    fn test_durations() {
+        assert_eq!(format_duration(0), "0m");
+        assert_eq!(format_duration(89), "1m");
+        assert_eq!(format_duration(7200), "2h");
+        assert_eq!(format_duration(5100), "1h 25m");
+
+        assert_eq!(parse_duration("1h 25m"), Some(5100));
+        assert_eq!(parse_duration("1.5h"), Some(5400));
+        assert_eq!(parse_duration("1d"), Some(8 * 3600));
+        assert_eq!(parse_duration("20"), Some(1200));
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("soon"), None);
+        assert_eq!(parse_duration("2x"), None);
+    }
+
+    #[test]
+    fn test_checked_date_format() {
+        assert_eq!(checked_date_format("%d/%m/%Y"), "%d/%m/%Y");
+        assert_eq!(checked_date_format("%G-W%V-%u"), "%G-W%V-%u");
+        assert_eq!(checked_date_format(""), DEFAULT_DATE_FORMAT);
+        assert_eq!(checked_date_format("%Q %Y"), DEFAULT_DATE_FORMAT);
+    }
+
+    #[test]
+    fn test_unknown_or_missing_timezone_uses_system() {
+        assert_eq!(DisplayTimezone::from_config(None), DisplayTimezone::Local);
+        assert_eq!(DisplayTimezone::from_config(Some(" ")), DisplayTimezone::Local);
+        assert_eq!(DisplayTimezone::from_config(Some("Mars/Olympus")), DisplayTimezone::Local);
+    }
+}
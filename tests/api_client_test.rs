@@ -1,8 +1,16 @@
-use lazyjira::infrastructure::api::{ApiClient, JiraApiClient};
+use lazyjira::app::cli;
+use lazyjira::domain::models::comment::{CommentOrder, CommentVisibility};
+use lazyjira::domain::models::dev_info::PullRequestStatus;
+use lazyjira::domain::models::permission::Permission;
+use lazyjira::domain::models::sprint::SprintState;
+use lazyjira::infrastructure::api::client::{FieldKind, FieldOption, LabelChange, RankPosition};
+use lazyjira::infrastructure::api::{ApiClient, JiraApiClient, JiraAuth, RateLimitProfile, RetryConfig};
 use lazyjira::infrastructure::config::{JiraCliConfig, JiraCliAuth};
 use mockito::Server;
 use serde_json::json;
 
+mod fixtures;
+
 /// Helper to create a mock Jira API client for testing
 async fn create_test_client(server: &Server) -> JiraApiClient {
     let config = JiraCliConfig {
@@ -15,69 +23,143 @@ async fn create_test_client(server: &Server) -> JiraApiClient {
     };
     
     // Use the mock server URL
-    let base_url = format!("{}/rest/api/3", server.url());
-    JiraApiClient::new(&config, Some(base_url)).unwrap()
+    JiraApiClient::builder()
+        .base_url(format!("{}/rest/api/3", server.url()))
+        .auth(JiraAuth::from_jira_cli_config(&config).unwrap())
+        .build()
+        .unwrap()
 }
 
 #[tokio::test]
 async fn test_get_issue_success() {
     let mut server = Server::new_async().await;
-    
-    let mock_response = json!({
-        "id": "10000",
-        "key": "PROJ-123",
-        "fields": {
-            "summary": "Test issue",
-            "status": {
-                "id": "3",
-                "name": "In Progress",
-                "statusCategory": {
-                    "key": "indeterminate"
-                }
-            },
-            "priority": {
-                "name": "High",
-                "id": "2"
-            },
-            "assignee": {
-                "accountId": "123456",
-                "displayName": "John Doe",
-                "emailAddress": "john@example.com"
-            },
-            "issuetype": {
-                "name": "Bug"
-            },
-            "project": {
-                "key": "PROJ"
-            },
-            "description": null,
-            "created": "2024-01-15T10:30:00.000+0000",
-            "updated": "2024-01-16T14:20:00.000+0000"
-        }
-    });
 
     let mock = server
         .mock("GET", "/rest/api/3/issue/PROJ-123")
+        .match_query(mockito::Matcher::UrlEncoded("expand".to_string(), "names,renderedFields".to_string()))
         .with_status(200)
         .with_header("content-type", "application/json")
-        .with_body(serde_json::to_string(&mock_response).unwrap())
+        .with_body(fixtures::sample_issue_response())
         .create();
 
     let client = create_test_client(&server).await;
     let ticket = client.get_issue("PROJ-123").await.unwrap();
 
     assert_eq!(ticket.key, "PROJ-123");
-    assert_eq!(ticket.summary, "Test issue");
+    assert_eq!(ticket.summary, "Fix bug in authentication");
     assert_eq!(ticket.status.name, "In Progress");
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_get_issue_details_in_one_request() {
+    let mut server = Server::new_async().await;
+    let history = |created: &str, from: &str, to: &str| {
+        json!({
+            "created": created,
+            "items": [{ "field": "status", "fromString": from, "toString": to }]
+        })
+    };
+    let issue = |total: usize| {
+        json!({
+            "id": "10000",
+            "key": "PROJ-123",
+            "fields": {
+                "summary": "Test issue",
+                "status": { "id": "3", "name": "In Progress", "statusCategory": { "key": "indeterminate" } },
+                "priority": { "name": "High" },
+                "issuetype": { "name": "Bug" },
+                "project": { "key": "PROJ" },
+                "created": "2024-01-15T10:30:00.000+0000",
+                "updated": "2024-01-16T14:20:00.000+0000"
+            },
+            "changelog": {
+                "startAt": 0,
+                "maxResults": 1,
+                "total": total,
+                "histories": [history("2024-01-16T09:00:00.000+0000", "To Do", "In Progress")]
+            },
+            "transitions": [
+                { "id": "31", "name": "Done", "to": { "name": "Done" }, "fields": {} }
+            ]
+        })
+    };
+    let expand = || {
+        mockito::Matcher::UrlEncoded(
+            "expand".to_string(),
+            "names,renderedFields,changelog,transitions.fields".to_string(),
+        )
+    };
+
+    let single = server
+        .mock("GET", "/rest/api/3/issue/PROJ-123")
+        .match_query(expand())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue(1).to_string())
+        .create();
+    let transitions = server
+        .mock("GET", "/rest/api/3/issue/PROJ-123/transitions")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create();
+    let changelog = server
+        .mock("GET", "/rest/api/3/issue/PROJ-123/changelog")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create();
+
+    let client = create_test_client(&server).await;
+    let details = client.get_issue_details("PROJ-123").await.unwrap();
+    assert_eq!(details.ticket.key, "PROJ-123");
+    assert_eq!(details.changes.len(), 1);
+    assert_eq!(details.changes[0].to, "In Progress");
+    assert_eq!(details.transitions.len(), 1);
+    assert_eq!(details.transitions[0].to_status, "Done");
+    single.assert();
+    transitions.assert();
+    changelog.assert();
+
+    // A changelog longer than what came embedded is paged separately
+    server.reset();
+    let single = server
+        .mock("GET", "/rest/api/3/issue/PROJ-123")
+        .match_query(expand())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue(2).to_string())
+        .create();
+    let changelog = server
+        .mock("GET", "/rest/api/3/issue/PROJ-123/changelog")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "isLast": true,
+                "values": [
+                    history("2024-01-15T11:00:00.000+0000", "Backlog", "To Do"),
+                    history("2024-01-16T09:00:00.000+0000", "To Do", "In Progress")
+                ]
+            })
+            .to_string(),
+        )
+        .create();
+
+    let details = client.get_issue_details("PROJ-123").await.unwrap();
+    assert_eq!(details.changes.len(), 2);
+    assert_eq!(details.changes[0].from, "Backlog");
+    single.assert();
+    changelog.assert();
+}
+
 #[tokio::test]
 async fn test_get_issue_not_found() {
     let mut server = Server::new_async().await;
 
     let mock = server
         .mock("GET", "/rest/api/3/issue/PROJ-999")
+        .match_query(mockito::Matcher::UrlEncoded("expand".to_string(), "names,renderedFields".to_string()))
         .with_status(404)
         .with_header("content-type", "application/json")
         .with_body(r#"{"errorMessages":["Issue does not exist"]}"#)
@@ -88,12 +170,14 @@ async fn test_get_issue_not_found() {
     let client = create_test_client(&server).await;
     let result = client.get_issue("PROJ-999").await;
 
-    assert!(result.is_err());
-    // Check it's an API error, not a network error (which would indicate retries)
-    assert!(matches!(
-        result.unwrap_err(),
-        lazyjira::utils::LazyJiraError::Api(_)
-    ));
+    // Check it's Jira's error, not a network error (which would indicate retries)
+    match result.unwrap_err() {
+        lazyjira::utils::LazyJiraError::Jira(error) => {
+            assert_eq!(error.status, 404);
+            assert_eq!(error.messages, vec!["Issue does not exist"]);
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
     mock.assert();
 }
 
@@ -103,6 +187,7 @@ async fn test_get_issue_unauthorized() {
 
     let mock = server
         .mock("GET", "/rest/api/3/issue/PROJ-123")
+        .match_query(mockito::Matcher::UrlEncoded("expand".to_string(), "names,renderedFields".to_string()))
         .with_status(401)
         .with_header("content-type", "application/json")
         .with_body(r#"{"errorMessages":["Unauthorized"]}"#)
@@ -173,7 +258,7 @@ async fn test_search_issues_success() {
     });
 
     let mock = server
-        .mock("GET", "/rest/api/3/search")
+        .mock("GET", "/rest/api/3/search/jql")
         .match_query(mockito::Matcher::AllOf(vec![
             mockito::Matcher::UrlEncoded("jql".to_string(), "assignee = currentUser()".to_string()),
             mockito::Matcher::UrlEncoded("startAt".to_string(), "0".to_string()),
@@ -201,22 +286,15 @@ async fn test_search_issues_success() {
 async fn test_search_issues_empty() {
     let mut server = Server::new_async().await;
 
-    let mock_response = json!({
-        "startAt": 0,
-        "maxResults": 50,
-        "total": 0,
-        "issues": []
-    });
-
     let mock = server
-        .mock("GET", "/rest/api/3/search")
+        .mock("GET", "/rest/api/3/search/jql")
         .match_query(mockito::Matcher::AnyOf(vec![
             mockito::Matcher::UrlEncoded("jql".to_string(), "project = PROJ".to_string()),
             mockito::Matcher::UrlEncoded("jql".to_string(), "project%20%3D%20PROJ".to_string()),
         ]))
         .with_status(200)
         .with_header("content-type", "application/json")
-        .with_body(serde_json::to_string(&mock_response).unwrap())
+        .with_body(fixtures::sample_empty_search_response())
         .create();
 
     let client = create_test_client(&server).await;
@@ -226,3 +304,1035 @@ async fn test_search_issues_empty() {
     assert_eq!(result.issues.len(), 0);
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_get_sprints_uses_agile_api() {
+    let mut server = Server::new_async().await;
+
+    let mock_response = json!({
+        "maxResults": 50,
+        "startAt": 0,
+        "isLast": true,
+        "values": [
+            {
+                "id": 7,
+                "state": "active",
+                "name": "Sprint 7",
+                "startDate": "2024-01-15T10:30:00.000Z",
+                "endDate": "2024-01-29T10:30:00.000Z",
+                "goal": "Ship it"
+            }
+        ]
+    });
+
+    let mock = server
+        .mock("GET", "/rest/agile/1.0/board/3/sprint")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&mock_response).unwrap())
+        .create();
+
+    let client = create_test_client(&server).await;
+    let sprints = client
+        .get_sprints("3", &[SprintState::Active, SprintState::Future])
+        .await
+        .unwrap();
+
+    assert_eq!(sprints.len(), 1);
+    assert_eq!(sprints[0].id, "7");
+    assert!(sprints[0].is_active());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_get_boards_follows_pages() {
+    let mut server = Server::new_async().await;
+
+    let first_page = json!({
+        "maxResults": 1,
+        "startAt": 0,
+        "isLast": false,
+        "values": [{ "id": 1, "name": "PROJ board", "type": "scrum" }]
+    });
+    let last_page = json!({
+        "maxResults": 1,
+        "startAt": 1,
+        "isLast": true,
+        "values": [{ "id": 2, "name": "OPS board", "type": "kanban" }]
+    });
+
+    let first = server
+        .mock("GET", "/rest/agile/1.0/board")
+        .match_query(mockito::Matcher::UrlEncoded("startAt".to_string(), "0".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&first_page).unwrap())
+        .create();
+    let last = server
+        .mock("GET", "/rest/agile/1.0/board")
+        .match_query(mockito::Matcher::UrlEncoded("startAt".to_string(), "1".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&last_page).unwrap())
+        .create();
+
+    let client = create_test_client(&server).await;
+    let boards = client.get_boards().await.unwrap();
+
+    let ids: Vec<&str> = boards.iter().map(|board| board.id.as_str()).collect();
+    assert_eq!(ids, vec!["1", "2"]);
+    first.assert();
+    last.assert();
+}
+
+#[tokio::test]
+async fn test_get_backlog_follows_pages() {
+    let mut server = Server::new_async().await;
+
+    let issue = |id: &str, key: &str| {
+        json!({
+            "id": id,
+            "key": key,
+            "fields": {
+                "summary": format!("Backlog item {}", key),
+                "status": {
+                    "id": "1",
+                    "name": "To Do",
+                    "statusCategory": { "key": "new" }
+                },
+                "issuetype": { "name": "Task" },
+                "project": { "key": "PROJ" },
+                "created": "2024-01-14T09:00:00.000+0000",
+                "updated": "2024-01-14T09:00:00.000+0000"
+            }
+        })
+    };
+    let first_page = json!({
+        "startAt": 0,
+        "maxResults": 2,
+        "total": 3,
+        "issues": [issue("10001", "PROJ-1"), issue("10002", "PROJ-2")]
+    });
+    let last_page = json!({
+        "startAt": 2,
+        "maxResults": 2,
+        "total": 3,
+        "issues": [issue("10003", "PROJ-3")]
+    });
+
+    let first = server
+        .mock("GET", "/rest/agile/1.0/board/3/backlog")
+        .match_query(mockito::Matcher::UrlEncoded("startAt".to_string(), "0".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&first_page).unwrap())
+        .create();
+    let last = server
+        .mock("GET", "/rest/agile/1.0/board/3/backlog")
+        .match_query(mockito::Matcher::UrlEncoded("startAt".to_string(), "2".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&last_page).unwrap())
+        .create();
+
+    let client = create_test_client(&server).await;
+    let backlog = client.get_backlog("3").await.unwrap();
+
+    let keys: Vec<&str> = backlog.iter().map(|ticket| ticket.key.as_str()).collect();
+    assert_eq!(keys, vec!["PROJ-1", "PROJ-2", "PROJ-3"]);
+    first.assert();
+    last.assert();
+}
+
+#[tokio::test]
+async fn test_complete_sprint_accepts_empty_response() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/rest/agile/1.0/sprint/7")
+        .match_body(mockito::Matcher::PartialJson(json!({ "state": "closed" })))
+        .with_status(204)
+        .create();
+
+    let client = create_test_client(&server).await;
+    client.complete_sprint("7").await.unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_rank_issue_before() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("PUT", "/rest/agile/1.0/issue/rank")
+        .match_body(mockito::Matcher::Json(json!({
+            "issues": ["PROJ-2"],
+            "rankBeforeIssue": "PROJ-1"
+        })))
+        .with_status(204)
+        .create();
+
+    let client = create_test_client(&server).await;
+    client
+        .rank_issue("PROJ-2", RankPosition::Before("PROJ-1".to_string()))
+        .await
+        .unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_release_version_puts_release_date() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("PUT", "/rest/api/3/version/10002")
+        .match_body(mockito::Matcher::Json(json!({
+            "released": true,
+            "releaseDate": "2024-04-30"
+        })))
+        .with_status(200)
+        .with_body(json!({ "id": "10002", "name": "2.0", "released": true }).to_string())
+        .create();
+
+    let client = create_test_client(&server).await;
+    client
+        .release_version("10002", chrono::NaiveDate::from_ymd_opt(2024, 4, 30).unwrap())
+        .await
+        .unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_add_worklog_posts_time_and_comment() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/rest/api/3/issue/PROJ-1/worklog")
+        .match_body(mockito::Matcher::Json(json!({
+            "started": "2024-01-15T09:00:00.000+0000",
+            "timeSpentSeconds": 5400,
+            "comment": {
+                "type": "doc",
+                "version": 1,
+                "content": [{
+                    "type": "paragraph",
+                    "content": [{ "type": "text", "text": "Pairing on the fix" }]
+                }]
+            }
+        })))
+        .with_status(201)
+        .with_body(json!({ "id": "100" }).to_string())
+        .create();
+
+    let client = create_test_client(&server).await;
+    let started = chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2024, 1, 15, 9, 0, 0).unwrap();
+    client
+        .add_worklog("PROJ-1", started, 5400, Some("Pairing on the fix"))
+        .await
+        .unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_assign_issue_puts_account_id() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("PUT", "/rest/api/3/issue/PROJ-1/assignee")
+        .match_body(mockito::Matcher::Json(json!({ "accountId": "5b10a" })))
+        .with_status(204)
+        .create();
+
+    let client = create_test_client(&server).await;
+    client.assign_issue("PROJ-1", "5b10a").await.unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_count_issues_uses_approximate_count() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/rest/api/3/search/approximate-count")
+        .match_body(mockito::Matcher::Json(json!({ "jql": "status = Review" })))
+        .with_status(200)
+        .with_body(r#"{"count": 7}"#)
+        .create();
+
+    let client = create_test_client(&server).await;
+    assert_eq!(client.count_issues("status = Review").await.unwrap(), 7);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_list_counts_tickets_beyond_the_first_page() {
+    let mut server = Server::new_async().await;
+    let issue = |n: usize| {
+        json!({
+            "id": format!("{}", 10000 + n),
+            "key": format!("PROJ-{}", n),
+            "fields": {
+                "summary": format!("Issue {}", n),
+                "status": { "id": "1", "name": "To Do", "statusCategory": { "key": "new" } },
+                "priority": { "name": "Medium" },
+                "issuetype": { "name": "Task" },
+                "project": { "key": "PROJ" },
+                "created": "2024-01-14T09:00:00.000+0000",
+                "updated": "2024-01-14T09:00:00.000+0000"
+            }
+        })
+    };
+    // The search/jql endpoint pages with a token and gives no total
+    let page = json!({
+        "issues": (1..=cli::LIST_LIMIT).map(issue).collect::<Vec<_>>(),
+        "nextPageToken": "next",
+        "isLast": false
+    });
+    let search = server
+        .mock("GET", "/rest/api/3/search/jql")
+        .match_query(mockito::Matcher::UrlEncoded("jql".to_string(), "project = PROJ".to_string()))
+        .with_status(200)
+        .with_body(page.to_string())
+        .create();
+    let count = server
+        .mock("POST", "/rest/api/3/search/approximate-count")
+        .match_body(mockito::Matcher::Json(json!({ "jql": "project = PROJ" })))
+        .with_status(200)
+        .with_body(r#"{"count": 250}"#)
+        .create();
+
+    let client = create_test_client(&server).await;
+    let listing = cli::list(&client, "project = PROJ").await.unwrap();
+    assert_eq!(listing.lines.len(), cli::LIST_LIMIT);
+    assert_eq!(listing.lines[0], "PROJ-1\tTo Do\tIssue 1");
+    assert_eq!(
+        listing.truncated_notice().as_deref(),
+        Some("showing 100 of 250 tickets; narrow the query to see the rest")
+    );
+    search.assert();
+    count.assert();
+}
+
+#[tokio::test]
+async fn test_move_issue_polls_task_and_returns_new_key() {
+    let mut server = Server::new_async().await;
+
+    let submit = server
+        .mock("POST", "/rest/api/3/bulk/issues/move")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "targetToSourcesMapping": {
+                "NEW,10002": { "issueIdsOrKeys": ["OLD-1"] }
+            }
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{ "taskId": "10641" }"#)
+        .create();
+    let poll = server
+        .mock("GET", "/rest/api/3/bulk/queue/10641")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{ "status": "COMPLETE", "progressPercent": 100, "failedAccessibleIssues": {} }"#)
+        .create();
+    let moved = json!({
+        "id": "10000",
+        "key": "NEW-42",
+        "fields": {
+            "summary": "Misfiled",
+            "status": { "id": "1", "name": "To Do", "statusCategory": { "key": "new" } },
+            "priority": { "name": "Medium", "id": "3" },
+            "assignee": null,
+            "issuetype": { "name": "Bug" },
+            "project": { "key": "NEW" },
+            "description": null,
+            "created": "2024-01-15T10:30:00.000+0000",
+            "updated": "2024-01-16T14:20:00.000+0000"
+        }
+    });
+    let fetch = server
+        .mock("GET", "/rest/api/3/issue/OLD-1")
+        .match_query(mockito::Matcher::UrlEncoded("expand".to_string(), "names,renderedFields".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&moved).unwrap())
+        .create();
+
+    let client = create_test_client(&server).await;
+    let ticket = client.move_issue("OLD-1", "NEW", "10002").await.unwrap();
+
+    assert_eq!(ticket.key, "NEW-42");
+    submit.assert();
+    poll.assert();
+    fetch.assert();
+}
+
+#[tokio::test]
+async fn test_change_label_sends_update_operation() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("PUT", "/rest/api/3/issue/PROJ-1")
+        .match_body(mockito::Matcher::Json(json!({
+            "update": { "labels": [{ "remove": "stale" }] }
+        })))
+        .with_status(204)
+        .create();
+
+    let client = create_test_client(&server).await;
+    client
+        .change_label("PROJ-1", &LabelChange::Remove("stale".to_string()))
+        .await
+        .unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_remote_links_are_listed_and_added() {
+    let mut server = Server::new_async().await;
+
+    let list = server
+        .mock("GET", "/rest/api/3/issue/PROJ-1/remotelink")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {
+                    "id": 10000,
+                    "application": { "name": "Confluence" },
+                    "object": { "url": "https://example.atlassian.net/wiki/x/AbC", "title": "Design" }
+                },
+                { "id": 10001, "object": { "url": "https://grafana.example.com/d/api" } },
+                { "id": 10002, "object": { "title": "No URL" } }
+            ])
+            .to_string(),
+        )
+        .create();
+    let add = server
+        .mock("POST", "/rest/api/3/issue/PROJ-1/remotelink")
+        .match_body(mockito::Matcher::Json(json!({
+            "object": { "url": "https://example.com/runbook", "title": "Runbook" }
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "id": 10003, "self": "https://example.com" }).to_string())
+        .create();
+
+    let client = create_test_client(&server).await;
+    let links = client.get_remote_links("PROJ-1").await.unwrap();
+    assert_eq!(links.len(), 2);
+    assert_eq!(links[0].id, "10000");
+    assert_eq!(links[0].title, "Design");
+    assert_eq!(links[0].application.as_deref(), Some("Confluence"));
+    assert_eq!(links[1].title, "https://grafana.example.com/d/api");
+    assert_eq!(links[1].application, None);
+
+    client
+        .add_remote_link("PROJ-1", "Runbook", "https://example.com/runbook")
+        .await
+        .unwrap();
+    list.assert();
+    add.assert();
+}
+
+#[tokio::test]
+async fn test_confluence_page_title_only_on_same_site() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/wiki/rest/api/content/12345")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "id": "12345", "type": "page", "title": "Payments design" }).to_string())
+        .create();
+
+    let client = create_test_client(&server).await;
+    let page = format!("{}/wiki/spaces/ENG/pages/12345/Design", server.url());
+    assert_eq!(
+        client.get_confluence_page_title(&page).await.unwrap().as_deref(),
+        Some("Payments design")
+    );
+    let elsewhere = "https://other.atlassian.net/wiki/spaces/ENG/pages/12345/Design";
+    assert_eq!(client.get_confluence_page_title(elsewhere).await.unwrap(), None);
+    let short = format!("{}/wiki/x/AbC", server.url());
+    assert_eq!(client.get_confluence_page_title(&short).await.unwrap(), None);
+    mock.expect(1).assert();
+}
+
+#[tokio::test]
+async fn test_dev_info_from_connected_tools() {
+    let mut server = Server::new_async().await;
+
+    let summary = server
+        .mock("GET", "/rest/dev-status/latest/issue/summary")
+        .match_query(mockito::Matcher::UrlEncoded("issueId".to_string(), "10001".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "summary": {
+                    "pullrequest": { "byInstanceType": { "GitHub": { "count": 1, "name": "GitHub" } } },
+                    "branch": { "byInstanceType": { "GitHub": { "count": 1 }, "bitbucket": { "count": 0 } } }
+                }
+            })
+            .to_string(),
+        )
+        .create();
+    let detail = |data_type: &str| {
+        mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("applicationType".to_string(), "GitHub".to_string()),
+            mockito::Matcher::UrlEncoded("dataType".to_string(), data_type.to_string()),
+        ])
+    };
+    let branches = server
+        .mock("GET", "/rest/dev-status/1.0/issue/detail")
+        .match_query(detail("branch"))
+        .with_status(200)
+        .with_body(
+            json!({ "detail": [{ "branches": [{
+                "name": "feature/PROJ-1-login",
+                "url": "https://github.com/acme/api/tree/feature/PROJ-1-login",
+                "repository": { "name": "acme/api" }
+            }] }] })
+            .to_string(),
+        )
+        .create();
+    let commits = server
+        .mock("GET", "/rest/dev-status/1.0/issue/detail")
+        .match_query(detail("repository"))
+        .with_status(200)
+        .with_body(
+            json!({ "detail": [{ "repositories": [{ "name": "acme/api", "commits": [{
+                "id": "abcdef1234567890",
+                "displayId": "abcdef1",
+                "message": "PROJ-1 Fix login\n\nLonger description",
+                "author": { "name": "Jane" },
+                "url": "https://github.com/acme/api/commit/abcdef1"
+            }] }] }] })
+            .to_string(),
+        )
+        .create();
+    let pull_requests = server
+        .mock("GET", "/rest/dev-status/1.0/issue/detail")
+        .match_query(detail("pullrequest"))
+        .with_status(200)
+        .with_body(
+            json!({ "detail": [{ "pullRequests": [{
+                "id": "#42",
+                "name": "Fix login",
+                "status": "MERGED",
+                "repositoryName": "acme/api",
+                "url": "https://github.com/acme/api/pull/42"
+            }] }] })
+            .to_string(),
+        )
+        .create();
+
+    let client = create_test_client(&server).await;
+    let info = client.get_dev_info("10001").await.unwrap();
+    assert_eq!(info.branches[0].name, "feature/PROJ-1-login");
+    assert_eq!(info.branches[0].repository, "acme/api");
+    assert_eq!(info.commits[0].id, "abcdef1");
+    assert_eq!(info.commits[0].message, "PROJ-1 Fix login");
+    assert_eq!(info.commits[0].author, "Jane");
+    assert_eq!(info.pull_requests[0].id, "#42");
+    assert_eq!(info.pull_requests[0].status, PullRequestStatus::Merged);
+    summary.assert();
+    branches.assert();
+    commits.assert();
+    pull_requests.assert();
+}
+
+#[tokio::test]
+async fn test_slas_of_service_desk_request() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/rest/servicedeskapi/request/HELP-7/sla")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "values": [
+                {
+                    "name": "Time to first response",
+                    "completedCycles": [{
+                        "breached": false,
+                        "goalDuration": { "millis": 14400000 },
+                        "remainingTime": { "millis": 3600000 }
+                    }]
+                },
+                {
+                    "name": "Time to resolution",
+                    "completedCycles": [],
+                    "ongoingCycle": {
+                        "breachTime": { "epochMillis": 1718000000000i64 },
+                        "breached": false,
+                        "paused": false,
+                        "goalDuration": { "millis": 28800000 },
+                        "remainingTime": { "millis": 7200000 }
+                    }
+                },
+                { "name": "Time to approve", "completedCycles": [] }
+            ] })
+            .to_string(),
+        )
+        .create();
+
+    let client = create_test_client(&server).await;
+    let slas = client.get_slas("HELP-7").await.unwrap();
+    assert_eq!(slas.len(), 2);
+    assert!(slas[0].completed);
+    assert_eq!(slas[0].remaining, Some(chrono::Duration::hours(1)));
+    assert_eq!(slas[1].name, "Time to resolution");
+    assert!(!slas[1].completed);
+    assert_eq!(slas[1].goal, Some(chrono::Duration::hours(8)));
+    assert_eq!(slas[1].breach_time.unwrap().timestamp_millis(), 1718000000000);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_approvals_are_listed_and_answered() {
+    let mut server = Server::new_async().await;
+    let list = server
+        .mock("GET", "/rest/servicedeskapi/request/HELP-7/approval")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "values": [{
+                "id": "3",
+                "name": "Manager sign-off",
+                "finalDecision": "pending",
+                "canAnswerApprovalDecision": true,
+                "approvers": [
+                    { "approver": { "accountId": "a1", "displayName": "Jane" }, "approverDecision": "approved" },
+                    { "approver": { "accountId": "a2", "displayName": "Alex" }, "approverDecision": "pending" }
+                ]
+            }] })
+            .to_string(),
+        )
+        .create();
+    let answer = server
+        .mock("POST", "/rest/servicedeskapi/request/HELP-7/approval/3")
+        .match_body(mockito::Matcher::Json(json!({ "decision": "decline" })))
+        .with_status(200)
+        .with_body("{}")
+        .create();
+
+    let client = create_test_client(&server).await;
+    let approvals = client.get_approvals("HELP-7").await.unwrap();
+    assert_eq!(approvals.len(), 1);
+    assert!(approvals[0].is_answerable());
+    assert_eq!(approvals[0].approvers_summary(), "Jane (approved), Alex");
+    client.answer_approval("HELP-7", "3", false).await.unwrap();
+    list.assert();
+    answer.assert();
+}
+
+#[tokio::test]
+async fn test_get_transitions_reads_screen_fields() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/rest/api/3/issue/PROJ-1/transitions")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "expand".to_string(),
+            "transitions.fields".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "transitions": [
+                    { "id": "11", "name": "Start", "to": { "name": "In Progress" }, "fields": {} },
+                    {
+                        "id": "31",
+                        "name": "Resolve",
+                        "to": { "name": "Resolved" },
+                        "fields": {
+                            "resolution": {
+                                "required": true,
+                                "name": "Resolution",
+                                "schema": { "type": "resolution" },
+                                "allowedValues": [
+                                    { "id": "10000", "name": "Done" },
+                                    { "id": "10001", "name": "Won't Do" }
+                                ]
+                            },
+                            "fixVersions": {
+                                "required": false,
+                                "name": "Fix versions",
+                                "schema": { "type": "array", "items": "version" },
+                                "allowedValues": [{ "id": "100", "name": "1.0" }]
+                            },
+                            "assignee": {
+                                "required": false,
+                                "name": "Assignee",
+                                "schema": { "type": "user" }
+                            }
+                        }
+                    }
+                ]
+            })
+            .to_string(),
+        )
+        .create();
+
+    let client = create_test_client(&server).await;
+    let transitions = client.get_transitions("PROJ-1").await.unwrap();
+
+    assert!(transitions[0].fields.is_empty());
+    let fields = &transitions[1].fields;
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].key, "resolution");
+    assert!(fields[0].required);
+    assert_eq!(
+        fields[1].kind,
+        FieldKind::Select {
+            options: vec![FieldOption {
+                id: "100".to_string(),
+                name: "1.0".to_string()
+            }],
+            multiple: true
+        }
+    );
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_transition_issue_sends_fields_and_comment() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/rest/api/3/issue/PROJ-1/transitions")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "transition": { "id": "31" },
+            "fields": { "resolution": { "id": "10000" } }
+        })))
+        .with_status(204)
+        .create();
+
+    let client = create_test_client(&server).await;
+    client
+        .transition_issue(
+            "PROJ-1",
+            "31",
+            Some("Fixed".to_string()),
+            Some(json!({ "resolution": { "id": "10000" } })),
+        )
+        .await
+        .unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_add_comment_restricted_to_role() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/rest/api/3/issue/PROJ-1/comment")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "visibility": { "type": "role", "value": "Developers" }
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "id": "10000" }).to_string())
+        .create();
+
+    let client = create_test_client(&server).await;
+    client
+        .add_comment(
+            "PROJ-1",
+            "Root cause found".to_string(),
+            &CommentVisibility::Role("Developers".to_string()),
+        )
+        .await
+        .unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_get_comments_newest_first() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/rest/api/3/issue/PROJ-1/comment")
+        .match_query(mockito::Matcher::UrlEncoded("orderBy".to_string(), "-created".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "comments": [] }).to_string())
+        .create();
+
+    let client = create_test_client(&server).await;
+    let comments = client.get_comments("PROJ-1", CommentOrder::NewestFirst).await.unwrap();
+    assert!(comments.is_empty());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_get_my_permissions() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/rest/api/3/mypermissions")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("projectKey".to_string(), "PROJ".to_string()),
+            mockito::Matcher::Regex("permissions=ASSIGN_ISSUES,TRANSITION_ISSUES".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "permissions": {
+                    "ASSIGN_ISSUES": { "havePermission": false },
+                    "TRANSITION_ISSUES": { "havePermission": true }
+                }
+            })
+            .to_string(),
+        )
+        .create();
+
+    let client = create_test_client(&server).await;
+    let permissions = client.get_my_permissions("PROJ").await.unwrap();
+    assert!(!permissions.allows(Permission::AssignIssues));
+    assert!(permissions.allows(Permission::TransitionIssues));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_get_edit_fields() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/rest/api/3/issue/PROJ-1/editmeta")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "fields": {
+                    "summary": { "required": true, "name": "Summary", "schema": { "type": "string", "system": "summary" } },
+                    "description": { "required": false, "name": "Description", "schema": { "type": "string", "system": "description" } },
+                    "duedate": { "required": false, "name": "Due date", "schema": { "type": "date", "system": "duedate" } },
+                    "assignee": { "required": false, "name": "Assignee", "schema": { "type": "user", "system": "assignee" } },
+                    "comment": { "required": false, "name": "Comment", "schema": { "type": "comments-page", "system": "comment" } },
+                    "labels": { "required": false, "name": "Labels", "schema": { "type": "array", "items": "string" } }
+                }
+            })
+            .to_string(),
+        )
+        .create();
+
+    let client = create_test_client(&server).await;
+    let fields = client.get_edit_fields("PROJ-1").await.unwrap();
+    let kinds: Vec<(&str, &FieldKind)> = fields.iter().map(|f| (f.key.as_str(), &f.kind)).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ("summary", &FieldKind::Text),
+            ("assignee", &FieldKind::User),
+            ("duedate", &FieldKind::Date),
+        ]
+    );
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_get_issue_properties() {
+    let mut server = Server::new_async().await;
+
+    let keys = server
+        .mock("GET", "/rest/api/3/issue/PROJ-1/properties")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "keys": [
+                    { "self": format!("{}/rest/api/3/issue/PROJ-1/properties/automation", server.url()), "key": "automation" }
+                ]
+            })
+            .to_string(),
+        )
+        .create();
+    let value = server
+        .mock("GET", "/rest/api/3/issue/PROJ-1/properties/automation")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "key": "automation", "value": { "rule": "Auto-assign" } }).to_string())
+        .create();
+
+    let client = create_test_client(&server).await;
+    let properties = client.get_issue_properties("PROJ-1").await.unwrap();
+    assert_eq!(properties.len(), 1);
+    assert_eq!(properties[0].key, "automation");
+    assert_eq!(properties[0].value, json!({ "rule": "Auto-assign" }));
+    keys.assert();
+    value.assert();
+}
+
+#[tokio::test]
+async fn test_metadata_is_cached_between_clients() {
+    let mut server = Server::new_async().await;
+    let dir = tempfile::TempDir::new().unwrap();
+    let cache = dir.path().join("metadata_cache.json");
+
+    let fields = server
+        .mock("GET", "/rest/api/3/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                { "id": "summary", "name": "Summary" },
+                { "id": "customfield_10016", "name": "Story Points" }
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let create_meta = server
+        .mock("GET", "/rest/api/3/issue/createmeta/PROJ/issuetypes/10001")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "fields": [
+                    { "fieldId": "summary", "required": true, "name": "Summary", "schema": { "type": "string", "system": "summary" } },
+                    { "fieldId": "duedate", "required": false, "name": "Due date", "schema": { "type": "date", "system": "duedate" } }
+                ]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let edit_meta = server
+        .mock("GET", "/rest/api/3/issue/PROJ-1/editmeta")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "fields": {} }).to_string())
+        .expect(2)
+        .create();
+    server
+        .mock("POST", "/rest/api/3/issue/PROJ-1/transitions")
+        .with_status(204)
+        .create();
+
+    let client = || async {
+        JiraApiClient::builder()
+            .base_url(format!("{}/rest/api/3", server.url()))
+            .metadata_cache(&cache)
+            .build()
+            .unwrap()
+    };
+    let first = client().await;
+    let names = first.get_field_names().await.unwrap();
+    assert_eq!(names["customfield_10016"], "Story Points");
+    let create = first.get_create_fields("PROJ", "10001").await.unwrap();
+    assert_eq!(create[0].key, "summary");
+    assert_eq!(create[1].kind, FieldKind::Date);
+    first.get_edit_fields("PROJ-1").await.unwrap();
+
+    // A later run reads the file instead of asking again
+    let second = client().await;
+    assert_eq!(second.get_field_names().await.unwrap(), names);
+    assert_eq!(second.get_create_fields("PROJ", "10001").await.unwrap(), create);
+    second.get_edit_fields("PROJ-1").await.unwrap();
+
+    // A transition may change the edit screen
+    second.transition_issue("PROJ-1", "31", None, None).await.unwrap();
+    second.get_edit_fields("PROJ-1").await.unwrap();
+
+    fields.assert();
+    create_meta.assert();
+    edit_meta.assert();
+}
+
+#[tokio::test]
+async fn test_requests_are_recorded_for_debug_view() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("PUT", "/rest/api/3/issue/PROJ-1/assignee")
+        .with_status(204)
+        .create();
+
+    let client = create_test_client(&server).await;
+    client.assign_issue("PROJ-1", "5b10a").await.unwrap();
+    mock.assert();
+
+    let records = client.recent_requests();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].method, "PUT");
+    assert_eq!(records[0].endpoint, "/rest/api/3/issue/PROJ-1/assignee");
+    assert_eq!(records[0].status, Some(204));
+    assert_eq!(records[0].retries, 0);
+}
+
+#[tokio::test]
+async fn test_builder_applies_auth_and_middleware() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/rest/api/3/issue/PROJ-1/comment")
+        .match_header("authorization", "Bearer pat-123")
+        .match_header("x-team", "platform")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_body(json!({"comments": []}).to_string())
+        .create_async()
+        .await;
+
+    let client = JiraApiClient::builder()
+        .base_url(format!("{}/rest/api/3/", server.url()))
+        .auth(JiraAuth::Bearer("pat-123".to_string()))
+        .rate_limit(RateLimitProfile::Unlimited)
+        .retry(RetryConfig::none())
+        .middleware(|request: reqwest::RequestBuilder| request.header("X-Team", "platform"))
+        .build()
+        .unwrap();
+    assert!(client.get_comments("PROJ-1", CommentOrder::OldestFirst).await.unwrap().is_empty());
+    mock.assert_async().await;
+
+    assert!(JiraApiClient::builder().build().is_err());
+}
+
+#[test]
+fn test_builder_rejects_empty_custom_rate_limit() {
+    use lazyjira::utils::LazyJiraError;
+    use std::time::Duration;
+
+    let build = |requests, per| {
+        JiraApiClient::builder()
+            .instance("acme.atlassian.net")
+            .rate_limit(RateLimitProfile::Custom { requests, per })
+            .build()
+    };
+    assert!(matches!(build(0, Duration::from_secs(60)), Err(LazyJiraError::Config(_))));
+    assert!(matches!(build(100, Duration::ZERO), Err(LazyJiraError::Config(_))));
+    assert!(build(100, Duration::from_secs(60)).is_ok());
+}
+
+#[tokio::test]
+async fn test_field_errors_parsed_from_rejected_transition() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/rest/api/3/issue/PROJ-1/transitions")
+        .with_status(400)
+        .with_body(
+            json!({
+                "errorMessages": [],
+                "errors": {"resolution": "Resolution is required."}
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = create_test_client(&server).await;
+    let error = client.transition_issue("PROJ-1", "31", None, None).await.unwrap_err();
+    match error {
+        lazyjira::utils::LazyJiraError::Jira(error) => {
+            assert_eq!(error.status, 400);
+            assert!(error.messages.is_empty());
+            assert_eq!(error.field_errors["resolution"], "Resolution is required.");
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
+    mock.assert_async().await;
+}
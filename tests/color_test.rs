@@ -0,0 +1,40 @@
+//! `--color=never` is process-wide, so it gets its own test binary
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazyjira::infrastructure::api::demo_client::DemoApiClient;
+use lazyjira::infrastructure::config::Config;
+use lazyjira::ui::App;
+use lazyjira::utils::color::{self, ColorChoice};
+use ratatui::backend::TestBackend;
+use ratatui::style::Color;
+use ratatui::Terminal;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_color_never_paints_no_color() {
+    color::set_choice(ColorChoice::Never);
+    let mut config = Config::default();
+    config.ui.show_avatars = true;
+    let terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+    let mut app = App::with_terminal(
+        terminal,
+        "Demo".to_string(),
+        Arc::new(DemoApiClient::new().unwrap()),
+        "demo.atlassian.net".to_string(),
+        config,
+    );
+    app.load().await;
+
+    // The first dashboard tile's list, then a ticket with its reporter's avatar
+    for _ in 0..2 {
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)).await;
+        for _ in 0..3 {
+            tokio::task::yield_now().await;
+            app.poll();
+        }
+        app.draw().unwrap();
+        for cell in &app.backend().buffer().content {
+            assert_eq!((cell.fg, cell.bg), (Color::Reset, Color::Reset), "{:?}", cell);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use lazyjira::app::doctor::{check_connection, CheckStatus};
+use lazyjira::infrastructure::api::{JiraApiClient, JiraAuth};
+use mockito::Server;
+use serde_json::json;
+
+fn create_test_client(server: &Server) -> JiraApiClient {
+    JiraApiClient::builder()
+        .base_url(format!("{}/rest/api/3", server.url()))
+        .auth(JiraAuth::basic("test@example.com", "test-token"))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_doctor_reports_version_user_and_low_headroom() {
+    let mut server = Server::new_async().await;
+    let _server_info = server
+        .mock("GET", "/rest/api/3/serverInfo")
+        .with_status(200)
+        .with_body(json!({"version": "1001.0.0", "deploymentType": "Cloud"}).to_string())
+        .create_async()
+        .await;
+    let _myself = server
+        .mock("GET", "/rest/api/3/myself")
+        .with_status(200)
+        .with_header("X-RateLimit-Remaining", "5")
+        .with_header("X-RateLimit-Limit", "100")
+        .with_body(json!({"displayName": "Jane Doe"}).to_string())
+        .create_async()
+        .await;
+
+    let checks = check_connection(&create_test_client(&server)).await;
+    let statuses: Vec<CheckStatus> = checks.iter().map(|check| check.status).collect();
+    assert_eq!(
+        statuses,
+        vec![CheckStatus::Pass, CheckStatus::Pass, CheckStatus::Pass, CheckStatus::Warn]
+    );
+    assert_eq!(checks[1].detail, "signed in as Jane Doe");
+    assert_eq!(checks[2].detail, "Jira Cloud 1001.0.0, REST API v3");
+    assert_eq!(checks[3].detail, "5 of 100 requests left");
+}
+
+#[tokio::test]
+async fn test_doctor_fails_on_rejected_credentials() {
+    let mut server = Server::new_async().await;
+    let _server_info = server
+        .mock("GET", "/rest/api/3/serverInfo")
+        .with_status(401)
+        .create_async()
+        .await;
+    let _myself = server
+        .mock("GET", "/rest/api/3/myself")
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let checks = check_connection(&create_test_client(&server)).await;
+    assert_eq!(checks[1].name, "credentials");
+    assert_eq!(checks[1].status, CheckStatus::Fail);
+    assert!(checks[1].hint.as_deref().unwrap().contains("API token"));
+}
@@ -0,0 +1,1013 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use lazyjira::domain::models::dev_info::{Branch, DevInfo, PullRequest, PullRequestStatus};
+use lazyjira::domain::models::permission::{Permission, ProjectPermissions};
+use lazyjira::infrastructure::api::demo_client::DemoApiClient;
+use lazyjira::infrastructure::config::{Config, FilterConfig};
+use lazyjira::infrastructure::storage::recent_tickets::RecentTickets;
+use lazyjira::infrastructure::storage::view_state::{SessionState, ViewState};
+use lazyjira::infrastructure::api::client::{SearchResult, UpdateIssueData};
+use lazyjira::infrastructure::api::ApiClient;
+use lazyjira::ui::message::{Action, ListChanges, Message, Write};
+use lazyjira::ui::App;
+use lazyjira::utils::{JiraApiError, LazyJiraError};
+use ratatui::backend::TestBackend;
+use ratatui::style::Color;
+use ratatui::Terminal;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// App on an in-memory terminal, backed by the demo data
+async fn create_test_app() -> App<TestBackend> {
+    create_test_app_with_config(Config::default()).await
+}
+
+async fn create_test_app_with_config(config: Config) -> App<TestBackend> {
+    let terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+    let mut app = App::with_terminal(
+        terminal,
+        "Demo".to_string(),
+        Arc::new(DemoApiClient::new().unwrap()),
+        "demo.atlassian.net".to_string(),
+        config,
+    );
+    app.load().await;
+    settle(&mut app).await;
+    app
+}
+
+/// Let the background actions started so far, and those their messages
+/// lead to, report back
+async fn settle(app: &mut App<TestBackend>) {
+    for _ in 0..3 {
+        tokio::task::yield_now().await;
+        app.poll();
+    }
+}
+
+/// Press a key and redraw once the background work it started has
+/// finished; capitals come with SHIFT, as terminals send them
+async fn press(app: &mut App<TestBackend>, code: KeyCode) {
+    app.handle_key(KeyEvent::new(code, modifiers(code))).await;
+    settle(app).await;
+    app.draw().unwrap();
+}
+
+fn modifiers(code: KeyCode) -> KeyModifiers {
+    match code {
+        KeyCode::Char(c) if c.is_ascii_uppercase() => KeyModifiers::SHIFT,
+        _ => KeyModifiers::NONE,
+    }
+}
+
+/// Rendered buffer as text, one line per row
+fn screen(app: &App<TestBackend>) -> String {
+    let buffer = app.backend().buffer();
+    let width = buffer.area.width as usize;
+    buffer
+        .content
+        .chunks(width)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[tokio::test]
+async fn test_open_ticket_from_list_and_go_back() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+
+    // The first dashboard tile opens the filtered ticket list
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+    let detail = screen(&app);
+    assert!(detail.contains("Ticket Details"));
+    assert!(detail.contains("PROJ-124 - Add new feature"));
+
+    press(&mut app, KeyCode::Esc).await;
+    assert!(!screen(&app).contains("Ticket Details"));
+}
+
+#[tokio::test]
+async fn test_opened_tickets_stay_out_of_shell_completion() {
+    let before = RecentTickets::load();
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("PROJ-124"));
+    assert_eq!(RecentTickets::load(), before);
+}
+
+#[tokio::test]
+async fn test_description_toggles_between_rendered_and_raw() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    // PROJ-123, in progress, has a description
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("PROJ-123"));
+    assert!(screen(&app).contains("Description — [p] raw"));
+
+    press(&mut app, KeyCode::Char('p')).await;
+    assert!(screen(&app).contains("Description (raw) — [p] rendered"));
+
+    press(&mut app, KeyCode::Char('p')).await;
+    assert!(screen(&app).contains("Description — [p] raw"));
+}
+
+#[tokio::test]
+async fn test_links_picker_without_links() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    // The description and comments of PROJ-123 have no URLs
+    press(&mut app, KeyCode::Char('w')).await;
+    assert!(screen(&app).contains("No links"));
+    assert!(!screen(&app).contains("Links — [Enter/1-9] open"));
+}
+
+#[tokio::test]
+async fn test_add_web_link_in_detail() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(!screen(&app).contains("Web links"));
+
+    press(&mut app, KeyCode::Char('W')).await;
+    assert!(screen(&app).contains("Add web link to PROJ-123"));
+    for c in "https://example.com/design".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    press(&mut app, KeyCode::Tab).await;
+    for c in "Design doc".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    press(&mut app, KeyCode::Enter).await;
+
+    let detail = screen(&app);
+    assert!(detail.contains("Added web link 'Design doc'"));
+    assert!(detail.contains("Web links (1)"));
+    assert!(detail.contains("Design doc — https://example.com/design"));
+
+    // Links into Confluence are labelled as such
+    press(&mut app, KeyCode::Char('W')).await;
+    for c in "https://example.atlassian.net/wiki/x/AbC".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    press(&mut app, KeyCode::Enter).await;
+    let detail = screen(&app);
+    assert!(detail.contains("Web links (2)"));
+    assert!(detail.contains("https://example.atlassian.net/wiki/x/AbC (Confluence) —"));
+}
+
+#[tokio::test]
+async fn test_development_panel_in_detail() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+    // The demo has no connected development tools
+    assert!(!screen(&app).contains("Development"));
+
+    app.update(Message::DevInfoLoaded {
+        key: "PROJ-123".to_string(),
+        info: Ok(DevInfo {
+            branches: vec![Branch {
+                name: "fix/PROJ-123".to_string(),
+                repository: "acme/api".to_string(),
+                url: "https://github.com/acme/api/tree/fix/PROJ-123".to_string(),
+            }],
+            commits: Vec::new(),
+            pull_requests: vec![PullRequest {
+                id: "#42".to_string(),
+                title: "Fix login".to_string(),
+                status: PullRequestStatus::Open,
+                repository: "acme/api".to_string(),
+                url: "https://github.com/acme/api/pull/42".to_string(),
+            }],
+        }),
+    });
+    app.draw().unwrap();
+    let detail = screen(&app);
+    assert!(detail.contains("Development — 1 PR, 1 branch, 0 commits"));
+    assert!(detail.contains("⇄ #42 Fix login [open]"));
+    assert!(detail.contains("⎇ fix/PROJ-123 (acme/api)"));
+
+    // Pull requests and branches can be opened from the link picker
+    press(&mut app, KeyCode::Char('w')).await;
+    assert!(screen(&app).contains("[1] #42 Fix login (open) — https://github.com/acme/api/pull/42"));
+}
+
+#[tokio::test]
+async fn test_enter_in_detail_without_referenced_issues() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Enter).await;
+    let detail = screen(&app);
+    assert!(detail.contains("No referenced issues"));
+    assert!(detail.contains("PROJ-123"));
+}
+
+#[tokio::test]
+async fn test_leaving_detail_drops_pending_fetch() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+
+    // Back out before the fetch had a chance to finish
+    app.handle_key(KeyEvent::new(KeyCode::Enter, modifiers(KeyCode::Enter))).await;
+    app.handle_key(KeyEvent::new(KeyCode::Esc, modifiers(KeyCode::Esc))).await;
+    tokio::task::yield_now().await;
+    app.poll();
+    app.draw().unwrap();
+    let list = screen(&app);
+    assert!(!list.contains("Ticket Details"));
+    assert!(!list.contains("Loading ticket details"));
+
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("PROJ-124 - Add new feature"));
+}
+
+#[tokio::test]
+async fn test_update_ignores_details_of_another_ticket() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    app.handle_key(KeyEvent::new(KeyCode::Enter, modifiers(KeyCode::Enter))).await;
+
+    let client = DemoApiClient::new().unwrap();
+    let actions = app.update(Message::DetailLoaded {
+        key: "PROJ-123".to_string(),
+        details: client.get_issue_details("PROJ-123").await,
+        comments: Ok(Vec::new()),
+        remote_links: Ok(Vec::new()),
+    });
+    assert!(actions.is_empty());
+    app.draw().unwrap();
+    assert!(screen(&app).contains("Loading ticket details"));
+
+    tokio::task::yield_now().await;
+    app.poll();
+    app.draw().unwrap();
+    assert!(screen(&app).contains("PROJ-124 - Add new feature"));
+}
+
+#[tokio::test]
+async fn test_monochrome_theme_marks_status_with_symbols() {
+    let mut config = Config::default();
+    config.ui.theme = "monochrome".to_string();
+    let mut app = create_test_app_with_config(config).await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("[○ To Do]"));
+
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("Status: ○ To Do"));
+}
+
+#[tokio::test]
+async fn test_monochrome_theme_paints_no_background() {
+    let mut config = Config::default();
+    config.ui.theme = "monochrome".to_string();
+    config.ui.show_avatars = true;
+    let mut app = create_test_app_with_config(config).await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    assert_no_background(&app);
+
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("Reporter:  JS  Jane Smith"));
+    assert_no_background(&app);
+}
+
+fn assert_no_background(app: &App<TestBackend>) {
+    let buffer = app.backend().buffer();
+    for (index, cell) in buffer.content.iter().enumerate() {
+        assert_eq!(cell.bg, Color::Reset, "background at cell {}: {:?}", index, cell);
+    }
+}
+
+#[tokio::test]
+async fn test_work_timer_logs_time_when_stopped() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('z')).await;
+    assert!(screen(&app).contains("⏱ PROJ-123 0:00:0"));
+
+    // Quitting stops the timer and asks about its time first
+    press(&mut app, KeyCode::Char('q')).await;
+    assert!(app.is_running());
+    assert!(screen(&app).contains("Log work on PROJ-123"));
+    for c in "Debugging".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    press(&mut app, KeyCode::Enter).await;
+    let detail = screen(&app);
+    assert!(detail.contains("Logged 1m on PROJ-123"));
+    assert!(!detail.contains("⏱"));
+
+    press(&mut app, KeyCode::Char('q')).await;
+    assert!(!app.is_running());
+}
+
+#[tokio::test]
+async fn test_pomodoro_offers_work_time_when_stopped() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('Z')).await;
+    assert!(screen(&app).contains("PROJ-123 work 2"));
+
+    press(&mut app, KeyCode::Char('Z')).await;
+    let prompt = screen(&app);
+    assert!(prompt.contains("Log work on PROJ-123"));
+    assert!(!prompt.contains("work 2"));
+    press(&mut app, KeyCode::Esc).await;
+    assert!(!screen(&app).contains("Log work on"));
+}
+
+#[tokio::test]
+async fn test_activity_stream_opens_ticket() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+
+    press(&mut app, KeyCode::Char('U')).await;
+    let activity = screen(&app);
+    assert!(activity.contains("Activity"));
+    assert!(activity.contains("3h ago  PROJ-123"));
+    assert!(activity.contains("moved To Do → In Progress"));
+
+    press(&mut app, KeyCode::Enter).await;
+    let detail = screen(&app);
+    assert!(detail.contains("Ticket Details"));
+    assert!(detail.contains("PROJ-123 - Fix bug in authentication"));
+}
+
+#[tokio::test]
+async fn test_toggle_comment_order_in_detail() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('U')).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("Comments (1, oldest first)"));
+
+    press(&mut app, KeyCode::Char('O')).await;
+    let detail = screen(&app);
+    assert!(detail.contains("Comments (1, newest first)"));
+    assert!(detail.contains("Comments newest first"));
+}
+
+#[tokio::test]
+async fn test_audit_log_view_and_quit() {
+    let mut app = create_test_app().await;
+    press(&mut app, KeyCode::Char('H')).await;
+    assert!(screen(&app).contains("No changes made this session"));
+
+    press(&mut app, KeyCode::Char('q')).await;
+    assert!(!app.is_running());
+}
+
+#[tokio::test]
+async fn test_quick_filter_highlighted_in_filter_bar() {
+    let config = Config {
+        filters: vec![
+            FilterConfig {
+                name: "My work".to_string(),
+                jql: "assignee = currentUser()".to_string(),
+            },
+            FilterConfig {
+                name: "Blocked".to_string(),
+                jql: "status = Blocked".to_string(),
+            },
+        ],
+        ..Config::default()
+    };
+    let mut app = create_test_app_with_config(config).await;
+    app.draw().unwrap();
+    assert!(screen(&app).contains(" Default (2)   1 My work (2)   2 Blocked (2) "));
+
+    press(&mut app, KeyCode::Char('2')).await;
+    assert!(screen(&app).contains("Filter: Blocked"));
+
+    press(&mut app, KeyCode::Char('2')).await;
+    assert!(screen(&app).contains("Filter: default"));
+}
+
+#[tokio::test]
+async fn test_start_at_filter_and_ticket() {
+    let config = Config {
+        filters: vec![FilterConfig {
+            name: "Blocked".to_string(),
+            jql: "status = Blocked".to_string(),
+        }],
+        ..Config::default()
+    };
+    let mut app = create_test_app_with_config(config).await;
+    app.start_at(Some("Blocked".to_string()), Some("proj-123".to_string()));
+    app.apply_start_at();
+    tokio::task::yield_now().await;
+    app.poll();
+    app.draw().unwrap();
+    assert!(screen(&app).contains("PROJ-123"));
+    assert!(screen(&app).contains("Ticket Details"));
+
+    press(&mut app, KeyCode::Esc).await;
+    assert!(screen(&app).contains("1 Blocked (2)"));
+}
+
+#[tokio::test]
+async fn test_filter_sidebar_toggles_facets() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('F')).await;
+    let sidebar = screen(&app);
+    assert!(sidebar.contains("Filters"));
+    assert!(sidebar.contains("Projects"));
+
+    press(&mut app, KeyCode::Char(' ')).await;
+    assert!(screen(&app).contains("[x] PROJ"));
+
+    press(&mut app, KeyCode::Esc).await;
+    let list = screen(&app);
+    assert!(!list.contains("Projects"));
+    assert!(list.contains("[1 facet(s)]"));
+}
+
+#[tokio::test]
+async fn test_cycle_assignee_filter() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('u')).await;
+    assert!(screen(&app).contains("— assigned to me"));
+    press(&mut app, KeyCode::Char('u')).await;
+    assert!(screen(&app).contains("— unassigned"));
+    press(&mut app, KeyCode::Char('u')).await;
+    assert!(screen(&app).contains("Showing tickets: everyone"));
+}
+
+#[tokio::test]
+async fn test_switch_to_smart_view() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+
+    press(&mut app, KeyCode::Char('v')).await;
+    assert!(screen(&app).contains("Mentions me"));
+
+    press(&mut app, KeyCode::Char('j')).await;
+    press(&mut app, KeyCode::Enter).await;
+    let switched = screen(&app);
+    assert!(switched.contains("View: Reported by me"));
+    assert!(!switched.contains("Mentions me"));
+}
+
+#[tokio::test]
+async fn test_change_reporter_from_list() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('P')).await;
+    assert!(screen(&app).contains("Set reporter of"));
+    for c in "Alex".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("to Alex Kim"));
+
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("AK  Alex Kim"));
+}
+
+#[tokio::test]
+async fn test_filter_list_by_sprint() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('i')).await;
+    assert!(screen(&app).contains("[ ] ACTIVE Demo Sprint 1"));
+
+    press(&mut app, KeyCode::Char(' ')).await;
+    assert!(screen(&app).contains("[x] ACTIVE Demo Sprint 1"));
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("[Demo Sprint 1]"));
+
+    press(&mut app, KeyCode::Char('i')).await;
+    press(&mut app, KeyCode::Backspace).await;
+    assert!(!screen(&app).contains("[Demo Sprint 1]"));
+}
+
+#[tokio::test]
+async fn test_board_shows_wip_limits() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('b')).await;
+    let board = screen(&app);
+    assert!(board.contains("In Progress 0/1"));
+    assert!(board.contains("In Progress (0)"));
+}
+
+/// Press a key with CTRL held and redraw
+async fn press_ctrl(app: &mut App<TestBackend>, c: char) {
+    app.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)).await;
+    app.draw().unwrap();
+}
+
+/// Advance the paused clock past the search debounce, then let the app
+/// search and apply the result
+async fn settle_search(app: &mut App<TestBackend>) {
+    tokio::time::advance(std::time::Duration::from_millis(250)).await;
+    app.poll();
+    tokio::task::yield_now().await;
+    app.poll();
+    app.draw().unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_search_waits_for_typing_to_pause() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+
+    press_ctrl(&mut app, 'f').await;
+    for c in "login".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    assert!(screen(&app).contains("Filter: login_"));
+    assert!(screen(&app).contains("PROJ-124"));
+
+    settle_search(&mut app).await;
+    assert!(!screen(&app).contains("PROJ-124"));
+
+    press(&mut app, KeyCode::Tab).await;
+    settle_search(&mut app).await;
+    let searched = screen(&app);
+    assert!(searched.contains(" Search "));
+    assert!(searched.contains("PROJ-124"));
+
+    press(&mut app, KeyCode::Esc).await;
+    let cleared = screen(&app);
+    assert!(cleared.contains("PROJ-124"));
+    assert!(!cleared.contains(" Search "));
+}
+
+#[tokio::test]
+async fn test_offer_to_restore_last_session() {
+    let terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+    let mut app = App::with_terminal(
+        terminal,
+        "Demo".to_string(),
+        Arc::new(DemoApiClient::new().unwrap()),
+        "demo.atlassian.net".to_string(),
+        Config::default(),
+    );
+    app.restore_view_state(ViewState {
+        session: Some(SessionState {
+            view: "detail".to_string(),
+            query: None,
+            ticket: Some("PROJ-124".to_string()),
+        }),
+        ..ViewState::default()
+    });
+    app.start().await;
+    app.draw().unwrap();
+    assert!(screen(&app).contains("Restore session"));
+    assert!(screen(&app).contains("detail view at PROJ-124?"));
+
+    press(&mut app, KeyCode::Char('y')).await;
+    assert!(screen(&app).contains("Ticket Details"));
+    assert!(screen(&app).contains("PROJ-124"));
+
+    press(&mut app, KeyCode::Esc).await;
+    assert!(screen(&app).contains(">   PROJ-124"));
+}
+
+/// Instance tabs sharing one demo client
+fn demo_tabs(names: &[&str]) -> Vec<(String, Arc<dyn ApiClient>)> {
+    let client: Arc<dyn ApiClient> = Arc::new(DemoApiClient::new().unwrap());
+    names.iter().map(|name| (name.to_string(), client.clone())).collect()
+}
+
+#[tokio::test]
+async fn test_switch_instance_tabs() {
+    let mut app = create_test_app().await;
+    app.set_tabs(demo_tabs(&["Home", "Acme", "Globex"]), 0);
+    app.draw().unwrap();
+    assert!(screen(&app).contains("[Home] Acme Globex | Demo"));
+
+    press(&mut app, KeyCode::Char(']')).await;
+    assert_eq!(app.take_tab_switch(), Some((1, None)));
+    press(&mut app, KeyCode::Char('[')).await;
+    assert_eq!(app.take_tab_switch(), Some((2, None)));
+}
+
+#[tokio::test]
+async fn test_search_all_instances() {
+    let mut app = create_test_app().await;
+    app.set_tabs(demo_tabs(&["Home", "Acme"]), 0);
+    app.draw().unwrap();
+
+    press(&mut app, KeyCode::Char('I')).await;
+    for c in "login".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    assert!(screen(&app).contains("JQL or text: login_"));
+    press(&mut app, KeyCode::Enter).await;
+    let results = screen(&app);
+    assert!(results.contains("Results (4)"));
+    assert!(results.contains("Found 4 issues on 2 of 2 instances"));
+
+    // Equally recent issues keep the tab order, so the second row is Acme's
+    press(&mut app, KeyCode::Down).await;
+    assert!(screen(&app).contains("> Acme"));
+    press(&mut app, KeyCode::Enter).await;
+    assert!(matches!(app.take_tab_switch(), Some((1, Some(_)))));
+
+    press(&mut app, KeyCode::Up).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("Ticket Details"));
+}
+
+#[tokio::test]
+async fn test_hide_actions_without_permission() {
+    let mut app = create_test_app().await;
+    tokio::task::yield_now().await;
+    app.poll();
+    app.update(Message::PermissionsLoaded(vec![(
+        "PROJ".to_string(),
+        Ok(ProjectPermissions::new([Permission::AddComments])),
+    )]));
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+    let detail = screen(&app);
+    assert!(detail.contains("Ticket Details"));
+    assert!(!detail.contains("[a]ssign"));
+    assert!(!detail.contains("[t]ransitions"));
+
+    press(&mut app, KeyCode::Char('t')).await;
+    assert!(screen(&app).contains("You don't have permission to transition issues in PROJ"));
+}
+
+#[tokio::test]
+async fn test_edit_fields_from_editmeta() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('e')).await;
+    let form = screen(&app);
+    assert!(form.contains("Edit PROJ-124"));
+    assert!(form.contains("Priority: ◀ (unchanged) ▶"));
+    for c in "Ship the feature".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    press(&mut app, KeyCode::Tab).await;
+    for c in "ale".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    press(&mut app, KeyCode::Right).await;
+    assert!(screen(&app).contains("Assignee: ◀ Alex Kim ▶"));
+
+    press(&mut app, KeyCode::Enter).await;
+    let detail = screen(&app);
+    assert!(detail.contains("Updated PROJ-124"));
+    assert!(detail.contains("PROJ-124 - Ship the feature"));
+    assert!(detail.contains("Alex Kim"));
+}
+
+#[tokio::test]
+async fn test_edit_form_of_a_left_ticket_stays_closed() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    app.handle_key(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE)).await;
+    app.draw().unwrap();
+    assert!(!screen(&app).contains("Edit PROJ-124"));
+    press(&mut app, KeyCode::Esc).await;
+    assert!(!screen(&app).contains("Edit PROJ-124"));
+
+    let client = DemoApiClient::new().unwrap();
+    let actions = app.update(Message::EditFieldsLoaded {
+        key: "PROJ-123".to_string(),
+        fields: client.get_edit_fields("PROJ-123").await,
+    });
+    assert!(actions.is_empty());
+    app.draw().unwrap();
+    assert!(!screen(&app).contains("Edit PROJ-123"));
+}
+
+#[tokio::test]
+async fn test_rejected_edit_reopens_form() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Char('e')).await;
+    press(&mut app, KeyCode::Char('x')).await;
+
+    // Sent, but Jira's answer comes from the test rather than the demo
+    app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)).await;
+    app.draw().unwrap();
+    assert!(!screen(&app).contains("Edit PROJ-124"));
+
+    let error = JiraApiError {
+        status: 400,
+        messages: Vec::new(),
+        field_errors: BTreeMap::from([("summary".to_string(), "Summary is too short".to_string())]),
+    };
+    let actions = app.update(Message::Written {
+        write: Write::Update {
+            key: "PROJ-124".to_string(),
+            data: UpdateIssueData {
+                fields: HashMap::new(),
+            },
+        },
+        result: Err(LazyJiraError::Jira(error)),
+    });
+    assert!(actions.is_empty());
+    app.draw().unwrap();
+    let form = screen(&app);
+    assert!(form.contains("Edit PROJ-124"));
+    assert!(form.contains("✗ Summary is too short"));
+}
+
+#[tokio::test]
+async fn test_stale_reload_is_dropped() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    let list = screen(&app);
+    assert!(list.contains("PROJ-124"));
+
+    let actions = app.update(Message::TicketsLoaded {
+        jql: "project = OLD".to_string(),
+        started: Instant::now(),
+        result: Ok(SearchResult {
+            start_at: 0,
+            max_results: 50,
+            total: 0,
+            issues: Vec::new(),
+        }),
+    });
+    assert!(actions.is_empty());
+    app.draw().unwrap();
+    assert_eq!(screen(&app), list);
+}
+
+/// Row of the ticket list that `key` is shown on
+fn row_of(app: &App<TestBackend>, key: &str) -> usize {
+    let screen = screen(app);
+    let found = screen.lines().position(|line| line.contains(&format!("{} ", key)));
+    found.unwrap_or_else(|| panic!("{} is not listed", key))
+}
+
+#[tokio::test]
+async fn test_refresh_moves_changed_ticket_to_its_place() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('S')).await;
+    press(&mut app, KeyCode::Esc).await;
+    let tickets = DemoApiClient::new().unwrap().search_issues("", 0, 50).await.unwrap().issues;
+    let (first, second) = (&tickets[0], &tickets[1]);
+    assert!(row_of(&app, &first.key) < row_of(&app, &second.key));
+
+    // Under the default ORDER BY updated DESC, the newest edit goes on top
+    let mut changed = second.clone();
+    changed.updated = chrono::Utc::now() + chrono::Duration::hours(1);
+    let actions = app.update(Message::ChangesLoaded {
+        jql: Config::default().jira.default_query,
+        started: Instant::now(),
+        result: Ok(ListChanges {
+            matching: vec![changed.clone()],
+            touched: Ok(vec![changed]),
+        }),
+    });
+    assert!(!actions.iter().any(|action| matches!(action, Action::Reload(_))));
+    app.draw().unwrap();
+    assert!(row_of(&app, &second.key) < row_of(&app, &first.key));
+}
+
+#[tokio::test]
+async fn test_refresh_reloads_when_order_is_up_to_jira() {
+    let mut config = Config::default();
+    config.jira.default_query = "project = PROJ ORDER BY priority DESC".to_string();
+    let mut app = create_test_app_with_config(config.clone()).await;
+    let tickets = DemoApiClient::new().unwrap().search_issues("", 0, 50).await.unwrap().issues;
+    let mut changed = tickets[1].clone();
+    changed.updated = chrono::Utc::now() + chrono::Duration::hours(1);
+    let actions = app.update(Message::ChangesLoaded {
+        jql: config.jira.default_query.clone(),
+        started: Instant::now(),
+        result: Ok(ListChanges {
+            matching: vec![changed.clone()],
+            touched: Ok(vec![changed]),
+        }),
+    });
+    assert_eq!(actions, vec![Action::Reload(config.jira.default_query.clone())]);
+
+    // A deleted or hidden issue makes Jira reject the search of the listed keys
+    let actions = app.update(Message::ChangesLoaded {
+        jql: config.jira.default_query.clone(),
+        started: Instant::now(),
+        result: Ok(ListChanges {
+            matching: Vec::new(),
+            touched: Err(LazyJiraError::Validation("An issue with key 'PROJ-1' does not exist".to_string())),
+        }),
+    });
+    assert_eq!(actions, vec![Action::Reload(config.jira.default_query)]);
+}
+
+#[tokio::test]
+async fn test_show_issue_properties() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('x')).await;
+    let panel = screen(&app);
+    assert!(panel.contains("Properties of PROJ-124 (1)"));
+    assert!(panel.contains("demo.automation"));
+    assert!(panel.contains("\"rule\": \"Assign new issues to the demo user\""));
+
+    press(&mut app, KeyCode::Esc).await;
+    assert!(!screen(&app).contains("Properties of"));
+    assert!(screen(&app).contains("Ticket Details"));
+}
+
+#[tokio::test]
+async fn test_step_between_tickets_in_detail_view() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    // Leaving the sprints lands on the unfiltered list
+    press(&mut app, KeyCode::Char('S')).await;
+    press(&mut app, KeyCode::Esc).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('[')).await;
+    assert!(screen(&app).contains("PROJ-123 is the first ticket in the list"));
+
+    press(&mut app, KeyCode::Char(']')).await;
+    let detail = screen(&app);
+    assert!(detail.contains("PROJ-124 - Add new feature"));
+    assert!(!detail.contains("PROJ-123 - Fix bug in authentication"));
+
+    press(&mut app, KeyCode::Char(']')).await;
+    assert!(screen(&app).contains("PROJ-124 is the last ticket in the list"));
+
+    // Back in the list, the stepped-to ticket is focused
+    press(&mut app, KeyCode::Esc).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("PROJ-124 - Add new feature"));
+    press(&mut app, KeyCode::Char('[')).await;
+    assert!(screen(&app).contains("PROJ-123 - Fix bug in authentication"));
+}
+
+#[tokio::test]
+async fn test_navigation_history() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('S')).await;
+    press(&mut app, KeyCode::Esc).await;
+    press(&mut app, KeyCode::Down).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("PROJ-124 - Add new feature"));
+    press(&mut app, KeyCode::Esc).await;
+    press(&mut app, KeyCode::Up).await;
+
+    press_ctrl(&mut app, 'o').await;
+    tokio::task::yield_now().await;
+    app.poll();
+    app.draw().unwrap();
+    assert!(screen(&app).contains("PROJ-124 - Add new feature"));
+
+    // Back in the list as it was left for the detail view
+    press_ctrl(&mut app, 'o').await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("PROJ-124 - Add new feature"));
+    press(&mut app, KeyCode::Esc).await;
+
+    press_ctrl(&mut app, 'o').await;
+    press_ctrl(&mut app, 'o').await;
+    press_ctrl(&mut app, 'o').await;
+    assert!(screen(&app).contains("Nothing to go back to"));
+    press(&mut app, KeyCode::Tab).await;
+    assert!(screen(&app).contains("PROJ-124 - Add new feature"));
+    press(&mut app, KeyCode::Tab).await;
+    assert!(!screen(&app).contains("Ticket Details"));
+    press(&mut app, KeyCode::Tab).await;
+    assert!(screen(&app).contains("Nothing to go forward to"));
+}
+
+#[tokio::test]
+async fn test_jump_to_row() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Char('S')).await;
+    press(&mut app, KeyCode::Esc).await;
+    assert!(!screen(&app).contains("1   PROJ-123"));
+
+    press(&mut app, KeyCode::Char(':')).await;
+    press(&mut app, KeyCode::Char('2')).await;
+    let prompt = screen(&app);
+    assert!(prompt.contains("Go to row: 2_"));
+    assert!(prompt.contains("1   PROJ-123"));
+    assert!(prompt.contains("2   PROJ-124"));
+
+    press(&mut app, KeyCode::Enter).await;
+    assert!(!screen(&app).contains("Go to row"));
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("PROJ-124 - Add new feature"));
+
+    press(&mut app, KeyCode::Esc).await;
+    press(&mut app, KeyCode::Char(':')).await;
+    press(&mut app, KeyCode::Char('9')).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("No row 9; the list has 2"));
+}
+
+#[tokio::test]
+async fn test_search_in_detail_view() {
+    let mut app = create_test_app().await;
+    app.draw().unwrap();
+    // PROJ-123 has a description and a comment
+    press(&mut app, KeyCode::Char('l')).await;
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+
+    press(&mut app, KeyCode::Char('/')).await;
+    for c in "THE".chars() {
+        press(&mut app, KeyCode::Char(c)).await;
+    }
+    let found = screen(&app);
+    assert!(found.contains("Find: THE_  1/2"));
+
+    press(&mut app, KeyCode::Char('x')).await;
+    assert!(screen(&app).contains("Find: THEx_  no matches"));
+    press(&mut app, KeyCode::Backspace).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("Find: THE  1/2  [n/N] next/previous"));
+
+    press(&mut app, KeyCode::Char('n')).await;
+    assert!(screen(&app).contains("Find: THE  2/2"));
+    press(&mut app, KeyCode::Char('N')).await;
+    assert!(screen(&app).contains("Find: THE  1/2"));
+
+    // Esc clears the search before leaving the ticket
+    press(&mut app, KeyCode::Esc).await;
+    let cleared = screen(&app);
+    assert!(!cleared.contains("Find:"));
+    assert!(cleared.contains("Ticket Details"));
+}
+
+#[tokio::test]
+async fn test_custom_field_aliases() {
+    let mut config = Config::default();
+    config.fields.insert("team".to_string(), "customfield_10201".to_string());
+    config.ui.list_columns = vec!["team".to_string()];
+    let mut app = create_test_app_with_config(config).await;
+    app.draw().unwrap();
+    press(&mut app, KeyCode::Enter).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(!screen(&app).contains("Team: "));
+
+    // Aliased fields come first in the edit form
+    press(&mut app, KeyCode::Char('e')).await;
+    assert!(screen(&app).contains("Team: ◀ (unchanged) ▶"));
+    press(&mut app, KeyCode::Right).await;
+    press(&mut app, KeyCode::Enter).await;
+    assert!(screen(&app).contains("Team: Platform"));
+
+    press(&mut app, KeyCode::Esc).await;
+    let list = screen(&app);
+    assert!(list.contains("PROJ-124"));
+    assert!(list.contains("• Team: Platform"));
+}